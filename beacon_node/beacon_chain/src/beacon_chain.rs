@@ -8,6 +8,7 @@ use crate::beacon_block_streamer::{BeaconBlockStreamer, CheckCaches};
 use crate::beacon_proposer_cache::{
     BeaconProposerCache, EpochBlockProposers, ensure_state_can_determine_proposers_for_epoch,
 };
+use crate::bid_selection::BidRejection;
 use crate::blob_verification::{GossipBlobError, GossipVerifiedBlob};
 use crate::block_times_cache::BlockTimesCache;
 use crate::block_verification::POS_PANDA_BANNER;
@@ -78,6 +79,7 @@ use crate::{
 use eth2::types::{
     EventKind, SseBlobSidecar, SseBlock, SseDataColumnSidecar, SseExtendedPayloadAttributes,
 };
+use eth2::vibehouse::BuilderReliability;
 use execution_layer::{
     BlockProposalContents, BlockProposalContentsType, BuilderParams, ChainHealth, ExecutionLayer,
     FailedCondition, PayloadAttributes, PayloadStatus,
@@ -117,10 +119,12 @@ use state_processing::{
 };
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 use store::iter::{BlockRootsIterator, StateRootsIterator};
 use store::{
@@ -154,10 +158,10 @@ use types::{
     SignedBeaconBlock, SignedBeaconBlockHash, SignedBlindedBeaconBlock,
     SignedBlindedExecutionPayloadEnvelope, SignedBlsToExecutionChange, SignedContributionAndProof,
     SignedExecutionPayloadBid, SignedExecutionPayloadBidGloas, SignedExecutionPayloadBidHeze,
-    SignedExecutionPayloadEnvelope, SignedInclusionList, SignedProposerPreferences,
-    SignedVoluntaryExit, SingleAttestation, Slot, SubnetId, SyncAggregate, SyncCommittee,
-    SyncCommitteeContribution, SyncCommitteeMessage, SyncContributionData, SyncDuty, SyncSubnetId,
-    Uint256, VariableList, Withdrawals,
+    SignedExecutionPayloadBidRef, SignedExecutionPayloadEnvelope, SignedInclusionList,
+    SignedProposerPreferences, SignedVoluntaryExit, SingleAttestation, Slot, SubnetId,
+    SyncAggregate, SyncCommittee, SyncCommitteeContribution, SyncCommitteeMessage,
+    SyncContributionData, SyncDuty, SyncSubnetId, Uint256, VariableList, Withdrawals,
 };
 
 pub type ForkChoiceError = fork_choice::Error<crate::ForkChoiceStoreError>;
@@ -362,6 +366,9 @@ pub struct BeaconChain<T: BeaconChainTypes> {
     pub task_executor: TaskExecutor,
     /// Database migrator for running background maintenance on the store.
     pub store_migrator: BackgroundMigrator<T::EthSpec, T::HotStore, T::ColdStore>,
+    /// Set while block history is being imported from the era files in `config.era_dir`, during
+    /// which backfill sync is held back.
+    pub era_import_in_progress: AtomicBool,
     /// Reports the current slot, typically based upon the system clock.
     pub slot_clock: T::SlotClock,
     /// Stores all operations (e.g., `Attestation`, `Deposit`, etc) that are candidates for
@@ -421,6 +428,11 @@ pub struct BeaconChain<T: BeaconChainTypes> {
         Mutex<crate::observed_execution_bids::ObservedExecutionBids<T::EthSpec>>,
     /// Pool of verified execution bids available for block production (bid selection).
     pub execution_bid_pool: Mutex<crate::execution_bid_pool::ExecutionBidPool<T::EthSpec>>,
+    /// Per-builder counters of bids, payload reveals and payments for the builder analysis API.
+    pub builder_reliability: Mutex<crate::builder_reliability::BuilderReliabilityTracker>,
+    /// Gloas ePBS: Blocks imported after their slot ended, whose `payload_attestation_outcome`
+    /// event is registered once their child has been imported.
+    pub late_payload_attestation_outcomes: Mutex<HashMap<Hash256, Slot>>,
     /// Maintains a record of which payload attestations we've seen (for equivocation detection).
     pub observed_payload_attestations:
         Mutex<crate::observed_payload_attestations::ObservedPayloadAttestations<T::EthSpec>>,
@@ -715,6 +727,26 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         Ok(())
     }
 
+    /// Persists all in-memory builder reliability counters to disk.
+    pub fn persist_builder_reliability(&self) -> Result<(), Error> {
+        self.builder_reliability.lock().persist(&self.store, None)?;
+
+        Ok(())
+    }
+
+    /// Returns the reliability counters of each builder summed over `start_epoch..=end_epoch`.
+    pub fn get_builder_reliability(
+        &self,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<Vec<BuilderReliability>, Error> {
+        Ok(self.builder_reliability.lock().get_builder_reliability(
+            &self.store,
+            start_epoch,
+            end_epoch,
+        )?)
+    }
+
     /// Returns the slot _right now_ according to `self.slot_clock`. Returns `Err` if the slot is
     /// unavailable.
     ///
@@ -2656,30 +2688,128 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         verified_bid: &crate::gloas_verification::VerifiedExecutionBid<T>,
     ) {
         let bid = verified_bid.bid();
+        self.builder_reliability
+            .lock()
+            .register_bid_seen::<T::EthSpec>(
+                *bid.to_ref().message().slot(),
+                *bid.to_ref().message().builder_index(),
+            );
         self.execution_bid_pool.lock().insert(bid.clone());
     }
 
-    /// Returns the best (highest value) external bid for the given slot and parent root, if any.
+    /// Returns the best (highest value) external bid for the given slot and parent root that is
+    /// accepted by the configured `BidSelectionPolicy`, if any.
     ///
     /// Used during block production to select a builder bid instead of self-building.
     /// Filters by `parent_block_root` to avoid selecting stale bids after a re-org.
     ///
+    /// Candidates are considered from highest to lowest value, and each one is checked against
+    /// the policy (minimum value, allow/deny lists, builder balance). The outcome for every
+    /// candidate is logged so that operators can audit why a bid was picked or rejected.
+    ///
     /// For Heze bids, also validates that `bid.inclusion_list_bits` satisfies
     /// `is_inclusion_list_bits_inclusive(store, state, slot - 1, bid.inclusion_list_bits)`.
     /// This ensures the builder has observed at least all the inclusion lists the proposer
     /// has locally seen. Bids failing this check are skipped.
+    ///
+    /// Bids are checked against the head state. Block production uses
+    /// `get_best_execution_bid_for_state` to check them against the proposal state instead.
     pub fn get_best_execution_bid(
         &self,
         slot: Slot,
         parent_block_root: Hash256,
     ) -> Option<SignedExecutionPayloadBid<T::EthSpec>> {
-        let mut pool = self.execution_bid_pool.lock();
-        pool.prune(slot);
-        let bid = pool.get_best_bid(slot, parent_block_root).cloned()?;
+        let head = self.canonical_head.cached_head();
+        self.get_best_execution_bid_for_state(slot, parent_block_root, &head.snapshot.beacon_state)
+    }
+
+    /// As `get_best_execution_bid`, but checks the bids against `state`, which should be the
+    /// state the block is proposed on.
+    pub fn get_best_execution_bid_for_state(
+        &self,
+        slot: Slot,
+        parent_block_root: Hash256,
+        state: &BeaconState<T::EthSpec>,
+    ) -> Option<SignedExecutionPayloadBid<T::EthSpec>> {
+        let candidates: Vec<_> = {
+            let mut pool = self.execution_bid_pool.lock();
+            pool.prune(slot);
+            pool.get_bids_by_value(slot, parent_block_root)
+                .into_iter()
+                .cloned()
+                .collect()
+        };
+
+        if candidates.is_empty() {
+            info!(
+                %slot,
+                ?parent_block_root,
+                reason = "no external bids for parent",
+                "Self-building execution payload"
+            );
+            return None;
+        }
+
+        let num_candidates = candidates.len();
+
+        for bid in candidates {
+            let builder_index = *bid.to_ref().message().builder_index();
+            let value = *bid.to_ref().message().value();
+
+            match self.check_execution_bid_for_proposal(&bid, slot, state) {
+                Ok(()) => {
+                    info!(
+                        %slot,
+                        builder_index,
+                        value,
+                        num_candidates,
+                        "Selected external builder bid"
+                    );
+                    return Some(bid);
+                }
+                Err(reason) => {
+                    info!(
+                        %slot,
+                        builder_index,
+                        value,
+                        %reason,
+                        "Rejected external builder bid"
+                    );
+                    metrics::inc_counter_vec(
+                        &metrics::BLOCK_PRODUCTION_BID_REJECTIONS_TOTAL,
+                        &[reason.as_str()],
+                    );
+                }
+            }
+        }
+
+        info!(
+            %slot,
+            num_candidates,
+            reason = "all external bids rejected",
+            "Self-building execution payload"
+        );
+        None
+    }
+
+    /// Checks a single external bid against the `BidSelectionPolicy` and, for Heze bids, the
+    /// inclusion list bits rule.
+    fn check_execution_bid_for_proposal(
+        &self,
+        bid: &SignedExecutionPayloadBid<T::EthSpec>,
+        slot: Slot,
+        state: &BeaconState<T::EthSpec>,
+    ) -> Result<(), BidRejection> {
+        self.config.bid_selection.check_bid(
+            *bid.to_ref().message().builder_index(),
+            *bid.to_ref().message().value(),
+            state,
+            &self.spec,
+        )?;
 
         // Heze: validate inclusion_list_bits per spec validator.md
         // The proposer must check is_inclusion_list_bits_inclusive(store, state, slot - 1, bits)
-        if let SignedExecutionPayloadBid::Heze(ref heze_bid) = bid {
+        if let SignedExecutionPayloadBid::Heze(heze_bid) = bid {
             let il_slot_ok = slot
                 .as_u64()
                 .checked_sub(1)
@@ -2690,43 +2820,34 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
                         .heze_enabled()
                 });
 
-            if let Some(il_slot) = il_slot_ok {
-                let head = self.canonical_head.cached_head();
-                let state = &head.snapshot.beacon_state;
-
-                if let Ok(committee) =
+            if let Some(il_slot) = il_slot_ok
+                && let Ok(committee) =
                     get_inclusion_list_committee::<T::EthSpec>(state, il_slot, &self.spec)
-                {
-                    let committee_fixed: ssz_types::FixedVector<
-                        u64,
-                        <T::EthSpec as EthSpec>::InclusionListCommitteeSize,
-                    > = match ssz_types::FixedVector::new(committee.clone()) {
-                        Ok(v) => v,
-                        Err(_) => return Some(bid),
-                    };
-                    let committee_root = committee_fixed.tree_hash_root();
-
-                    let store = self.inclusion_list_store.lock();
-                    let bits: Vec<bool> = heze_bid.message.inclusion_list_bits.iter().collect();
-
-                    if !store.is_inclusion_list_bits_inclusive(
-                        &committee,
-                        committee_root,
-                        il_slot,
-                        &bits,
-                    ) {
-                        debug!(
-                            builder_index = heze_bid.message.builder_index,
-                            %slot,
-                            "Rejecting external bid: inclusion_list_bits not inclusive"
-                        );
-                        return None;
-                    }
+            {
+                let committee_fixed: ssz_types::FixedVector<
+                    u64,
+                    <T::EthSpec as EthSpec>::InclusionListCommitteeSize,
+                > = match ssz_types::FixedVector::new(committee.clone()) {
+                    Ok(v) => v,
+                    Err(_) => return Ok(()),
+                };
+                let committee_root = committee_fixed.tree_hash_root();
+
+                let store = self.inclusion_list_store.lock();
+                let bits: Vec<bool> = heze_bid.message.inclusion_list_bits.iter().collect();
+
+                if !store.is_inclusion_list_bits_inclusive(
+                    &committee,
+                    committee_root,
+                    il_slot,
+                    &bits,
+                ) {
+                    return Err(BidRejection::InclusionListBitsNotInclusive);
                 }
             }
         }
 
-        Some(bid)
+        Ok(())
     }
 
     /// Applies a verified payload envelope to fork choice (gloas ePBS).
@@ -3264,6 +3385,9 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
     /// - `pending_gossip_envelopes` — root-keyed, bounded by capacity
     /// - `execution_proof_tracker` — root-keyed, bounded by capacity
     /// - `pending_execution_proofs` — root-keyed, bounded by capacity
+    ///
+    /// Inclusion lists and payload attestations leaving the pools are first written to the store
+    /// by `persist_side_objects`, if they are being retained.
     fn prune_gloas_pools(&self, current_slot: Slot) {
         /// Maximum number of slots to retain for slot-keyed Gloas pools.
         const MAX_GLOAS_POOL_SLOTS: u64 = 4;
@@ -3274,6 +3398,13 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
 
         let earliest_slot = Slot::new(current_slot.as_u64().saturating_sub(MAX_GLOAS_POOL_SLOTS));
 
+        if let Err(e) = self.persist_side_objects(Some(earliest_slot)) {
+            warn!(
+                error = ?e,
+                "Failed to persist inclusion lists and payload attestations"
+            );
+        }
+
         self.payload_attestation_pool
             .lock()
             .retain(|&slot, _| slot >= earliest_slot);
@@ -3424,6 +3555,69 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         }
     }
 
+    /// Returns `true` if `block_root` is still waiting on execution proofs in stateless mode.
+    ///
+    /// Only Gloas blocks in fork choice whose payload is neither valid nor invalid require proofs,
+    /// other blocks are never added to `execution_proof_tracker`.
+    ///
+    /// Proofs persisted by a previous run are loaded into `execution_proof_tracker` first, so a
+    /// restarted node does not re-request proofs it already has. If the stored proofs reach the
+    /// threshold the block is marked execution-valid and `false` is returned.
+    pub fn execution_proofs_missing(&self, block_root: Hash256) -> bool {
+        if !self.config.stateless_validation {
+            return false;
+        }
+
+        let proofs_required = self
+            .canonical_head
+            .fork_choice_read_lock()
+            .get_block(&block_root)
+            .is_some_and(|block| {
+                self.spec
+                    .fork_name_at_slot::<T::EthSpec>(block.slot)
+                    .gloas_enabled()
+                    && !matches!(
+                        block.execution_status,
+                        ExecutionStatus::Valid(_) | ExecutionStatus::Invalid(_)
+                    )
+            });
+        if !proofs_required {
+            return false;
+        }
+
+        let required = self.config.stateless_min_proofs_required;
+        let mut tracker = self.execution_proof_tracker.lock();
+        let subnets = tracker.entry(block_root).or_default();
+        if subnets.len() >= required {
+            return false;
+        }
+
+        match self.store.get_execution_proofs(&block_root) {
+            Ok(stored) => subnets.extend(stored.iter().map(|proof| proof.subnet_id)),
+            Err(e) => {
+                warn!(?block_root, error = ?e, "Failed to load stored execution proofs");
+            }
+        }
+
+        if subnets.len() < required {
+            return true;
+        }
+
+        drop(tracker);
+        if let Err(e) = self
+            .canonical_head
+            .fork_choice_write_lock()
+            .on_valid_execution_payload(block_root)
+        {
+            debug!(
+                ?block_root,
+                error = ?e,
+                "Failed to mark block as execution-valid from stored proofs"
+            );
+        }
+        false
+    }
+
     /// Process a locally-produced self-build envelope directly (no gossip verification).
     ///
     /// This is called after block import for self-built Gloas blocks. It:
@@ -3903,31 +4097,86 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             return vec![];
         };
 
-        // Group attestations by data and aggregate bits + signatures.
-        let mut aggregated: HashMap<PayloadAttestationData, PayloadAttestation<T::EthSpec>> =
-            HashMap::new();
+        aggregate_payload_attestations(
+            atts.iter()
+                .filter(|att| att.data.beacon_block_root == parent_block_root),
+        )
+        .into_iter()
+        .take(T::EthSpec::max_payload_attestations())
+        .collect()
+    }
 
-        for att in atts
-            .iter()
-            .filter(|att| att.data.beacon_block_root == parent_block_root)
-        {
-            match aggregated.entry(att.data) {
-                std::collections::hash_map::Entry::Vacant(entry) => {
-                    entry.insert(att.clone());
-                }
-                std::collections::hash_map::Entry::Occupied(mut entry) => {
-                    let existing = entry.get_mut();
-                    existing.aggregation_bits =
-                        existing.aggregation_bits.union(&att.aggregation_bits);
-                    existing.signature.add_assign_aggregate(&att.signature);
-                }
+    /// Returns the signed inclusion lists received for `slot`, sorted by validator index.
+    ///
+    /// Recent slots are served from the inclusion list store, older ones from the database if
+    /// they were retained.
+    pub fn get_inclusion_list_history(
+        &self,
+        slot: Slot,
+    ) -> Result<Vec<SignedInclusionList<T::EthSpec>>, Error> {
+        let mut lists = self.get_all_inclusion_lists(Some(slot));
+        if lists.is_empty() {
+            lists = self.store.get_inclusion_list_history(slot)?;
+        }
+        lists.sort_by_key(|list| list.message.validator_index);
+        Ok(lists)
+    }
+
+    /// Returns the aggregated payload attestations received for `slot`.
+    ///
+    /// Recent slots are served from the pool, older ones from the database if they were retained.
+    pub fn get_payload_attestation_history(
+        &self,
+        slot: Slot,
+    ) -> Result<Vec<PayloadAttestation<T::EthSpec>>, Error> {
+        let aggregated = aggregate_payload_attestations(
+            self.payload_attestation_pool
+                .lock()
+                .get(&slot)
+                .into_iter()
+                .flatten(),
+        );
+        if !aggregated.is_empty() {
+            return Ok(aggregated);
+        }
+        Ok(self.store.get_payload_attestation_history(slot)?)
+    }
+
+    /// Writes the signed inclusion lists and aggregated payload attestations of the slots before
+    /// `before_slot` (or of all slots if `None`) to the store, if they are being retained.
+    pub fn persist_side_objects(&self, before_slot: Option<Slot>) -> Result<(), Error> {
+        if !self.store.retains_side_objects() {
+            return Ok(());
+        }
+        let is_persisted = |slot: Slot| before_slot.is_none_or(|before| slot < before);
+
+        let mut inclusion_lists: BTreeMap<Slot, Vec<SignedInclusionList<T::EthSpec>>> =
+            BTreeMap::new();
+        for ((slot, _), by_validator) in &self.inclusion_list_store.lock().signed_cache {
+            if is_persisted(*slot) {
+                inclusion_lists
+                    .entry(*slot)
+                    .or_default()
+                    .extend(by_validator.values().cloned());
             }
         }
+        for (slot, mut lists) in inclusion_lists {
+            lists.sort_by_key(|list| list.message.validator_index);
+            self.store.put_inclusion_list_history(slot, lists)?;
+        }
 
-        aggregated
-            .into_values()
-            .take(T::EthSpec::max_payload_attestations())
-            .collect()
+        let payload_attestations = self
+            .payload_attestation_pool
+            .lock()
+            .iter()
+            .filter(|(slot, _)| is_persisted(**slot))
+            .map(|(slot, atts)| (*slot, aggregate_payload_attestations(atts)))
+            .collect::<Vec<_>>();
+        for (slot, atts) in payload_attestations {
+            self.store.put_payload_attestation_history(slot, atts)?;
+        }
+
+        Ok(())
     }
 
     /// Insert a verified proposer preferences message into the pool.
@@ -3946,6 +4195,16 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             return false;
         }
 
+        // This method is called for both API and gossip preferences, so this covers all
+        // preferences events.
+        if let Some(event_handler) = self.event_handler.as_ref()
+            && event_handler.has_proposer_preferences_subscribers()
+        {
+            event_handler.register(EventKind::ProposerPreferences(Box::new(
+                signed_preferences.clone(),
+            )));
+        }
+
         pool.insert(slot, signed_preferences);
 
         // Prune preferences older than 2 epochs to bound memory.
@@ -5369,6 +5628,18 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         proof: VerifiedExecutionProof<T>,
     ) -> Result<AvailabilityProcessingStatus, BlockError> {
         let subnet_id = proof.subnet_id();
+        let inner = proof.into_inner();
+
+        // Persist the proof so it survives restarts and can be served to peers via
+        // `execution_proofs_by_root`. A failure here only affects serving, not validation.
+        if let Err(e) = self.store.put_execution_proof(&inner) {
+            warn!(
+                ?block_root,
+                %subnet_id,
+                error = ?e,
+                "Failed to persist execution proof"
+            );
+        }
 
         // For stateless nodes: Gloas blocks are imported immediately without entering the
         // DA checker cache, so put_execution_proofs would silently discard them (epoch
@@ -5403,7 +5674,6 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         }
 
         // Non-stateless path: use the DA checker normally
-        let inner = proof.into_inner();
         let availability = self
             .data_availability_checker
             .put_gossip_verified_execution_proofs(block_root, vec![(subnet_id, inner)])?;
@@ -5911,6 +6181,16 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         );
         self.import_block_update_slasher(block, &state, &mut consensus_context);
 
+        if let Ok(bid) = block.body().signed_execution_payload_bid() {
+            self.builder_reliability.lock().register_block_bid(
+                block_root,
+                block.slot(),
+                *bid.message().builder_index(),
+                *bid.message().value(),
+                &self.spec,
+            );
+        }
+
         // Store the block and its state, and execute the confirmation batch for the intermediate
         // states, which will delete their temporary flags.
         // If the write fails, revert fork choice to the version from disk, else we can
@@ -6152,6 +6432,23 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             );
         }
 
+        // Payload attestations.
+        if let Ok(payload_attestations) = block.body().payload_attestations() {
+            for payload_attestation in payload_attestations {
+                validator_monitor
+                    .register_payload_attestation_in_block(block.slot(), payload_attestation);
+            }
+        }
+
+        // Inclusion list bits.
+        if let Ok(SignedExecutionPayloadBidRef::Heze(bid)) =
+            block.body().signed_execution_payload_bid()
+        {
+            let inclusion_list_bits = bid.message.inclusion_list_bits.iter().collect::<Vec<_>>();
+            validator_monitor
+                .register_inclusion_list_bits_in_block(block.slot(), &inclusion_list_bits);
+        }
+
         for exit in block.body().voluntary_exits() {
             validator_monitor.register_block_voluntary_exit(&exit.message);
         }
@@ -6306,6 +6603,8 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             }));
         }
 
+        self.register_late_payload_attestation_outcome(block, block_root, current_slot);
+
         // Do not trigger light_client server update producer for old blocks, to extra work
         // during sync.
         if self.config.enable_light_client_server
@@ -6963,6 +7262,7 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             head_hash: parent_head_hash,
             justified_hash: canonical_forkchoice_params.justified_hash,
             finalized_hash: canonical_forkchoice_params.finalized_hash,
+            confirmed_hash: canonical_forkchoice_params.confirmed_hash,
         };
 
         debug!(
@@ -7049,15 +7349,33 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         let prepare_payload_handle = partial_beacon_block.prepare_payload_handle.take();
         let block_contents_type_option =
             if let Some(prepare_payload_handle) = prepare_payload_handle {
-                Some(
-                    prepare_payload_handle
-                        .await
-                        .map_err(BlockProductionError::TokioJoin)?
-                        .ok_or(BlockProductionError::ShuttingDown)??,
-                )
+                match prepare_payload_handle
+                    .await
+                    .map_err(BlockProductionError::TokioJoin)?
+                    .ok_or(BlockProductionError::ShuttingDown)?
+                {
+                    Ok(block_contents_type) => Some(block_contents_type),
+                    // The local payload was only requested to compare against the external bid
+                    // under a self-build boost, so fall back to the bid if it failed.
+                    Err(error) if partial_beacon_block.selected_external_bid.is_some() => {
+                        warn!(
+                            ?error,
+                            slot = %partial_beacon_block.state.slot(),
+                            "Failed to get local payload, using external builder bid"
+                        );
+                        None
+                    }
+                    Err(error) => return Err(error),
+                }
             } else {
                 None
             };
+        // ePBS (Gloas+) self-build boost: if both an external bid and a local payload are
+        // available, keep only the winner.
+        let block_contents_type_option = self.apply_self_build_boost(
+            &mut partial_beacon_block.selected_external_bid,
+            block_contents_type_option,
+        );
         // Part 3/3 (blocking)
         if let Some(block_contents_type) = block_contents_type_option {
             match block_contents_type {
@@ -7189,6 +7507,62 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         }
     }
 
+    /// Compares the selected external bid with the locally built payload when the
+    /// `BidSelectionPolicy` has a self-build boost configured.
+    ///
+    /// Returns the block contents that should be used to complete the block: `None` if the
+    /// external bid wins, or the local contents (with `selected_external_bid` cleared) if the
+    /// boosted local payload is worth at least as much as the bid.
+    fn apply_self_build_boost(
+        &self,
+        selected_external_bid: &mut Option<SignedExecutionPayloadBid<T::EthSpec>>,
+        block_contents_type_option: Option<BlockProposalContentsType<T::EthSpec>>,
+    ) -> Option<BlockProposalContentsType<T::EthSpec>> {
+        let Some(bid) = selected_external_bid.as_ref() else {
+            return block_contents_type_option;
+        };
+        let block_contents_type = block_contents_type_option?;
+
+        let local_value = match &block_contents_type {
+            BlockProposalContentsType::Full(contents) => *contents.block_value(),
+            BlockProposalContentsType::Blinded(contents) => *contents.block_value(),
+        };
+        let builder_index = *bid.to_ref().message().builder_index();
+        let value = *bid.to_ref().message().value();
+
+        match self
+            .config
+            .bid_selection
+            .check_against_local_payload(value, local_value)
+        {
+            Ok(()) => {
+                info!(
+                    slot = %bid.to_ref().message().slot(),
+                    builder_index,
+                    value,
+                    %local_value,
+                    "External builder bid beats boosted local payload"
+                );
+                None
+            }
+            Err(reason) => {
+                info!(
+                    slot = %bid.to_ref().message().slot(),
+                    builder_index,
+                    value,
+                    %reason,
+                    "Rejected external builder bid, self-building execution payload"
+                );
+                metrics::inc_counter_vec(
+                    &metrics::BLOCK_PRODUCTION_BID_REJECTIONS_TOTAL,
+                    &[reason.as_str()],
+                );
+                *selected_external_bid = None;
+                Some(block_contents_type)
+            }
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn produce_partial_beacon_block(
         self: &Arc<Self>,
@@ -7249,17 +7623,21 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         // builder will provide the execution payload via a separate envelope.
         // Filter by parent_root to avoid selecting stale bids after a re-org.
         let selected_external_bid = if state.fork_name_unchecked().gloas_enabled() {
-            self.get_best_execution_bid(produce_at_slot, parent_root)
+            self.get_best_execution_bid_for_state(produce_at_slot, parent_root, &state)
         } else {
             None
         };
 
         // If required, start the process of loading an execution payload from the EL early. This
         // allows it to run concurrently with things like attestation packing.
-        // For Gloas with external bids, skip the EL fetch — the builder handles the payload.
+        // For Gloas with external bids, skip the EL fetch — the builder handles the payload —
+        // unless a self-build boost is configured, in which case the local payload is needed to
+        // compare against the bid. If the local payload can't be requested the bid is used.
+        let needs_local_payload = selected_external_bid.is_none()
+            || self.config.bid_selection.self_build_boost_percent.is_some();
         let prepare_payload_handle =
-            if state.fork_name_unchecked().bellatrix_enabled() && selected_external_bid.is_none() {
-                let prepare_payload_handle = get_execution_payload(
+            if state.fork_name_unchecked().bellatrix_enabled() && needs_local_payload {
+                match get_execution_payload(
                     self.clone(),
                     &state,
                     parent_root,
@@ -7267,8 +7645,18 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
                     builder_params,
                     builder_boost_factor,
                     block_production_version,
-                )?;
-                Some(prepare_payload_handle)
+                ) {
+                    Ok(prepare_payload_handle) => Some(prepare_payload_handle),
+                    Err(error) if selected_external_bid.is_some() => {
+                        warn!(
+                            ?error,
+                            slot = %produce_at_slot,
+                            "Failed to request local payload, using external builder bid"
+                        );
+                        None
+                    }
+                    Err(error) => return Err(error),
+                }
             } else {
                 None
             };
@@ -8492,14 +8880,12 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         // `execution_engine_forkchoice_lock` apart from the one here.
         let forkchoice_lock = execution_layer.execution_engine_forkchoice_lock().await;
 
-        let (head_block_root, head_hash, justified_hash, finalized_hash) =
+        let (head_block_root, head_hash, safe_hash, finalized_hash) =
             if let Some(head_hash) = params.head_hash {
                 (
                     params.head_root,
                     head_hash,
-                    params
-                        .justified_hash
-                        .unwrap_or_else(ExecutionBlockHash::zero),
+                    params.safe_hash().unwrap_or_else(ExecutionBlockHash::zero),
                     params
                         .finalized_hash
                         .unwrap_or_else(ExecutionBlockHash::zero),
@@ -8531,9 +8917,7 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
                             (
                                 params.head_root,
                                 terminal_pow_block_hash,
-                                params
-                                    .justified_hash
-                                    .unwrap_or_else(ExecutionBlockHash::zero),
+                                params.safe_hash().unwrap_or_else(ExecutionBlockHash::zero),
                                 params
                                     .finalized_hash
                                     .unwrap_or_else(ExecutionBlockHash::zero),
@@ -8554,7 +8938,7 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         let forkchoice_updated_response = execution_layer
             .notify_forkchoice_updated(
                 head_hash,
-                justified_hash,
+                safe_hash,
                 finalized_hash,
                 current_slot,
                 head_block_root,
@@ -8857,6 +9241,9 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             // Run fork choice and signal to any waiting task that it has completed.
             self.recompute_head_at_current_slot().await;
 
+            self.register_payload_attestation_outcomes(slot.saturating_sub(1_u64));
+            self.register_builder_payload_outcomes(slot.saturating_sub(1_u64));
+
             // Send the notification regardless of fork choice success, this is a "best effort"
             // notification and we don't want block production to hit the timeout in case of error.
             // Use a blocking task to avoid blocking the core executor whilst waiting for locks
@@ -8880,6 +9267,67 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         }
     }
 
+    /// Gloas ePBS: Record the PTC's verdict on the payload of each imported block from `slot` which
+    /// committed to an external builder's bid.
+    ///
+    /// Should be called once `slot` has ended, when gossip payload attestations for it are no
+    /// longer accepted.
+    fn register_builder_payload_outcomes(&self, slot: Slot) {
+        let block_roots = self.builder_reliability.lock().block_roots_at_slot(slot);
+        if block_roots.is_empty() {
+            return;
+        }
+
+        let outcomes = {
+            let fork_choice = self.canonical_head.fork_choice_read_lock();
+            block_roots
+                .into_iter()
+                .filter_map(|block_root| {
+                    fork_choice
+                        .get_payload_attestation_outcome(&block_root, &self.spec)
+                        .map(|outcome| (block_root, outcome))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        {
+            let mut builder_reliability = self.builder_reliability.lock();
+            for (block_root, outcome) in outcomes {
+                builder_reliability.register_payload_outcome::<T::EthSpec>(
+                    block_root,
+                    outcome.payload_present,
+                    outcome.payload_present_votes,
+                );
+            }
+        }
+
+        self.update_builder_reliability_canonical_chain(
+            self.canonical_head.cached_head().head_block_root(),
+        );
+    }
+
+    /// Gloas ePBS: Count the builder reliability outcomes of the blocks on the chain of
+    /// `head_block_root` only, moving the counters of blocks which a re-org moved on or off the
+    /// canonical chain.
+    pub(crate) fn update_builder_reliability_canonical_chain(&self, head_block_root: Hash256) {
+        let block_roots = self.builder_reliability.lock().block_roots();
+        if block_roots.is_empty() {
+            return;
+        }
+
+        let canonical = {
+            let fork_choice = self.canonical_head.fork_choice_read_lock();
+            block_roots
+                .into_iter()
+                .filter(|block_root| fork_choice.is_descendant(*block_root, head_block_root))
+                .collect::<HashSet<_>>()
+        };
+
+        self.builder_reliability
+            .lock()
+            .update_canonical_chain::<T::EthSpec>(|block_root| canonical.contains(block_root));
+    }
+
     /// This function provides safe and efficient multi-threaded access to the beacon proposer cache.
     ///
     /// The arguments are:
@@ -9610,12 +10058,37 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
     }
 }
 
+/// Aggregate the payload attestations with the same data into a single attestation with the
+/// union of their aggregation bits and the aggregate of their signatures.
+fn aggregate_payload_attestations<'a, E: EthSpec>(
+    attestations: impl IntoIterator<Item = &'a PayloadAttestation<E>>,
+) -> Vec<PayloadAttestation<E>> {
+    let mut aggregated: HashMap<PayloadAttestationData, PayloadAttestation<E>> = HashMap::new();
+
+    for att in attestations {
+        match aggregated.entry(att.data) {
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                entry.insert(att.clone());
+            }
+            std::collections::hash_map::Entry::Occupied(mut entry) => {
+                let existing = entry.get_mut();
+                existing.aggregation_bits = existing.aggregation_bits.union(&att.aggregation_bits);
+                existing.signature.add_assign_aggregate(&att.signature);
+            }
+        }
+    }
+
+    aggregated.into_values().collect()
+}
+
 impl<T: BeaconChainTypes> Drop for BeaconChain<T> {
     fn drop(&mut self) {
         let drop = || -> Result<(), Error> {
             self.persist_fork_choice()?;
             self.persist_op_pool()?;
-            self.persist_custody_context()
+            self.persist_custody_context()?;
+            self.persist_side_objects(None)?;
+            self.persist_builder_reliability()
         };
 
         if let Err(e) = drop() {
@@ -0,0 +1,360 @@
+//! Provides a `BidSelectionPolicy` which decides whether an external `SignedExecutionPayloadBid`
+//! from the `ExecutionBidPool` may be used during Gloas block production.
+//!
+//! During block production the proposer walks the candidate bids from highest to lowest value and
+//! selects the first one that the policy accepts. If no bid is accepted the proposer self-builds.
+//! Every rejection is described by a `BidRejection` so that the decision can be logged and audited.
+
+use serde::{Deserialize, Serialize};
+use state_processing::per_block_processing::gloas::can_builder_cover_bid;
+use std::fmt;
+use std::str::FromStr;
+use types::{BeaconState, BuilderIndex, ChainSpec, EthSpec, PublicKeyBytes, Uint256};
+
+/// Number of Wei in one Gwei, used to compare bid values (Gwei) with local payload values (Wei).
+const WEI_PER_GWEI: u64 = 1_000_000_000;
+
+/// Identifies a builder either by its index in `BeaconState::builders` or by its pubkey.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BuilderId {
+    Index(BuilderIndex),
+    Pubkey(PublicKeyBytes),
+}
+
+impl BuilderId {
+    fn matches(&self, builder_index: BuilderIndex, pubkey: Option<&PublicKeyBytes>) -> bool {
+        match self {
+            BuilderId::Index(index) => *index == builder_index,
+            BuilderId::Pubkey(expected) => pubkey == Some(expected),
+        }
+    }
+}
+
+impl FromStr for BuilderId {
+    type Err = String;
+
+    /// Parses either a `0x`-prefixed builder pubkey or a decimal builder index.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.starts_with("0x") {
+            PublicKeyBytes::from_str(s)
+                .map(BuilderId::Pubkey)
+                .map_err(|e| format!("Invalid builder pubkey {s}: {e:?}"))
+        } else {
+            s.parse::<BuilderIndex>()
+                .map(BuilderId::Index)
+                .map_err(|e| format!("Invalid builder index {s}: {e:?}"))
+        }
+    }
+}
+
+impl fmt::Display for BuilderId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuilderId::Index(index) => write!(f, "{index}"),
+            BuilderId::Pubkey(pubkey) => write!(f, "{pubkey}"),
+        }
+    }
+}
+
+/// The reason an external bid was not selected for block production.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BidRejection {
+    /// The bid value is below the configured minimum.
+    BelowMinimumValue { value: u64, min_bid_value: u64 },
+    /// An allow-list is configured and the builder is not on it.
+    NotAllowlisted,
+    /// The builder is on the deny-list.
+    Denylisted,
+    /// The builder is not present in `BeaconState::builders`.
+    UnknownBuilder,
+    /// The builder's balance in the proposal state does not cover the bid.
+    InsufficientBuilderBalance { balance: u64 },
+    /// The builder's balance could not be checked against the state.
+    BalanceCheckFailed,
+    /// Heze: the bid's `inclusion_list_bits` do not cover all locally observed inclusion lists.
+    InclusionListBitsNotInclusive,
+    /// The locally built payload is worth more than the bid once the self-build boost is applied.
+    OutbidBySelfBuild {
+        local_value: Uint256,
+        boosted_local_value: Uint256,
+    },
+}
+
+impl BidRejection {
+    /// A short, static label suitable for use as a metric label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BidRejection::BelowMinimumValue { .. } => "below_minimum_value",
+            BidRejection::NotAllowlisted => "not_allowlisted",
+            BidRejection::Denylisted => "denylisted",
+            BidRejection::UnknownBuilder => "unknown_builder",
+            BidRejection::InsufficientBuilderBalance { .. } => "insufficient_builder_balance",
+            BidRejection::BalanceCheckFailed => "balance_check_failed",
+            BidRejection::InclusionListBitsNotInclusive => "inclusion_list_bits_not_inclusive",
+            BidRejection::OutbidBySelfBuild { .. } => "outbid_by_self_build",
+        }
+    }
+}
+
+impl fmt::Display for BidRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BidRejection::BelowMinimumValue {
+                value,
+                min_bid_value,
+            } => write!(
+                f,
+                "bid value {value} is below the minimum of {min_bid_value}"
+            ),
+            BidRejection::NotAllowlisted => write!(f, "builder is not on the allow-list"),
+            BidRejection::Denylisted => write!(f, "builder is on the deny-list"),
+            BidRejection::UnknownBuilder => write!(f, "builder is unknown to the proposal state"),
+            BidRejection::InsufficientBuilderBalance { balance } => {
+                write!(f, "builder balance {balance} does not cover the bid")
+            }
+            BidRejection::BalanceCheckFailed => write!(f, "unable to check builder balance"),
+            BidRejection::InclusionListBitsNotInclusive => {
+                write!(f, "inclusion_list_bits are not inclusive")
+            }
+            BidRejection::OutbidBySelfBuild {
+                local_value,
+                boosted_local_value,
+            } => write!(
+                f,
+                "local payload value {local_value} (boosted to {boosted_local_value}) beats the bid"
+            ),
+        }
+    }
+}
+
+/// Operator-configurable rules for choosing an external builder bid over self-building.
+///
+/// The default policy accepts any gossip-verified bid, which matches the behaviour of always
+/// selecting the highest-value bid.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct BidSelectionPolicy {
+    /// Bids with a `value` (in Gwei) below this threshold are ignored and the proposer self-builds.
+    pub min_bid_value: u64,
+    /// If non-empty, only bids from these builders are considered.
+    pub builder_allowlist: Vec<BuilderId>,
+    /// Bids from these builders are never considered. Takes precedence over the allow-list.
+    pub builder_denylist: Vec<BuilderId>,
+    /// Require the builder's balance in the proposal state to cover the bid.
+    pub require_builder_balance: bool,
+    /// If set, the local payload is always built and the external bid is only used if its value
+    /// exceeds the local payload value boosted by this percentage. The bid is used if the local
+    /// payload can't be built.
+    pub self_build_boost_percent: Option<u64>,
+}
+
+impl BidSelectionPolicy {
+    /// Returns `true` if checking a bid requires looking up the builder in the state.
+    fn needs_builder_record(&self) -> bool {
+        self.require_builder_balance
+            || self
+                .builder_allowlist
+                .iter()
+                .chain(self.builder_denylist.iter())
+                .any(|id| matches!(id, BuilderId::Pubkey(_)))
+    }
+
+    /// Checks a bid from `builder_index` with `value` (in Gwei) against the policy, using `state`
+    /// to resolve builder pubkeys and balances.
+    pub fn check_bid<E: EthSpec>(
+        &self,
+        builder_index: BuilderIndex,
+        value: u64,
+        state: &BeaconState<E>,
+        spec: &ChainSpec,
+    ) -> Result<(), BidRejection> {
+        let builder = if self.needs_builder_record() {
+            Some(
+                state
+                    .builders()
+                    .ok()
+                    .and_then(|builders| builders.get(builder_index as usize))
+                    .ok_or(BidRejection::UnknownBuilder)?,
+            )
+        } else {
+            None
+        };
+
+        self.check_value_and_builder(builder_index, value, builder.map(|b| &b.pubkey))?;
+
+        if let Some(builder) = builder
+            && self.require_builder_balance
+        {
+            let covered = can_builder_cover_bid(state, builder_index, value, spec)
+                .map_err(|_| BidRejection::BalanceCheckFailed)?;
+            if !covered {
+                return Err(BidRejection::InsufficientBuilderBalance {
+                    balance: builder.balance,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the minimum value and the allow/deny lists.
+    fn check_value_and_builder(
+        &self,
+        builder_index: BuilderIndex,
+        value: u64,
+        pubkey: Option<&PublicKeyBytes>,
+    ) -> Result<(), BidRejection> {
+        if value < self.min_bid_value {
+            return Err(BidRejection::BelowMinimumValue {
+                value,
+                min_bid_value: self.min_bid_value,
+            });
+        }
+
+        if self
+            .builder_denylist
+            .iter()
+            .any(|id| id.matches(builder_index, pubkey))
+        {
+            return Err(BidRejection::Denylisted);
+        }
+
+        if !self.builder_allowlist.is_empty()
+            && !self
+                .builder_allowlist
+                .iter()
+                .any(|id| id.matches(builder_index, pubkey))
+        {
+            return Err(BidRejection::NotAllowlisted);
+        }
+
+        Ok(())
+    }
+
+    /// Compares a locally built payload worth `local_value` (in Wei) with an external bid worth
+    /// `bid_value` (in Gwei).
+    ///
+    /// Returns an error if the self-build boost is enabled and the boosted local value is greater
+    /// than or equal to the bid value.
+    pub fn check_against_local_payload(
+        &self,
+        bid_value: u64,
+        local_value: Uint256,
+    ) -> Result<(), BidRejection> {
+        let Some(boost_percent) = self.self_build_boost_percent else {
+            return Ok(());
+        };
+
+        let boosted_local_value = local_value
+            .saturating_mul(Uint256::from(100u64.saturating_add(boost_percent)))
+            / Uint256::from(100u64);
+        let bid_value_wei = Uint256::from(bid_value).saturating_mul(Uint256::from(WEI_PER_GWEI));
+
+        if boosted_local_value >= bid_value_wei {
+            Err(BidRejection::OutbidBySelfBuild {
+                local_value,
+                boosted_local_value,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pubkey(byte: u8) -> PublicKeyBytes {
+        let mut bytes = [0u8; 48];
+        bytes[0] = byte;
+        PublicKeyBytes::deserialize(&bytes).unwrap()
+    }
+
+    #[test]
+    fn default_policy_accepts_everything() {
+        let policy = BidSelectionPolicy::default();
+        assert_eq!(policy.check_value_and_builder(7, 0, None), Ok(()));
+        assert_eq!(
+            policy.check_against_local_payload(0, Uint256::from(u64::MAX)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_bid_below_minimum_value() {
+        let policy = BidSelectionPolicy {
+            min_bid_value: 100,
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.check_value_and_builder(1, 99, None),
+            Err(BidRejection::BelowMinimumValue {
+                value: 99,
+                min_bid_value: 100
+            })
+        );
+        assert_eq!(policy.check_value_and_builder(1, 100, None), Ok(()));
+    }
+
+    #[test]
+    fn allowlist_by_index_and_pubkey() {
+        let policy = BidSelectionPolicy {
+            builder_allowlist: vec![BuilderId::Index(3), BuilderId::Pubkey(pubkey(1))],
+            ..Default::default()
+        };
+        assert_eq!(policy.check_value_and_builder(3, 1, None), Ok(()));
+        assert_eq!(
+            policy.check_value_and_builder(4, 1, Some(&pubkey(1))),
+            Ok(())
+        );
+        assert_eq!(
+            policy.check_value_and_builder(4, 1, Some(&pubkey(2))),
+            Err(BidRejection::NotAllowlisted)
+        );
+    }
+
+    #[test]
+    fn denylist_takes_precedence_over_allowlist() {
+        let policy = BidSelectionPolicy {
+            builder_allowlist: vec![BuilderId::Index(3)],
+            builder_denylist: vec![BuilderId::Pubkey(pubkey(9))],
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.check_value_and_builder(3, 1, Some(&pubkey(9))),
+            Err(BidRejection::Denylisted)
+        );
+    }
+
+    #[test]
+    fn self_build_boost_compares_in_wei() {
+        let policy = BidSelectionPolicy {
+            self_build_boost_percent: Some(10),
+            ..Default::default()
+        };
+        // Local payload worth 1 ETH boosted by 10% is 1.1 ETH.
+        let local_value = Uint256::from(1_000_000_000_000_000_000u64);
+        // A bid of 1.2 ETH (in Gwei) wins.
+        assert_eq!(
+            policy.check_against_local_payload(1_200_000_000, local_value),
+            Ok(())
+        );
+        // A bid of 1.05 ETH loses to the boosted local payload.
+        assert!(matches!(
+            policy.check_against_local_payload(1_050_000_000, local_value),
+            Err(BidRejection::OutbidBySelfBuild { .. })
+        ));
+    }
+
+    #[test]
+    fn builder_id_from_str() {
+        assert_eq!(BuilderId::from_str("42"), Ok(BuilderId::Index(42)));
+        let pk = pubkey(1);
+        assert_eq!(
+            BuilderId::from_str(&format!("{pk}")),
+            Ok(BuilderId::Pubkey(pk))
+        );
+        assert!(BuilderId::from_str("not-a-builder").is_err());
+    }
+}
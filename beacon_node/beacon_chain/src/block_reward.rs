@@ -91,11 +91,17 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             })
             .collect();
 
-        // Add the attestation data if desired.
-        let attestations = if include_attestations {
-            block.body().attestations().map(|a| *a.data()).collect()
+        // Add the attestation data and attesting indices if desired.
+        let (attestations, attesting_indices) = if include_attestations {
+            (
+                block.body().attestations().map(|a| *a.data()).collect(),
+                split_attestations
+                    .iter()
+                    .map(|att| att.indexed.attesting_indices.clone())
+                    .collect(),
+            )
         } else {
-            vec![]
+            (vec![], vec![])
         };
 
         let attestation_rewards = AttestationRewards {
@@ -104,6 +110,7 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             curr_epoch_total,
             per_attestation_rewards,
             attestations,
+            attesting_indices,
         };
 
         // Sync committee rewards.
@@ -37,6 +37,7 @@ use state_processing::{AllCaches, per_slot_processing};
 use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 use store::{Error as StoreError, HotColdDB, ItemStore, KeyValueStoreOp};
 use task_executor::{ShutdownReason, TaskExecutor};
@@ -1046,6 +1047,9 @@ where
         };
         debug!(?custody_context, "Loaded persisted custody context");
 
+        // Cleared by `BeaconChain::spawn_era_import` once the import has finished.
+        let era_import_in_progress = AtomicBool::new(self.chain_config.era_dir.is_some());
+
         let beacon_chain = BeaconChain {
             spec: self.spec.clone(),
             config: self.chain_config,
@@ -1054,6 +1058,7 @@ where
                 .task_executor
                 .ok_or("Cannot build without task executor")?,
             store_migrator,
+            era_import_in_progress,
             slot_clock: slot_clock.clone(),
             op_pool: self.op_pool.ok_or("Cannot build without op pool")?,
             // TODO(#36): allow for persisting and loading these pools from disk.
@@ -1076,6 +1081,8 @@ where
             observed_bls_to_execution_changes: <_>::default(),
             observed_execution_bids: <_>::default(),
             execution_bid_pool: <_>::default(),
+            builder_reliability: <_>::default(),
+            late_payload_attestation_outcomes: <_>::default(),
             observed_payload_attestations: <_>::default(),
             observed_payload_envelopes: <_>::default(),
             payload_envelope_timings: <_>::default(),
@@ -0,0 +1,549 @@
+//! Per-builder reliability accounting for Gloas ePBS.
+//!
+//! Records how each in-protocol builder behaves over time: bids seen on gossip, bids won by
+//! imported blocks, payloads revealed or withheld according to the PTC, and the fate of the
+//! builder pending payments created by won bids.
+//!
+//! Only blocks on the canonical chain are counted. The outcomes of recent blocks are kept so that
+//! their counters can be moved between chains when the head is re-orged.
+//!
+//! Counters are kept in memory per epoch and merged into `DBColumn::BuilderReliability` once the
+//! epoch can no longer change, or when the beacon chain is dropped. Bids won by blocks imported
+//! before a restart are not carried over, so their payments are not settled in the counters.
+
+use eth2::vibehouse::BuilderReliability;
+use ssz::{Decode, Encode};
+use std::collections::{BTreeMap, HashMap};
+use store::{DBColumn, Error as StoreError, HotColdDB, ItemStore, KeyValueStore};
+use types::{
+    BeaconState, BeaconStateError, BuilderIndex, ChainSpec, Epoch, EthSpec, Hash256, Slot,
+};
+
+/// The PTC's verdict on the payload of a block, once its slot has ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PayloadOutcome {
+    present: bool,
+    votes: u64,
+}
+
+/// The fate of the builder pending payment created by a bid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PaymentOutcome {
+    Settled,
+    Missed,
+    Slashed,
+}
+
+/// A bid committed to by an imported block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BlockBid {
+    slot: Slot,
+    builder_index: BuilderIndex,
+    value: u64,
+    payload_outcome: Option<PayloadOutcome>,
+    payment_outcome: Option<PaymentOutcome>,
+    /// Whether the counters of this block are currently included in the epoch counters.
+    canonical: bool,
+}
+
+impl BlockBid {
+    /// The counters contributed by this block while it is on the canonical chain.
+    fn counters(&self) -> BuilderReliability {
+        let mut record = BuilderReliability {
+            bids_won: 1,
+            ..BuilderReliability::new(self.builder_index)
+        };
+        if let Some(outcome) = self.payload_outcome {
+            if outcome.present {
+                record.payloads_revealed = 1;
+            } else {
+                record.payloads_withheld = 1;
+            }
+            record.ptc_votes = outcome.votes;
+        }
+        match self.payment_outcome {
+            Some(PaymentOutcome::Settled) => record.payments_settled = 1,
+            Some(PaymentOutcome::Missed) => record.payments_missed = 1,
+            Some(PaymentOutcome::Slashed) => record.payments_slashed = 1,
+            None => {}
+        }
+        record
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BuilderReliabilityTracker {
+    /// Counters for epochs which have not yet been written to disk.
+    epochs: BTreeMap<Epoch, HashMap<BuilderIndex, BuilderReliability>>,
+    /// Bids committed to by imported blocks from epochs which have not yet been written to disk.
+    block_bids: HashMap<Hash256, BlockBid>,
+}
+
+impl BuilderReliabilityTracker {
+    fn record<E: EthSpec>(
+        &mut self,
+        slot: Slot,
+        builder_index: BuilderIndex,
+        update: impl FnOnce(&mut BuilderReliability),
+    ) {
+        update(
+            self.epochs
+                .entry(slot.epoch(E::slots_per_epoch()))
+                .or_default()
+                .entry(builder_index)
+                .or_insert_with(|| BuilderReliability::new(builder_index)),
+        )
+    }
+
+    /// Apply `update` to the bid of `block_root`, keeping the epoch counters in step if the block
+    /// is canonical.
+    fn update_block_bid<E: EthSpec>(
+        &mut self,
+        block_root: Hash256,
+        update: impl FnOnce(&mut BlockBid),
+    ) {
+        let Some(bid) = self.block_bids.get_mut(&block_root) else {
+            return;
+        };
+        let before = bid.counters();
+        update(bid);
+        let (bid, after) = (*bid, bid.counters());
+        if bid.canonical && before != after {
+            self.record::<E>(bid.slot, bid.builder_index, |record| {
+                subtract_record(record, &before);
+                record.merge(&after);
+            });
+        }
+    }
+
+    /// Register a bid from `builder_index` for `slot` which passed gossip verification.
+    pub fn register_bid_seen<E: EthSpec>(&mut self, slot: Slot, builder_index: BuilderIndex) {
+        self.record::<E>(slot, builder_index, |record| record.bids_seen += 1);
+    }
+
+    /// Register the bid committed to by the imported block `block_root`.
+    ///
+    /// Self-built blocks are ignored.
+    pub fn register_block_bid(
+        &mut self,
+        block_root: Hash256,
+        slot: Slot,
+        builder_index: BuilderIndex,
+        value: u64,
+        spec: &ChainSpec,
+    ) {
+        if builder_index != spec.builder_index_self_build {
+            self.block_bids.insert(
+                block_root,
+                BlockBid {
+                    slot,
+                    builder_index,
+                    value,
+                    payload_outcome: None,
+                    payment_outcome: None,
+                    canonical: false,
+                },
+            );
+        }
+    }
+
+    /// Returns the roots of the imported blocks from `slot` which committed to an external bid.
+    pub fn block_roots_at_slot(&self, slot: Slot) -> Vec<Hash256> {
+        self.block_bids
+            .iter()
+            .filter(|(_, bid)| bid.slot == slot)
+            .map(|(root, _)| *root)
+            .collect()
+    }
+
+    /// Returns the roots of all imported blocks which committed to an external bid.
+    pub fn block_roots(&self) -> Vec<Hash256> {
+        self.block_bids.keys().copied().collect()
+    }
+
+    /// Register the PTC's verdict on the payload of `block_root`, once its slot has ended.
+    ///
+    /// The bid counts as won while the block is on the canonical chain whether or not a verdict
+    /// was ever registered, see `update_canonical_chain`.
+    pub fn register_payload_outcome<E: EthSpec>(
+        &mut self,
+        block_root: Hash256,
+        payload_present: bool,
+        payload_present_votes: u64,
+    ) {
+        self.update_block_bid::<E>(block_root, |bid| {
+            bid.payload_outcome = Some(PayloadOutcome {
+                present: payload_present,
+                votes: payload_present_votes,
+            });
+        });
+    }
+
+    /// Include the counters of the blocks for which `is_canonical` returns `true` in the epoch
+    /// counters, and remove those of the blocks which are no longer canonical.
+    pub fn update_canonical_chain<E: EthSpec>(&mut self, is_canonical: impl Fn(&Hash256) -> bool) {
+        let changed = self
+            .block_bids
+            .iter_mut()
+            .filter_map(|(block_root, bid)| {
+                let canonical = is_canonical(block_root);
+                (bid.canonical != canonical).then(|| {
+                    bid.canonical = canonical;
+                    *bid
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for bid in changed {
+            let counters = bid.counters();
+            self.record::<E>(bid.slot, bid.builder_index, |record| {
+                if bid.canonical {
+                    record.merge(&counters);
+                } else {
+                    subtract_record(record, &counters);
+                }
+            });
+        }
+    }
+
+    /// Register the outcome of the builder pending payments which `state` is about to process at
+    /// the end of its current epoch, i.e. those for bids from the previous epoch.
+    ///
+    /// The outcomes of payments evaluated on another chain before a re-org are replaced.
+    ///
+    /// If `include_current_epoch` is set, the payments for the current epoch are also evaluated.
+    /// This is correct when the chain skips the whole of the next epoch, as no more weight can be
+    /// added to them before they are processed.
+    ///
+    /// `head_block_root` must be the root of the latest block applied to `state`.
+    pub fn process_builder_pending_payments<E: EthSpec>(
+        &mut self,
+        state: &BeaconState<E>,
+        head_block_root: Hash256,
+        include_current_epoch: bool,
+        spec: &ChainSpec,
+    ) -> Result<(), BeaconStateError> {
+        let Ok(payments) = state.builder_pending_payments() else {
+            return Ok(());
+        };
+
+        // Spec: get_builder_payment_quorum_threshold
+        let quorum = (state.get_total_active_balance()? / E::slots_per_epoch())
+            .saturating_mul(spec.builder_payment_threshold_numerator)
+            / spec.builder_payment_threshold_denominator;
+
+        let current_epoch = state.current_epoch();
+        let mut epochs = vec![];
+        if current_epoch > 0 {
+            epochs.push((0, current_epoch - 1));
+        }
+        if include_current_epoch {
+            epochs.push((E::slots_per_epoch() as usize, current_epoch));
+        }
+
+        for (offset, epoch) in epochs {
+            for (i, slot) in epoch.slot_iter(E::slots_per_epoch()).enumerate() {
+                let block_root = if slot == state.slot() {
+                    head_block_root
+                } else if slot < state.slot() {
+                    *state.get_block_root(slot)?
+                } else {
+                    break;
+                };
+                if !self
+                    .block_bids
+                    .get(&block_root)
+                    // Zero-value bids create no pending payment.
+                    .is_some_and(|bid| bid.slot == slot && bid.value > 0)
+                {
+                    continue;
+                }
+                let Some(payment) = payments.get(offset + i) else {
+                    continue;
+                };
+
+                // A proposer slashing removes the payment for the proposal slot.
+                let outcome = if payment.withdrawal.amount == 0 {
+                    PaymentOutcome::Slashed
+                } else if payment.weight >= quorum {
+                    PaymentOutcome::Settled
+                } else {
+                    PaymentOutcome::Missed
+                };
+                self.update_block_bid::<E>(block_root, |bid| bid.payment_outcome = Some(outcome));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Merge the counters for all epochs up to and including `up_to_epoch` into the store and
+    /// drop them from memory. If `up_to_epoch` is `None`, all epochs are written.
+    pub fn persist<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>>(
+        &mut self,
+        store: &HotColdDB<E, Hot, Cold>,
+        up_to_epoch: Option<Epoch>,
+    ) -> Result<(), StoreError> {
+        let epochs = self
+            .epochs
+            .keys()
+            .copied()
+            .filter(|epoch| up_to_epoch.is_none_or(|up_to| *epoch <= up_to))
+            .collect::<Vec<_>>();
+
+        for epoch in epochs {
+            let mut records = load_epoch(store, epoch)?;
+            if let Some(in_memory) = self.epochs.get(&epoch) {
+                merge_records(&mut records, in_memory.values());
+            }
+            store.hot_db.put_bytes(
+                DBColumn::BuilderReliability,
+                &epoch.as_u64().to_be_bytes(),
+                &records.as_ssz_bytes(),
+            )?;
+            self.epochs.remove(&epoch);
+        }
+
+        // The blocks of persisted epochs can no longer move on or off the canonical chain.
+        self.block_bids.retain(|_, bid| {
+            up_to_epoch.is_some_and(|up_to| bid.slot.epoch(E::slots_per_epoch()) > up_to)
+        });
+
+        Ok(())
+    }
+
+    /// Returns the counters for each builder summed over `start_epoch..=end_epoch`, sorted by
+    /// builder index.
+    pub fn get_builder_reliability<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>>(
+        &self,
+        store: &HotColdDB<E, Hot, Cold>,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<Vec<BuilderReliability>, StoreError> {
+        let mut totals = vec![];
+
+        for res in store.hot_db.iter_column_from::<Vec<u8>>(
+            DBColumn::BuilderReliability,
+            &start_epoch.as_u64().to_be_bytes(),
+        ) {
+            let (key, bytes) = res?;
+            let epoch = Epoch::new(u64::from_be_bytes(key.as_slice().try_into().map_err(
+                |_| StoreError::InvalidKey(format!("builder reliability key len {}", key.len())),
+            )?));
+            if epoch > end_epoch {
+                break;
+            }
+            let records = Vec::<BuilderReliability>::from_ssz_bytes(&bytes)?;
+            merge_records(&mut totals, &records);
+        }
+
+        for (_, in_memory) in self.epochs.range(start_epoch..=end_epoch) {
+            merge_records(&mut totals, in_memory.values());
+        }
+
+        Ok(totals)
+    }
+}
+
+fn load_epoch<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>>(
+    store: &HotColdDB<E, Hot, Cold>,
+    epoch: Epoch,
+) -> Result<Vec<BuilderReliability>, StoreError> {
+    store
+        .hot_db
+        .get_bytes(DBColumn::BuilderReliability, &epoch.as_u64().to_be_bytes())?
+        .map(|bytes| Vec::<BuilderReliability>::from_ssz_bytes(&bytes))
+        .transpose()
+        .map(Option::unwrap_or_default)
+        .map_err(Into::into)
+}
+
+/// Subtract the counters of `other` from `record`.
+fn subtract_record(record: &mut BuilderReliability, other: &BuilderReliability) {
+    record.bids_seen = record.bids_seen.saturating_sub(other.bids_seen);
+    record.bids_won = record.bids_won.saturating_sub(other.bids_won);
+    record.payloads_revealed = record
+        .payloads_revealed
+        .saturating_sub(other.payloads_revealed);
+    record.payloads_withheld = record
+        .payloads_withheld
+        .saturating_sub(other.payloads_withheld);
+    record.ptc_votes = record.ptc_votes.saturating_sub(other.ptc_votes);
+    record.payments_settled = record
+        .payments_settled
+        .saturating_sub(other.payments_settled);
+    record.payments_missed = record.payments_missed.saturating_sub(other.payments_missed);
+    record.payments_slashed = record
+        .payments_slashed
+        .saturating_sub(other.payments_slashed);
+}
+
+/// Add each of `records` to the entry for the same builder in `totals`, keeping `totals` sorted.
+fn merge_records<'a>(
+    totals: &mut Vec<BuilderReliability>,
+    records: impl IntoIterator<Item = &'a BuilderReliability>,
+) {
+    for record in records {
+        match totals.binary_search_by_key(&record.builder_index, |total| total.builder_index) {
+            Ok(i) => totals[i].merge(record),
+            Err(i) => totals.insert(i, record.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use store::{MemoryStore, StoreConfig};
+    use types::MinimalEthSpec;
+
+    type E = MinimalEthSpec;
+
+    fn store() -> HotColdDB<E, MemoryStore<E>, MemoryStore<E>> {
+        HotColdDB::open_ephemeral(StoreConfig::default(), ChainSpec::minimal().into()).unwrap()
+    }
+
+    #[test]
+    fn payload_outcomes_are_attributed_to_the_winning_builder() {
+        let spec = ChainSpec::minimal();
+        let mut tracker = BuilderReliabilityTracker::default();
+        let revealed = Hash256::repeat_byte(1);
+        let withheld = Hash256::repeat_byte(2);
+        let self_built = Hash256::repeat_byte(3);
+        let orphaned = Hash256::repeat_byte(4);
+
+        tracker.register_block_bid(revealed, Slot::new(9), 4, 100, &spec);
+        tracker.register_block_bid(withheld, Slot::new(10), 4, 100, &spec);
+        tracker.register_block_bid(orphaned, Slot::new(10), 5, 100, &spec);
+        tracker.register_block_bid(
+            self_built,
+            Slot::new(11),
+            spec.builder_index_self_build,
+            0,
+            &spec,
+        );
+        assert_eq!(tracker.block_roots_at_slot(Slot::new(9)), vec![revealed]);
+        assert!(tracker.block_roots_at_slot(Slot::new(11)).is_empty());
+
+        tracker.register_payload_outcome::<E>(revealed, true, 400);
+        tracker.register_payload_outcome::<E>(withheld, false, 20);
+        tracker.register_payload_outcome::<E>(orphaned, true, 300);
+        tracker.register_payload_outcome::<E>(self_built, true, 512);
+        tracker.update_canonical_chain::<E>(|root| *root != orphaned);
+
+        let store = store();
+        let records = tracker
+            .get_builder_reliability(&store, Epoch::new(1), Epoch::new(1))
+            .unwrap();
+        assert_eq!(
+            records,
+            vec![BuilderReliability {
+                bids_won: 2,
+                payloads_revealed: 1,
+                payloads_withheld: 1,
+                ptc_votes: 420,
+                ..BuilderReliability::new(4)
+            }]
+        );
+
+        // A re-org onto the orphaned block moves the bid it won to its builder.
+        tracker.update_canonical_chain::<E>(|root| *root != withheld);
+        let records = tracker
+            .get_builder_reliability(&store, Epoch::new(1), Epoch::new(1))
+            .unwrap();
+        assert_eq!(
+            records,
+            vec![
+                BuilderReliability {
+                    bids_won: 1,
+                    payloads_revealed: 1,
+                    ptc_votes: 400,
+                    ..BuilderReliability::new(4)
+                },
+                BuilderReliability {
+                    bids_won: 1,
+                    payloads_revealed: 1,
+                    ptc_votes: 300,
+                    ..BuilderReliability::new(5)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn canonical_blocks_win_without_payload_outcome() {
+        let spec = ChainSpec::minimal();
+        let mut tracker = BuilderReliabilityTracker::default();
+        let block_root = Hash256::repeat_byte(1);
+        tracker.register_block_bid(block_root, Slot::new(9), 4, 100, &spec);
+        tracker.update_canonical_chain::<E>(|_| true);
+
+        let store = store();
+        let won = BuilderReliability {
+            bids_won: 1,
+            ..BuilderReliability::new(4)
+        };
+        assert_eq!(
+            tracker
+                .get_builder_reliability(&store, Epoch::new(1), Epoch::new(1))
+                .unwrap(),
+            vec![won.clone()]
+        );
+
+        // A late verdict is added to the win that was already counted.
+        tracker.register_payload_outcome::<E>(block_root, false, 10);
+        assert_eq!(
+            tracker
+                .get_builder_reliability(&store, Epoch::new(1), Epoch::new(1))
+                .unwrap(),
+            vec![BuilderReliability {
+                payloads_withheld: 1,
+                ptc_votes: 10,
+                ..won
+            }]
+        );
+    }
+
+    #[test]
+    fn persisted_counters_are_merged_with_memory() {
+        let store = store();
+        let mut tracker = BuilderReliabilityTracker::default();
+        tracker.register_bid_seen::<E>(Slot::new(1), 2);
+        tracker.register_bid_seen::<E>(Slot::new(9), 2);
+        tracker.register_bid_seen::<E>(Slot::new(17), 1);
+
+        // Persist epochs 0 and 1, then record more for epoch 1 as if after a restart.
+        tracker.persist(&store, Some(Epoch::new(1))).unwrap();
+        assert_eq!(tracker.epochs.len(), 1);
+        tracker.register_bid_seen::<E>(Slot::new(10), 2);
+        tracker.persist(&store, Some(Epoch::new(1))).unwrap();
+
+        let records = tracker
+            .get_builder_reliability(&store, Epoch::new(1), Epoch::new(2))
+            .unwrap();
+        assert_eq!(
+            records,
+            vec![
+                BuilderReliability {
+                    bids_seen: 1,
+                    ..BuilderReliability::new(1)
+                },
+                BuilderReliability {
+                    bids_seen: 2,
+                    ..BuilderReliability::new(2)
+                },
+            ]
+        );
+
+        let records = tracker
+            .get_builder_reliability(&store, Epoch::new(0), Epoch::new(0))
+            .unwrap();
+        assert_eq!(
+            records,
+            vec![BuilderReliability {
+                bids_seen: 1,
+                ..BuilderReliability::new(2)
+            }]
+        );
+    }
+}
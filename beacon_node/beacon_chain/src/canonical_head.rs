@@ -41,7 +41,9 @@ use crate::{
     metrics,
     validator_monitor::get_slot_delay_ms,
 };
-use eth2::types::{EventKind, SseChainReorg, SseFinalizedCheckpoint, SseHead, SseLateHead};
+use eth2::types::{
+    EventKind, SseChainReorg, SseFastConfirmation, SseFinalizedCheckpoint, SseHead, SseLateHead,
+};
 use fork_choice::{
     ExecutionStatus, ForkChoiceStore, ForkChoiceView, ForkchoiceUpdateParameters, ProtoBlock,
     ResetPayloadStatuses,
@@ -60,8 +62,8 @@ use task_executor::{JoinHandle, ShutdownReason};
 use tracing::info_span;
 use tracing::{debug, error, info, instrument, warn};
 use types::{
-    BeaconState, BeaconStateError, ChainSpec, Checkpoint, EthSpec, ExecPayload, ExecutionBlockHash,
-    Fork, Hash256, RelativeEpoch, SignedBeaconBlock, Slot,
+    BeaconState, BeaconStateError, ChainSpec, Checkpoint, Epoch, EthSpec, ExecPayload,
+    ExecutionBlockHash, Fork, Hash256, RelativeEpoch, SignedBeaconBlock, Slot,
 };
 use vibehouse_tracing::SPAN_RECOMPUTE_HEAD;
 
@@ -118,6 +120,10 @@ pub struct CachedHead<E: EthSpec> {
     justified_hash: Option<ExecutionBlockHash>,
     /// The `execution_payload.block_hash` of the finalized block. Set to `None` before Bellatrix.
     finalized_hash: Option<ExecutionBlockHash>,
+    /// The block confirmed by the Fast Confirmation Rule. Set to `None` if the rule is disabled.
+    confirmed_root: Option<Hash256>,
+    /// The execution block hash of `confirmed_root`. Set to `None` if the rule is disabled.
+    confirmed_hash: Option<ExecutionBlockHash>,
 }
 
 impl<E: EthSpec> CachedHead<E> {
@@ -236,6 +242,12 @@ impl<E: EthSpec> CachedHead<E> {
         self.justified_checkpoint
     }
 
+    /// Returns the block confirmed by the Fast Confirmation Rule, or `None` if the rule is
+    /// disabled.
+    pub fn confirmed_root(&self) -> Option<Hash256> {
+        self.confirmed_root
+    }
+
     /// Returns the cached values of `ForkChoice::forkchoice_update_parameters`.
     ///
     /// Useful for supplying to the execution layer.
@@ -245,6 +257,7 @@ impl<E: EthSpec> CachedHead<E> {
             head_hash: self.head_hash,
             justified_hash: self.justified_hash,
             finalized_hash: self.finalized_hash,
+            confirmed_hash: self.confirmed_hash,
         }
     }
 }
@@ -297,6 +310,8 @@ impl<T: BeaconChainTypes> CanonicalHead<T> {
             head_hash,
             justified_hash: forkchoice_update_params.justified_hash,
             finalized_hash: forkchoice_update_params.finalized_hash,
+            confirmed_root: fork_choice_view.confirmed_root,
+            confirmed_hash: forkchoice_update_params.confirmed_hash,
         };
 
         Self {
@@ -358,6 +373,8 @@ impl<T: BeaconChainTypes> CanonicalHead<T> {
             head_hash,
             justified_hash: forkchoice_update_params.justified_hash,
             finalized_hash: forkchoice_update_params.finalized_hash,
+            confirmed_root: fork_choice_view.confirmed_root,
+            confirmed_hash: forkchoice_update_params.confirmed_hash,
         };
 
         *fork_choice_write_lock = fork_choice;
@@ -651,6 +668,7 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             head_block_root: old_cached_head.head_block_root(),
             justified_checkpoint: old_cached_head.justified_checkpoint(),
             finalized_checkpoint: old_cached_head.finalized_checkpoint(),
+            confirmed_root: old_cached_head.confirmed_root(),
         };
 
         let mut fork_choice_write_lock = self.canonical_head.fork_choice_write_lock();
@@ -658,6 +676,11 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         // Recompute the current head via the fork choice algorithm.
         fork_choice_write_lock.get_head(current_slot, &self.spec)?;
 
+        // Update the confirmed block, if the Fast Confirmation Rule is enabled.
+        if self.config.fast_confirmation {
+            fork_choice_write_lock.on_fast_confirmation(current_slot, &self.spec);
+        }
+
         // Downgrade the fork choice write-lock to a read lock, without allowing access to any
         // other writers.
         let fork_choice_read_lock = RwLockWriteGuard::downgrade(fork_choice_write_lock);
@@ -701,8 +724,14 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             });
         }
 
-        // Exit early if the head or justified/finalized checkpoints have not changed, there's
-        // nothing to do.
+        // Read the newly confirmed block, if any, for server-sent events.
+        let new_confirmed_proto_block = new_view
+            .confirmed_root
+            .filter(|root| old_view.confirmed_root != Some(*root))
+            .and_then(|root| fork_choice_read_lock.get_block(&root));
+
+        // Exit early if the head, confirmed block or justified/finalized checkpoints have not
+        // changed, there's nothing to do.
         if new_view == old_view {
             debug!(
                 head = ?new_view.head_block_root,
@@ -745,12 +774,14 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
                 head_hash,
                 justified_hash: new_forkchoice_update_parameters.justified_hash,
                 finalized_hash: new_forkchoice_update_parameters.finalized_hash,
+                confirmed_root: new_view.confirmed_root,
+                confirmed_hash: new_forkchoice_update_parameters.confirmed_hash,
             };
 
             let mut cached_head_write_lock = self.canonical_head.cached_head_write_lock();
 
             // Enshrine the new head as the canonical cached head. Whilst the head block hasn't
-            // changed, the FFG checkpoints must have changed.
+            // changed, the FFG checkpoints or the confirmed block must have changed.
             *cached_head_write_lock = new_cached_head;
 
             // Take a clone of the cached head for later use. It is cloned whilst
@@ -805,6 +836,8 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
                 head_hash,
                 justified_hash: new_forkchoice_update_parameters.justified_hash,
                 finalized_hash: new_forkchoice_update_parameters.finalized_hash,
+                confirmed_root: new_view.confirmed_root,
+                confirmed_hash: new_forkchoice_update_parameters.confirmed_hash,
             };
 
             let new_head = {
@@ -856,6 +889,20 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             );
         }
 
+        // Register server-sent-events for a new fast confirmed block.
+        if let Some(confirmed_proto_block) = new_confirmed_proto_block
+            && let Some(event_handler) = self.event_handler.as_ref()
+            && event_handler.has_fast_confirmation_subscribers()
+        {
+            event_handler.register(EventKind::FastConfirmation(SseFastConfirmation {
+                block: confirmed_proto_block.root,
+                slot: confirmed_proto_block.slot,
+                execution_optimistic: confirmed_proto_block
+                    .execution_status
+                    .is_optimistic_or_invalid(),
+            }));
+        }
+
         // The execution layer updates might attempt to take a write-lock on fork choice, so it's
         // important to ensure the fork-choice lock isn't being held.
         //
@@ -962,6 +1009,19 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             self.op_pool.prune_attestations(self.epoch()?);
         }
 
+        // Count the bids won by the blocks on the new canonical chain, as soon as they become
+        // canonical.
+        self.update_builder_reliability_canonical_chain(new_snapshot.beacon_block_root);
+
+        if is_epoch_transition || reorg_distance.is_some() {
+            let reorg_slot = reorg_distance
+                .map(|distance| old_snapshot.beacon_state.slot().saturating_sub(distance));
+            if let Err(e) = self.update_builder_reliability(old_snapshot, new_snapshot, reorg_slot)
+            {
+                warn!(error = ?e, "Failed to update builder reliability");
+            }
+        }
+
         // Register server-sent-events for a new head.
         if let Some(event_handler) = self
             .event_handler
@@ -1011,6 +1071,71 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         Ok(())
     }
 
+    /// Update the builder reliability counters after the head changed epoch or was re-orged.
+    ///
+    /// The builder payments processed by the transition into the head's epoch are evaluated on
+    /// the last state of the previous epoch on the canonical chain. If a re-org changed that
+    /// state, the payments are evaluated again and replace the outcomes from the old chain,
+    /// provided the state is still cached. The counters of epochs which can no longer change are
+    /// then persisted.
+    ///
+    /// `reorg_slot` is the slot of the common ancestor of the old and new heads, if re-orged.
+    fn update_builder_reliability(
+        &self,
+        old_snapshot: &BeaconSnapshot<T::EthSpec>,
+        new_snapshot: &BeaconSnapshot<T::EthSpec>,
+        reorg_slot: Option<Slot>,
+    ) -> Result<(), Error> {
+        let new_epoch = new_snapshot.beacon_state.current_epoch();
+        let epoch_start_slot = new_epoch.start_slot(T::EthSpec::slots_per_epoch());
+        let old_epoch = old_snapshot.beacon_state.current_epoch();
+
+        if let Some(pre_transition_slot) = epoch_start_slot.as_u64().checked_sub(1).map(Slot::new)
+            && (old_epoch < new_epoch || reorg_slot.is_some_and(|slot| slot < epoch_start_slot))
+        {
+            let pre_transition_root = *new_snapshot
+                .beacon_state
+                .get_block_root(pre_transition_slot)?;
+            // This runs while the head is being updated, so the state is never read from disk.
+            // It was either the head until now or, after a re-org, imported recently enough to
+            // still be in the state cache.
+            let cached_state;
+            let pre_transition_state = if pre_transition_root == old_snapshot.beacon_block_root {
+                Some(&old_snapshot.beacon_state)
+            } else {
+                cached_state = self
+                    .store
+                    .get_advanced_hot_state_from_cache(pre_transition_root, pre_transition_slot)
+                    .map(|(_, state)| state);
+                cached_state.as_ref()
+            };
+
+            if let Some(pre_transition_state) = pre_transition_state {
+                self.builder_reliability
+                    .lock()
+                    .process_builder_pending_payments(
+                        pre_transition_state,
+                        pre_transition_root,
+                        new_epoch >= pre_transition_state.current_epoch() + 2,
+                        &self.spec,
+                    )?;
+            } else {
+                debug!(
+                    block_root = ?pre_transition_root,
+                    "Pre-transition state not cached, skipping builder payment outcomes"
+                );
+            }
+        }
+
+        if let Some(settled_epoch) = new_epoch.as_u64().checked_sub(2) {
+            self.builder_reliability
+                .lock()
+                .persist(&self.store, Some(Epoch::new(settled_epoch)))?;
+        }
+
+        Ok(())
+    }
+
     /// Perform updates to caches and other components after the finalized checkpoint has been
     /// changed.
     ///
@@ -1242,6 +1367,13 @@ fn perform_debug_logging<T: BeaconChainTypes>(
             "Fork choice justified"
         );
     }
+    if new_view.confirmed_root != old_view.confirmed_root {
+        debug!(
+            new_root = ?new_view.confirmed_root,
+            old_root = ?old_view.confirmed_root,
+            "Fork choice fast confirmed"
+        );
+    }
     if new_view.finalized_checkpoint != old_view.finalized_checkpoint {
         debug!(
             new_root = ?new_view.finalized_checkpoint.root,
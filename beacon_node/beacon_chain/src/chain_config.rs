@@ -1,6 +1,8 @@
+use crate::bid_selection::BidSelectionPolicy;
 use crate::custody_context::NodeCustodyType;
 pub use proto_array::{DisallowedReOrgOffsets, ReOrgThreshold};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::{collections::HashSet, sync::LazyLock, time::Duration};
 use types::{Checkpoint, Epoch, Hash256};
@@ -87,6 +89,8 @@ pub struct ChainConfig {
     /// If using a weak-subjectivity sync, whether we should download blocks all the way back to
     /// genesis.
     pub genesis_backfill: bool,
+    /// Directory of era files used to fill block history below the anchor at startup.
+    pub era_dir: Option<PathBuf>,
     /// EXPERIMENTAL: backfill blobs and data columns beyond the data availability window.
     pub complete_blob_backfill: bool,
     /// Whether to send payload attributes every slot, regardless of connected proposers.
@@ -130,6 +134,10 @@ pub struct ChainConfig {
     /// Minimum number of execution proofs required before considering a block "proven".
     /// Only relevant when `stateless_validation` is enabled.
     pub stateless_min_proofs_required: usize,
+    /// Policy used to select an external builder bid during Gloas block production.
+    pub bid_selection: BidSelectionPolicy,
+    /// Run the Fast Confirmation Rule and use the confirmed block as the EL's safe block.
+    pub fast_confirmation: bool,
 }
 
 impl Default for ChainConfig {
@@ -158,6 +166,7 @@ impl Default for ChainConfig {
             optimistic_finalized_sync: true,
             shuffling_cache_size: crate::shuffling_cache::DEFAULT_CACHE_SIZE,
             genesis_backfill: false,
+            era_dir: None,
             complete_blob_backfill: false,
             always_prepare_payload: false,
             epochs_per_migration: crate::migrate::DEFAULT_EPOCHS_PER_MIGRATION,
@@ -174,6 +183,8 @@ impl Default for ChainConfig {
             stateless_validation: false,
             generate_execution_proofs: false,
             stateless_min_proofs_required: 1,
+            bid_selection: BidSelectionPolicy::default(),
+            fast_confirmation: false,
         }
     }
 }
@@ -226,6 +237,7 @@ mod tests {
         assert_eq!(config.prepare_payload_lookahead, Duration::from_secs(4));
         assert!(config.optimistic_finalized_sync);
         assert!(!config.genesis_backfill);
+        assert_eq!(config.era_dir, None);
         assert!(!config.always_prepare_payload);
         assert!(config.enable_light_client_server);
         assert_eq!(config.malicious_withhold_count, 0);
@@ -242,6 +254,7 @@ mod tests {
         assert!(!config.stateless_validation);
         assert!(!config.generate_execution_proofs);
         assert_eq!(config.stateless_min_proofs_required, 1);
+        assert_eq!(config.bid_selection, BidSelectionPolicy::default());
     }
 
     #[test]
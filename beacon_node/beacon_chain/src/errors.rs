@@ -58,6 +58,8 @@ pub enum BeaconChainError {
     EpochCacheError(EpochCacheError),
     DBInconsistent(String),
     DBError(store::Error),
+    /// A database snapshot was requested while another one is still being written.
+    DatabaseSnapshotInProgress,
     ForkChoiceError(ForkChoiceError),
     ForkChoiceStoreError(ForkChoiceStoreError),
     MissingBeaconBlock(Hash256),
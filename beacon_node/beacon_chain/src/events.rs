@@ -33,6 +33,10 @@ pub struct ServerSentEventHandler<E: EthSpec> {
     execution_payload_tx: Sender<EventKind<E>>,
     payload_attestation_tx: Sender<EventKind<E>>,
     execution_proof_received_tx: Sender<EventKind<E>>,
+    fast_confirmation_tx: Sender<EventKind<E>>,
+    inclusion_list_tx: Sender<EventKind<E>>,
+    proposer_preferences_tx: Sender<EventKind<E>>,
+    payload_attestation_outcome_tx: Sender<EventKind<E>>,
 }
 
 impl<E: EthSpec> ServerSentEventHandler<E> {
@@ -64,6 +68,10 @@ impl<E: EthSpec> ServerSentEventHandler<E> {
         let (execution_payload_tx, _) = broadcast::channel(capacity);
         let (payload_attestation_tx, _) = broadcast::channel(capacity);
         let (execution_proof_received_tx, _) = broadcast::channel(capacity);
+        let (fast_confirmation_tx, _) = broadcast::channel(capacity);
+        let (inclusion_list_tx, _) = broadcast::channel(capacity);
+        let (proposer_preferences_tx, _) = broadcast::channel(capacity);
+        let (payload_attestation_outcome_tx, _) = broadcast::channel(capacity);
 
         Self {
             attestation_tx,
@@ -89,6 +97,10 @@ impl<E: EthSpec> ServerSentEventHandler<E> {
             execution_payload_tx,
             payload_attestation_tx,
             execution_proof_received_tx,
+            fast_confirmation_tx,
+            inclusion_list_tx,
+            proposer_preferences_tx,
+            payload_attestation_outcome_tx,
         }
     }
 
@@ -193,6 +205,22 @@ impl<E: EthSpec> ServerSentEventHandler<E> {
                 .execution_proof_received_tx
                 .send(kind)
                 .map(|count| log_count("execution proof received", count)),
+            EventKind::FastConfirmation(_) => self
+                .fast_confirmation_tx
+                .send(kind)
+                .map(|count| log_count("fast confirmation", count)),
+            EventKind::InclusionList(_) => self
+                .inclusion_list_tx
+                .send(kind)
+                .map(|count| log_count("inclusion list", count)),
+            EventKind::ProposerPreferences(_) => self
+                .proposer_preferences_tx
+                .send(kind)
+                .map(|count| log_count("proposer preferences", count)),
+            EventKind::PayloadAttestationOutcome(_) => self
+                .payload_attestation_outcome_tx
+                .send(kind)
+                .map(|count| log_count("payload attestation outcome", count)),
         };
         if let Err(SendError(event)) = result {
             trace!(?event, "No receivers registered to listen for event");
@@ -374,6 +402,38 @@ impl<E: EthSpec> ServerSentEventHandler<E> {
     pub fn has_execution_proof_received_subscribers(&self) -> bool {
         self.execution_proof_received_tx.receiver_count() > 0
     }
+
+    pub fn subscribe_fast_confirmation(&self) -> Receiver<EventKind<E>> {
+        self.fast_confirmation_tx.subscribe()
+    }
+
+    pub fn has_fast_confirmation_subscribers(&self) -> bool {
+        self.fast_confirmation_tx.receiver_count() > 0
+    }
+
+    pub fn subscribe_inclusion_list(&self) -> Receiver<EventKind<E>> {
+        self.inclusion_list_tx.subscribe()
+    }
+
+    pub fn has_inclusion_list_subscribers(&self) -> bool {
+        self.inclusion_list_tx.receiver_count() > 0
+    }
+
+    pub fn subscribe_proposer_preferences(&self) -> Receiver<EventKind<E>> {
+        self.proposer_preferences_tx.subscribe()
+    }
+
+    pub fn has_proposer_preferences_subscribers(&self) -> bool {
+        self.proposer_preferences_tx.receiver_count() > 0
+    }
+
+    pub fn subscribe_payload_attestation_outcome(&self) -> Receiver<EventKind<E>> {
+        self.payload_attestation_outcome_tx.subscribe()
+    }
+
+    pub fn has_payload_attestation_outcome_subscribers(&self) -> bool {
+        self.payload_attestation_outcome_tx.receiver_count() > 0
+    }
 }
 
 #[cfg(test)]
@@ -408,6 +468,10 @@ mod tests {
         assert!(!handler.has_execution_payload_subscribers());
         assert!(!handler.has_payload_attestation_subscribers());
         assert!(!handler.has_execution_proof_received_subscribers());
+        assert!(!handler.has_fast_confirmation_subscribers());
+        assert!(!handler.has_inclusion_list_subscribers());
+        assert!(!handler.has_proposer_preferences_subscribers());
+        assert!(!handler.has_payload_attestation_outcome_subscribers());
     }
 
     #[test]
@@ -459,6 +523,34 @@ mod tests {
         assert!(handler.has_execution_proof_received_subscribers());
     }
 
+    #[test]
+    fn subscribe_fast_confirmation_shows_subscriber() {
+        let handler = ServerSentEventHandler::<E>::new(1);
+        let _rx = handler.subscribe_fast_confirmation();
+        assert!(handler.has_fast_confirmation_subscribers());
+    }
+
+    #[test]
+    fn subscribe_inclusion_list_shows_subscriber() {
+        let handler = ServerSentEventHandler::<E>::new(1);
+        let _rx = handler.subscribe_inclusion_list();
+        assert!(handler.has_inclusion_list_subscribers());
+    }
+
+    #[test]
+    fn subscribe_proposer_preferences_shows_subscriber() {
+        let handler = ServerSentEventHandler::<E>::new(1);
+        let _rx = handler.subscribe_proposer_preferences();
+        assert!(handler.has_proposer_preferences_subscribers());
+    }
+
+    #[test]
+    fn subscribe_payload_attestation_outcome_shows_subscriber() {
+        let handler = ServerSentEventHandler::<E>::new(1);
+        let _rx = handler.subscribe_payload_attestation_outcome();
+        assert!(handler.has_payload_attestation_outcome_subscribers());
+    }
+
     #[test]
     fn drop_receiver_removes_subscriber() {
         let handler = ServerSentEventHandler::<E>::new(1);
@@ -70,6 +70,25 @@ impl<E: EthSpec> ExecutionBidPool<E> {
         })
     }
 
+    /// Get all bids for a given slot and parent block root, ordered from highest to lowest value.
+    ///
+    /// Used by block production to apply the `BidSelectionPolicy` to each candidate in turn.
+    pub fn get_bids_by_value(
+        &self,
+        slot: Slot,
+        parent_block_root: Hash256,
+    ) -> Vec<&SignedExecutionPayloadBid<E>> {
+        let mut bids: Vec<_> = self
+            .bids
+            .get(&slot)
+            .into_iter()
+            .flat_map(HashMap::values)
+            .filter(|bid| *bid.to_ref().message().parent_block_root() == parent_block_root)
+            .collect();
+        bids.sort_by_key(|bid| std::cmp::Reverse(*bid.to_ref().message().value()));
+        bids
+    }
+
     /// Remove all bids older than `current_slot - MAX_BID_POOL_SLOTS`.
     pub fn prune(&mut self, current_slot: Slot) {
         let earliest = Slot::new(current_slot.as_u64().saturating_sub(MAX_BID_POOL_SLOTS));
@@ -496,6 +515,26 @@ mod tests {
         assert_eq!(pool.total_bid_count(), 0);
     }
 
+    #[test]
+    fn bids_by_value_sorted_and_filtered_by_parent() {
+        let root_a = Hash256::from_low_u64_be(0xaa);
+        let root_b = Hash256::from_low_u64_be(0xbb);
+
+        let mut pool = ExecutionBidPool::<E>::new();
+        pool.insert(make_bid_with_parent(10, 1, 300, root_a));
+        pool.insert(make_bid_with_parent(10, 2, 900, root_a));
+        pool.insert(make_bid_with_parent(10, 3, 5000, root_b));
+        pool.insert(make_bid_with_parent(10, 4, 100, root_a));
+
+        let values: Vec<u64> = pool
+            .get_bids_by_value(Slot::new(10), root_a)
+            .into_iter()
+            .map(|bid| *bid.to_ref().message().value())
+            .collect();
+        assert_eq!(values, vec![900, 300, 100]);
+        assert!(pool.get_bids_by_value(Slot::new(11), root_a).is_empty());
+    }
+
     /// Verify that inserting a bid for the same (slot, builder_index) after
     /// pruning works correctly — the pool doesn't retain stale metadata.
     #[test]
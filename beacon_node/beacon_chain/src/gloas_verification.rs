@@ -15,8 +15,10 @@
 //! - Equivocation detection via observed message tracking
 //! - Signature verification batching where applicable
 
+use crate::observed_execution_bids::ObservedExecutionBids;
 use crate::{BeaconChain, BeaconChainError, BeaconChainTypes, metrics};
 use bls::PublicKey;
+use eth2::types::{EventKind, SsePayloadAttestationOutcome};
 use safe_arith::ArithError;
 use slot_clock::SlotClock;
 use state_processing;
@@ -30,7 +32,7 @@ use strum::AsRefStr;
 use tracing::debug;
 use tree_hash::TreeHash;
 use types::{
-    Address, BeaconStateError, BuilderIndex, EthSpec, ExecutionBlockHash, Hash256,
+    Address, BeaconBlockRef, BeaconStateError, BuilderIndex, EthSpec, ExecutionBlockHash, Hash256,
     PayloadAttestation, SignedExecutionPayloadBid, SignedExecutionPayloadEnvelope, Slot,
     consts::gloas::BUILDER_INDEX_SELF_BUILD,
 };
@@ -160,6 +162,11 @@ pub enum ExecutionBidError {
         parent_block_hash: ExecutionBlockHash,
         bid_value: u64,
     },
+    /// The bid passed gossip verification but fails `process_execution_payload_bid` against the
+    /// state of its parent block advanced to the bid slot.
+    ///
+    /// Only checked for bids submitted over the HTTP API with a `consensus` broadcast level.
+    ConsensusInvalid { reason: String },
     /// Beacon chain error occurred during validation.
     BeaconChainError(BeaconChainError),
     /// State error occurred during validation.
@@ -415,6 +422,22 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         bid: SignedExecutionPayloadBid<T::EthSpec>,
     ) -> Result<VerifiedExecutionBid<T>, ExecutionBidError> {
         let _timer = metrics::start_timer(&metrics::EXECUTION_BID_GOSSIP_VERIFICATION_TIMES);
+        let verified_bid = self.verify_execution_bid_for_gossip_without_observation(bid)?;
+        self.observe_execution_bid(&verified_bid)?;
+        Ok(verified_bid)
+    }
+
+    /// Verify an execution payload bid as `Self::verify_execution_bid_for_gossip` does, without
+    /// the checks which record the bid as observed (checks 6 and 7).
+    ///
+    /// Used by the HTTP API, which runs further checks before calling
+    /// `Self::observe_execution_bid`, so that a bid which fails them neither counts as a duplicate
+    /// nor blocks a corrected bid from the same builder.
+    #[allow(clippy::result_large_err)]
+    pub fn verify_execution_bid_for_gossip_without_observation(
+        &self,
+        bid: SignedExecutionPayloadBid<T::EthSpec>,
+    ) -> Result<VerifiedExecutionBid<T>, ExecutionBidError> {
         let bid_slot = *bid.to_ref().message().slot();
         let builder_index = *bid.to_ref().message().builder_index();
 
@@ -556,58 +579,118 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             return Err(ExecutionBidError::InvalidSignature);
         }
 
-        // Check 5b: Equivocation detection (after signature verification so that
-        // invalid-signature bids don't block later valid bids from the same builder).
-        // Spec: [IGNORE] this is the first signed bid seen with a valid signature
-        // from the given builder for this slot.
-        let bid_root = match &bid {
+        Ok(VerifiedExecutionBid { bid })
+    }
+
+    /// Record a verified bid as observed, completing `Self::verify_execution_bid_for_gossip`.
+    ///
+    /// Returns an error if the builder has already had this bid or a conflicting one observed for
+    /// the same slot, or if a bid of equal or greater value has been observed for the same parent.
+    #[allow(clippy::result_large_err)]
+    pub fn observe_execution_bid(
+        &self,
+        verified_bid: &VerifiedExecutionBid<T>,
+    ) -> Result<(), ExecutionBidError> {
+        observe_bid(verified_bid.bid(), &mut self.observed_execution_bids.lock())
+    }
+
+    /// Record a verified bid as observed as `Self::observe_execution_bid` does, but first return
+    /// an error if its builder has signed any bid for the same slot which conflicts with it.
+    ///
+    /// Unlike a plain observation, this also refuses the bid if it was itself observed first but
+    /// its builder has equivocated since. The check is made under the same lock as the
+    /// observation, so a conflicting bid which is verified concurrently is either seen here or
+    /// is itself refused as an equivocation.
+    #[allow(clippy::result_large_err)]
+    pub fn observe_execution_bid_unless_equivocating(
+        &self,
+        verified_bid: &VerifiedExecutionBid<T>,
+    ) -> Result<(), ExecutionBidError> {
+        let bid = verified_bid.bid();
+        let slot = *bid.to_ref().message().slot();
+        let builder_index = *bid.to_ref().message().builder_index();
+        let bid_root = match bid {
             SignedExecutionPayloadBid::Gloas(inner) => inner.tree_hash_root(),
             SignedExecutionPayloadBid::Heze(inner) => inner.tree_hash_root(),
         };
 
-        let observation_outcome =
-            self.observed_execution_bids
-                .lock()
-                .observe_bid(bid_slot, builder_index, bid_root);
+        let mut observed_execution_bids = self.observed_execution_bids.lock();
+        if let Some(previous_bid_root) =
+            observed_execution_bids.conflicting_bid_root(slot, builder_index, bid_root)
+        {
+            return Err(ExecutionBidError::BuilderEquivocation {
+                builder_index,
+                slot,
+                previous_bid_root,
+                new_bid_root: bid_root,
+            });
+        }
 
-        match observation_outcome {
-            crate::observed_execution_bids::BidObservationOutcome::New => {
-                // Continue with validation
-            }
-            crate::observed_execution_bids::BidObservationOutcome::Duplicate => {
-                return Err(ExecutionBidError::DuplicateBid { bid_root });
-            }
-            crate::observed_execution_bids::BidObservationOutcome::Equivocation {
-                existing_bid_root,
-                new_bid_root,
-            } => {
-                return Err(ExecutionBidError::BuilderEquivocation {
-                    builder_index,
-                    slot: bid_slot,
-                    previous_bid_root: existing_bid_root,
-                    new_bid_root,
-                });
-            }
+        observe_bid(bid, &mut observed_execution_bids)
+    }
+
+    /// Verify that a gossip-verified bid would be accepted by `process_execution_payload_bid` in a
+    /// block at `bid.slot` built on `bid.parent_block_root`.
+    ///
+    /// The check runs against a copy of the parent state advanced to the bid slot, so it covers
+    /// the `parent_block_hash` and `prev_randao` commitments which gossip verification skips.
+    pub fn verify_execution_bid_for_consensus(
+        &self,
+        verified_bid: &VerifiedExecutionBid<T>,
+    ) -> Result<(), ExecutionBidError> {
+        let bid = verified_bid.bid().to_ref();
+        let message = bid.message();
+        let bid_slot = *message.slot();
+        let parent_root = *message.parent_block_root();
+        let parent_block_hash = *message.parent_block_hash();
+
+        let parent = self
+            .canonical_head
+            .fork_choice_read_lock()
+            .get_block(&parent_root)
+            .ok_or(ExecutionBidError::InvalidParentRoot {
+                received: parent_root,
+            })?;
+
+        let (state_root, mut state) = self
+            .store
+            .get_advanced_hot_state(parent_root, bid_slot, parent.state_root)
+            .map_err(BeaconChainError::DBError)?
+            .ok_or(ExecutionBidError::InvalidParentRoot {
+                received: parent_root,
+            })?;
+
+        // A bid building on a revealed parent payload must be checked against the post-envelope
+        // state. Cached states may be pre-envelope, so patch `latest_block_hash` in the same way
+        // as block import does.
+        if parent.payload_revealed
+            && parent.bid_block_hash == Some(parent_block_hash)
+            && let Ok(latest_block_hash) = state.latest_block_hash_mut()
+        {
+            *latest_block_hash = parent_block_hash;
         }
 
-        // Check 6: Highest-value bid filtering (after signature verification
-        // to prevent invalid bids from blocking legitimate higher-value ones).
-        // Spec: [IGNORE] this bid is the highest value bid seen for the tuple
-        // (bid.slot, bid.parent_block_hash, bid.parent_block_root).
-        if !self.observed_execution_bids.lock().is_highest_value_bid(
-            bid_slot,
-            *bid.to_ref().message().parent_block_hash(),
-            *bid.to_ref().message().parent_block_root(),
-            *bid.to_ref().message().value(),
-        ) {
-            return Err(ExecutionBidError::NotHighestValue {
-                slot: bid_slot,
-                parent_block_hash: *bid.to_ref().message().parent_block_hash(),
-                bid_value: *bid.to_ref().message().value(),
-            });
+        if state.slot() < bid_slot {
+            state_processing::state_advance::partial_state_advance(
+                &mut state,
+                Some(state_root),
+                bid_slot,
+                &self.spec,
+            )
+            .map_err(BeaconChainError::StateAdvanceError)?;
         }
 
-        Ok(VerifiedExecutionBid { bid })
+        state_processing::per_block_processing::gloas::process_execution_payload_bid(
+            &mut state,
+            bid,
+            bid_slot,
+            parent_root,
+            state_processing::VerifySignatures::False,
+            &self.spec,
+        )
+        .map_err(|e| ExecutionBidError::ConsensusInvalid {
+            reason: format!("{e:?}"),
+        })
     }
 
     /// Verify a payload attestation received via gossip.
@@ -805,6 +888,23 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         signed_envelope: Arc<SignedExecutionPayloadEnvelope<T::EthSpec>>,
     ) -> Result<VerifiedPayloadEnvelope<T>, PayloadEnvelopeError> {
         let _timer = metrics::start_timer(&metrics::PAYLOAD_ENVELOPE_GOSSIP_VERIFICATION_TIMES);
+        let verified_envelope =
+            self.verify_payload_envelope_for_gossip_without_observation(signed_envelope)?;
+        self.observe_payload_envelope(&verified_envelope)?;
+        Ok(verified_envelope)
+    }
+
+    /// Verify a payload envelope as `Self::verify_payload_envelope_for_gossip` does, without
+    /// recording its block root as observed.
+    ///
+    /// Used by the HTTP API, which processes the envelope before calling
+    /// `Self::observe_payload_envelope`, so that an envelope which fails processing does not
+    /// block a corrected envelope for the same block.
+    #[allow(clippy::result_large_err)]
+    pub fn verify_payload_envelope_for_gossip_without_observation(
+        &self,
+        signed_envelope: Arc<SignedExecutionPayloadEnvelope<T::EthSpec>>,
+    ) -> Result<VerifiedPayloadEnvelope<T>, PayloadEnvelopeError> {
         let envelope = &signed_envelope.message;
         let beacon_block_root = envelope.beacon_block_root;
 
@@ -834,7 +934,7 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         //
         // Check early to avoid expensive block retrieval + signature verification
         // for known duplicates. The root is only permanently recorded after all
-        // validation passes (see end of this function).
+        // validation passes (see `Self::observe_payload_envelope`).
         if self
             .observed_payload_envelopes
             .lock()
@@ -941,15 +1041,192 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             }
         }
 
-        // All validation passed — record this block root so future duplicate
-        // envelopes are ignored (spec IGNORE condition).
-        self.observed_payload_envelopes
-            .lock()
-            .observe_envelope(beacon_block_root);
-
         Ok(VerifiedPayloadEnvelope {
             envelope: signed_envelope,
             beacon_block_root,
         })
     }
+
+    /// Record the block root of a verified envelope as observed, completing
+    /// `Self::verify_payload_envelope_for_gossip`, so that future duplicate envelopes are
+    /// ignored (spec IGNORE condition).
+    ///
+    /// Returns an error if an envelope has already been observed for the same block root.
+    #[allow(clippy::result_large_err)]
+    pub fn observe_payload_envelope(
+        &self,
+        verified_envelope: &VerifiedPayloadEnvelope<T>,
+    ) -> Result<(), PayloadEnvelopeError> {
+        let beacon_block_root = verified_envelope.beacon_block_root();
+        if self
+            .observed_payload_envelopes
+            .lock()
+            .observe_envelope(beacon_block_root)
+        {
+            Ok(())
+        } else {
+            Err(PayloadEnvelopeError::DuplicateEnvelope {
+                block_root: beacon_block_root,
+            })
+        }
+    }
+
+    /// Register a `payload_attestation_outcome` server-sent event with the PTC's verdict on each
+    /// block from `slot` known to fork choice.
+    ///
+    /// The verdict is only final once no more payload attestations can be verified for `slot`, so
+    /// rather than on each payload attestation verified above, this should be called once `slot`
+    /// has ended, when gossip payload attestations for it are no longer accepted. Blocks from
+    /// `slot` imported later are registered by `register_late_payload_attestation_outcome`.
+    pub(crate) fn register_payload_attestation_outcomes(&self, slot: Slot) {
+        if !self.has_payload_attestation_outcome_subscribers(slot) {
+            return;
+        }
+
+        let block_roots = self
+            .canonical_head
+            .fork_choice_read_lock()
+            .proto_array()
+            .core_proto_array()
+            .nodes
+            .iter()
+            .filter(|node| node.slot == slot)
+            .map(|node| node.root)
+            .collect::<Vec<_>>();
+
+        for block_root in block_roots {
+            self.register_payload_attestation_outcome(block_root, slot);
+        }
+    }
+
+    /// Register a `payload_attestation_outcome` server-sent event with the PTC's verdict on the
+    /// block `block_root` from `slot`.
+    fn register_payload_attestation_outcome(&self, beacon_block_root: Hash256, slot: Slot) {
+        let Some(event_handler) = self.event_handler.as_ref() else {
+            return;
+        };
+
+        if let Some(outcome) = self
+            .canonical_head
+            .fork_choice_read_lock()
+            .get_payload_attestation_outcome(&beacon_block_root, &self.spec)
+        {
+            event_handler.register(EventKind::PayloadAttestationOutcome(
+                SsePayloadAttestationOutcome {
+                    slot,
+                    beacon_block_root,
+                    payload_present: outcome.payload_present,
+                    blob_data_available: outcome.blob_data_available,
+                    payload_present_votes: outcome.payload_present_votes,
+                    blob_data_available_votes: outcome.blob_data_available_votes,
+                },
+            ));
+        }
+    }
+
+    /// Returns `true` if `payload_attestation_outcome` events should be registered for blocks
+    /// from `slot`.
+    fn has_payload_attestation_outcome_subscribers(&self, slot: Slot) -> bool {
+        self.event_handler
+            .as_ref()
+            .is_some_and(|handler| handler.has_payload_attestation_outcome_subscribers())
+            && self
+                .spec
+                .fork_name_at_slot::<T::EthSpec>(slot)
+                .gloas_enabled()
+    }
+
+    /// Register a `payload_attestation_outcome` server-sent event for the parent of `block` if the
+    /// parent was imported after its slot ended.
+    ///
+    /// `register_payload_attestation_outcomes` has already run for the slot of such a block, and
+    /// the PTC's votes on it only arrive in its child, so its verdict is registered once the child
+    /// carrying the votes has been imported.
+    pub(crate) fn register_late_payload_attestation_outcome(
+        &self,
+        block: BeaconBlockRef<T::EthSpec>,
+        block_root: Hash256,
+        current_slot: Slot,
+    ) {
+        if !self.has_payload_attestation_outcome_subscribers(block.slot()) {
+            return;
+        }
+
+        let parent_root = block.parent_root();
+        let late_parent_slot = {
+            let mut late_blocks = self.late_payload_attestation_outcomes.lock();
+            late_blocks.retain(|_, slot| *slot + 1 >= block.slot());
+            if block.slot() < current_slot {
+                late_blocks.insert(block_root, block.slot());
+            }
+            late_blocks.remove(&parent_root)
+        };
+        // Payload attestations can only be included in the block of the following slot.
+        if let Some(parent_slot) = late_parent_slot
+            && parent_slot + 1 == block.slot()
+        {
+            self.register_payload_attestation_outcome(parent_root, parent_slot);
+        }
+    }
+}
+
+/// Record `bid` in `observed_execution_bids`, as `BeaconChain::observe_execution_bid` does.
+#[allow(clippy::result_large_err)]
+fn observe_bid<E: EthSpec>(
+    bid: &SignedExecutionPayloadBid<E>,
+    observed_execution_bids: &mut ObservedExecutionBids<E>,
+) -> Result<(), ExecutionBidError> {
+    let bid_slot = *bid.to_ref().message().slot();
+    let builder_index = *bid.to_ref().message().builder_index();
+
+    // Check 5b: Equivocation detection (after signature verification so that
+    // invalid-signature bids don't block later valid bids from the same builder).
+    // Spec: [IGNORE] this is the first signed bid seen with a valid signature
+    // from the given builder for this slot.
+    let bid_root = match bid {
+        SignedExecutionPayloadBid::Gloas(inner) => inner.tree_hash_root(),
+        SignedExecutionPayloadBid::Heze(inner) => inner.tree_hash_root(),
+    };
+
+    let observation_outcome =
+        observed_execution_bids.observe_bid(bid_slot, builder_index, bid_root);
+
+    match observation_outcome {
+        crate::observed_execution_bids::BidObservationOutcome::New => {
+            // Continue with validation
+        }
+        crate::observed_execution_bids::BidObservationOutcome::Duplicate => {
+            return Err(ExecutionBidError::DuplicateBid { bid_root });
+        }
+        crate::observed_execution_bids::BidObservationOutcome::Equivocation {
+            existing_bid_root,
+            new_bid_root,
+        } => {
+            return Err(ExecutionBidError::BuilderEquivocation {
+                builder_index,
+                slot: bid_slot,
+                previous_bid_root: existing_bid_root,
+                new_bid_root,
+            });
+        }
+    }
+
+    // Check 6: Highest-value bid filtering (after signature verification
+    // to prevent invalid bids from blocking legitimate higher-value ones).
+    // Spec: [IGNORE] this bid is the highest value bid seen for the tuple
+    // (bid.slot, bid.parent_block_hash, bid.parent_block_root).
+    if !observed_execution_bids.is_highest_value_bid(
+        bid_slot,
+        *bid.to_ref().message().parent_block_hash(),
+        *bid.to_ref().message().parent_block_root(),
+        *bid.to_ref().message().value(),
+    ) {
+        return Err(ExecutionBidError::NotHighestValue {
+            slot: bid_slot,
+            parent_block_hash: *bid.to_ref().message().parent_block_hash(),
+            bid_value: *bid.to_ref().message().value(),
+        });
+    }
+
+    Ok(())
 }
@@ -7,6 +7,7 @@
 //! 2. Import to the InclusionListStore
 
 use crate::{BeaconChain, BeaconChainError, BeaconChainTypes, metrics};
+use eth2::types::EventKind;
 use slot_clock::SlotClock;
 use state_processing::per_block_processing::heze::{
     get_inclusion_list_committee, is_valid_inclusion_list_signature,
@@ -248,6 +249,16 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
 
     /// Import a verified inclusion list into the InclusionListStore.
     pub fn import_inclusion_list(&self, verified: VerifiedInclusionList<T>) {
+        // This method is called for both API and gossip inclusion lists, so this covers all
+        // inclusion list events.
+        if let Some(event_handler) = self.event_handler.as_ref()
+            && event_handler.has_inclusion_list_subscribers()
+        {
+            event_handler.register(EventKind::InclusionList(Box::new(
+                verified.signed_il.clone(),
+            )));
+        }
+
         let mut store = self.inclusion_list_store.lock();
         store.process_signed_inclusion_list(
             verified.signed_il,
@@ -7,11 +7,13 @@ use state_processing::{
 };
 use std::borrow::Cow;
 use std::iter;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 use store::metadata::DataColumnInfo;
 use store::{AnchorInfo, BlobInfo, DBColumn, Error as StoreError, KeyValueStore, KeyValueStoreOp};
 use strum::IntoStaticStr;
-use tracing::{debug, instrument};
+use tracing::{debug, info, instrument, warn};
 use types::{FixedBytesExtended, Hash256, Slot};
 
 /// Use a longer timeout on the pubkey cache.
@@ -310,6 +312,46 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
 
         Ok(num_relevant)
     }
+
+    /// Fill block history from the era files in `config.era_dir` on a blocking thread, so that
+    /// backfill sync has less to download.
+    ///
+    /// Backfill sync is held back by `era_import_in_progress` until the import has finished, as
+    /// both move the anchor.
+    pub fn spawn_era_import(self: &Arc<Self>) {
+        let Some(era_dir) = self.config.era_dir.clone() else {
+            return;
+        };
+        let chain = self.clone();
+        self.task_executor.spawn_blocking(
+            move || {
+                match chain.store.import_era_dir(
+                    &era_dir,
+                    chain.genesis_block_root,
+                    chain.data_availability_boundary(),
+                ) {
+                    Ok(blocks_imported) => {
+                        info!(
+                            blocks_imported,
+                            oldest_block_slot = %chain.store.get_oldest_block_slot(),
+                            "Imported historic blocks from era files"
+                        );
+                        // Reconstruction is only started at startup if block history was already
+                        // complete, so start it here if the era files completed it.
+                        if blocks_imported > 0
+                            && chain.store.get_oldest_block_slot() == 0
+                            && chain.config.reconstruct_historic_states
+                        {
+                            chain.store_migrator.process_reconstruction();
+                        }
+                    }
+                    Err(e) => warn!(error = ?e, "Failed to import era files"),
+                }
+                chain.era_import_in_progress.store(false, Ordering::Release);
+            },
+            "era_import",
+        );
+    }
 }
 
 #[cfg(test)]
@@ -9,12 +9,14 @@ mod beacon_fork_choice_store;
 pub mod beacon_proposer_cache;
 mod beacon_snapshot;
 pub mod bellatrix_readiness;
+pub mod bid_selection;
 pub mod blob_verification;
 pub(crate) mod block_reward;
 mod block_times_cache;
 mod block_verification;
 pub mod block_verification_types;
 pub mod builder;
+pub(crate) mod builder_reliability;
 pub mod canonical_head;
 pub mod chain_config;
 pub mod custody_context;
@@ -234,6 +234,15 @@ pub static BLOCK_PRODUCTION_SELF_BUILD_TOTAL: LazyLock<Result<IntCounter>> = Laz
     )
 });
 
+pub static BLOCK_PRODUCTION_BID_REJECTIONS_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "beacon_block_production_bid_rejections_total",
+            "Count of external builder bids rejected by the bid selection policy",
+            &["reason"],
+        )
+    });
+
 /*
  * Self-Build Envelope Processing (gloas ePBS)
  */
@@ -1369,6 +1378,73 @@ pub static VALIDATOR_MONITOR_VALIDATOR_IN_CURRENT_SYNC_COMMITTEE: LazyLock<Resul
             &["validator"],
         )
     });
+pub static VALIDATOR_MONITOR_PREV_EPOCH_PTC_DUTIES: LazyLock<Result<IntGaugeVec>> =
+    LazyLock::new(|| {
+        try_create_int_gauge_vec(
+            "validator_monitor_prev_epoch_ptc_duties",
+            "The number of payload timeliness committee duties in the previous epoch.",
+            &["validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PREV_EPOCH_PAYLOAD_ATTESTATIONS_TOTAL: LazyLock<Result<IntGaugeVec>> =
+    LazyLock::new(|| {
+        try_create_int_gauge_vec(
+            "validator_monitor_prev_epoch_payload_attestations_total",
+            "The number of payload attestations seen in the previous epoch.",
+            &["validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PREV_EPOCH_PAYLOAD_ATTESTATIONS_MIN_DELAY_SECONDS: LazyLock<
+    Result<HistogramVec>,
+> = LazyLock::new(|| {
+    try_create_histogram_vec(
+        "validator_monitor_prev_epoch_payload_attestations_min_delay_seconds",
+        "The min delay between when the validator should send the payload attestation and when it was received.",
+        &["validator"],
+    )
+});
+pub static VALIDATOR_MONITOR_PREV_EPOCH_PAYLOAD_ATTESTATION_BLOCK_INCLUSIONS: LazyLock<
+    Result<IntGaugeVec>,
+> = LazyLock::new(|| {
+    try_create_int_gauge_vec(
+        "validator_monitor_prev_epoch_payload_attestation_block_inclusions",
+        "The count of times a payload attestation was seen inside a block.",
+        &["validator"],
+    )
+});
+pub static VALIDATOR_MONITOR_PREV_EPOCH_PAYLOAD_ATTESTATION_CORRECT_VOTES: LazyLock<
+    Result<IntGaugeVec>,
+> = LazyLock::new(|| {
+    try_create_int_gauge_vec(
+        "validator_monitor_prev_epoch_payload_attestation_correct_votes",
+        "The number of payload attestations in the previous epoch which matched the eventual payload status.",
+        &["validator"],
+    )
+});
+pub static VALIDATOR_MONITOR_PREV_EPOCH_INCLUSION_LIST_DUTIES: LazyLock<Result<IntGaugeVec>> =
+    LazyLock::new(|| {
+        try_create_int_gauge_vec(
+            "validator_monitor_prev_epoch_inclusion_list_duties",
+            "The number of inclusion list committee duties in the previous epoch.",
+            &["validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PREV_EPOCH_INCLUSION_LISTS_TOTAL: LazyLock<Result<IntGaugeVec>> =
+    LazyLock::new(|| {
+        try_create_int_gauge_vec(
+            "validator_monitor_prev_epoch_inclusion_lists_total",
+            "The number of inclusion lists seen in the previous epoch.",
+            &["validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PREV_EPOCH_INCLUSION_LISTS_SATISFIED: LazyLock<Result<IntGaugeVec>> =
+    LazyLock::new(|| {
+        try_create_int_gauge_vec(
+            "validator_monitor_prev_epoch_inclusion_lists_satisfied",
+            "The number of inclusion list duties in the previous epoch which the next block satisfied.",
+            &["validator"],
+        )
+    });
 
 /*
  * Validator Monitor Metrics (real-time)
@@ -1534,6 +1610,112 @@ pub static VALIDATOR_MONITOR_ATTESTER_SLASHING_TOTAL: LazyLock<Result<IntCounter
             &["src", "validator"],
         )
     });
+pub static VALIDATOR_MONITOR_PTC_DUTIES_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_ptc_duties_total",
+            "Number of payload timeliness committee duties assigned",
+            &["validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PAYLOAD_ATTESTATIONS_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_payload_attestations_total",
+            "Number of payload attestation messages seen",
+            &["src", "validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PAYLOAD_ATTESTATIONS_DELAY_SECONDS: LazyLock<Result<HistogramVec>> =
+    LazyLock::new(|| {
+        try_create_histogram_vec(
+            "validator_monitor_payload_attestations_delay_seconds",
+            "The delay between when the validator should send the payload attestation and when it was received.",
+            &["src", "validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PAYLOAD_ATTESTATION_IN_BLOCK_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_payload_attestation_in_block_total",
+            "Number of times a validator's payload attestation has been seen in a block",
+            &["src", "validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PAYLOAD_ATTESTATION_MISSING_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_payload_attestation_missing_total",
+            "Number of payload timeliness committee duties without an observed payload attestation",
+            &["validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_PAYLOAD_ATTESTATION_CORRECT_VOTE_TOTAL: LazyLock<
+    Result<IntCounterVec>,
+> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "validator_monitor_payload_attestation_correct_vote_total",
+        "Number of payload attestations which matched the eventual payload status",
+        &["validator"],
+    )
+});
+pub static VALIDATOR_MONITOR_PAYLOAD_ATTESTATION_INCORRECT_VOTE_TOTAL: LazyLock<
+    Result<IntCounterVec>,
+> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "validator_monitor_payload_attestation_incorrect_vote_total",
+        "Number of payload attestations which did not match the eventual payload status",
+        &["validator"],
+    )
+});
+pub static VALIDATOR_MONITOR_INCLUSION_LIST_DUTIES_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_inclusion_list_duties_total",
+            "Number of inclusion list committee duties assigned",
+            &["validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_INCLUSION_LISTS_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_inclusion_lists_total",
+            "Number of inclusion lists seen",
+            &["src", "validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_INCLUSION_LISTS_DELAY_SECONDS: LazyLock<Result<HistogramVec>> =
+    LazyLock::new(|| {
+        try_create_histogram_vec(
+            "validator_monitor_inclusion_lists_delay_seconds",
+            "The delay between the start of the slot and when the inclusion list was received.",
+            &["src", "validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_INCLUSION_LISTS_LATE_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_inclusion_lists_late_total",
+            "Number of inclusion lists received after the view freeze cutoff",
+            &["src", "validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_INCLUSION_LIST_SATISFIED_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_inclusion_list_satisfied_total",
+            "Number of inclusion list duties marked as satisfied by the next block's bid",
+            &["validator"],
+        )
+    });
+pub static VALIDATOR_MONITOR_INCLUSION_LIST_UNSATISFIED_TOTAL: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "validator_monitor_inclusion_list_unsatisfied_total",
+            "Number of inclusion list duties not marked as satisfied by the next block's bid",
+            &["validator"],
+        )
+    });
 
 // Prevent recursion limit
 
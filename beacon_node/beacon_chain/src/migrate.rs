@@ -7,6 +7,7 @@ use std::sync::{Arc, mpsc};
 use std::thread;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use store::hot_cold_store::{HotColdDBError, migrate_database};
+use store::snapshot::{SnapshotPaths, SnapshotProgress};
 use store::{Error, ItemStore, Split, StoreOp};
 pub use store::{HotColdDB, MemoryStore};
 use tracing::{debug, error, info, warn};
@@ -36,6 +37,7 @@ pub struct BackgroundMigrator<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>>
     prev_migration: Arc<Mutex<PrevMigration>>,
     #[allow(clippy::type_complexity)]
     tx_thread: Option<Mutex<(mpsc::Sender<Notification>, thread::JoinHandle<()>)>>,
+    latest_snapshot: Mutex<Option<Arc<DatabaseSnapshot>>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -122,6 +124,7 @@ pub enum Notification {
     PruneBlobs(Epoch),
     ManualFinalization(ManualFinalizationNotification),
     ManualCompaction,
+    Snapshot(Arc<DatabaseSnapshot>),
 }
 
 pub struct ManualFinalizationNotification {
@@ -135,6 +138,28 @@ pub struct FinalizationNotification {
     pub prev_migration: Arc<Mutex<PrevMigration>>,
 }
 
+/// A copy of the database requested via `BackgroundMigrator::process_snapshot`.
+pub struct DatabaseSnapshot {
+    pub paths: SnapshotPaths,
+    pub progress: SnapshotProgress,
+    /// The split of the copy, or the reason it failed. `None` while the copy is being written.
+    outcome: Mutex<Option<Result<Split, String>>>,
+}
+
+impl DatabaseSnapshot {
+    fn new(paths: SnapshotPaths) -> Self {
+        Self {
+            paths,
+            progress: SnapshotProgress::default(),
+            outcome: Mutex::new(None),
+        }
+    }
+
+    pub fn outcome(&self) -> Option<Result<Split, String>> {
+        self.outcome.lock().clone()
+    }
+}
+
 impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Hot, Cold> {
     /// Create a new `BackgroundMigrator` and spawn its thread if necessary.
     pub fn new(db: Arc<HotColdDB<E, Hot, Cold>>, config: MigratorConfig) -> Self {
@@ -152,6 +177,7 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
             db,
             prev_migration,
             tx_thread,
+            latest_snapshot: Mutex::new(None),
         }
     }
 
@@ -217,6 +243,61 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
         }
     }
 
+    /// Copy the database to `paths` from the migration thread, so that the freezer migration does
+    /// not run while the copy is written.
+    ///
+    /// Only one snapshot can be written at a time.
+    pub fn process_snapshot(
+        &self,
+        paths: SnapshotPaths,
+    ) -> Result<Arc<DatabaseSnapshot>, BeaconChainError> {
+        let snapshot = {
+            let mut latest_snapshot = self.latest_snapshot.lock();
+            if latest_snapshot
+                .as_ref()
+                .is_some_and(|snapshot| snapshot.outcome().is_none())
+            {
+                return Err(BeaconChainError::DatabaseSnapshotInProgress);
+            }
+            let snapshot = Arc::new(DatabaseSnapshot::new(paths));
+            *latest_snapshot = Some(snapshot.clone());
+            snapshot
+        };
+
+        if let Some(Notification::Snapshot(snapshot)) =
+            self.send_background_notification(Notification::Snapshot(snapshot.clone()))
+        {
+            Self::run_snapshot(self.db.clone(), snapshot);
+        }
+
+        Ok(snapshot)
+    }
+
+    /// The most recently requested snapshot, which may still be in progress.
+    pub fn latest_snapshot(&self) -> Option<Arc<DatabaseSnapshot>> {
+        self.latest_snapshot.lock().clone()
+    }
+
+    fn run_snapshot(db: Arc<HotColdDB<E, Hot, Cold>>, snapshot: Arc<DatabaseSnapshot>) {
+        info!(
+            hot_path = %snapshot.paths.hot.display(),
+            "Database snapshot started"
+        );
+        let outcome = db
+            .snapshot(&snapshot.paths, &snapshot.progress)
+            .map_err(|e| format!("{e:?}"));
+        match &outcome {
+            Ok(split) => info!(
+                split_slot = %split.slot,
+                entries = snapshot.progress.entries(),
+                bytes = snapshot.progress.bytes(),
+                "Database snapshot complete"
+            ),
+            Err(error) => error!(%error, "Database snapshot failed"),
+        }
+        *snapshot.outcome.lock() = Some(outcome);
+    }
+
     pub fn run_reconstruction(
         db: Arc<HotColdDB<E, Hot, Cold>>,
         opt_tx: Option<mpsc::Sender<Notification>>,
@@ -286,6 +367,7 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
                         Notification::PruneBlobs(_) => "prune_blobs",
                         Notification::ManualFinalization(_) => "manual_finalization",
                         Notification::ManualCompaction => "manual_compaction",
+                        Notification::Snapshot(_) => "snapshot",
                     };
                     warn!(
                         notification = notif_kind,
@@ -418,6 +500,10 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
             }
         };
 
+        if let Err(e) = db.prune_side_objects(notif.finalized_checkpoint.epoch) {
+            warn!(error = ?e, "Side object pruning failed");
+        }
+
         // Finally, compact the database so that new free space is properly reclaimed.
         if let Err(e) = Self::run_compaction(
             db,
@@ -454,12 +540,14 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
                 let mut manual_finalization_notif = None;
                 let mut manual_compaction_notif = None;
                 let mut prune_blobs_notif = None;
+                let mut snapshot_notif = None;
                 match notif {
                     Notification::Reconstruction => reconstruction_notif = Some(notif),
                     Notification::Finalization(fin) => finalization_notif = Some(fin),
                     Notification::ManualFinalization(fin) => manual_finalization_notif = Some(fin),
                     Notification::PruneBlobs(dab) => prune_blobs_notif = Some(dab),
                     Notification::ManualCompaction => manual_compaction_notif = Some(notif),
+                    Notification::Snapshot(snapshot) => snapshot_notif = Some(snapshot),
                 }
                 // Read the rest of the messages in the channel, taking the best of each type.
                 for notif in rx.try_iter() {
@@ -489,6 +577,7 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
                         Notification::PruneBlobs(dab) => {
                             prune_blobs_notif = std::cmp::max(prune_blobs_notif, Some(dab));
                         }
+                        Notification::Snapshot(snapshot) => snapshot_notif = Some(snapshot),
                     }
                 }
                 // Run finalization and blob pruning migrations first, then a reconstruction batch.
@@ -503,6 +592,9 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
                 if let Some(dab) = prune_blobs_notif {
                     Self::run_prune_blobs(db.clone(), dab);
                 }
+                if let Some(snapshot) = snapshot_notif {
+                    Self::run_snapshot(db.clone(), snapshot);
+                }
                 if reconstruction_notif.is_some() {
                     Self::run_reconstruction(db.clone(), Some(inner_tx.clone()));
                 }
@@ -785,6 +877,7 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
                     StoreOp::DeleteBlock(block_root),
                     StoreOp::DeleteExecutionPayload(block_root),
                     StoreOp::DeletePayloadEnvelope(block_root),
+                    StoreOp::DeleteExecutionProofs(block_root),
                     StoreOp::DeleteBlobs(block_root),
                     StoreOp::DeleteSyncCommitteeBranch(block_root),
                 ]
@@ -798,6 +891,16 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
         // Prune sync committee branches of non-checkpoint canonical finalized blocks
         Self::prune_non_checkpoint_sync_committee_branches(&newly_finalized_blocks, &mut batch);
 
+        // Execution proofs only matter until a block is finalized: stateless validation ignores
+        // proofs for finalized blocks, so there is nothing left to serve them for, unless they are
+        // being retained for audits.
+        Self::prune_finalized_execution_proofs(
+            &store,
+            new_finalized_slot,
+            &newly_finalized_blocks,
+            &mut batch,
+        )?;
+
         // Prune all payloads of the canonical finalized blocks
         if store.get_config().prune_payloads {
             Self::prune_finalized_payloads(
@@ -844,6 +947,25 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> BackgroundMigrator<E, Ho
         }
     }
 
+    fn prune_finalized_execution_proofs(
+        store: &HotColdDB<E, Hot, Cold>,
+        new_finalized_slot: Slot,
+        finalized_blocks: &[(Hash256, Slot)],
+        hot_db_ops: &mut Vec<StoreOp<E>>,
+    ) -> Result<(), BeaconChainError> {
+        let retain = store.retains_side_objects();
+        let mut seen = HashSet::new();
+        for (block_root, slot) in finalized_blocks {
+            if *slot < new_finalized_slot && seen.insert(*block_root) {
+                if retain && let Some(op) = store.execution_proof_history_op(block_root, *slot)? {
+                    hot_db_ops.push(StoreOp::KeyValueOp(op));
+                }
+                hot_db_ops.push(StoreOp::DeleteExecutionProofs(*block_root));
+            }
+        }
+        Ok(())
+    }
+
     fn prune_non_checkpoint_sync_committee_branches(
         finalized_blocks_desc: &[(Hash256, Slot)],
         hot_db_ops: &mut Vec<StoreOp<E>>,
@@ -46,6 +46,8 @@ pub struct ObservedExecutionBids<E: EthSpec> {
     /// Spec: `[IGNORE] this bid is the highest value bid seen for the tuple
     /// (bid.slot, bid.parent_block_hash, bid.parent_block_root).`
     highest_bid_values: HashMap<(Slot, ExecutionBlockHash, Hash256), u64>,
+    /// The first conflicting bid root seen per (slot, builder_index) which has equivocated.
+    equivocations: HashMap<(Slot, BuilderIndex), Hash256>,
     /// Slots we've observed, in insertion order for efficient pruning
     observed_slots: Vec<Slot>,
     _phantom: PhantomData<E>,
@@ -89,6 +91,9 @@ impl<E: EthSpec> ObservedExecutionBids<E> {
                     BidObservationOutcome::Duplicate
                 } else {
                     // Different bid from same builder for same slot - equivocation!
+                    self.equivocations
+                        .entry((slot, builder_index))
+                        .or_insert(bid_root);
                     BidObservationOutcome::Equivocation {
                         existing_bid_root,
                         new_bid_root: bid_root,
@@ -124,6 +129,27 @@ impl<E: EthSpec> ObservedExecutionBids<E> {
         }
     }
 
+    /// Returns the root of a bid from `builder_index` in `slot` which conflicts with `bid_root`.
+    ///
+    /// This is either the bid observed for the builder in `slot`, or, if that is `bid_root`
+    /// itself, the first conflicting bid seen from the builder since. Used to check a bid which
+    /// has not been observed yet, so the check can be made under the same lock as its
+    /// observation.
+    pub fn conflicting_bid_root(
+        &self,
+        slot: Slot,
+        builder_index: BuilderIndex,
+        bid_root: Hash256,
+    ) -> Option<Hash256> {
+        self.observed_bids
+            .get(&slot)
+            .and_then(|slot_bids| slot_bids.get(&builder_index))
+            .into_iter()
+            .chain(self.equivocations.get(&(slot, builder_index)))
+            .copied()
+            .find(|root| *root != bid_root)
+    }
+
     /// Prune old slots from the cache to prevent unbounded growth.
     ///
     /// Retains only the most recent `MAX_OBSERVED_SLOTS` slots.
@@ -135,6 +161,8 @@ impl<E: EthSpec> ObservedExecutionBids<E> {
         self.observed_bids.retain(|&slot, _| slot >= earliest_slot);
         self.highest_bid_values
             .retain(|&(slot, _, _), _| slot >= earliest_slot);
+        self.equivocations
+            .retain(|&(slot, _), _| slot >= earliest_slot);
 
         // Also prune the observed_slots vector
         self.observed_slots.retain(|&slot| slot >= earliest_slot);
@@ -158,6 +186,7 @@ impl<E: EthSpec> ObservedExecutionBids<E> {
     pub fn clear(&mut self) {
         self.observed_bids.clear();
         self.highest_bid_values.clear();
+        self.equivocations.clear();
         self.observed_slots.clear();
     }
 }
@@ -223,6 +252,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conflicting_bid_root() {
+        let mut cache = ObservedExecutionBids::<E>::new();
+        let slot = Slot::new(100);
+        let builder_index = 42;
+        let bid_root_1 = Hash256::from_low_u64_be(1);
+        let bid_root_2 = Hash256::from_low_u64_be(2);
+        let bid_root_3 = Hash256::from_low_u64_be(3);
+
+        // Nothing observed yet.
+        assert_eq!(
+            cache.conflicting_bid_root(slot, builder_index, bid_root_2),
+            None
+        );
+
+        // An unobserved bid conflicts with the observed one, but the observed one does not
+        // conflict with itself.
+        cache.observe_bid(slot, builder_index, bid_root_1);
+        assert_eq!(
+            cache.conflicting_bid_root(slot, builder_index, bid_root_2),
+            Some(bid_root_1)
+        );
+        assert_eq!(
+            cache.conflicting_bid_root(slot, builder_index, bid_root_1),
+            None
+        );
+
+        // Once the builder equivocates, the observed bid conflicts with the first equivocating
+        // bid.
+        cache.observe_bid(slot, builder_index, bid_root_2);
+        cache.observe_bid(slot, builder_index, bid_root_3);
+        assert_eq!(
+            cache.conflicting_bid_root(slot, builder_index, bid_root_1),
+            Some(bid_root_2)
+        );
+        assert_eq!(
+            cache.conflicting_bid_root(slot, builder_index, bid_root_2),
+            Some(bid_root_1)
+        );
+        assert_eq!(cache.conflicting_bid_root(slot, 43, bid_root_2), None);
+
+        cache.prune_old_slots(Slot::new(slot.as_u64() + MAX_OBSERVED_SLOTS + 1));
+        assert_eq!(
+            cache.conflicting_bid_root(slot, builder_index, bid_root_1),
+            None
+        );
+    }
+
     #[test]
     fn test_multiple_builders_same_slot() {
         let mut cache = ObservedExecutionBids::<E>::new();
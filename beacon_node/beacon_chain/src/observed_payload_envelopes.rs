@@ -41,10 +41,14 @@ impl<E: EthSpec> ObservedPayloadEnvelopes<E> {
     ///
     /// Call this only after full validation succeeds, so that invalid
     /// envelopes don't prevent a later valid one from being processed.
-    pub(crate) fn observe_envelope(&mut self, beacon_block_root: Hash256) {
-        if self.observed_roots.insert(beacon_block_root) {
+    ///
+    /// Returns `false` if the root had already been recorded.
+    pub(crate) fn observe_envelope(&mut self, beacon_block_root: Hash256) -> bool {
+        let is_new = self.observed_roots.insert(beacon_block_root);
+        if is_new {
             self.insertion_order.push(beacon_block_root);
         }
+        is_new
     }
 
     /// Keep only the most recent `MAX_OBSERVED_ROOTS` entries (FIFO).
@@ -107,8 +111,8 @@ mod tests {
         let mut cache = ObservedPayloadEnvelopes::<E>::new();
         let root = Hash256::from_low_u64_be(1);
 
-        cache.observe_envelope(root);
-        cache.observe_envelope(root);
+        assert!(cache.observe_envelope(root));
+        assert!(!cache.observe_envelope(root));
         assert_eq!(cache.len(), 1);
     }
 
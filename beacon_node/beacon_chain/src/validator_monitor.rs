@@ -11,10 +11,12 @@ use serde::{Deserialize, Serialize};
 use slot_clock::SlotClock;
 use smallvec::SmallVec;
 use state_processing::common::get_attestation_participation_flag_indices;
+use state_processing::per_block_processing::gloas::get_ptc_committee;
+use state_processing::per_block_processing::heze::get_inclusion_list_committee;
 use state_processing::per_epoch_processing::{
     EpochProcessingSummary, errors::EpochProcessingError,
 };
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::io;
 use std::marker::PhantomData;
 use std::str::Utf8Error;
@@ -28,8 +30,9 @@ use types::consts::altair::{
 use types::{
     Attestation, AttestationData, AttesterSlashingRef, BeaconBlockRef, BeaconState,
     BeaconStateError, ChainSpec, Epoch, EthSpec, Hash256, IndexedAttestation,
-    IndexedAttestationRef, ProposerSlashing, PublicKeyBytes, SignedAggregateAndProof,
-    SignedContributionAndProof, Slot, SyncCommitteeMessage, VoluntaryExit,
+    IndexedAttestationRef, PayloadAttestation, PayloadAttestationData, PayloadAttestationMessage,
+    ProposerSlashing, PublicKeyBytes, SignedAggregateAndProof, SignedContributionAndProof,
+    SignedInclusionList, Slot, SyncCommitteeMessage, VoluntaryExit,
 };
 /// Used for Prometheus labels. Set to `total` to align with Nimbus.
 const TOTAL_LABEL: &str = "total";
@@ -79,6 +82,10 @@ pub const UNAGGREGATED_ATTESTATION_LAG_SLOTS: usize = 16;
 /// from the current and previous epoch.
 pub const MAX_UNAGGREGATED_ATTESTATION_HASHMAP_LENGTH: usize = 64;
 
+/// Payload attestations for slot `N` can only be included in a block at slot `N + 1`, so wait for a
+/// state at slot `N + 2` before checking them against the payload status.
+pub const PAYLOAD_ATTESTATION_LAG_SLOTS: u64 = 2;
+
 #[derive(Debug)]
 pub enum Error {
     InvalidPubkey(String),
@@ -137,6 +144,40 @@ pub struct EpochSummary {
     /// The delay between when the sync committee contribution should have been produced and when it was observed.
     sync_contribution_min_delay: Option<Duration>,
 
+    /*
+     * Payload timeliness committee duties with a slot in the current epoch.
+     */
+    /// The number of slots in which the validator is a member of the payload timeliness committee.
+    pub ptc_duties: usize,
+    /// The number of payload attestation messages seen.
+    pub payload_attestations: usize,
+    /// The delay between when the payload attestation should have been produced and when it was observed.
+    pub payload_attestation_min_delay: Option<Duration>,
+    /// The number of times a validator's payload attestation was seen in a block.
+    pub payload_attestation_block_inclusions: usize,
+    /// The number of PTC duties for which no payload attestation was seen.
+    pub payload_attestation_misses: usize,
+    /// The number of payload attestations which matched the eventual payload status.
+    pub payload_attestation_correct_votes: usize,
+    /// The number of payload attestations which did not match the eventual payload status.
+    pub payload_attestation_incorrect_votes: usize,
+
+    /*
+     * Inclusion list committee duties with a slot in the current epoch.
+     */
+    /// The number of slots in which the validator is a member of the inclusion list committee.
+    pub inclusion_list_duties: usize,
+    /// The number of inclusion lists seen.
+    pub inclusion_lists: usize,
+    /// The delay between the start of the slot and when the inclusion list was observed.
+    pub inclusion_list_min_delay: Option<Duration>,
+    /// The number of inclusion lists seen after the view freeze cutoff.
+    pub inclusion_lists_late: usize,
+    /// The number of inclusion list duties which the bid of the next block marked as satisfied.
+    pub inclusion_lists_satisfied: usize,
+    /// The number of inclusion list duties which the bid of the next block did not mark as satisfied.
+    pub inclusion_lists_unsatisfied: usize,
+
     /*
      * Others pertaining to this epoch.
      */
@@ -214,6 +255,51 @@ impl EpochSummary {
         self.sync_signature_block_inclusions += 1;
     }
 
+    pub fn register_ptc_duty(&mut self) {
+        self.ptc_duties += 1;
+    }
+
+    pub fn register_payload_attestation(&mut self, delay: Duration) {
+        self.payload_attestations += 1;
+        Self::update_if_lt(&mut self.payload_attestation_min_delay, delay);
+    }
+
+    pub fn register_payload_attestation_block_inclusion(&mut self) {
+        self.payload_attestation_block_inclusions += 1;
+    }
+
+    pub fn register_payload_attestation_miss(&mut self) {
+        self.payload_attestation_misses += 1;
+    }
+
+    pub fn register_payload_attestation_vote(&mut self, correct: bool) {
+        if correct {
+            self.payload_attestation_correct_votes += 1;
+        } else {
+            self.payload_attestation_incorrect_votes += 1;
+        }
+    }
+
+    pub fn register_inclusion_list_duty(&mut self) {
+        self.inclusion_list_duties += 1;
+    }
+
+    pub fn register_inclusion_list(&mut self, delay: Duration, on_time: bool) {
+        self.inclusion_lists += 1;
+        if !on_time {
+            self.inclusion_lists_late += 1;
+        }
+        Self::update_if_lt(&mut self.inclusion_list_min_delay, delay);
+    }
+
+    pub fn register_inclusion_list_satisfaction(&mut self, satisfied: bool) {
+        if satisfied {
+            self.inclusion_lists_satisfied += 1;
+        } else {
+            self.inclusion_lists_unsatisfied += 1;
+        }
+    }
+
     pub fn register_exit(&mut self) {
         self.exits += 1;
     }
@@ -396,6 +482,17 @@ pub struct ValidatorMonitor<E: EthSpec> {
     beacon_proposer_cache: Arc<Mutex<BeaconProposerCache>>,
     // Unaggregated attestations generated by the committee index at each slot.
     unaggregated_attestations: HashMap<Slot, Attestation<E>>,
+    /// The latest epoch for which PTC and inclusion list duties have been computed.
+    duties_epoch: Option<Epoch>,
+    /// The `(validator_index, committee_position)` of monitored validators in the payload
+    /// timeliness committee, by slot.
+    ptc_duties: BTreeMap<Slot, Vec<(u64, usize)>>,
+    /// The `(validator_index, committee_position)` of monitored validators in the inclusion list
+    /// committee, by slot.
+    inclusion_list_duties: BTreeMap<Slot, Vec<(u64, usize)>>,
+    /// Payload attestations of monitored validators awaiting comparison with the payload status,
+    /// by slot and validator index.
+    payload_attestation_votes: RwLock<BTreeMap<Slot, HashMap<u64, PayloadAttestationData>>>,
     _phantom: PhantomData<E>,
 }
 
@@ -418,6 +515,10 @@ impl<E: EthSpec> ValidatorMonitor<E> {
             missed_blocks: <_>::default(),
             beacon_proposer_cache,
             unaggregated_attestations: <_>::default(),
+            duties_epoch: None,
+            ptc_duties: <_>::default(),
+            inclusion_list_duties: <_>::default(),
+            payload_attestation_votes: <_>::default(),
             _phantom: PhantomData,
         };
         for pubkey in validators {
@@ -494,6 +595,8 @@ impl<E: EthSpec> ValidatorMonitor<E> {
         // Add missed non-finalized blocks for the monitored validators
         self.add_validators_missed_blocks(state, spec);
         self.process_unaggregated_attestations(state, spec);
+        self.add_validators_committee_duties(state, spec);
+        self.process_payload_attestation_votes(state);
 
         // Update metrics for individual validators.
         for monitored_validator in self.validators.values() {
@@ -679,6 +782,250 @@ impl<E: EthSpec> ValidatorMonitor<E> {
         }
     }
 
+    /// Record the payload timeliness committee (Gloas) and inclusion list committee (Heze) duties
+    /// of the monitored validators for the current epoch of `state`.
+    fn add_validators_committee_duties(&mut self, state: &BeaconState<E>, spec: &ChainSpec) {
+        let epoch = state.current_epoch();
+        let fork_name = state.fork_name_unchecked();
+
+        if !fork_name.gloas_enabled()
+            || self.validators.is_empty()
+            || self
+                .duties_epoch
+                .is_some_and(|duties_epoch| duties_epoch >= epoch)
+        {
+            return;
+        }
+        self.duties_epoch = Some(epoch);
+
+        for slot in epoch.slot_iter(E::slots_per_epoch()) {
+            match get_ptc_committee(state, slot, spec) {
+                Ok(ptc) => {
+                    let duties = self.monitored_committee_positions(&ptc);
+                    for validator_index in duties.iter().map(|(i, _)| *i).unique() {
+                        if let Some(validator) = self.get_validator(validator_index) {
+                            let id = &validator.id;
+                            self.aggregatable_metric(id, |label| {
+                                metrics::inc_counter_vec(
+                                    &metrics::VALIDATOR_MONITOR_PTC_DUTIES_TOTAL,
+                                    &[label],
+                                );
+                            });
+                            if self.individual_tracking() {
+                                debug!(
+                                    %epoch,
+                                    %slot,
+                                    validator = %id,
+                                    "Payload timeliness committee duty"
+                                );
+                            }
+                            validator.with_epoch_summary(epoch, |summary| {
+                                summary.register_ptc_duty();
+                            });
+                        }
+                    }
+                    if !duties.is_empty() {
+                        self.ptc_duties.insert(slot, duties);
+                    }
+                }
+                Err(e) => {
+                    debug!(
+                        %slot,
+                        error = ?e,
+                        "Unable to compute payload timeliness committee"
+                    );
+                }
+            }
+
+            if !fork_name.heze_enabled() {
+                continue;
+            }
+
+            match get_inclusion_list_committee(state, slot, spec) {
+                Ok(committee) => {
+                    let duties = self.monitored_committee_positions(&committee);
+                    for validator_index in duties.iter().map(|(i, _)| *i).unique() {
+                        if let Some(validator) = self.get_validator(validator_index) {
+                            let id = &validator.id;
+                            self.aggregatable_metric(id, |label| {
+                                metrics::inc_counter_vec(
+                                    &metrics::VALIDATOR_MONITOR_INCLUSION_LIST_DUTIES_TOTAL,
+                                    &[label],
+                                );
+                            });
+                            if self.individual_tracking() {
+                                debug!(
+                                    %epoch,
+                                    %slot,
+                                    validator = %id,
+                                    "Inclusion list committee duty"
+                                );
+                            }
+                            validator.with_epoch_summary(epoch, |summary| {
+                                summary.register_inclusion_list_duty();
+                            });
+                        }
+                    }
+                    if !duties.is_empty() {
+                        self.inclusion_list_duties.insert(slot, duties);
+                    }
+                }
+                Err(e) => {
+                    debug!(
+                        %slot,
+                        error = ?e,
+                        "Unable to compute inclusion list committee"
+                    );
+                }
+            }
+        }
+
+        // Duties from the previous epoch are still needed to check the first block of this epoch.
+        let oldest_slot = epoch.saturating_sub(1u64).start_slot(E::slots_per_epoch());
+        self.ptc_duties.retain(|slot, _| *slot >= oldest_slot);
+        self.inclusion_list_duties
+            .retain(|slot, _| *slot >= oldest_slot);
+    }
+
+    /// Returns the `(validator_index, committee_position)` of each monitored validator in
+    /// `committee`.
+    fn monitored_committee_positions(&self, committee: &[u64]) -> Vec<(u64, usize)> {
+        committee
+            .iter()
+            .enumerate()
+            .filter(|(_, validator_index)| self.get_validator(**validator_index).is_some())
+            .map(|(position, validator_index)| (*validator_index, position))
+            .collect()
+    }
+
+    /// Compare the payload attestations of the monitored validators against the payload status
+    /// recorded in `state`, and register any PTC duties for which no payload attestation was seen.
+    ///
+    /// Duties at skipped slots are dropped, as there is no block to attest to.
+    fn process_payload_attestation_votes(&mut self, state: &BeaconState<E>) {
+        let Ok(payload_availability) = state.execution_payload_availability() else {
+            return;
+        };
+        let current_slot = state.slot();
+        let is_due = |slot: &Slot| *slot + PAYLOAD_ATTESTATION_LAG_SLOTS <= current_slot;
+
+        let mut votes = std::mem::take(self.payload_attestation_votes.get_mut());
+        let due_slots = self
+            .ptc_duties
+            .keys()
+            .chain(votes.keys())
+            .copied()
+            .filter(is_due)
+            .collect::<BTreeSet<_>>();
+
+        for slot in due_slots {
+            let duties = self.ptc_duties.remove(&slot).unwrap_or_default();
+            let slot_votes = votes.remove(&slot).unwrap_or_default();
+            let epoch = slot.epoch(E::slots_per_epoch());
+
+            // The payload status is only meaningful if a block was proposed at `slot`.
+            let block_root = state.get_block_root(slot).ok().copied();
+            let prev_block_root = slot
+                .as_u64()
+                .checked_sub(1)
+                .and_then(|prev_slot| state.get_block_root(Slot::new(prev_slot)).ok())
+                .copied();
+            let payload_status = block_root
+                .filter(|root| Some(*root) != prev_block_root)
+                .zip(
+                    payload_availability
+                        .get(slot.as_usize() % E::slots_per_historical_root())
+                        .ok(),
+                );
+
+            // Payload attestations are only expected when a block was proposed at `slot`.
+            let Some((block_root, payload_available)) = payload_status else {
+                continue;
+            };
+
+            for validator_index in duties.iter().map(|(i, _)| *i).unique() {
+                if slot_votes.contains_key(&validator_index) {
+                    continue;
+                }
+                if let Some(validator) = self.get_validator(validator_index) {
+                    let id = &validator.id;
+                    self.aggregatable_metric(id, |label| {
+                        metrics::inc_counter_vec(
+                            &metrics::VALIDATOR_MONITOR_PAYLOAD_ATTESTATION_MISSING_TOTAL,
+                            &[label],
+                        );
+                    });
+                    if self.individual_tracking() {
+                        debug!(
+                            %epoch,
+                            %slot,
+                            validator = %id,
+                            "Payload attestation missing"
+                        );
+                    }
+                    validator.with_epoch_summary(epoch, |summary| {
+                        summary.register_payload_attestation_miss();
+                    });
+                }
+            }
+
+            for (validator_index, data) in slot_votes {
+                let Some(validator) = self.get_validator(validator_index) else {
+                    continue;
+                };
+                let id = &validator.id;
+
+                // Votes for a block which is not canonical in `state` say nothing about the
+                // eventual payload status.
+                if data.beacon_block_root != block_root {
+                    if self.individual_tracking() {
+                        debug!(
+                            vote_root = ?data.beacon_block_root,
+                            canonical_root = ?block_root,
+                            %slot,
+                            validator = %id,
+                            "Payload attestation for non-canonical block"
+                        );
+                    }
+                    continue;
+                }
+
+                let correct = data.payload_present == payload_available;
+                if correct {
+                    self.aggregatable_metric(id, |label| {
+                        metrics::inc_counter_vec(
+                            &metrics::VALIDATOR_MONITOR_PAYLOAD_ATTESTATION_CORRECT_VOTE_TOTAL,
+                            &[label],
+                        );
+                    });
+                } else {
+                    self.aggregatable_metric(id, |label| {
+                        metrics::inc_counter_vec(
+                            &metrics::VALIDATOR_MONITOR_PAYLOAD_ATTESTATION_INCORRECT_VOTE_TOTAL,
+                            &[label],
+                        );
+                    });
+                }
+                if self.individual_tracking() {
+                    debug!(
+                        payload_present = data.payload_present,
+                        payload_available,
+                        correct,
+                        %epoch,
+                        %slot,
+                        validator = %id,
+                        "Payload attestation vote evaluated"
+                    );
+                }
+                validator.with_epoch_summary(epoch, |summary| {
+                    summary.register_payload_attestation_vote(correct);
+                });
+            }
+        }
+
+        *self.payload_attestation_votes.get_mut() = votes;
+    }
+
     fn get_proposers_by_epoch_from_cache(
         &self,
         epoch: Epoch,
@@ -783,6 +1130,9 @@ impl<E: EthSpec> ValidatorMonitor<E> {
         let mut head_miss = Vec::new();
         let mut target_miss = Vec::new();
         let mut suboptimal_inclusion = Vec::new();
+        let mut payload_attestation_miss = Vec::new();
+        let mut payload_attestation_incorrect = Vec::new();
+        let mut inclusion_list_unsatisfied = Vec::new();
 
         // We subtract two from the state of the epoch that generated these summaries.
         //
@@ -1002,6 +1352,50 @@ impl<E: EthSpec> ValidatorMonitor<E> {
                         );
                     }
                 }
+
+                // Payload timeliness committee and inclusion list duties are checked within a
+                // couple of slots, so the previous epoch is complete by now.
+                let epoch_summaries = monitored_validator.summaries.read();
+                if let Some(epoch_summary) = epoch_summaries.get(&prev_epoch) {
+                    if epoch_summary.ptc_duties > 0 {
+                        if epoch_summary.payload_attestation_misses > 0 {
+                            payload_attestation_miss.push(id);
+                        }
+                        if epoch_summary.payload_attestation_incorrect_votes > 0 {
+                            payload_attestation_incorrect.push(id);
+                        }
+                        if self.individual_tracking() {
+                            info!(
+                                duties = epoch_summary.ptc_duties,
+                                seen = epoch_summary.payload_attestations,
+                                included = epoch_summary.payload_attestation_block_inclusions,
+                                missed = epoch_summary.payload_attestation_misses,
+                                correct = epoch_summary.payload_attestation_correct_votes,
+                                incorrect = epoch_summary.payload_attestation_incorrect_votes,
+                                epoch = %prev_epoch,
+                                validator = id,
+                                "Previous epoch payload attestations"
+                            );
+                        }
+                    }
+                    if epoch_summary.inclusion_list_duties > 0 {
+                        if epoch_summary.inclusion_lists_unsatisfied > 0 {
+                            inclusion_list_unsatisfied.push(id);
+                        }
+                        if self.individual_tracking() {
+                            info!(
+                                duties = epoch_summary.inclusion_list_duties,
+                                seen = epoch_summary.inclusion_lists,
+                                late = epoch_summary.inclusion_lists_late,
+                                satisfied = epoch_summary.inclusion_lists_satisfied,
+                                unsatisfied = epoch_summary.inclusion_lists_unsatisfied,
+                                epoch = %prev_epoch,
+                                validator = id,
+                                "Previous epoch inclusion lists"
+                            );
+                        }
+                    }
+                }
             }
         }
 
@@ -1046,6 +1440,30 @@ impl<E: EthSpec> ValidatorMonitor<E> {
             );
         }
 
+        if !payload_attestation_miss.is_empty() {
+            info!(
+                epoch = %prev_epoch,
+                validators = ?payload_attestation_miss,
+                "Previous epoch payload attestation(s) missing"
+            );
+        }
+
+        if !payload_attestation_incorrect.is_empty() {
+            info!(
+                epoch = %prev_epoch,
+                validators = ?payload_attestation_incorrect,
+                "Previous epoch payload attestation(s) failed to match payload status"
+            );
+        }
+
+        if !inclusion_list_unsatisfied.is_empty() {
+            info!(
+                epoch = %prev_epoch,
+                validators = ?inclusion_list_unsatisfied,
+                "Previous epoch inclusion list(s) not satisfied"
+            );
+        }
+
         Ok(())
     }
 
@@ -1707,6 +2125,298 @@ impl<E: EthSpec> ValidatorMonitor<E> {
         }
     }
 
+    /// Register a payload attestation message received over gossip.
+    pub fn register_gossip_payload_attestation<S: SlotClock>(
+        &self,
+        seen_timestamp: Duration,
+        message: &PayloadAttestationMessage,
+        slot_clock: &S,
+    ) {
+        self.register_payload_attestation("gossip", seen_timestamp, message, slot_clock);
+    }
+
+    /// Register a payload attestation message received over the http api.
+    pub fn register_api_payload_attestation<S: SlotClock>(
+        &self,
+        seen_timestamp: Duration,
+        message: &PayloadAttestationMessage,
+        slot_clock: &S,
+    ) {
+        self.register_payload_attestation("api", seen_timestamp, message, slot_clock);
+    }
+
+    /// Register a payload attestation message.
+    fn register_payload_attestation<S: SlotClock>(
+        &self,
+        src: &str,
+        seen_timestamp: Duration,
+        message: &PayloadAttestationMessage,
+        slot_clock: &S,
+    ) {
+        if let Some(validator) = self.get_validator(message.validator_index) {
+            let id = &validator.id;
+            let data = &message.data;
+
+            let epoch = data.slot.epoch(E::slots_per_epoch());
+            let delay = get_message_delay_ms(
+                seen_timestamp,
+                data.slot,
+                slot_clock.payload_attestation_production_delay(),
+                slot_clock,
+            );
+
+            self.aggregatable_metric(id, |label| {
+                metrics::inc_counter_vec(
+                    &metrics::VALIDATOR_MONITOR_PAYLOAD_ATTESTATIONS_TOTAL,
+                    &[src, label],
+                );
+                metrics::observe_timer_vec(
+                    &metrics::VALIDATOR_MONITOR_PAYLOAD_ATTESTATIONS_DELAY_SECONDS,
+                    &[src, label],
+                    delay,
+                );
+            });
+
+            if self.individual_tracking() {
+                info!(
+                    head = %data.beacon_block_root,
+                    payload_present = data.payload_present,
+                    blob_data_available = data.blob_data_available,
+                    delay_ms = %delay.as_millis(),
+                    %epoch,
+                    slot = %data.slot,
+                    src,
+                    validator = %id,
+                    "Payload attestation"
+                );
+            }
+
+            validator.with_epoch_summary(epoch, |summary| {
+                summary.register_payload_attestation(delay);
+            });
+
+            self.add_payload_attestation_vote(message.validator_index, data);
+        }
+    }
+
+    /// Register that the `payload_attestation` was included in a *valid* `BeaconBlock`.
+    pub fn register_payload_attestation_in_block(
+        &self,
+        block_slot: Slot,
+        payload_attestation: &PayloadAttestation<E>,
+    ) {
+        let data = &payload_attestation.data;
+        let epoch = data.slot.epoch(E::slots_per_epoch());
+
+        let Some(duties) = self.ptc_duties.get(&data.slot) else {
+            return;
+        };
+
+        let participants = duties
+            .iter()
+            .filter(|(_, position)| {
+                payload_attestation
+                    .aggregation_bits
+                    .get(*position)
+                    .unwrap_or(false)
+            })
+            .map(|(validator_index, _)| *validator_index)
+            .unique();
+
+        for validator_index in participants {
+            if let Some(validator) = self.get_validator(validator_index) {
+                let id = &validator.id;
+
+                self.aggregatable_metric(id, |label| {
+                    metrics::inc_counter_vec(
+                        &metrics::VALIDATOR_MONITOR_PAYLOAD_ATTESTATION_IN_BLOCK_TOTAL,
+                        &["block", label],
+                    );
+                });
+
+                if self.individual_tracking() {
+                    info!(
+                        head = %data.beacon_block_root,
+                        payload_present = data.payload_present,
+                        %block_slot,
+                        %epoch,
+                        slot = %data.slot,
+                        validator = %id,
+                        "Payload attestation included in block"
+                    );
+                }
+
+                validator.with_epoch_summary(epoch, |summary| {
+                    summary.register_payload_attestation_block_inclusion();
+                });
+
+                self.add_payload_attestation_vote(validator_index, data);
+            }
+        }
+    }
+
+    /// Remember the first payload attestation seen from `validator_index` for `data.slot`, to be
+    /// compared against the payload status once it is known.
+    fn add_payload_attestation_vote(&self, validator_index: u64, data: &PayloadAttestationData) {
+        self.payload_attestation_votes
+            .write()
+            .entry(data.slot)
+            .or_default()
+            .entry(validator_index)
+            .or_insert(*data);
+    }
+
+    /// Register an inclusion list received over gossip.
+    pub fn register_gossip_inclusion_list<S: SlotClock>(
+        &self,
+        seen_timestamp: Duration,
+        signed_inclusion_list: &SignedInclusionList<E>,
+        is_before_view_freeze_cutoff: bool,
+        slot_clock: &S,
+    ) {
+        self.register_inclusion_list(
+            "gossip",
+            seen_timestamp,
+            signed_inclusion_list,
+            is_before_view_freeze_cutoff,
+            slot_clock,
+        );
+    }
+
+    /// Register an inclusion list received over the http api.
+    pub fn register_api_inclusion_list<S: SlotClock>(
+        &self,
+        seen_timestamp: Duration,
+        signed_inclusion_list: &SignedInclusionList<E>,
+        is_before_view_freeze_cutoff: bool,
+        slot_clock: &S,
+    ) {
+        self.register_inclusion_list(
+            "api",
+            seen_timestamp,
+            signed_inclusion_list,
+            is_before_view_freeze_cutoff,
+            slot_clock,
+        );
+    }
+
+    /// Register an inclusion list.
+    ///
+    /// An inclusion list is on time if it was seen before the view freeze cutoff of its slot, after
+    /// which it no longer constrains the next block.
+    fn register_inclusion_list<S: SlotClock>(
+        &self,
+        src: &str,
+        seen_timestamp: Duration,
+        signed_inclusion_list: &SignedInclusionList<E>,
+        is_before_view_freeze_cutoff: bool,
+        slot_clock: &S,
+    ) {
+        let inclusion_list = &signed_inclusion_list.message;
+
+        if let Some(validator) = self.get_validator(inclusion_list.validator_index) {
+            let id = &validator.id;
+
+            let epoch = inclusion_list.slot.epoch(E::slots_per_epoch());
+            let delay = get_slot_delay_ms(seen_timestamp, inclusion_list.slot, slot_clock);
+
+            self.aggregatable_metric(id, |label| {
+                metrics::inc_counter_vec(
+                    &metrics::VALIDATOR_MONITOR_INCLUSION_LISTS_TOTAL,
+                    &[src, label],
+                );
+                metrics::observe_timer_vec(
+                    &metrics::VALIDATOR_MONITOR_INCLUSION_LISTS_DELAY_SECONDS,
+                    &[src, label],
+                    delay,
+                );
+                if !is_before_view_freeze_cutoff {
+                    metrics::inc_counter_vec(
+                        &metrics::VALIDATOR_MONITOR_INCLUSION_LISTS_LATE_TOTAL,
+                        &[src, label],
+                    );
+                }
+            });
+
+            if self.individual_tracking() {
+                info!(
+                    transactions = inclusion_list.transactions.len(),
+                    on_time = is_before_view_freeze_cutoff,
+                    delay_ms = %delay.as_millis(),
+                    %epoch,
+                    slot = %inclusion_list.slot,
+                    src,
+                    validator = %id,
+                    "Inclusion list"
+                );
+            }
+
+            validator.with_epoch_summary(epoch, |summary| {
+                summary.register_inclusion_list(delay, is_before_view_freeze_cutoff);
+            });
+        }
+    }
+
+    /// Register the `inclusion_list_bits` of the bid in a *valid* `BeaconBlock` at `block_slot`.
+    ///
+    /// The bits cover the inclusion list committee of the previous slot: a set bit means the
+    /// builder committed to satisfying that member's inclusion list.
+    pub fn register_inclusion_list_bits_in_block(
+        &self,
+        block_slot: Slot,
+        inclusion_list_bits: &[bool],
+    ) {
+        let Some(inclusion_list_slot) = block_slot.as_u64().checked_sub(1).map(Slot::new) else {
+            return;
+        };
+        let Some(duties) = self.inclusion_list_duties.get(&inclusion_list_slot) else {
+            return;
+        };
+        let epoch = inclusion_list_slot.epoch(E::slots_per_epoch());
+
+        for validator_index in duties.iter().map(|(i, _)| *i).unique() {
+            if let Some(validator) = self.get_validator(validator_index) {
+                let id = &validator.id;
+
+                let satisfied = duties.iter().any(|(i, position)| {
+                    *i == validator_index
+                        && inclusion_list_bits.get(*position).copied().unwrap_or(false)
+                });
+
+                if satisfied {
+                    self.aggregatable_metric(id, |label| {
+                        metrics::inc_counter_vec(
+                            &metrics::VALIDATOR_MONITOR_INCLUSION_LIST_SATISFIED_TOTAL,
+                            &[label],
+                        );
+                    });
+                } else {
+                    self.aggregatable_metric(id, |label| {
+                        metrics::inc_counter_vec(
+                            &metrics::VALIDATOR_MONITOR_INCLUSION_LIST_UNSATISFIED_TOTAL,
+                            &[label],
+                        );
+                    });
+                }
+
+                if self.individual_tracking() {
+                    info!(
+                        satisfied,
+                        %block_slot,
+                        %epoch,
+                        slot = %inclusion_list_slot,
+                        validator = %id,
+                        "Inclusion list checked against block"
+                    );
+                }
+
+                validator.with_epoch_summary(epoch, |summary| {
+                    summary.register_inclusion_list_satisfaction(satisfied);
+                });
+            }
+        }
+    }
+
     /// Register an exit from the gossip network.
     pub fn register_gossip_voluntary_exit(&self, exit: &VoluntaryExit) {
         self.register_voluntary_exit("gossip", exit);
@@ -2000,6 +2710,60 @@ impl<E: EthSpec> ValidatorMonitor<E> {
                             );
                         });
                     }
+                    /*
+                     * Payload attestations
+                     */
+                    if let Some(delay) = summary.payload_attestation_min_delay {
+                        self.aggregatable_metric(id, |tag| {
+                            metrics::observe_timer_vec(
+                                &metrics::VALIDATOR_MONITOR_PREV_EPOCH_PAYLOAD_ATTESTATIONS_MIN_DELAY_SECONDS,
+                                &[tag],
+                                delay,
+                            );
+                        });
+                    }
+                    if self.individual_tracking() {
+                        metrics::set_gauge_vec(
+                            &metrics::VALIDATOR_MONITOR_PREV_EPOCH_PTC_DUTIES,
+                            &[id],
+                            summary.ptc_duties as i64,
+                        );
+                        metrics::set_gauge_vec(
+                            &metrics::VALIDATOR_MONITOR_PREV_EPOCH_PAYLOAD_ATTESTATIONS_TOTAL,
+                            &[id],
+                            summary.payload_attestations as i64,
+                        );
+                        metrics::set_gauge_vec(
+                            &metrics::VALIDATOR_MONITOR_PREV_EPOCH_PAYLOAD_ATTESTATION_BLOCK_INCLUSIONS,
+                            &[id],
+                            summary.payload_attestation_block_inclusions as i64,
+                        );
+                        metrics::set_gauge_vec(
+                            &metrics::VALIDATOR_MONITOR_PREV_EPOCH_PAYLOAD_ATTESTATION_CORRECT_VOTES,
+                            &[id],
+                            summary.payload_attestation_correct_votes as i64,
+                        );
+                    }
+                    /*
+                     * Inclusion lists
+                     */
+                    if self.individual_tracking() {
+                        metrics::set_gauge_vec(
+                            &metrics::VALIDATOR_MONITOR_PREV_EPOCH_INCLUSION_LIST_DUTIES,
+                            &[id],
+                            summary.inclusion_list_duties as i64,
+                        );
+                        metrics::set_gauge_vec(
+                            &metrics::VALIDATOR_MONITOR_PREV_EPOCH_INCLUSION_LISTS_TOTAL,
+                            &[id],
+                            summary.inclusion_lists as i64,
+                        );
+                        metrics::set_gauge_vec(
+                            &metrics::VALIDATOR_MONITOR_PREV_EPOCH_INCLUSION_LISTS_SATISFIED,
+                            &[id],
+                            summary.inclusion_lists_satisfied as i64,
+                        );
+                    }
                     /*
                      * Other
                      */
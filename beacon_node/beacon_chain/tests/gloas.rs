@@ -15,6 +15,7 @@ use beacon_chain::AvailabilityProcessingStatus;
 use beacon_chain::BeaconChainError;
 use beacon_chain::BlockError;
 use beacon_chain::ChainConfig;
+use beacon_chain::NotifyExecutionLayer;
 use beacon_chain::execution_payload::{
     NotifyExecutionLayer, PayloadNotifier, validate_execution_payload_for_gossip,
 };
@@ -27,6 +28,8 @@ use beacon_chain::test_utils::{
     AttestationStrategy, BeaconChainHarness, DEFAULT_ETH1_BLOCK_HASH, EphemeralHarnessType,
     HARNESS_GENESIS_TIME, InteropGenesisBuilder,
 };
+use beacon_chain::validator_monitor::{ValidatorMonitorConfig, timestamp_now};
+use eth2::vibehouse::BuilderReliability;
 use execution_layer::test_utils::generate_genesis_header;
 use fork_choice::{
     ExecutionStatus, ForkChoiceStore, ForkchoiceUpdateParameters, InvalidationOperation,
@@ -562,6 +565,170 @@ async fn gloas_validator_ptc_duties_future_epoch() {
     }
 }
 
+/// A Gloas harness whose validator monitor tracks every validator.
+fn gloas_harness_monitoring_all_validators() -> BeaconChainHarness<EphemeralHarnessType<E>> {
+    let mut spec = E::default_spec();
+    spec.altair_fork_epoch = Some(Epoch::new(0));
+    spec.bellatrix_fork_epoch = Some(Epoch::new(0));
+    spec.capella_fork_epoch = Some(Epoch::new(0));
+    spec.deneb_fork_epoch = Some(Epoch::new(0));
+    spec.electra_fork_epoch = Some(Epoch::new(0));
+    spec.fulu_fork_epoch = Some(Epoch::new(0));
+    spec.gloas_fork_epoch = Some(Epoch::new(0));
+
+    let keypairs = types::test_utils::generate_deterministic_keypairs(VALIDATOR_COUNT);
+    let harness = BeaconChainHarness::builder(E::default())
+        .spec(spec.into())
+        .keypairs(keypairs.clone())
+        .fresh_ephemeral_store()
+        .mock_execution_layer()
+        .validator_monitor_config(ValidatorMonitorConfig {
+            validators: keypairs.iter().map(|kp| kp.pk.compress()).collect(),
+            ..<_>::default()
+        })
+        .build();
+    harness.advance_slot();
+    harness
+}
+
+/// Returns the number of PTC duties of the monitored validators in `epoch`, and the number of
+/// them which were checked as a correct vote, an incorrect vote or a miss.
+fn ptc_duty_outcomes(
+    harness: &BeaconChainHarness<EphemeralHarnessType<E>>,
+    epoch: Epoch,
+) -> (usize, usize) {
+    let validator_monitor = harness.chain.validator_monitor.read();
+    let mut duties = 0;
+    let mut outcomes = 0;
+    for index in 0..VALIDATOR_COUNT as u64 {
+        let summaries = validator_monitor
+            .get_monitored_validator(index)
+            .unwrap()
+            .summaries
+            .read();
+        let Some(summary) = summaries.get(&epoch) else {
+            continue;
+        };
+        duties += summary.ptc_duties;
+        outcomes += summary.payload_attestation_correct_votes
+            + summary.payload_attestation_incorrect_votes
+            + summary.payload_attestation_misses;
+    }
+    (duties, outcomes)
+}
+
+/// Returns the number of distinct PTC members at `slot`.
+fn ptc_size(
+    harness: &BeaconChainHarness<EphemeralHarnessType<E>>,
+    state: &BeaconState<E>,
+    slot: Slot,
+) -> usize {
+    get_ptc_committee(state, slot, &harness.spec)
+        .unwrap()
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>()
+        .len()
+}
+
+/// Test that the validator monitor records PTC duties and checks payload attestations of
+/// monitored validators against the eventual payload status.
+#[tokio::test]
+async fn gloas_validator_monitor_tracks_ptc_duties() {
+    let harness = gloas_harness_monitoring_all_validators();
+
+    let slots_per_epoch = E::slots_per_epoch();
+    let duty_epoch = Epoch::new(1);
+    Box::pin(harness.extend_slots(slots_per_epoch as usize + 1)).await;
+
+    // Submit a payload attestation for the head block from one of its PTC members.
+    let head = harness.chain.head_snapshot();
+    let head_slot = head.beacon_block.slot();
+    let ptc = get_ptc_committee(&head.beacon_state, head_slot, &harness.spec).unwrap();
+    let voter = ptc[0];
+    let message = PayloadAttestationMessage {
+        validator_index: voter,
+        data: PayloadAttestationData {
+            beacon_block_root: head.beacon_block_root,
+            slot: head_slot,
+            payload_present: true,
+            blob_data_available: true,
+        },
+        signature: Signature::empty(),
+    };
+    harness
+        .chain
+        .validator_monitor
+        .read()
+        .register_api_payload_attestation(timestamp_now(), &message, &harness.chain.slot_clock);
+
+    // Advance far enough for every slot of the duty epoch to be checked.
+    Box::pin(harness.extend_slots(slots_per_epoch as usize)).await;
+
+    let head_state = harness.chain.head_beacon_state_cloned();
+    let expected_duties: usize = duty_epoch
+        .slot_iter(slots_per_epoch)
+        .map(|slot| ptc_size(&harness, &head_state, slot))
+        .sum();
+
+    {
+        let validator_monitor = harness.chain.validator_monitor.read();
+        let summaries = validator_monitor
+            .get_monitored_validator(voter)
+            .unwrap()
+            .summaries
+            .read();
+        let summary = summaries.get(&duty_epoch).unwrap();
+        assert_eq!(summary.payload_attestations, 1);
+        assert_eq!(summary.payload_attestation_correct_votes, 1);
+        assert_eq!(summary.payload_attestation_incorrect_votes, 0);
+    }
+
+    let (duties, outcomes) = ptc_duty_outcomes(&harness, duty_epoch);
+    assert_eq!(duties, expected_duties);
+    assert_eq!(
+        outcomes, duties,
+        "every PTC duty should be checked exactly once"
+    );
+}
+
+/// Test that PTC duties at a skipped slot are not counted as missed payload attestations.
+#[tokio::test]
+async fn gloas_validator_monitor_ignores_ptc_duties_at_skipped_slot() {
+    let harness = gloas_harness_monitoring_all_validators();
+
+    let slots_per_epoch = E::slots_per_epoch();
+    let duty_epoch = Epoch::new(1);
+    Box::pin(harness.extend_slots(slots_per_epoch as usize + 1)).await;
+
+    // Skip the next slot of the duty epoch.
+    let skipped_slot = harness.chain.head_snapshot().beacon_block.slot() + 1;
+    assert_eq!(skipped_slot.epoch(slots_per_epoch), duty_epoch);
+    harness.advance_slot();
+    harness.advance_slot();
+
+    // Advance far enough for every slot of the duty epoch to be checked.
+    Box::pin(harness.extend_slots(slots_per_epoch as usize)).await;
+
+    let head_state = harness.chain.head_beacon_state_cloned();
+    assert_eq!(
+        *head_state.get_block_root(skipped_slot).unwrap(),
+        *head_state.get_block_root(skipped_slot - 1).unwrap(),
+        "no block should be proposed at the skipped slot"
+    );
+    let expected_duties: usize = duty_epoch
+        .slot_iter(slots_per_epoch)
+        .map(|slot| ptc_size(&harness, &head_state, slot))
+        .sum();
+
+    let (duties, outcomes) = ptc_duty_outcomes(&harness, duty_epoch);
+    assert_eq!(duties, expected_duties);
+    assert_eq!(
+        outcomes,
+        duties - ptc_size(&harness, &head_state, skipped_slot),
+        "only the PTC duties at slots with a block should be checked"
+    );
+}
+
 /// Test that get_payload_attestation_data returns correct data for the head slot.
 #[tokio::test]
 async fn gloas_payload_attestation_data_head_slot() {
@@ -4897,6 +5064,95 @@ async fn gloas_stateless_duplicate_subnet_proofs_deduped() {
     );
 }
 
+/// Verified proofs are persisted so they can be served over `ExecutionProofsByRoot` and survive
+/// restarts.
+#[tokio::test]
+async fn gloas_stateless_verified_proof_persisted() {
+    let harness = gloas_stateless_harness(1);
+    let (block_root, block_hash) = import_blocks_into_stateless(&harness, 3).await;
+
+    assert!(
+        harness
+            .chain
+            .store
+            .get_execution_proofs(&block_root)
+            .unwrap()
+            .is_empty()
+    );
+
+    let proof = make_stub_execution_proof(block_root, block_hash);
+    let subnet_id = ExecutionProofSubnetId::new(0).unwrap();
+    let verified = harness
+        .chain
+        .verify_execution_proof_for_gossip(proof.clone(), subnet_id)
+        .expect("proof should pass gossip verification");
+
+    let head_slot = harness.chain.head_snapshot().beacon_block.slot();
+    harness
+        .chain
+        .check_gossip_execution_proof_availability_and_import(head_slot, block_root, verified)
+        .await
+        .expect("should not error");
+
+    let stored = harness
+        .chain
+        .store
+        .get_execution_proofs(&block_root)
+        .unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0], proof);
+}
+
+/// `execution_proofs_missing` reports blocks below the threshold, and re-seeds the tracker from
+/// persisted proofs (e.g. after a restart) to mark the block execution-valid.
+#[tokio::test]
+async fn gloas_stateless_execution_proofs_missing_uses_stored_proofs() {
+    let harness = gloas_stateless_harness(1);
+    let (block_root, block_hash) = import_blocks_into_stateless(&harness, 3).await;
+
+    assert!(harness.chain.execution_proofs_missing(block_root));
+
+    let proof = make_stub_execution_proof(block_root, block_hash);
+    harness.chain.store.put_execution_proof(&proof).unwrap();
+
+    assert!(!harness.chain.execution_proofs_missing(block_root));
+
+    let fc = harness.chain.canonical_head.fork_choice_read_lock();
+    let exec_status = fc
+        .get_block_execution_status(&block_root)
+        .expect("block should be in fork choice");
+    assert!(
+        exec_status.is_valid_or_irrelevant(),
+        "stored proofs should mark the block execution-valid, got {exec_status:?}"
+    );
+}
+
+/// `execution_proofs_missing` only tracks blocks which still need proofs.
+#[tokio::test]
+async fn gloas_stateless_execution_proofs_missing_ignores_blocks_without_proofs() {
+    let harness = gloas_stateless_harness(1);
+    let (block_root, block_hash) = import_blocks_into_stateless(&harness, 3).await;
+
+    // Unknown blocks don't need proofs.
+    let unknown_root = Hash256::repeat_byte(0xaa);
+    assert!(!harness.chain.execution_proofs_missing(unknown_root));
+    assert!(
+        !harness
+            .chain
+            .execution_proof_tracker
+            .lock()
+            .contains_key(&unknown_root)
+    );
+
+    // Nor do blocks whose payload is already valid.
+    let proof = make_stub_execution_proof(block_root, block_hash);
+    harness.chain.store.put_execution_proof(&proof).unwrap();
+    assert!(!harness.chain.execution_proofs_missing(block_root));
+    harness.chain.execution_proof_tracker.lock().clear();
+    assert!(!harness.chain.execution_proofs_missing(block_root));
+    assert!(harness.chain.execution_proof_tracker.lock().is_empty());
+}
+
 /// `process_pending_execution_proofs` is a no-op when `stateless_validation = false`.
 /// This ensures standard (non-stateless) nodes don't accidentally trigger the proof path.
 #[tokio::test]
@@ -9046,6 +9302,77 @@ async fn gloas_external_bid_block_import_payload_unrevealed() {
     // IS in fork choice and has the correct execution status.
 }
 
+/// The builder reliability counters record a gossip bid, the block which won it once imported,
+/// and the withheld payload once the slot has ended. They remain queryable after being persisted.
+#[tokio::test]
+async fn gloas_builder_reliability_records_withheld_payload() {
+    let harness = gloas_harness_with_builders(&[(0, 10_000_000_000)]);
+    Box::pin(harness.extend_slots(64)).await;
+
+    let head = harness.chain.head_snapshot();
+    let head_root = head.beacon_block_root;
+    let next_slot = head.beacon_block.slot() + 1;
+    let epoch = next_slot.epoch(E::slots_per_epoch());
+    let state = harness.chain.head_beacon_state_cloned();
+
+    // Receive the bid via gossip.
+    insert_bid_proposer_preferences(&harness, next_slot);
+    let bid = make_external_bid(&state, head_root, next_slot, 0, 5000);
+    let verified_bid = harness
+        .chain
+        .verify_execution_bid_for_gossip(bid.into())
+        .expect("bid should pass gossip verification");
+    harness.chain.import_execution_bid(&verified_bid);
+
+    // Produce and import a block using the bid.
+    harness.advance_slot();
+    let ((signed_block, blobs), _state, _envelope) =
+        harness.make_block_with_envelope(state, next_slot).await;
+    let block_root = signed_block.canonical_root();
+    harness
+        .process_block(next_slot, block_root, (signed_block, blobs))
+        .await
+        .expect("should import block with external bid");
+
+    // The bid counts as won as soon as the block is canonical, before the PTC's verdict.
+    assert_eq!(
+        harness.chain.get_builder_reliability(epoch, epoch).unwrap(),
+        vec![BuilderReliability {
+            bids_seen: 1,
+            bids_won: 1,
+            ..BuilderReliability::new(0)
+        }]
+    );
+
+    // End the slot without the builder revealing its envelope.
+    harness.advance_slot();
+    harness.chain.per_slot_task().await;
+
+    let expected = vec![BuilderReliability {
+        bids_seen: 1,
+        bids_won: 1,
+        payloads_withheld: 1,
+        ..BuilderReliability::new(0)
+    }];
+    assert_eq!(
+        harness.chain.get_builder_reliability(epoch, epoch).unwrap(),
+        expected
+    );
+
+    harness.chain.persist_builder_reliability().unwrap();
+    assert_eq!(
+        harness.chain.get_builder_reliability(epoch, epoch).unwrap(),
+        expected
+    );
+    assert!(
+        harness
+            .chain
+            .get_builder_reliability(epoch + 1, epoch + 1)
+            .unwrap()
+            .is_empty()
+    );
+}
+
 /// Test that a block produced with an external bid can coexist in fork choice with
 /// the previous self-build block, and both have correct builder_index values.
 /// This verifies the block import pipeline correctly handles external bids
@@ -11320,6 +11647,200 @@ async fn gloas_external_builder_envelope_buffered_then_processed() {
 // Proposer preferences pool behavior
 // =============================================================================
 
+/// Test that `insert_proposer_preferences` registers a `proposer_preferences` event for the
+/// first preferences of a slot only.
+#[tokio::test]
+async fn gloas_proposer_preferences_event_on_insert() {
+    let harness = gloas_harness_at_epoch(0);
+    Box::pin(harness.extend_slots(1)).await;
+
+    let event_handler = harness.chain.event_handler.as_ref().unwrap();
+    let mut preferences_event_receiver = event_handler.subscribe_proposer_preferences();
+
+    let current_slot = harness.chain.slot().expect("should have slot");
+    let prefs = SignedProposerPreferences {
+        message: ProposerPreferences {
+            proposal_slot: current_slot.as_u64(),
+            validator_index: 0,
+            fee_recipient: Address::repeat_byte(0xAA),
+            gas_limit: 30_000_000,
+        },
+        signature: Signature::empty(),
+    };
+
+    assert!(harness.chain.insert_proposer_preferences(prefs.clone()));
+    assert_eq!(
+        preferences_event_receiver.try_recv().unwrap(),
+        eth2::types::EventKind::ProposerPreferences(Box::new(prefs.clone()))
+    );
+
+    // A duplicate for the same slot is not inserted and must not be published.
+    assert!(!harness.chain.insert_proposer_preferences(prefs));
+    assert!(preferences_event_receiver.try_recv().is_err());
+}
+
+/// Test that the per-slot task registers the PTC's verdict on the previous slot's blocks.
+#[tokio::test]
+async fn gloas_payload_attestation_outcome_event_after_slot() {
+    let harness = gloas_harness_at_epoch(0);
+    Box::pin(harness.extend_slots(2)).await;
+
+    let event_handler = harness.chain.event_handler.as_ref().unwrap();
+    let mut outcome_event_receiver = event_handler.subscribe_payload_attestation_outcome();
+
+    let head = harness.chain.canonical_head.cached_head();
+    let head_root = head.head_block_root();
+    let head_slot = head.head_slot();
+    let outcome = harness
+        .chain
+        .canonical_head
+        .fork_choice_read_lock()
+        .get_payload_attestation_outcome(&head_root, &harness.chain.spec)
+        .expect("head block is in fork choice");
+
+    harness.advance_slot();
+    harness.chain.per_slot_task().await;
+
+    assert_eq!(
+        outcome_event_receiver.try_recv().unwrap(),
+        eth2::types::EventKind::PayloadAttestationOutcome(
+            eth2::types::SsePayloadAttestationOutcome {
+                slot: head_slot,
+                beacon_block_root: head_root,
+                payload_present: outcome.payload_present,
+                blob_data_available: outcome.blob_data_available,
+                payload_present_votes: outcome.payload_present_votes,
+                blob_data_available_votes: outcome.blob_data_available_votes,
+            }
+        )
+    );
+}
+
+/// Test that a block imported after its slot ended registers the PTC's verdict once its child,
+/// which carries the PTC's votes, has been imported. The per-slot task for its slot has already
+/// run.
+#[tokio::test]
+async fn gloas_payload_attestation_outcome_event_on_late_import() {
+    let harness = gloas_harness_at_epoch(0);
+    Box::pin(harness.extend_slots(2)).await;
+
+    harness.advance_slot();
+    let head_state = harness.chain.head_beacon_state_cloned();
+    let block_slot = head_state.slot() + 1;
+    let ((block, _), block_state) = harness.make_block(head_state, block_slot).await;
+    let block_root = block.canonical_root();
+
+    // Every PTC member votes for the block. The votes only reach fork choice through the child.
+    let data = PayloadAttestationData {
+        beacon_block_root: block_root,
+        slot: block_slot,
+        payload_present: true,
+        blob_data_available: true,
+    };
+    let ptc = get_ptc_committee::<E>(&block_state, block_slot, &harness.spec).unwrap();
+    let mut aggregation_bits = BitVector::default();
+    let mut signature = AggregateSignature::infinity();
+    for (position, validator_index) in ptc.iter().enumerate() {
+        aggregation_bits.set(position, true).unwrap();
+        signature.add_assign(&sign_payload_attestation_data(
+            &data,
+            *validator_index as usize,
+            &block_state,
+            &harness.spec,
+        ));
+    }
+    harness
+        .chain
+        .insert_payload_attestation_to_pool(PayloadAttestation {
+            aggregation_bits,
+            data,
+            signature,
+        });
+
+    let child_slot = block_slot + 1;
+    let ((child, _), _) = harness.make_block(block_state, child_slot).await;
+    let child_root = child.canonical_root();
+    assert!(
+        !child
+            .message()
+            .body()
+            .payload_attestations()
+            .unwrap()
+            .is_empty(),
+        "the child should carry the PTC's votes"
+    );
+
+    let event_handler = harness.chain.event_handler.as_ref().unwrap();
+    let mut outcome_event_receiver = event_handler.subscribe_payload_attestation_outcome();
+
+    // Import both blocks once their slots have ended.
+    harness.set_current_slot(child_slot + 1);
+    for (root, block) in [(block_root, block), (child_root, child)] {
+        let rpc_block = harness
+            .build_rpc_block_from_blobs(root, block, None)
+            .unwrap();
+        harness
+            .chain
+            .process_block(
+                root,
+                rpc_block,
+                NotifyExecutionLayer::Yes,
+                BlockImportSource::Gossip,
+                || Ok(()),
+            )
+            .await
+            .expect("late block should import");
+
+        if root == block_root {
+            assert!(
+                outcome_event_receiver.try_recv().is_err(),
+                "the verdict is not known before the child is imported"
+            );
+        }
+    }
+
+    let outcome = harness
+        .chain
+        .canonical_head
+        .fork_choice_read_lock()
+        .get_payload_attestation_outcome(&block_root, &harness.chain.spec)
+        .unwrap();
+    assert!(outcome.payload_present_votes > 0);
+    assert_eq!(
+        outcome_event_receiver.try_recv().unwrap(),
+        eth2::types::EventKind::PayloadAttestationOutcome(
+            eth2::types::SsePayloadAttestationOutcome {
+                slot: block_slot,
+                beacon_block_root: block_root,
+                payload_present: outcome.payload_present,
+                blob_data_available: outcome.blob_data_available,
+                payload_present_votes: outcome.payload_present_votes,
+                blob_data_available_votes: outcome.blob_data_available_votes,
+            }
+        )
+    );
+    assert!(outcome_event_receiver.try_recv().is_err());
+
+    // A block imported during its own slot registers the verdict on its late parent, and leaves
+    // its own verdict to the per-slot task.
+    let head_state = harness.chain.head_beacon_state_cloned();
+    let next_slot = harness.chain.slot().unwrap();
+    let ((block, _), _) = harness.make_block(head_state, next_slot).await;
+    let next_block_root = block.canonical_root();
+    harness
+        .process_block(next_slot, next_block_root, (block, None))
+        .await
+        .expect("timely block should import");
+    match outcome_event_receiver.try_recv().unwrap() {
+        eth2::types::EventKind::PayloadAttestationOutcome(outcome) => {
+            assert_eq!(outcome.beacon_block_root, child_root);
+            assert_eq!(outcome.slot, child_slot);
+        }
+        other => panic!("unexpected event {other:?}"),
+    }
+    assert!(outcome_event_receiver.try_recv().is_err());
+}
+
 /// Test that `insert_proposer_preferences` rejects a duplicate slot and that
 /// preferences older than 2 epochs are pruned from the pool.
 ///
@@ -11909,6 +12430,7 @@ async fn gloas_cross_epoch_withdrawal_uses_advanced_state() {
         head_hash: None,
         justified_hash: None,
         finalized_hash: None,
+        confirmed_hash: None,
     };
 
     // Request withdrawals for epoch 1, slot 0 (the first slot of the next epoch)
@@ -17,6 +17,7 @@ use beacon_chain::test_utils::{
 };
 use execution_layer::test_utils::generate_genesis_header;
 use std::sync::{Arc, LazyLock};
+use tree_hash::TreeHash;
 use types::*;
 
 type E = MainnetEthSpec;
@@ -581,6 +582,122 @@ async fn bid_equivocation_via_gossip_path() {
     );
 }
 
+/// A bid verified without observation (as the HTTP API does) must be refused as an equivocation
+/// when a conflicting bid from the same builder is observed before it, and when its builder
+/// equivocates after it was first observed.
+#[tokio::test]
+async fn bid_equivocation_checked_at_observation() {
+    let harness = gloas_harness_with_builders(BLOCKS_TO_FINALIZE, &[(0, 2_000_000_000)]).await;
+    let current_slot = harness.chain.slot().unwrap();
+    let spec = &harness.chain.spec;
+
+    let head = harness.chain.head_snapshot();
+    let head_root = head.beacon_block_root;
+    let state = &head.beacon_state;
+
+    let domain = spec.get_domain(
+        current_slot.epoch(E::slots_per_epoch()),
+        Domain::BeaconBuilder,
+        &state.fork(),
+        state.genesis_validators_root(),
+    );
+    let sign_bid = |bid_msg: ExecutionPayloadBidGloas<E>| {
+        let signature = BUILDER_KEYPAIRS[0].sk.sign(bid_msg.signing_root(domain));
+        SignedExecutionPayloadBidGloas {
+            message: bid_msg,
+            signature,
+        }
+    };
+
+    let bid_1 = sign_bid(ExecutionPayloadBidGloas::<E> {
+        slot: current_slot,
+        execution_payment: 1,
+        builder_index: 0,
+        value: 100,
+        parent_block_root: head_root,
+        ..Default::default()
+    });
+    let bid_2 = sign_bid(ExecutionPayloadBidGloas::<E> {
+        slot: current_slot,
+        execution_payment: 2,
+        builder_index: 0,
+        value: 200,
+        parent_block_root: head_root,
+        ..Default::default()
+    });
+    let bid_1_root = bid_1.tree_hash_root();
+    let bid_2_root = bid_2.tree_hash_root();
+
+    insert_preferences_for_bid(
+        &harness.chain,
+        current_slot,
+        bid_1.message.fee_recipient,
+        bid_1.message.gas_limit,
+    );
+
+    // Bid 2 passes verification, then bid 1 is observed via gossip before bid 2 is observed.
+    let verified_bid_2 = harness
+        .chain
+        .verify_execution_bid_for_gossip_without_observation(bid_2.clone().into())
+        .expect("bid 2 should pass verification");
+    let result = harness
+        .chain
+        .verify_execution_bid_for_gossip(bid_1.clone().into());
+    assert!(result.is_ok(), "bid 1 should pass: {:?}", result.err());
+
+    let err = unwrap_err(
+        harness
+            .chain
+            .observe_execution_bid_unless_equivocating(&verified_bid_2),
+        "should refuse bid 2 after conflicting bid 1 was observed",
+    );
+    assert!(
+        matches!(
+            err,
+            ExecutionBidError::BuilderEquivocation {
+                builder_index: 0,
+                previous_bid_root,
+                new_bid_root,
+                ..
+            } if previous_bid_root == bid_1_root && new_bid_root == bid_2_root
+        ),
+        "expected BuilderEquivocation against bid 1, got {err:?}"
+    );
+
+    // Bid 1 passes verification again, then the builder equivocates via gossip before it is
+    // observed.
+    let verified_bid_1 = harness
+        .chain
+        .verify_execution_bid_for_gossip_without_observation(bid_1.into())
+        .expect("bid 1 should pass verification");
+    let err = unwrap_err(
+        harness.chain.verify_execution_bid_for_gossip(bid_2.into()),
+        "bid 2 should be an equivocation on gossip",
+    );
+    assert!(
+        matches!(err, ExecutionBidError::BuilderEquivocation { .. }),
+        "expected BuilderEquivocation, got {err:?}"
+    );
+
+    let err = unwrap_err(
+        harness
+            .chain
+            .observe_execution_bid_unless_equivocating(&verified_bid_1),
+        "should refuse bid 1 after its builder equivocated",
+    );
+    assert!(
+        matches!(
+            err,
+            ExecutionBidError::BuilderEquivocation {
+                builder_index: 0,
+                previous_bid_root,
+                ..
+            } if previous_bid_root == bid_2_root
+        ),
+        "expected BuilderEquivocation against bid 2, got {err:?}"
+    );
+}
+
 #[tokio::test]
 async fn bid_invalid_parent_root() {
     // Active builder with sufficient balance, bid passes all checks except parent root
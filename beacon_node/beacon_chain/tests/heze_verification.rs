@@ -403,6 +403,29 @@ async fn gossip_il_ignore_equivocator() {
     );
 }
 
+#[tokio::test]
+async fn import_il_registers_event() {
+    let harness = heze_harness(1).await;
+    let current_slot = harness.chain.slot().unwrap();
+    let (committee, committee_root) = committee_info(&harness, current_slot);
+
+    let event_handler = harness.chain.event_handler.as_ref().unwrap();
+    let mut il_event_receiver = event_handler.subscribe_inclusion_list();
+
+    let signed_il =
+        make_valid_signed_il(&harness, current_slot, committee[0], committee_root, vec![]);
+    let verified = harness
+        .chain
+        .verify_inclusion_list_for_gossip(signed_il.clone())
+        .unwrap_or_else(|e| panic!("IL should pass: {e:?}"));
+    harness.chain.import_inclusion_list(verified);
+
+    assert_eq!(
+        il_event_receiver.try_recv().unwrap(),
+        eth2::types::EventKind::InclusionList(Box::new(signed_il))
+    );
+}
+
 // ===========================================================================
 // get_inclusion_lists_by_committee_indices tests
 // ===========================================================================
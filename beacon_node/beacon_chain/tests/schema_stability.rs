@@ -105,9 +105,10 @@ fn check_db_columns() {
     let current_columns: Vec<&'static str> =
         DBColumn::iter().map(store::DBColumn::as_str).collect();
     let expected_columns = vec![
-        "bma", "blk", "blb", "bev", "bdc", "bdi", "ste", "hsd", "hsn", "bsn", "bsd", "bss", "bs3",
-        "bcs", "bst", "exp", "bch", "opo", "etc", "frk", "pkc", "brp", "bsx", "bsr", "bbx", "bbr",
-        "bhr", "brm", "dht", "cus", "otb", "bhs", "olc", "lcu", "scb", "scm", "dmy",
+        "bma", "blk", "blb", "bev", "bdc", "bla", "dca", "bep", "bph", "bdi", "bil", "bpa", "ste",
+        "hsd", "hsn", "bsn", "bsd", "bss", "bs3", "bcs", "bst", "exp", "bch", "opo", "etc", "frk",
+        "pkc", "brp", "bsx", "bsr", "bbx", "bbr", "bhr", "brm", "dht", "cus", "otb", "bhs", "olc",
+        "lcu", "scb", "scm", "brl", "vhi", "dmy",
     ];
     assert_eq!(expected_columns, current_columns);
 }
@@ -39,8 +39,10 @@ use std::sync::{Arc, LazyLock};
 use std::time::Duration;
 use store::database::interface::BeaconNodeBackend;
 use store::metadata::STATE_UPPER_LIMIT_NO_RETAIN;
+use store::snapshot::{SnapshotPaths, SnapshotProgress};
+use store::verify::{DanglingEntry, DanglingReason, Database, Problem, VerifyConfig};
 use store::{
-    BlobInfo, DBColumn, HotColdDB, StoreConfig, StoreOp,
+    BlobInfo, DBColumn, HotColdDB, KeyValueStore, KeyValueStoreOp, StoreConfig, StoreOp,
     hdiff::HierarchyConfig,
     iter::{BlockRootsIterator, StateRootsIterator},
 };
@@ -3247,6 +3249,417 @@ async fn weak_subjectivity_sync_test(
     assert_eq!(store.get_anchor_info().state_upper_limit, Slot::new(0));
 }
 
+/// Export the first eras from a node with full history and use them to fill the block history
+/// of a store which only knows the block at the start of the third era.
+#[tokio::test]
+async fn era_export_and_import() {
+    let slots_per_historical_root = E::slots_per_historical_root() as u64;
+    let anchor_slot = Slot::new(2 * slots_per_historical_root);
+
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (anchor_slot.as_u64() + 8 * E::slots_per_epoch()) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+    assert!(store.get_split_slot() > anchor_slot);
+
+    let era_dir = tempdir().unwrap();
+    for era in 0..=2 {
+        let era_file = store.export_era(era).unwrap();
+        assert_eq!(era_file.era(), era);
+        let bytes = era_file.to_bytes().unwrap();
+        let decoded = store::era::EraFile::<E>::from_bytes(&bytes, &harness.spec).unwrap();
+        assert_eq!(decoded.blocks, era_file.blocks);
+        std::fs::write(
+            era_dir.path().join(era_file.file_name("minimal").unwrap()),
+            bytes,
+        )
+        .unwrap();
+    }
+
+    // Boundary states beyond the split are not exported.
+    assert!(matches!(
+        store.export_era(u64::MAX / slots_per_historical_root),
+        Err(store::Error::Era(store::era::Error::NotFinalized { .. }))
+    ));
+
+    // Initialise a second store whose oldest block is the first block of the third era.
+    let anchor_parent = harness
+        .chain
+        .block_root_at_slot(anchor_slot - 1, WhenSlotSkipped::Prev)
+        .unwrap()
+        .unwrap();
+    let db_path2 = tempdir().unwrap();
+    let store2 = get_store(&db_path2);
+    let anchor_op = store2
+        .init_anchor_info(anchor_parent, anchor_slot, anchor_slot, false)
+        .unwrap();
+    store2.hot_db.do_atomically(vec![anchor_op]).unwrap();
+
+    // Era files carry no execution payload envelopes, so Gloas blocks are only imported once
+    // their envelopes are stored.
+    if harness
+        .spec
+        .fork_name_at_slot::<E>(anchor_slot - 1)
+        .gloas_enabled()
+    {
+        assert_eq!(
+            store2
+                .import_era_dir(era_dir.path(), harness.chain.genesis_block_root, None)
+                .unwrap(),
+            0
+        );
+        assert_eq!(store2.get_oldest_block_slot(), anchor_slot);
+
+        let envelope_ops = (1..anchor_slot.as_u64())
+            .filter_map(|slot| {
+                let block_root = harness
+                    .chain
+                    .block_root_at_slot(Slot::new(slot), WhenSlotSkipped::None)
+                    .unwrap()?;
+                let envelope = store
+                    .hot_db
+                    .get_bytes(DBColumn::BeaconEnvelope, block_root.as_slice())
+                    .unwrap()?;
+                Some(KeyValueStoreOp::PutKeyValue(
+                    DBColumn::BeaconEnvelope,
+                    block_root.as_slice().to_vec(),
+                    envelope,
+                ))
+            })
+            .collect();
+        store2.hot_db.do_atomically(envelope_ops).unwrap();
+    }
+
+    let blocks_imported = store2
+        .import_era_dir(era_dir.path(), harness.chain.genesis_block_root, None)
+        .unwrap();
+    assert_eq!(blocks_imported, anchor_slot.as_usize() - 1);
+    assert_eq!(store2.get_oldest_block_slot(), Slot::new(0));
+    assert_eq!(
+        store2.get_anchor_info().oldest_block_parent,
+        Hash256::zero()
+    );
+
+    for slot in 0..anchor_slot.as_u64() {
+        let slot = Slot::new(slot);
+        let block_root = harness
+            .chain
+            .block_root_at_slot(slot, WhenSlotSkipped::Prev)
+            .unwrap()
+            .unwrap();
+        assert_eq!(store2.get_cold_block_root(slot).unwrap(), Some(block_root));
+        if slot != 0 {
+            assert!(store2.block_exists(&block_root).unwrap());
+        }
+    }
+
+    // Importing again is a no-op once history is complete.
+    assert_eq!(
+        store2
+            .import_era_dir(era_dir.path(), harness.chain.genesis_block_root, None)
+            .unwrap(),
+        0
+    );
+}
+
+/// An era directory with a gap cannot be imported.
+#[tokio::test]
+async fn era_import_rejects_gap() {
+    let slots_per_historical_root = E::slots_per_historical_root() as u64;
+    let anchor_slot = Slot::new(2 * slots_per_historical_root);
+
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (anchor_slot.as_u64() + 8 * E::slots_per_epoch()) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    // Only export the first era, leaving out the era which connects to the anchor.
+    let era_dir = tempdir().unwrap();
+    let era_file = store.export_era(1).unwrap();
+    std::fs::write(
+        era_dir.path().join(era_file.file_name("minimal").unwrap()),
+        era_file.to_bytes().unwrap(),
+    )
+    .unwrap();
+
+    let anchor_parent = harness
+        .chain
+        .block_root_at_slot(anchor_slot - 1, WhenSlotSkipped::Prev)
+        .unwrap()
+        .unwrap();
+    let db_path2 = tempdir().unwrap();
+    let store2 = get_store(&db_path2);
+    let anchor_op = store2
+        .init_anchor_info(anchor_parent, anchor_slot, anchor_slot, false)
+        .unwrap();
+    store2.hot_db.do_atomically(vec![anchor_op]).unwrap();
+
+    assert!(matches!(
+        store2.import_era_dir(era_dir.path(), harness.chain.genesis_block_root, None),
+        Err(store::Error::Era(
+            store::era::Error::MismatchedBlockRoot { .. }
+        ))
+    ));
+    assert_eq!(store2.get_oldest_block_slot(), anchor_slot);
+}
+
+/// A database built by the harness has no problems, and every state stored as a snapshot or diff
+/// is reconstructed.
+#[tokio::test]
+async fn verify_clean_database() {
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (E::slots_per_epoch() * 6) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+    assert!(store.get_split_slot() > 0);
+
+    let report = store.verify(&VerifyConfig::default()).unwrap();
+    assert_eq!(report.problems, vec![]);
+    assert_eq!(report.dangling, vec![]);
+    assert_eq!(report.blocks_checked, E::slots_per_epoch() as usize * 6 + 1);
+    assert!(report.states_reconstructed > 0);
+}
+
+/// Dangling entries are deleted by `delete_dangling`, and missing summaries are reported.
+#[tokio::test]
+async fn verify_repairs_dangling_and_reports_problems() {
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (E::slots_per_epoch() * 6) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+    let config = VerifyConfig {
+        reconstruct_states: false,
+    };
+
+    // Blobs for a block which doesn't exist are dangling, but not a problem.
+    let orphan_root = Hash256::repeat_byte(0xaa);
+    store
+        .blobs_db
+        .put_bytes(DBColumn::BeaconBlob, orphan_root.as_slice(), &[])
+        .unwrap();
+    let report = store.verify(&config).unwrap();
+    assert!(report.is_ok());
+    assert_eq!(
+        report.dangling,
+        vec![DanglingEntry {
+            database: Database::Blobs,
+            column: DBColumn::BeaconBlob,
+            key: orphan_root.as_slice().to_vec(),
+            reason: DanglingReason::OrphanedBlockData,
+        }]
+    );
+
+    store.delete_dangling(&report.dangling).unwrap();
+    assert!(!store.blobs_exist(&orphan_root).unwrap());
+    assert_eq!(store.verify(&config).unwrap().dangling, vec![]);
+
+    // Deleting the summary of the state prior to the head breaks the head state's link.
+    let head_state_root = harness.chain.head_snapshot().beacon_state_root();
+    let previous_state_root = store
+        .load_hot_state_summary(&head_state_root)
+        .unwrap()
+        .unwrap()
+        .previous_state_root;
+    store
+        .hot_db
+        .key_delete(
+            DBColumn::BeaconStateHotSummary,
+            previous_state_root.as_slice(),
+        )
+        .unwrap();
+
+    let report = store.verify(&config).unwrap();
+    assert!(!report.is_ok());
+    assert!(report.problems.contains(&Problem::MissingHotStateSummary {
+        state_root: previous_state_root,
+        referenced_by: head_state_root,
+    }));
+}
+
+/// A corrupt freezer block root is reported, and the canonical block at that slot is neither
+/// classed as abandoned nor deleted on repair.
+#[tokio::test]
+async fn verify_repair_keeps_canonical_block_with_corrupt_freezer_root() {
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (E::slots_per_epoch() * 6) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+    let config = VerifyConfig {
+        reconstruct_states: false,
+    };
+
+    let slot = Slot::new(1);
+    assert!(slot < store.get_split_slot());
+    let block_root = harness
+        .chain
+        .block_root_at_slot(slot, WhenSlotSkipped::None)
+        .unwrap()
+        .unwrap();
+    let corrupt_root = Hash256::repeat_byte(0xbb);
+    store
+        .cold_db
+        .put_bytes(
+            DBColumn::BeaconBlockRoots,
+            &slot.as_u64().to_be_bytes(),
+            corrupt_root.as_slice(),
+        )
+        .unwrap();
+
+    let report = store.verify(&config).unwrap();
+    assert!(
+        report
+            .problems
+            .contains(&Problem::FinalizedBlockRootMismatch {
+                block_root,
+                slot,
+                cold_block_root: Some(corrupt_root),
+            })
+    );
+    assert!(
+        report
+            .dangling
+            .iter()
+            .all(|entry| entry.key != block_root.as_slice())
+    );
+
+    store.delete_dangling(&report.deletable_dangling()).unwrap();
+    assert!(store.block_exists(&block_root).unwrap());
+}
+
+/// A block whose state summary is missing is reported rather than classed as abandoned, and
+/// repair leaves blocks and their data alone while the problem remains.
+#[tokio::test]
+async fn verify_repair_keeps_head_block_without_state_summary() {
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (E::slots_per_epoch() * 6) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+    let config = VerifyConfig {
+        reconstruct_states: false,
+    };
+
+    let head = harness.chain.head_snapshot();
+    let head_block_root = head.beacon_block_root;
+    let head_state_root = head.beacon_state_root();
+    store
+        .hot_db
+        .key_delete(DBColumn::BeaconStateHotSummary, head_state_root.as_slice())
+        .unwrap();
+    // Data of a block which doesn't exist is only deleted once the problem has been fixed.
+    let orphan_root = Hash256::repeat_byte(0xaa);
+    store
+        .blobs_db
+        .put_bytes(DBColumn::BeaconBlob, orphan_root.as_slice(), &[])
+        .unwrap();
+
+    let report = store.verify(&config).unwrap();
+    assert!(
+        report
+            .problems
+            .contains(&Problem::MissingBlockStateSummary {
+                block_root: head_block_root,
+                slot: head.beacon_block.slot(),
+                state_root: head_state_root,
+            })
+    );
+    assert!(
+        report
+            .dangling
+            .iter()
+            .all(|entry| entry.key != head_block_root.as_slice())
+    );
+    assert!(report.deletable_dangling().iter().all(|entry| !matches!(
+        entry.reason,
+        DanglingReason::AbandonedBlock
+            | DanglingReason::OrphanedBlockData
+            | DanglingReason::OrphanedHotStateData
+    )));
+
+    store.delete_dangling(&report.deletable_dangling()).unwrap();
+    assert!(store.block_exists(&head_block_root).unwrap());
+    assert!(store.blobs_exist(&orphan_root).unwrap());
+}
+
+/// A snapshot of a running database can be opened as a database in its own right.
+#[tokio::test]
+async fn snapshot_opens_as_consistent_database() {
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (E::slots_per_epoch() * 6) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    let target_path = tempdir().unwrap();
+    let paths = SnapshotPaths {
+        hot: target_path.path().join("chain_db"),
+        cold: target_path.path().join("freezer_db"),
+        blobs: target_path.path().join("blobs_db"),
+    };
+    let progress = SnapshotProgress::default();
+    let split = store.snapshot(&paths, &progress).unwrap();
+    assert_eq!(split, store.get_split_info());
+    assert!(progress.entries() > 0);
+
+    // Writing to the same location twice is refused.
+    assert!(
+        store
+            .snapshot(&paths, &SnapshotProgress::default())
+            .is_err()
+    );
+
+    let copy = get_store(&target_path);
+    assert_eq!(copy.get_split_info(), split);
+    assert_eq!(copy.get_anchor_info(), store.get_anchor_info());
+    let head_root = harness.head_block_root();
+    assert_eq!(
+        copy.get_blinded_block(&head_root).unwrap(),
+        store.get_blinded_block(&head_root).unwrap()
+    );
+    let report = copy.verify(&VerifyConfig::default()).unwrap();
+    assert_eq!(report.problems, vec![]);
+}
+
 // This test prunes data columns from epoch 0 and then tries to re-import them via
 // the same code paths that custody backfill sync imports data columns
 #[tokio::test]
@@ -3900,6 +4313,296 @@ async fn deneb_prune_blobs_happy_case() {
     check_blob_existence(&harness, oldest_blob_slot, harness.head_slot(), true);
 }
 
+/// Check that blob pruning with an archive moves blobs and data columns into it, from where they
+/// can still be read.
+#[tokio::test]
+async fn prune_blobs_to_archive() {
+    let db_path = tempdir().unwrap();
+    let archive_path = tempdir().unwrap();
+    let store_config = StoreConfig {
+        prune_payloads: false,
+        blob_archive_dir: Some(archive_path.path().to_path_buf()),
+        ..StoreConfig::default()
+    };
+    let store = get_store_generic(&db_path, store_config, test_spec::<E>());
+
+    if store.get_chain_spec().deneb_fork_epoch.is_none() {
+        // No-op prior to Deneb.
+        return;
+    }
+
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (E::slots_per_epoch() * 8) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    let data_availability_boundary = Epoch::new(2);
+    let archived_range = harness
+        .chain
+        .forwards_iter_block_roots_until(
+            Slot::new(0),
+            data_availability_boundary.start_slot(E::slots_per_epoch()) - 1,
+        )
+        .unwrap()
+        .map(|res| {
+            let (block_root, _) = res.unwrap();
+            let blobs = store.get_blobs(&block_root).unwrap().blobs();
+            let data_columns = store.get_data_columns(&block_root).unwrap();
+            (block_root, blobs, data_columns)
+        })
+        .collect::<Vec<_>>();
+
+    store
+        .try_prune_blobs(true, data_availability_boundary)
+        .unwrap();
+
+    let mut sidecars_archived = 0;
+    for (block_root, blobs, data_columns) in archived_range {
+        assert!(!store.blobs_exist(&block_root).unwrap());
+        assert_eq!(
+            store
+                .blobs_db
+                .iter_column_from::<Vec<u8>>(DBColumn::BeaconDataColumn, block_root.as_slice())
+                .filter_map(Result::ok)
+                .filter(|(key, _)| key.starts_with(block_root.as_slice()))
+                .count(),
+            0
+        );
+
+        sidecars_archived += blobs.as_ref().map_or(0, |blobs| blobs.len());
+        sidecars_archived += data_columns.as_ref().map_or(0, |columns| columns.len());
+        assert_eq!(store.get_blobs(&block_root).unwrap().blobs(), blobs);
+        assert_eq!(store.get_data_columns(&block_root).unwrap(), data_columns);
+    }
+
+    let archive_files = std::fs::read_dir(archive_path.path()).unwrap().count();
+    if sidecars_archived > 0 {
+        assert_eq!(archive_files, 1);
+    }
+}
+
+/// Check that the validator history is recorded for each epoch migrated to the freezer.
+#[tokio::test]
+async fn validator_history_recorded_on_migration() {
+    let db_path = tempdir().unwrap();
+    let store_config = StoreConfig {
+        validator_history: true,
+        ..StoreConfig::default()
+    };
+    let store = get_store_generic(&db_path, store_config, test_spec::<E>());
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            (E::slots_per_epoch() * 6) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    let split = store.get_split_info();
+    let split_epoch = split.slot.epoch(E::slots_per_epoch());
+    assert!(split_epoch > 1);
+    let split_state = store
+        .get_state(&split.state_root, Some(split.slot), false)
+        .unwrap()
+        .unwrap();
+
+    for validator_index in [0, LOW_VALIDATOR_COUNT - 1] {
+        let history = store
+            .get_validator_history(validator_index as u64, Epoch::new(0), Epoch::max_value())
+            .unwrap();
+        assert_eq!(
+            history
+                .iter()
+                .map(|summary| summary.epoch)
+                .collect::<Vec<_>>(),
+            (0..split_epoch.as_u64())
+                .map(Epoch::new)
+                .collect::<Vec<_>>()
+        );
+
+        // The latest entry is taken from the split state.
+        let latest = history.last().unwrap();
+        let validator = split_state.get_validator(validator_index).unwrap();
+        assert_eq!(
+            latest.balance,
+            split_state.get_balance(validator_index).unwrap()
+        );
+        assert_eq!(latest.effective_balance, validator.effective_balance);
+        assert!(latest.active && !latest.slashed && !latest.exit_initiated && !latest.exited);
+        if let Ok(participation) = split_state.previous_epoch_participation() {
+            assert_eq!(
+                latest.participation_flags,
+                participation.get(validator_index).unwrap().into_u8()
+            );
+        }
+    }
+
+    // Epoch ranges are inclusive.
+    let history = store
+        .get_validator_history(0, Epoch::new(1), Epoch::new(1))
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].epoch, Epoch::new(1));
+
+    // Unknown validators have no history.
+    assert!(
+        store
+            .get_validator_history(
+                LOW_VALIDATOR_COUNT as u64,
+                Epoch::new(0),
+                Epoch::max_value()
+            )
+            .unwrap()
+            .is_empty()
+    );
+}
+
+/// Check that inclusion lists, payload attestations and execution proofs are retained past
+/// finalization and pruned once they expire.
+#[tokio::test]
+async fn side_objects_retained_until_expiry() {
+    let db_path = tempdir().unwrap();
+    let store_config = StoreConfig {
+        side_object_retention_epochs: 16,
+        ..StoreConfig::default()
+    };
+    let store = get_store_generic(&db_path, store_config, test_spec::<E>());
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+    harness
+        .extend_chain(
+            E::slots_per_epoch() as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    // Attach a proof to an early canonical block, and side objects to its slot.
+    let slot = Slot::new(1);
+    let block_root = harness
+        .chain
+        .block_root_at_slot(slot, WhenSlotSkipped::None)
+        .unwrap()
+        .unwrap();
+    let proof = ExecutionProof::new(
+        block_root,
+        ExecutionBlockHash::repeat_byte(1),
+        ExecutionProofSubnetId::new(0).unwrap(),
+        1,
+        b"proof".to_vec(),
+    );
+    store.put_execution_proof(&proof).unwrap();
+
+    let inclusion_list = SignedInclusionList::<E> {
+        message: InclusionList {
+            slot,
+            validator_index: 3,
+            inclusion_list_committee_root: Hash256::repeat_byte(2),
+            transactions: <_>::default(),
+        },
+        signature: Signature::empty(),
+    };
+    let payload_attestation = PayloadAttestation::<E> {
+        aggregation_bits: BitVector::new(),
+        data: PayloadAttestationData {
+            beacon_block_root: block_root,
+            slot,
+            payload_present: true,
+            blob_data_available: true,
+        },
+        signature: AggregateSignature::empty(),
+    };
+    store
+        .put_inclusion_list_history(slot, vec![inclusion_list.clone()])
+        .unwrap();
+    // Duplicates are ignored.
+    store
+        .put_inclusion_list_history(slot, vec![inclusion_list.clone()])
+        .unwrap();
+    store
+        .put_payload_attestation_history(slot, vec![payload_attestation.clone()])
+        .unwrap();
+
+    harness
+        .extend_chain(
+            (E::slots_per_epoch() * 5) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+    let finalized_epoch = harness.finalized_checkpoint().epoch;
+    assert!(finalized_epoch > 1);
+
+    // The proof was moved out of the hot proofs column on finalization.
+    assert!(store.get_execution_proofs(&block_root).unwrap().is_empty());
+    assert_eq!(
+        store.get_execution_proof_history(slot).unwrap(),
+        vec![proof]
+    );
+    assert_eq!(
+        harness.chain.get_inclusion_list_history(slot).unwrap(),
+        vec![inclusion_list]
+    );
+    assert_eq!(
+        harness.chain.get_payload_attestation_history(slot).unwrap(),
+        vec![payload_attestation]
+    );
+
+    // Everything expires once finalization is far enough ahead.
+    store.prune_side_objects(finalized_epoch + 16).unwrap();
+    assert!(store.get_execution_proof_history(slot).unwrap().is_empty());
+    assert!(store.get_inclusion_list_history(slot).unwrap().is_empty());
+    assert!(
+        store
+            .get_payload_attestation_history(slot)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+/// Check that concurrent writes of side objects for the same slot are all retained.
+#[test]
+fn side_objects_concurrent_writes_for_same_slot() {
+    let db_path = tempdir().unwrap();
+    let store_config = StoreConfig {
+        side_object_retention_epochs: 16,
+        ..StoreConfig::default()
+    };
+    let store = get_store_generic(&db_path, store_config, test_spec::<E>());
+
+    let slot = Slot::new(1);
+    let inclusion_lists = (0..32)
+        .map(|validator_index| SignedInclusionList::<E> {
+            message: InclusionList {
+                slot,
+                validator_index,
+                inclusion_list_committee_root: Hash256::repeat_byte(2),
+                transactions: <_>::default(),
+            },
+            signature: Signature::empty(),
+        })
+        .collect::<Vec<_>>();
+
+    std::thread::scope(|scope| {
+        for inclusion_list in &inclusion_lists {
+            let store = &store;
+            scope.spawn(move || {
+                store
+                    .put_inclusion_list_history(slot, vec![inclusion_list.clone()])
+                    .unwrap();
+            });
+        }
+    });
+
+    let mut stored = store.get_inclusion_list_history(slot).unwrap();
+    stored.sort_by_key(|inclusion_list| inclusion_list.message.validator_index);
+    assert_eq!(stored, inclusion_lists);
+}
+
 /// Check that blob pruning does not prune without finalization.
 #[tokio::test]
 async fn deneb_prune_blobs_no_finalization() {
@@ -340,6 +340,213 @@ async fn finalizes_with_full_participation() {
     );
 }
 
+#[tokio::test]
+async fn fast_confirmation_disabled_by_default() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness
+        .extend_chain(
+            MinimalEthSpec::slots_per_epoch() as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    let cached_head = harness.chain.canonical_head.cached_head();
+    assert_eq!(cached_head.confirmed_root(), None);
+    assert_eq!(
+        cached_head.forkchoice_update_parameters().confirmed_hash,
+        None
+    );
+}
+
+#[tokio::test]
+async fn fast_confirmation_confirms_recent_blocks_with_full_participation() {
+    let num_blocks_produced = MinimalEthSpec::slots_per_epoch() * 5;
+
+    let harness = get_harness_with_config(
+        VALIDATOR_COUNT,
+        ChainConfig {
+            fast_confirmation: true,
+            ..ChainConfig::default()
+        },
+    );
+
+    harness
+        .extend_chain(
+            num_blocks_produced as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    let cached_head = harness.chain.canonical_head.cached_head();
+    let head_root = cached_head.head_block_root();
+    let confirmed_root = cached_head
+        .confirmed_root()
+        .expect("fast confirmation is enabled");
+
+    let fork_choice = harness.chain.canonical_head.fork_choice_read_lock();
+    assert_eq!(fork_choice.confirmed_root(), Some(confirmed_root));
+    assert!(
+        fork_choice.is_descendant(confirmed_root, head_root),
+        "the confirmed block should be canonical"
+    );
+
+    let confirmed_slot = fork_choice.get_block(&confirmed_root).unwrap().slot;
+    let justified_slot = fork_choice
+        .get_block(&fork_choice.justified_checkpoint().root)
+        .unwrap()
+        .slot;
+    assert!(
+        confirmed_slot > justified_slot,
+        "the confirmed block should be ahead of the justified checkpoint"
+    );
+    assert!(
+        confirmed_slot + 2 >= cached_head.head_slot(),
+        "the confirmed block should trail the head by at most two slots"
+    );
+}
+
+fn fast_confirmation_harness() -> BeaconChainHarness<EphemeralHarnessType<MinimalEthSpec>> {
+    get_harness_with_config(
+        VALIDATOR_COUNT,
+        ChainConfig {
+            fast_confirmation: true,
+            ..ChainConfig::default()
+        },
+    )
+}
+
+/// Returns the slots of the confirmed block and of the justified checkpoint.
+fn confirmed_and_justified_slots(
+    harness: &BeaconChainHarness<EphemeralHarnessType<MinimalEthSpec>>,
+) -> (Slot, Slot) {
+    let fork_choice = harness.chain.canonical_head.fork_choice_read_lock();
+    let confirmed_root = fork_choice
+        .confirmed_root()
+        .expect("fast confirmation is enabled");
+    let confirmed_slot = fork_choice.get_block(&confirmed_root).unwrap().slot;
+    let justified_slot = fork_choice
+        .get_block(&fork_choice.justified_checkpoint().root)
+        .unwrap()
+        .slot;
+    (confirmed_slot, justified_slot)
+}
+
+#[tokio::test]
+async fn fast_confirmation_does_not_confirm_with_low_participation() {
+    let harness = fast_confirmation_harness();
+
+    let half_of_validators = (0..VALIDATOR_COUNT / 2).collect::<Vec<_>>();
+    harness
+        .extend_chain(
+            (MinimalEthSpec::slots_per_epoch() * 5) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::SomeValidators(half_of_validators),
+        )
+        .await;
+
+    let (confirmed_slot, justified_slot) = confirmed_and_justified_slots(&harness);
+    assert!(
+        confirmed_slot <= justified_slot,
+        "no block should be confirmed beyond the justified checkpoint"
+    );
+}
+
+#[tokio::test]
+async fn fast_confirmation_ignores_equivocating_validators() {
+    let slots_per_epoch = MinimalEthSpec::slots_per_epoch();
+    let harness = fast_confirmation_harness();
+
+    harness
+        .extend_chain(
+            (slots_per_epoch * 2) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    // A quarter of the validators equivocate. Their votes must not count towards the support of
+    // any block, which leaves too little support to confirm anything.
+    let equivocating_indices = (0..VALIDATOR_COUNT as u64 / 4).collect::<Vec<_>>();
+    let attester_slashing = harness.make_attester_slashing(equivocating_indices);
+    harness
+        .chain
+        .canonical_head
+        .fork_choice_write_lock()
+        .on_attester_slashing(attester_slashing.to_ref());
+
+    harness
+        .extend_chain(
+            (slots_per_epoch * 3) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    let (confirmed_slot, justified_slot) = confirmed_and_justified_slots(&harness);
+    assert!(
+        justified_slot > slots_per_epoch * 2,
+        "the chain should keep justifying"
+    );
+    assert!(
+        confirmed_slot <= justified_slot,
+        "no block should be confirmed beyond the justified checkpoint"
+    );
+}
+
+#[tokio::test]
+async fn fast_confirmation_reverts_after_reorg_of_confirmed_block() {
+    let slots_per_epoch = MinimalEthSpec::slots_per_epoch();
+    let harness = fast_confirmation_harness();
+
+    harness
+        .extend_chain(
+            (slots_per_epoch * 4 + slots_per_epoch / 2) as usize,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    let (confirmed_slot, justified_slot) = confirmed_and_justified_slots(&harness);
+    assert!(confirmed_slot > justified_slot + 1);
+    let confirmed_root = harness
+        .chain
+        .canonical_head
+        .fork_choice_read_lock()
+        .confirmed_root()
+        .unwrap();
+
+    // Fork off the parent of the confirmed block in the next epoch, where every validator can
+    // vote again, so that the fork becomes canonical.
+    let first_fork_slot = (confirmed_slot.epoch(slots_per_epoch) + 1).start_slot(slots_per_epoch);
+    harness
+        .extend_chain(
+            4,
+            BlockStrategy::ForkCanonicalChainAt {
+                previous_slot: confirmed_slot - 1,
+                first_slot: first_fork_slot,
+            },
+            AttestationStrategy::AllValidators,
+        )
+        .await;
+
+    let head_root = harness.chain.canonical_head.cached_head().head_block_root();
+    let fork_choice = harness.chain.canonical_head.fork_choice_read_lock();
+    assert!(
+        !fork_choice.is_descendant(confirmed_root, head_root),
+        "the confirmed block should have been re-orged out"
+    );
+    let new_confirmed_root = fork_choice
+        .confirmed_root()
+        .expect("fast confirmation is enabled");
+    assert!(
+        fork_choice.is_descendant(new_confirmed_root, head_root),
+        "the confirmed block should be canonical after the re-org"
+    );
+}
+
 #[tokio::test]
 async fn finalizes_with_two_thirds_participation() {
     let num_blocks_produced = MinimalEthSpec::slots_per_epoch() * 5;
@@ -136,7 +136,9 @@ pub struct BeaconProcessorQueueLengths {
     dcbroots_queue: usize,
     dcbrange_queue: usize,
     epbroots_queue: usize,
+    epbrange_queue: usize,
     ilbci_queue: usize,
+    eproofs_queue: usize,
     gossip_bls_to_execution_change_queue: usize,
     lc_gossip_finality_update_queue: usize,
     lc_gossip_optimistic_update_queue: usize,
@@ -210,7 +212,9 @@ impl BeaconProcessorQueueLengths {
             dcbroots_queue: 1024,
             dcbrange_queue: 1024,
             epbroots_queue: 1024,
+            epbrange_queue: 1024,
             ilbci_queue: 1024,
+            eproofs_queue: 1024,
             gossip_bls_to_execution_change_queue: 16384,
             lc_gossip_finality_update_queue: 1024,
             lc_gossip_optimistic_update_queue: 1024,
@@ -614,6 +618,8 @@ pub enum Work<E: EthSpec> {
     GossipPayloadAttestation(AsyncFn),
     GossipProposerPreferences(BlockingFn),
     GossipExecutionProof(AsyncFn),
+    /// An RPC-received execution proof requested by sync for a stateless node.
+    RpcExecutionProof(AsyncFn),
     // Heze FOCIL gossip.
     GossipInclusionList(BlockingFn),
     RpcBlock {
@@ -635,7 +641,9 @@ pub enum Work<E: EthSpec> {
     BlobsByRangeRequest(BlockingFn),
     BlobsByRootsRequest(BlockingFn),
     ExecutionPayloadEnvelopesByRootRequest(BlockingFn),
+    ExecutionPayloadEnvelopesByRangeRequest(BlockingFn),
     InclusionListByCommitteeIndicesRequest(BlockingFn),
+    ExecutionProofsByRootRequest(BlockingFn),
     DataColumnsByRootsRequest(BlockingFn),
     DataColumnsByRangeRequest(BlockingFn),
     GossipBlsToExecutionChange(BlockingFn),
@@ -682,6 +690,7 @@ pub enum WorkType {
     GossipPayloadAttestation,
     GossipProposerPreferences,
     GossipExecutionProof,
+    RpcExecutionProof,
     GossipInclusionList,
     RpcBlock,
     RpcBlobs,
@@ -696,7 +705,9 @@ pub enum WorkType {
     BlobsByRangeRequest,
     BlobsByRootsRequest,
     ExecutionPayloadEnvelopesByRootRequest,
+    ExecutionPayloadEnvelopesByRangeRequest,
     InclusionListByCommitteeIndicesRequest,
+    ExecutionProofsByRootRequest,
     DataColumnsByRootsRequest,
     DataColumnsByRangeRequest,
     GossipBlsToExecutionChange,
@@ -740,6 +751,7 @@ impl<E: EthSpec> Work<E> {
             Work::GossipPayloadAttestation(_) => WorkType::GossipPayloadAttestation,
             Work::GossipProposerPreferences(_) => WorkType::GossipProposerPreferences,
             Work::GossipExecutionProof(_) => WorkType::GossipExecutionProof,
+            Work::RpcExecutionProof(_) => WorkType::RpcExecutionProof,
             Work::GossipInclusionList(_) => WorkType::GossipInclusionList,
             Work::GossipBlsToExecutionChange(_) => WorkType::GossipBlsToExecutionChange,
             Work::RpcBlock { .. } => WorkType::RpcBlock,
@@ -757,9 +769,13 @@ impl<E: EthSpec> Work<E> {
             Work::ExecutionPayloadEnvelopesByRootRequest(_) => {
                 WorkType::ExecutionPayloadEnvelopesByRootRequest
             }
+            Work::ExecutionPayloadEnvelopesByRangeRequest(_) => {
+                WorkType::ExecutionPayloadEnvelopesByRangeRequest
+            }
             Work::InclusionListByCommitteeIndicesRequest(_) => {
                 WorkType::InclusionListByCommitteeIndicesRequest
             }
+            Work::ExecutionProofsByRootRequest(_) => WorkType::ExecutionProofsByRootRequest,
             Work::DataColumnsByRootsRequest(_) => WorkType::DataColumnsByRootsRequest,
             Work::DataColumnsByRangeRequest(_) => WorkType::DataColumnsByRangeRequest,
             Work::LightClientBootstrapRequest(_) => WorkType::LightClientBootstrapRequest,
@@ -944,7 +960,9 @@ impl<E: EthSpec> BeaconProcessor<E> {
         let mut dcbroots_queue = FifoQueue::new(queue_lengths.dcbroots_queue);
         let mut dcbrange_queue = FifoQueue::new(queue_lengths.dcbrange_queue);
         let mut epbroots_queue = FifoQueue::new(queue_lengths.epbroots_queue);
+        let mut epbrange_queue = FifoQueue::new(queue_lengths.epbrange_queue);
         let mut ilbci_queue = FifoQueue::new(queue_lengths.ilbci_queue);
+        let mut eproofs_queue = FifoQueue::new(queue_lengths.eproofs_queue);
 
         let mut gossip_bls_to_execution_change_queue =
             FifoQueue::new(queue_lengths.gossip_bls_to_execution_change_queue);
@@ -1276,8 +1294,12 @@ impl<E: EthSpec> BeaconProcessor<E> {
                                 Some(item)
                             } else if let Some(item) = epbroots_queue.pop() {
                                 Some(item)
+                            } else if let Some(item) = epbrange_queue.pop() {
+                                Some(item)
                             } else if let Some(item) = ilbci_queue.pop() {
                                 Some(item)
+                            } else if let Some(item) = eproofs_queue.pop() {
+                                Some(item)
                             // Check slashings after all other consensus messages so we prioritize
                             // following head.
                             //
@@ -1426,7 +1448,7 @@ impl<E: EthSpec> BeaconProcessor<E> {
                             Work::GossipProposerPreferences { .. } => {
                                 gossip_proposer_preferences_queue.push(work, work_id);
                             }
-                            Work::GossipExecutionProof { .. } => {
+                            Work::GossipExecutionProof { .. } | Work::RpcExecutionProof { .. } => {
                                 gossip_execution_proof_queue.push(work, work_id);
                             }
                             Work::GossipInclusionList { .. } => {
@@ -1483,9 +1505,15 @@ impl<E: EthSpec> BeaconProcessor<E> {
                             Work::ExecutionPayloadEnvelopesByRootRequest { .. } => {
                                 epbroots_queue.push(work, work_id);
                             }
+                            Work::ExecutionPayloadEnvelopesByRangeRequest { .. } => {
+                                epbrange_queue.push(work, work_id);
+                            }
                             Work::InclusionListByCommitteeIndicesRequest { .. } => {
                                 ilbci_queue.push(work, work_id);
                             }
+                            Work::ExecutionProofsByRootRequest { .. } => {
+                                eproofs_queue.push(work, work_id);
+                            }
                             Work::DataColumnsByRootsRequest { .. } => {
                                 dcbroots_queue.push(work, work_id);
                             }
@@ -1532,7 +1560,9 @@ impl<E: EthSpec> BeaconProcessor<E> {
                         WorkType::GossipProposerPreferences => {
                             gossip_proposer_preferences_queue.len()
                         }
-                        WorkType::GossipExecutionProof => gossip_execution_proof_queue.len(),
+                        WorkType::GossipExecutionProof | WorkType::RpcExecutionProof => {
+                            gossip_execution_proof_queue.len()
+                        }
                         WorkType::GossipInclusionList => gossip_inclusion_list_queue.len(),
                         WorkType::GossipSyncSignature => sync_message_queue.len(),
                         WorkType::GossipSyncContribution => sync_contribution_queue.len(),
@@ -1556,7 +1586,9 @@ impl<E: EthSpec> BeaconProcessor<E> {
                         WorkType::DataColumnsByRootsRequest => dcbroots_queue.len(),
                         WorkType::DataColumnsByRangeRequest => dcbrange_queue.len(),
                         WorkType::ExecutionPayloadEnvelopesByRootRequest => epbroots_queue.len(),
+                        WorkType::ExecutionPayloadEnvelopesByRangeRequest => epbrange_queue.len(),
                         WorkType::InclusionListByCommitteeIndicesRequest => ilbci_queue.len(),
+                        WorkType::ExecutionProofsByRootRequest => eproofs_queue.len(),
                         WorkType::GossipBlsToExecutionChange => {
                             gossip_bls_to_execution_change_queue.len()
                         }
@@ -1696,7 +1728,9 @@ impl<E: EthSpec> BeaconProcessor<E> {
             | Work::BlobsByRangeRequest(process_fn)
             | Work::BlobsByRootsRequest(process_fn)
             | Work::ExecutionPayloadEnvelopesByRootRequest(process_fn)
+            | Work::ExecutionPayloadEnvelopesByRangeRequest(process_fn)
             | Work::InclusionListByCommitteeIndicesRequest(process_fn)
+            | Work::ExecutionProofsByRootRequest(process_fn)
             | Work::DataColumnsByRootsRequest(process_fn)
             | Work::DataColumnsByRangeRequest(process_fn)
             | Work::GossipVoluntaryExit(process_fn)
@@ -1730,7 +1764,8 @@ impl<E: EthSpec> BeaconProcessor<E> {
             | Work::GossipExecutionPayload(process_fn)
             | Work::RpcPayloadEnvelope(process_fn)
             | Work::GossipPayloadAttestation(process_fn)
-            | Work::GossipExecutionProof(process_fn) => task_spawner.spawn_async(process_fn),
+            | Work::GossipExecutionProof(process_fn)
+            | Work::RpcExecutionProof(process_fn) => task_spawner.spawn_async(process_fn),
             Work::GossipBlock(work)
             | Work::GossipBlobSidecar(work)
             | Work::GossipDataColumnSidecar(work) => task_spawner.spawn_async(async move {
@@ -1,4 +1,5 @@
 use crate::Client;
+use crate::checkpoint_sync;
 use crate::compute_light_client_updates::{
     LIGHT_CLIENT_SERVER_CHANNEL_CAPACITY, compute_light_client_updates,
 };
@@ -21,10 +22,6 @@ use beacon_chain::{Kzg, LightClientProducerEvent};
 use beacon_processor::{BeaconProcessor, BeaconProcessorChannels};
 use beacon_processor::{BeaconProcessorConfig, BeaconProcessorQueueLengths};
 use environment::RuntimeContext;
-use eth2::{
-    BeaconNodeHttpClient, Error as ApiError, Timeouts,
-    types::{BlockId, StateId},
-};
 use execution_layer::ExecutionLayer;
 use execution_layer::test_utils::generate_genesis_header;
 use futures::channel::mpsc::Receiver;
@@ -279,13 +276,16 @@ where
             } => {
                 let execution_payload_header = generate_genesis_header(&spec, true);
                 let keypairs = generate_deterministic_keypairs(validator_count);
-                let genesis_state = interop_genesis_state(
+                let mut genesis_state = interop_genesis_state(
                     &keypairs,
                     genesis_time,
                     Hash256::from(DEFAULT_ETH1_BLOCK_HASH),
                     execution_payload_header,
                     &spec,
                 )?;
+                if config.genesis_builders > 0 {
+                    inject_genesis_builders(&mut genesis_state, config.genesis_builders, &spec)?;
+                }
                 builder.genesis_state(genesis_state)?
             }
             ClientGenesis::GenesisState => {
@@ -381,74 +381,59 @@ where
                     genesis_state,
                 )?
             }
-            ClientGenesis::CheckpointSyncUrl { url } => {
+            ClientGenesis::CheckpointSyncUrl { urls, quorum } => {
                 info!(
-                    remote_url = %url,
+                    remote_urls = ?urls.iter().map(ToString::to_string).collect::<Vec<_>>(),
                     "Starting checkpoint sync"
                 );
                 if config.chain.genesis_backfill {
                     info!("Blocks will be downloaded all the way back to genesis");
                 }
 
-                let remote = BeaconNodeHttpClient::new(
-                    url,
-                    Timeouts::set_all(Duration::from_secs(
-                        config.chain.checkpoint_sync_url_timeout,
-                    )),
-                );
-
-                debug!("Downloading finalized state");
-                let state = remote
-                    .get_debug_beacon_states_ssz::<E>(StateId::Finalized, &spec)
-                    .await
-                    .map_err(|e| format!("Error loading checkpoint state from remote: {e:?}"))?
-                    .ok_or_else(|| "Checkpoint state missing from remote".to_string())?;
-
-                debug!(slot = ?state.slot(), "Downloaded finalized state");
-
-                let finalized_block_slot = state.latest_block_header().slot;
-
-                debug!(block_slot = ?finalized_block_slot,"Downloading finalized block");
-                let block = remote
-                    .get_beacon_blocks_ssz::<E>(BlockId::Slot(finalized_block_slot), &spec)
-                    .await
-                    .map_err(|e| match e {
-                        ApiError::InvalidSsz(e) => format!(
-                            "Unable to parse SSZ: {e:?}. Ensure the checkpoint-sync-url refers to a \
-                            node for the correct network"
-                        ),
-                        e => format!("Error fetching finalized block from remote: {e:?}"),
-                    })?
-                    .ok_or("Finalized block missing from remote, it returned 404")?;
-                let block_root = block.canonical_root();
-
-                debug!("Downloaded finalized block");
+                let timeout = Duration::from_secs(config.chain.checkpoint_sync_url_timeout);
+                let (providers, checkpoint) = match urls.as_slice() {
+                    [url] => (vec![url.clone()], None),
+                    _ => {
+                        let (providers, checkpoint) = checkpoint_sync::agree_on_checkpoint::<E>(
+                            &urls,
+                            quorum,
+                            timeout,
+                            Duration::from_millis(spec.slot_duration_ms),
+                        )
+                        .await?;
+                        (providers, Some(checkpoint))
+                    }
+                };
 
-                // `get_blob_sidecars` API is deprecated from Fulu and may not be supported by all servers
-                let is_before_fulu = !spec
-                    .fork_name_at_slot::<E>(finalized_block_slot)
-                    .fulu_enabled();
-                let blobs = if is_before_fulu && block.message().body().has_blobs() {
-                    debug!("Downloading finalized blobs");
-                    if let Some(response) = remote
-                        .get_blob_sidecars::<E>(BlockId::Root(block_root), None, &spec)
+                // Any provider which reported the agreed checkpoint can serve it, so fall back to
+                // the others if a download fails.
+                let mut anchor = None;
+                let mut errors = vec![];
+                for url in providers {
+                    match checkpoint_sync::download_anchor::<E>(&url, checkpoint, timeout, &spec)
                         .await
-                        .map_err(|e| format!("Error fetching finalized blobs from remote: {e:?}"))?
                     {
-                        debug!("Downloaded finalized blobs");
-                        Some(response.into_data())
-                    } else {
-                        warn!(
-                            block_root = %block_root,
-                            hint = "use a different URL or ask the provider to update",
-                            impact = "db will be slightly corrupt until these blobs are pruned",
-                            "Checkpoint server is missing blobs"
-                        );
-                        None
+                        Ok(downloaded) => {
+                            anchor = Some(downloaded);
+                            break;
+                        }
+                        Err(error) => {
+                            warn!(
+                                remote_url = %url,
+                                %error,
+                                "Failed to download checkpoint from provider"
+                            );
+                            errors.push(format!("{url}: {error}"));
+                        }
                     }
-                } else {
-                    None
-                };
+                }
+                let (state, block, blobs) = anchor.ok_or_else(|| {
+                    format!(
+                        "Unable to download the checkpoint from any provider: {}",
+                        errors.join("; ")
+                    )
+                })?;
+                let block_root = block.canonical_root();
 
                 let genesis_state = genesis_state(&runtime_context, &config).await?;
 
@@ -721,6 +706,9 @@ where
             let state_advance_context = runtime_context.service_context("state_advance".into());
             spawn_state_advance_timer(state_advance_context.executor, beacon_chain.clone());
 
+            // Fill block history from era files in the background, ahead of backfill sync.
+            beacon_chain.spawn_era_import();
+
             if let Some(execution_layer) = beacon_chain.execution_layer.as_ref() {
                 // Only send a head update *after* genesis.
                 if let Ok(current_slot) = beacon_chain.slot() {
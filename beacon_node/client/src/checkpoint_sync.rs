@@ -0,0 +1,334 @@
+//! Checkpoint sync from several remote beacon nodes.
+//!
+//! Each provider is asked for its finalized checkpoint and the root of the state at the start of
+//! the checkpoint epoch. The anchor is only downloaded once at least `quorum` providers report the
+//! same checkpoint, and then only from one of those providers, falling back to the others if a
+//! download fails. The downloaded state and block are checked against the agreed roots.
+use eth2::types::{BlockId, StateId};
+use eth2::{BeaconNodeHttpClient, Error as ApiError, Timeouts};
+use futures::future::join_all;
+use sensitive_url::SensitiveUrl;
+use std::fmt;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+use types::{BeaconState, BlobSidecarList, ChainSpec, Epoch, EthSpec, Hash256, SignedBeaconBlock};
+
+/// Number of times the providers are queried before giving up on a quorum.
+///
+/// Providers which import the same block at slightly different times can briefly disagree about
+/// the finalized checkpoint, so a failed round is retried after `retry_delay`.
+const QUORUM_ATTEMPTS: usize = 3;
+
+/// The anchor state, block and blobs downloaded from a provider.
+pub type Anchor<E> = (
+    BeaconState<E>,
+    SignedBeaconBlock<E>,
+    Option<BlobSidecarList<E>>,
+);
+
+/// A finalized checkpoint as reported by a single provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderCheckpoint {
+    pub epoch: Epoch,
+    pub block_root: Hash256,
+    pub state_root: Hash256,
+}
+
+impl fmt::Display for ProviderCheckpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "epoch {}, block root {:?}, state root {:?}",
+            self.epoch, self.block_root, self.state_root
+        )
+    }
+}
+
+async fn fetch_checkpoint<E: EthSpec>(
+    remote: &BeaconNodeHttpClient,
+) -> Result<ProviderCheckpoint, String> {
+    let finalized = remote
+        .get_beacon_states_finality_checkpoints(StateId::Head)
+        .await
+        .map_err(|e| format!("error fetching finality checkpoints: {e:?}"))?
+        .ok_or("finality checkpoints missing, it returned 404")?
+        .data
+        .finalized;
+    let state_root = remote
+        .get_beacon_states_root(StateId::Slot(
+            finalized.epoch.start_slot(E::slots_per_epoch()),
+        ))
+        .await
+        .map_err(|e| format!("error fetching finalized state root: {e:?}"))?
+        .ok_or("finalized state root missing, it returned 404")?
+        .data
+        .root;
+    Ok(ProviderCheckpoint {
+        epoch: finalized.epoch,
+        block_root: finalized.root,
+        state_root,
+    })
+}
+
+/// Return the checkpoint reported by at least `quorum` of the `responses`, or a report of every
+/// response if there is no such checkpoint or more than one.
+fn tally(
+    responses: &[(SensitiveUrl, Result<ProviderCheckpoint, String>)],
+    quorum: usize,
+) -> Result<ProviderCheckpoint, String> {
+    let mut groups: Vec<(ProviderCheckpoint, usize)> = vec![];
+    for checkpoint in responses.iter().filter_map(|(_, res)| res.as_ref().ok()) {
+        match groups.iter_mut().find(|(group, _)| group == checkpoint) {
+            Some((_, count)) => *count += 1,
+            None => groups.push((*checkpoint, 1)),
+        }
+    }
+
+    let agreed = groups
+        .iter()
+        .filter(|(_, count)| *count >= quorum)
+        .map(|(checkpoint, _)| *checkpoint)
+        .collect::<Vec<_>>();
+    if let [checkpoint] = agreed.as_slice() {
+        return Ok(*checkpoint);
+    }
+
+    let mut report = format!(
+        "Checkpoint sync providers did not agree on a finalized checkpoint, {} of {} must agree:",
+        quorum,
+        responses.len()
+    );
+    for (url, res) in responses {
+        match res {
+            Ok(checkpoint) => report.push_str(&format!("\n  {url}: {checkpoint}")),
+            Err(e) => report.push_str(&format!("\n  {url}: {e}")),
+        }
+    }
+    Err(report)
+}
+
+/// Query every provider in `urls` for its finalized checkpoint and return the checkpoint reported
+/// by at least `quorum` of them, along with the providers which reported it in the order given.
+pub async fn agree_on_checkpoint<E: EthSpec>(
+    urls: &[SensitiveUrl],
+    quorum: usize,
+    timeout: Duration,
+    retry_delay: Duration,
+) -> Result<(Vec<SensitiveUrl>, ProviderCheckpoint), String> {
+    if quorum == 0 || quorum > urls.len() {
+        return Err(format!(
+            "Checkpoint sync quorum must be between 1 and the number of URLs ({}), got {}",
+            urls.len(),
+            quorum
+        ));
+    }
+    let remotes = urls
+        .iter()
+        .map(|url| BeaconNodeHttpClient::new(url.clone(), Timeouts::set_all(timeout)))
+        .collect::<Vec<_>>();
+
+    let mut attempt = 1;
+    loop {
+        let checkpoints = join_all(remotes.iter().map(fetch_checkpoint::<E>)).await;
+        let responses = urls.iter().cloned().zip(checkpoints).collect::<Vec<_>>();
+
+        match tally(&responses, quorum) {
+            Ok(checkpoint) => {
+                let agreeing = responses
+                    .iter()
+                    .filter(|(_, res)| res.as_ref() == Ok(&checkpoint))
+                    .map(|(url, _)| url.clone())
+                    .collect::<Vec<_>>();
+                for (url, res) in &responses {
+                    match res {
+                        Ok(other) if *other == checkpoint => {}
+                        Ok(other) => warn!(
+                            remote_url = %url,
+                            checkpoint = %other,
+                            "Checkpoint sync provider disagrees with quorum"
+                        ),
+                        Err(error) => warn!(
+                            remote_url = %url,
+                            %error,
+                            "Checkpoint sync provider unavailable"
+                        ),
+                    }
+                }
+                info!(
+                    %checkpoint,
+                    quorum,
+                    providers = urls.len(),
+                    agreeing_providers = agreeing.len(),
+                    "Checkpoint sync providers agree"
+                );
+                return Ok((agreeing, checkpoint));
+            }
+            Err(report) if attempt >= QUORUM_ATTEMPTS => return Err(report),
+            Err(report) => {
+                warn!(
+                    attempt,
+                    retry_delay_secs = retry_delay.as_secs(),
+                    "{report}"
+                );
+                tokio::time::sleep(retry_delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Download the anchor state, block and blobs from the provider at `url`.
+///
+/// If the providers agreed on a `checkpoint`, exactly that state is downloaded and the state and
+/// block are checked against its roots. Otherwise the provider's finalized state is used.
+pub async fn download_anchor<E: EthSpec>(
+    url: &SensitiveUrl,
+    checkpoint: Option<ProviderCheckpoint>,
+    timeout: Duration,
+    spec: &ChainSpec,
+) -> Result<Anchor<E>, String> {
+    let remote = BeaconNodeHttpClient::new(url.clone(), Timeouts::set_all(timeout));
+
+    // Download exactly the agreed state, if any, in case finalization has advanced.
+    let state_id = checkpoint.map_or(StateId::Finalized, |checkpoint| {
+        StateId::Root(checkpoint.state_root)
+    });
+    debug!("Downloading finalized state");
+    let mut state = remote
+        .get_debug_beacon_states_ssz::<E>(state_id, spec)
+        .await
+        .map_err(|e| format!("Error loading checkpoint state from remote: {e:?}"))?
+        .ok_or_else(|| "Checkpoint state missing from remote".to_string())?;
+
+    debug!(slot = ?state.slot(), "Downloaded finalized state");
+
+    if let Some(checkpoint) = checkpoint {
+        let state_root = state
+            .canonical_root()
+            .map_err(|e| format!("Unable to hash checkpoint state: {e:?}"))?;
+        if state_root != checkpoint.state_root {
+            return Err(format!(
+                "Checkpoint state from remote has root {state_root:?}, but the \
+                 providers agreed on {:?}",
+                checkpoint.state_root
+            ));
+        }
+    }
+
+    let finalized_block_slot = state.latest_block_header().slot;
+
+    debug!(block_slot = ?finalized_block_slot,"Downloading finalized block");
+    let block = remote
+        .get_beacon_blocks_ssz::<E>(BlockId::Slot(finalized_block_slot), spec)
+        .await
+        .map_err(|e| match e {
+            ApiError::InvalidSsz(e) => format!(
+                "Unable to parse SSZ: {e:?}. Ensure the checkpoint-sync-url refers to a \
+                node for the correct network"
+            ),
+            e => format!("Error fetching finalized block from remote: {e:?}"),
+        })?
+        .ok_or("Finalized block missing from remote, it returned 404")?;
+    let block_root = block.canonical_root();
+    if let Some(checkpoint) = checkpoint
+        && block_root != checkpoint.block_root
+    {
+        return Err(format!(
+            "Checkpoint block from remote has root {block_root:?}, but the providers \
+             agreed on {:?}",
+            checkpoint.block_root
+        ));
+    }
+
+    debug!("Downloaded finalized block");
+
+    // `get_blob_sidecars` API is deprecated from Fulu and may not be supported by all servers
+    let is_before_fulu = !spec
+        .fork_name_at_slot::<E>(finalized_block_slot)
+        .fulu_enabled();
+    let blobs = if is_before_fulu && block.message().body().has_blobs() {
+        debug!("Downloading finalized blobs");
+        if let Some(response) = remote
+            .get_blob_sidecars::<E>(BlockId::Root(block_root), None, spec)
+            .await
+            .map_err(|e| format!("Error fetching finalized blobs from remote: {e:?}"))?
+        {
+            debug!("Downloaded finalized blobs");
+            Some(response.into_data())
+        } else {
+            warn!(
+                block_root = %block_root,
+                hint = "use a different URL or ask the provider to update",
+                impact = "db will be slightly corrupt until these blobs are pruned",
+                "Checkpoint server is missing blobs"
+            );
+            None
+        }
+    } else {
+        None
+    };
+
+    Ok((state, block, blobs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(n: usize) -> SensitiveUrl {
+        SensitiveUrl::parse(&format!("http://provider-{n}.example:5052")).unwrap()
+    }
+
+    fn checkpoint(n: u64) -> ProviderCheckpoint {
+        ProviderCheckpoint {
+            epoch: Epoch::new(n),
+            block_root: Hash256::repeat_byte(n as u8),
+            state_root: Hash256::repeat_byte(n as u8 + 1),
+        }
+    }
+
+    #[test]
+    fn tally_quorum_reached() {
+        let responses = vec![
+            (url(0), Ok(checkpoint(1))),
+            (url(1), Err("timeout".to_string())),
+            (url(2), Ok(checkpoint(1))),
+            (url(3), Ok(checkpoint(2))),
+        ];
+        assert_eq!(tally(&responses, 2), Ok(checkpoint(1)));
+    }
+
+    #[test]
+    fn tally_quorum_not_reached() {
+        let responses = vec![
+            (url(0), Ok(checkpoint(1))),
+            (url(1), Err("timeout".to_string())),
+            (url(2), Ok(checkpoint(2))),
+        ];
+        let report = tally(&responses, 2).unwrap_err();
+        assert!(report.contains("2 of 3 must agree"));
+        assert!(report.contains("timeout"));
+        assert!(report.contains(&checkpoint(2).to_string()));
+    }
+
+    #[test]
+    fn tally_rejects_competing_quorums() {
+        let responses = vec![
+            (url(0), Ok(checkpoint(1))),
+            (url(1), Ok(checkpoint(1))),
+            (url(2), Ok(checkpoint(2))),
+            (url(3), Ok(checkpoint(2))),
+        ];
+        assert!(tally(&responses, 2).is_err());
+        assert!(tally(&responses, 3).is_err());
+    }
+
+    #[test]
+    fn tally_checks_state_root() {
+        let mut other_state = checkpoint(1);
+        other_state.state_root = Hash256::repeat_byte(0xff);
+        let responses = vec![(url(0), Ok(checkpoint(1))), (url(1), Ok(other_state))];
+        assert!(tally(&responses, 2).is_err());
+        assert_eq!(tally(&responses, 1).ok(), None);
+    }
+}
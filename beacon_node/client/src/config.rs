@@ -42,8 +42,12 @@ pub enum ClientGenesis {
         anchor_block_bytes: Vec<u8>,
         anchor_blobs_bytes: Option<Vec<u8>>,
     },
+    /// Downloads the finalized state and block from remote beacon nodes.
+    ///
+    /// With more than one URL, at least `quorum` of them must agree on the finalized checkpoint.
     CheckpointSyncUrl {
-        url: SensitiveUrl,
+        urls: Vec<SensitiveUrl>,
+        quorum: usize,
     },
 }
 
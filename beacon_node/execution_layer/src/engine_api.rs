@@ -35,7 +35,7 @@ mod new_payload_request;
 
 pub use new_payload_request::{NewPayloadRequest, NewPayloadRequestGloas};
 pub(crate) use new_payload_request::{
-    NewPayloadRequestDeneb, NewPayloadRequestElectra, NewPayloadRequestFulu,
+    NewPayloadRequestDeneb, NewPayloadRequestElectra, NewPayloadRequestFulu, OwnedNewPayloadRequest,
 };
 
 pub(crate) const LATEST_TAG: &str = "latest";
@@ -114,6 +114,30 @@ impl<'block, E: EthSpec> NewPayloadRequest<'block, E> {
         }
     }
 
+    /// Returns a copy of the request which does not borrow from the block.
+    pub(crate) fn to_owned_request(&self) -> OwnedNewPayloadRequest<E> {
+        let execution_payload = match self {
+            Self::Bellatrix(request) => OwnedPayload::Bellatrix(request.execution_payload.clone()),
+            Self::Capella(request) => OwnedPayload::Capella(request.execution_payload.clone()),
+            Self::Deneb(request) => OwnedPayload::Deneb(request.execution_payload.clone()),
+            Self::Electra(request) => OwnedPayload::Electra(request.execution_payload.clone()),
+            Self::Fulu(request) => OwnedPayload::Fulu(request.execution_payload.clone()),
+            Self::Gloas(request) => OwnedPayload::Gloas(request.execution_payload.clone()),
+            Self::Heze(request) => OwnedPayload::Heze(request.execution_payload.clone()),
+        };
+        OwnedNewPayloadRequest {
+            execution_payload,
+            versioned_hashes: self.versioned_hashes().cloned().unwrap_or_default(),
+            parent_beacon_block_root: self.parent_beacon_block_root().copied().unwrap_or_default(),
+            execution_requests: self
+                .execution_requests()
+                .ok()
+                .copied()
+                .cloned()
+                .unwrap_or_default(),
+        }
+    }
+
     /// Performs the required verifications of the payload when the chain is optimistically syncing.
     ///
     /// ## Specification
@@ -177,6 +201,82 @@ impl<'block, E: EthSpec> NewPayloadRequest<'block, E> {
     }
 }
 
+/// An owned copy of a `NewPayloadRequest`, for requests sent from a task which outlives the block.
+///
+/// Fields which the fork of the request does not have are left at their default values.
+pub(crate) struct OwnedNewPayloadRequest<E: EthSpec> {
+    execution_payload: OwnedPayload<E>,
+    versioned_hashes: Vec<VersionedHash>,
+    parent_beacon_block_root: Hash256,
+    execution_requests: ExecutionRequests<E>,
+}
+
+/// The payload of an `OwnedNewPayloadRequest`, tagged with the fork of the request.
+enum OwnedPayload<E: EthSpec> {
+    Bellatrix(ExecutionPayloadBellatrix<E>),
+    Capella(ExecutionPayloadCapella<E>),
+    Deneb(ExecutionPayloadDeneb<E>),
+    Electra(ExecutionPayloadElectra<E>),
+    Fulu(ExecutionPayloadFulu<E>),
+    Gloas(ExecutionPayloadGloas<E>),
+    Heze(ExecutionPayloadGloas<E>),
+}
+
+impl<E: EthSpec> OwnedNewPayloadRequest<E> {
+    pub(crate) fn as_request(&self) -> NewPayloadRequest<'_, E> {
+        let versioned_hashes = self.versioned_hashes.clone();
+        let parent_beacon_block_root = self.parent_beacon_block_root;
+        let execution_requests = &self.execution_requests;
+        match &self.execution_payload {
+            OwnedPayload::Bellatrix(execution_payload) => {
+                NewPayloadRequest::Bellatrix(NewPayloadRequestBellatrix { execution_payload })
+            }
+            OwnedPayload::Capella(execution_payload) => {
+                NewPayloadRequest::Capella(NewPayloadRequestCapella { execution_payload })
+            }
+            OwnedPayload::Deneb(execution_payload) => {
+                NewPayloadRequest::Deneb(NewPayloadRequestDeneb {
+                    execution_payload,
+                    versioned_hashes,
+                    parent_beacon_block_root,
+                })
+            }
+            OwnedPayload::Electra(execution_payload) => {
+                NewPayloadRequest::Electra(NewPayloadRequestElectra {
+                    execution_payload,
+                    versioned_hashes,
+                    parent_beacon_block_root,
+                    execution_requests,
+                })
+            }
+            OwnedPayload::Fulu(execution_payload) => {
+                NewPayloadRequest::Fulu(NewPayloadRequestFulu {
+                    execution_payload,
+                    versioned_hashes,
+                    parent_beacon_block_root,
+                    execution_requests,
+                })
+            }
+            OwnedPayload::Gloas(execution_payload) => {
+                NewPayloadRequest::Gloas(NewPayloadRequestGloas {
+                    execution_payload,
+                    versioned_hashes,
+                    parent_beacon_block_root,
+                    execution_requests,
+                })
+            }
+            OwnedPayload::Heze(execution_payload) => {
+                NewPayloadRequest::Heze(NewPayloadRequestHeze {
+                    execution_payload,
+                    versioned_hashes,
+                    parent_beacon_block_root,
+                    execution_requests,
+                })
+            }
+        }
+    }
+}
+
 impl<'a, E: EthSpec> TryFrom<BeaconBlockRef<'a, E>> for NewPayloadRequest<'a, E> {
     type Error = BeaconStateError;
 
@@ -5,6 +5,7 @@ use crate::engine_api::{
     PayloadId,
 };
 use crate::{ClientVersionV1, HttpJsonRpc};
+use futures::future::join_all;
 use lru::LruCache;
 use std::future::Future;
 use std::num::NonZeroUsize;
@@ -13,6 +14,7 @@ use std::time::Duration;
 use task_executor::TaskExecutor;
 use tokio::sync::{Mutex, RwLock, watch};
 use tokio_stream::wrappers::WatchStream;
+use tokio_stream::{StreamExt, StreamMap};
 use tracing::{debug, error, info, warn};
 use types::ExecutionBlockHash;
 use types::non_zero_usize::new_non_zero_usize;
@@ -385,6 +387,206 @@ impl Engine {
     }
 }
 
+/// The execution engines used by the beacon node, in order of priority.
+///
+/// Requests are sent to the highest priority engine which is synced, failing over to the other
+/// engines if it returns an error. Engines which are not synced are only tried once every synced
+/// engine has failed.
+pub(crate) struct Engines {
+    engines: Vec<Arc<Engine>>,
+    /// The combined state of the engines, which is online if any engine is online.
+    state: watch::Receiver<EngineState>,
+}
+
+impl Engines {
+    /// Wraps `engines`, which must not be empty, and spawns a task combining their states.
+    pub(crate) fn new(engines: Vec<Arc<Engine>>, executor: &TaskExecutor) -> Self {
+        let (notifier, state) = watch::channel(EngineState::Online);
+
+        let watched = engines.clone();
+        executor.spawn(
+            async move {
+                let mut streams = StreamMap::new();
+                for (index, engine) in watched.iter().enumerate() {
+                    streams.insert(index, engine.watch_state().await);
+                }
+                let mut states = vec![EngineState::Online; streams.len()];
+                while let Some((index, state)) = streams.next().await {
+                    states[index] = state;
+                    let new_state = if states.contains(&EngineState::Online) {
+                        EngineState::Online
+                    } else {
+                        EngineState::Offline
+                    };
+                    notifier.send_if_modified(|last_state| {
+                        let changed = *last_state != new_state;
+                        *last_state = new_state;
+                        changed
+                    });
+                }
+            },
+            "engines_state_watch",
+        );
+
+        Self { engines, state }
+    }
+
+    /// The engine configured with the highest priority.
+    pub(crate) fn primary(&self) -> &Arc<Engine> {
+        &self.engines[0]
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Arc<Engine>> {
+        self.engines.iter()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.engines.len()
+    }
+
+    /// Returns the engines in the order requests should be sent to them: synced engines first,
+    /// then the others, each in order of priority.
+    pub(crate) async fn by_preference(&self) -> Vec<&Arc<Engine>> {
+        let mut synced = Vec::with_capacity(self.engines.len());
+        let mut others = vec![];
+        for engine in &self.engines {
+            if engine.is_synced().await {
+                synced.push(engine);
+            } else {
+                others.push(engine);
+            }
+        }
+        synced.extend(others);
+        synced
+    }
+
+    /// The engine requests are sent to first.
+    pub(crate) async fn active(&self) -> &Arc<Engine> {
+        for engine in &self.engines {
+            if engine.is_synced().await {
+                return engine;
+            }
+        }
+        self.primary()
+    }
+
+    /// Run `func` on each engine in order of preference, returning the first successful result or
+    /// the error from the last engine.
+    pub(crate) async fn first_success<'a, F, G, H>(&'a self, func: F) -> Result<H, EngineError>
+    where
+        F: Fn(&'a Engine) -> G,
+        G: Future<Output = Result<H, EngineApiError>>,
+    {
+        self.first_success_matching(func, |_| true).await
+    }
+
+    /// Run `func` on each engine in order of preference, returning the first successful result
+    /// for which `accept` returns `true`.
+    ///
+    /// If no engine returns an accepted result, the first successful result is returned, or the
+    /// error from the last engine if every engine failed.
+    pub(crate) async fn first_success_matching<'a, F, G, H, A>(
+        &'a self,
+        func: F,
+        accept: A,
+    ) -> Result<H, EngineError>
+    where
+        F: Fn(&'a Engine) -> G,
+        G: Future<Output = Result<H, EngineApiError>>,
+        A: Fn(&H) -> bool,
+    {
+        let engines = self.by_preference().await;
+        let mut first_result = None;
+        let mut last_error = EngineError::Offline;
+        for (i, &engine) in engines.iter().enumerate() {
+            let next = engines.get(i + 1);
+            match engine.request(&func).await {
+                Ok(result) if accept(&result) => return Ok(result),
+                Ok(result) => {
+                    if let Some(next) = next {
+                        debug!(
+                            skipped = %engine.api,
+                            next = %next.api,
+                            "Trying the next execution engine"
+                        );
+                    }
+                    first_result.get_or_insert(result);
+                }
+                Err(error) => {
+                    if let Some(next) = next {
+                        warn!(
+                            failed = %engine.api,
+                            next = %next.api,
+                            "Failing over to the next execution engine"
+                        );
+                    }
+                    last_error = error;
+                }
+            }
+        }
+        first_result.ok_or(last_error)
+    }
+
+    /// Send `forkchoice_state` to every engine other than `active` in the background, so that
+    /// standby engines follow the head and can take over at short notice.
+    ///
+    /// Payload attributes are not sent, the payload is built by `active`. If a standby engine
+    /// takes over before the proposal it is asked for a payload ID when the payload is requested.
+    pub(crate) fn spawn_standby_forkchoice_updated(
+        &self,
+        active: &Arc<Engine>,
+        forkchoice_state: ForkchoiceState,
+    ) {
+        for engine in self.iter().filter(|engine| !Arc::ptr_eq(engine, active)) {
+            let engine = engine.clone();
+            self.primary().executor.spawn(
+                async move {
+                    // Offline engines are sent the latest forkchoice state when they come back.
+                    if engine.is_offline().await {
+                        return;
+                    }
+                    let _ = engine
+                        .request(|engine| engine.notify_forkchoice_updated(forkchoice_state, None))
+                        .await;
+                },
+                "standby_forkchoice_updated",
+            );
+        }
+    }
+
+    /// Run `Engine::upcheck` on every engine.
+    pub(crate) async fn upcheck(&self) {
+        join_all(self.engines.iter().map(|engine| engine.upcheck())).await;
+    }
+
+    /// Returns `true` if any engine is synced.
+    pub(crate) async fn is_synced(&self) -> bool {
+        for engine in &self.engines {
+            if engine.is_synced().await {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns `true` if every engine is offline.
+    pub(crate) async fn is_offline(&self) -> bool {
+        for engine in &self.engines {
+            if !engine.is_offline().await {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Gives access to a channel containing the combined state of the engines.
+    ///
+    /// This can be called several times.
+    pub(crate) fn watch_state(&self) -> WatchStream<EngineState> {
+        WatchStream::new(self.state.clone())
+    }
+}
+
 impl PayloadIdCacheKey {
     fn new(head_block_hash: &ExecutionBlockHash, attributes: &PayloadAttributes) -> Self {
         Self {
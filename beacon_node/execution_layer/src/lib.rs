@@ -20,16 +20,20 @@ pub use engine_api::{
     VariableList, Withdrawal, Withdrawals, auth, http, json_structures,
 };
 use engine_api::{GetPayloadResponse, GetPayloadResponseType, LATEST_TAG};
-use engines::Engine;
+use engines::{Engine, Engines};
 pub use engines::{EngineError, EngineState, ForkchoiceState};
 use eth2::types::{BlobsBundle, FullPayloadContents};
 use eth2::types::{ForkVersionedResponse, builder_bid::SignedBuilderBid};
 use fixed_bytes::UintExtended;
 use fork_choice::ForkchoiceUpdateParameters;
+use futures::stream::{FuturesUnordered, StreamExt};
 use logging::crit;
 use lru::LruCache;
 pub use payload_status::PayloadStatus;
-use payload_status::process_payload_status;
+use payload_status::{
+    CrossCheckOutcome, cross_check_payload_statuses, is_payload_verdict, preferred_payload_verdict,
+    process_payload_status,
+};
 use sensitive_url::SensitiveUrl;
 use serde::{Deserialize, Serialize};
 use slot_clock::SlotClock;
@@ -38,7 +42,7 @@ use std::fmt;
 use std::future::Future;
 use std::io::Write;
 use std::num::NonZeroUsize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use strum::AsRefStr;
@@ -437,7 +441,9 @@ pub enum SubmitBlindedBlockResponse<E: EthSpec> {
 type PayloadContentsRefTuple<'a, E> = (ExecutionPayloadRef<'a, E>, Option<&'a BlobsBundle<E>>);
 
 struct Inner<E: EthSpec> {
-    engine: Arc<Engine>,
+    engines: Engines,
+    /// Send `newPayload` to every engine and compare their verdicts.
+    cross_check_new_payload: bool,
     builder: ArcSwapOption<BuilderHttpClient>,
     execution_engine_forkchoice_lock: Mutex<()>,
     suggested_fee_recipient: Option<Address>,
@@ -457,6 +463,11 @@ struct Inner<E: EthSpec> {
 pub struct Config {
     /// Endpoint url for EL nodes that are running the engine api.
     pub execution_endpoint: Option<SensitiveUrl>,
+    /// Endpoint urls for EL nodes to fail over to, in order of priority.
+    pub fallback_execution_endpoints: Vec<SensitiveUrl>,
+    /// Send `newPayload` to the fallback EL nodes as well, and report any disagreement between
+    /// their payload statuses.
+    pub cross_check_new_payload: bool,
     /// Endpoint urls for services providing the builder api.
     pub builder_url: Option<SensitiveUrl>,
     /// The timeout value used when making a request to fetch a block header
@@ -468,6 +479,9 @@ pub struct Config {
     pub disable_builder_ssz_requests: bool,
     /// JWT secret for the above endpoint running the engine api.
     pub secret_file: Option<PathBuf>,
+    /// JWT secrets for the fallback endpoints, in the same order. If empty, the fallback endpoints
+    /// use `secret_file`.
+    pub fallback_secret_files: Vec<PathBuf>,
     /// The default fee recipient to use on the beacon node if none if provided from
     /// the validator client during block preparation.
     pub suggested_fee_recipient: Option<Address>,
@@ -480,8 +494,8 @@ pub struct Config {
     pub execution_timeout_multiplier: Option<u32>,
 }
 
-/// Provides access to one execution engine and provides a neat interface for consumption by the
-/// `BeaconChain`.
+/// Provides access to one or more execution engines and provides a neat interface for consumption
+/// by the `BeaconChain`.
 #[derive(Clone)]
 pub struct ExecutionLayer<E: EthSpec> {
     inner: Arc<Inner<E>>,
@@ -492,11 +506,14 @@ impl<E: EthSpec> ExecutionLayer<E> {
     pub fn from_config(config: Config, executor: TaskExecutor) -> Result<Self, Error> {
         let Config {
             execution_endpoint: url,
+            fallback_execution_endpoints,
+            cross_check_new_payload,
             builder_url,
             builder_user_agent,
             builder_header_timeout,
             disable_builder_ssz_requests,
             secret_file,
+            fallback_secret_files,
             suggested_fee_recipient,
             jwt_id,
             jwt_version,
@@ -509,45 +526,42 @@ impl<E: EthSpec> ExecutionLayer<E> {
         // Use the default jwt secret path if not provided via cli.
         let secret_file = secret_file.unwrap_or_else(|| default_datadir.join(DEFAULT_JWT_FILE));
 
-        let jwt_key = if secret_file.exists() {
-            // Read secret from file if it already exists
-            std::fs::read_to_string(&secret_file)
-                .map_err(|e| format!("Failed to read JWT secret file. Error: {e:?}"))
-                .and_then(|ref s| {
-                    let secret = JwtKey::from_slice(
-                        &hex::decode(strip_prefix(s.trim_end()))
-                            .map_err(|e| format!("Invalid hex string: {e:?}"))?,
-                    )?;
-                    Ok(secret)
-                })
-                .map_err(Error::InvalidJWTSecret)
-        } else {
-            // Create a new file and write a randomly generated secret to it if file does not exist
-            warn!(path = %secret_file.display(),"No JWT found on disk. Generating");
-            std::fs::File::options()
-                .write(true)
-                .create_new(true)
-                .open(&secret_file)
-                .map_err(|e| format!("Failed to open JWT secret file. Error: {e:?}"))
-                .and_then(|mut f| {
-                    let secret = auth::JwtKey::random();
-                    f.write_all(secret.hex_string().as_bytes())
-                        .map_err(|e| format!("Failed to write to JWT secret file: {e:?}"))?;
-                    Ok(secret)
-                })
-                .map_err(Error::InvalidJWTSecret)
-        }?;
+        if !fallback_secret_files.is_empty()
+            && fallback_secret_files.len() != fallback_execution_endpoints.len()
+        {
+            return Err(Error::InvalidJWTSecret(format!(
+                "{} JWT secrets provided for {} fallback execution endpoints",
+                fallback_secret_files.len(),
+                fallback_execution_endpoints.len()
+            )));
+        }
+
+        let endpoints = std::iter::once((execution_url, secret_file.clone())).chain(
+            fallback_execution_endpoints
+                .into_iter()
+                .enumerate()
+                .map(|(i, url)| {
+                    let secret_file = fallback_secret_files
+                        .get(i)
+                        .cloned()
+                        .unwrap_or_else(|| secret_file.clone());
+                    (url, secret_file)
+                }),
+        );
 
-        let engine: Engine = {
-            let auth = Auth::new(jwt_key, jwt_id, jwt_version);
+        let mut engines = vec![];
+        for (execution_url, secret_file) in endpoints {
+            let jwt_key = load_jwt_secret(&secret_file)?;
+            let auth = Auth::new(jwt_key, jwt_id.clone(), jwt_version.clone());
             debug!(endpoint = %execution_url, jwt_path = ?secret_file.as_path(),"Loaded execution endpoint");
             let api = HttpJsonRpc::new_with_auth(execution_url, auth, execution_timeout_multiplier)
                 .map_err(Error::ApiError)?;
-            Engine::new(api, executor.clone())
-        };
+            engines.push(Arc::new(Engine::new(api, executor.clone())));
+        }
 
         let inner = Inner {
-            engine: Arc::new(engine),
+            engines: Engines::new(engines, &executor),
+            cross_check_new_payload,
             builder: ArcSwapOption::empty(),
             execution_engine_forkchoice_lock: <_>::default(),
             suggested_fee_recipient,
@@ -575,8 +589,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
         Ok(el)
     }
 
-    fn engine(&self) -> &Arc<Engine> {
-        &self.inner.engine
+    fn engines(&self) -> &Engines {
+        &self.inner.engines
     }
 
     pub fn builder(&self) -> Option<Arc<BuilderHttpClient>> {
@@ -638,7 +652,9 @@ impl<E: EthSpec> ExecutionLayer<E> {
     /// Get the current difficulty of the PoW chain.
     pub async fn get_current_difficulty(&self) -> Result<Option<Uint256>, ApiError> {
         let block = self
-            .engine()
+            .engines()
+            .active()
+            .await
             .api
             .get_block_by_number(BlockByNumberQuery::Tag(LATEST_TAG))
             .await?
@@ -656,7 +672,7 @@ impl<E: EthSpec> ExecutionLayer<E> {
     ///
     /// This can be called several times.
     pub async fn get_responsiveness_watch(&self) -> WatchStream<EngineState> {
-        self.engine().watch_state().await
+        self.engines().watch_state()
     }
 
     /// Note: this function returns a mutex guard, be careful to avoid deadlocks.
@@ -704,7 +720,7 @@ impl<E: EthSpec> ExecutionLayer<E> {
 
     /// Performs a single execution of the watchdog routine.
     pub async fn watchdog_task(&self) {
-        self.engine().upcheck().await;
+        self.engines().upcheck().await;
     }
 
     /// Spawns a routine which cleans the cached proposer data periodically.
@@ -745,9 +761,9 @@ impl<E: EthSpec> ExecutionLayer<E> {
         self.spawn(preparation_cleaner, "exec_preparation_cleanup");
     }
 
-    /// Returns `true` if the execution engine is synced and reachable.
+    /// Returns `true` if any execution engine is synced and reachable.
     pub async fn is_synced(&self) -> bool {
-        self.engine().is_synced().await
+        self.engines().is_synced().await
     }
 
     /// Execution nodes return a "SYNCED" response when they do not have any peers.
@@ -760,7 +776,9 @@ impl<E: EthSpec> ExecutionLayer<E> {
         let synced = self.is_synced().await;
         if synced
             && let Ok(Some(block)) = self
-                .engine()
+                .engines()
+                .active()
+                .await
                 .api
                 .get_block_by_number(BlockByNumberQuery::Tag(LATEST_TAG))
                 .await
@@ -779,7 +797,7 @@ impl<E: EthSpec> ExecutionLayer<E> {
     /// be used to give an indication on the HTTP API that the node's execution layer is struggling,
     /// which can in turn be used by the VC.
     pub async fn is_offline_or_erroring(&self) -> bool {
-        self.engine().is_offline().await || *self.inner.last_new_payload_errored.read().await
+        self.engines().is_offline().await || *self.inner.last_new_payload_errored.read().await
     }
 
     /// Updates the proposer preparation data provided by validators
@@ -1269,8 +1287,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
             ..
         } = payload_parameters;
 
-        self.engine()
-            .request(move |engine| async move {
+        self.engines()
+            .first_success(move |engine| async move {
                 let payload_id = if let Some(id) = engine
                     .get_payload_id(&parent_hash, payload_attributes)
                     .await
@@ -1291,7 +1309,7 @@ impl<E: EthSpec> ExecutionLayer<E> {
                     let fork_choice_state = ForkchoiceState {
                         head_block_hash: parent_hash,
                         safe_block_hash: forkchoice_update_params
-                            .justified_hash
+                            .safe_hash()
                             .unwrap_or_else(ExecutionBlockHash::zero),
                         finalized_block_hash: forkchoice_update_params
                             .finalized_hash
@@ -1385,10 +1403,18 @@ impl<E: EthSpec> ExecutionLayer<E> {
         let block_hash = new_payload_request.block_hash();
         let parent_hash = new_payload_request.parent_hash();
 
-        let result = self
-            .engine()
-            .request(|engine| engine.api.new_payload(new_payload_request))
-            .await;
+        let result = if self.inner.cross_check_new_payload && self.engines().len() > 1 {
+            self.cross_check_new_payload(new_payload_request).await
+        } else {
+            // An engine that is `SYNCING` or has only `ACCEPTED` the payload is not the final word,
+            // so give the other engines a chance to return a verdict.
+            self.engines()
+                .first_success_matching(
+                    |engine| engine.api.new_payload(new_payload_request.clone()),
+                    |status| is_payload_verdict(status.status),
+                )
+                .await
+        };
 
         if let Ok(status) = &result {
             let status_str = <&'static str>::from(status.status);
@@ -1412,9 +1438,97 @@ impl<E: EthSpec> ExecutionLayer<E> {
             .map_err(Error::EngineError)
     }
 
+    /// Send `newPayload` to every engine and report any disagreement between their verdicts.
+    ///
+    /// Returns as soon as the most preferred engine with a verdict (`VALID` or `INVALID`) has
+    /// responded, see `preferred_payload_verdict`, while the remaining responses are compared in
+    /// the background. If the engines which have responded by then disagree, `SYNCING` is returned
+    /// so that the payload is imported optimistically.
+    ///
+    /// If no engine returns a verdict, the first response in order of preference is returned, or
+    /// the error from the most preferred engine if none responded.
+    async fn cross_check_new_payload(
+        &self,
+        new_payload_request: NewPayloadRequest<'_, E>,
+    ) -> Result<PayloadStatusV1, EngineError> {
+        let block_hash = new_payload_request.block_hash();
+        let request = Arc::new(new_payload_request.to_owned_request());
+        let engines = self
+            .engines()
+            .by_preference()
+            .await
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut pending = engines
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, engine)| {
+                let request = request.clone();
+                async move {
+                    let new_payload_request = request.as_request();
+                    let result = engine
+                        .request(move |engine| engine.api.new_payload(new_payload_request))
+                        .await;
+                    (index, result)
+                }
+            })
+            .collect::<FuturesUnordered<_>>();
+        let mut responses = (0..engines.len()).map(|_| None).collect::<Vec<_>>();
+
+        let mut verdict = None;
+        while let Some((index, result)) = pending.next().await {
+            responses[index] = Some(result);
+            if let Some(status) = preferred_payload_verdict(&responses) {
+                verdict = Some(status.clone());
+                break;
+            }
+        }
+
+        if let Some(verdict) = verdict {
+            let disagree = cross_check_payload_statuses(payload_statuses(&responses))
+                == CrossCheckOutcome::Disagree;
+
+            self.executor().spawn(
+                async move {
+                    while let Some((index, result)) = pending.next().await {
+                        responses[index] = Some(result);
+                    }
+                    record_new_payload_cross_check(block_hash, &engines, &responses);
+                },
+                "new_payload_cross_check",
+            );
+
+            return if disagree {
+                Ok(PayloadStatusV1 {
+                    status: PayloadStatusV1Status::Syncing,
+                    latest_valid_hash: None,
+                    validation_error: None,
+                })
+            } else {
+                Ok(verdict)
+            };
+        }
+
+        // Every engine responded without a verdict.
+        record_new_payload_cross_check(block_hash, &engines, &responses);
+        let mut first_error = None;
+        for result in responses.into_iter().flatten() {
+            match result {
+                Ok(status) => return Ok(status),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+        Err(first_error.unwrap_or(EngineError::Offline))
+    }
+
     /// Update engine sync status.
     pub async fn upcheck(&self) {
-        self.engine().upcheck().await;
+        self.engines().upcheck().await;
     }
 
     /// Register that the given `validator_index` is going to produce a block at `slot`.
@@ -1478,7 +1592,7 @@ impl<E: EthSpec> ExecutionLayer<E> {
     pub async fn notify_forkchoice_updated(
         &self,
         head_block_hash: ExecutionBlockHash,
-        justified_block_hash: ExecutionBlockHash,
+        safe_block_hash: ExecutionBlockHash,
         finalized_block_hash: ExecutionBlockHash,
         current_slot: Slot,
         head_block_root: Hash256,
@@ -1490,7 +1604,7 @@ impl<E: EthSpec> ExecutionLayer<E> {
 
         debug!(
             ?finalized_block_hash,
-            ?justified_block_hash,
+            ?safe_block_hash,
             ?head_block_hash,
             ?head_block_root,
             ?current_slot,
@@ -1517,21 +1631,27 @@ impl<E: EthSpec> ExecutionLayer<E> {
 
         let forkchoice_state = ForkchoiceState {
             head_block_hash,
-            safe_block_hash: justified_block_hash,
+            safe_block_hash,
             finalized_block_hash,
         };
 
-        self.engine()
-            .set_latest_forkchoice_state(forkchoice_state)
-            .await;
+        for engine in self.engines().iter() {
+            engine.set_latest_forkchoice_state(forkchoice_state).await;
+        }
 
+        let active = self.engines().active().await;
+        self.engines()
+            .spawn_standby_forkchoice_updated(active, forkchoice_state);
+
+        // As for `newPayload`, a `SYNCING` engine is not the final word on the head.
         let result = self
-            .engine()
-            .request(|engine| async move {
-                engine
-                    .notify_forkchoice_updated(forkchoice_state, payload_attributes)
-                    .await
-            })
+            .engines()
+            .first_success_matching(
+                |engine| {
+                    engine.notify_forkchoice_updated(forkchoice_state, payload_attributes.clone())
+                },
+                |response| is_payload_verdict(response.payload_status.status),
+            )
             .await;
 
         if let Ok(status) = &result {
@@ -1562,8 +1682,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
         &self,
         age_limit: Option<Duration>,
     ) -> Result<EngineCapabilities, Error> {
-        self.engine()
-            .request(|engine| engine.get_engine_capabilities(age_limit))
+        self.engines()
+            .first_success(|engine| engine.get_engine_capabilities(age_limit))
             .await
             .map_err(Into::into)
     }
@@ -1582,8 +1702,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
         age_limit: Option<Duration>,
     ) -> Result<Vec<ClientVersionV1>, Error> {
         let versions = self
-            .engine()
-            .request(|engine| engine.get_engine_version(age_limit))
+            .engines()
+            .first_success(|engine| engine.get_engine_version(age_limit))
             .await
             .map_err(Into::<Error>::into)?;
         metrics::expose_execution_layer_info(&versions);
@@ -1609,8 +1729,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
         );
 
         let hash_opt = self
-            .engine()
-            .request(|engine| async move {
+            .engines()
+            .first_success(|engine| async move {
                 let terminal_block_hash = spec.terminal_block_hash;
                 if terminal_block_hash != ExecutionBlockHash::zero() {
                     if self
@@ -1735,8 +1855,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
             &[metrics::IS_VALID_TERMINAL_POW_BLOCK_HASH],
         );
 
-        self.engine()
-            .request(|engine| async move {
+        self.engines()
+            .first_success(|engine| async move {
                 if let Some(pow_block) = self.get_pow_block(engine, block_hash).await?
                     && let Some(pow_parent) =
                         self.get_pow_block(engine, pow_block.parent_hash).await?
@@ -1796,9 +1916,9 @@ impl<E: EthSpec> ExecutionLayer<E> {
         &self,
         hashes: Vec<ExecutionBlockHash>,
     ) -> Result<Vec<Option<ExecutionPayloadBodyV1<E>>>, Error> {
-        self.engine()
-            .request(|engine: &Engine| async move {
-                engine.api.get_payload_bodies_by_hash_v1(hashes).await
+        self.engines()
+            .first_success(|engine: &Engine| {
+                engine.api.get_payload_bodies_by_hash_v1(hashes.clone())
             })
             .await
             .map_err(Box::new)
@@ -1811,8 +1931,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
         count: u64,
     ) -> Result<Vec<Option<ExecutionPayloadBodyV1<E>>>, Error> {
         let _timer = metrics::start_timer(&metrics::EXECUTION_LAYER_GET_PAYLOAD_BODIES_BY_RANGE);
-        self.engine()
-            .request(|engine: &Engine| async move {
+        self.engines()
+            .first_success(|engine: &Engine| async move {
                 engine
                     .api
                     .get_payload_bodies_by_range_v1(start, count)
@@ -1877,8 +1997,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
         let capabilities = self.get_engine_capabilities(None).await?;
 
         if capabilities.get_blobs_v1 {
-            self.engine()
-                .request(|engine| async move { engine.api.get_blobs_v1(query).await })
+            self.engines()
+                .first_success(|engine| engine.api.get_blobs_v1(query.clone()))
                 .await
                 .map_err(Box::new)
                 .map_err(Error::EngineError)
@@ -1894,8 +2014,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
         let capabilities = self.get_engine_capabilities(None).await?;
 
         if capabilities.get_blobs_v2 {
-            self.engine()
-                .request(|engine| async move { engine.api.get_blobs_v2(query).await })
+            self.engines()
+                .first_success(|engine| engine.api.get_blobs_v2(query.clone()))
                 .await
                 .map_err(Box::new)
                 .map_err(Error::EngineError)
@@ -1908,8 +2028,8 @@ impl<E: EthSpec> ExecutionLayer<E> {
         &self,
         query: BlockByNumberQuery<'_>,
     ) -> Result<Option<ExecutionBlock>, Error> {
-        self.engine()
-            .request(|engine| async move { engine.api.get_block_by_number(query).await })
+        self.engines()
+            .first_success(|engine| async move { engine.api.get_block_by_number(query).await })
             .await
             .map_err(Box::new)
             .map_err(Error::EngineError)
@@ -2255,6 +2375,39 @@ fn verify_builder_bid<E: EthSpec>(
     }
 }
 
+/// Read the JWT secret from `secret_file`, or generate one and write it to `secret_file` if the
+/// file does not exist.
+fn load_jwt_secret(secret_file: &Path) -> Result<JwtKey, Error> {
+    if secret_file.exists() {
+        // Read secret from file if it already exists
+        std::fs::read_to_string(secret_file)
+            .map_err(|e| format!("Failed to read JWT secret file. Error: {e:?}"))
+            .and_then(|ref s| {
+                let secret = JwtKey::from_slice(
+                    &hex::decode(strip_prefix(s.trim_end()))
+                        .map_err(|e| format!("Invalid hex string: {e:?}"))?,
+                )?;
+                Ok(secret)
+            })
+            .map_err(Error::InvalidJWTSecret)
+    } else {
+        // Create a new file and write a randomly generated secret to it if file does not exist
+        warn!(path = %secret_file.display(),"No JWT found on disk. Generating");
+        std::fs::File::options()
+            .write(true)
+            .create_new(true)
+            .open(secret_file)
+            .map_err(|e| format!("Failed to open JWT secret file. Error: {e:?}"))
+            .and_then(|mut f| {
+                let secret = auth::JwtKey::random();
+                f.write_all(secret.hex_string().as_bytes())
+                    .map_err(|e| format!("Failed to write to JWT secret file: {e:?}"))?;
+                Ok(secret)
+            })
+            .map_err(Error::InvalidJWTSecret)
+    }
+}
+
 /// A helper function to record the time it takes to execute a future.
 async fn timed_future<F: Future<Output = T>, T>(metric: &str, future: F) -> (T, Duration) {
     let start = Instant::now();
@@ -2273,6 +2426,48 @@ fn timestamp_now() -> u64 {
         .as_secs()
 }
 
+/// Returns the statuses of the successful `newPayload` responses in `responses`.
+fn payload_statuses(
+    responses: &[Option<Result<PayloadStatusV1, EngineError>>],
+) -> impl Iterator<Item = PayloadStatusV1Status> + '_ {
+    responses
+        .iter()
+        .flatten()
+        .filter_map(|result| result.as_ref().ok().map(|status| status.status))
+}
+
+/// Compare the `newPayload` responses of `engines`, given in the same order, and report the
+/// outcome.
+fn record_new_payload_cross_check(
+    block_hash: ExecutionBlockHash,
+    engines: &[Arc<Engine>],
+    responses: &[Option<Result<PayloadStatusV1, EngineError>>],
+) {
+    let outcome = cross_check_payload_statuses(payload_statuses(responses));
+    metrics::inc_counter_vec(
+        &metrics::EXECUTION_LAYER_NEW_PAYLOAD_CROSS_CHECK,
+        &[outcome.into()],
+    );
+    if outcome == CrossCheckOutcome::Disagree {
+        let verdicts = engines
+            .iter()
+            .zip(responses)
+            .filter_map(|(engine, response)| {
+                response.as_ref().map(|result| match result {
+                    Ok(status) => format!("{}: {}", engine.api, <&str>::from(status.status)),
+                    Err(error) => format!("{}: {error:?}", engine.api),
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        crit!(
+            ?block_hash,
+            %verdicts,
+            "Execution engines disagree on payload validity"
+        );
+    }
+}
+
 fn noop<E: EthSpec>(
     _: &ExecutionLayer<E>,
     _: PayloadContentsRefTuple<E>,
@@ -2283,12 +2478,34 @@ fn noop<E: EthSpec>(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::test_utils::MockExecutionLayer as GenericMockExecutionLayer;
+    use crate::test_utils::{MockExecutionLayer as GenericMockExecutionLayer, MockServer};
     use task_executor::test_utils::TestRuntime;
     use types::MainnetEthSpec;
 
     type MockExecutionLayer = GenericMockExecutionLayer<MainnetEthSpec>;
 
+    /// An endpoint nothing is listening on.
+    const OFFLINE_ENDPOINT: &str = "http://127.0.0.1:1";
+
+    fn payload_status(status: PayloadStatusV1Status) -> PayloadStatusV1 {
+        PayloadStatusV1 {
+            status,
+            latest_valid_hash: None,
+            validation_error: None,
+        }
+    }
+
+    fn payload_with_hash(byte: u8) -> ExecutionPayload<MainnetEthSpec> {
+        ExecutionPayload::Bellatrix(ExecutionPayloadBellatrix {
+            block_hash: ExecutionBlockHash::from_root(Hash256::repeat_byte(byte)),
+            ..<_>::default()
+        })
+    }
+
+    fn url(server: &MockServer<MainnetEthSpec>) -> SensitiveUrl {
+        SensitiveUrl::parse(&server.url()).unwrap()
+    }
+
     #[tokio::test]
     async fn produce_three_valid_pos_execution_blocks() {
         let runtime = TestRuntime::default();
@@ -2302,6 +2519,202 @@ mod test {
             .await;
     }
 
+    #[tokio::test]
+    async fn new_payload_fails_over_from_offline_engine() {
+        let runtime = TestRuntime::default();
+        let mock = MockExecutionLayer::default_params(runtime.task_executor.clone());
+        let fallback = MockServer::unit_testing(mock.spec.clone());
+        let mock = mock.with_fallback_execution_endpoints(
+            vec![
+                SensitiveUrl::parse(OFFLINE_ENDPOINT).unwrap(),
+                url(&fallback),
+            ],
+            false,
+        );
+
+        let payload = payload_with_hash(1);
+        mock.server
+            .set_new_payload_error(payload.block_hash(), "unavailable".to_string());
+        fallback.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Valid),
+        );
+
+        let status = mock
+            .el
+            .notify_new_payload(payload.to_ref().try_into().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(status, PayloadStatus::Valid);
+    }
+
+    #[tokio::test]
+    async fn new_payload_fails_over_from_syncing_engine() {
+        let runtime = TestRuntime::default();
+        let mock = MockExecutionLayer::default_params(runtime.task_executor.clone());
+        let fallback = MockServer::unit_testing(mock.spec.clone());
+        let mock = mock.with_fallback_execution_endpoints(vec![url(&fallback)], false);
+
+        let payload = payload_with_hash(2);
+        mock.server.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Syncing),
+        );
+        fallback.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Valid),
+        );
+
+        let status = mock
+            .el
+            .notify_new_payload(payload.to_ref().try_into().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(status, PayloadStatus::Valid);
+
+        // Without a verdict from any engine, the most preferred response is used.
+        let payload = payload_with_hash(3);
+        mock.server.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Syncing),
+        );
+        fallback.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Accepted),
+        );
+
+        let status = mock
+            .el
+            .notify_new_payload(payload.to_ref().try_into().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(status, PayloadStatus::Syncing);
+    }
+
+    #[tokio::test]
+    async fn cross_check_new_payload_reports_disagreement() {
+        let runtime = TestRuntime::default();
+        let mock = MockExecutionLayer::default_params(runtime.task_executor.clone());
+        let fallback = MockServer::unit_testing(mock.spec.clone());
+        let mock = mock.with_fallback_execution_endpoints(vec![url(&fallback)], true);
+        let disagreements = || {
+            metrics::get_int_counter(
+                &metrics::EXECUTION_LAYER_NEW_PAYLOAD_CROSS_CHECK,
+                &[CrossCheckOutcome::Disagree.into()],
+            )
+            .map_or(0, |counter| counter.get())
+        };
+
+        let payload = payload_with_hash(4);
+        mock.server.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Valid),
+        );
+        fallback.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Invalid),
+        );
+        // The disagreement is known before the preferred engine's verdict if it responds last.
+        mock.server
+            .set_new_payload_delay(Some(Duration::from_millis(500)));
+
+        let disagreements_before = disagreements();
+        let status = mock
+            .el
+            .notify_new_payload(payload.to_ref().try_into().unwrap())
+            .await
+            .unwrap();
+        // The payload is imported optimistically.
+        assert_eq!(status, PayloadStatus::Syncing);
+        // The responses are compared in the background.
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while disagreements() == disagreements_before {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("disagreement should be reported");
+
+        // A syncing engine defers to the verdict of the others.
+        let payload = payload_with_hash(5);
+        mock.server.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Syncing),
+        );
+        fallback.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Invalid),
+        );
+
+        let status = mock
+            .el
+            .notify_new_payload(payload.to_ref().try_into().unwrap())
+            .await
+            .unwrap();
+        assert!(matches!(status, PayloadStatus::Invalid { .. }));
+    }
+
+    #[tokio::test]
+    async fn cross_check_new_payload_does_not_wait_for_slow_engine() {
+        let runtime = TestRuntime::default();
+        let mock = MockExecutionLayer::default_params(runtime.task_executor.clone());
+        // Accepts connections but never responds.
+        let unresponsive = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let unresponsive_url =
+            SensitiveUrl::parse(&format!("http://{}", unresponsive.local_addr().unwrap())).unwrap();
+        let mock = mock.with_fallback_execution_endpoints(vec![unresponsive_url], true);
+
+        let payload = payload_with_hash(6);
+        mock.server.set_new_payload_status(
+            payload.block_hash(),
+            payload_status(PayloadStatusV1Status::Valid),
+        );
+
+        let status = tokio::time::timeout(
+            Duration::from_secs(5),
+            mock.el
+                .notify_new_payload(payload.to_ref().try_into().unwrap()),
+        )
+        .await
+        .expect("should not wait for the unresponsive engine")
+        .unwrap();
+        assert_eq!(status, PayloadStatus::Valid);
+    }
+
+    #[tokio::test]
+    async fn forkchoice_updated_fails_over_from_syncing_engine() {
+        let runtime = TestRuntime::default();
+        let mock = MockExecutionLayer::default_params(runtime.task_executor.clone());
+        let fallback = MockServer::unit_testing(mock.spec.clone());
+        let mock = mock.with_fallback_execution_endpoints(vec![url(&fallback)], false);
+
+        let head_block_hash = ExecutionBlockHash::repeat_byte(7);
+        mock.server.set_fcu_payload_status(
+            head_block_hash,
+            payload_status(PayloadStatusV1Status::Syncing),
+        );
+        fallback.set_fcu_payload_status(
+            head_block_hash,
+            PayloadStatusV1 {
+                latest_valid_hash: Some(head_block_hash),
+                ..payload_status(PayloadStatusV1Status::Valid)
+            },
+        );
+
+        let status = mock
+            .el
+            .notify_forkchoice_updated(
+                head_block_hash,
+                ExecutionBlockHash::zero(),
+                ExecutionBlockHash::zero(),
+                Slot::new(0),
+                Hash256::zero(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, PayloadStatus::Valid);
+    }
+
     #[tokio::test]
     async fn test_expected_gas_limit() {
         let spec = ChainSpec::mainnet();
@@ -2344,7 +2757,7 @@ mod test {
         MockExecutionLayer::default_params(runtime.task_executor.clone())
             .move_to_block_prior_to_terminal_block()
             .with_terminal_block(|spec, el, _| async move {
-                el.engine().upcheck().await;
+                el.upcheck().await;
                 assert_eq!(
                     el.get_terminal_pow_block_hash(&spec, timestamp_now())
                         .await
@@ -2371,7 +2784,7 @@ mod test {
         MockExecutionLayer::default_params(runtime.task_executor.clone())
             .move_to_block_prior_to_terminal_block()
             .with_terminal_block(|spec, el, _| async move {
-                el.engine().upcheck().await;
+                el.upcheck().await;
                 assert_eq!(
                     el.get_terminal_pow_block_hash(&spec, timestamp_now())
                         .await
@@ -2399,7 +2812,7 @@ mod test {
         MockExecutionLayer::default_params(runtime.task_executor.clone())
             .move_to_terminal_block()
             .with_terminal_block(|spec, el, terminal_block| async move {
-                el.engine().upcheck().await;
+                el.upcheck().await;
                 assert_eq!(
                     el.is_valid_terminal_pow_block_hash(terminal_block.unwrap().block_hash, &spec)
                         .await
@@ -2416,7 +2829,7 @@ mod test {
         MockExecutionLayer::default_params(runtime.task_executor.clone())
             .move_to_terminal_block()
             .with_terminal_block(|spec, el, terminal_block| async move {
-                el.engine().upcheck().await;
+                el.upcheck().await;
                 let invalid_terminal_block = terminal_block.unwrap().parent_hash;
 
                 assert_eq!(
@@ -2435,7 +2848,7 @@ mod test {
         MockExecutionLayer::default_params(runtime.task_executor.clone())
             .move_to_terminal_block()
             .with_terminal_block(|spec, el, _| async move {
-                el.engine().upcheck().await;
+                el.upcheck().await;
                 let missing_terminal_block = ExecutionBlockHash::repeat_byte(42);
 
                 assert_eq!(
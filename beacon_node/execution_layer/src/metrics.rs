@@ -80,6 +80,14 @@ pub(crate) static EXECUTION_LAYER_PAYLOAD_STATUS: LazyLock<Result<IntCounterVec>
             &["method", "status"],
         )
     });
+pub(crate) static EXECUTION_LAYER_NEW_PAYLOAD_CROSS_CHECK: LazyLock<Result<IntCounterVec>> =
+    LazyLock::new(|| {
+        try_create_int_counter_vec(
+            "execution_layer_new_payload_cross_check",
+            "Outcomes of comparing the newPayload verdicts of all execution engines",
+            &["outcome"],
+        )
+    });
 pub(crate) static EXECUTION_LAYER_GET_PAYLOAD_OUTCOME: LazyLock<Result<IntCounterVec>> =
     LazyLock::new(|| {
         try_create_int_counter_vec(
@@ -1,5 +1,6 @@
 use crate::engine_api::{Error as ApiError, PayloadStatusV1, PayloadStatusV1Status};
 use crate::engines::EngineError;
+use strum::IntoStaticStr;
 use tracing::warn;
 use types::ExecutionBlockHash;
 
@@ -100,6 +101,76 @@ pub(crate) fn process_payload_status(
     }
 }
 
+/// The result of comparing the `newPayload` responses of several execution engines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum CrossCheckOutcome {
+    /// At least two engines gave a verdict, and they all agree.
+    Agree,
+    /// Some engines consider the payload valid while others consider it invalid.
+    Disagree,
+    /// Fewer than two engines gave a verdict.
+    Inconclusive,
+}
+
+/// Returns `true` if `status` is a verdict on the validity of a payload.
+///
+/// `SYNCING` and `ACCEPTED` mean the engine could not (yet) validate the payload.
+pub(crate) fn is_payload_verdict(status: PayloadStatusV1Status) -> bool {
+    matches!(
+        status,
+        PayloadStatusV1Status::Valid
+            | PayloadStatusV1Status::Invalid
+            | PayloadStatusV1Status::InvalidBlockHash
+    )
+}
+
+/// Compare the statuses returned by several engines for the same payload.
+///
+/// `SYNCING` and `ACCEPTED` are not verdicts on the validity of the payload and are ignored, as is
+/// the distinction between `INVALID` and `INVALID_BLOCK_HASH`.
+pub(crate) fn cross_check_payload_statuses(
+    statuses: impl IntoIterator<Item = PayloadStatusV1Status>,
+) -> CrossCheckOutcome {
+    let mut valid = 0;
+    let mut invalid = 0;
+    for status in statuses {
+        match status {
+            PayloadStatusV1Status::Valid => valid += 1,
+            PayloadStatusV1Status::Invalid | PayloadStatusV1Status::InvalidBlockHash => {
+                invalid += 1
+            }
+            PayloadStatusV1Status::Syncing | PayloadStatusV1Status::Accepted => {}
+        }
+    }
+    if valid > 0 && invalid > 0 {
+        CrossCheckOutcome::Disagree
+    } else if valid + invalid >= 2 {
+        CrossCheckOutcome::Agree
+    } else {
+        CrossCheckOutcome::Inconclusive
+    }
+}
+
+/// Picks the `newPayload` response to act on from the responses of several execution engines in
+/// order of preference, where `None` marks an engine which has not responded yet.
+///
+/// This is the response of the most preferred engine which gave a verdict, once every more
+/// preferred engine has responded without one. Returns `None` while that is still unknown, or if
+/// no engine gave a verdict.
+pub(crate) fn preferred_payload_verdict(
+    responses: &[Option<Result<PayloadStatusV1, EngineError>>],
+) -> Option<&PayloadStatusV1> {
+    for response in responses {
+        match response {
+            None => return None,
+            Some(Ok(status)) if is_payload_verdict(status.status) => return Some(status),
+            Some(_) => {}
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,4 +331,57 @@ mod tests {
             PayloadStatus::InvalidBlockHash { .. }
         ));
     }
+
+    #[test]
+    fn cross_check_statuses() {
+        use PayloadStatusV1Status::{Accepted, Invalid, InvalidBlockHash, Syncing, Valid};
+
+        assert_eq!(
+            cross_check_payload_statuses([Valid, Valid, Syncing]),
+            CrossCheckOutcome::Agree
+        );
+        assert_eq!(
+            cross_check_payload_statuses([Invalid, InvalidBlockHash]),
+            CrossCheckOutcome::Agree
+        );
+        assert_eq!(
+            cross_check_payload_statuses([Valid, Accepted, InvalidBlockHash]),
+            CrossCheckOutcome::Disagree
+        );
+        assert_eq!(
+            cross_check_payload_statuses([Valid, Syncing, Accepted]),
+            CrossCheckOutcome::Inconclusive
+        );
+        assert_eq!(
+            cross_check_payload_statuses([]),
+            CrossCheckOutcome::Inconclusive
+        );
+    }
+
+    #[test]
+    fn preferred_verdict_waits_for_more_preferred_engines() {
+        use PayloadStatusV1Status::{Invalid, Syncing, Valid};
+        let response = |status| Some(ok_status(status, None, None));
+
+        // A verdict from a less preferred engine is not used before the others have responded.
+        assert_eq!(preferred_payload_verdict(&[None, response(Valid)]), None);
+        assert_eq!(
+            preferred_payload_verdict(&[response(Invalid), None]).map(|status| status.status),
+            Some(Invalid)
+        );
+        assert_eq!(
+            preferred_payload_verdict(&[
+                response(Syncing),
+                Some(Err(EngineError::Offline)),
+                response(Valid),
+                None
+            ])
+            .map(|status| status.status),
+            Some(Valid)
+        );
+        assert_eq!(
+            preferred_payload_verdict(&[response(Syncing), Some(Err(EngineError::Offline))]),
+            None
+        );
+    }
 }
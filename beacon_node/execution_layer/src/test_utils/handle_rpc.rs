@@ -222,6 +222,11 @@ pub(super) async fn handle_rpc<E: EthSpec>(
                 _ => unreachable!(),
             }
 
+            let delay = *ctx.new_payload_delay.lock();
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+
             // Canned responses set by block hash take priority.
             if let Some(status) = ctx.get_new_payload_status(request.block_hash()) {
                 return status
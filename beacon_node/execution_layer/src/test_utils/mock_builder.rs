@@ -994,6 +994,7 @@ impl<E: EthSpec> MockBuilder<E> {
             head_hash: Some(head_execution_hash),
             finalized_hash: Some(finalized_execution_hash),
             justified_hash: Some(justified_execution_hash),
+            confirmed_hash: None,
             head_root: head_block_root,
         };
 
@@ -68,6 +68,39 @@ impl<E: EthSpec> MockExecutionLayer<E> {
         );
 
         let url = SensitiveUrl::parse(&server.url()).unwrap();
+        let el = Self::execution_layer(url, vec![], false, executor.clone());
+
+        Self {
+            server,
+            el,
+            executor,
+            spec,
+        }
+    }
+
+    /// Rebuild the execution layer with `fallback_urls` as fallback execution endpoints, in order
+    /// of priority after `self.server`.
+    pub fn with_fallback_execution_endpoints(
+        mut self,
+        fallback_urls: Vec<SensitiveUrl>,
+        cross_check_new_payload: bool,
+    ) -> Self {
+        let url = SensitiveUrl::parse(&self.server.url()).unwrap();
+        self.el = Self::execution_layer(
+            url,
+            fallback_urls,
+            cross_check_new_payload,
+            self.executor.clone(),
+        );
+        self
+    }
+
+    fn execution_layer(
+        url: SensitiveUrl,
+        fallback_execution_endpoints: Vec<SensitiveUrl>,
+        cross_check_new_payload: bool,
+        executor: TaskExecutor,
+    ) -> ExecutionLayer<E> {
         let file = NamedTempFile::new().unwrap();
 
         let path = file.path().into();
@@ -75,6 +108,8 @@ impl<E: EthSpec> MockExecutionLayer<E> {
 
         let config = Config {
             execution_endpoint: Some(url),
+            fallback_execution_endpoints,
+            cross_check_new_payload,
             secret_file: Some(path),
             suggested_fee_recipient: Some(Address::repeat_byte(42)),
             // Use a higher timeout multiplier for tests to avoid flaky failures
@@ -83,14 +118,7 @@ impl<E: EthSpec> MockExecutionLayer<E> {
             execution_timeout_multiplier: Some(5),
             ..Default::default()
         };
-        let el = ExecutionLayer::from_config(config, executor.clone()).unwrap();
-
-        Self {
-            server,
-            el,
-            executor,
-            spec,
-        }
+        ExecutionLayer::from_config(config, executor).unwrap()
     }
 
     pub async fn produce_valid_execution_payload_on_head(self) -> Self {
@@ -110,6 +138,7 @@ impl<E: EthSpec> MockExecutionLayer<E> {
             head_hash: Some(parent_hash),
             justified_hash: None,
             finalized_hash: None,
+            confirmed_hash: None,
         };
         let payload_attributes =
             PayloadAttributes::new(timestamp, prev_randao, Address::repeat_byte(42), None, None);
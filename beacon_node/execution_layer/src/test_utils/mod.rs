@@ -24,6 +24,7 @@ use std::future::Future;
 use std::marker::PhantomData;
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 use tokio::{runtime, sync::oneshot};
 use tracing::info;
 use types::{ChainSpec, EthSpec, ExecutionBlockHash, Uint256};
@@ -183,6 +184,7 @@ impl<E: EthSpec> MockServer<E> {
             new_payload_statuses: <_>::default(),
             fcu_payload_statuses: <_>::default(),
             syncing_response: Arc::new(Mutex::new(Ok(false))),
+            new_payload_delay: <_>::default(),
             engine_capabilities: Arc::new(RwLock::new(DEFAULT_ENGINE_CAPABILITIES)),
             execution_requests: <_>::default(),
             _phantom: PhantomData,
@@ -524,6 +526,12 @@ impl<E: EthSpec> MockServer<E> {
     pub fn set_syncing_response(&self, res: Result<bool, String>) {
         *self.ctx.syncing_response.lock() = res;
     }
+
+    /// Delay every `newPayload` response by `delay`, e.g. to control the order in which several
+    /// engines respond.
+    pub fn set_new_payload_delay(&self, delay: Option<Duration>) {
+        *self.ctx.new_payload_delay.lock() = delay;
+    }
 }
 
 #[derive(Debug)]
@@ -574,6 +582,7 @@ pub struct Context<E: EthSpec> {
     pub fcu_payload_statuses:
         Arc<Mutex<HashMap<ExecutionBlockHash, Result<PayloadStatusV1, String>>>>,
     pub syncing_response: Arc<Mutex<Result<bool, String>>>,
+    pub new_payload_delay: Arc<Mutex<Option<Duration>>>,
 
     pub engine_capabilities: Arc<RwLock<EngineCapabilities>>,
     pub execution_requests: Arc<Mutex<Option<types::ExecutionRequests<E>>>>,
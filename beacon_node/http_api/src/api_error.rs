@@ -1,6 +1,6 @@
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
-use eth2::types::{ErrorMessage, Failure, IndexedErrorMessage};
+use eth2::types::{ErrorMessage, Failure, IndexedErrorMessage, RejectionErrorMessage};
 use std::fmt;
 
 /// Unified error type for the beacon node HTTP API.
@@ -19,6 +19,11 @@ pub enum ApiError {
         message: String,
         failures: Vec<Failure>,
     },
+    /// 400 with a machine-readable `reason` naming the verification check that failed.
+    Rejected {
+        reason: String,
+        message: String,
+    },
 }
 
 impl fmt::Debug for ApiError {
@@ -35,6 +40,7 @@ impl fmt::Debug for ApiError {
             ApiError::IndexedBadRequest { message, .. } => {
                 write!(f, "IndexedBadRequest({message})")
             }
+            ApiError::Rejected { reason, message } => write!(f, "Rejected({reason}: {message})"),
         }
     }
 }
@@ -51,6 +57,15 @@ impl IntoResponse for ApiError {
                 };
                 (code, axum::Json(body)).into_response()
             }
+            ApiError::Rejected { reason, message } => {
+                let code = StatusCode::BAD_REQUEST;
+                let body = RejectionErrorMessage {
+                    code: code.as_u16(),
+                    message: format!("BAD_REQUEST: {message}"),
+                    reason,
+                };
+                (code, axum::Json(body)).into_response()
+            }
             ApiError::BroadcastWithoutImport(msg) => {
                 let code = StatusCode::ACCEPTED;
                 let body = ErrorMessage {
@@ -86,9 +101,9 @@ impl IntoResponse for ApiError {
                         format!("UNSUPPORTED_MEDIA_TYPE: {msg}"),
                     ),
                     // Already handled above.
-                    ApiError::BroadcastWithoutImport(_) | ApiError::IndexedBadRequest { .. } => {
-                        unreachable!()
-                    }
+                    ApiError::BroadcastWithoutImport(_)
+                    | ApiError::IndexedBadRequest { .. }
+                    | ApiError::Rejected { .. } => unreachable!(),
                 };
 
                 let body = ErrorMessage {
@@ -124,6 +139,13 @@ impl ApiError {
         ApiError::UnsupportedMediaType(msg.into())
     }
 
+    pub fn rejected(reason: impl Into<String>, msg: impl Into<String>) -> Self {
+        ApiError::Rejected {
+            reason: reason.into(),
+            message: msg.into(),
+        }
+    }
+
     pub fn object_invalid(msg: impl Into<String>) -> Self {
         ApiError::BadRequest(format!("Invalid object: {}", msg.into()))
     }
@@ -199,6 +221,12 @@ mod tests {
         assert_eq!(format!("{e:?}"), "IndexedBadRequest(batch fail)");
     }
 
+    #[test]
+    fn debug_rejected() {
+        let e = ApiError::rejected("NotHighestValue", "outbid");
+        assert_eq!(format!("{e:?}"), "Rejected(NotHighestValue: outbid)");
+    }
+
     #[test]
     fn debug_forbidden() {
         let e = ApiError::Forbidden("denied".to_string());
@@ -317,4 +345,14 @@ mod tests {
         assert!(body.contains("BAD_REQUEST: batch"));
         assert!(body.contains("bad item"));
     }
+
+    #[tokio::test]
+    async fn into_response_rejected() {
+        let (status, body) =
+            response_status_and_body(ApiError::rejected("BuilderEquivocation", "two bids")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let body: RejectionErrorMessage = serde_json::from_str(&body).unwrap();
+        assert_eq!(body.reason, "BuilderEquivocation");
+        assert!(body.message.contains("BAD_REQUEST: two bids"));
+    }
 }
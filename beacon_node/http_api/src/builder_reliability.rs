@@ -0,0 +1,26 @@
+use crate::api_error::ApiError;
+use beacon_chain::{BeaconChain, BeaconChainTypes};
+use eth2::vibehouse::{BuilderReliability, BuilderReliabilityQuery};
+use std::sync::Arc;
+
+/// Returns the reliability counters of each builder with recorded activity in the epochs of
+/// `query`.
+pub(crate) fn get_builder_reliability<T: BeaconChainTypes>(
+    query: BuilderReliabilityQuery,
+    chain: Arc<BeaconChain<T>>,
+) -> Result<Vec<BuilderReliability>, ApiError> {
+    let BuilderReliabilityQuery {
+        start_epoch,
+        end_epoch,
+    } = query;
+
+    if start_epoch > end_epoch {
+        return Err(ApiError::bad_request(format!(
+            "invalid start and end epochs: {start_epoch}, {end_epoch}"
+        )));
+    }
+
+    chain
+        .get_builder_reliability(start_epoch, end_epoch)
+        .map_err(ApiError::unhandled_error)
+}
@@ -0,0 +1,80 @@
+use crate::api_error::ApiError;
+use crate::state_id::StateId;
+use beacon_chain::{BeaconChain, BeaconChainTypes};
+use eth2::types::{BuilderData, BuilderStatus, ValidatorId};
+use std::{collections::HashSet, sync::Arc};
+use types::ForkName;
+
+/// Returns the builders in the Gloas builder registry of `state_id`, optionally filtered by
+/// index/pubkey and status.
+///
+/// Returns the builders along with the `execution_optimistic` and `finalized` flags and the fork
+/// of the state.
+///
+/// Returns a 400 error for pre-Gloas states, which have no builder registry.
+pub(crate) fn get_beacon_state_builders<T: BeaconChainTypes>(
+    state_id: StateId,
+    chain: Arc<BeaconChain<T>>,
+    query_ids: &Option<Vec<ValidatorId>>,
+    query_statuses: &Option<Vec<BuilderStatus>>,
+) -> Result<(Vec<BuilderData>, bool, bool, ForkName), ApiError> {
+    state_id.map_state_and_execution_optimistic_and_finalized(
+        &chain,
+        |state, execution_optimistic, finalized| {
+            let Ok(builders) = state.builders() else {
+                return Err(ApiError::bad_request(
+                    "Builders not found in pre-Gloas state".to_string(),
+                ));
+            };
+            let current_epoch = state.current_epoch();
+            let finalized_epoch = state.finalized_checkpoint().epoch;
+
+            // Map [] to None, indicating that no filtering should be applied (return all
+            // builders).
+            let ids_filter_set: Option<HashSet<&ValidatorId>> = query_ids
+                .as_ref()
+                .filter(|list| !list.is_empty())
+                .map(HashSet::from_iter);
+
+            let statuses_filter_set: Option<HashSet<&BuilderStatus>> = query_statuses
+                .as_ref()
+                .filter(|list| !list.is_empty())
+                .map(HashSet::from_iter);
+
+            Ok((
+                builders
+                    .iter()
+                    .enumerate()
+                    // filter by builder id(s) if provided
+                    .filter(|(index, builder)| {
+                        ids_filter_set.as_ref().is_none_or(|ids_set| {
+                            ids_set.contains(&ValidatorId::PublicKey(builder.pubkey))
+                                || ids_set.contains(&ValidatorId::Index(*index as u64))
+                        })
+                    })
+                    // filter by status(es) if provided and map the result
+                    .filter_map(|(index, builder)| {
+                        let status = BuilderStatus::from_builder(
+                            builder,
+                            current_epoch,
+                            finalized_epoch,
+                            &chain.spec,
+                        );
+
+                        statuses_filter_set
+                            .as_ref()
+                            .is_none_or(|statuses| statuses.contains(&status))
+                            .then(|| BuilderData {
+                                index: index as u64,
+                                status,
+                                builder: builder.clone(),
+                            })
+                    })
+                    .collect::<Vec<_>>(),
+                execution_optimistic,
+                finalized,
+                state.fork_name_unchecked(),
+            ))
+        },
+    )
+}
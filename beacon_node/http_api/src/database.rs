@@ -1,9 +1,15 @@
 use crate::api_error::ApiError;
+use beacon_chain::migrate::DatabaseSnapshot;
 use beacon_chain::store::metadata::CURRENT_SCHEMA_VERSION;
-use beacon_chain::{BeaconChain, BeaconChainTypes};
+use beacon_chain::{BeaconChain, BeaconChainError, BeaconChainTypes};
+use directory::DEFAULT_BEACON_NODE_DIR;
+use eth2::vibehouse::{DatabaseSnapshotRequest, DatabaseSnapshotState, DatabaseSnapshotStatus};
 use serde::Serialize;
+use std::path::Path;
 use std::sync::Arc;
+use store::snapshot::SnapshotPaths;
 use store::{AnchorInfo, BlobInfo, Split, StoreConfig};
+use types::{Checkpoint, EthSpec};
 
 #[derive(Debug, Serialize)]
 pub(crate) struct DatabaseInfo {
@@ -32,3 +38,88 @@ pub(crate) fn info<T: BeaconChainTypes>(
         blob_info,
     })
 }
+
+/// Start copying the database to `request.target_dir`, laid out like a data directory.
+pub(crate) fn start_snapshot<T: BeaconChainTypes>(
+    chain: Arc<BeaconChain<T>>,
+    request: DatabaseSnapshotRequest,
+) -> Result<DatabaseSnapshotStatus, ApiError> {
+    if !request.target_dir.is_absolute() {
+        return Err(ApiError::bad_request(format!(
+            "target_dir must be an absolute path: {}",
+            request.target_dir.display()
+        )));
+    }
+    let beacon_dir = request.target_dir.join(DEFAULT_BEACON_NODE_DIR);
+    let paths = SnapshotPaths {
+        hot: beacon_dir.join("chain_db"),
+        cold: beacon_dir.join("freezer_db"),
+        blobs: beacon_dir.join("blobs_db"),
+    };
+    for path in [&paths.hot, &paths.cold, &paths.blobs] {
+        if path.exists() {
+            return Err(ApiError::bad_request(format!(
+                "{} already exists",
+                path.display()
+            )));
+        }
+    }
+
+    let snapshot = chain
+        .store_migrator
+        .process_snapshot(paths)
+        .map_err(|e| match e {
+            BeaconChainError::DatabaseSnapshotInProgress => {
+                ApiError::bad_request("a database snapshot is already in progress")
+            }
+            e => ApiError::unhandled_error(e),
+        })?;
+    Ok(snapshot_status::<T::EthSpec>(
+        &request.target_dir,
+        &snapshot,
+    ))
+}
+
+/// The status of the most recently requested snapshot.
+pub(crate) fn latest_snapshot<T: BeaconChainTypes>(
+    chain: Arc<BeaconChain<T>>,
+) -> Result<DatabaseSnapshotStatus, ApiError> {
+    let snapshot = chain
+        .store_migrator
+        .latest_snapshot()
+        .ok_or_else(|| ApiError::not_found("no database snapshot has been requested"))?;
+    let target_dir = snapshot
+        .paths
+        .hot
+        .parent()
+        .and_then(Path::parent)
+        .unwrap_or(&snapshot.paths.hot)
+        .to_path_buf();
+    Ok(snapshot_status::<T::EthSpec>(&target_dir, &snapshot))
+}
+
+fn snapshot_status<E: EthSpec>(
+    target_dir: &Path,
+    snapshot: &DatabaseSnapshot,
+) -> DatabaseSnapshotStatus {
+    let (state, finalized_checkpoint, error) = match snapshot.outcome() {
+        None => (DatabaseSnapshotState::Running, None, None),
+        Some(Ok(split)) => (
+            DatabaseSnapshotState::Complete,
+            Some(Checkpoint {
+                epoch: split.slot.epoch(E::slots_per_epoch()),
+                root: split.block_root,
+            }),
+            None,
+        ),
+        Some(Err(error)) => (DatabaseSnapshotState::Failed, None, Some(error)),
+    };
+    DatabaseSnapshotStatus {
+        target_dir: target_dir.to_path_buf(),
+        state,
+        entries_copied: snapshot.progress.entries(),
+        bytes_copied: snapshot.progress.bytes(),
+        finalized_checkpoint,
+        error,
+    }
+}
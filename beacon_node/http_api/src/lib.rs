@@ -17,7 +17,9 @@ mod block_id;
 mod block_packing_efficiency;
 mod block_rewards;
 mod build_block_contents;
+mod builder_reliability;
 mod builder_states;
+mod builders;
 mod custody;
 mod database;
 mod extractors;
@@ -30,6 +32,8 @@ mod proposer_duties;
 mod ptc_duties;
 mod publish_attestations;
 mod publish_blocks;
+mod publish_builder_messages;
+mod side_objects;
 mod standard_block_rewards;
 mod state_id;
 mod sync_committee_rewards;
@@ -38,6 +42,7 @@ mod task_spawner;
 pub mod test_utils;
 mod ui;
 mod validator;
+mod validator_history;
 mod validator_inclusion;
 mod validators;
 mod version;
@@ -78,7 +83,7 @@ pub use publish_blocks::{
 };
 use serde::{Deserialize, Serialize};
 use slot_clock::SlotClock;
-use ssz::Encode;
+use ssz::{Decode, Encode};
 pub use state_id::StateId;
 use std::collections::HashSet;
 use std::future::Future;
@@ -98,16 +103,18 @@ use tracing::{debug, error, info, warn};
 use types::{
     Attestation, AttestationData, AttestationShufflingId, AttesterSlashing, BeaconStateError,
     ChainSpec, Checkpoint, CommitteeCache, ConfigAndPreset, Domain, Epoch, EthSpec, ExecutionProof,
-    ForkName, Hash256, PayloadAttestationMessage, ProposerPreparationData, ProposerSlashing,
-    RelativeEpoch, SignedAggregateAndProof, SignedBlindedBeaconBlock, SignedBlsToExecutionChange,
-    SignedContributionAndProof, SignedExecutionPayloadBid, SignedExecutionPayloadEnvelope,
-    SignedProposerPreferences, SignedRoot, SignedValidatorRegistrationData, SignedVoluntaryExit,
-    SingleAttestation, Slot, SyncCommitteeMessage, SyncContributionData,
+    ForkName, ForkVersionDecode, Hash256, PayloadAttestationMessage, ProposerPreparationData,
+    ProposerSlashing, RelativeEpoch, SignedAggregateAndProof, SignedBlindedBeaconBlock,
+    SignedBlsToExecutionChange, SignedContributionAndProof, SignedExecutionPayloadBid,
+    SignedExecutionPayloadEnvelope, SignedProposerPreferences, SignedRoot,
+    SignedValidatorRegistrationData, SignedVoluntaryExit, SingleAttestation, Slot,
+    SyncCommitteeMessage, SyncContributionData,
 };
 use validator::pubkey_to_validator_index;
 use version::{
     ResponseIncludesVersion, V1, V2, V3, add_consensus_version_header, add_ssz_content_type_header,
-    execution_optimistic_finalized_beacon_response, inconsistent_fork_rejection,
+    execution_optimistic_finalized_beacon_response,
+    execution_optimistic_finalized_ssz_or_json_response, inconsistent_fork_rejection,
     unsupported_version_rejection,
 };
 use vibehouse_network::rpc::methods::MetaData;
@@ -555,6 +562,18 @@ pub fn serve<T: BeaconChainTypes>(
             "/eth/v1/beacon/states/{state_id}/pending_consolidations",
             get(get_beacon_state_pending_consolidations::<T>),
         )
+        .route(
+            "/eth/v1/beacon/states/{state_id}/builders",
+            get(get_beacon_state_builders::<T>),
+        )
+        .route(
+            "/eth/v1/beacon/states/{state_id}/builder_pending_payments",
+            get(get_beacon_state_builder_pending_payments::<T>),
+        )
+        .route(
+            "/eth/v1/beacon/states/{state_id}/builder_pending_withdrawals",
+            get(get_beacon_state_builder_pending_withdrawals::<T>),
+        )
         .route(
             "/eth/v1/beacon/states/{state_id}/proposer_lookahead",
             get(get_beacon_state_proposer_lookahead::<T>),
@@ -846,6 +865,14 @@ pub fn serve<T: BeaconChainTypes>(
             "/vibehouse/analysis/block_packing_efficiency",
             get(get_vibehouse_block_packing_efficiency::<T>),
         )
+        .route(
+            "/vibehouse/analysis/builders",
+            get(get_vibehouse_builder_reliability::<T>),
+        )
+        .route(
+            "/vibehouse/analysis/validator_history/{validator_id}",
+            get(get_vibehouse_validator_history::<T>),
+        )
         .route(
             "/vibehouse/merge_readiness",
             get(get_vibehouse_merge_readiness::<T>),
@@ -873,6 +900,10 @@ pub fn serve<T: BeaconChainTypes>(
             "/vibehouse/database/reconstruct",
             post(post_vibehouse_database_reconstruct::<T>),
         )
+        .route(
+            "/vibehouse/database/snapshot",
+            post(post_vibehouse_database_snapshot::<T>).get(get_vibehouse_database_snapshot::<T>),
+        )
         .route(
             "/vibehouse/custody/backfill",
             post(post_vibehouse_custody_backfill::<T>),
@@ -887,6 +918,18 @@ pub fn serve<T: BeaconChainTypes>(
             "/vibehouse/execution_proofs",
             post(post_vibehouse_execution_proofs::<T>),
         )
+        .route(
+            "/vibehouse/history/inclusion_lists/{block_id}",
+            get(get_vibehouse_history_inclusion_lists::<T>),
+        )
+        .route(
+            "/vibehouse/history/payload_attestations/{block_id}",
+            get(get_vibehouse_history_payload_attestations::<T>),
+        )
+        .route(
+            "/vibehouse/history/execution_proofs/{block_id}",
+            get(get_vibehouse_history_execution_proofs::<T>),
+        )
         // Middleware
         .layer(middleware::from_fn(prometheus_metrics_middleware))
         .layer(SetResponseHeaderLayer::overriding(
@@ -1568,6 +1611,111 @@ async fn get_beacon_state_pending_consolidations<T: BeaconChainTypes>(
         .await
 }
 
+async fn get_beacon_state_builders<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    Path(state_id): Path<StateId>,
+    MultiKeyQuery(query): MultiKeyQuery<api_types::BuildersQuery>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    let accept = accept_header(&headers);
+    state
+        .task_spawner()
+        .blocking_response_task(Priority::P1, move || {
+            let (data, execution_optimistic, finalized, fork_name) =
+                crate::builders::get_beacon_state_builders(
+                    state_id,
+                    chain,
+                    &query.id,
+                    &query.status,
+                )?;
+            execution_optimistic_finalized_ssz_or_json_response(
+                accept,
+                fork_name,
+                execution_optimistic,
+                finalized,
+                data,
+            )
+        })
+        .await
+}
+
+async fn get_beacon_state_builder_pending_payments<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    Path(state_id): Path<StateId>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    let accept = accept_header(&headers);
+    state
+        .task_spawner()
+        .blocking_response_task(Priority::P1, move || {
+            let (data, execution_optimistic, finalized, fork_name) = state_id
+                .map_state_and_execution_optimistic_and_finalized(
+                    &chain,
+                    |state, execution_optimistic, finalized| {
+                        let Ok(payments) = state.builder_pending_payments() else {
+                            return Err(ApiError::bad_request(
+                                "Builder pending payments not found".to_string(),
+                            ));
+                        };
+                        Ok((
+                            payments.clone(),
+                            execution_optimistic,
+                            finalized,
+                            state.fork_name_unchecked(),
+                        ))
+                    },
+                )?;
+            execution_optimistic_finalized_ssz_or_json_response(
+                accept,
+                fork_name,
+                execution_optimistic,
+                finalized,
+                data,
+            )
+        })
+        .await
+}
+
+async fn get_beacon_state_builder_pending_withdrawals<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    Path(state_id): Path<StateId>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    let accept = accept_header(&headers);
+    state
+        .task_spawner()
+        .blocking_response_task(Priority::P1, move || {
+            let (data, execution_optimistic, finalized, fork_name) = state_id
+                .map_state_and_execution_optimistic_and_finalized(
+                    &chain,
+                    |state, execution_optimistic, finalized| {
+                        let Ok(withdrawals) = state.builder_pending_withdrawals() else {
+                            return Err(ApiError::bad_request(
+                                "Builder pending withdrawals not found".to_string(),
+                            ));
+                        };
+                        Ok((
+                            withdrawals.clone(),
+                            execution_optimistic,
+                            finalized,
+                            state.fork_name_unchecked(),
+                        ))
+                    },
+                )?;
+            execution_optimistic_finalized_ssz_or_json_response(
+                accept,
+                fork_name,
+                execution_optimistic,
+                finalized,
+                data,
+            )
+        })
+        .await
+}
+
 async fn get_beacon_state_proposer_lookahead<T: BeaconChainTypes>(
     State(state): State<SharedState<T>>,
     Path(state_id): Path<StateId>,
@@ -2502,11 +2650,21 @@ async fn post_beacon_pool_payload_attestations<T: BeaconChainTypes>(
     state
         .task_spawner()
         .blocking_json_task(Priority::P0, move || {
+            let seen_timestamp = timestamp_now();
             let mut failures = vec![];
 
             for (index, message) in messages.into_iter().enumerate() {
                 match chain.import_payload_attestation_message(message.clone()) {
                     Ok(_attestation) => {
+                        chain
+                            .validator_monitor
+                            .read()
+                            .register_api_payload_attestation(
+                                seen_timestamp,
+                                &message,
+                                &chain.slot_clock,
+                            );
+
                         publish_pubsub_message(
                             &network_tx,
                             PubsubMessage::PayloadAttestation(Box::new(message)),
@@ -2747,44 +2905,44 @@ async fn get_expected_withdrawals_handler<T: BeaconChainTypes>(
 
 async fn post_builder_bids<T: BeaconChainTypes>(
     State(state): State<SharedState<T>>,
+    Query(validation_level): Query<api_types::BroadcastValidationQuery>,
     headers: HeaderMap,
     body: Bytes,
 ) -> Result<Response, ApiError> {
     let chain = state.chain()?;
     let network_tx = state.network_tx()?;
-    let bid: SignedExecutionPayloadBid<T::EthSpec> = json_body(&headers, body).await?;
+    let consensus_version = optional_consensus_version_header(&headers);
+    let is_ssz = headers
+        .get(CONTENT_TYPE_HEADER)
+        .is_some_and(|ct| ct.as_bytes() == SSZ_CONTENT_TYPE_HEADER.as_bytes());
     state
         .task_spawner()
         .blocking_json_task(Priority::P0, move || {
-            use beacon_chain::gloas_verification::ExecutionBidError;
-
             if !chain.spec.is_gloas_scheduled() {
                 return Err(ApiError::bad_request("Gloas is not scheduled"));
             }
 
-            let builder_index = *bid.to_ref().message().builder_index();
-
-            let verified_bid = match chain.verify_execution_bid_for_gossip(bid) {
-                Ok(verified) => verified,
-                Err(ExecutionBidError::DuplicateBid { .. }) => {
-                    debug!(builder_index, "Duplicate execution bid submitted via HTTP");
-                    return Ok(());
-                }
-                Err(e) => {
-                    return Err(ApiError::bad_request(format!(
-                        "invalid execution bid: {e:?}"
-                    )));
-                }
+            let bid = if is_ssz {
+                let fork_name = consensus_version
+                    .ok_or_else(|| ApiError::bad_request("missing Eth-Consensus-Version header"))?;
+                SignedExecutionPayloadBid::<T::EthSpec>::from_ssz_bytes_by_fork(&body, fork_name)
+                    .map_err(|e| ApiError::bad_request(format!("invalid SSZ: {e:?}")))?
+            } else if let Some(fork_name) = consensus_version {
+                let value: serde_json::Value = serde_json::from_slice(&body)
+                    .map_err(|e| ApiError::bad_request(format!("invalid JSON: {e:?}")))?;
+                SignedExecutionPayloadBid::<T::EthSpec>::context_deserialize(&value, fork_name)
+                    .map_err(|e| ApiError::bad_request(format!("invalid JSON: {e:?}")))?
+            } else {
+                serde_json::from_slice(&body)
+                    .map_err(|e| ApiError::bad_request(format!("body deserialize error: {e:?}")))?
             };
 
-            chain.import_execution_bid(&verified_bid);
-
-            publish_pubsub_message(
+            publish_builder_messages::publish_execution_bid(
+                bid,
+                &chain,
                 &network_tx,
-                PubsubMessage::ExecutionBid(Box::new(verified_bid.into_inner())),
-            )?;
-
-            Ok(())
+                validation_level.broadcast_validation,
+            )
         })
         .await
 }
@@ -2818,6 +2976,8 @@ async fn post_beacon_pool_inclusion_lists<T: BeaconChainTypes>(
         .blocking_json_task(Priority::P0, move || {
             use beacon_chain::heze_verification::InclusionListError;
 
+            let seen_timestamp = timestamp_now();
+
             if !chain.spec.is_heze_scheduled() {
                 return Err(ApiError::bad_request("Heze is not scheduled"));
             }
@@ -2835,6 +2995,13 @@ async fn post_beacon_pool_inclusion_lists<T: BeaconChainTypes>(
                 }
             };
 
+            chain.validator_monitor.read().register_api_inclusion_list(
+                seen_timestamp,
+                &verified_il.signed_il,
+                verified_il.is_before_view_freeze_cutoff,
+                &chain.slot_clock,
+            );
+
             let signed_for_gossip = verified_il.signed_il.clone();
             chain.import_inclusion_list(verified_il);
 
@@ -2890,80 +3057,38 @@ async fn get_beacon_execution_payload_envelope<T: BeaconChainTypes>(
 
 async fn post_beacon_execution_payload_envelope<T: BeaconChainTypes>(
     State(state): State<SharedState<T>>,
+    Query(validation_level): Query<api_types::BroadcastValidationQuery>,
     headers: HeaderMap,
     body: Bytes,
 ) -> Result<Response, ApiError> {
     let chain = state.chain()?;
     let network_tx = state.network_tx()?;
-    let envelope: SignedExecutionPayloadEnvelope<T::EthSpec> = json_body(&headers, body).await?;
+    let is_ssz = headers
+        .get(CONTENT_TYPE_HEADER)
+        .is_some_and(|ct| ct.as_bytes() == SSZ_CONTENT_TYPE_HEADER.as_bytes());
     state
         .task_spawner()
         .spawn_async_with_rejection(Priority::P0, async move {
-            use beacon_chain::gloas_verification::PayloadEnvelopeError;
-
             if !chain.spec.is_gloas_scheduled() {
                 return Err(ApiError::bad_request("Gloas is not scheduled"));
             }
 
-            let builder_index = envelope.message.builder_index;
-
-            let verified_envelope =
-                match chain.verify_payload_envelope_for_gossip(Arc::new(envelope)) {
-                    Ok(verified) => verified,
-                    Err(PayloadEnvelopeError::PriorToFinalization { .. }) => {
-                        debug!(
-                            builder_index,
-                            "Stale execution payload envelope submitted via HTTP"
-                        );
-                        return Ok(StatusCode::OK.into_response());
-                    }
-                    Err(e) => {
-                        return Err(ApiError::bad_request(format!(
-                            "invalid execution payload envelope: {e:?}"
-                        )));
-                    }
-                };
+            let envelope = if is_ssz {
+                SignedExecutionPayloadEnvelope::<T::EthSpec>::from_ssz_bytes(&body)
+                    .map_err(|e| ApiError::bad_request(format!("invalid SSZ: {e:?}")))?
+            } else {
+                serde_json::from_slice(&body)
+                    .map_err(|e| ApiError::bad_request(format!("body deserialize error: {e:?}")))?
+            };
 
-            publish_pubsub_message(
+            publish_builder_messages::publish_execution_payload_envelope(
+                envelope,
+                chain,
                 &network_tx,
-                PubsubMessage::ExecutionPayload(Box::new(verified_envelope.envelope().clone())),
-            )?;
-
-            match chain.process_payload_envelope(&verified_envelope).await {
-                Ok(el_valid) => {
-                    let beacon_block_root = verified_envelope.beacon_block_root();
-
-                    if let Err(e) = chain.apply_payload_envelope_to_fork_choice(&verified_envelope)
-                    {
-                        warn!(
-                            ?beacon_block_root,
-                            builder_index,
-                            error = ?e,
-                            "Failed to import payload envelope to fork choice"
-                        );
-                    } else if el_valid
-                        && let Err(e) = chain
-                            .canonical_head
-                            .fork_choice_write_lock()
-                            .on_valid_execution_payload(beacon_block_root)
-                    {
-                        warn!(
-                            ?beacon_block_root,
-                            error = ?e,
-                            "Failed to mark envelope payload as valid in fork choice"
-                        );
-                    }
-                }
-                Err(e) => {
-                    warn!(
-                        builder_index,
-                        error = ?e,
-                        "Failed to process execution payload envelope"
-                    );
-                }
-            }
-
-            Ok(StatusCode::OK.into_response())
+                validation_level.broadcast_validation,
+            )
+            .await
+            .map(|()| StatusCode::OK.into_response())
         })
         .await
 }
@@ -3290,6 +3415,7 @@ async fn get_debug_fork_choice<T: BeaconChainTypes>(
             Ok(ForkChoice {
                 justified_checkpoint: proto_array.justified_checkpoint,
                 finalized_checkpoint: proto_array.finalized_checkpoint,
+                confirmed_root: beacon_fork_choice.confirmed_root(),
                 fork_choice_nodes,
             })
         })
@@ -4297,6 +4423,14 @@ async fn get_events<T: BeaconChainTypes>(
             api_types::EventTopic::ExecutionProofReceived => {
                 event_handler.subscribe_execution_proof_received()
             }
+            api_types::EventTopic::FastConfirmation => event_handler.subscribe_fast_confirmation(),
+            api_types::EventTopic::InclusionList => event_handler.subscribe_inclusion_list(),
+            api_types::EventTopic::ProposerPreferences => {
+                event_handler.subscribe_proposer_preferences()
+            }
+            api_types::EventTopic::PayloadAttestationOutcome => {
+                event_handler.subscribe_payload_attestation_outcome()
+            }
         };
 
         receivers.push(
@@ -4559,6 +4693,33 @@ async fn get_vibehouse_block_packing_efficiency<T: BeaconChainTypes>(
         .await
 }
 
+async fn get_vibehouse_builder_reliability<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    Query(query): Query<eth2::vibehouse::BuilderReliabilityQuery>,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    state
+        .task_spawner()
+        .blocking_json_task(Priority::P1, move || {
+            builder_reliability::get_builder_reliability(query, chain)
+        })
+        .await
+}
+
+async fn get_vibehouse_validator_history<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    Path(validator_id): Path<ValidatorId>,
+    Query(query): Query<eth2::vibehouse::ValidatorHistoryQuery>,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    state
+        .task_spawner()
+        .blocking_json_task(Priority::P1, move || {
+            validator_history::get_validator_history(validator_id, query, chain)
+        })
+        .await
+}
+
 async fn get_vibehouse_merge_readiness<T: BeaconChainTypes>(
     State(state): State<SharedState<T>>,
 ) -> Result<Response, ApiError> {
@@ -4750,6 +4911,31 @@ async fn post_vibehouse_database_reconstruct<T: BeaconChainTypes>(
         .await
 }
 
+async fn post_vibehouse_database_snapshot<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    let request_data: eth2::vibehouse::DatabaseSnapshotRequest = json_body(&headers, body).await?;
+    state
+        .task_spawner()
+        .blocking_json_task(Priority::P1, move || {
+            database::start_snapshot(chain, request_data)
+        })
+        .await
+}
+
+async fn get_vibehouse_database_snapshot<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    state
+        .task_spawner()
+        .blocking_json_task(Priority::P1, move || database::latest_snapshot(chain))
+        .await
+}
+
 async fn post_vibehouse_custody_backfill<T: BeaconChainTypes>(
     State(state): State<SharedState<T>>,
 ) -> Result<Response, ApiError> {
@@ -4848,6 +5034,46 @@ async fn get_vibehouse_execution_proof_status<T: BeaconChainTypes>(
         .await
 }
 
+async fn get_vibehouse_history_inclusion_lists<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    Path(block_id): Path<BlockId>,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    state
+        .task_spawner()
+        .blocking_json_task(Priority::P1, move || {
+            side_objects::get_inclusion_lists(block_id, chain)
+        })
+        .await
+}
+
+async fn get_vibehouse_history_payload_attestations<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    Path(block_id): Path<BlockId>,
+    Query(query): Query<eth2::vibehouse::PayloadAttestationHistoryQuery>,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    state
+        .task_spawner()
+        .blocking_json_task(Priority::P1, move || {
+            side_objects::get_payload_attestations(block_id, query, chain)
+        })
+        .await
+}
+
+async fn get_vibehouse_history_execution_proofs<T: BeaconChainTypes>(
+    State(state): State<SharedState<T>>,
+    Path(block_id): Path<BlockId>,
+) -> Result<Response, ApiError> {
+    let chain = state.chain()?;
+    state
+        .task_spawner()
+        .blocking_json_task(Priority::P1, move || {
+            side_objects::get_execution_proofs(block_id, chain)
+        })
+        .await
+}
+
 async fn post_vibehouse_execution_proofs<T: BeaconChainTypes>(
     State(state): State<SharedState<T>>,
     headers: HeaderMap,
@@ -0,0 +1,212 @@
+//! Publication of builder messages (execution payload bids and envelopes) submitted over the HTTP
+//! API, using the same broadcast validation levels as `publish_blocks`:
+//!
+//! - `gossip`: publish as soon as the message passes gossip verification.
+//! - `consensus`: publish only once the message passes the checks applied on import. For bids
+//!   this is `process_execution_payload_bid` against the parent state, for envelopes it is the
+//!   envelope state transition and `newPayload`.
+//! - `consensus_and_equivocation`: as `consensus`, and additionally refuse to publish a bid if its
+//!   builder has signed any conflicting bid for the same slot. Envelopes are
+//!   deduplicated by block root during gossip verification, so this level is equivalent to
+//!   `consensus` for them.
+//!
+//! Verification failures are returned as `ApiError::Rejected`, with the name of the failed check
+//! as the reason.
+
+use crate::api_error::ApiError;
+use beacon_chain::gloas_verification::{ExecutionBidError, PayloadEnvelopeError};
+use beacon_chain::{BeaconChain, BeaconChainError, BeaconChainTypes};
+use eth2::types::BroadcastValidation;
+use network::NetworkMessage;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{debug, warn};
+use types::{SignedExecutionPayloadBid, SignedExecutionPayloadEnvelope};
+use vibehouse_network::PubsubMessage;
+
+/// Rejection reason for envelopes which fail the envelope state transition or `newPayload`.
+const ENVELOPE_CONSENSUS_INVALID: &str = "ConsensusInvalid";
+
+/// Verify `bid` to `validation_level`, then import it to the bid pool and publish it.
+///
+/// The bid is only recorded as observed once it has passed `validation_level`, so that a bid
+/// which fails can be corrected and resubmitted. Duplicates of bids which have already been
+/// observed, and therefore published, are silently accepted.
+pub fn publish_execution_bid<T: BeaconChainTypes>(
+    bid: SignedExecutionPayloadBid<T::EthSpec>,
+    chain: &BeaconChain<T>,
+    network_tx: &UnboundedSender<NetworkMessage<T::EthSpec>>,
+    validation_level: BroadcastValidation,
+) -> Result<(), ApiError> {
+    let builder_index = *bid.to_ref().message().builder_index();
+
+    let verified_bid = chain
+        .verify_execution_bid_for_gossip_without_observation(bid)
+        .map_err(execution_bid_rejection)?;
+
+    if validation_level != BroadcastValidation::Gossip {
+        chain
+            .verify_execution_bid_for_consensus(&verified_bid)
+            .map_err(execution_bid_rejection)?;
+    }
+
+    let observation = if validation_level == BroadcastValidation::ConsensusAndEquivocation {
+        chain.observe_execution_bid_unless_equivocating(&verified_bid)
+    } else {
+        chain.observe_execution_bid(&verified_bid)
+    };
+
+    match observation {
+        Ok(()) => {}
+        Err(ExecutionBidError::DuplicateBid { .. }) => {
+            debug!(builder_index, "Duplicate execution bid submitted via HTTP");
+            return Ok(());
+        }
+        Err(e) => return Err(execution_bid_rejection(e)),
+    }
+
+    chain.import_execution_bid(&verified_bid);
+
+    crate::publish_pubsub_message(
+        network_tx,
+        PubsubMessage::ExecutionBid(Box::new(verified_bid.into_inner())),
+    )
+}
+
+/// Verify `envelope` to `validation_level`, then publish it and apply it to fork choice.
+///
+/// As for bids, the envelope is only recorded as observed once it has passed `validation_level`,
+/// so that an envelope which fails processing can be corrected and resubmitted. Duplicate
+/// envelopes and envelopes prior to finalization are silently accepted. If the envelope was
+/// published at the `gossip` level, a processing failure is only logged.
+pub async fn publish_execution_payload_envelope<T: BeaconChainTypes>(
+    envelope: SignedExecutionPayloadEnvelope<T::EthSpec>,
+    chain: Arc<BeaconChain<T>>,
+    network_tx: &UnboundedSender<NetworkMessage<T::EthSpec>>,
+    validation_level: BroadcastValidation,
+) -> Result<(), ApiError> {
+    let builder_index = envelope.message.builder_index;
+
+    let verified_envelope =
+        match chain.verify_payload_envelope_for_gossip_without_observation(Arc::new(envelope)) {
+            Ok(verified) => verified,
+            Err(PayloadEnvelopeError::DuplicateEnvelope { .. }) => {
+                debug!(
+                    builder_index,
+                    "Duplicate execution payload envelope submitted via HTTP"
+                );
+                return Ok(());
+            }
+            Err(PayloadEnvelopeError::PriorToFinalization { .. }) => {
+                debug!(
+                    builder_index,
+                    "Stale execution payload envelope submitted via HTTP"
+                );
+                return Ok(());
+            }
+            Err(e) => return Err(payload_envelope_rejection(e)),
+        };
+    let beacon_block_root = verified_envelope.beacon_block_root();
+
+    let observe_and_publish = || -> Result<bool, ApiError> {
+        match chain.observe_payload_envelope(&verified_envelope) {
+            Ok(()) => {}
+            Err(PayloadEnvelopeError::DuplicateEnvelope { .. }) => {
+                debug!(
+                    builder_index,
+                    "Duplicate execution payload envelope submitted via HTTP"
+                );
+                return Ok(false);
+            }
+            Err(e) => return Err(payload_envelope_rejection(e)),
+        }
+        crate::publish_pubsub_message(
+            network_tx,
+            PubsubMessage::ExecutionPayload(Box::new(verified_envelope.envelope().clone())),
+        )?;
+        Ok(true)
+    };
+
+    if validation_level == BroadcastValidation::Gossip && !observe_and_publish()? {
+        return Ok(());
+    }
+
+    let el_valid = match chain.process_payload_envelope(&verified_envelope).await {
+        Ok(el_valid) => el_valid,
+        Err(e) => {
+            warn!(
+                ?beacon_block_root,
+                builder_index,
+                error = ?e,
+                "Failed to process execution payload envelope"
+            );
+            if validation_level == BroadcastValidation::Gossip {
+                return Ok(());
+            }
+            return Err(match e {
+                BeaconChainError::EnvelopeError(_)
+                | BeaconChainError::EnvelopeProcessingError(_) => ApiError::rejected(
+                    ENVELOPE_CONSENSUS_INVALID,
+                    format!("invalid execution payload envelope: {e:?}"),
+                ),
+                e => ApiError::server_error(format!(
+                    "unable to process execution payload envelope: {e:?}"
+                )),
+            });
+        }
+    };
+
+    if validation_level != BroadcastValidation::Gossip && !observe_and_publish()? {
+        return Ok(());
+    }
+
+    if let Err(e) = chain.apply_payload_envelope_to_fork_choice(&verified_envelope) {
+        warn!(
+            ?beacon_block_root,
+            builder_index,
+            error = ?e,
+            "Failed to import payload envelope to fork choice"
+        );
+    } else if el_valid
+        && let Err(e) = chain
+            .canonical_head
+            .fork_choice_write_lock()
+            .on_valid_execution_payload(beacon_block_root)
+    {
+        warn!(
+            ?beacon_block_root,
+            error = ?e,
+            "Failed to mark envelope payload as valid in fork choice"
+        );
+    }
+
+    Ok(())
+}
+
+fn execution_bid_rejection(e: ExecutionBidError) -> ApiError {
+    match e {
+        ExecutionBidError::BeaconChainError(_)
+        | ExecutionBidError::BeaconStateError(_)
+        | ExecutionBidError::ArithError(_) => {
+            ApiError::server_error(format!("unable to verify execution bid: {e:?}"))
+        }
+        e => {
+            let reason: &str = e.as_ref();
+            ApiError::rejected(reason, format!("invalid execution bid: {e:?}"))
+        }
+    }
+}
+
+fn payload_envelope_rejection(e: PayloadEnvelopeError) -> ApiError {
+    match e {
+        PayloadEnvelopeError::BeaconChainError(_) | PayloadEnvelopeError::BeaconStateError(_) => {
+            ApiError::server_error(format!(
+                "unable to verify execution payload envelope: {e:?}"
+            ))
+        }
+        e => {
+            let reason: &str = e.as_ref();
+            ApiError::rejected(reason, format!("invalid execution payload envelope: {e:?}"))
+        }
+    }
+}
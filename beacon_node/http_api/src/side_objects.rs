@@ -0,0 +1,91 @@
+use crate::api_error::ApiError;
+use crate::block_id::BlockId;
+use beacon_chain::{BeaconChain, BeaconChainTypes};
+use eth2::types::BlockId as CoreBlockId;
+use eth2::types::{ExecutionOptimisticFinalizedResponse, GenericResponse};
+use eth2::vibehouse::PayloadAttestationHistoryQuery;
+use std::sync::Arc;
+use types::{ExecutionProof, Hash256, PayloadAttestation, SignedInclusionList, Slot};
+
+/// Returns the slot identified by `block_id`, and the root of the block it identifies unless it is
+/// a slot.
+///
+/// Slots are not resolved to blocks, so that the objects of skipped slots can be fetched.
+fn slot_and_block_root<T: BeaconChainTypes>(
+    block_id: &BlockId,
+    chain: &BeaconChain<T>,
+) -> Result<(Slot, Option<Hash256>), ApiError> {
+    if let CoreBlockId::Slot(slot) = block_id.0 {
+        return Ok((slot, None));
+    }
+    let (block, _, _) = block_id.blinded_block(chain)?;
+    Ok((block.slot(), Some(block.canonical_root())))
+}
+
+/// Returns the signed inclusion lists received for the slot of `block_id`.
+pub(crate) fn get_inclusion_lists<T: BeaconChainTypes>(
+    block_id: BlockId,
+    chain: Arc<BeaconChain<T>>,
+) -> Result<GenericResponse<Vec<SignedInclusionList<T::EthSpec>>>, ApiError> {
+    let (slot, _) = slot_and_block_root(&block_id, &chain)?;
+    chain
+        .get_inclusion_list_history(slot)
+        .map(GenericResponse::from)
+        .map_err(ApiError::unhandled_error)
+}
+
+/// Returns the aggregated payload attestations received for the slot of `block_id`, optionally
+/// only those for the block in `query`.
+///
+/// If `block_id` identifies a block rather than a slot, only the attestations for that block are
+/// returned.
+pub(crate) fn get_payload_attestations<T: BeaconChainTypes>(
+    block_id: BlockId,
+    query: PayloadAttestationHistoryQuery,
+    chain: Arc<BeaconChain<T>>,
+) -> Result<GenericResponse<Vec<PayloadAttestation<T::EthSpec>>>, ApiError> {
+    let (slot, block_root) = slot_and_block_root(&block_id, &chain)?;
+    let mut attestations = chain
+        .get_payload_attestation_history(slot)
+        .map_err(ApiError::unhandled_error)?;
+    for beacon_block_root in [block_root, query.beacon_block_root].into_iter().flatten() {
+        attestations.retain(|attestation| attestation.data.beacon_block_root == beacon_block_root);
+    }
+    Ok(GenericResponse::from(attestations))
+}
+
+/// Returns the execution proofs of the block identified by `block_id`, ordered by subnet id.
+///
+/// Proofs of unfinalized blocks are served until they are pruned, and those of finalized blocks
+/// only if they were retained.
+pub(crate) fn get_execution_proofs<T: BeaconChainTypes>(
+    block_id: BlockId,
+    chain: Arc<BeaconChain<T>>,
+) -> Result<ExecutionOptimisticFinalizedResponse<Vec<ExecutionProof>>, ApiError> {
+    let (block, execution_optimistic, finalized) = block_id.blinded_block(&chain)?;
+    let block_root = block.canonical_root();
+
+    let mut proofs = chain
+        .store
+        .get_execution_proofs(&block_root)
+        .map_err(ApiError::unhandled_error)?
+        .into_iter()
+        .map(Arc::unwrap_or_clone)
+        .collect::<Vec<_>>();
+    if proofs.is_empty() {
+        proofs = chain
+            .store
+            .get_execution_proof_history(block.slot())
+            .map_err(ApiError::unhandled_error)?
+            .into_iter()
+            .filter(|proof| proof.block_root == block_root)
+            .collect();
+        proofs.sort_by_key(|proof| *proof.subnet_id);
+    }
+
+    Ok(ExecutionOptimisticFinalizedResponse {
+        data: proofs,
+        execution_optimistic: Some(execution_optimistic),
+        finalized: Some(finalized),
+    })
+}
@@ -0,0 +1,62 @@
+use crate::api_error::ApiError;
+use beacon_chain::{BeaconChain, BeaconChainTypes};
+use eth2::types::ValidatorId;
+use eth2::vibehouse::{ValidatorHistory, ValidatorHistoryQuery};
+use std::sync::Arc;
+use store::validator_history::ValidatorEpochSummary;
+use types::consts::altair::{
+    TIMELY_HEAD_FLAG_INDEX, TIMELY_SOURCE_FLAG_INDEX, TIMELY_TARGET_FLAG_INDEX,
+};
+
+/// Returns the recorded history of a validator over the epochs of `query`.
+///
+/// Only epochs which have been migrated to the freezer (or reconstructed) while
+/// `--validator-history` was enabled are included.
+pub(crate) fn get_validator_history<T: BeaconChainTypes>(
+    validator_id: ValidatorId,
+    query: ValidatorHistoryQuery,
+    chain: Arc<BeaconChain<T>>,
+) -> Result<Vec<ValidatorHistory>, ApiError> {
+    let ValidatorHistoryQuery {
+        start_epoch,
+        end_epoch,
+    } = query;
+
+    if start_epoch > end_epoch {
+        return Err(ApiError::bad_request(format!(
+            "invalid start and end epochs: {start_epoch}, {end_epoch}"
+        )));
+    }
+
+    let validator_index = match validator_id {
+        ValidatorId::Index(index) => index,
+        ValidatorId::PublicKey(pubkey) => chain
+            .validator_index(&pubkey)
+            .map_err(ApiError::unhandled_error)?
+            .ok_or_else(|| ApiError::not_found(format!("unknown validator: {pubkey}")))?
+            as u64,
+    };
+
+    let history = chain
+        .store
+        .get_validator_history(validator_index, start_epoch, end_epoch)
+        .map_err(ApiError::unhandled_error)?;
+
+    Ok(history.into_iter().map(to_api_history).collect())
+}
+
+fn to_api_history(summary: ValidatorEpochSummary) -> ValidatorHistory {
+    let has_flag = |flag_index: usize| summary.participation_flags & (1 << flag_index) != 0;
+    ValidatorHistory {
+        epoch: summary.epoch,
+        balance: summary.balance,
+        effective_balance: summary.effective_balance,
+        timely_source: has_flag(TIMELY_SOURCE_FLAG_INDEX),
+        timely_target: has_flag(TIMELY_TARGET_FLAG_INDEX),
+        timely_head: has_flag(TIMELY_HEAD_FLAG_INDEX),
+        active: summary.active,
+        slashed: summary.slashed,
+        exit_initiated: summary.exit_initiated,
+        exited: summary.exited,
+    }
+}
@@ -1,11 +1,13 @@
 use crate::api_error::ApiError;
-use crate::api_types::EndpointVersion;
-use axum::response::Response;
+use crate::api_types::{Accept, EndpointVersion};
+use axum::Json;
+use axum::response::{IntoResponse, Response};
 use eth2::{
     CONSENSUS_BLOCK_VALUE_HEADER, CONSENSUS_VERSION_HEADER, CONTENT_TYPE_HEADER,
     EXECUTION_PAYLOAD_BLINDED_HEADER, EXECUTION_PAYLOAD_VALUE_HEADER, SSZ_CONTENT_TYPE_HEADER,
 };
 use serde::Serialize;
+use ssz::Encode;
 use types::{
     BeaconResponse, ForkName, ForkVersionedResponse, InconsistentFork, Uint256,
     UnversionedResponse,
@@ -70,6 +72,34 @@ pub(crate) fn execution_optimistic_finalized_beacon_response<T: Serialize>(
     }
 }
 
+/// Respond with `data` as SSZ if the `accept` header asks for it, otherwise as fork versioned JSON
+/// with the execution optimistic and finalized metadata. Both carry the `Eth-Consensus-Version`
+/// header.
+pub(crate) fn execution_optimistic_finalized_ssz_or_json_response<T: Serialize + Encode>(
+    accept: Option<Accept>,
+    fork_name: ForkName,
+    execution_optimistic: bool,
+    finalized: bool,
+    data: T,
+) -> Result<Response, ApiError> {
+    match accept {
+        Some(Accept::Ssz) => Response::builder()
+            .status(200)
+            .body(axum::body::Body::from(data.as_ssz_bytes()))
+            .map(add_ssz_content_type_header)
+            .map(|resp| add_consensus_version_header(resp, fork_name))
+            .map_err(|e| ApiError::server_error(format!("failed to create response: {e}"))),
+        _ => execution_optimistic_finalized_beacon_response(
+            ResponseIncludesVersion::Yes(fork_name),
+            execution_optimistic,
+            finalized,
+            data,
+        )
+        .map(|res| Json(res).into_response())
+        .map(|resp| add_consensus_version_header(resp, fork_name)),
+    }
+}
+
 /// Add the 'Content-Type application/octet-stream` header to a response.
 pub(crate) fn add_ssz_content_type_header(resp: Response) -> Response {
     let mut resp = resp;
@@ -1273,11 +1273,11 @@ async fn post_execution_payload_envelope_unknown_block_root() {
         result.is_err(),
         "should reject envelope with unknown block root"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("BlockRootUnknown"),
-            "expected BlockRootUnknown error, got: {}",
+        assert_eq!(
+            msg.reason, "BlockRootUnknown",
+            "unexpected rejection: {}",
             msg.message
         );
     }
@@ -1328,11 +1328,11 @@ async fn post_execution_payload_envelope_slot_mismatch() {
         result.is_err(),
         "should reject envelope with mismatched slot"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("SlotMismatch"),
-            "expected SlotMismatch error, got: {}",
+        assert_eq!(
+            msg.reason, "SlotMismatch",
+            "unexpected rejection: {}",
             msg.message
         );
     }
@@ -1382,11 +1382,11 @@ async fn post_execution_payload_envelope_builder_index_mismatch() {
         result.is_err(),
         "should reject envelope with wrong builder index"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("BuilderIndexMismatch"),
-            "expected BuilderIndexMismatch error, got: {}",
+        assert_eq!(
+            msg.reason, "BuilderIndexMismatch",
+            "unexpected rejection: {}",
             msg.message
         );
     }
@@ -1438,11 +1438,11 @@ async fn post_execution_payload_envelope_block_hash_mismatch() {
         result.is_err(),
         "should reject envelope with wrong block hash"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("BlockHashMismatch"),
-            "expected BlockHashMismatch error, got: {}",
+        assert_eq!(
+            msg.reason, "BlockHashMismatch",
+            "unexpected rejection: {}",
             msg.message
         );
     }
@@ -1589,11 +1589,11 @@ async fn post_execution_payload_envelope_invalid_signature() {
         result.is_err(),
         "should reject envelope with invalid signature"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("InvalidSignature"),
-            "expected InvalidSignature error, got: {}",
+        assert_eq!(
+            msg.reason, "InvalidSignature",
+            "unexpected rejection: {}",
             msg.message
         );
     }
@@ -3083,7 +3083,7 @@ async fn bid_submission_rejected_unknown_builder() {
         result.is_err(),
         "bid with unknown builder should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
         assert!(
             msg.message.contains("invalid execution bid"),
@@ -3113,7 +3113,7 @@ async fn bid_submission_rejected_zero_payment() {
 
     let result = client.post_builder_bids(&bid).await;
     assert!(result.is_err(), "bid with zero payment should be rejected");
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
         assert!(
             msg.message.contains("invalid execution bid"),
@@ -3841,15 +3841,11 @@ async fn bid_submission_rejected_invalid_signature() {
         result.is_err(),
         "bid with invalid signature should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400, "should return 400 for bad signature");
-        assert!(
-            msg.message.contains("invalid execution bid"),
-            "expected error about invalid bid, got: {}",
-            msg.message
-        );
+        assert_eq!(msg.reason, "InvalidSignature");
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -3910,15 +3906,15 @@ async fn bid_submission_rejected_inactive_builder() {
         result.is_err(),
         "bid from inactive builder should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("InactiveBuilder"),
-            "expected InactiveBuilder error, got: {}",
+        assert_eq!(
+            msg.reason, "InactiveBuilder",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -4005,15 +4001,15 @@ async fn bid_submission_rejected_insufficient_balance() {
         result.is_err(),
         "bid with insufficient balance should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("InsufficientBuilderBalance"),
-            "expected InsufficientBuilderBalance error, got: {}",
+        assert_eq!(
+            msg.reason, "InsufficientBuilderBalance",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -4101,15 +4097,15 @@ async fn bid_submission_rejected_fee_recipient_mismatch() {
         result.is_err(),
         "bid with mismatched fee_recipient should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("FeeRecipientMismatch"),
-            "expected FeeRecipientMismatch error, got: {}",
+        assert_eq!(
+            msg.reason, "FeeRecipientMismatch",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -4197,15 +4193,15 @@ async fn bid_submission_rejected_gas_limit_mismatch() {
         result.is_err(),
         "bid with mismatched gas_limit should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("GasLimitMismatch"),
-            "expected GasLimitMismatch error, got: {}",
+        assert_eq!(
+            msg.reason, "GasLimitMismatch",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -4319,15 +4315,15 @@ async fn bid_submission_rejected_not_highest_value() {
         result.is_err(),
         "bid with lower value than existing should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("NotHighestValue"),
-            "expected NotHighestValue error, got: {}",
+        assert_eq!(
+            msg.reason, "NotHighestValue",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -4380,15 +4376,15 @@ async fn bid_submission_rejected_slot_not_current_or_next() {
 
     let result = client.post_builder_bids(&signed_bid).await;
     assert!(result.is_err(), "bid with stale slot should be rejected");
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("SlotNotCurrentOrNext"),
-            "expected SlotNotCurrentOrNext error, got: {}",
+        assert_eq!(
+            msg.reason, "SlotNotCurrentOrNext",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -4440,15 +4436,15 @@ async fn bid_submission_rejected_invalid_parent_root() {
         result.is_err(),
         "bid with unknown parent root should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("InvalidParentRoot"),
-            "expected InvalidParentRoot error, got: {}",
+        assert_eq!(
+            msg.reason, "InvalidParentRoot",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -4501,15 +4497,15 @@ async fn bid_submission_rejected_unknown_parent_block_hash() {
         result.is_err(),
         "bid with unknown parent block hash should be rejected"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("UnknownParentBlockHash"),
-            "expected UnknownParentBlockHash error, got: {}",
+        assert_eq!(
+            msg.reason, "UnknownParentBlockHash",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
     }
 }
 
@@ -4619,16 +4615,224 @@ async fn bid_submission_rejected_builder_equivocation() {
         result.is_err(),
         "second bid from same builder/slot should be rejected as equivocation"
     );
-    if let Err(eth2::Error::ServerMessage(msg)) = &result {
+    if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
         assert_eq!(msg.code, 400);
-        assert!(
-            msg.message.contains("BuilderEquivocation"),
-            "expected BuilderEquivocation error, got: {}",
+        assert_eq!(
+            msg.reason, "BuilderEquivocation",
+            "unexpected rejection: {}",
             msg.message
         );
     } else {
-        panic!("expected ServerMessage error, got: {result:?}");
+        panic!("expected ServerRejectionMessage error, got: {result:?}");
+    }
+}
+
+/// POST builder/bids at the `consensus` level should reject a bid which passes gossip checks but
+/// does not commit to the parent's block hash and RANDAO mix, and accept one which does.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn bid_submission_broadcast_validation_consensus() {
+    use eth2::types::BroadcastValidation;
+    use types::{
+        Domain, ExecutionPayloadBidGloas, ProposerPreferences, SignedExecutionPayloadBid,
+        SignedExecutionPayloadBidGloas, SignedProposerPreferences, SignedRoot,
+    };
+
+    let validator_count = 32;
+    let (tester, builder_keypairs) =
+        gloas_tester_with_builders(validator_count, &[(0, 10_000_000_000), (1, 10_000_000_000)])
+            .await;
+    let harness = &tester.harness;
+    let client = &tester.client;
+
+    harness.extend_slots(32).await;
+
+    let head = harness.chain.head_snapshot();
+    let state = &head.beacon_state;
+    let head_root = head.beacon_block_root;
+    let next_slot = harness.chain.slot().unwrap() + 1;
+    let spec = &harness.chain.spec;
+
+    let fee_recipient = Address::repeat_byte(0x42);
+    let gas_limit = 30_000_000u64;
+
+    let preferences = ProposerPreferences {
+        proposal_slot: next_slot.as_u64(),
+        validator_index: 0,
+        fee_recipient,
+        gas_limit,
+    };
+    let pref_domain = spec.get_domain(
+        next_slot.epoch(E::slots_per_epoch()),
+        Domain::ProposerPreferences,
+        &state.fork(),
+        state.genesis_validators_root(),
+    );
+    let pref_signature = generate_deterministic_keypair(0)
+        .sk
+        .sign(preferences.signing_root(pref_domain));
+    client
+        .post_beacon_pool_proposer_preferences(&SignedProposerPreferences {
+            message: preferences,
+            signature: pref_signature,
+        })
+        .await
+        .expect("should accept proposer preferences");
+
+    let bid_domain = spec.get_domain(
+        next_slot.epoch(E::slots_per_epoch()),
+        Domain::BeaconBuilder,
+        &state.fork(),
+        state.genesis_validators_root(),
+    );
+    let sign_bid = |message: ExecutionPayloadBidGloas<E>| -> SignedExecutionPayloadBid<E> {
+        let signature = builder_keypairs[message.builder_index as usize]
+            .sk
+            .sign(message.signing_root(bid_domain));
+        SignedExecutionPayloadBidGloas { message, signature }.into()
+    };
+
+    // Passes gossip verification, but commits to the wrong RANDAO mix.
+    let inconsistent_bid = sign_bid(ExecutionPayloadBidGloas {
+        slot: next_slot,
+        execution_payment: 1,
+        builder_index: 0,
+        value: 100,
+        parent_block_root: head_root,
+        prev_randao: Hash256::repeat_byte(0xff),
+        fee_recipient,
+        gas_limit,
+        ..Default::default()
+    });
+    // Resubmitting the rejected bid must not be mistaken for a duplicate of a published bid.
+    for _ in 0..2 {
+        let result = client
+            .post_builder_bids_ssz(&inconsistent_bid, Some(BroadcastValidation::Consensus))
+            .await;
+        if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
+            assert_eq!(msg.code, 400);
+            assert_eq!(
+                msg.reason, "ConsensusInvalid",
+                "unexpected rejection: {}",
+                msg.message
+            );
+        } else {
+            panic!("expected ServerRejectionMessage error, got: {result:?}");
+        }
+    }
+
+    // The rejected bid was never observed, so a corrected bid from the same builder for the same
+    // slot is not an equivocation.
+    let valid_bid = sign_bid(ExecutionPayloadBidGloas {
+        slot: next_slot,
+        execution_payment: 1,
+        builder_index: 0,
+        value: 100,
+        parent_block_hash: *state.latest_block_hash().unwrap(),
+        parent_block_root: head_root,
+        prev_randao: *state.get_randao_mix(state.current_epoch()).unwrap(),
+        fee_recipient,
+        gas_limit,
+        ..Default::default()
+    });
+    client
+        .post_builder_bids_ssz(
+            &valid_bid,
+            Some(BroadcastValidation::ConsensusAndEquivocation),
+        )
+        .await
+        .expect("consistent bid should be accepted");
+    assert!(
+        harness
+            .chain
+            .execution_bid_pool
+            .lock()
+            .get_best_bid(next_slot, head_root)
+            .is_some(),
+        "accepted bid should be in the pool"
+    );
+}
+
+/// POST beacon/execution_payload_envelope at the `consensus` level should reject an envelope which
+/// passes gossip checks but fails the envelope state transition, without recording it as
+/// observed, so that the corrected envelope for the same block is still accepted.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn envelope_submission_broadcast_validation_consensus_allows_resubmission() {
+    use eth2::types::BroadcastValidation;
+
+    let validator_count = 32;
+    let spec = gloas_spec(Epoch::new(0));
+    let tester = InteractiveTester::<E>::new(Some(spec.clone()), validator_count).await;
+    let harness = &tester.harness;
+    let client = &tester.client;
+
+    harness.extend_slots(8).await;
+
+    let next_slot = harness.chain.slot().unwrap() + 1;
+    harness.advance_slot();
+    let (block_contents, post_state, envelope) = harness
+        .make_block_with_envelope(harness.chain.head_beacon_state_cloned(), next_slot)
+        .await;
+    let envelope = envelope.expect("self-build block should have an envelope");
+    let block_root = block_contents.0.canonical_root();
+    let proposer_index = block_contents.0.message().proposer_index() as usize;
+
+    // Import the block without its envelope.
+    harness
+        .process_block(next_slot, block_root, block_contents)
+        .await
+        .expect("block import should succeed");
+
+    // Re-sign the envelope with a wrong state root, so that it passes gossip verification but
+    // fails processing.
+    let mut bad_envelope = envelope.clone();
+    bad_envelope.message.state_root = Hash256::repeat_byte(0xcd);
+    let domain = spec.get_domain(
+        next_slot.epoch(E::slots_per_epoch()),
+        Domain::BeaconBuilder,
+        &post_state.fork(),
+        post_state.genesis_validators_root(),
+    );
+    bad_envelope.signature = harness.validator_keypairs[proposer_index]
+        .sk
+        .sign(bad_envelope.message.signing_root(domain));
+
+    // Resubmitting the rejected envelope must not be mistaken for a duplicate of a published one.
+    for _ in 0..2 {
+        let result = client
+            .post_beacon_execution_payload_envelope_with_validation(
+                &bad_envelope,
+                Some(BroadcastValidation::Consensus),
+            )
+            .await;
+        if let Err(eth2::Error::ServerRejectionMessage(msg)) = &result {
+            assert_eq!(msg.code, 400);
+            assert_eq!(
+                msg.reason, "ConsensusInvalid",
+                "unexpected rejection: {}",
+                msg.message
+            );
+        } else {
+            panic!("expected ServerRejectionMessage error, got: {result:?}");
+        }
     }
+
+    client
+        .post_beacon_execution_payload_envelope_with_validation(
+            &envelope,
+            Some(BroadcastValidation::Consensus),
+        )
+        .await
+        .expect("corrected envelope should be accepted");
+    assert!(
+        harness
+            .chain
+            .canonical_head
+            .fork_choice_read_lock()
+            .get_block(&block_root)
+            .expect("block should be in fork choice")
+            .payload_revealed,
+        "corrected envelope should reveal the payload"
+    );
 }
 
 // ── Heze (FOCIL) Inclusion List Duty Tests ──────────────────────────
@@ -5263,3 +5467,170 @@ async fn post_execution_proof_unknown_block_root_rejected() {
         panic!("expected ServerMessage error, got: {result:?}");
     }
 }
+
+/// GET beacon/states/{state_id}/builders returns the builder registry, and supports filtering
+/// by index, pubkey and status in both JSON and SSZ.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_beacon_states_builders_gloas() {
+    use eth2::types::{BuilderStatus, ValidatorId};
+
+    let validator_count = 32;
+    let (tester, builder_keypairs) =
+        gloas_tester_with_builders(validator_count, &[(0, 2_000_000_000), (0, 3_000_000_000)])
+            .await;
+    let client = &tester.client;
+    let spec = &tester.harness.chain.spec;
+    let state = tester.harness.get_current_state();
+    let builders = state.builders().unwrap();
+
+    let response = client
+        .get_beacon_states_builders(StateId::Head, None, None)
+        .await
+        .unwrap()
+        .expect("builders should be found");
+    assert_eq!(response.version(), Some(state.fork_name_unchecked()));
+    let all = response.into_data();
+    assert_eq!(all.len(), builders.len());
+    for (data, builder) in all.iter().zip(builders.iter()) {
+        assert_eq!(&data.builder, builder);
+        assert_eq!(
+            data.status,
+            BuilderStatus::from_builder(
+                builder,
+                state.current_epoch(),
+                state.finalized_checkpoint().epoch,
+                spec
+            )
+        );
+    }
+
+    // SSZ response matches JSON.
+    let all_ssz = client
+        .get_beacon_states_builders_ssz(StateId::Head, None, None)
+        .await
+        .unwrap()
+        .expect("builders should be found");
+    assert_eq!(all_ssz, all);
+
+    // Filter by index and by pubkey.
+    let by_index = client
+        .get_beacon_states_builders(StateId::Head, Some(&[ValidatorId::Index(1)]), None)
+        .await
+        .unwrap()
+        .unwrap()
+        .into_data();
+    assert_eq!(by_index, vec![all[1].clone()]);
+
+    let by_pubkey = client
+        .get_beacon_states_builders(
+            StateId::Head,
+            Some(&[ValidatorId::PublicKey(
+                builder_keypairs[0].pk.clone().into(),
+            )]),
+            None,
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .into_data();
+    assert_eq!(by_pubkey, vec![all[0].clone()]);
+
+    // Filter by status.
+    let status = all[0].status;
+    let by_status = client
+        .get_beacon_states_builders(StateId::Head, None, Some(&[status]))
+        .await
+        .unwrap()
+        .unwrap()
+        .into_data();
+    assert_eq!(by_status, all);
+
+    let other_status = if status == BuilderStatus::Withdrawn {
+        BuilderStatus::Active
+    } else {
+        BuilderStatus::Withdrawn
+    };
+    let none = client
+        .get_beacon_states_builders_ssz(StateId::Head, None, Some(&[other_status]))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(none.is_empty());
+}
+
+/// GET builder_pending_payments and builder_pending_withdrawals return the corresponding state
+/// fields in both JSON and SSZ.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_beacon_states_builder_pending_payments_and_withdrawals_gloas() {
+    let validator_count = 32;
+    let (tester, _) = gloas_tester_with_builders(validator_count, &[(0, 2_000_000_000)]).await;
+    let client = &tester.client;
+    let state = tester.harness.get_current_state();
+
+    let payments = client
+        .get_beacon_states_builder_pending_payments(StateId::Head)
+        .await
+        .unwrap()
+        .expect("pending payments should be found")
+        .data;
+    let expected_payments = state.builder_pending_payments().unwrap().to_vec();
+    assert_eq!(payments, expected_payments);
+    assert_eq!(
+        client
+            .get_beacon_states_builder_pending_payments_ssz(StateId::Head)
+            .await
+            .unwrap()
+            .unwrap(),
+        expected_payments
+    );
+
+    let withdrawals = client
+        .get_beacon_states_builder_pending_withdrawals(StateId::Head)
+        .await
+        .unwrap()
+        .expect("pending withdrawals should be found")
+        .data;
+    let expected_withdrawals = state.builder_pending_withdrawals().unwrap().to_vec();
+    assert_eq!(withdrawals, expected_withdrawals);
+    assert_eq!(
+        client
+            .get_beacon_states_builder_pending_withdrawals_ssz(StateId::Head)
+            .await
+            .unwrap()
+            .unwrap(),
+        expected_withdrawals
+    );
+}
+
+/// Builder registry endpoints should return 400 for pre-Gloas states.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_beacon_states_builders_rejected_before_gloas() {
+    let validator_count = 32;
+    let spec = capella_spec(Epoch::new(0));
+    let tester = InteractiveTester::<E>::new(Some(spec), validator_count).await;
+    let client = &tester.client;
+
+    let assert_bad_request = |result: Result<_, eth2::Error>| match result {
+        Err(eth2::Error::ServerMessage(msg)) => assert_eq!(msg.code, 400),
+        other => panic!("expected 400 error, got: {other:?}"),
+    };
+
+    assert_bad_request(
+        client
+            .get_beacon_states_builders(StateId::Head, None, None)
+            .await
+            .map(|_| ()),
+    );
+    assert_bad_request(
+        client
+            .get_beacon_states_builder_pending_payments(StateId::Head)
+            .await
+            .map(|_| ()),
+    );
+    assert_bad_request(
+        client
+            .get_beacon_states_builder_pending_withdrawals(StateId::Head)
+            .await
+            .map(|_| ()),
+    );
+}
@@ -15,6 +15,7 @@ use eth2::{
     types::{
         BlockId as CoreBlockId, ForkChoiceNode, ProduceBlockV3Response, StateId as CoreStateId, *,
     },
+    vibehouse::{DatabaseSnapshotRequest, DatabaseSnapshotState},
 };
 use execution_layer::expected_gas_limit;
 use execution_layer::test_utils::{
@@ -6190,6 +6191,90 @@ impl ApiTester {
         self
     }
 
+    pub(crate) async fn test_get_vibehouse_analysis_validator_history(self) -> Self {
+        let epoch = self.chain.epoch().unwrap();
+
+        // The history is disabled by default, so nothing is recorded.
+        let history = self
+            .client
+            .get_vibehouse_analysis_validator_history(ValidatorId::Index(0), Epoch::new(0), epoch)
+            .await
+            .unwrap();
+        assert!(history.is_empty());
+
+        assert!(
+            self.client
+                .get_vibehouse_analysis_validator_history(
+                    ValidatorId::Index(0),
+                    epoch,
+                    Epoch::new(0)
+                )
+                .await
+                .is_err()
+        );
+
+        self
+    }
+
+    pub(crate) async fn test_get_vibehouse_history(self) -> Self {
+        let head_slot = self.chain.head_snapshot().beacon_block.slot();
+        let head_root = self.chain.head_beacon_block_root();
+
+        // Inclusion lists and payload attestations can be looked up by slot or by block.
+        for block_id in [
+            CoreBlockId::Slot(head_slot),
+            CoreBlockId::Root(head_root),
+            CoreBlockId::Head,
+        ] {
+            let inclusion_lists = self
+                .client
+                .get_vibehouse_history_inclusion_lists::<E>(block_id)
+                .await
+                .unwrap()
+                .data;
+            assert!(inclusion_lists.is_empty());
+
+            let payload_attestations = self
+                .client
+                .get_vibehouse_history_payload_attestations::<E>(block_id, Some(head_root))
+                .await
+                .unwrap()
+                .data;
+            assert!(payload_attestations.is_empty());
+        }
+
+        // An unknown block root is not found, rather than resolved to a slot.
+        let unknown_root = CoreBlockId::Root(Hash256::repeat_byte(0xff));
+        assert_eq!(
+            self.client
+                .get_vibehouse_history_inclusion_lists::<E>(unknown_root)
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(404)
+        );
+        assert_eq!(
+            self.client
+                .get_vibehouse_history_payload_attestations::<E>(unknown_root, None)
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(404)
+        );
+
+        let proofs = self
+            .client
+            .get_vibehouse_history_execution_proofs(CoreBlockId::Head)
+            .await
+            .unwrap();
+        assert!(proofs.data.is_empty());
+        assert_eq!(proofs.finalized, Some(false));
+
+        self
+    }
+
     pub(crate) async fn test_post_vibehouse_database_reconstruct(self) -> Self {
         let response = self
             .client
@@ -6200,6 +6285,58 @@ impl ApiTester {
         self
     }
 
+    pub(crate) async fn test_vibehouse_database_snapshot(self) -> Self {
+        assert!(
+            self.client
+                .get_vibehouse_database_snapshot()
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let target = tempfile::tempdir().unwrap();
+        let request = DatabaseSnapshotRequest {
+            target_dir: target.path().to_path_buf(),
+        };
+        self.client
+            .post_vibehouse_database_snapshot(&request)
+            .await
+            .unwrap();
+
+        let status = loop {
+            let status = self
+                .client
+                .get_vibehouse_database_snapshot()
+                .await
+                .unwrap()
+                .unwrap();
+            if status.state != DatabaseSnapshotState::Running {
+                break status;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        };
+        assert_eq!(status.state, DatabaseSnapshotState::Complete, "{status:?}");
+        assert_eq!(status.target_dir, target.path());
+        assert!(status.entries_copied > 0);
+        let split = self.chain.store.get_split_info();
+        assert_eq!(
+            status
+                .finalized_checkpoint
+                .map(|checkpoint| checkpoint.root),
+            Some(split.block_root)
+        );
+
+        // The target already holds a copy, so it can't be written again.
+        assert!(
+            self.client
+                .post_vibehouse_database_snapshot(&request)
+                .await
+                .is_err()
+        );
+
+        self
+    }
+
     pub(crate) async fn test_post_vibehouse_add_remove_peer(self) -> Self {
         let trusted_peers = self.ctx.network_globals.as_ref().unwrap().trusted_peers();
         // Check that there aren't any trusted peers on startup
@@ -8081,8 +8218,14 @@ async fn vibehouse_endpoints() {
         .await
         .test_get_vibehouse_validator_inclusion_global()
         .await
+        .test_get_vibehouse_analysis_validator_history()
+        .await
+        .test_get_vibehouse_history()
+        .await
         .test_post_vibehouse_database_reconstruct()
         .await
+        .test_vibehouse_database_snapshot()
+        .await
         .test_post_vibehouse_liveness()
         .await
         .test_post_vibehouse_add_remove_peer()
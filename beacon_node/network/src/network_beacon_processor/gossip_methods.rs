@@ -1635,6 +1635,7 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
                 // For stateless nodes: process any buffered execution proofs that arrived
                 // before this block was in fork choice.
                 self.chain.process_pending_execution_proofs(*block_root);
+                self.request_missing_execution_proofs(*block_root);
 
                 self.chain.recompute_head_at_current_slot().await;
 
@@ -3923,6 +3924,7 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
         message_id: MessageId,
         peer_id: PeerId,
         message: types::PayloadAttestationMessage,
+        seen_timestamp: Duration,
     ) {
         use beacon_chain::gloas_verification::PayloadAttestationError;
 
@@ -4078,6 +4080,12 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
 
         self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Accept);
 
+        // Register with validator monitor
+        self.chain
+            .validator_monitor
+            .read()
+            .register_gossip_payload_attestation(seen_timestamp, &message, &self.chain.slot_clock);
+
         // Import to fork choice first (borrows verified_attestation), then consume for pool.
         if let Err(e) = self
             .chain
@@ -4512,6 +4520,72 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
         }
     }
 
+    /// Process an RPC-received execution proof (stateless validation).
+    ///
+    /// Requested by sync via `ExecutionProofsByRoot` when an imported block has not gathered
+    /// enough proofs over gossip. Verification is identical to gossip, but there is nothing to
+    /// propagate and an invalid proof is reported against the serving peer.
+    pub(crate) async fn process_rpc_execution_proof(
+        self: &Arc<Self>,
+        peer_id: PeerId,
+        proof: Arc<ExecutionProof>,
+    ) {
+        let block_root = proof.block_root;
+        let subnet_id = proof.subnet_id;
+
+        match self
+            .chain
+            .verify_execution_proof_for_gossip(proof, subnet_id)
+        {
+            Ok(verified_proof) => {
+                debug!(
+                    %block_root,
+                    subnet_id = *subnet_id,
+                    %peer_id,
+                    "Successfully verified RPC execution proof"
+                );
+                self.process_gossip_verified_execution_proof(peer_id, verified_proof)
+                    .await;
+            }
+            Err(
+                err @ (GossipExecutionProofError::UnknownBlockRoot { .. }
+                | GossipExecutionProofError::PriorToFinalization { .. }
+                | GossipExecutionProofError::BeaconChainError(_)),
+            ) => {
+                debug!(
+                    %block_root,
+                    %peer_id,
+                    error = ?err,
+                    "Ignoring RPC execution proof"
+                );
+            }
+            Err(
+                err @ (GossipExecutionProofError::InvalidSubnetId { .. }
+                | GossipExecutionProofError::InvalidVersion { .. }
+                | GossipExecutionProofError::ProofDataEmpty
+                | GossipExecutionProofError::ProofDataTooLarge { .. }
+                | GossipExecutionProofError::BlockHashMismatch { .. }
+                | GossipExecutionProofError::InvalidProofData
+                | GossipExecutionProofError::Sp1VerificationUnavailable
+                | GossipExecutionProofError::InvalidProof { .. }
+                | GossipExecutionProofError::PublicValuesBlockHashMismatch { .. }),
+            ) => {
+                debug!(
+                    %block_root,
+                    %peer_id,
+                    error = ?err,
+                    "Rejecting invalid RPC execution proof"
+                );
+                self.send_network_message(NetworkMessage::ReportPeer {
+                    peer_id,
+                    action: PeerAction::LowToleranceError,
+                    source: ReportSource::SyncService,
+                    msg: "rpc_execution_proof_invalid",
+                });
+            }
+        }
+    }
+
     /// Process a gossip-received inclusion list (Heze FOCIL, EIP-7805).
     ///
     /// Validates the inclusion list (slot, committee membership, committee root, signature,
@@ -4521,6 +4595,7 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
         message_id: MessageId,
         peer_id: PeerId,
         signed_il: types::SignedInclusionList<T::EthSpec>,
+        seen_timestamp: Duration,
     ) {
         use beacon_chain::heze_verification::InclusionListError;
 
@@ -4702,6 +4777,17 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
         metrics::inc_counter(&metrics::BEACON_PROCESSOR_INCLUSION_LIST_VERIFIED_TOTAL);
         self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Accept);
 
+        // Register with validator monitor
+        self.chain
+            .validator_monitor
+            .read()
+            .register_gossip_inclusion_list(
+                seen_timestamp,
+                &verified.signed_il,
+                verified.is_before_view_freeze_cutoff,
+                &self.chain.slot_clock,
+            );
+
         // Import to InclusionListStore
         self.chain.import_inclusion_list(verified);
         metrics::inc_counter(&metrics::BEACON_PROCESSOR_INCLUSION_LIST_IMPORTED_TOTAL);
@@ -29,7 +29,8 @@ use types::{
 use vibehouse_network::rpc::InboundRequestId;
 use vibehouse_network::rpc::methods::{
     BlobsByRangeRequest, BlobsByRootRequest, DataColumnsByRangeRequest, DataColumnsByRootRequest,
-    ExecutionPayloadEnvelopesByRootRequest, InclusionListByCommitteeIndicesRequest,
+    ExecutionPayloadEnvelopesByRangeRequest, ExecutionPayloadEnvelopesByRootRequest,
+    ExecutionProofsByRootRequest, InclusionListByCommitteeIndicesRequest,
     LightClientUpdatesByRangeRequest,
 };
 use vibehouse_network::service::api_types::CustodyBackfillBatchId;
@@ -329,11 +330,12 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
         message_id: MessageId,
         peer_id: PeerId,
         message: Box<types::PayloadAttestationMessage>,
+        seen_timestamp: Duration,
     ) -> Result<(), Error<T::EthSpec>> {
         let processor = self.clone();
         let process_fn = async move {
             processor
-                .process_gossip_payload_attestation(message_id, peer_id, *message)
+                .process_gossip_payload_attestation(message_id, peer_id, *message, seen_timestamp)
                 .await;
         };
 
@@ -389,16 +391,35 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
         })
     }
 
+    /// Create a new `Work` event for an execution proof received via `ExecutionProofsByRoot`.
+    pub(crate) fn send_rpc_execution_proof(
+        self: &Arc<Self>,
+        peer_id: PeerId,
+        proof: Arc<ExecutionProof>,
+    ) -> Result<(), Error<T::EthSpec>> {
+        let processor = self.clone();
+        let process_fn = async move {
+            processor.process_rpc_execution_proof(peer_id, proof).await;
+        };
+
+        self.try_send(BeaconWorkEvent {
+            drop_during_sync: false,
+            work: Work::RpcExecutionProof(Box::pin(process_fn)),
+        })
+    }
+
     /// Create a new `Work` event for a Heze inclusion list (FOCIL).
     pub(crate) fn send_gossip_inclusion_list(
         self: &Arc<Self>,
         message_id: MessageId,
         peer_id: PeerId,
         signed_il: Box<types::SignedInclusionList<T::EthSpec>>,
+        seen_timestamp: Duration,
     ) -> Result<(), Error<T::EthSpec>> {
         let processor = self.clone();
-        let process_fn =
-            move || processor.process_gossip_inclusion_list(message_id, peer_id, *signed_il);
+        let process_fn = move || {
+            processor.process_gossip_inclusion_list(message_id, peer_id, *signed_il, seen_timestamp)
+        };
 
         self.try_send(BeaconWorkEvent {
             drop_during_sync: true,
@@ -800,6 +821,46 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
         })
     }
 
+    /// Create a new work event to process `ExecutionPayloadEnvelopesByRangeRequest`s from the RPC network.
+    pub(crate) fn send_execution_payload_envelopes_by_range_request(
+        self: &Arc<Self>,
+        peer_id: PeerId,
+        inbound_request_id: InboundRequestId,
+        request: ExecutionPayloadEnvelopesByRangeRequest,
+    ) -> Result<(), Error<T::EthSpec>> {
+        let processor = self.clone();
+        let process_fn = move || {
+            processor.handle_execution_payload_envelopes_by_range_request(
+                peer_id,
+                inbound_request_id,
+                request,
+            );
+        };
+
+        self.try_send(BeaconWorkEvent {
+            drop_during_sync: false,
+            work: Work::ExecutionPayloadEnvelopesByRangeRequest(Box::new(process_fn)),
+        })
+    }
+
+    /// Create a new work event to process `ExecutionProofsByRootRequest`s from the RPC network.
+    pub(crate) fn send_execution_proofs_by_roots_request(
+        self: &Arc<Self>,
+        peer_id: PeerId,
+        inbound_request_id: InboundRequestId,
+        request: ExecutionProofsByRootRequest,
+    ) -> Result<(), Error<T::EthSpec>> {
+        let processor = self.clone();
+        let process_fn = move || {
+            processor.handle_execution_proofs_by_root_request(peer_id, inbound_request_id, request);
+        };
+
+        self.try_send(BeaconWorkEvent {
+            drop_during_sync: false,
+            work: Work::ExecutionProofsByRootRequest(Box::new(process_fn)),
+        })
+    }
+
     /// Create a new work event to process `InclusionListByCommitteeIndicesRequest`s from the RPC network.
     pub(crate) fn send_inclusion_list_by_committee_indices_request(
         self: &Arc<Self>,
@@ -934,6 +995,20 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
             .unwrap_or_else(|e| debug!(error = %e, "Could not send message to the sync service"));
     }
 
+    /// Ask sync to request the execution proofs for an imported block if a stateless node is still
+    /// missing them.
+    ///
+    /// This is called for blocks imported from gossip, lookups and range sync alike, as the proofs
+    /// may not have been received on gossip in any of those cases.
+    ///
+    /// Sync holds the request back until the slot after the block has started, so proofs that are
+    /// still in flight on gossip are not requested twice.
+    pub(crate) fn request_missing_execution_proofs(&self, block_root: Hash256) {
+        if self.chain.execution_proofs_missing(block_root) {
+            self.send_sync_message(SyncMessage::MissingExecutionProofs(block_root));
+        }
+    }
+
     /// Send a message to `network_tx`.
     ///
     /// Creates a log if there is an internal error.
@@ -15,7 +15,8 @@ use types::blob_sidecar::BlobIdentifier;
 use types::{ColumnIndex, Epoch, EthSpec, Hash256, Slot};
 use vibehouse_network::rpc::methods::{
     BlobsByRangeRequest, BlobsByRootRequest, DataColumnsByRangeRequest, DataColumnsByRootRequest,
-    ExecutionPayloadEnvelopesByRootRequest, InclusionListByCommitteeIndicesRequest,
+    ExecutionPayloadEnvelopesByRangeRequest, ExecutionPayloadEnvelopesByRootRequest,
+    ExecutionProofsByRootRequest, InclusionListByCommitteeIndicesRequest,
 };
 use vibehouse_network::rpc::{
     BlocksByRangeRequest, BlocksByRootRequest, GoodbyeReason, InboundRequestId,
@@ -26,7 +27,9 @@ use vibehouse_tracing::{
     SPAN_HANDLE_BLOBS_BY_RANGE_REQUEST, SPAN_HANDLE_BLOBS_BY_ROOT_REQUEST,
     SPAN_HANDLE_BLOCKS_BY_RANGE_REQUEST, SPAN_HANDLE_BLOCKS_BY_ROOT_REQUEST,
     SPAN_HANDLE_DATA_COLUMNS_BY_RANGE_REQUEST, SPAN_HANDLE_DATA_COLUMNS_BY_ROOT_REQUEST,
-    SPAN_HANDLE_EXECUTION_PAYLOAD_ENVELOPES_BY_ROOT_REQUEST, SPAN_HANDLE_LIGHT_CLIENT_BOOTSTRAP,
+    SPAN_HANDLE_EXECUTION_PAYLOAD_ENVELOPES_BY_RANGE_REQUEST,
+    SPAN_HANDLE_EXECUTION_PAYLOAD_ENVELOPES_BY_ROOT_REQUEST,
+    SPAN_HANDLE_EXECUTION_PROOFS_BY_ROOT_REQUEST, SPAN_HANDLE_LIGHT_CLIENT_BOOTSTRAP,
     SPAN_HANDLE_LIGHT_CLIENT_FINALITY_UPDATE, SPAN_HANDLE_LIGHT_CLIENT_OPTIMISTIC_UPDATE,
     SPAN_HANDLE_LIGHT_CLIENT_UPDATES_BY_RANGE,
 };
@@ -563,6 +566,197 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
         );
     }
 
+    /// Handle an `ExecutionProofsByRoot` request from the peer.
+    #[instrument(
+        name = SPAN_HANDLE_EXECUTION_PROOFS_BY_ROOT_REQUEST,
+        parent = None,
+        level = "debug",
+        skip_all,
+        fields(peer_id = %peer_id, client = tracing::field::Empty)
+    )]
+    pub(crate) fn handle_execution_proofs_by_root_request(
+        self: Arc<Self>,
+        peer_id: PeerId,
+        inbound_request_id: InboundRequestId,
+        request: ExecutionProofsByRootRequest,
+    ) {
+        let client = self.network_globals.client(&peer_id);
+        Span::current().record("client", field::display(client.kind));
+
+        self.terminate_response_stream(
+            peer_id,
+            inbound_request_id,
+            {
+                self.handle_execution_proofs_by_root_request_inner(
+                    peer_id,
+                    inbound_request_id,
+                    request,
+                );
+                Ok(())
+            },
+            Response::ExecutionProofsByRoot,
+        );
+    }
+
+    /// Handle an `ExecutionProofsByRoot` request from the peer.
+    ///
+    /// Serves every persisted proof for each requested root. Roots we hold no proofs for are
+    /// skipped.
+    fn handle_execution_proofs_by_root_request_inner(
+        &self,
+        peer_id: PeerId,
+        inbound_request_id: InboundRequestId,
+        request: ExecutionProofsByRootRequest,
+    ) {
+        let mut send_count = 0;
+
+        for block_root in request.block_roots.as_slice() {
+            match self.chain.store.get_execution_proofs(block_root) {
+                Ok(proofs) => {
+                    for proof in proofs {
+                        self.send_response(
+                            peer_id,
+                            inbound_request_id,
+                            Response::ExecutionProofsByRoot(Some(proof)),
+                        );
+                        send_count += 1;
+                    }
+                }
+                Err(e) => {
+                    debug!(
+                        ?peer_id,
+                        block_root = ?block_root,
+                        error = ?e,
+                        "Error fetching execution proofs for peer"
+                    );
+                }
+            }
+        }
+
+        debug!(
+            %peer_id,
+            requested = request.block_roots.len(),
+            returned = send_count,
+            "ExecutionProofsByRoot outgoing response processed"
+        );
+    }
+
+    /// Handle an `ExecutionPayloadEnvelopesByRange` request from the peer.
+    #[instrument(
+        name = SPAN_HANDLE_EXECUTION_PAYLOAD_ENVELOPES_BY_RANGE_REQUEST,
+        parent = None,
+        level = "debug",
+        skip_all,
+        fields(peer_id = %peer_id, client = tracing::field::Empty)
+    )]
+    pub(crate) fn handle_execution_payload_envelopes_by_range_request(
+        self: Arc<Self>,
+        peer_id: PeerId,
+        inbound_request_id: InboundRequestId,
+        req: ExecutionPayloadEnvelopesByRangeRequest,
+    ) {
+        let client = self.network_globals.client(&peer_id);
+        Span::current().record("client", field::display(client.kind));
+
+        self.terminate_response_stream(
+            peer_id,
+            inbound_request_id,
+            self.handle_execution_payload_envelopes_by_range_request_inner(
+                peer_id,
+                inbound_request_id,
+                req,
+            ),
+            Response::ExecutionPayloadEnvelopesByRange,
+        );
+    }
+
+    /// Handle an `ExecutionPayloadEnvelopesByRange` request from the peer.
+    ///
+    /// Envelopes are served in slot order for the canonical blocks in the range. Blocks whose
+    /// payload was never revealed (or has been pruned) are skipped.
+    fn handle_execution_payload_envelopes_by_range_request_inner(
+        &self,
+        peer_id: PeerId,
+        inbound_request_id: InboundRequestId,
+        req: ExecutionPayloadEnvelopesByRangeRequest,
+    ) -> Result<(), (RpcErrorResponse, &'static str)> {
+        debug!(
+            ?peer_id,
+            count = req.count,
+            start_slot = req.start_slot,
+            "Received ExecutionPayloadEnvelopesByRange Request"
+        );
+
+        if req.count > self.chain.spec.max_request_payloads as u64 {
+            return Err((
+                RpcErrorResponse::InvalidRequest,
+                "Request exceeded `MAX_REQUEST_PAYLOADS`",
+            ));
+        }
+
+        // Envelopes only exist from the Gloas fork onwards, clamp the request to that range.
+        let Some(gloas_start_slot) = self
+            .chain
+            .spec
+            .gloas_fork_epoch
+            .map(|epoch| epoch.start_slot(T::EthSpec::slots_per_epoch()))
+        else {
+            return Ok(());
+        };
+        let request_end_slot = req.start_slot.saturating_add(req.count);
+        if request_end_slot <= gloas_start_slot.as_u64() {
+            return Ok(());
+        }
+        let start_slot = std::cmp::max(req.start_slot, gloas_start_slot.as_u64());
+        let count = request_end_slot - start_slot;
+
+        let block_roots = self.get_block_roots_for_slot_range(
+            start_slot,
+            count,
+            "ExecutionPayloadEnvelopesByRange",
+        )?;
+
+        let mut envelopes_sent = 0;
+        for block_root in block_roots {
+            match self.chain.store.get_payload_envelope(&block_root) {
+                Ok(Some(envelope)) => {
+                    self.send_response(
+                        peer_id,
+                        inbound_request_id,
+                        Response::ExecutionPayloadEnvelopesByRange(Some(Arc::new(envelope))),
+                    );
+                    envelopes_sent += 1;
+                }
+                Ok(None) => {
+                    // The payload for this block was withheld or is not yet known, skip it.
+                }
+                Err(e) => {
+                    error!(
+                        request = ?req,
+                        %peer_id,
+                        ?block_root,
+                        error = ?e,
+                        "Error fetching execution payload envelope for range request"
+                    );
+                    return Err((
+                        RpcErrorResponse::ServerError,
+                        "Failed fetching execution payload envelopes from database",
+                    ));
+                }
+            }
+        }
+
+        debug!(
+            %peer_id,
+            start_slot = req.start_slot,
+            requested = req.count,
+            returned = envelopes_sent,
+            "ExecutionPayloadEnvelopesByRange outgoing response processed"
+        );
+
+        Ok(())
+    }
+
     /// Handle an `InclusionListByCommitteeIndices` request from the peer.
     ///
     /// Serves inclusion lists from the local InclusionListStore for the requested
@@ -218,6 +218,10 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
                 );
 
                 self.chain.recompute_head_at_current_slot().await;
+
+                // Stateless nodes need proofs to consider the payload valid. Blocks fetched via
+                // lookup may have missed their proofs on gossip, so request them directly.
+                self.request_missing_execution_proofs(*hash);
             }
             Ok(AvailabilityProcessingStatus::MissingComponents(..)) => {
                 // Block is valid, we can now attempt fetching blobs from EL using version hashes
@@ -698,6 +702,9 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
                 if !imported_blocks.is_empty() {
                     self.chain.recompute_head_at_current_slot().await;
                 }
+                for (block_root, _) in &imported_blocks {
+                    self.request_missing_execution_proofs(*block_root);
+                }
                 (imported_blocks.len(), Ok(()))
             }
             ChainSegmentResult::Failed {
@@ -709,6 +716,9 @@ impl<T: BeaconChainTypes> NetworkBeaconProcessor<T> {
                 if !imported_blocks.is_empty() {
                     self.chain.recompute_head_at_current_slot().await;
                 }
+                for (block_root, _) in &imported_blocks {
+                    self.request_missing_execution_proofs(*block_root);
+                }
                 (imported_blocks.len(), r)
             }
         }
@@ -50,10 +50,11 @@ use types::{
 use vibehouse_network::rpc::InboundRequestId;
 use vibehouse_network::rpc::methods::{
     BlobsByRangeRequest, BlobsByRootRequest, DataColumnsByRangeRequest,
-    ExecutionPayloadEnvelopesByRootRequest, MetaDataV3,
+    ExecutionPayloadEnvelopesByRangeRequest, ExecutionPayloadEnvelopesByRootRequest,
+    ExecutionProofsByRootRequest, MetaDataV3,
 };
 use vibehouse_network::{
-    Client, MessageId, NetworkConfig, NetworkGlobals, PeerId, Response,
+    Client, MessageId, NetworkConfig, NetworkGlobals, PeerAction, PeerId, Response,
     discv5::enr::{self, CombinedKey},
     rpc::methods::{MetaData, MetaDataV2},
     types::{EnrAttestationBitfield, EnrSyncCommitteeBitfield},
@@ -656,6 +657,33 @@ impl TestRig {
             .unwrap();
     }
 
+    pub(crate) fn enqueue_execution_proofs_by_root_request(&self, block_roots: Vec<Hash256>) {
+        let request = ExecutionProofsByRootRequest {
+            block_roots: RuntimeVariableList::new(
+                block_roots,
+                self.chain.spec.max_request_payloads,
+            )
+            .unwrap(),
+        };
+        self.network_beacon_processor
+            .send_execution_proofs_by_roots_request(
+                PeerId::random(),
+                InboundRequestId::new_unchecked(42, 24),
+                request,
+            )
+            .unwrap();
+    }
+
+    pub(crate) fn enqueue_envelopes_by_range_request(&self, start_slot: u64, count: u64) {
+        self.network_beacon_processor
+            .send_execution_payload_envelopes_by_range_request(
+                PeerId::random(),
+                InboundRequestId::new_unchecked(42, 24),
+                ExecutionPayloadEnvelopesByRangeRequest { start_slot, count },
+            )
+            .unwrap();
+    }
+
     pub(crate) fn enqueue_data_columns_by_range_request(&self, count: u64, columns: Vec<u64>) {
         self.network_beacon_processor
             .send_data_columns_by_range_request(
@@ -2546,7 +2574,12 @@ async fn test_gloas_gossip_payload_attestation_unknown_root_ignored() {
     };
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2579,7 +2612,12 @@ async fn test_gloas_gossip_payload_attestation_future_slot_rejected() {
     };
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2613,7 +2651,12 @@ async fn test_gloas_gossip_payload_attestation_past_slot_rejected() {
     };
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2648,7 +2691,12 @@ async fn test_gloas_gossip_payload_attestation_non_ptc_validator_rejected() {
     };
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2730,7 +2778,12 @@ async fn test_gloas_gossip_payload_attestation_valid_accepted() {
     let (message, _validator_index, _ptc_bit) = build_valid_payload_attestation_message(&rig, true);
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2758,7 +2811,12 @@ async fn test_gloas_gossip_payload_attestation_equivocation_rejected() {
         build_valid_payload_attestation_message(&rig, true);
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message1)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message1,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2769,7 +2827,12 @@ async fn test_gloas_gossip_payload_attestation_equivocation_rejected() {
         build_valid_payload_attestation_message(&rig, false);
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message2)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message2,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2833,7 +2896,12 @@ async fn test_gloas_gossip_payload_attestation_invalid_signature_rejected() {
     };
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2933,7 +3001,12 @@ async fn test_gloas_gossip_payload_attestation_accumulates_ptc_weight() {
     };
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message_0)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message_0,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -2961,7 +3034,12 @@ async fn test_gloas_gossip_payload_attestation_accumulates_ptc_weight() {
     };
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message_1)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message_1,
+            Duration::from_secs(0),
+        )
         .await;
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -5139,6 +5217,201 @@ async fn test_gloas_envelopes_by_root_mixed_roots() {
     );
 }
 
+// ======== ExecutionPayloadEnvelopesByRange RPC handler tests ========
+//
+// These tests verify the handle_execution_payload_envelopes_by_range_request handler
+// (rpc_methods.rs) which serves payload envelopes for canonical blocks in a slot range.
+
+/// Helper: drain all ExecutionPayloadEnvelopesByRange responses from the network channel until
+/// the stream terminator (None) is received. Returns the received envelopes.
+async fn drain_envelopes_by_range_responses(
+    network_rx: &mut mpsc::UnboundedReceiver<NetworkMessage<E>>,
+) -> Vec<Arc<SignedExecutionPayloadEnvelope<E>>> {
+    let timeout = Duration::from_secs(5);
+    let deadline = tokio::time::sleep(timeout);
+    tokio::pin!(deadline);
+
+    let mut envelopes = Vec::new();
+    loop {
+        tokio::select! {
+            () = &mut deadline => panic!("timeout waiting for ExecutionPayloadEnvelopesByRange response"),
+            msg = network_rx.recv() => {
+                match msg {
+                    Some(NetworkMessage::SendResponse {
+                        response: Response::ExecutionPayloadEnvelopesByRange(maybe_envelope),
+                        ..
+                    }) => {
+                        match maybe_envelope {
+                            Some(envelope) => envelopes.push(envelope),
+                            None => return envelopes, // stream terminator
+                        }
+                    }
+                    Some(_) => {} // skip other messages
+                    None => panic!("network_rx channel closed"),
+                }
+            }
+        }
+    }
+}
+
+/// ExecutionPayloadEnvelopesByRange: every canonical block in range → envelopes served in
+/// ascending slot order.
+#[tokio::test]
+async fn test_gloas_envelopes_by_range_served_in_slot_order() {
+    if test_spec::<E>().gloas_fork_epoch.is_none() {
+        return;
+    }
+
+    let mut rig = gloas_rig(SMALL_CHAIN).await;
+
+    rig.enqueue_envelopes_by_range_request(1, SMALL_CHAIN);
+
+    let received = drain_envelopes_by_range_responses(&mut rig.network_rx).await;
+    assert_eq!(
+        received.len(),
+        SMALL_CHAIN as usize,
+        "should receive one envelope per block in range"
+    );
+    for (i, envelope) in received.iter().enumerate() {
+        assert_eq!(envelope.message.slot, Slot::new(1 + i as u64));
+    }
+}
+
+/// ExecutionPayloadEnvelopesByRange: range beyond the head → only the terminator is sent.
+#[tokio::test]
+async fn test_gloas_envelopes_by_range_future_slots_empty() {
+    if test_spec::<E>().gloas_fork_epoch.is_none() {
+        return;
+    }
+
+    let mut rig = gloas_rig(SMALL_CHAIN).await;
+
+    rig.enqueue_envelopes_by_range_request(SMALL_CHAIN + 10, 4);
+
+    let received = drain_envelopes_by_range_responses(&mut rig.network_rx).await;
+    assert_eq!(received.len(), 0, "future slots should yield no envelopes");
+}
+
+// ======== ExecutionProofsByRoot RPC handler tests ========
+//
+// These tests verify the handle_execution_proofs_by_root_request handler (rpc_methods.rs),
+// which serves persisted execution proofs by beacon block root, and the processing of proofs
+// received in response to our own requests.
+
+/// Helper: drain all ExecutionProofsByRoot responses from the network channel until the stream
+/// terminator (None) is received. Returns the received proofs.
+async fn drain_execution_proofs_by_root_responses(
+    network_rx: &mut mpsc::UnboundedReceiver<NetworkMessage<E>>,
+) -> Vec<Arc<ExecutionProof>> {
+    let timeout = Duration::from_secs(5);
+    let deadline = tokio::time::sleep(timeout);
+    tokio::pin!(deadline);
+
+    let mut proofs = Vec::new();
+    loop {
+        tokio::select! {
+            () = &mut deadline => panic!("timeout waiting for ExecutionProofsByRoot response"),
+            msg = network_rx.recv() => {
+                match msg {
+                    Some(NetworkMessage::SendResponse {
+                        response: Response::ExecutionProofsByRoot(maybe_proof),
+                        ..
+                    }) => {
+                        match maybe_proof {
+                            Some(proof) => proofs.push(proof),
+                            None => return proofs, // stream terminator
+                        }
+                    }
+                    Some(_) => {} // skip other messages
+                    None => panic!("network_rx channel closed"),
+                }
+            }
+        }
+    }
+}
+
+/// ExecutionProofsByRoot: every stored proof for a requested root is served, unknown roots are
+/// skipped.
+#[tokio::test]
+async fn test_gloas_execution_proofs_by_root_stored_proofs_served() {
+    if test_spec::<E>().gloas_fork_epoch.is_none() {
+        return;
+    }
+
+    let mut rig = gloas_rig(SMALL_CHAIN).await;
+    let block_root = rig.chain.head_snapshot().beacon_block_root;
+
+    let proof = ExecutionProof::new(
+        block_root,
+        ExecutionBlockHash::repeat_byte(0xaa),
+        ExecutionProofSubnetId::new(0).unwrap(),
+        types::execution_proof::PROOF_VERSION_STUB,
+        vec![0x42],
+    );
+    rig.chain.store.put_execution_proof(&proof).unwrap();
+
+    rig.enqueue_execution_proofs_by_root_request(vec![block_root, Hash256::repeat_byte(0xab)]);
+
+    let received = drain_execution_proofs_by_root_responses(&mut rig.network_rx).await;
+    assert_eq!(received.len(), 1, "should receive the stored proof");
+    assert_eq!(*received[0], proof);
+}
+
+/// ExecutionProofsByRoot: a root without stored proofs yields only the stream terminator.
+#[tokio::test]
+async fn test_gloas_execution_proofs_by_root_unknown_root_not_served() {
+    if test_spec::<E>().gloas_fork_epoch.is_none() {
+        return;
+    }
+
+    let mut rig = gloas_rig(SMALL_CHAIN).await;
+
+    rig.enqueue_execution_proofs_by_root_request(vec![Hash256::repeat_byte(0xab)]);
+
+    let received = drain_execution_proofs_by_root_responses(&mut rig.network_rx).await;
+    assert_eq!(received.len(), 0, "unknown roots should yield no proofs");
+}
+
+/// RPC execution proof with an unsupported version gets the serving peer reported.
+#[tokio::test]
+async fn test_gloas_rpc_execution_proof_invalid_version_reports_peer() {
+    if test_spec::<E>().gloas_fork_epoch.is_none() {
+        return;
+    }
+
+    let mut rig = gloas_rig(SMALL_CHAIN).await;
+    let head = rig.chain.head_snapshot();
+    let peer_id = junk_peer_id();
+
+    let proof = Arc::new(ExecutionProof::new(
+        head.beacon_block_root,
+        ExecutionBlockHash::repeat_byte(0xaa),
+        ExecutionProofSubnetId::new(0).unwrap(),
+        99, // unsupported version
+        vec![0x42],
+    ));
+
+    rig.network_beacon_processor
+        .process_rpc_execution_proof(peer_id, proof)
+        .await;
+
+    loop {
+        match rig.network_rx.try_recv() {
+            Ok(NetworkMessage::ReportPeer {
+                peer_id: reported,
+                action,
+                ..
+            }) => {
+                assert_eq!(reported, peer_id);
+                assert_eq!(action, PeerAction::LowToleranceError);
+                break;
+            }
+            Ok(_) => {} // skip other messages
+            Err(e) => panic!("expected ReportPeer, got {e:?}"),
+        }
+    }
+}
+
 // ======== SSE event emission integration tests ========
 //
 // These tests verify that the gossip handlers emit Server-Sent Events (SSE) for
@@ -5237,7 +5510,12 @@ async fn test_gloas_sse_event_payload_attestation() {
     let expected_data = message.data;
 
     rig.network_beacon_processor
-        .process_gossip_payload_attestation(junk_message_id(), junk_peer_id(), message)
+        .process_gossip_payload_attestation(
+            junk_message_id(),
+            junk_peer_id(),
+            message,
+            Duration::from_secs(0),
+        )
         .await;
 
     // Verify the gossip was accepted
@@ -6625,7 +6903,12 @@ async fn test_heze_gossip_inclusion_list_valid_accepted() {
     let signed_il = sign_inclusion_list(&rig, il);
 
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il),
+            Duration::from_secs(0),
+        )
         .unwrap();
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6659,7 +6942,12 @@ async fn test_heze_gossip_inclusion_list_wrong_slot_rejected() {
     };
 
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il),
+            Duration::from_secs(0),
+        )
         .unwrap();
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6690,7 +6978,12 @@ async fn test_heze_gossip_inclusion_list_not_in_committee_rejected() {
     };
 
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il),
+            Duration::from_secs(0),
+        )
         .unwrap();
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6715,7 +7008,12 @@ async fn test_heze_gossip_inclusion_list_wrong_committee_root_ignored() {
     let signed_il = sign_inclusion_list(&rig, il);
 
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il),
+            Duration::from_secs(0),
+        )
         .unwrap();
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6742,7 +7040,12 @@ async fn test_heze_gossip_inclusion_list_invalid_signature_rejected() {
     };
 
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il),
+            Duration::from_secs(0),
+        )
         .unwrap();
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6771,6 +7074,7 @@ async fn test_heze_gossip_inclusion_list_duplicate_ignored() {
             junk_message_id(),
             junk_peer_id(),
             Box::new(signed_il.clone()),
+            Duration::from_secs(0),
         )
         .unwrap();
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6778,7 +7082,12 @@ async fn test_heze_gossip_inclusion_list_duplicate_ignored() {
 
     // Second submission (exact duplicate): Ignore
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il),
+            Duration::from_secs(0),
+        )
         .unwrap();
     let result = drain_validation_result(&mut rig.network_rx).await;
     assert_ignore(result);
@@ -6807,7 +7116,12 @@ async fn test_heze_gossip_inclusion_list_transactions_too_large_rejected() {
     let signed_il = sign_inclusion_list(&rig, il);
 
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il),
+            Duration::from_secs(0),
+        )
         .unwrap();
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6836,6 +7150,7 @@ async fn test_heze_gossip_inclusion_list_equivocator_ignored() {
             junk_message_id(),
             junk_peer_id(),
             Box::new(signed_il1.clone()),
+            Duration::from_secs(0),
         )
         .unwrap();
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6854,6 +7169,7 @@ async fn test_heze_gossip_inclusion_list_equivocator_ignored() {
             junk_message_id(),
             junk_peer_id(),
             Box::new(signed_il2.clone()),
+            Duration::from_secs(0),
         )
         .unwrap();
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -6868,7 +7184,12 @@ async fn test_heze_gossip_inclusion_list_equivocator_ignored() {
     let signed_il3 = sign_inclusion_list(&rig, il3);
 
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il3))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il3),
+            Duration::from_secs(0),
+        )
         .unwrap();
     let result = drain_validation_result(&mut rig.network_rx).await;
     assert_ignore(result);
@@ -6891,7 +7212,12 @@ async fn test_heze_gossip_inclusion_list_different_committee_position_accepted()
     let signed_il = sign_inclusion_list(&rig, il);
 
     rig.network_beacon_processor
-        .send_gossip_inclusion_list(junk_message_id(), junk_peer_id(), Box::new(signed_il))
+        .send_gossip_inclusion_list(
+            junk_message_id(),
+            junk_peer_id(),
+            Box::new(signed_il),
+            Duration::from_secs(0),
+        )
         .unwrap();
 
     let result = drain_validation_result(&mut rig.network_rx).await;
@@ -19,7 +19,7 @@ use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::{debug, error, trace, warn};
 use types::{
-    BlobSidecar, DataColumnSidecar, EthSpec, ForkContext, SignedBeaconBlock,
+    BlobSidecar, DataColumnSidecar, EthSpec, ExecutionProof, ForkContext, SignedBeaconBlock,
     SignedExecutionPayloadEnvelope,
 };
 use vibehouse_network::rpc::{
@@ -289,6 +289,24 @@ impl<T: BeaconChainTypes> Router<T> {
                             request,
                         ),
                 ),
+            RequestType::ExecutionPayloadEnvelopesByRange(request) => self
+                .handle_beacon_processor_send_result(
+                    self.network_beacon_processor
+                        .send_execution_payload_envelopes_by_range_request(
+                            peer_id,
+                            inbound_request_id,
+                            request,
+                        ),
+                ),
+            RequestType::ExecutionProofsByRoot(request) => self
+                .handle_beacon_processor_send_result(
+                    self.network_beacon_processor
+                        .send_execution_proofs_by_roots_request(
+                            peer_id,
+                            inbound_request_id,
+                            request,
+                        ),
+                ),
             RequestType::InclusionListByCommitteeIndices(request) => self
                 .handle_beacon_processor_send_result(
                     self.network_beacon_processor
@@ -338,6 +356,12 @@ impl<T: BeaconChainTypes> Router<T> {
             Response::ExecutionPayloadEnvelopesByRoot(envelope) => {
                 self.on_envelopes_by_root_response(peer_id, app_request_id, envelope);
             }
+            Response::ExecutionPayloadEnvelopesByRange(envelope) => {
+                self.on_envelopes_by_range_response(peer_id, app_request_id, envelope);
+            }
+            Response::ExecutionProofsByRoot(proof) => {
+                self.on_execution_proofs_by_root_response(peer_id, app_request_id, proof);
+            }
             Response::InclusionListByCommitteeIndices(_il) => {
                 // Phase 5: handle inclusion list responses in beacon chain integration
                 debug!(%peer_id, "Received inclusion list by committee indices response");
@@ -531,7 +555,7 @@ impl<T: BeaconChainTypes> Router<T> {
             ),
             PubsubMessage::PayloadAttestation(message) => self.handle_beacon_processor_send_result(
                 self.network_beacon_processor
-                    .send_gossip_payload_attestation(message_id, peer_id, message),
+                    .send_gossip_payload_attestation(message_id, peer_id, message, timestamp_now()),
             ),
             PubsubMessage::ProposerPreferences(preferences) => self
                 .handle_beacon_processor_send_result(
@@ -551,8 +575,12 @@ impl<T: BeaconChainTypes> Router<T> {
                 );
             }
             PubsubMessage::InclusionList(il) => self.handle_beacon_processor_send_result(
-                self.network_beacon_processor
-                    .send_gossip_inclusion_list(message_id, peer_id, il),
+                self.network_beacon_processor.send_gossip_inclusion_list(
+                    message_id,
+                    peer_id,
+                    il,
+                    timestamp_now(),
+                ),
             ),
         }
     }
@@ -808,8 +836,7 @@ impl<T: BeaconChainTypes> Router<T> {
     ) {
         let sync_request_id = match app_request_id {
             AppRequestId::Sync(sync_id) => match sync_id {
-                id
-                @ (SyncRequestId::EnvelopesByRoot(..) | SyncRequestId::SingleEnvelope { .. }) => id,
+                id @ SyncRequestId::SingleEnvelope { .. } => id,
                 other => {
                     crit!(request = ?other, "EnvelopesByRoot response on incorrect request");
                     return;
@@ -834,6 +861,62 @@ impl<T: BeaconChainTypes> Router<T> {
         });
     }
 
+    fn on_execution_proofs_by_root_response(
+        &self,
+        peer_id: PeerId,
+        app_request_id: AppRequestId,
+        proof: Option<Arc<ExecutionProof>>,
+    ) {
+        let sync_request_id = match app_request_id {
+            AppRequestId::Sync(sync_id) => match sync_id {
+                id @ SyncRequestId::SingleExecutionProofs { .. } => id,
+                other => {
+                    crit!(request = ?other, "ExecutionProofsByRoot response on incorrect request");
+                    return;
+                }
+            },
+            AppRequestId::Router => {
+                crit!(%peer_id, "All ExecutionProofsByRoot requests belong to sync");
+                return;
+            }
+            AppRequestId::Internal => unreachable!("Handled internally"),
+        };
+
+        trace!(
+            %peer_id,
+            "Received ExecutionProofsByRoot Response"
+        );
+        self.send_to_sync(SyncMessage::RpcExecutionProof {
+            sync_request_id,
+            peer_id,
+            proof,
+            seen_timestamp: timestamp_now(),
+        });
+    }
+
+    fn on_envelopes_by_range_response(
+        &self,
+        peer_id: PeerId,
+        app_request_id: AppRequestId,
+        envelope: Option<Arc<SignedExecutionPayloadEnvelope<T::EthSpec>>>,
+    ) {
+        trace!(
+            %peer_id,
+            "Received EnvelopesByRange Response"
+        );
+
+        if let AppRequestId::Sync(sync_request_id) = app_request_id {
+            self.send_to_sync(SyncMessage::RpcEnvelope {
+                sync_request_id,
+                peer_id,
+                envelope,
+                seen_timestamp: timestamp_now(),
+            });
+        } else {
+            crit!("All envelopes by range responses should belong to sync");
+        }
+    }
+
     fn handle_beacon_processor_send_result(
         &mut self,
         result: Result<(), crate::network_beacon_processor::Error<T::EthSpec>>,
@@ -30,6 +30,7 @@ use std::collections::{
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 use strum::IntoEnumIterator;
 use tracing::{debug, error, info, warn};
 use types::{ColumnIndex, Epoch, EthSpec};
@@ -151,6 +152,10 @@ pub(crate) struct BackFillSync<T: BeaconChainTypes> {
     /// This signifies that we are able to attempt to restart a failed chain.
     restart_failed_sync: bool,
 
+    /// Set while the beacon chain is importing block history from era files. Backfill sync waits
+    /// for the import to finish and then starts from wherever it left the anchor.
+    awaiting_era_import: bool,
+
     /// Reference to the beacon chain to obtain initial starting points for the backfill sync.
     beacon_chain: Arc<BeaconChain<T>>,
 
@@ -191,6 +196,7 @@ impl<T: BeaconChainTypes> BackFillSync<T> {
             validated_batches: 0,
             participating_peers: HashSet::new(),
             restart_failed_sync: false,
+            awaiting_era_import: beacon_chain.era_import_in_progress.load(Ordering::Acquire),
             beacon_chain,
         };
 
@@ -218,6 +224,25 @@ impl<T: BeaconChainTypes> BackFillSync<T> {
         match self.state() {
             BackFillState::Syncing => {} // already syncing ignore.
             BackFillState::Paused => {
+                if self.awaiting_era_import {
+                    if self
+                        .beacon_chain
+                        .era_import_in_progress
+                        .load(Ordering::Acquire)
+                    {
+                        return Ok(SyncStart::NotSyncing);
+                    }
+                    self.awaiting_era_import = false;
+                    if self.reset_start_epoch().is_err() {
+                        info!("Block history completed from era files");
+                        self.set_state(BackFillState::Completed);
+                        return Ok(SyncStart::NotSyncing);
+                    }
+                    self.processing_target = self.current_start;
+                    self.to_be_downloaded = self.current_start;
+                    debug!(start_epoch = %self.current_start, "Era import finished, backfill sync starting from the imported history");
+                }
+
                 if self
                     .network_globals
                     .peers
@@ -349,6 +374,9 @@ impl<T: BeaconChainTypes> BackFillSync<T> {
                     CouplingError::BlobPeerFailure(msg) => {
                         tracing::debug!(?batch_id, msg, "Blob peer failure");
                     }
+                    CouplingError::EnvelopePeerFailure(msg) => {
+                        tracing::debug!(?batch_id, msg, "Envelope peer failure");
+                    }
                     CouplingError::InternalError(msg) => {
                         error!(?batch_id, msg, "Block components coupling internal error");
                     }
@@ -1218,7 +1246,7 @@ mod tests {
     use super::*;
     use beacon_chain::test_utils::BeaconChainHarness;
     use bls::Hash256;
-    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::atomic::AtomicU64;
     use types::MinimalEthSpec;
     use vibehouse_network::{NetworkConfig, SyncInfo, SyncStatus};
 
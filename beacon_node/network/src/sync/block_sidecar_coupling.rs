@@ -5,12 +5,13 @@ use std::{collections::HashMap, sync::Arc};
 use tracing::{Span, debug};
 use types::{
     BlobSidecar, ChainSpec, ColumnIndex, DataColumnSidecar, DataColumnSidecarList, EthSpec,
-    Hash256, RuntimeVariableList, SignedBeaconBlock,
+    Hash256, RuntimeVariableList, SignedBeaconBlock, SignedExecutionPayloadEnvelope,
 };
 use vibehouse_network::{
     PeerId,
     service::api_types::{
         BlobsByRangeRequestId, BlocksByRangeRequestId, DataColumnsByRangeRequestId,
+        EnvelopesByRangeRequestId,
     },
 };
 
@@ -28,6 +29,7 @@ pub(crate) type CouplingResult<E> = Result<(Vec<RpcBlock<E>>, PeerGroup), Coupli
 /// - Blocks themselves (always required)
 /// - Blob sidecars (pre-Fulu fork)
 /// - Data columns (Fulu fork and later)
+/// - Execution payload envelopes (Gloas fork and later)
 ///
 /// It accumulates responses until all expected components are received, then couples
 /// them together and returns complete `RpcBlock`s ready for processing. Handles validation
@@ -37,12 +39,39 @@ pub(crate) struct RangeBlockComponentsRequest<E: EthSpec> {
     blocks_request: ByRangeRequest<BlocksByRangeRequestId, Vec<Arc<SignedBeaconBlock<E>>>>,
     /// Sidecars we have received awaiting for their corresponding block.
     block_data_request: RangeBlockDataRequest<E>,
+    /// Execution payload envelopes for Gloas blocks, requested alongside the blocks so that each
+    /// block and its payload are imported together.
+    envelopes_request: Option<
+        ByRangeRequest<EnvelopesByRangeRequestId, Vec<Arc<SignedExecutionPayloadEnvelope<E>>>>,
+    >,
+    /// Other peers the envelopes can be requested from.
+    envelopes_fallback: Option<EnvelopesFallback>,
     /// Tracks which peers contributed to this batch (block peer, blob peer, column peers).
     peers: HashMap<PeerId, Vec<usize>>,
     /// Span to track the range request and all children range requests.
     pub(crate) request_span: Span,
 }
 
+/// The part of a batch that a peer served, recorded in the batch's [`PeerGroup`].
+pub(super) enum BatchComponent {
+    /// Index 0 of the peer group.
+    Blocks,
+    /// Index 1 of the peer group.
+    Blobs,
+    /// The served column indices.
+    Columns(Vec<usize>),
+    /// Execution payload envelopes (Gloas fork and later).
+    Envelopes,
+}
+
+/// Peers to retry an envelopes by range request with if the envelope peer does not support the
+/// protocol.
+struct EnvelopesFallback {
+    start_slot: u64,
+    count: u64,
+    peers: Vec<PeerId>,
+}
+
 pub(crate) enum ByRangeRequest<I: PartialEq + std::fmt::Display, T> {
     Active(I),
     Complete(T),
@@ -81,6 +110,7 @@ pub(crate) enum CouplingError {
         exceeded_retries: bool,
     },
     BlobPeerFailure(String),
+    EnvelopePeerFailure(String),
 }
 
 impl<E: EthSpec> RangeBlockComponentsRequest<E> {
@@ -90,6 +120,7 @@ impl<E: EthSpec> RangeBlockComponentsRequest<E> {
     /// * `blocks_req_id` - Request ID for the blocks
     /// * `blobs_req_id` - Optional request ID for blobs (pre-Fulu fork)
     /// * `data_columns` - Optional tuple of (request_id->column_indices pairs, expected_custody_columns) for Fulu fork
+    /// * `envelopes_req_id` - Optional request ID for execution payload envelopes (Gloas fork)
     #[allow(clippy::type_complexity)]
     pub(super) fn new(
         blocks_req_id: BlocksByRangeRequestId,
@@ -99,6 +130,7 @@ impl<E: EthSpec> RangeBlockComponentsRequest<E> {
             Vec<ColumnIndex>,
         )>,
         deferred_columns: Option<(Vec<ColumnIndex>, u64, u64)>,
+        envelopes_req_id: Option<EnvelopesByRangeRequestId>,
         request_span: Span,
     ) -> Self {
         let block_data_request = if let Some(blobs_req_id) = blobs_req_id {
@@ -127,6 +159,8 @@ impl<E: EthSpec> RangeBlockComponentsRequest<E> {
         Self {
             blocks_request: ByRangeRequest::Active(blocks_req_id),
             block_data_request,
+            envelopes_request: envelopes_req_id.map(ByRangeRequest::Active),
+            envelopes_fallback: None,
             peers: HashMap::new(),
             request_span,
         }
@@ -200,9 +234,15 @@ impl<E: EthSpec> RangeBlockComponentsRequest<E> {
     }
 
     /// Record a peer that contributed to this batch's download.
-    /// `index` convention: 0 = blocks, 1 = blobs, column_index + 2 = data columns.
-    pub(super) fn record_peer(&mut self, peer_id: PeerId, indices: Vec<usize>) {
-        self.peers.entry(peer_id).or_default().extend(indices);
+    pub(super) fn record_peer(&mut self, peer_id: PeerId, component: BatchComponent) {
+        let indices = self.peers.entry(peer_id).or_default();
+        match component {
+            BatchComponent::Blocks => indices.push(0),
+            BatchComponent::Blobs => indices.push(1),
+            BatchComponent::Columns(columns) => indices.extend(columns),
+            // Envelopes are not indexed, the peer only joins the group.
+            BatchComponent::Envelopes => {}
+        }
     }
 
     /// Build a PeerGroup from all recorded peers.
@@ -241,6 +281,61 @@ impl<E: EthSpec> RangeBlockComponentsRequest<E> {
         }
     }
 
+    /// Adds received execution payload envelopes to the request.
+    ///
+    /// Returns an error if no envelopes were requested, or if the request ID doesn't match.
+    pub(super) fn add_envelopes(
+        &mut self,
+        req_id: EnvelopesByRangeRequestId,
+        envelopes: Vec<Arc<SignedExecutionPayloadEnvelope<E>>>,
+    ) -> Result<(), String> {
+        match &mut self.envelopes_request {
+            Some(req) => req.finish(req_id, envelopes),
+            None => Err("received envelopes but expected none".to_owned()),
+        }
+    }
+
+    /// Sets the peers to re-route the envelopes request to if the envelope peer does not support
+    /// the protocol.
+    pub(super) fn set_envelopes_fallback(
+        &mut self,
+        start_slot: u64,
+        count: u64,
+        peers: Vec<PeerId>,
+    ) {
+        self.envelopes_fallback = Some(EnvelopesFallback {
+            start_slot,
+            count,
+            peers,
+        });
+    }
+
+    /// Pops the next peer to re-route the envelopes request to, with the slot range to request.
+    pub(super) fn next_envelopes_peer(&mut self) -> Option<(PeerId, u64, u64)> {
+        let fallback = self.envelopes_fallback.as_mut()?;
+        let peer_id = fallback.peers.pop()?;
+        Some((peer_id, fallback.start_slot, fallback.count))
+    }
+
+    /// Replaces a failed envelopes request with a request to another peer.
+    pub(super) fn reroute_envelopes(
+        &mut self,
+        failed_req_id: EnvelopesByRangeRequestId,
+        req_id: EnvelopesByRangeRequestId,
+    ) -> Result<(), String> {
+        match &mut self.envelopes_request {
+            Some(ByRangeRequest::Active(expected_id)) if *expected_id == failed_req_id => {
+                *expected_id = req_id;
+                Ok(())
+            }
+            Some(ByRangeRequest::Active(expected_id)) => Err(format!(
+                "unexpected req_id expected {expected_id} got {failed_req_id}"
+            )),
+            Some(ByRangeRequest::Complete(_)) => Err("request already complete".to_owned()),
+            None => Err("rerouted envelopes but expected none".to_owned()),
+        }
+    }
+
     /// Adds received custody columns to the request.
     ///
     /// Returns an error if this request expects blobs instead of data columns,
@@ -272,23 +367,37 @@ impl<E: EthSpec> RangeBlockComponentsRequest<E> {
     /// Returns `Some(Ok(_))` with valid RPC blocks if all data is present and valid.
     /// Returns `Some(Err(_))` if there are issues coupling blocks with their data.
     pub(super) fn responses(&mut self, spec: &ChainSpec) -> Option<CouplingResult<E>> {
-        let blocks = self.blocks_request.to_finished()?;
+        let blocks = self.blocks_request.to_finished()?.clone();
+        let envelopes = match &self.envelopes_request {
+            Some(req) => Some(req.to_finished()?.clone()),
+            None => None,
+        };
         let peer_group = self.peer_group();
+        let resp = self.responses_with_block_data(blocks, spec)?;
 
+        Some(resp.and_then(|blocks| {
+            let blocks = match envelopes {
+                Some(envelopes) => Self::attach_envelopes(blocks, envelopes)?,
+                None => blocks,
+            };
+            Ok((blocks, peer_group))
+        }))
+    }
+
+    /// Couples the blocks with their blobs or data columns, once all of them have arrived.
+    fn responses_with_block_data(
+        &mut self,
+        blocks: Vec<Arc<SignedBeaconBlock<E>>>,
+        spec: &ChainSpec,
+    ) -> Option<Result<Vec<RpcBlock<E>>, CouplingError>> {
         // Increment the attempt once this function returns the response or errors
         match &mut self.block_data_request {
             // Columns are deferred — not ready until column requests are activated and complete
             RangeBlockDataRequest::DeferredColumns { .. } => None,
-            RangeBlockDataRequest::NoData => Some(
-                Self::responses_with_blobs(blocks.clone(), vec![], spec)
-                    .map(|blocks| (blocks, peer_group)),
-            ),
+            RangeBlockDataRequest::NoData => Some(Self::responses_with_blobs(blocks, vec![], spec)),
             RangeBlockDataRequest::Blobs(request) => {
                 let blobs = request.to_finished()?;
-                Some(
-                    Self::responses_with_blobs(blocks.clone(), blobs.clone(), spec)
-                        .map(|blocks| (blocks, peer_group)),
-                )
+                Some(Self::responses_with_blobs(blocks, blobs.clone(), spec))
             }
             RangeBlockDataRequest::DataColumns {
                 requests,
@@ -321,7 +430,7 @@ impl<E: EthSpec> RangeBlockComponentsRequest<E> {
                 }
 
                 let resp = Self::responses_with_custody_columns(
-                    blocks.clone(),
+                    blocks,
                     data_columns,
                     column_to_peer_id,
                     expected_custody_columns,
@@ -342,9 +451,51 @@ impl<E: EthSpec> RangeBlockComponentsRequest<E> {
                     }
                 }
 
-                Some(resp.map(|blocks| (blocks, peer_group)))
+                Some(resp)
+            }
+        }
+    }
+
+    /// Attaches each envelope to the block it commits to.
+    ///
+    /// A Gloas block without an envelope is valid (the builder may have withheld the payload), so
+    /// missing envelopes are not an error. An envelope for a block outside the batch, or a second
+    /// envelope for the same block, is a peer fault.
+    fn attach_envelopes(
+        mut blocks: Vec<RpcBlock<E>>,
+        envelopes: Vec<Arc<SignedExecutionPayloadEnvelope<E>>>,
+    ) -> Result<Vec<RpcBlock<E>>, CouplingError> {
+        let mut envelopes_by_root = HashMap::with_capacity(envelopes.len());
+        for envelope in envelopes {
+            let block_root = envelope.message.beacon_block_root;
+            if envelopes_by_root.insert(block_root, envelope).is_some() {
+                return Err(CouplingError::EnvelopePeerFailure(format!(
+                    "Repeat envelope for block {block_root:?}"
+                )));
+            }
+        }
+
+        for block in &mut blocks {
+            if let Some(envelope) = envelopes_by_root.remove(&block.block_root()) {
+                let block_slot = block.as_block().slot();
+                if envelope.message.slot != block_slot {
+                    return Err(CouplingError::EnvelopePeerFailure(format!(
+                        "Envelope slot {} does not match block slot {block_slot}",
+                        envelope.message.slot,
+                    )));
+                }
+                block.set_envelope(envelope);
             }
         }
+
+        if !envelopes_by_root.is_empty() {
+            let remaining_roots = envelopes_by_root.keys().collect::<Vec<_>>();
+            return Err(CouplingError::EnvelopePeerFailure(format!(
+                "Envelopes for blocks not in batch {remaining_roots:?}"
+            )));
+        }
+
+        Ok(blocks)
     }
 
     fn responses_with_blobs(
@@ -542,12 +693,16 @@ mod tests {
     use rand::SeedableRng;
     use std::sync::Arc;
     use tracing::Span;
-    use types::{Epoch, ForkName, MinimalEthSpec as E, SignedBeaconBlock, test_utils::XorShiftRng};
+    use types::{
+        Epoch, ForkName, Hash256, MinimalEthSpec as E, SignedBeaconBlock,
+        SignedExecutionPayloadEnvelope, test_utils::XorShiftRng,
+    };
     use vibehouse_network::{
         PeerId,
         service::api_types::{
             BlobsByRangeRequestId, BlocksByRangeRequestId, ComponentsByRangeRequestId,
-            DataColumnsByRangeRequestId, DataColumnsByRangeRequester, Id, RangeRequestId,
+            DataColumnsByRangeRequestId, DataColumnsByRangeRequester, EnvelopesByRangeRequestId,
+            Id, RangeRequestId,
         },
     };
 
@@ -575,6 +730,20 @@ mod tests {
         }
     }
 
+    fn envelopes_id(parent_request_id: ComponentsByRangeRequestId) -> EnvelopesByRangeRequestId {
+        EnvelopesByRangeRequestId {
+            id: 2,
+            parent_request_id,
+        }
+    }
+
+    fn envelope_for(block: &SignedBeaconBlock<E>) -> Arc<SignedExecutionPayloadEnvelope<E>> {
+        let mut envelope = SignedExecutionPayloadEnvelope::empty();
+        envelope.message.beacon_block_root = block.canonical_root();
+        envelope.message.slot = block.slot();
+        Arc::new(envelope)
+    }
+
     fn columns_id(
         id: Id,
         parent_request_id: DataColumnsByRangeRequester,
@@ -604,8 +773,14 @@ mod tests {
             .collect::<Vec<Arc<SignedBeaconBlock<E>>>>();
 
         let blocks_req_id = blocks_id(components_id());
-        let mut info =
-            RangeBlockComponentsRequest::<E>::new(blocks_req_id, None, None, None, Span::none());
+        let mut info = RangeBlockComponentsRequest::<E>::new(
+            blocks_req_id,
+            None,
+            None,
+            None,
+            None,
+            Span::none(),
+        );
 
         // Send blocks and complete terminate response
         info.add_blocks(blocks_req_id, blocks).unwrap();
@@ -614,6 +789,83 @@ mod tests {
         info.responses(&test_spec::<E>()).unwrap().unwrap();
     }
 
+    #[test]
+    fn envelopes_coupled_with_blocks() {
+        let spec = test_spec::<E>();
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let blocks = (0..2)
+            .map(|_| {
+                generate_rand_block_and_blobs::<E>(ForkName::Base, NumBlobs::None, &mut rng, &spec)
+                    .0
+                    .into()
+            })
+            .collect::<Vec<Arc<SignedBeaconBlock<E>>>>();
+
+        let components_id = components_id();
+        let blocks_req_id = blocks_id(components_id);
+        let envelopes_req_id = envelopes_id(components_id);
+        let mut info = RangeBlockComponentsRequest::<E>::new(
+            blocks_req_id,
+            None,
+            None,
+            None,
+            Some(envelopes_req_id),
+            Span::none(),
+        );
+
+        info.add_blocks(blocks_req_id, blocks.clone()).unwrap();
+        // Assert response is not finished until the envelopes arrive
+        assert!(!is_finished(&mut info));
+
+        // Only the first block has a revealed payload
+        info.add_envelopes(envelopes_req_id, vec![envelope_for(&blocks[0])])
+            .unwrap();
+
+        let (rpc_blocks, _peer_group) = info.responses(&spec).unwrap().unwrap();
+        assert_eq!(rpc_blocks.len(), 2);
+        assert_eq!(
+            rpc_blocks[0].envelope().unwrap().message.beacon_block_root,
+            blocks[0].canonical_root()
+        );
+        assert!(rpc_blocks[1].envelope().is_none());
+    }
+
+    #[test]
+    fn envelope_for_unknown_block_is_peer_failure() {
+        let spec = test_spec::<E>();
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let blocks = (0..2)
+            .map(|_| {
+                generate_rand_block_and_blobs::<E>(ForkName::Base, NumBlobs::None, &mut rng, &spec)
+                    .0
+                    .into()
+            })
+            .collect::<Vec<Arc<SignedBeaconBlock<E>>>>();
+
+        let components_id = components_id();
+        let blocks_req_id = blocks_id(components_id);
+        let envelopes_req_id = envelopes_id(components_id);
+        let mut info = RangeBlockComponentsRequest::<E>::new(
+            blocks_req_id,
+            None,
+            None,
+            None,
+            Some(envelopes_req_id),
+            Span::none(),
+        );
+
+        let mut unknown = SignedExecutionPayloadEnvelope::empty();
+        unknown.message.beacon_block_root = Hash256::repeat_byte(0xaa);
+        info.add_blocks(blocks_req_id, blocks).unwrap();
+        info.add_envelopes(envelopes_req_id, vec![Arc::new(unknown)])
+            .unwrap();
+
+        assert!(matches!(
+            info.responses(&spec).unwrap(),
+            Err(super::CouplingError::EnvelopePeerFailure(_))
+        ));
+    }
+
     #[test]
     fn empty_blobs_into_responses() {
         let spec = test_spec::<E>();
@@ -640,6 +892,7 @@ mod tests {
             Some(blobs_req_id),
             None,
             None,
+            None,
             Span::none(),
         );
 
@@ -690,6 +943,7 @@ mod tests {
             None,
             Some((columns_req_id.clone(), expects_custody_columns.clone())),
             None,
+            None,
             Span::none(),
         );
         // Send blocks and complete terminate response
@@ -754,6 +1008,7 @@ mod tests {
             None,
             Some((columns_req_id.clone(), expects_custody_columns)),
             None,
+            None,
             Span::none(),
         );
 
@@ -843,6 +1098,7 @@ mod tests {
             None,
             Some((columns_req_id.clone(), expected_custody_columns.clone())),
             None,
+            None,
             Span::none(),
         );
 
@@ -930,6 +1186,7 @@ mod tests {
             None,
             Some((columns_req_id.clone(), expected_custody_columns)),
             None,
+            None,
             Span::none(),
         );
 
@@ -1024,6 +1281,7 @@ mod tests {
             None,
             Some((columns_req_id.clone(), expected_custody_columns)),
             None,
+            None,
             Span::none(),
         );
 
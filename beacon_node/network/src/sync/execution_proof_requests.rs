@@ -0,0 +1,188 @@
+//! Tracks the execution proofs a stateless node is still missing for imported blocks.
+//!
+//! Proofs are expected on gossip, so a block is only requested via `ExecutionProofsByRoot` once
+//! the slot after it has started. If a peer responds with nothing useful or the request fails, the
+//! block is requested again from a peer that has not been tried yet, up to
+//! `MAX_EXECUTION_PROOF_REQUEST_ATTEMPTS` peers.
+
+use rand::seq::IteratorRandom;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+use types::Hash256;
+use vibehouse_network::PeerId;
+
+/// The maximum number of peers to request the proofs of a single block from.
+pub const MAX_EXECUTION_PROOF_REQUEST_ATTEMPTS: usize = 3;
+
+/// How long to wait after a completed request before checking whether the proofs are still
+/// missing. Leaves time for the beacon processor to verify the proofs that were received.
+pub const EXECUTION_PROOF_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+struct PendingProofs {
+    /// The earliest time at which to send the next request.
+    request_at: Instant,
+    /// Whether a request for this block is currently in flight.
+    in_flight: bool,
+    /// Peers the proofs have already been requested from.
+    tried_peers: HashSet<PeerId>,
+}
+
+#[derive(Default)]
+pub struct ExecutionProofRequests {
+    pending: HashMap<Hash256, PendingProofs>,
+}
+
+/// The outcome of `ExecutionProofRequests::next_peer`.
+#[derive(Debug, PartialEq)]
+pub enum NextPeer {
+    /// Request the proofs from this peer.
+    Request(PeerId),
+    /// Every given peer has already been tried, try again later.
+    NoPeer,
+    /// Every attempt has been used up, the block is no longer tracked.
+    Exhausted,
+}
+
+impl ExecutionProofRequests {
+    /// Starts tracking `block_root`, requesting its proofs no earlier than `request_at`.
+    ///
+    /// Does nothing if the block is already tracked.
+    pub fn insert(&mut self, block_root: Hash256, request_at: Instant) {
+        self.pending
+            .entry(block_root)
+            .or_insert_with(|| PendingProofs {
+                request_at,
+                in_flight: false,
+                tried_peers: HashSet::new(),
+            });
+    }
+
+    pub fn remove(&mut self, block_root: &Hash256) {
+        self.pending.remove(block_root);
+    }
+
+    /// Returns the blocks without a request in flight whose next request is due at `now`.
+    pub fn due(&self, now: Instant) -> Vec<Hash256> {
+        self.pending
+            .iter()
+            .filter(|(_, pending)| !pending.in_flight && pending.request_at <= now)
+            .map(|(block_root, _)| *block_root)
+            .collect()
+    }
+
+    /// Picks a random peer out of `peers` that has not been asked for the proofs of `block_root`
+    /// yet and marks a request to it as in flight.
+    pub fn next_peer(
+        &mut self,
+        block_root: Hash256,
+        peers: impl Iterator<Item = PeerId>,
+    ) -> NextPeer {
+        let Some(pending) = self.pending.get_mut(&block_root) else {
+            return NextPeer::Exhausted;
+        };
+        if pending.tried_peers.len() >= MAX_EXECUTION_PROOF_REQUEST_ATTEMPTS {
+            self.pending.remove(&block_root);
+            return NextPeer::Exhausted;
+        }
+
+        let Some(peer_id) = peers
+            .filter(|peer_id| !pending.tried_peers.contains(peer_id))
+            .choose(&mut rand::rng())
+        else {
+            return NextPeer::NoPeer;
+        };
+        pending.tried_peers.insert(peer_id);
+        pending.in_flight = true;
+        NextPeer::Request(peer_id)
+    }
+
+    /// The peer finished responding to the request for `block_root`.
+    ///
+    /// The proofs it sent are still being verified, so only check again after
+    /// `EXECUTION_PROOF_RETRY_DELAY`.
+    pub fn on_request_completed(&mut self, block_root: &Hash256, now: Instant) {
+        if let Some(pending) = self.pending.get_mut(block_root) {
+            pending.in_flight = false;
+            pending.request_at = now + EXECUTION_PROOF_RETRY_DELAY;
+        }
+    }
+
+    /// The request for `block_root` failed, retry straight away with a different peer.
+    pub fn on_request_failed(&mut self, block_root: &Hash256, now: Instant) {
+        if let Some(pending) = self.pending.get_mut(block_root) {
+            pending.in_flight = false;
+            pending.request_at = now;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_are_delayed_until_due() {
+        let mut requests = ExecutionProofRequests::default();
+        let now = Instant::now();
+        let block_root = Hash256::repeat_byte(1);
+
+        requests.insert(block_root, now + Duration::from_secs(6));
+        assert!(requests.due(now).is_empty());
+        assert_eq!(requests.due(now + Duration::from_secs(6)), vec![block_root]);
+
+        // Re-inserting does not reset the schedule.
+        requests.insert(block_root, now + Duration::from_secs(60));
+        assert_eq!(requests.due(now + Duration::from_secs(6)), vec![block_root]);
+    }
+
+    #[test]
+    fn retries_use_a_different_peer() {
+        let mut requests = ExecutionProofRequests::default();
+        let now = Instant::now();
+        let block_root = Hash256::repeat_byte(1);
+        let peers = (0..MAX_EXECUTION_PROOF_REQUEST_ATTEMPTS + 1)
+            .map(|_| PeerId::random())
+            .collect::<Vec<_>>();
+        requests.insert(block_root, now);
+
+        let NextPeer::Request(first) = requests.next_peer(block_root, peers.iter().copied()) else {
+            panic!("expected a peer");
+        };
+        // No further request while one is in flight.
+        assert!(requests.due(now).is_empty());
+
+        // An empty response is retried after a delay.
+        requests.on_request_completed(&block_root, now);
+        assert!(requests.due(now).is_empty());
+        assert_eq!(
+            requests.due(now + EXECUTION_PROOF_RETRY_DELAY),
+            vec![block_root]
+        );
+        assert_eq!(
+            requests.next_peer(block_root, std::iter::once(first)),
+            NextPeer::NoPeer
+        );
+        let NextPeer::Request(second) = requests.next_peer(block_root, peers.iter().copied())
+        else {
+            panic!("expected a peer");
+        };
+        assert_ne!(first, second);
+
+        // A failed request is retried straight away.
+        requests.on_request_failed(&block_root, now);
+        assert_eq!(requests.due(now), vec![block_root]);
+
+        for _ in 2..MAX_EXECUTION_PROOF_REQUEST_ATTEMPTS {
+            assert!(matches!(
+                requests.next_peer(block_root, peers.iter().copied()),
+                NextPeer::Request(_)
+            ));
+            requests.on_request_failed(&block_root, now);
+        }
+        assert_eq!(
+            requests.next_peer(block_root, peers.iter().copied()),
+            NextPeer::Exhausted
+        );
+        assert!(requests.pending.is_empty());
+    }
+}
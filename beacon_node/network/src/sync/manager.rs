@@ -35,6 +35,7 @@
 
 use super::backfill_sync::{BackFillSync, ProcessResult, SyncStart};
 use super::block_lookups::BlockLookups;
+use super::execution_proof_requests::{ExecutionProofRequests, NextPeer};
 use super::network_context::{
     CustodyByRootResult, RangeBlockComponent, RangeRequestId, RpcEvent, SyncNetworkContext,
 };
@@ -60,20 +61,20 @@ use lru_cache::LRUTimeCache;
 use slot_clock::SlotClock;
 use std::ops::Sub;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
-use tracing::{debug, error, info, trace, warn};
+use tracing::{debug, error, info, trace};
 use types::{
-    BlobSidecar, DataColumnSidecar, EthSpec, ForkContext, Hash256, SignedBeaconBlock,
-    SignedExecutionPayloadEnvelope, Slot,
+    BlobSidecar, DataColumnSidecar, EthSpec, ExecutionProof, ForkContext, Hash256,
+    SignedBeaconBlock, SignedExecutionPayloadEnvelope, Slot,
 };
 use vibehouse_network::SyncInfo;
 use vibehouse_network::rpc::RPCError;
 use vibehouse_network::service::api_types::{
     BlobsByRangeRequestId, BlocksByRangeRequestId, ComponentsByRangeRequestId,
     CustodyBackFillBatchRequestId, CustodyBackfillBatchId, CustodyRequester,
-    DataColumnsByRangeRequestId, DataColumnsByRangeRequester, DataColumnsByRootRequestId, Id,
-    SingleLookupReqId, SyncRequestId,
+    DataColumnsByRangeRequestId, DataColumnsByRangeRequester, DataColumnsByRootRequestId,
+    EnvelopesByRangeRequestId, Id, SingleLookupReqId, SyncRequestId,
 };
 use vibehouse_network::types::{NetworkGlobals, SyncState};
 use vibehouse_network::{PeerAction, PeerId};
@@ -134,7 +135,7 @@ pub(crate) enum SyncMessage<E: EthSpec> {
         seen_timestamp: Duration,
     },
 
-    /// An execution payload envelope has been received from the RPC (range sync).
+    /// An execution payload envelope has been received from the RPC.
     RpcEnvelope {
         sync_request_id: SyncRequestId,
         peer_id: PeerId,
@@ -142,6 +143,14 @@ pub(crate) enum SyncMessage<E: EthSpec> {
         seen_timestamp: Duration,
     },
 
+    /// An execution proof has been received from the RPC.
+    RpcExecutionProof {
+        sync_request_id: SyncRequestId,
+        peer_id: PeerId,
+        proof: Option<Arc<ExecutionProof>>,
+        seen_timestamp: Duration,
+    },
+
     /// A block with an unknown parent has been received.
     UnknownParentBlock(PeerId, Arc<SignedBeaconBlock<E>>, Hash256),
 
@@ -159,6 +168,10 @@ pub(crate) enum SyncMessage<E: EthSpec> {
     /// has not been seen. Request the envelope via ExecutionPayloadEnvelopesByRoot.
     MissingEnvelopeFromAttestation(PeerId, Hash256),
 
+    /// A block was imported on a stateless node without enough execution proofs having been
+    /// seen. Request them via ExecutionProofsByRoot once the slot after the block has started.
+    MissingExecutionProofs(Hash256),
+
     /// A peer has disconnected.
     Disconnect(PeerId),
 
@@ -273,6 +286,9 @@ pub(crate) struct SyncManager<T: BeaconChainTypes> {
     /// Debounce duplicated `MissingEnvelopeFromAttestation` events for the same block root.
     /// Keyed by block_root only (not peer) since the envelope is the same regardless of peer.
     notified_missing_envelopes: LRUTimeCache<Hash256>,
+
+    /// Blocks whose execution proofs are still missing, along with the peers already asked.
+    execution_proof_requests: ExecutionProofRequests,
 }
 
 /// Spawns a new `SyncManager` thread which has a weak reference to underlying beacon
@@ -336,6 +352,7 @@ impl<T: BeaconChainTypes> SyncManager<T> {
             notified_missing_envelopes: LRUTimeCache::new(Duration::from_secs(
                 NOTIFIED_UNKNOWN_ROOT_EXPIRY_SECONDS,
             )),
+            execution_proof_requests: ExecutionProofRequests::default(),
         }
     }
 
@@ -531,48 +548,20 @@ impl<T: BeaconChainTypes> SyncManager<T> {
                     "Attestation-triggered envelope request failed"
                 );
             }
-            SyncRequestId::EnvelopesByRoot(id) => {
-                // Envelope request failed — deliver the batch without envelopes.
-                // Blocks will fail state root check if they needed FULL parent state,
-                // which will trigger a retry from a different peer.
-                warn!(
-                    ?id,
+            SyncRequestId::EnvelopesByRange(req_id) => {
+                self.on_envelopes_by_range_response(req_id, peer_id, RpcEvent::RPCError(error));
+            }
+            SyncRequestId::SingleExecutionProofs { id, block_root } => {
+                debug!(
+                    ?block_root,
+                    %id,
                     %peer_id,
-                    "Envelope request failed, delivering batch without envelopes"
+                    ?error,
+                    "Execution proofs request failed"
                 );
-                if let Some((blocks, parent_request_id, peer_group)) =
-                    self.network.on_envelope_by_root_response(id, peer_id, None)
-                {
-                    match parent_request_id.requester {
-                        RangeRequestId::RangeSync { chain_id, batch_id } => {
-                            self.range_sync.blocks_by_range_response(
-                                &mut self.network,
-                                peer_group,
-                                chain_id,
-                                batch_id,
-                                parent_request_id.id,
-                                blocks,
-                            );
-                            self.update_sync_state();
-                        }
-                        RangeRequestId::BackfillSync { batch_id } => {
-                            match self.backfill_sync.on_block_response(
-                                &mut self.network,
-                                batch_id,
-                                peer_group,
-                                parent_request_id.id,
-                                blocks,
-                            ) {
-                                Ok(ProcessResult::SyncCompleted) => self.update_sync_state(),
-                                Ok(ProcessResult::Successful) => {}
-                                Err(_error) => {
-                                    self.network.remove_backfill_range_components();
-                                    self.update_sync_state();
-                                }
-                            }
-                        }
-                    }
-                }
+                self.execution_proof_requests
+                    .on_request_failed(&block_root, Instant::now());
+                self.request_missing_execution_proofs();
             }
         }
     }
@@ -833,6 +822,8 @@ impl<T: BeaconChainTypes> SyncManager<T> {
 
         let mut register_metrics_interval = tokio::time::interval(Duration::from_secs(5));
 
+        let mut execution_proofs_interval = tokio::time::interval(Duration::from_secs(1));
+
         // Trigger a sync state update every epoch. This helps check if we need to trigger a custody backfill sync.
         let epoch_duration =
             self.chain.slot_clock.slot_duration().as_secs() * T::EthSpec::slots_per_epoch();
@@ -862,6 +853,9 @@ impl<T: BeaconChainTypes> SyncManager<T> {
                 _ = epoch_interval.tick() => {
                     self.update_sync_state();
                 }
+                _ = execution_proofs_interval.tick() => {
+                    self.request_missing_execution_proofs();
+                }
             }
         }
     }
@@ -918,6 +912,12 @@ impl<T: BeaconChainTypes> SyncManager<T> {
                 envelope,
                 seen_timestamp,
             } => self.rpc_envelope_received(sync_request_id, peer_id, envelope, seen_timestamp),
+            SyncMessage::RpcExecutionProof {
+                sync_request_id,
+                peer_id,
+                proof,
+                seen_timestamp: _,
+            } => self.rpc_execution_proof_received(sync_request_id, peer_id, proof),
             SyncMessage::UnknownParentBlock(peer_id, block, block_root) => {
                 let block_slot = block.slot();
                 let parent_root = block.parent_root();
@@ -989,6 +989,26 @@ impl<T: BeaconChainTypes> SyncManager<T> {
                     self.network.request_single_envelope(peer_id, block_root);
                 }
             }
+            SyncMessage::MissingExecutionProofs(block_root) => {
+                // Give the proofs until the start of the next slot to arrive on gossip. Blocks
+                // imported late, e.g. by lookups or range sync, are requested straight away.
+                let block_slot = self
+                    .chain
+                    .canonical_head
+                    .fork_choice_read_lock()
+                    .get_block(&block_root)
+                    .map(|block| block.slot);
+                if let Some(block_slot) = block_slot {
+                    let request_at = Instant::now()
+                        + self
+                            .chain
+                            .slot_clock
+                            .duration_to_slot(block_slot + 1)
+                            .unwrap_or_default();
+                    self.execution_proof_requests.insert(block_root, request_at);
+                    self.request_missing_execution_proofs();
+                }
+            }
             SyncMessage::Disconnect(peer_id) => {
                 debug!(%peer_id, "Received disconnected message");
                 self.peer_disconnect(&peer_id);
@@ -1288,47 +1308,14 @@ impl<T: BeaconChainTypes> SyncManager<T> {
         sync_request_id: SyncRequestId,
         peer_id: PeerId,
         envelope: Option<Arc<SignedExecutionPayloadEnvelope<T::EthSpec>>>,
-        _seen_timestamp: Duration,
+        seen_timestamp: Duration,
     ) {
         match sync_request_id {
-            SyncRequestId::EnvelopesByRoot(id) => {
-                if let Some((blocks, parent_request_id, peer_group)) = self
-                    .network
-                    .on_envelope_by_root_response(id, peer_id, envelope)
-                {
-                    // All envelopes received — route the completed batch as if it just came
-                    // from range_block_component_response.
-                    match parent_request_id.requester {
-                        RangeRequestId::RangeSync { chain_id, batch_id } => {
-                            self.range_sync.blocks_by_range_response(
-                                &mut self.network,
-                                peer_group,
-                                chain_id,
-                                batch_id,
-                                parent_request_id.id,
-                                blocks,
-                            );
-                            self.update_sync_state();
-                        }
-                        RangeRequestId::BackfillSync { batch_id } => {
-                            match self.backfill_sync.on_block_response(
-                                &mut self.network,
-                                batch_id,
-                                peer_group,
-                                parent_request_id.id,
-                                blocks,
-                            ) {
-                                Ok(ProcessResult::SyncCompleted) => self.update_sync_state(),
-                                Ok(ProcessResult::Successful) => {}
-                                Err(_error) => {
-                                    self.network.remove_backfill_range_components();
-                                    self.update_sync_state();
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+            SyncRequestId::EnvelopesByRange(id) => self.on_envelopes_by_range_response(
+                id,
+                peer_id,
+                RpcEvent::from_chunk(envelope, seen_timestamp),
+            ),
             SyncRequestId::SingleEnvelope { id, block_root } => {
                 match envelope {
                     Some(env) => {
@@ -1364,6 +1351,90 @@ impl<T: BeaconChainTypes> SyncManager<T> {
         }
     }
 
+    fn rpc_execution_proof_received(
+        &mut self,
+        sync_request_id: SyncRequestId,
+        peer_id: PeerId,
+        proof: Option<Arc<ExecutionProof>>,
+    ) {
+        match sync_request_id {
+            SyncRequestId::SingleExecutionProofs { id, block_root } => match proof {
+                Some(proof) => {
+                    if proof.block_root != block_root {
+                        self.network.report_peer(
+                            peer_id,
+                            PeerAction::LowToleranceError,
+                            "unrequested_execution_proof",
+                        );
+                        return;
+                    }
+                    if let Err(e) = self.network.send_rpc_execution_proof(peer_id, proof) {
+                        debug!(
+                            ?block_root,
+                            error = ?e,
+                            "Failed to send RPC execution proof to beacon processor"
+                        );
+                    }
+                }
+                None => {
+                    debug!(
+                        ?block_root,
+                        %id,
+                        %peer_id,
+                        "Execution proofs request completed"
+                    );
+                    // If the peer did not have enough proofs, the next check retries with a
+                    // different peer.
+                    self.execution_proof_requests
+                        .on_request_completed(&block_root, Instant::now());
+                }
+            },
+            _ => {
+                crit!(%peer_id, "bad request id for execution proof");
+            }
+        }
+    }
+
+    /// Sends an `ExecutionProofsByRoot` request for every block whose proofs are still missing and
+    /// whose next request is due, each to a synced peer that has not been asked before.
+    fn request_missing_execution_proofs(&mut self) {
+        for block_root in self.execution_proof_requests.due(Instant::now()) {
+            if !self.chain.execution_proofs_missing(block_root) {
+                self.execution_proof_requests.remove(&block_root);
+                continue;
+            }
+
+            let synced_peers = self
+                .network_globals()
+                .peers
+                .read()
+                .synced_peers()
+                .copied()
+                .collect::<Vec<_>>();
+            match self
+                .execution_proof_requests
+                .next_peer(block_root, synced_peers.into_iter())
+            {
+                NextPeer::Request(peer_id) => {
+                    debug!(?block_root, ?peer_id, "Requesting missing execution proofs");
+                    self.network.request_execution_proofs(peer_id, block_root);
+                }
+                NextPeer::NoPeer => {
+                    trace!(
+                        ?block_root,
+                        "No untried synced peer to request execution proofs from"
+                    );
+                }
+                NextPeer::Exhausted => {
+                    debug!(
+                        ?block_root,
+                        "Giving up on requesting execution proofs, relying on gossip"
+                    );
+                }
+            }
+        }
+    }
+
     fn on_single_blob_response(
         &mut self,
         id: SingleLookupReqId,
@@ -1432,6 +1503,24 @@ impl<T: BeaconChainTypes> SyncManager<T> {
         }
     }
 
+    fn on_envelopes_by_range_response(
+        &mut self,
+        id: EnvelopesByRangeRequestId,
+        peer_id: PeerId,
+        envelope: RpcEvent<Arc<SignedExecutionPayloadEnvelope<T::EthSpec>>>,
+    ) {
+        if let Some(resp) = self
+            .network
+            .on_envelopes_by_range_response(id, peer_id, envelope)
+        {
+            self.on_range_components_response(
+                id.parent_request_id,
+                peer_id,
+                RangeBlockComponent::Envelope(id, resp),
+            );
+        }
+    }
+
     fn on_data_columns_by_range_response(
         &mut self,
         id: DataColumnsByRangeRequestId,
@@ -1489,17 +1578,6 @@ impl<T: BeaconChainTypes> SyncManager<T> {
         ) {
             match resp {
                 Ok((blocks, peer_group)) => {
-                    // Check if any blocks need envelope downloads (Gloas ePBS).
-                    // If so, the blocks are stashed and will be delivered via
-                    // rpc_envelope_received once envelopes arrive.
-                    let Some(blocks) = self.network.request_envelopes_if_needed(
-                        blocks,
-                        range_request_id,
-                        peer_group.clone(),
-                    ) else {
-                        return;
-                    };
-
                     match range_request_id.requester {
                         RangeRequestId::RangeSync { chain_id, batch_id } => {
                             self.range_sync.blocks_by_range_response(
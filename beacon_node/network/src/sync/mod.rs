@@ -6,6 +6,7 @@ mod batch;
 mod block_lookups;
 mod block_sidecar_coupling;
 mod custody_backfill_sync;
+mod execution_proof_requests;
 pub(crate) mod manager;
 mod network_context;
 mod peer_sync_info;
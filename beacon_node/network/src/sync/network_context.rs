@@ -14,7 +14,7 @@ use crate::service::NetworkMessage;
 use crate::status::ToStatusMessage;
 use crate::sync::batch::ByRangeRequestType;
 use crate::sync::block_lookups::SingleLookupId;
-use crate::sync::block_sidecar_coupling::CouplingError;
+use crate::sync::block_sidecar_coupling::{BatchComponent, CouplingError};
 use crate::sync::network_context::requests::BlobsByRootSingleBlockRequest;
 use crate::sync::range_data_column_batch_request::RangeDataColumnBatchRequest;
 use beacon_chain::block_verification_types::{AsBlock, RpcBlock};
@@ -26,6 +26,7 @@ pub(crate) use requests::LookupVerifyError;
 use requests::{
     ActiveRequests, BlobsByRangeRequestItems, BlobsByRootRequestItems, BlocksByRangeRequestItems,
     BlocksByRootRequestItems, DataColumnsByRangeRequestItems, DataColumnsByRootRequestItems,
+    EnvelopesByRangeRequestItems,
 };
 #[cfg(test)]
 use slot_clock::SlotClock;
@@ -41,10 +42,11 @@ use tracing::{Span, debug, debug_span, error, warn};
 use types::blob_sidecar::FixedBlobSidecarList;
 use types::{
     BlobSidecar, BlockImportSource, ColumnIndex, DataColumnSidecar, DataColumnSidecarList, EthSpec,
-    ForkContext, Hash256, SignedBeaconBlock, SignedExecutionPayloadEnvelope, Slot,
+    ExecutionProof, ForkContext, Hash256, SignedBeaconBlock, SignedExecutionPayloadEnvelope, Slot,
 };
 use vibehouse_network::rpc::methods::{
-    BlobsByRangeRequest, DataColumnsByRangeRequest, ExecutionPayloadEnvelopesByRootRequest,
+    BlobsByRangeRequest, DataColumnsByRangeRequest, ExecutionPayloadEnvelopesByRangeRequest,
+    ExecutionPayloadEnvelopesByRootRequest, ExecutionProofsByRootRequest,
 };
 use vibehouse_network::rpc::{BlocksByRangeRequest, GoodbyeReason, RPCError, RequestType};
 pub(crate) use vibehouse_network::service::api_types::RangeRequestId;
@@ -52,7 +54,7 @@ use vibehouse_network::service::api_types::{
     AppRequestId, BlobsByRangeRequestId, BlocksByRangeRequestId, ComponentsByRangeRequestId,
     CustodyBackFillBatchRequestId, CustodyBackfillBatchId, CustodyId, CustodyRequester,
     DataColumnsByRangeRequestId, DataColumnsByRangeRequester, DataColumnsByRootRequestId,
-    EnvelopesByRootRequestId, Id, SingleLookupReqId, SyncRequestId,
+    EnvelopesByRangeRequestId, Id, SingleLookupReqId, SyncRequestId,
 };
 use vibehouse_network::{Client, NetworkGlobals, PeerAction, PeerId, ReportSource};
 use vibehouse_tracing::{SPAN_OUTGOING_BLOCK_BY_ROOT_REQUEST, SPAN_OUTGOING_RANGE_REQUEST};
@@ -191,23 +193,6 @@ pub(crate) enum LookupRequestResult<I = ReqId> {
 }
 
 /// Wraps a Network channel to employ various RPC related network functionality for the Sync manager. This includes management of a global RPC request Id.
-/// Result of a completed envelope batch: (blocks, parent_request_id, peer_group).
-type EnvelopeBatchResult<E> = (Vec<RpcBlock<E>>, ComponentsByRangeRequestId, PeerGroup);
-
-/// Tracks a batch of coupled RPC blocks awaiting envelope downloads for Gloas blocks.
-struct PendingEnvelopeBatch<E: EthSpec> {
-    /// The coupled blocks ready for processing once envelopes arrive.
-    blocks: Vec<RpcBlock<E>>,
-    /// Block roots of Gloas blocks that need envelopes.
-    expected_roots: HashSet<Hash256>,
-    /// Envelopes received so far, keyed by block root.
-    received_envelopes: HashMap<Hash256, Arc<SignedExecutionPayloadEnvelope<E>>>,
-    /// The original components-by-range request ID (for routing the result).
-    parent_request_id: ComponentsByRangeRequestId,
-    /// The peers that contributed to this batch.
-    peer_group: PeerGroup,
-}
-
 pub(crate) struct SyncNetworkContext<T: BeaconChainTypes> {
     /// The network channel to relay messages to the Network service.
     network_send: mpsc::UnboundedSender<NetworkMessage<T::EthSpec>>,
@@ -232,6 +217,9 @@ pub(crate) struct SyncNetworkContext<T: BeaconChainTypes> {
     /// A mapping of active DataColumnsByRange requests
     data_columns_by_range_requests:
         ActiveRequests<DataColumnsByRangeRequestId, DataColumnsByRangeRequestItems<T::EthSpec>>,
+    /// A mapping of active ExecutionPayloadEnvelopesByRange requests
+    envelopes_by_range_requests:
+        ActiveRequests<EnvelopesByRangeRequestId, EnvelopesByRangeRequestItems<T::EthSpec>>,
     /// Mapping of active custody column requests for a block root
     custody_by_root_requests: FnvHashMap<CustodyRequester, ActiveCustodyRequest<T>>,
 
@@ -239,10 +227,6 @@ pub(crate) struct SyncNetworkContext<T: BeaconChainTypes> {
     components_by_range_requests:
         FnvHashMap<ComponentsByRangeRequestId, RangeBlockComponentsRequest<T::EthSpec>>,
 
-    /// Batches waiting for envelope downloads before being sent to the processor.
-    /// Keyed by the envelope request ID, maps to the coupled blocks and metadata.
-    pending_envelope_batches: FnvHashMap<Id, PendingEnvelopeBatch<T::EthSpec>>,
-
     /// A batch of data columns by range request for custody sync
     custody_backfill_data_column_batch_requests:
         FnvHashMap<CustodyBackFillBatchRequestId, RangeDataColumnBatchRequest<T>>,
@@ -273,6 +257,10 @@ pub(crate) enum RangeBlockComponent<E: EthSpec> {
         DataColumnsByRangeRequestId,
         RpcResponseResult<Vec<Arc<DataColumnSidecar<E>>>>,
     ),
+    Envelope(
+        EnvelopesByRangeRequestId,
+        RpcResponseResult<Vec<Arc<SignedExecutionPayloadEnvelope<E>>>>,
+    ),
 }
 
 #[cfg(test)]
@@ -321,9 +309,9 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
             blocks_by_range_requests: ActiveRequests::new("blocks_by_range"),
             blobs_by_range_requests: ActiveRequests::new("blobs_by_range"),
             data_columns_by_range_requests: ActiveRequests::new("data_columns_by_range"),
+            envelopes_by_range_requests: ActiveRequests::new("envelopes_by_range"),
             custody_by_root_requests: <_>::default(),
             components_by_range_requests: FnvHashMap::default(),
-            pending_envelope_batches: FnvHashMap::default(),
             custody_backfill_data_column_batch_requests: FnvHashMap::default(),
             network_beacon_processor,
             chain,
@@ -350,11 +338,11 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
             blocks_by_range_requests,
             blobs_by_range_requests,
             data_columns_by_range_requests,
+            envelopes_by_range_requests,
             // custody_by_root_requests is a meta request of data_columns_by_root_requests
             custody_by_root_requests: _,
             // components_by_range_requests is a meta request of various _by_range requests
             components_by_range_requests: _,
-            pending_envelope_batches: _,
             custody_backfill_data_column_batch_requests: _,
             execution_engine_state: _,
             network_beacon_processor: _,
@@ -386,12 +374,17 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
             .active_requests_of_peer(peer_id)
             .into_iter()
             .map(|req_id| SyncRequestId::DataColumnsByRange(*req_id));
+        let envelopes_by_range_ids = envelopes_by_range_requests
+            .active_requests_of_peer(peer_id)
+            .into_iter()
+            .map(|req_id| SyncRequestId::EnvelopesByRange(*req_id));
         blocks_by_root_ids
             .chain(blobs_by_root_ids)
             .chain(data_column_by_root_ids)
             .chain(blocks_by_range_ids)
             .chain(blobs_by_range_ids)
             .chain(data_column_by_range_ids)
+            .chain(envelopes_by_range_ids)
             .collect()
     }
 
@@ -452,11 +445,11 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
             blocks_by_range_requests,
             blobs_by_range_requests,
             data_columns_by_range_requests,
+            envelopes_by_range_requests,
             // custody_by_root_requests is a meta request of data_columns_by_root_requests
             custody_by_root_requests: _,
             // components_by_range_requests is a meta request of various _by_range requests
             components_by_range_requests: _,
-            pending_envelope_batches: _,
             custody_backfill_data_column_batch_requests: _,
             execution_engine_state: _,
             network_beacon_processor: _,
@@ -475,6 +468,7 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
             .chain(blocks_by_range_requests.iter_request_peers())
             .chain(blobs_by_range_requests.iter_request_peers())
             .chain(data_columns_by_range_requests.iter_request_peers())
+            .chain(envelopes_by_range_requests.iter_request_peers())
         {
             *active_request_count_by_peer.entry(peer_id).or_default() += 1;
         }
@@ -812,8 +806,35 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
             })
             .transpose()?;
 
+        // Gloas blocks carry only a bid; their payloads arrive in separate envelopes. Fetch them
+        // from the block peer so each block can be imported together with its payload.
+        let last_slot = Slot::new(request.start_slot() + request.count().saturating_sub(1));
+        let envelopes_req_id = if self
+            .chain
+            .spec
+            .fork_name_at_slot::<T::EthSpec>(last_slot)
+            .gloas_enabled()
+        {
+            Some(self.send_envelopes_by_range_request(
+                block_peer,
+                ExecutionPayloadEnvelopesByRangeRequest {
+                    start_slot: *request.start_slot(),
+                    count: *request.count(),
+                },
+                id,
+                new_range_request_span!(
+                    self,
+                    "outgoing_envelopes_by_range",
+                    range_request_span.clone(),
+                    block_peer
+                ),
+            )?)
+        } else {
+            None
+        };
+
         let epoch = Slot::new(*request.start_slot()).epoch(T::EthSpec::slots_per_epoch());
-        let info = RangeBlockComponentsRequest::new(
+        let mut info = RangeBlockComponentsRequest::new(
             blocks_req_id,
             blobs_req_id,
             data_column_requests.map(|data_column_requests| {
@@ -823,8 +844,20 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
                 )
             }),
             deferred_columns,
+            envelopes_req_id,
             range_request_span,
         );
+        if envelopes_req_id.is_some() {
+            info.set_envelopes_fallback(
+                *request.start_slot(),
+                *request.count(),
+                block_peers
+                    .iter()
+                    .filter(|peer| **peer != block_peer)
+                    .copied()
+                    .collect(),
+            );
+        }
         self.components_by_range_requests.insert(id, info);
 
         Ok(id.id)
@@ -893,6 +926,19 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
         peer_id: PeerId,
         range_block_component: RangeBlockComponent<T::EthSpec>,
     ) -> Option<Result<(Vec<RpcBlock<T::EthSpec>>, PeerGroup), RpcResponseError>> {
+        // A peer without the envelopes protocol can still serve the blocks, so only re-route the
+        // envelopes request instead of failing the batch. If no other peer can serve them the
+        // batch fails below, as importing the blocks without their envelopes would leave their
+        // payloads missing.
+        if let RangeBlockComponent::Envelope(
+            req_id,
+            Err(RpcResponseError::Rpc(RPCError::UnsupportedProtocol)),
+        ) = &range_block_component
+            && self.reroute_envelopes_by_range_request(id, *req_id, peer_id)
+        {
+            return None;
+        }
+
         let Entry::Occupied(mut entry) = self.components_by_range_requests.entry(id) else {
             metrics::inc_counter_vec(&metrics::SYNC_UNKNOWN_NETWORK_REQUESTS, &["range_blocks"]);
             return None;
@@ -902,13 +948,13 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
             let request = entry.get_mut();
             match range_block_component {
                 RangeBlockComponent::Block(req_id, resp) => resp.and_then(|(blocks, _)| {
-                    request.record_peer(peer_id, vec![0]);
+                    request.record_peer(peer_id, BatchComponent::Blocks);
                     request.add_blocks(req_id, blocks).map_err(|e| {
                         RpcResponseError::BlockComponentCoupling(CouplingError::InternalError(e))
                     })
                 }),
                 RangeBlockComponent::Blob(req_id, resp) => resp.and_then(|(blobs, _)| {
-                    request.record_peer(peer_id, vec![1]);
+                    request.record_peer(peer_id, BatchComponent::Blobs);
                     request.add_blobs(req_id, blobs).map_err(|e| {
                         RpcResponseError::BlockComponentCoupling(CouplingError::InternalError(e))
                     })
@@ -917,7 +963,7 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
                     resp.and_then(|(custody_columns, _)| {
                         let col_indices: Vec<usize> =
                             custody_columns.iter().map(|c| c.index() as usize).collect();
-                        request.record_peer(peer_id, col_indices);
+                        request.record_peer(peer_id, BatchComponent::Columns(col_indices));
                         request
                             .add_custody_columns(req_id, custody_columns)
                             .map_err(|e| {
@@ -927,6 +973,12 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
                             })
                     })
                 }
+                RangeBlockComponent::Envelope(req_id, resp) => resp.and_then(|(envelopes, _)| {
+                    request.record_peer(peer_id, BatchComponent::Envelopes);
+                    request.add_envelopes(req_id, envelopes).map_err(|e| {
+                        RpcResponseError::BlockComponentCoupling(CouplingError::InternalError(e))
+                    })
+                }),
             }
         } {
             entry.remove();
@@ -962,138 +1014,54 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
         }
     }
 
-    /// After blocks are coupled, check if any are Gloas blocks that need envelopes.
-    /// If so, fire off an `ExecutionPayloadEnvelopesByRoot` request and stash the blocks.
-    /// Returns `Some(blocks)` if no envelopes are needed (pass-through), `None` if waiting.
-    pub(crate) fn request_envelopes_if_needed(
+    /// Re-sends a failed envelopes by range request to the next connected fallback peer.
+    ///
+    /// Returns `false` if there is no such peer or the request could not be sent.
+    fn reroute_envelopes_by_range_request(
         &mut self,
-        blocks: Vec<RpcBlock<T::EthSpec>>,
-        parent_request_id: ComponentsByRangeRequestId,
-        peer_group: PeerGroup,
-    ) -> Option<Vec<RpcBlock<T::EthSpec>>> {
-        let gloas_roots: Vec<Hash256> = blocks
-            .iter()
-            .filter(|b| {
-                self.chain
-                    .spec
-                    .fork_name_at_slot::<T::EthSpec>(b.slot())
-                    .gloas_enabled()
-            })
-            .map(RpcBlock::block_root)
-            .collect();
-
-        if gloas_roots.is_empty() {
-            return Some(blocks);
-        }
-
-        let request = match ExecutionPayloadEnvelopesByRootRequest::new(
-            gloas_roots.clone(),
-            &self.chain.spec,
-        ) {
-            Ok(req) => req,
-            Err(e) => {
-                warn!(error = ?e, "Failed to create envelope request");
-                return Some(blocks);
-            }
-        };
-
-        let id = self.next_id();
-        let envelope_id = EnvelopesByRootRequestId {
-            id,
-            parent_request_id,
+        id: ComponentsByRangeRequestId,
+        failed_req_id: EnvelopesByRangeRequestId,
+        failed_peer: PeerId,
+    ) -> bool {
+        let network_globals = &self.network_beacon_processor.network_globals;
+        let Some(request) = self.components_by_range_requests.get_mut(&id) else {
+            return false;
         };
-
-        // Pick a peer from the group to request envelopes from (prefer the block peer)
-        let Some(envelope_peer) = peer_group.all().next().copied() else {
-            warn!("No peers in peer group to request envelopes from");
-            return Some(blocks);
+        let Some((peer_id, start_slot, count)) =
+            std::iter::from_fn(|| request.next_envelopes_peer())
+                .find(|(peer_id, _, _)| network_globals.peers.read().is_connected(peer_id))
+        else {
+            return false;
         };
-
-        if let Err(e) = self.network_send.send(NetworkMessage::SendRequest {
-            peer_id: envelope_peer,
-            request: RequestType::ExecutionPayloadEnvelopesByRoot(request),
-            app_request_id: AppRequestId::Sync(SyncRequestId::EnvelopesByRoot(envelope_id)),
-        }) {
-            warn!(error = ?e, "Failed to send envelope request");
-            return Some(blocks);
-        }
+        let request_span = request.request_span.clone();
 
         debug!(
-            count = gloas_roots.len(),
-            %id,
-            peer = %envelope_peer,
-            "Requesting envelopes for range sync batch"
+            %failed_peer,
+            %peer_id,
+            %failed_req_id,
+            "Peer does not support envelopes by range, re-routing request"
         );
-
-        let expected_roots: HashSet<Hash256> = gloas_roots.into_iter().collect();
-        self.pending_envelope_batches.insert(
+        let req_id = match self.send_envelopes_by_range_request(
+            peer_id,
+            ExecutionPayloadEnvelopesByRangeRequest { start_slot, count },
             id,
-            PendingEnvelopeBatch {
-                blocks,
-                expected_roots,
-                received_envelopes: HashMap::new(),
-                parent_request_id,
-                peer_group,
-            },
-        );
-
-        None
-    }
-
-    /// Handle an incoming envelope response for a range sync batch.
-    /// Returns `Some((blocks, parent_request_id, peer))` when all envelopes are received.
-    pub(crate) fn on_envelope_by_root_response(
-        &mut self,
-        id: EnvelopesByRootRequestId,
-        _peer_id: PeerId,
-        envelope: Option<Arc<SignedExecutionPayloadEnvelope<T::EthSpec>>>,
-    ) -> Option<EnvelopeBatchResult<T::EthSpec>> {
-        let Some(batch) = self.pending_envelope_batches.get_mut(&id.id) else {
-            warn!(%id.id, "Envelope response for unknown batch");
-            return None;
-        };
-
-        if let Some(env) = envelope {
-            let block_root = env.message.beacon_block_root;
-            if batch.expected_roots.contains(&block_root) {
-                batch.received_envelopes.insert(block_root, env);
-            } else {
-                warn!(
-                    ?block_root,
-                    "Received envelope for unexpected block root in batch"
-                );
-            }
-            None
-        } else {
-            // Stream terminated — attach envelopes to blocks and return
-            let mut batch = self.pending_envelope_batches.remove(&id.id)?;
-
-            for block in &mut batch.blocks {
-                if let Some(env) = batch.received_envelopes.remove(&block.block_root()) {
-                    block.set_envelope(env);
-                }
-            }
-
-            let missing: usize = batch
-                .expected_roots
-                .iter()
-                .filter(|r| {
-                    !batch
-                        .blocks
-                        .iter()
-                        .any(|b| b.block_root() == **r && b.envelope().is_some())
-                })
-                .count();
-
-            if missing > 0 {
-                warn!(
-                    count = missing,
-                    "Missing envelopes in range sync batch — blocks may fail state root check"
-                );
+            new_range_request_span!(self, "outgoing_envelopes_by_range", request_span, peer_id),
+        ) {
+            Ok(req_id) => req_id,
+            Err(e) => {
+                debug!(error = ?e, "Failed to re-route envelopes by range request");
+                return false;
             }
+        };
 
-            Some((batch.blocks, batch.parent_request_id, batch.peer_group))
+        let Some(request) = self.components_by_range_requests.get_mut(&id) else {
+            return false;
+        };
+        if let Err(e) = request.reroute_envelopes(failed_req_id, req_id) {
+            debug!(error = e, "Failed to re-route envelopes by range request");
+            return false;
         }
+        true
     }
 
     /// Request a single envelope for a known block. Triggered when an index-1
@@ -1136,6 +1104,44 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
             .map_err(|e| format!("Failed to send to beacon processor: {e:?}"))
     }
 
+    /// Request all execution proofs for an imported block. Triggered on stateless nodes when a
+    /// block was imported without enough proofs having been seen on gossip.
+    pub(crate) fn request_execution_proofs(&mut self, peer_id: PeerId, block_root: Hash256) {
+        let request = match ExecutionProofsByRootRequest::new(vec![block_root], &self.chain.spec) {
+            Ok(req) => req,
+            Err(e) => {
+                warn!(error = ?e, ?block_root, "Failed to create execution proofs request");
+                return;
+            }
+        };
+
+        let id = self.next_id();
+        let sync_id = SyncRequestId::SingleExecutionProofs { id, block_root };
+
+        if let Err(e) = self.network_send.send(NetworkMessage::SendRequest {
+            peer_id,
+            request: RequestType::ExecutionProofsByRoot(request),
+            app_request_id: AppRequestId::Sync(sync_id),
+        }) {
+            warn!(error = ?e, ?block_root, "Failed to send execution proofs request");
+        }
+    }
+
+    /// Send an RPC-received execution proof to the beacon processor for verification and import.
+    pub(crate) fn send_rpc_execution_proof(
+        &self,
+        peer_id: PeerId,
+        proof: Arc<ExecutionProof>,
+    ) -> Result<(), String> {
+        let beacon_processor = self
+            .beacon_processor_if_enabled()
+            .ok_or("beacon processor not available")?;
+
+        beacon_processor
+            .send_rpc_execution_proof(peer_id, proof)
+            .map_err(|e| format!("Failed to send to beacon processor: {e:?}"))
+    }
+
     /// Request block of `block_root` if necessary by checking:
     /// - If the da_checker has a pending block from gossip or a previous request
     ///
@@ -1572,6 +1578,47 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
         Ok(id)
     }
 
+    fn send_envelopes_by_range_request(
+        &mut self,
+        peer_id: PeerId,
+        request: ExecutionPayloadEnvelopesByRangeRequest,
+        parent_request_id: ComponentsByRangeRequestId,
+        request_span: Span,
+    ) -> Result<EnvelopesByRangeRequestId, RpcRequestSendError> {
+        let id = EnvelopesByRangeRequestId {
+            id: self.next_id(),
+            parent_request_id,
+        };
+
+        self.network_send
+            .send(NetworkMessage::SendRequest {
+                peer_id,
+                request: RequestType::ExecutionPayloadEnvelopesByRange(request.clone()),
+                app_request_id: AppRequestId::Sync(SyncRequestId::EnvelopesByRange(id)),
+            })
+            .map_err(|_| RpcRequestSendError::InternalError("network send error".to_owned()))?;
+
+        debug!(
+            method = "ExecutionPayloadEnvelopesByRange",
+            slots = request.count,
+            epoch = %Slot::new(request.start_slot).epoch(T::EthSpec::slots_per_epoch()),
+            peer = %peer_id,
+            %id,
+            "Sync RPC request sent"
+        );
+
+        self.envelopes_by_range_requests.insert(
+            id,
+            peer_id,
+            // false = do not enforce max_requests are returned for *_by_range methods. We don't
+            // know if there are missed blocks or withheld payloads.
+            false,
+            EnvelopesByRangeRequestItems::new(request),
+            request_span,
+        );
+        Ok(id)
+    }
+
     fn send_data_columns_by_range_request(
         &mut self,
         peer_id: PeerId,
@@ -1838,6 +1885,17 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
         self.on_rpc_response_result(resp, peer_id)
     }
 
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn on_envelopes_by_range_response(
+        &mut self,
+        id: EnvelopesByRangeRequestId,
+        peer_id: PeerId,
+        rpc_event: RpcEvent<Arc<SignedExecutionPayloadEnvelope<T::EthSpec>>>,
+    ) -> Option<RpcResponseResult<Vec<Arc<SignedExecutionPayloadEnvelope<T::EthSpec>>>>> {
+        let resp = self.envelopes_by_range_requests.on_response(id, rpc_event);
+        self.on_rpc_response_result(resp, peer_id)
+    }
+
     /// Common handler for consistent scoring of RpcResponseError
     fn on_rpc_response_result<R>(
         &self,
@@ -2110,6 +2168,7 @@ impl<T: BeaconChainTypes> SyncNetworkContext<T> {
                 "data_columns_by_range",
                 self.data_columns_by_range_requests.len(),
             ),
+            ("envelopes_by_range", self.envelopes_by_range_requests.len()),
             ("custody_by_root", self.custody_by_root_requests.len()),
             (
                 "components_by_range",
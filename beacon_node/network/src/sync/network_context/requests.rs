@@ -16,6 +16,7 @@ pub(crate) use data_columns_by_range::DataColumnsByRangeRequestItems;
 pub(crate) use data_columns_by_root::{
     DataColumnsByRootRequestItems, DataColumnsByRootSingleBlockRequest,
 };
+pub(crate) use envelopes_by_range::EnvelopesByRangeRequestItems;
 
 use crate::metrics;
 
@@ -27,6 +28,7 @@ mod blocks_by_range;
 mod blocks_by_root;
 mod data_columns_by_range;
 mod data_columns_by_root;
+mod envelopes_by_range;
 
 #[derive(Debug, PartialEq, Eq, IntoStaticStr)]
 pub(crate) enum LookupVerifyError {
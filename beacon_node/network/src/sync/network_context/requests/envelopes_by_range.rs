@@ -0,0 +1,101 @@
+use super::{ActiveRequestItems, LookupVerifyError};
+use std::sync::Arc;
+use types::{EthSpec, SignedExecutionPayloadEnvelope};
+use vibehouse_network::rpc::methods::ExecutionPayloadEnvelopesByRangeRequest;
+
+/// Accumulates results of an execution_payload_envelopes_by_range request. Only returns items
+/// after receiving the stream termination.
+pub(crate) struct EnvelopesByRangeRequestItems<E: EthSpec> {
+    request: ExecutionPayloadEnvelopesByRangeRequest,
+    items: Vec<Arc<SignedExecutionPayloadEnvelope<E>>>,
+}
+
+impl<E: EthSpec> EnvelopesByRangeRequestItems<E> {
+    pub(crate) fn new(request: ExecutionPayloadEnvelopesByRangeRequest) -> Self {
+        Self {
+            request,
+            items: vec![],
+        }
+    }
+}
+
+impl<E: EthSpec> ActiveRequestItems for EnvelopesByRangeRequestItems<E> {
+    type Item = Arc<SignedExecutionPayloadEnvelope<E>>;
+
+    fn add(&mut self, envelope: Self::Item) -> Result<bool, LookupVerifyError> {
+        let slot = envelope.message.slot;
+        if slot.as_u64() < self.request.start_slot
+            || slot.as_u64() >= self.request.start_slot + self.request.count
+        {
+            return Err(LookupVerifyError::UnrequestedSlot(slot));
+        }
+        if self
+            .items
+            .iter()
+            .any(|existing| existing.message.slot == slot)
+        {
+            // DuplicatedData is a common error for all components, default index to 0
+            return Err(LookupVerifyError::DuplicatedData(slot, 0));
+        }
+
+        self.items.push(envelope);
+
+        Ok(self.items.len() >= self.request.count as usize)
+    }
+
+    fn consume(&mut self) -> Vec<Self::Item> {
+        std::mem::take(&mut self.items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::{MinimalEthSpec, Slot};
+
+    type E = MinimalEthSpec;
+
+    fn make_envelope(slot: u64) -> Arc<SignedExecutionPayloadEnvelope<E>> {
+        let mut envelope = SignedExecutionPayloadEnvelope::empty();
+        envelope.message.slot = Slot::new(slot);
+        Arc::new(envelope)
+    }
+
+    fn make_request(start_slot: u64, count: u64) -> EnvelopesByRangeRequestItems<E> {
+        EnvelopesByRangeRequestItems::new(ExecutionPayloadEnvelopesByRangeRequest {
+            start_slot,
+            count,
+        })
+    }
+
+    #[test]
+    fn add_valid_envelopes_returns_complete_when_count_reached() {
+        let mut req = make_request(10, 2);
+        assert_eq!(req.add(make_envelope(10)), Ok(false));
+        assert_eq!(req.add(make_envelope(11)), Ok(true));
+    }
+
+    #[test]
+    fn reject_envelope_outside_range() {
+        let mut req = make_request(10, 3);
+        assert_eq!(
+            req.add(make_envelope(9)),
+            Err(LookupVerifyError::UnrequestedSlot(Slot::new(9)))
+        );
+        // Range is [10, 13), so slot 13 is out of range
+        assert_eq!(
+            req.add(make_envelope(13)),
+            Err(LookupVerifyError::UnrequestedSlot(Slot::new(13)))
+        );
+    }
+
+    #[test]
+    fn reject_duplicate_slot() {
+        let mut req = make_request(10, 3);
+        assert_eq!(req.add(make_envelope(10)), Ok(false));
+        assert_eq!(
+            req.add(make_envelope(10)),
+            Err(LookupVerifyError::DuplicatedData(Slot::new(10), 0))
+        );
+    }
+}
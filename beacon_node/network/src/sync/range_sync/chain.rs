@@ -948,6 +948,9 @@ impl<T: BeaconChainTypes> SyncingChain<T> {
                     CouplingError::BlobPeerFailure(msg) => {
                         tracing::debug!(?batch_id, msg, "Blob peer failure");
                     }
+                    CouplingError::EnvelopePeerFailure(msg) => {
+                        tracing::debug!(?batch_id, msg, "Envelope peer failure");
+                    }
                     CouplingError::InternalError(msg) => {
                         tracing::error!(?batch_id, msg, "Block components coupling internal error");
                     }
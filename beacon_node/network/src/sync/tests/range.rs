@@ -14,14 +14,14 @@ use types::{
     BlobSidecarList, BlockImportSource, Epoch, EthSpec, Hash256, MinimalEthSpec as E,
     SignedBeaconBlock, SignedBeaconBlockHash, Slot,
 };
-use vibehouse_network::rpc::RequestType;
 use vibehouse_network::rpc::methods::{
-    BlobsByRangeRequest, DataColumnsByRangeRequest, OldBlocksByRangeRequest,
-    OldBlocksByRangeRequestV2, StatusMessageV2,
+    BlobsByRangeRequest, DataColumnsByRangeRequest, ExecutionPayloadEnvelopesByRangeRequest,
+    OldBlocksByRangeRequest, OldBlocksByRangeRequestV2, StatusMessageV2,
 };
+use vibehouse_network::rpc::{RPCError, RequestType};
 use vibehouse_network::service::api_types::{
     AppRequestId, BlobsByRangeRequestId, BlocksByRangeRequestId, DataColumnsByRangeRequestId,
-    SyncRequestId,
+    EnvelopesByRangeRequestId, SyncRequestId,
 };
 use vibehouse_network::{PeerId, SyncInfo};
 
@@ -36,6 +36,10 @@ enum ByRangeDataRequestIds {
     PreDeneb,
     PrePeerDAS(BlobsByRangeRequestId, PeerId),
     PostPeerDAS(Vec<(DataColumnsByRangeRequestId, PeerId)>),
+    PostGloas(
+        Vec<(DataColumnsByRangeRequestId, PeerId)>,
+        (EnvelopesByRangeRequestId, PeerId),
+    ),
 }
 
 /// Sync tests are usually written in the form:
@@ -228,7 +232,7 @@ impl TestRig {
                 panic!("Should have a BlocksByRange request, filter {request_filter:?}: {e:?}")
             });
 
-        let by_range_data_requests = if self.after_fulu() {
+        let by_range_data_requests = if self.fork_name.fulu_enabled() {
             let mut data_columns_requests = vec![];
             while let Ok(data_columns_request) = self.pop_received_network_event(|ev| match ev {
                 NetworkMessage::SendRequest {
@@ -243,11 +247,35 @@ impl TestRig {
             }) {
                 data_columns_requests.push(data_columns_request);
             }
-            assert!(
-                !data_columns_requests.is_empty(),
-                "Found zero DataColumnsByRange requests, filter {request_filter:?}"
-            );
-            ByRangeDataRequestIds::PostPeerDAS(data_columns_requests)
+            // Batches of Gloas epochs request envelopes, and only columns if the batch still
+            // covers PeerDAS epochs.
+            if self.fork_name.gloas_enabled() {
+                let envelopes_request = self
+                    .pop_received_network_event(|ev| match ev {
+                        NetworkMessage::SendRequest {
+                            peer_id,
+                            request:
+                                RequestType::ExecutionPayloadEnvelopesByRange(
+                                    ExecutionPayloadEnvelopesByRangeRequest { start_slot, .. },
+                                ),
+                            app_request_id:
+                                AppRequestId::Sync(SyncRequestId::EnvelopesByRange(id)),
+                        } if filter_f(*peer_id, *start_slot) => Some((*id, *peer_id)),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|e| {
+                        panic!(
+                            "Should have an envelopes by range request, filter {request_filter:?}: {e:?}"
+                        )
+                    });
+                ByRangeDataRequestIds::PostGloas(data_columns_requests, envelopes_request)
+            } else {
+                assert!(
+                    !data_columns_requests.is_empty(),
+                    "Found zero DataColumnsByRange requests, filter {request_filter:?}"
+                );
+                ByRangeDataRequestIds::PostPeerDAS(data_columns_requests)
+            }
         } else if self.after_deneb() {
             let (id, peer) = self
                 .pop_received_network_event(|ev| match ev {
@@ -302,24 +330,44 @@ impl TestRig {
                 });
             }
             ByRangeDataRequestIds::PostPeerDAS(data_column_req_ids) => {
+                self.complete_data_columns_by_range_requests(data_column_req_ids);
+            }
+            ByRangeDataRequestIds::PostGloas(data_column_req_ids, (id, peer_id)) => {
+                self.complete_data_columns_by_range_requests(data_column_req_ids);
                 // Complete the request with a single stream termination
-                for (id, peer_id) in data_column_req_ids {
-                    self.log(&format!(
-                        "Completing DataColumnsByRange request {id:?} with empty stream"
-                    ));
-                    self.send_sync_message(SyncMessage::RpcDataColumn {
-                        sync_request_id: SyncRequestId::DataColumnsByRange(id),
-                        peer_id,
-                        data_column: None,
-                        seen_timestamp: D,
-                    });
-                }
+                self.log(&format!(
+                    "Completing EnvelopesByRange request {id:?} with empty stream"
+                ));
+                self.send_sync_message(SyncMessage::RpcEnvelope {
+                    sync_request_id: SyncRequestId::EnvelopesByRange(id),
+                    peer_id,
+                    envelope: None,
+                    seen_timestamp: D,
+                });
             }
         }
 
         blocks_req_id.parent_request_id.requester
     }
 
+    fn complete_data_columns_by_range_requests(
+        &mut self,
+        data_column_req_ids: Vec<(DataColumnsByRangeRequestId, PeerId)>,
+    ) {
+        // Complete the request with a single stream termination
+        for (id, peer_id) in data_column_req_ids {
+            self.log(&format!(
+                "Completing DataColumnsByRange request {id:?} with empty stream"
+            ));
+            self.send_sync_message(SyncMessage::RpcDataColumn {
+                sync_request_id: SyncRequestId::DataColumnsByRange(id),
+                peer_id,
+                data_column: None,
+                seen_timestamp: D,
+            });
+        }
+    }
+
     fn find_and_complete_processing_chain_segment(&mut self, id: ChainSegmentProcessId) {
         self.pop_received_processor_event(|ev| {
             (ev.work_type() == WorkType::ChainSegment).then_some(())
@@ -616,3 +664,104 @@ fn finalized_sync_not_enough_custody_peers_on_start() {
     let last_epoch = advanced_epochs + EXTRA_SYNCED_EPOCHS;
     r.complete_and_process_range_sync_until(last_epoch, filter());
 }
+
+#[test]
+fn envelopes_request_rerouted_when_peer_lacks_protocol() {
+    let mut r = TestRig::test_setup();
+    // Only Gloas batches request envelopes
+    if !r.fork_name.gloas_enabled() {
+        return;
+    }
+
+    let remote_info = r.finalized_remote_info_advanced_by(Epoch::new(2));
+    r.add_supernode_peer(remote_info.clone());
+    r.add_supernode_peer(remote_info);
+    r.assert_state(RangeSyncType::Finalized);
+
+    let (_, by_range_data_request_ids) = r.find_blocks_by_range_request(filter());
+    let ByRangeDataRequestIds::PostGloas(_, (envelopes_req_id, envelopes_peer)) =
+        by_range_data_request_ids
+    else {
+        panic!("Should have an envelopes by range request");
+    };
+
+    // The peer serves blocks but not envelopes
+    r.send_sync_message(SyncMessage::RpcError {
+        peer_id: envelopes_peer,
+        sync_request_id: SyncRequestId::EnvelopesByRange(envelopes_req_id),
+        error: RPCError::UnsupportedProtocol,
+    });
+
+    // The envelopes are requested from the other chain peer instead of failing the batch
+    let rerouted_peer = r
+        .pop_received_network_event(|ev| match ev {
+            NetworkMessage::SendRequest {
+                peer_id,
+                request: RequestType::ExecutionPayloadEnvelopesByRange(_),
+                app_request_id: AppRequestId::Sync(SyncRequestId::EnvelopesByRange(id)),
+            } if id.parent_request_id == envelopes_req_id.parent_request_id => Some(*peer_id),
+            _ => None,
+        })
+        .unwrap_or_else(|e| panic!("Should re-route the envelopes by range request: {e:?}"));
+    assert_ne!(rerouted_peer, envelopes_peer);
+    r.assert_no_failed_chains();
+}
+
+#[test]
+fn batch_fails_when_no_peer_serves_envelopes() {
+    let mut r = TestRig::test_setup();
+    // Only Gloas batches request envelopes
+    if !r.fork_name.gloas_enabled() {
+        return;
+    }
+
+    let remote_info = r.finalized_remote_info_advanced_by(Epoch::new(2));
+    r.add_supernode_peer(remote_info.clone());
+    r.add_supernode_peer(remote_info);
+    r.assert_state(RangeSyncType::Finalized);
+
+    let ((blocks_req_id, block_peer), by_range_data_request_ids) =
+        r.find_blocks_by_range_request(filter().epoch(0));
+    let ByRangeDataRequestIds::PostGloas(data_column_req_ids, (envelopes_req_id, envelopes_peer)) =
+        by_range_data_request_ids
+    else {
+        panic!("Should have an envelopes by range request");
+    };
+
+    // The blocks and columns are served
+    r.send_sync_message(SyncMessage::RpcBlock {
+        sync_request_id: SyncRequestId::BlocksByRange(blocks_req_id),
+        peer_id: block_peer,
+        beacon_block: None,
+        seen_timestamp: D,
+    });
+    r.complete_data_columns_by_range_requests(data_column_req_ids);
+
+    // Neither chain peer serves envelopes
+    r.send_sync_message(SyncMessage::RpcError {
+        peer_id: envelopes_peer,
+        sync_request_id: SyncRequestId::EnvelopesByRange(envelopes_req_id),
+        error: RPCError::UnsupportedProtocol,
+    });
+    let (rerouted_req_id, rerouted_peer) = r
+        .pop_received_network_event(|ev| match ev {
+            NetworkMessage::SendRequest {
+                peer_id,
+                request: RequestType::ExecutionPayloadEnvelopesByRange(_),
+                app_request_id: AppRequestId::Sync(SyncRequestId::EnvelopesByRange(id)),
+            } if id.parent_request_id == envelopes_req_id.parent_request_id => {
+                Some((*id, *peer_id))
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|e| panic!("Should re-route the envelopes by range request: {e:?}"));
+    r.send_sync_message(SyncMessage::RpcError {
+        peer_id: rerouted_peer,
+        sync_request_id: SyncRequestId::EnvelopesByRange(rerouted_req_id),
+        error: RPCError::UnsupportedProtocol,
+    });
+
+    // The blocks are not imported without their envelopes, the batch is downloaded again
+    r.expect_empty_processor();
+    r.find_blocks_by_range_request(filter().epoch(0));
+}
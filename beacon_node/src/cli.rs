@@ -42,6 +42,17 @@ pub fn cli_app() -> Command {
                 .action(ArgAction::Set)
                 .display_order(0)
         )
+        .arg(
+            Arg::new("beacon-node-backend")
+                .long("beacon-node-backend")
+                .value_name("DATABASE")
+                .help("Set the database backend to be used by the beacon node. An existing \
+                       database can only be opened with the backend that created it, use \
+                       `database_manager convert-backend` to switch.")
+                .action(ArgAction::Set)
+                .value_parser(store::DatabaseBackend::VARIANTS.to_vec())
+                .display_order(0)
+        )
         /*
          * Network parameters.
          */
@@ -413,6 +424,17 @@ pub fn cli_app() -> Command {
                 .help_heading(FLAG_HEADER)
                 .display_order(0)
         )
+        .arg(
+            Arg::new("era-dir")
+                .long("era-dir")
+                .value_name("DIR")
+                .help("Directory of era files used to fill the block history below the oldest \
+                       stored block. The import runs in the background after startup and \
+                       backfill sync waits for it to finish. Blocks whose blobs or data columns \
+                       are still within the data availability window are left to backfill sync.")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
         .arg(
             Arg::new("complete-blob-backfill")
                 .long("complete-blob-backfill")
@@ -798,6 +820,18 @@ pub fn cli_app() -> Command {
                 .action(ArgAction::Set)
                 .display_order(0)
         )
+        .arg(
+            Arg::new("state-cache-memory-mb")
+                .long("state-cache-memory-mb")
+                .value_name("MEGABYTES")
+                .help("Limit the estimated memory used by the hot and historic state caches and \
+                       their hdiff buffers to this many megabytes, shared between them. Memory \
+                       shared between cached states is only counted once. When set, this replaces \
+                       the limits of --state-cache-size, --historic-state-cache-size, \
+                       --hdiff-buffer-cache-size and --hot-hdiff-buffer-cache-size.")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
         /*
          * Execution Layer Integration
          */
@@ -836,6 +870,43 @@ pub fn cli_app() -> Command {
                 .action(ArgAction::Set)
                 .display_order(0)
         )
+        .arg(
+            Arg::new("execution-fallback-endpoints")
+                .long("execution-fallback-endpoints")
+                .value_name("EXECUTION-ENDPOINTS")
+                .help("Comma-separated list of execution layer endpoints to fail over to, in \
+                       order of priority. Requests are sent to the first synced endpoint of \
+                       --execution-endpoint and this list, and to the next one if it fails. \
+                       The fallback endpoints are kept following the head with \
+                       forkchoiceUpdated.")
+                .requires("execution-endpoint")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
+        .arg(
+            Arg::new("execution-fallback-jwts")
+                .long("execution-fallback-jwts")
+                .value_name("EXECUTION-JWTS")
+                .help("Comma-separated list of files containing the hex-encoded JWT secrets \
+                       for the endpoints in --execution-fallback-endpoints, in the same order. \
+                       If not provided, the fallback endpoints use the JWT secret of \
+                       --execution-endpoint.")
+                .requires("execution-fallback-endpoints")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
+        .arg(
+            Arg::new("execution-cross-check")
+                .long("execution-cross-check")
+                .help("Send every newPayload request to all of the execution endpoints and \
+                       log an error if some consider the payload valid while others consider \
+                       it invalid. A payload is imported optimistically if the endpoints are \
+                       known to disagree when the first verdict is returned.")
+                .requires("execution-fallback-endpoints")
+                .action(ArgAction::SetTrue)
+                .help_heading(FLAG_HEADER)
+                .display_order(0)
+        )
         .arg(
             Arg::new("execution-jwt-id")
                 .long("execution-jwt-id")
@@ -1046,6 +1117,39 @@ pub fn cli_app() -> Command {
                 .default_value("0")
                 .display_order(0)
         )
+        .arg(
+            Arg::new("blob-archive-dir")
+                .long("blob-archive-dir")
+                .value_name("DIR")
+                .help("Move blobs and data columns to compressed archive files in this \
+                       directory when they are pruned, instead of deleting them. Archived \
+                       sidecars remain available from the blob and data column sidecar HTTP \
+                       APIs.")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
+        .arg(
+            Arg::new("validator-history")
+                .long("validator-history")
+                .action(ArgAction::SetTrue)
+                .help_heading(FLAG_HEADER)
+                .help("If present, record the balance, participation and status of every \
+                       validator for each finalized epoch in the freezer database, for the \
+                       /vibehouse/analysis/validator_history API.")
+                .display_order(0)
+        )
+        .arg(
+            Arg::new("side-object-retention-epochs")
+                .long("side-object-retention-epochs")
+                .value_name("EPOCHS")
+                .help("Keep signed inclusion lists, aggregated payload attestations and \
+                       execution proofs in the database until they are this many epochs older \
+                       than the finalized checkpoint, and serve them from the /vibehouse/history \
+                       API. Set to 0 to discard them as soon as they are no longer needed.")
+                .action(ArgAction::Set)
+                .default_value("0")
+                .display_order(0)
+        )
         /*
          * Misc.
          */
@@ -1243,12 +1347,27 @@ pub fn cli_app() -> Command {
         .arg(
             Arg::new("checkpoint-sync-url")
                 .long("checkpoint-sync-url")
-                .help("Set the remote beacon node HTTP endpoint to use for checkpoint sync.")
-                .value_name("BEACON_NODE")
+                .help("Set the remote beacon node HTTP endpoint to use for checkpoint sync. \
+                       Several comma-separated endpoints can be given, in which case the \
+                       finalized checkpoint is only accepted once --checkpoint-sync-quorum of \
+                       them agree on it.")
+                .value_name("BEACON_NODES")
                 .action(ArgAction::Set)
                 .conflicts_with("checkpoint-state")
                 .display_order(0)
         )
+        .arg(
+            Arg::new("checkpoint-sync-quorum")
+                .long("checkpoint-sync-quorum")
+                .help("Number of the --checkpoint-sync-url endpoints which must report the same \
+                       finalized block root and state root before the checkpoint state is \
+                       downloaded from one of them. The node refuses to start if no checkpoint \
+                       reaches the quorum. Defaults to all of the endpoints.")
+                .value_name("N")
+                .action(ArgAction::Set)
+                .requires("checkpoint-sync-url")
+                .display_order(0)
+        )
         .arg(
             Arg::new("checkpoint-sync-url-timeout")
                 .long("checkpoint-sync-url-timeout")
@@ -1429,6 +1548,16 @@ pub fn cli_app() -> Command {
                 .action(ArgAction::Set)
                 .display_order(0)
         )
+        .arg(
+            Arg::new("fast-confirmation")
+                .long("fast-confirmation")
+                .help("Enable the Fast Confirmation Rule. The latest confirmed block is sent to \
+                       the execution engine as the safe block, and is exposed via the fork \
+                       choice debug endpoint and the fast_confirmation event stream.")
+                .action(ArgAction::SetTrue)
+                .help_heading(FLAG_HEADER)
+                .display_order(0)
+        )
         .arg(
             Arg::new("paranoid-block-proposal")
                 .long("paranoid-block-proposal")
@@ -1486,6 +1615,54 @@ pub fn cli_app() -> Command {
                 .help_heading(FLAG_HEADER)
                 .display_order(0)
         )
+        .arg(
+            Arg::new("epbs-min-bid-value")
+                .long("epbs-min-bid-value")
+                .value_name("GWEI")
+                .help("Gloas only. External builder bids with a value (in Gwei) below this \
+                        threshold are ignored, and the node self-builds the execution payload.")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
+        .arg(
+            Arg::new("epbs-builder-allowlist")
+                .long("epbs-builder-allowlist")
+                .value_name("BUILDERS")
+                .help("Gloas only. Comma-separated list of builder indices or 0x-prefixed builder \
+                        pubkeys. If set, only bids from these builders are considered during \
+                        block production.")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
+        .arg(
+            Arg::new("epbs-builder-denylist")
+                .long("epbs-builder-denylist")
+                .value_name("BUILDERS")
+                .help("Gloas only. Comma-separated list of builder indices or 0x-prefixed builder \
+                        pubkeys whose bids are never considered during block production. Takes \
+                        precedence over --epbs-builder-allowlist.")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
+        .arg(
+            Arg::new("epbs-require-builder-balance")
+                .long("epbs-require-builder-balance")
+                .help("Gloas only. Only select an external builder bid if the builder's balance \
+                        in the proposal state covers the bid value.")
+                .action(ArgAction::SetTrue)
+                .help_heading(FLAG_HEADER)
+                .display_order(0)
+        )
+        .arg(
+            Arg::new("epbs-self-build-boost")
+                .long("epbs-self-build-boost")
+                .value_name("PERCENT")
+                .help("Gloas only. Always build a local execution payload and only use the best \
+                        external builder bid if it is worth more than the local payload value \
+                        boosted by this percentage.")
+                .action(ArgAction::Set)
+                .display_order(0)
+        )
         .arg(
             Arg::new("builder-user-agent")
                 .long("builder-user-agent")
@@ -1,4 +1,5 @@
 use account_utils::{STDIN_INPUTS_FLAG, read_input_from_user};
+use beacon_chain::bid_selection::BuilderId;
 use beacon_chain::chain_config::{
     DEFAULT_PREPARE_PAYLOAD_LOOKAHEAD_FACTOR, DEFAULT_RE_ORG_HEAD_THRESHOLD,
     DEFAULT_RE_ORG_MAX_EPOCHS_SINCE_FINALIZATION, DEFAULT_RE_ORG_PARENT_THRESHOLD,
@@ -22,7 +23,7 @@ use std::fs;
 use std::io::IsTerminal;
 use std::net::Ipv6Addr;
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs};
-use std::num::NonZeroU16;
+use std::num::{NonZeroU16, NonZeroUsize};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Duration;
@@ -337,6 +338,28 @@ pub fn get_config<E: EthSpec>(
         el_config.disable_builder_ssz_requests = cli_args.get_flag("builder-disable-ssz");
     }
 
+    // Parse the execution endpoints to fail over to, if any.
+    if let Some(endpoints) = cli_args.get_one::<String>("execution-fallback-endpoints") {
+        el_config.fallback_execution_endpoints = endpoints
+            .split(',')
+            .map(SensitiveUrl::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|e| {
+                format!("--execution-fallback-endpoints contains an invalid value {e:?}")
+            })?;
+    }
+    if let Some(secret_files) = cli_args.get_one::<String>("execution-fallback-jwts") {
+        el_config.fallback_secret_files = secret_files.split(',').map(PathBuf::from).collect();
+        if el_config.fallback_secret_files.len() != el_config.fallback_execution_endpoints.len() {
+            return Err(format!(
+                "--execution-fallback-jwts must contain one file for each of the {} fallback \
+                 execution endpoints",
+                el_config.fallback_execution_endpoints.len()
+            ));
+        }
+    }
+    el_config.cross_check_new_payload = cli_args.get_flag("execution-cross-check");
+
     // Set config values from parse values.
     el_config.secret_file = Some(secret_file);
     el_config.execution_endpoint = Some(execution_endpoint);
@@ -373,6 +396,10 @@ pub fn get_config<E: EthSpec>(
         client_config.blobs_db_path = Some(PathBuf::from(blobs_db_dir));
     }
 
+    if let Some(backend) = clap_utils::parse_optional(cli_args, "beacon-node-backend")? {
+        client_config.store.backend = backend;
+    }
+
     if let Some(block_cache_size) = cli_args.get_one::<String>("block-cache-size") {
         client_config.store.block_cache_size = block_cache_size
             .parse()
@@ -403,6 +430,15 @@ pub fn get_config<E: EthSpec>(
         client_config.store.hot_hdiff_buffer_cache_size = hdiff_buffer_cache_size;
     }
 
+    if let Some(state_cache_memory_mb) =
+        clap_utils::parse_optional::<usize>(cli_args, "state-cache-memory-mb")?
+    {
+        client_config.store.state_cache_memory_budget = Some(
+            NonZeroUsize::new(state_cache_memory_mb.saturating_mul(1024 * 1024))
+                .ok_or("state-cache-memory-mb must be greater than 0")?,
+        );
+    }
+
     client_config.store.compact_on_init = cli_args.get_flag("compact-db");
     if let Some(compact_on_prune) = cli_args.get_one::<String>("auto-compact-db") {
         client_config.store.compact_on_prune = compact_on_prune
@@ -446,6 +482,17 @@ pub fn get_config<E: EthSpec>(
         client_config.store.blob_prune_margin_epochs = blob_prune_margin_epochs;
     }
 
+    client_config.store.blob_archive_dir =
+        clap_utils::parse_optional(cli_args, "blob-archive-dir")?;
+
+    client_config.store.validator_history = cli_args.get_flag("validator-history");
+
+    if let Some(side_object_retention_epochs) =
+        clap_utils::parse_optional(cli_args, "side-object-retention-epochs")?
+    {
+        client_config.store.side_object_retention_epochs = side_object_retention_epochs;
+    }
+
     #[cfg(feature = "testing")]
     if let Some(malicious_withhold_count) =
         clap_utils::parse_optional(cli_args, "malicious-withhold-count")?
@@ -512,7 +559,11 @@ pub fn get_config<E: EthSpec>(
         // genesis state, adopt the timeout from the checkpoint sync URL too.
         client_config.genesis_state_url_timeout =
             Duration::from_secs(client_config.chain.checkpoint_sync_url_timeout);
-        Some(checkpoint_sync_url)
+        // With several checkpoint sync URLs, download the genesis state from the first.
+        checkpoint_sync_url
+            .split(',')
+            .next()
+            .map(|url| url.trim().to_string())
     } else {
         None
     };
@@ -542,11 +593,36 @@ pub fn get_config<E: EthSpec>(
                 anchor_block_bytes,
                 anchor_blobs_bytes,
             }
-        } else if let Some(remote_bn_url) = cli_args.get_one::<String>("checkpoint-sync-url") {
-            let url = SensitiveUrl::parse(remote_bn_url)
-                .map_err(|e| format!("Invalid checkpoint sync URL: {e:?}"))?;
+        } else if let Some(remote_bn_urls) = cli_args.get_one::<String>("checkpoint-sync-url") {
+            let urls = remote_bn_urls
+                .split(',')
+                .map(|url| {
+                    SensitiveUrl::parse(url.trim())
+                        .map_err(|e| format!("Invalid checkpoint sync URL: {e:?}"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            // The same provider listed twice would count twice towards the quorum.
+            let mut providers = HashSet::new();
+            for url in &urls {
+                let host = url.full.host_str().map(str::to_ascii_lowercase);
+                if !providers.insert((host, url.full.port_or_known_default())) {
+                    return Err(format!(
+                        "Duplicate checkpoint sync URL: {url}. Each provider may only be listed \
+                         once"
+                    ));
+                }
+            }
+            let quorum = clap_utils::parse_optional(cli_args, "checkpoint-sync-quorum")?
+                .unwrap_or(urls.len());
+            if quorum == 0 || quorum > urls.len() {
+                return Err(format!(
+                    "--checkpoint-sync-quorum must be between 1 and the number of checkpoint sync \
+                     URLs ({}), got {quorum}",
+                    urls.len()
+                ));
+            }
 
-            ClientGenesis::CheckpointSyncUrl { url }
+            ClientGenesis::CheckpointSyncUrl { urls, quorum }
         } else {
             ClientGenesis::GenesisState
         }
@@ -787,6 +863,8 @@ pub fn get_config<E: EthSpec>(
 
     client_config.chain.paranoid_block_proposal = cli_args.get_flag("paranoid-block-proposal");
 
+    client_config.chain.fast_confirmation = cli_args.get_flag("fast-confirmation");
+
     /*
      * Builder fallback configs.
      */
@@ -801,6 +879,25 @@ pub fn get_config<E: EthSpec>(
     client_config.chain.builder_fallback_disable_checks =
         cli_args.get_flag("builder-fallback-disable-checks");
 
+    /*
+     * Gloas bid selection policy.
+     */
+    if let Some(min_bid_value) = clap_utils::parse_optional(cli_args, "epbs-min-bid-value")? {
+        client_config.chain.bid_selection.min_bid_value = min_bid_value;
+    }
+    if let Some(allowlist) = cli_args.get_one::<String>("epbs-builder-allowlist") {
+        client_config.chain.bid_selection.builder_allowlist = parse_builder_ids(allowlist)
+            .map_err(|e| format!("Invalid --epbs-builder-allowlist value: {e}"))?;
+    }
+    if let Some(denylist) = cli_args.get_one::<String>("epbs-builder-denylist") {
+        client_config.chain.bid_selection.builder_denylist = parse_builder_ids(denylist)
+            .map_err(|e| format!("Invalid --epbs-builder-denylist value: {e}"))?;
+    }
+    client_config.chain.bid_selection.require_builder_balance =
+        cli_args.get_flag("epbs-require-builder-balance");
+    client_config.chain.bid_selection.self_build_boost_percent =
+        clap_utils::parse_optional(cli_args, "epbs-self-build-boost")?;
+
     // Graphical user interface config.
     if cli_args.get_flag("gui") {
         client_config.http_api.enabled = true;
@@ -815,6 +912,8 @@ pub fn get_config<E: EthSpec>(
         client_config.chain.genesis_backfill = true;
     }
 
+    client_config.chain.era_dir = clap_utils::parse_optional(cli_args, "era-dir")?;
+
     client_config.chain.complete_blob_backfill = cli_args.get_flag("complete-blob-backfill");
 
     // Ensure `prune_blobs` is false whenever complete-blob-backfill is set. This overrides any
@@ -893,6 +992,14 @@ pub fn get_config<E: EthSpec>(
     Ok(client_config)
 }
 
+/// Parses a comma-separated list of builder indices and/or builder pubkeys.
+fn parse_builder_ids(list: &str) -> Result<Vec<BuilderId>, String> {
+    list.split(',')
+        .filter(|s| !s.trim().is_empty())
+        .map(BuilderId::from_str)
+        .collect()
+}
+
 /// Gets the listening_addresses for vibehouse based on the cli options.
 pub(crate) fn parse_listening_addresses(cli_args: &ArgMatches) -> Result<ListenAddress, String> {
     let listen_addresses_str = cli_args
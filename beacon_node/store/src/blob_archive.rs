@@ -0,0 +1,354 @@
+//! Append-only archive of blob and data column sidecars pruned from the blobs database.
+//!
+//! When `StoreConfig::blob_archive_dir` is set, blob pruning moves `BeaconBlob` and
+//! `BeaconDataColumn` entries older than the data availability boundary into archive files instead
+//! of deleting them. Archive file `N` holds the sidecars of the blocks in slots
+//! `[N * SLOTS_PER_ARCHIVE_FILE, (N + 1) * SLOTS_PER_ARCHIVE_FILE)`:
+//!
+//! ```text
+//! archive := Record*
+//! Record  := Length | Value
+//! ```
+//!
+//! where `Length` is the 4-byte little-endian length of `Value`, the zstd-compressed database value
+//! of a single entry. Records are only ever appended.
+//!
+//! The location of each record is stored in the blobs database under `BeaconBlobArchive` or
+//! `BeaconDataColumnArchive`, with the same key as the original entry. Index entries are written in
+//! the same batch that deletes the originals, after the records have been synced to disk, so a
+//! crash can at worst leave unreferenced records behind in an archive file.
+use crate::hot_cold_store::HotColdDB;
+use crate::{DBColumn, Error as StoreError, ItemStore, KeyValueStore, KeyValueStoreOp};
+use parking_lot::Mutex;
+use ssz::{Decode, Encode};
+use ssz_derive::{Decode, Encode};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+use types::{EthSpec, Slot};
+
+/// Number of slots covered by each archive file.
+pub const SLOTS_PER_ARCHIVE_FILE: u64 = 8192;
+
+/// Length of the prefix of each record.
+const RECORD_HEADER_LEN: u64 = 4;
+
+/// Maximum total size of the records appended to the archive before their index is committed.
+const ARCHIVE_BATCH_BYTES: usize = 64 * 1024 * 1024;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    RecordTooLarge(usize),
+    /// Only `BeaconBlob` and `BeaconDataColumn` entries can be archived.
+    UnarchivableColumn(DBColumn),
+    /// The length prefix of a record doesn't match the length in its index entry.
+    LengthMismatch {
+        file: u64,
+        offset: u64,
+        expected: u64,
+        found: u64,
+    },
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Position of a single record within the archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct ArchiveLocation {
+    pub file: u64,
+    /// Offset of the record's value, after its length prefix.
+    pub offset: u64,
+    pub length: u64,
+}
+
+/// A sidecar waiting to be moved from the blobs database into the archive.
+struct PendingRecord {
+    file: u64,
+    column: DBColumn,
+    index_column: DBColumn,
+    key: Vec<u8>,
+    compressed_value: Vec<u8>,
+}
+
+/// A directory of archive files.
+pub struct BlobArchive {
+    dir: PathBuf,
+    /// Held while appending, so that concurrent appends to the same file can't interleave.
+    append_lock: Mutex<()>,
+}
+
+impl BlobArchive {
+    /// Open the archive in `dir`, creating the directory if necessary.
+    pub fn open(dir: &Path) -> Result<Self, Error> {
+        fs::create_dir_all(dir)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            append_lock: Mutex::new(()),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The archive file holding the sidecars of a block at `slot`.
+    pub fn file_for_slot(slot: Slot) -> u64 {
+        slot.as_u64() / SLOTS_PER_ARCHIVE_FILE
+    }
+
+    pub fn file_path(&self, file: u64) -> PathBuf {
+        self.dir.join(format!("{file:06}.sidecars"))
+    }
+
+    /// Append `records` to archive file `file` and sync it, returning the location of each record.
+    pub fn append(&self, file: u64, records: &[&[u8]]) -> Result<Vec<ArchiveLocation>, Error> {
+        let _append_lock = self.append_lock.lock();
+        let mut archive_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.file_path(file))?;
+
+        // Any partially written records from an interrupted append are skipped over.
+        let mut offset = archive_file.metadata()?.len();
+        let mut bytes = vec![];
+        let mut locations = Vec::with_capacity(records.len());
+        for record in records {
+            let length =
+                u32::try_from(record.len()).map_err(|_| Error::RecordTooLarge(record.len()))?;
+            bytes.extend_from_slice(&length.to_le_bytes());
+            bytes.extend_from_slice(record);
+            locations.push(ArchiveLocation {
+                file,
+                offset: offset + RECORD_HEADER_LEN,
+                length: u64::from(length),
+            });
+            offset += RECORD_HEADER_LEN + u64::from(length);
+        }
+        archive_file.write_all(&bytes)?;
+        archive_file.sync_data()?;
+        Ok(locations)
+    }
+
+    /// Read the record at `location`.
+    pub fn read(&self, location: &ArchiveLocation) -> Result<Vec<u8>, Error> {
+        let mut archive_file = File::open(self.file_path(location.file))?;
+        archive_file.seek(SeekFrom::Start(
+            location.offset.saturating_sub(RECORD_HEADER_LEN),
+        ))?;
+        let mut header = [0; RECORD_HEADER_LEN as usize];
+        archive_file.read_exact(&mut header)?;
+        let found = u64::from(u32::from_le_bytes(header));
+        if found != location.length {
+            return Err(Error::LengthMismatch {
+                file: location.file,
+                offset: location.offset,
+                expected: location.length,
+                found,
+            });
+        }
+        let mut record = vec![0; found as usize];
+        archive_file.read_exact(&mut record)?;
+        Ok(record)
+    }
+}
+
+/// The column indexing the archived entries of `column`, if it can be archived.
+pub(crate) fn archive_index_column(column: DBColumn) -> Option<DBColumn> {
+    match column {
+        DBColumn::BeaconBlob => Some(DBColumn::BeaconBlobArchive),
+        DBColumn::BeaconDataColumn => Some(DBColumn::BeaconDataColumnArchive),
+        _ => None,
+    }
+}
+
+impl<E, Hot, Cold> HotColdDB<E, Hot, Cold>
+where
+    E: EthSpec,
+    Hot: ItemStore<E>,
+    Cold: ItemStore<E>,
+{
+    /// Fetch an entry from `column` of the blobs database, falling back to the archive if it has
+    /// been moved there.
+    pub(crate) fn get_blobs_db_bytes(
+        &self,
+        column: DBColumn,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, StoreError> {
+        if let Some(bytes) = self.blobs_db.get_bytes(column, key)? {
+            return Ok(Some(bytes));
+        }
+        let (Some(archive), Some(index_column)) =
+            (&self.blob_archive, archive_index_column(column))
+        else {
+            return Ok(None);
+        };
+        let Some(location_bytes) = self.blobs_db.get_bytes(index_column, key)? else {
+            return Ok(None);
+        };
+        let location = ArchiveLocation::from_ssz_bytes(&location_bytes)?;
+        let compressed_value = archive.read(&location)?;
+        self.config
+            .decompress_bytes(&compressed_value)
+            .map(Some)
+            .map_err(StoreError::Compression)
+    }
+
+    /// Move the `(slot, column, key)` entries of the blobs database into the archive.
+    ///
+    /// Entries are written in batches of at most `ARCHIVE_BATCH_BYTES`. Each batch is appended to
+    /// the archive, then indexed and deleted from the blobs database atomically.
+    pub(crate) fn archive_sidecars(
+        &self,
+        archive: &BlobArchive,
+        entries: impl IntoIterator<Item = (Slot, DBColumn, Vec<u8>)>,
+    ) -> Result<(), StoreError> {
+        let mut records = vec![];
+        let mut records_bytes = 0;
+        let mut num_archived = 0;
+        for (slot, column, key) in entries {
+            let index_column =
+                archive_index_column(column).ok_or(Error::UnarchivableColumn(column))?;
+            let Some(value) = self.blobs_db.get_bytes(column, &key)? else {
+                continue;
+            };
+            let compressed_value = self
+                .config
+                .compress_bytes(&value)
+                .map_err(StoreError::Compression)?;
+            records_bytes += compressed_value.len();
+            records.push(PendingRecord {
+                file: BlobArchive::file_for_slot(slot),
+                column,
+                index_column,
+                key,
+                compressed_value,
+            });
+            if records_bytes >= ARCHIVE_BATCH_BYTES {
+                num_archived += records.len();
+                self.archive_records(archive, std::mem::take(&mut records))?;
+                records_bytes = 0;
+            }
+        }
+        num_archived += records.len();
+        self.archive_records(archive, records)?;
+
+        if num_archived > 0 {
+            debug!(
+                num_archived,
+                dir = %archive.dir().display(),
+                "Archived blobs and data columns"
+            );
+        }
+        Ok(())
+    }
+
+    fn archive_records(
+        &self,
+        archive: &BlobArchive,
+        records: Vec<PendingRecord>,
+    ) -> Result<(), StoreError> {
+        let mut ops = Vec::with_capacity(2 * records.len());
+        let mut remaining = records.as_slice();
+        while let Some(first) = remaining.first() {
+            // Entries are archived in slot order, so the records of each file are contiguous.
+            let count = remaining
+                .iter()
+                .take_while(|record| record.file == first.file)
+                .count();
+            let (same_file, rest) = remaining.split_at(count);
+            let values = same_file
+                .iter()
+                .map(|record| record.compressed_value.as_slice())
+                .collect::<Vec<_>>();
+            let locations = archive.append(first.file, &values)?;
+
+            for (record, location) in same_file.iter().zip(locations) {
+                ops.push(KeyValueStoreOp::PutKeyValue(
+                    record.index_column,
+                    record.key.clone(),
+                    location.as_ssz_bytes(),
+                ));
+                ops.push(KeyValueStoreOp::DeleteKey(
+                    record.column,
+                    record.key.clone(),
+                ));
+            }
+            remaining = rest;
+        }
+
+        if ops.is_empty() {
+            return Ok(());
+        }
+        self.blobs_db.do_atomically(ops)
+    }
+}
+
+impl From<Error> for StoreError {
+    fn from(e: Error) -> Self {
+        StoreError::BlobArchive(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn append_and_read() {
+        let dir = tempdir().unwrap();
+        let archive = BlobArchive::open(dir.path()).unwrap();
+
+        let first = archive.append(3, &[b"abc", b""]).unwrap();
+        let second = archive.append(3, &[b"defgh"]).unwrap();
+        assert_eq!(first[0].offset, RECORD_HEADER_LEN);
+        assert_eq!(
+            second[0].offset,
+            2 * RECORD_HEADER_LEN + 3 + RECORD_HEADER_LEN
+        );
+
+        assert_eq!(archive.read(&first[0]).unwrap(), b"abc");
+        assert_eq!(archive.read(&first[1]).unwrap(), b"");
+        assert_eq!(archive.read(&second[0]).unwrap(), b"defgh");
+        assert!(archive.file_path(3).exists());
+    }
+
+    #[test]
+    fn read_rejects_wrong_length() {
+        let dir = tempdir().unwrap();
+        let archive = BlobArchive::open(dir.path()).unwrap();
+        let location = archive.append(0, &[b"abc"]).unwrap()[0];
+
+        let wrong = ArchiveLocation {
+            length: 4,
+            ..location
+        };
+        assert!(matches!(
+            archive.read(&wrong),
+            Err(Error::LengthMismatch {
+                expected: 4,
+                found: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn file_for_slot() {
+        assert_eq!(BlobArchive::file_for_slot(Slot::new(0)), 0);
+        assert_eq!(
+            BlobArchive::file_for_slot(Slot::new(SLOTS_PER_ARCHIVE_FILE - 1)),
+            0
+        );
+        assert_eq!(
+            BlobArchive::file_for_slot(Slot::new(SLOTS_PER_ARCHIVE_FILE)),
+            1
+        );
+    }
+}
@@ -1,3 +1,8 @@
+#[cfg(feature = "lmdb")]
+use crate::database::lmdb_impl;
+#[cfg(feature = "mdbx")]
+use crate::database::mdbx_impl;
+use crate::database::redb_impl;
 use crate::hdiff::HierarchyConfig;
 use crate::superstruct;
 use crate::{DBColumn, Error, StoreItem};
@@ -6,6 +11,8 @@ use ssz::{Decode, Encode};
 use ssz_derive::{Decode, Encode};
 use std::io::{Read, Write};
 use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator, IntoStaticStr, VariantNames};
 use types::EthSpec;
 use types::non_zero_usize::new_non_zero_usize;
 use zstd::{Decoder, Encoder};
@@ -21,6 +28,53 @@ const EST_COMPRESSION_FACTOR: usize = 2;
 pub(crate) const DEFAULT_EPOCHS_PER_BLOB_PRUNE: u64 = 1;
 pub(crate) const DEFAULT_BLOB_PUNE_MARGIN_EPOCHS: u64 = 0;
 
+/// Key-value database engine used for the hot, cold and blobs databases.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    Display,
+    EnumString,
+    EnumIter,
+    IntoStaticStr,
+    VariantNames,
+)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+    #[default]
+    Redb,
+    #[cfg(feature = "lmdb")]
+    Lmdb,
+    #[cfg(feature = "mdbx")]
+    Mdbx,
+}
+
+impl DatabaseBackend {
+    /// Name of the data file which identifies a database created by this backend.
+    pub fn data_file_name(self) -> &'static str {
+        match self {
+            DatabaseBackend::Redb => redb_impl::DB_FILE_NAME,
+            #[cfg(feature = "lmdb")]
+            DatabaseBackend::Lmdb => lmdb_impl::DB_FILE_NAME,
+            #[cfg(feature = "mdbx")]
+            DatabaseBackend::Mdbx => mdbx_impl::DB_FILE_NAME,
+        }
+    }
+
+    /// Determine which backend created the database in `path`, if any.
+    ///
+    /// Only backends compiled into this binary are detected.
+    pub fn detect(path: &Path) -> Option<Self> {
+        Self::iter().find(|backend| path.join(backend.data_file_name()).exists())
+    }
+}
+
 /// Database configuration parameters.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StoreConfig {
@@ -38,6 +92,11 @@ pub struct StoreConfig {
     pub cold_hdiff_buffer_cache_size: NonZeroUsize,
     /// Maximum number of hot `HDiffBuffers` to store in memory.
     pub hot_hdiff_buffer_cache_size: NonZeroUsize,
+    /// Maximum estimated memory in bytes to use for the in-memory state and `HDiffBuffer` caches.
+    ///
+    /// When set, the hot and historic caches are limited by this budget instead of the entry
+    /// counts above.
+    pub state_cache_memory_budget: Option<NonZeroUsize>,
     /// Whether to compact the database on initialization.
     pub compact_on_init: bool,
     /// Whether to compact the database during database pruning.
@@ -53,6 +112,15 @@ pub struct StoreConfig {
     /// The margin for blob pruning in epochs. The oldest blobs are pruned up until
     /// data_availability_boundary - blob_prune_margin_epochs. Default: 0.
     pub blob_prune_margin_epochs: u64,
+    /// Directory to move pruned blobs and data columns to, instead of deleting them.
+    pub blob_archive_dir: Option<PathBuf>,
+    /// Whether to record per-epoch validator summaries in the freezer database.
+    pub validator_history: bool,
+    /// Number of epochs before finalization to keep inclusion lists, payload attestations and
+    /// execution proofs for. 0 disables retention.
+    pub side_object_retention_epochs: u64,
+    /// Database backend to use for the hot, cold and blobs databases.
+    pub backend: DatabaseBackend,
 }
 
 /// Variant of `StoreConfig` that gets written to disk. Contains immutable configuration params.
@@ -100,6 +168,7 @@ impl Default for StoreConfig {
             historic_state_cache_size: DEFAULT_HISTORIC_STATE_CACHE_SIZE,
             cold_hdiff_buffer_cache_size: DEFAULT_COLD_HDIFF_BUFFER_CACHE_SIZE,
             hot_hdiff_buffer_cache_size: DEFAULT_HOT_HDIFF_BUFFER_CACHE_SIZE,
+            state_cache_memory_budget: None,
             compression_level: DEFAULT_COMPRESSION_LEVEL,
             compact_on_init: false,
             compact_on_prune: true,
@@ -108,6 +177,10 @@ impl Default for StoreConfig {
             prune_blobs: true,
             epochs_per_blob_prune: DEFAULT_EPOCHS_PER_BLOB_PRUNE,
             blob_prune_margin_epochs: DEFAULT_BLOB_PUNE_MARGIN_EPOCHS,
+            blob_archive_dir: None,
+            validator_history: false,
+            side_object_retention_epochs: 0,
+            backend: DatabaseBackend::default(),
         }
     }
 }
@@ -417,12 +490,16 @@ mod test {
         let config = StoreConfig::default();
         assert_eq!(config.block_cache_size, DEFAULT_BLOCK_CACHE_SIZE);
         assert_eq!(config.state_cache_size, DEFAULT_STATE_CACHE_SIZE);
+        assert_eq!(config.state_cache_memory_budget, None);
         assert_eq!(config.compression_level, DEFAULT_COMPRESSION_LEVEL);
         assert!(config.prune_payloads);
         assert!(config.prune_blobs);
         assert!(config.compact_on_prune);
         assert!(!config.compact_on_init);
         assert_eq!(config.epochs_per_blob_prune, DEFAULT_EPOCHS_PER_BLOB_PRUNE);
+        assert_eq!(config.blob_archive_dir, None);
+        assert!(!config.validator_history);
+        assert_eq!(config.side_object_retention_epochs, 0);
         assert_eq!(
             config.blob_prune_margin_epochs,
             DEFAULT_BLOB_PUNE_MARGIN_EPOCHS
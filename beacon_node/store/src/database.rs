@@ -1,2 +1,8 @@
+#[cfg(any(feature = "lmdb", feature = "mdbx"))]
+pub(crate) mod chunked_iter;
 pub mod interface;
+#[cfg(feature = "lmdb")]
+pub(crate) mod lmdb_impl;
+#[cfg(feature = "mdbx")]
+pub(crate) mod mdbx_impl;
 pub(crate) mod redb_impl;
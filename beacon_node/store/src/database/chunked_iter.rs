@@ -0,0 +1,59 @@
+//! Column iteration for the LMDB and MDBX backends.
+//!
+//! Cursors in both engines borrow a read transaction, which in turn borrows the environment, so
+//! they can't be returned from `iter_column_from` without a self-referential struct. Instead we
+//! read the column in chunks of `ITER_CHUNK_SIZE` entries, each from a fresh read transaction,
+//! resuming after the last key of the previous chunk.
+use crate::Error;
+
+/// Maximum number of entries read by a single read transaction.
+pub(crate) const ITER_CHUNK_SIZE: usize = 1024;
+
+/// A chunk of key-value pairs read from a column in key order.
+pub(crate) type Chunk = Vec<(Vec<u8>, Vec<u8>)>;
+
+pub(crate) struct ChunkedIter<'a> {
+    /// Read up to `ITER_CHUNK_SIZE` entries with keys `>= start`, or `> start` if the flag is set.
+    read_chunk: Box<dyn FnMut(&[u8], bool) -> Result<Chunk, Error> + 'a>,
+    /// Key to resume from, or `None` once the column has been exhausted.
+    resume_from: Option<Vec<u8>>,
+    exclusive: bool,
+    buffer: std::vec::IntoIter<(Vec<u8>, Vec<u8>)>,
+}
+
+impl<'a> ChunkedIter<'a> {
+    pub(crate) fn new(
+        from: &[u8],
+        read_chunk: impl FnMut(&[u8], bool) -> Result<Chunk, Error> + 'a,
+    ) -> Self {
+        Self {
+            read_chunk: Box::new(read_chunk),
+            resume_from: Some(from.to_vec()),
+            exclusive: false,
+            buffer: vec![].into_iter(),
+        }
+    }
+}
+
+impl Iterator for ChunkedIter<'_> {
+    type Item = Result<(Vec<u8>, Vec<u8>), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(entry) = self.buffer.next() {
+            return Some(Ok(entry));
+        }
+
+        let start = self.resume_from.take()?;
+        match (self.read_chunk)(&start, self.exclusive) {
+            Ok(chunk) => {
+                if chunk.len() >= ITER_CHUNK_SIZE {
+                    self.resume_from = chunk.last().map(|(key, _)| key.clone());
+                    self.exclusive = true;
+                }
+                self.buffer = chunk.into_iter();
+                self.buffer.next().map(Ok)
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
@@ -1,71 +1,179 @@
-use crate::KeyValueStoreOp;
+#[cfg(feature = "lmdb")]
+use crate::database::lmdb_impl;
+#[cfg(feature = "mdbx")]
+use crate::database::mdbx_impl;
 use crate::database::redb_impl;
-use crate::{ColumnIter, ColumnKeyIter, DBColumn, Error, ItemStore, Key, KeyValueStore, metrics};
+use crate::{
+    ColumnIter, ColumnKeyIter, DBColumn, DatabaseBackend, Error, ItemStore, Key, KeyValueStore,
+    KeyValueStoreOp, StoreConfig, metrics, snapshot::SnapshotProgress,
+};
 use std::collections::HashSet;
 use std::path::Path;
 use types::EthSpec;
 
-pub struct BeaconNodeBackend<E: EthSpec>(redb_impl::Redb<E>);
+pub enum BeaconNodeBackend<E: EthSpec> {
+    Redb(redb_impl::Redb<E>),
+    #[cfg(feature = "lmdb")]
+    Lmdb(lmdb_impl::Lmdb<E>),
+    #[cfg(feature = "mdbx")]
+    Mdbx(mdbx_impl::Mdbx<E>),
+}
 
 impl<E: EthSpec> ItemStore<E> for BeaconNodeBackend<E> {}
 
 impl<E: EthSpec> KeyValueStore<E> for BeaconNodeBackend<E> {
     fn get_bytes(&self, column: DBColumn, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
-        redb_impl::Redb::get_bytes(&self.0, column, key)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::get_bytes(db, column, key),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::get_bytes(db, column, key),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::get_bytes(db, column, key),
+        }
     }
 
     fn put_bytes(&self, column: DBColumn, key: &[u8], value: &[u8]) -> Result<(), Error> {
-        redb_impl::Redb::put_bytes_with_options(&self.0, column, key, value, self.0.write_options())
+        match self {
+            BeaconNodeBackend::Redb(db) => {
+                redb_impl::Redb::put_bytes_with_options(db, column, key, value, db.write_options())
+            }
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => {
+                lmdb_impl::Lmdb::put_bytes_with_options(db, column, key, value, db.write_options())
+            }
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => {
+                mdbx_impl::Mdbx::put_bytes_with_options(db, column, key, value, db.write_options())
+            }
+        }
     }
 
     fn put_bytes_sync(&self, column: DBColumn, key: &[u8], value: &[u8]) -> Result<(), Error> {
-        redb_impl::Redb::put_bytes_with_options(
-            &self.0,
-            column,
-            key,
-            value,
-            self.0.write_options_sync(),
-        )
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::put_bytes_with_options(
+                db,
+                column,
+                key,
+                value,
+                db.write_options_sync(),
+            ),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::put_bytes_with_options(
+                db,
+                column,
+                key,
+                value,
+                db.write_options_sync(),
+            ),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::put_bytes_with_options(
+                db,
+                column,
+                key,
+                value,
+                db.write_options_sync(),
+            ),
+        }
     }
 
     fn sync(&self) -> Result<(), Error> {
-        redb_impl::Redb::sync(&self.0)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::sync(db),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::sync(db),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::sync(db),
+        }
     }
 
     fn key_exists(&self, column: DBColumn, key: &[u8]) -> Result<bool, Error> {
-        redb_impl::Redb::key_exists(&self.0, column, key)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::key_exists(db, column, key),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::key_exists(db, column, key),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::key_exists(db, column, key),
+        }
     }
 
     fn key_delete(&self, column: DBColumn, key: &[u8]) -> Result<(), Error> {
-        redb_impl::Redb::key_delete(&self.0, column, key)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::key_delete(db, column, key),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::key_delete(db, column, key),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::key_delete(db, column, key),
+        }
     }
 
     fn do_atomically(&self, batch: Vec<KeyValueStoreOp>) -> Result<(), Error> {
-        redb_impl::Redb::do_atomically(&self.0, batch)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::do_atomically(db, batch),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::do_atomically(db, batch),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::do_atomically(db, batch),
+        }
     }
 
     fn compact(&self) -> Result<(), Error> {
-        redb_impl::Redb::compact(&self.0)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::compact(db),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::compact(db),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::compact(db),
+        }
     }
 
     fn iter_column_keys_from<K: Key>(&self, column: DBColumn, from: &[u8]) -> ColumnKeyIter<'_, K> {
-        redb_impl::Redb::iter_column_keys_from(&self.0, column, from)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::iter_column_keys_from(db, column, from),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::iter_column_keys_from(db, column, from),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::iter_column_keys_from(db, column, from),
+        }
     }
 
     fn iter_column_keys<K: Key>(&self, column: DBColumn) -> ColumnKeyIter<'_, K> {
-        redb_impl::Redb::iter_column_keys(&self.0, column)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::iter_column_keys(db, column),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::iter_column_keys(db, column),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::iter_column_keys(db, column),
+        }
     }
 
     fn iter_column_from<K: Key>(&self, column: DBColumn, from: &[u8]) -> ColumnIter<'_, K> {
-        redb_impl::Redb::iter_column_from(&self.0, column, from)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::iter_column_from(db, column, from),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::iter_column_from(db, column, from),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::iter_column_from(db, column, from),
+        }
     }
 
     fn compact_column(&self, _column: DBColumn) -> Result<(), Error> {
-        redb_impl::Redb::compact(&self.0)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::compact(db),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::compact(db),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::compact(db),
+        }
     }
 
     fn delete_batch(&self, col: DBColumn, ops: HashSet<&[u8]>) -> Result<(), Error> {
-        redb_impl::Redb::delete_batch(&self.0, col, ops)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::delete_batch(db, col, ops),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::delete_batch(db, col, ops),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::delete_batch(db, col, ops),
+        }
     }
 
     fn delete_if(
@@ -73,14 +181,61 @@ impl<E: EthSpec> KeyValueStore<E> for BeaconNodeBackend<E> {
         column: DBColumn,
         f: impl FnMut(&[u8]) -> Result<bool, Error>,
     ) -> Result<(), Error> {
-        redb_impl::Redb::delete_if(&self.0, column, f)
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::delete_if(db, column, f),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::delete_if(db, column, f),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::delete_if(db, column, f),
+        }
+    }
+
+    fn snapshot(&self, path: &Path, progress: &SnapshotProgress) -> Result<(), Error> {
+        match self {
+            BeaconNodeBackend::Redb(db) => redb_impl::Redb::snapshot(db, path, progress),
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(db) => lmdb_impl::Lmdb::snapshot(db, path, progress),
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(db) => mdbx_impl::Mdbx::snapshot(db, path, progress),
+        }
     }
 }
 
 impl<E: EthSpec> BeaconNodeBackend<E> {
-    pub fn open(path: &Path) -> Result<Self, Error> {
-        metrics::inc_counter_vec(&metrics::DISK_DB_TYPE, &["redb"]);
-        redb_impl::Redb::open(path).map(BeaconNodeBackend)
+    /// Open the database in `path` using the backend from `config`.
+    ///
+    /// Opening a database which was created by a different backend is an error, rather than
+    /// silently creating a second, empty database alongside it.
+    pub fn open(config: &StoreConfig, path: &Path) -> Result<Self, Error> {
+        if let Some(on_disk) = DatabaseBackend::detect(path)
+            && on_disk != config.backend
+        {
+            return Err(Error::DatabaseBackendMismatch {
+                path: path.to_path_buf(),
+                configured: config.backend,
+                on_disk,
+            });
+        }
+
+        metrics::inc_counter_vec(&metrics::DISK_DB_TYPE, &[config.backend.into()]);
+        match config.backend {
+            DatabaseBackend::Redb => redb_impl::Redb::open(path).map(BeaconNodeBackend::Redb),
+            #[cfg(feature = "lmdb")]
+            DatabaseBackend::Lmdb => lmdb_impl::Lmdb::open(path).map(BeaconNodeBackend::Lmdb),
+            #[cfg(feature = "mdbx")]
+            DatabaseBackend::Mdbx => mdbx_impl::Mdbx::open(path).map(BeaconNodeBackend::Mdbx),
+        }
+    }
+
+    /// The backend of this database.
+    pub fn backend(&self) -> DatabaseBackend {
+        match self {
+            BeaconNodeBackend::Redb(_) => DatabaseBackend::Redb,
+            #[cfg(feature = "lmdb")]
+            BeaconNodeBackend::Lmdb(_) => DatabaseBackend::Lmdb,
+            #[cfg(feature = "mdbx")]
+            BeaconNodeBackend::Mdbx(_) => DatabaseBackend::Mdbx,
+        }
     }
 }
 
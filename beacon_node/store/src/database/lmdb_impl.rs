@@ -0,0 +1,313 @@
+//! LMDB implementation of the beacon node database, with one named database per `DBColumn`.
+//!
+//! Every LMDB commit is durable, so there is no distinction between synced and unsynced writes.
+use crate::database::chunked_iter::{Chunk, ChunkedIter, ITER_CHUNK_SIZE};
+use crate::snapshot::{SnapshotProgress, SnapshotWriter};
+use crate::{ColumnIter, ColumnKeyIter, DBColumn, Error, Key, KeyValueStoreOp, metrics};
+use lmdb::{Cursor, DatabaseFlags, Transaction, WriteFlags};
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+use std::path::Path;
+use strum::IntoEnumIterator;
+use types::EthSpec;
+
+use super::interface::WriteOptions;
+
+pub(crate) const DB_FILE_NAME: &str = "data.mdb";
+
+/// Upper limit on the size of the memory map, and therefore the database.
+///
+/// LMDB reserves this much virtual address space up front, but the file only grows as needed.
+const MAP_SIZE: usize = 1 << 42;
+
+/// Maximum number of concurrent read transactions.
+///
+/// Each thread with an open read transaction holds a reader slot, and the LMDB default of 126 is
+/// easily exhausted by the beacon node's blocking and rayon thread pools.
+const MAX_READERS: u32 = 1024;
+
+pub(crate) struct Lmdb<E: EthSpec> {
+    env: lmdb::Environment,
+    dbs: HashMap<DBColumn, lmdb::Database>,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EthSpec> Lmdb<E> {
+    pub(crate) fn open(path: &Path) -> Result<Self, Error> {
+        std::fs::create_dir_all(path).map_err(|e| Error::DBError {
+            message: format!("Failed to create directory {}: {e}", path.display()),
+        })?;
+        let env = lmdb::Environment::new()
+            .set_max_dbs(DBColumn::iter().count() as u32)
+            .set_map_size(MAP_SIZE)
+            .set_max_readers(MAX_READERS)
+            .open_with_permissions(path, 0o600)?;
+
+        let dbs = DBColumn::iter()
+            .map(|column| {
+                let db = env.create_db(Some(column.into()), DatabaseFlags::empty())?;
+                Ok((column, db))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            env,
+            dbs,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn db(&self, column: DBColumn) -> Result<lmdb::Database, Error> {
+        self.dbs
+            .get(&column)
+            .copied()
+            .ok_or_else(|| Error::DBError {
+                message: format!("LMDB database for column {column:?} is not open"),
+            })
+    }
+
+    #[allow(clippy::unused_self)]
+    pub(crate) fn write_options(&self) -> WriteOptions {
+        WriteOptions::new()
+    }
+
+    #[allow(clippy::unused_self)]
+    pub(crate) fn write_options_sync(&self) -> WriteOptions {
+        let mut opts = WriteOptions::new();
+        opts.sync = true;
+        opts
+    }
+
+    pub(crate) fn put_bytes_with_options(
+        &self,
+        col: DBColumn,
+        key: &[u8],
+        val: &[u8],
+        _opts: WriteOptions,
+    ) -> Result<(), Error> {
+        metrics::inc_counter_vec(&metrics::DISK_DB_WRITE_COUNT, &[col.into()]);
+        metrics::inc_counter_vec_by(
+            &metrics::DISK_DB_WRITE_BYTES,
+            &[col.into()],
+            val.len() as u64,
+        );
+        let _timer = metrics::start_timer(&metrics::DISK_DB_WRITE_TIMES);
+
+        let db = self.db(col)?;
+        let mut txn = self.env.begin_rw_txn()?;
+        txn.put(db, &key, &val, WriteFlags::empty())?;
+        txn.commit().map_err(Into::into)
+    }
+
+    pub(crate) fn sync(&self) -> Result<(), Error> {
+        self.env.sync(true).map_err(Into::into)
+    }
+
+    pub(crate) fn get_bytes(&self, col: DBColumn, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        metrics::inc_counter_vec(&metrics::DISK_DB_READ_COUNT, &[col.into()]);
+        let _timer = metrics::start_timer(&metrics::DISK_DB_READ_TIMES);
+
+        let db = self.db(col)?;
+        let txn = self.env.begin_ro_txn()?;
+        match txn.get(db, &key) {
+            Ok(value) => {
+                metrics::inc_counter_vec_by(
+                    &metrics::DISK_DB_READ_BYTES,
+                    &[col.into()],
+                    value.len() as u64,
+                );
+                Ok(Some(value.to_vec()))
+            }
+            Err(lmdb::Error::NotFound) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(crate) fn key_exists(&self, col: DBColumn, key: &[u8]) -> Result<bool, Error> {
+        metrics::inc_counter_vec(&metrics::DISK_DB_EXISTS_COUNT, &[col.into()]);
+
+        let db = self.db(col)?;
+        let txn = self.env.begin_ro_txn()?;
+        match txn.get(db, &key) {
+            Ok(_) => Ok(true),
+            Err(lmdb::Error::NotFound) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(crate) fn key_delete(&self, col: DBColumn, key: &[u8]) -> Result<(), Error> {
+        metrics::inc_counter_vec(&metrics::DISK_DB_DELETE_COUNT, &[col.into()]);
+
+        let db = self.db(col)?;
+        let mut txn = self.env.begin_rw_txn()?;
+        Self::del(&mut txn, db, key)?;
+        txn.commit().map_err(Into::into)
+    }
+
+    /// Delete `key` from `db`, ignoring keys which are not present.
+    fn del(txn: &mut lmdb::RwTransaction<'_>, db: lmdb::Database, key: &[u8]) -> Result<(), Error> {
+        match txn.del(db, &key, None) {
+            Ok(()) | Err(lmdb::Error::NotFound) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(crate) fn do_atomically(&self, ops_batch: Vec<KeyValueStoreOp>) -> Result<(), Error> {
+        let mut txn = self.env.begin_rw_txn()?;
+        for op in ops_batch {
+            match op {
+                KeyValueStoreOp::PutKeyValue(column, key, value) => {
+                    let _timer = metrics::start_timer(&metrics::DISK_DB_WRITE_TIMES);
+                    metrics::inc_counter_vec_by(
+                        &metrics::DISK_DB_WRITE_BYTES,
+                        &[column.into()],
+                        value.len() as u64,
+                    );
+                    metrics::inc_counter_vec(&metrics::DISK_DB_WRITE_COUNT, &[column.into()]);
+                    txn.put(self.db(column)?, &key, &value, WriteFlags::empty())?;
+                }
+
+                KeyValueStoreOp::DeleteKey(column, key) => {
+                    metrics::inc_counter_vec(&metrics::DISK_DB_DELETE_COUNT, &[column.into()]);
+                    let _timer = metrics::start_timer(&metrics::DISK_DB_DELETE_TIMES);
+                    Self::del(&mut txn, self.db(column)?, &key)?;
+                }
+            }
+        }
+        txn.commit().map_err(Into::into)
+    }
+
+    /// LMDB reuses freed pages in place and has no online compaction, so this is a no-op.
+    #[allow(clippy::unused_self)]
+    pub(crate) fn compact(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Read up to `ITER_CHUNK_SIZE` entries of `column` starting at `start`.
+    ///
+    /// Values are only copied out if `with_values` is set.
+    fn read_chunk(
+        &self,
+        column: DBColumn,
+        start: &[u8],
+        exclusive: bool,
+        with_values: bool,
+    ) -> Result<Chunk, Error> {
+        let db = self.db(column)?;
+        let txn = self.env.begin_ro_txn()?;
+        let mut cursor = txn.open_ro_cursor(db)?;
+
+        let mut chunk = Vec::with_capacity(ITER_CHUNK_SIZE);
+        for entry in cursor.iter_from(start) {
+            let (key, value) = entry?;
+            if exclusive && key == start {
+                continue;
+            }
+            let value = if with_values { value.to_vec() } else { vec![] };
+            chunk.push((key.to_vec(), value));
+            if chunk.len() >= ITER_CHUNK_SIZE {
+                break;
+            }
+        }
+        Ok(chunk)
+    }
+
+    pub(crate) fn iter_column_keys_from<K: Key>(
+        &self,
+        column: DBColumn,
+        from: &[u8],
+    ) -> ColumnKeyIter<'_, K> {
+        Box::new(
+            ChunkedIter::new(from, move |start, exclusive| {
+                self.read_chunk(column, start, exclusive, false)
+            })
+            .map(move |res| {
+                let (key, _) = res?;
+                metrics::inc_counter_vec(&metrics::DISK_DB_KEY_READ_COUNT, &[column.into()]);
+                metrics::inc_counter_vec_by(
+                    &metrics::DISK_DB_KEY_READ_BYTES,
+                    &[column.into()],
+                    key.len() as u64,
+                );
+                K::from_bytes(&key)
+            }),
+        )
+    }
+
+    pub(crate) fn iter_column_keys<K: Key>(&self, column: DBColumn) -> ColumnKeyIter<'_, K> {
+        self.iter_column_keys_from(column, &vec![0; column.key_size()])
+    }
+
+    pub(crate) fn iter_column_from<K: Key>(
+        &self,
+        column: DBColumn,
+        from: &[u8],
+    ) -> ColumnIter<'_, K> {
+        Box::new(
+            ChunkedIter::new(from, move |start, exclusive| {
+                self.read_chunk(column, start, exclusive, true)
+            })
+            .map(move |res| {
+                let (key, value) = res?;
+                metrics::inc_counter_vec(&metrics::DISK_DB_READ_COUNT, &[column.into()]);
+                metrics::inc_counter_vec_by(
+                    &metrics::DISK_DB_READ_BYTES,
+                    &[column.into()],
+                    value.len() as u64,
+                );
+                Ok((K::from_bytes(&key)?, value))
+            }),
+        )
+    }
+
+    pub(crate) fn delete_batch(&self, col: DBColumn, ops: HashSet<&[u8]>) -> Result<(), Error> {
+        let db = self.db(col)?;
+        let mut txn = self.env.begin_rw_txn()?;
+        for key in ops {
+            Self::del(&mut txn, db, key)?;
+        }
+        txn.commit().map_err(Into::into)
+    }
+
+    pub(crate) fn delete_if(
+        &self,
+        column: DBColumn,
+        mut f: impl FnMut(&[u8]) -> Result<bool, Error>,
+    ) -> Result<(), Error> {
+        let db = self.db(column)?;
+        let mut txn = self.env.begin_rw_txn()?;
+
+        let mut to_delete = vec![];
+        {
+            let mut cursor = txn.open_ro_cursor(db)?;
+            for entry in cursor.iter_start() {
+                let (key, value) = entry?;
+                if f(value)? {
+                    to_delete.push(key.to_vec());
+                }
+            }
+        }
+
+        for key in to_delete {
+            Self::del(&mut txn, db, &key)?;
+        }
+        txn.commit().map_err(Into::into)
+    }
+
+    pub(crate) fn snapshot(&self, path: &Path, progress: &SnapshotProgress) -> Result<(), Error> {
+        let target = Self::open(path)?;
+        let mut writer = SnapshotWriter::new(progress, |ops| target.do_atomically(ops));
+
+        let txn = self.env.begin_ro_txn()?;
+        for column in DBColumn::iter() {
+            let mut cursor = txn.open_ro_cursor(self.db(column)?)?;
+            for entry in cursor.iter_start() {
+                let (key, value) = entry?;
+                writer.put(column, key, value)?;
+            }
+        }
+        drop(txn);
+        writer.finish()?;
+        target.sync()
+    }
+}
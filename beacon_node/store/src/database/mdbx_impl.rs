@@ -0,0 +1,313 @@
+//! MDBX implementation of the beacon node database, with one named database per `DBColumn`.
+//!
+//! Every MDBX commit is durable, so there is no distinction between synced and unsynced writes.
+use crate::database::chunked_iter::{Chunk, ChunkedIter, ITER_CHUNK_SIZE};
+use crate::snapshot::{SnapshotProgress, SnapshotWriter};
+use crate::{ColumnIter, ColumnKeyIter, DBColumn, Error, Key, KeyValueStoreOp, metrics};
+use mdbx::{DatabaseFlags, Geometry, WriteFlags};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::path::Path;
+use strum::IntoEnumIterator;
+use types::EthSpec;
+
+use super::interface::WriteOptions;
+
+pub(crate) const DB_FILE_NAME: &str = "mdbx.dat";
+
+/// Upper limit on the size of the database.
+const MAX_DB_SIZE: usize = 1 << 42;
+/// Amount by which the database file grows when it runs out of space.
+const GROWTH_STEP: isize = 256 * (1 << 20);
+
+type Environment = mdbx::Environment<mdbx::NoWriteMap>;
+
+pub(crate) struct Mdbx<E: EthSpec> {
+    env: Environment,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EthSpec> Mdbx<E> {
+    pub(crate) fn open(path: &Path) -> Result<Self, Error> {
+        std::fs::create_dir_all(path).map_err(|e| Error::DBError {
+            message: format!("Failed to create directory {}: {e}", path.display()),
+        })?;
+        let env = Environment::new()
+            .set_max_dbs(DBColumn::iter().count())
+            .set_geometry(Self::geometry())
+            .open_with_permissions(path, 0o600)?;
+
+        let txn = env.begin_rw_txn()?;
+        for column in DBColumn::iter() {
+            txn.create_db(Some(column.into()), DatabaseFlags::default())?;
+        }
+        txn.commit()?;
+
+        Ok(Self {
+            env,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn geometry() -> Geometry<Range<usize>> {
+        Geometry {
+            size: Some(0..MAX_DB_SIZE),
+            growth_step: Some(GROWTH_STEP),
+            shrink_threshold: None,
+            page_size: None,
+        }
+    }
+
+    #[allow(clippy::unused_self)]
+    pub(crate) fn write_options(&self) -> WriteOptions {
+        WriteOptions::new()
+    }
+
+    #[allow(clippy::unused_self)]
+    pub(crate) fn write_options_sync(&self) -> WriteOptions {
+        let mut opts = WriteOptions::new();
+        opts.sync = true;
+        opts
+    }
+
+    pub(crate) fn put_bytes_with_options(
+        &self,
+        col: DBColumn,
+        key: &[u8],
+        val: &[u8],
+        _opts: WriteOptions,
+    ) -> Result<(), Error> {
+        metrics::inc_counter_vec(&metrics::DISK_DB_WRITE_COUNT, &[col.into()]);
+        metrics::inc_counter_vec_by(
+            &metrics::DISK_DB_WRITE_BYTES,
+            &[col.into()],
+            val.len() as u64,
+        );
+        let _timer = metrics::start_timer(&metrics::DISK_DB_WRITE_TIMES);
+
+        let txn = self.env.begin_rw_txn()?;
+        let db = txn.open_db(Some(col.into()))?;
+        txn.put(&db, key, val, WriteFlags::default())?;
+        drop(db);
+        txn.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn sync(&self) -> Result<(), Error> {
+        self.env.sync(true)?;
+        Ok(())
+    }
+
+    pub(crate) fn get_bytes(&self, col: DBColumn, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        metrics::inc_counter_vec(&metrics::DISK_DB_READ_COUNT, &[col.into()]);
+        let _timer = metrics::start_timer(&metrics::DISK_DB_READ_TIMES);
+
+        let txn = self.env.begin_ro_txn()?;
+        let db = txn.open_db(Some(col.into()))?;
+        let value: Option<Cow<'_, [u8]>> = txn.get(&db, key)?;
+        Ok(value.map(|value| {
+            metrics::inc_counter_vec_by(
+                &metrics::DISK_DB_READ_BYTES,
+                &[col.into()],
+                value.len() as u64,
+            );
+            value.into_owned()
+        }))
+    }
+
+    pub(crate) fn key_exists(&self, col: DBColumn, key: &[u8]) -> Result<bool, Error> {
+        metrics::inc_counter_vec(&metrics::DISK_DB_EXISTS_COUNT, &[col.into()]);
+
+        let txn = self.env.begin_ro_txn()?;
+        let db = txn.open_db(Some(col.into()))?;
+        let value: Option<Cow<'_, [u8]>> = txn.get(&db, key)?;
+        Ok(value.is_some())
+    }
+
+    pub(crate) fn key_delete(&self, col: DBColumn, key: &[u8]) -> Result<(), Error> {
+        metrics::inc_counter_vec(&metrics::DISK_DB_DELETE_COUNT, &[col.into()]);
+
+        let txn = self.env.begin_rw_txn()?;
+        let db = txn.open_db(Some(col.into()))?;
+        txn.del(&db, key, None)?;
+        drop(db);
+        txn.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn do_atomically(&self, ops_batch: Vec<KeyValueStoreOp>) -> Result<(), Error> {
+        let txn = self.env.begin_rw_txn()?;
+        for op in ops_batch {
+            match op {
+                KeyValueStoreOp::PutKeyValue(column, key, value) => {
+                    let _timer = metrics::start_timer(&metrics::DISK_DB_WRITE_TIMES);
+                    metrics::inc_counter_vec_by(
+                        &metrics::DISK_DB_WRITE_BYTES,
+                        &[column.into()],
+                        value.len() as u64,
+                    );
+                    metrics::inc_counter_vec(&metrics::DISK_DB_WRITE_COUNT, &[column.into()]);
+                    let db = txn.open_db(Some(column.into()))?;
+                    txn.put(&db, key, value, WriteFlags::default())?;
+                }
+
+                KeyValueStoreOp::DeleteKey(column, key) => {
+                    metrics::inc_counter_vec(&metrics::DISK_DB_DELETE_COUNT, &[column.into()]);
+                    let _timer = metrics::start_timer(&metrics::DISK_DB_DELETE_TIMES);
+                    let db = txn.open_db(Some(column.into()))?;
+                    txn.del(&db, key, None)?;
+                }
+            }
+        }
+        txn.commit()?;
+        Ok(())
+    }
+
+    /// MDBX reclaims freed pages itself (and shrinks the file via its geometry), so this is a
+    /// no-op.
+    #[allow(clippy::unused_self)]
+    pub(crate) fn compact(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Read up to `ITER_CHUNK_SIZE` entries of `column` starting at `start`.
+    ///
+    /// Values are only copied out if `with_values` is set.
+    fn read_chunk(
+        &self,
+        column: DBColumn,
+        start: &[u8],
+        exclusive: bool,
+        with_values: bool,
+    ) -> Result<Chunk, Error> {
+        let txn = self.env.begin_ro_txn()?;
+        let db = txn.open_db(Some(column.into()))?;
+        let mut cursor = txn.cursor(&db)?;
+
+        let mut chunk = Vec::with_capacity(ITER_CHUNK_SIZE);
+        let mut entry: Option<(Cow<'_, [u8]>, Cow<'_, [u8]>)> = cursor.set_range(start)?;
+        while let Some((key, value)) = entry {
+            if !(exclusive && key.as_ref() == start) {
+                let value = if with_values {
+                    value.into_owned()
+                } else {
+                    vec![]
+                };
+                chunk.push((key.into_owned(), value));
+                if chunk.len() >= ITER_CHUNK_SIZE {
+                    break;
+                }
+            }
+            entry = cursor.next()?;
+        }
+        Ok(chunk)
+    }
+
+    pub(crate) fn iter_column_keys_from<K: Key>(
+        &self,
+        column: DBColumn,
+        from: &[u8],
+    ) -> ColumnKeyIter<'_, K> {
+        Box::new(
+            ChunkedIter::new(from, move |start, exclusive| {
+                self.read_chunk(column, start, exclusive, false)
+            })
+            .map(move |res| {
+                let (key, _) = res?;
+                metrics::inc_counter_vec(&metrics::DISK_DB_KEY_READ_COUNT, &[column.into()]);
+                metrics::inc_counter_vec_by(
+                    &metrics::DISK_DB_KEY_READ_BYTES,
+                    &[column.into()],
+                    key.len() as u64,
+                );
+                K::from_bytes(&key)
+            }),
+        )
+    }
+
+    pub(crate) fn iter_column_keys<K: Key>(&self, column: DBColumn) -> ColumnKeyIter<'_, K> {
+        self.iter_column_keys_from(column, &vec![0; column.key_size()])
+    }
+
+    pub(crate) fn iter_column_from<K: Key>(
+        &self,
+        column: DBColumn,
+        from: &[u8],
+    ) -> ColumnIter<'_, K> {
+        Box::new(
+            ChunkedIter::new(from, move |start, exclusive| {
+                self.read_chunk(column, start, exclusive, true)
+            })
+            .map(move |res| {
+                let (key, value) = res?;
+                metrics::inc_counter_vec(&metrics::DISK_DB_READ_COUNT, &[column.into()]);
+                metrics::inc_counter_vec_by(
+                    &metrics::DISK_DB_READ_BYTES,
+                    &[column.into()],
+                    value.len() as u64,
+                );
+                Ok((K::from_bytes(&key)?, value))
+            }),
+        )
+    }
+
+    pub(crate) fn delete_batch(&self, col: DBColumn, ops: HashSet<&[u8]>) -> Result<(), Error> {
+        let txn = self.env.begin_rw_txn()?;
+        let db = txn.open_db(Some(col.into()))?;
+        for key in ops {
+            txn.del(&db, key, None)?;
+        }
+        drop(db);
+        txn.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_if(
+        &self,
+        column: DBColumn,
+        mut f: impl FnMut(&[u8]) -> Result<bool, Error>,
+    ) -> Result<(), Error> {
+        let txn = self.env.begin_rw_txn()?;
+        let db = txn.open_db(Some(column.into()))?;
+
+        let mut to_delete = vec![];
+        {
+            let mut cursor = txn.cursor(&db)?;
+            let mut entry: Option<(Cow<'_, [u8]>, Cow<'_, [u8]>)> = cursor.first()?;
+            while let Some((key, value)) = entry {
+                if f(&value)? {
+                    to_delete.push(key.into_owned());
+                }
+                entry = cursor.next()?;
+            }
+        }
+
+        for key in to_delete {
+            txn.del(&db, key, None)?;
+        }
+        drop(db);
+        txn.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn snapshot(&self, path: &Path, progress: &SnapshotProgress) -> Result<(), Error> {
+        let target = Self::open(path)?;
+        let mut writer = SnapshotWriter::new(progress, |ops| target.do_atomically(ops));
+
+        let txn = self.env.begin_ro_txn()?;
+        for column in DBColumn::iter() {
+            let db = txn.open_db(Some(column.into()))?;
+            let mut cursor = txn.cursor(&db)?;
+            let mut entry: Option<(Cow<'_, [u8]>, Cow<'_, [u8]>)> = cursor.first()?;
+            while let Some((key, value)) = entry {
+                writer.put(column, &key, &value)?;
+                entry = cursor.next()?;
+            }
+        }
+        drop(txn);
+        writer.finish()?;
+        target.sync()
+    }
+}
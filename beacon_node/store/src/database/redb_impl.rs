@@ -1,3 +1,4 @@
+use crate::snapshot::{SnapshotProgress, SnapshotWriter};
 use crate::{ColumnIter, ColumnKeyIter, Key, metrics};
 use crate::{DBColumn, Error, KeyValueStoreOp};
 use parking_lot::RwLock;
@@ -321,4 +322,24 @@ impl<E: EthSpec> Redb<E> {
         tx.commit()?;
         Ok(())
     }
+
+    pub(crate) fn snapshot(&self, path: &Path, progress: &SnapshotProgress) -> Result<(), Error> {
+        // Read transactions don't borrow the database, so the lock can be released immediately.
+        let read_txn = self.db.read().begin_read()?;
+        let target = Self::open(path)?;
+        let mut writer = SnapshotWriter::new(progress, |ops| target.do_atomically(ops));
+
+        for column in DBColumn::iter() {
+            let table_definition: TableDefinition<'_, &[u8], &[u8]> =
+                TableDefinition::new(column.into());
+            let table = read_txn.open_table(table_definition)?;
+            let start: &[u8] = &[];
+            for entry in table.range(start..)? {
+                let (key, value) = entry?;
+                writer.put(column, key.value(), value.value())?;
+            }
+        }
+        writer.finish()?;
+        target.sync()
+    }
 }
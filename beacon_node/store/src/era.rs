@@ -0,0 +1,1159 @@
+//! Export and import of finalized history as era files.
+//!
+//! An era file is an [e2store] file holding the blocks of one `SLOTS_PER_HISTORICAL_ROOT` period
+//! followed by the state at the end of that period:
+//!
+//! ```text
+//! era := Version | CompressedSignedBeaconBlock* | CompressedBeaconState | SlotIndex(block)? | SlotIndex(state)
+//! ```
+//!
+//! Blocks and states are SSZ-encoded and compressed using the snappy framing format. Era `N` holds
+//! the blocks of slots `[(N - 1) * SLOTS_PER_HISTORICAL_ROOT, N * SLOTS_PER_HISTORICAL_ROOT)` and
+//! the state at slot `N * SLOTS_PER_HISTORICAL_ROOT`. Era 0 holds only the genesis state.
+//!
+//! Era files are imported with an [`EraReader`], which uses the slot indices to read the boundary
+//! state and then one block at a time, newest first. Blocks are verified and written in batches of
+//! `ERA_IMPORT_BATCH_SIZE`, so that memory use does not grow with the size of an era.
+//!
+//! Era files carry neither blobs, data columns nor execution payload envelopes. Import stops at
+//! the first block whose sidecars are still within the data availability window, or at the first
+//! Gloas block whose envelope is not already stored, so that they can be fetched by backfill sync
+//! instead.
+//!
+//! [e2store]: https://github.com/status-im/nimbus-eth2/blob/stable/docs/e2store.md
+use crate::hot_cold_store::HotColdDB;
+use crate::metrics;
+use crate::{AnchorInfo, DBColumn, Error as StoreError, ItemStore, KeyValueStoreOp};
+use snap::read::FrameDecoder;
+use snap::write::FrameEncoder;
+use ssz::Encode;
+use state_processing::per_block_processing::ParallelSignatureSets;
+use state_processing::signature_sets::{
+    Error as SignatureSetError, block_proposal_signature_set_from_parts,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry as HashMapEntry;
+use std::fs::{self, File};
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+use tree_hash::TreeHash;
+use types::{
+    BeaconState, ChainSpec, Epoch, EthSpec, FixedBytesExtended, Hash256, SignedBeaconBlock, Slot,
+};
+
+/// The type tag of an e2store entry.
+pub type EntryType = [u8; 2];
+
+pub const VERSION: EntryType = [0x65, 0x32];
+pub const COMPRESSED_SIGNED_BEACON_BLOCK: EntryType = [0x01, 0x00];
+pub const COMPRESSED_BEACON_STATE: EntryType = [0x02, 0x00];
+pub const SLOT_INDEX: EntryType = [0x69, 0x32];
+
+/// Length of an entry header: 2 bytes of type, 4 bytes of length and 2 reserved bytes.
+const HEADER_LEN: usize = 8;
+
+/// The number of blocks whose signatures are verified and which are written together when
+/// importing an era file.
+pub const ERA_IMPORT_BATCH_SIZE: usize = 256;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Snappy(std::io::Error),
+    SszDecode(ssz::DecodeError),
+    /// An entry header or body extends past the end of the file.
+    Truncated {
+        offset: usize,
+    },
+    /// An entry header has non-zero reserved bytes.
+    InvalidReservedBytes {
+        offset: usize,
+    },
+    EntryTooLarge(usize),
+    /// A slot index is malformed or does not match the entries it points to.
+    InvalidSlotIndex {
+        offset: u64,
+    },
+    /// The slot index points at a block from a different slot.
+    BlockIndexMismatch {
+        slot: Slot,
+        block_slot: Slot,
+    },
+    MissingVersion,
+    MissingState,
+    UnexpectedEntry {
+        entry_type: EntryType,
+    },
+    UnalignedState {
+        slot: Slot,
+    },
+    BlockOutsideEra {
+        slot: Slot,
+        era: u64,
+    },
+    UnorderedBlocks {
+        slot: Slot,
+        previous_slot: Slot,
+    },
+    MissingHistoricalRoot {
+        era: u64,
+    },
+    EraNumberMismatch {
+        file_era: u64,
+        state_era: u64,
+    },
+    /// The boundary state of the era has not been migrated to the freezer yet.
+    NotFinalized {
+        slot: Slot,
+        split_slot: Slot,
+    },
+    /// The boundary state of the era has been pruned or was never reconstructed.
+    StateUnavailable {
+        slot: Slot,
+    },
+    /// The blocks of the era predate the node's oldest block.
+    BlocksUnavailable {
+        oldest_block_slot: Slot,
+    },
+    /// A block does not match the block root recorded in the era's boundary state.
+    StateBlockRootMismatch {
+        slot: Slot,
+        block_root: Hash256,
+        state_block_root: Hash256,
+    },
+    /// A block does not extend the existing block history backwards, e.g. because an era file is
+    /// missing from the directory.
+    MismatchedBlockRoot {
+        slot: Slot,
+        block_root: Hash256,
+        expected_block_root: Hash256,
+    },
+    UnknownProposer {
+        proposer_index: u64,
+    },
+    InvalidProposerPubkey {
+        proposer_index: u64,
+    },
+    SignatureSet(SignatureSetError),
+    InvalidSignature,
+}
+
+/// A single type-length-value record of an e2store file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub entry_type: EntryType,
+    pub data: Vec<u8>,
+}
+
+impl Entry {
+    pub fn new(entry_type: EntryType, data: Vec<u8>) -> Self {
+        Self { entry_type, data }
+    }
+
+    /// Append the encoded entry to `buf`.
+    pub fn write_to(&self, buf: &mut Vec<u8>) -> Result<(), Error> {
+        let len =
+            u32::try_from(self.data.len()).map_err(|_| Error::EntryTooLarge(self.data.len()))?;
+        buf.extend_from_slice(&self.entry_type);
+        buf.extend_from_slice(&len.to_le_bytes());
+        buf.extend_from_slice(&[0, 0]);
+        buf.extend_from_slice(&self.data);
+        Ok(())
+    }
+}
+
+/// Split the contents of an e2store file into its entries.
+pub fn read_entries(bytes: &[u8]) -> Result<Vec<Entry>, Error> {
+    let mut entries = vec![];
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        let header = bytes
+            .get(offset..offset + HEADER_LEN)
+            .ok_or(Error::Truncated { offset })?;
+        let entry_type = [header[0], header[1]];
+        let len = u32::from_le_bytes([header[2], header[3], header[4], header[5]]) as usize;
+        if header[6..] != [0, 0] {
+            return Err(Error::InvalidReservedBytes { offset });
+        }
+
+        let start = offset + HEADER_LEN;
+        let data = bytes
+            .get(start..start + len)
+            .ok_or(Error::Truncated { offset })?;
+        entries.push(Entry::new(entry_type, data.to_vec()));
+        offset = start + len;
+    }
+
+    Ok(entries)
+}
+
+/// Read the entry at `offset` of an e2store file which is `len` bytes long.
+fn read_entry_at<R: Read + Seek>(reader: &mut R, len: u64, offset: u64) -> Result<Entry, Error> {
+    let truncated = || Error::Truncated {
+        offset: offset as usize,
+    };
+    let data_offset = offset
+        .checked_add(HEADER_LEN as u64)
+        .filter(|data_offset| *data_offset <= len)
+        .ok_or_else(truncated)?;
+
+    let mut header = [0; HEADER_LEN];
+    reader.seek(SeekFrom::Start(offset)).map_err(Error::Io)?;
+    reader.read_exact(&mut header).map_err(Error::Io)?;
+    let entry_type = [header[0], header[1]];
+    let data_len = u64::from(u32::from_le_bytes([
+        header[2], header[3], header[4], header[5],
+    ]));
+    if header[6..] != [0, 0] {
+        return Err(Error::InvalidReservedBytes {
+            offset: offset as usize,
+        });
+    }
+    if data_offset + data_len > len {
+        return Err(truncated());
+    }
+
+    let mut data = vec![0; data_len as usize];
+    reader.read_exact(&mut data).map_err(Error::Io)?;
+    Ok(Entry::new(entry_type, data))
+}
+
+/// Read the slot index which ends at `end`.
+///
+/// Returns the starting slot of the index, the absolute offset of the entry at each slot (`None`
+/// for empty slots) and the offset of the index itself.
+fn read_slot_index<R: Read + Seek>(
+    reader: &mut R,
+    len: u64,
+    end: u64,
+) -> Result<(Slot, Vec<Option<u64>>, u64), Error> {
+    let count_offset = end
+        .checked_sub(8)
+        .ok_or(Error::InvalidSlotIndex { offset: end })?;
+    let mut count = [0; 8];
+    reader
+        .seek(SeekFrom::Start(count_offset))
+        .map_err(Error::Io)?;
+    reader.read_exact(&mut count).map_err(Error::Io)?;
+    let count = u64::from_le_bytes(count);
+
+    let index_offset = count
+        .checked_add(2)
+        .and_then(|words| words.checked_mul(8))
+        .and_then(|data_len| data_len.checked_add(HEADER_LEN as u64))
+        .and_then(|entry_len| end.checked_sub(entry_len))
+        .ok_or(Error::InvalidSlotIndex {
+            offset: count_offset,
+        })?;
+    let entry = read_entry_at(reader, len, index_offset)?;
+    if entry.entry_type != SLOT_INDEX
+        || index_offset + HEADER_LEN as u64 + entry.data.len() as u64 != end
+    {
+        return Err(Error::InvalidSlotIndex {
+            offset: index_offset,
+        });
+    }
+
+    let mut words = entry.data.chunks_exact(8).map(|word| {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(word);
+        bytes
+    });
+    let starting_slot = words
+        .next()
+        .map(|word| Slot::new(u64::from_le_bytes(word)))
+        .ok_or(Error::InvalidSlotIndex {
+            offset: index_offset,
+        })?;
+    let offsets = words
+        .take(count as usize)
+        .map(|word| match i64::from_le_bytes(word) {
+            0 => Ok(None),
+            relative => index_offset
+                .checked_add_signed(relative)
+                .filter(|offset| *offset < index_offset)
+                .map(Some)
+                .ok_or(Error::InvalidSlotIndex {
+                    offset: index_offset,
+                }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((starting_slot, offsets, index_offset))
+}
+
+fn compress(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut compressed = vec![];
+    let mut encoder = FrameEncoder::new(&mut compressed);
+    encoder.write_all(bytes).map_err(Error::Snappy)?;
+    encoder.flush().map_err(Error::Snappy)?;
+    drop(encoder);
+    Ok(compressed)
+}
+
+fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut decompressed = vec![];
+    FrameDecoder::new(bytes)
+        .read_to_end(&mut decompressed)
+        .map_err(Error::Snappy)?;
+    Ok(decompressed)
+}
+
+/// Encode a slot index: the starting slot, one offset per slot and the number of offsets.
+///
+/// Offsets are relative to the start of the index entry itself, with zero marking an empty slot.
+fn slot_index_data(starting_slot: Slot, offsets: &[i64]) -> Vec<u8> {
+    let mut data = Vec::with_capacity((offsets.len() + 2) * 8);
+    data.extend_from_slice(&starting_slot.as_u64().to_le_bytes());
+    for offset in offsets {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(&(offsets.len() as u64).to_le_bytes());
+    data
+}
+
+/// Parse the era number from a file name of the form
+/// `<config-name>-<era-number>-<short-historical-root>.era`.
+pub fn parse_era_number(file_name: &str) -> Option<u64> {
+    let stem = file_name.strip_suffix(".era")?;
+    let mut parts = stem.rsplitn(3, '-');
+    let _short_historical_root = parts.next()?;
+    let era = parts.next()?;
+    let _config_name = parts.next()?;
+    era.parse().ok()
+}
+
+/// Return the era files in `dir`, sorted by era number.
+pub fn era_files_in_dir(dir: &Path) -> Result<Vec<(u64, PathBuf)>, Error> {
+    let mut files = fs::read_dir(dir)
+        .map_err(Error::Io)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(Error::Io)?
+        .into_iter()
+        .filter_map(|path| {
+            let era = parse_era_number(path.file_name()?.to_str()?)?;
+            Some((era, path))
+        })
+        .collect::<Vec<_>>();
+    files.sort_unstable_by_key(|(era, _)| *era);
+    Ok(files)
+}
+
+/// The decoded contents of an era file.
+#[derive(Debug, Clone)]
+pub struct EraFile<E: EthSpec> {
+    /// Blocks of the era in ascending slot order.
+    pub blocks: Vec<SignedBeaconBlock<E>>,
+    /// The state at the first slot after the era.
+    pub state: BeaconState<E>,
+}
+
+impl<E: EthSpec> EraFile<E> {
+    /// The era number, derived from the slot of the boundary state.
+    pub fn era(&self) -> u64 {
+        self.state.slot().as_u64() / E::slots_per_historical_root() as u64
+    }
+
+    /// The first slot covered by the blocks of this era.
+    fn start_slot(&self) -> Slot {
+        self.state
+            .slot()
+            .saturating_sub(E::slots_per_historical_root() as u64)
+    }
+
+    /// Check that the boundary state is aligned and that the blocks are ordered and within the
+    /// era.
+    fn validate(&self) -> Result<(), Error> {
+        let state_slot = self.state.slot();
+        if state_slot % E::slots_per_historical_root() as u64 != 0 {
+            return Err(Error::UnalignedState { slot: state_slot });
+        }
+
+        let start_slot = self.start_slot();
+        let mut previous_slot = None;
+        for block in &self.blocks {
+            let slot = block.slot();
+            if slot < start_slot || slot >= state_slot {
+                return Err(Error::BlockOutsideEra {
+                    slot,
+                    era: self.era(),
+                });
+            }
+            if let Some(previous_slot) = previous_slot
+                && slot <= previous_slot
+            {
+                return Err(Error::UnorderedBlocks {
+                    slot,
+                    previous_slot,
+                });
+            }
+            previous_slot = Some(slot);
+        }
+        Ok(())
+    }
+
+    /// The historical root summarising this era, or the genesis validators root for era 0.
+    fn short_historical_root(&self) -> Result<Hash256, Error> {
+        let era = self.era();
+        let Some(index) = era.checked_sub(1) else {
+            return Ok(self.state.genesis_validators_root());
+        };
+        let historical_roots = self.state.historical_roots();
+        if let Some(root) = historical_roots.get(index as usize) {
+            return Ok(*root);
+        }
+        self.state
+            .historical_summaries()
+            .ok()
+            .and_then(|summaries| summaries.get(index as usize - historical_roots.len()))
+            .map(|summary| summary.tree_hash_root())
+            .ok_or(Error::MissingHistoricalRoot { era })
+    }
+
+    /// The standard file name for this era: `<config-name>-<era-number>-<short-historical-root>.era`.
+    pub fn file_name(&self, config_name: &str) -> Result<String, Error> {
+        let short_root = self.short_historical_root()?.as_slice()[..4]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        Ok(format!("{config_name}-{:05}-{short_root}.era", self.era()))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.validate()?;
+
+        let mut buf = vec![];
+        Entry::new(VERSION, vec![]).write_to(&mut buf)?;
+
+        let start_slot = self.start_slot();
+        let mut block_positions = vec![None; E::slots_per_historical_root()];
+        for block in &self.blocks {
+            let index = (block.slot() - start_slot).as_usize();
+            block_positions[index] = Some(buf.len() as i64);
+            Entry::new(
+                COMPRESSED_SIGNED_BEACON_BLOCK,
+                compress(&block.as_ssz_bytes())?,
+            )
+            .write_to(&mut buf)?;
+        }
+
+        let state_position = buf.len() as i64;
+        Entry::new(
+            COMPRESSED_BEACON_STATE,
+            compress(&self.state.as_ssz_bytes())?,
+        )
+        .write_to(&mut buf)?;
+
+        if self.era() > 0 {
+            let index_position = buf.len() as i64;
+            let offsets = block_positions
+                .into_iter()
+                .map(|position| position.map_or(0, |position| position - index_position))
+                .collect::<Vec<_>>();
+            Entry::new(SLOT_INDEX, slot_index_data(start_slot, &offsets)).write_to(&mut buf)?;
+        }
+
+        let index_position = buf.len() as i64;
+        Entry::new(
+            SLOT_INDEX,
+            slot_index_data(self.state.slot(), &[state_position - index_position]),
+        )
+        .write_to(&mut buf)?;
+
+        Ok(buf)
+    }
+
+    /// Decode an era file. Slot indices are not required and are ignored, as are unknown entries.
+    pub fn from_bytes(bytes: &[u8], spec: &ChainSpec) -> Result<Self, Error> {
+        let mut entries = read_entries(bytes)?.into_iter();
+        if entries.next().map(|entry| entry.entry_type) != Some(VERSION) {
+            return Err(Error::MissingVersion);
+        }
+
+        let mut blocks = vec![];
+        let mut state = None;
+        for entry in entries {
+            match entry.entry_type {
+                COMPRESSED_SIGNED_BEACON_BLOCK if state.is_none() => {
+                    let block = SignedBeaconBlock::from_ssz_bytes(&decompress(&entry.data)?, spec)
+                        .map_err(Error::SszDecode)?;
+                    blocks.push(block);
+                }
+                COMPRESSED_BEACON_STATE if state.is_none() => {
+                    let decoded = BeaconState::from_ssz_bytes(&decompress(&entry.data)?, spec)
+                        .map_err(Error::SszDecode)?;
+                    state = Some(decoded);
+                }
+                COMPRESSED_SIGNED_BEACON_BLOCK | COMPRESSED_BEACON_STATE | VERSION => {
+                    return Err(Error::UnexpectedEntry {
+                        entry_type: entry.entry_type,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let era_file = Self {
+            blocks,
+            state: state.ok_or(Error::MissingState)?,
+        };
+        era_file.validate()?;
+        Ok(era_file)
+    }
+}
+
+/// Reads an era file using its slot indices, so that the boundary state and then one block at a
+/// time can be decoded without loading the whole file.
+pub struct EraReader<E: EthSpec, R = BufReader<File>> {
+    reader: R,
+    len: u64,
+    /// The state at the first slot after the era.
+    state: BeaconState<E>,
+    /// The slot and file offset of each block of the era, in ascending slot order.
+    blocks: Vec<(Slot, u64)>,
+}
+
+impl<E: EthSpec> EraReader<E> {
+    pub fn open(path: &Path, spec: &ChainSpec) -> Result<Self, Error> {
+        let file = File::open(path).map_err(Error::Io)?;
+        Self::new(BufReader::new(file), spec)
+    }
+}
+
+impl<E: EthSpec, R: Read + Seek> EraReader<E, R> {
+    /// Read the slot indices and decode the boundary state of an era file.
+    pub fn new(mut reader: R, spec: &ChainSpec) -> Result<Self, Error> {
+        let len = reader.seek(SeekFrom::End(0)).map_err(Error::Io)?;
+        if read_entry_at(&mut reader, len, 0)?.entry_type != VERSION {
+            return Err(Error::MissingVersion);
+        }
+
+        let (state_slot, state_offsets, state_index_offset) =
+            read_slot_index(&mut reader, len, len)?;
+        let [Some(state_offset)] = state_offsets[..] else {
+            return Err(Error::InvalidSlotIndex {
+                offset: state_index_offset,
+            });
+        };
+        let entry = read_entry_at(&mut reader, len, state_offset)?;
+        if entry.entry_type != COMPRESSED_BEACON_STATE {
+            return Err(Error::UnexpectedEntry {
+                entry_type: entry.entry_type,
+            });
+        }
+        let state = BeaconState::from_ssz_bytes(&decompress(&entry.data)?, spec)
+            .map_err(Error::SszDecode)?;
+        if state.slot() != state_slot {
+            return Err(Error::InvalidSlotIndex {
+                offset: state_index_offset,
+            });
+        }
+        if state_slot % E::slots_per_historical_root() as u64 != 0 {
+            return Err(Error::UnalignedState { slot: state_slot });
+        }
+
+        // Era 0 holds only the genesis state and has no block index.
+        let blocks = if let Some(start_slot) = state_slot
+            .as_u64()
+            .checked_sub(E::slots_per_historical_root() as u64)
+            .map(Slot::new)
+        {
+            let (index_slot, block_offsets, block_index_offset) =
+                read_slot_index(&mut reader, len, state_index_offset)?;
+            if index_slot != start_slot || block_offsets.len() != E::slots_per_historical_root() {
+                return Err(Error::InvalidSlotIndex {
+                    offset: block_index_offset,
+                });
+            }
+            (start_slot.as_u64()..)
+                .map(Slot::new)
+                .zip(block_offsets)
+                .filter_map(|(slot, offset)| Some((slot, offset?)))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        Ok(Self {
+            reader,
+            len,
+            state,
+            blocks,
+        })
+    }
+
+    /// The era number, derived from the slot of the boundary state.
+    pub fn era(&self) -> u64 {
+        self.state.slot().as_u64() / E::slots_per_historical_root() as u64
+    }
+
+    /// The state at the first slot after the era.
+    pub fn state(&self) -> &BeaconState<E> {
+        &self.state
+    }
+
+    /// The slots of the era which have a block, in ascending order.
+    pub fn block_slots(&self) -> Vec<Slot> {
+        self.blocks.iter().map(|(slot, _)| *slot).collect()
+    }
+
+    /// Read and decode the block at `slot`, if the era has one.
+    pub fn read_block(
+        &mut self,
+        slot: Slot,
+        spec: &ChainSpec,
+    ) -> Result<Option<SignedBeaconBlock<E>>, Error> {
+        let Ok(index) = self.blocks.binary_search_by_key(&slot, |(slot, _)| *slot) else {
+            return Ok(None);
+        };
+        let entry = read_entry_at(&mut self.reader, self.len, self.blocks[index].1)?;
+        if entry.entry_type != COMPRESSED_SIGNED_BEACON_BLOCK {
+            return Err(Error::UnexpectedEntry {
+                entry_type: entry.entry_type,
+            });
+        }
+        let block = SignedBeaconBlock::from_ssz_bytes(&decompress(&entry.data)?, spec)
+            .map_err(Error::SszDecode)?;
+        if block.slot() != slot {
+            return Err(Error::BlockIndexMismatch {
+                slot,
+                block_slot: block.slot(),
+            });
+        }
+        Ok(Some(block))
+    }
+}
+
+/// The result of importing a single era file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EraImport {
+    pub blocks_imported: usize,
+    /// Import stopped at this block because its blobs or data columns are still within the data
+    /// availability window, or its execution payload envelope is missing, and they must be
+    /// fetched over the network.
+    pub sidecars_required_at: Option<Slot>,
+}
+
+fn has_sidecars<E: EthSpec>(block: &SignedBeaconBlock<E>) -> bool {
+    block.num_expected_blobs() > 0
+        || block
+            .message()
+            .body()
+            .signed_execution_payload_bid()
+            .is_ok_and(|bid| !bid.message().blob_kzg_commitments().is_empty())
+}
+
+impl<E, Hot, Cold> HotColdDB<E, Hot, Cold>
+where
+    E: EthSpec,
+    Hot: ItemStore<E>,
+    Cold: ItemStore<E>,
+{
+    /// Load the blocks and boundary state of `era` from the database.
+    ///
+    /// Requires the boundary state to be in the freezer and available, and the full blocks of the
+    /// era, including execution payloads, to be stored.
+    pub fn export_era(&self, era: u64) -> Result<EraFile<E>, StoreError> {
+        let slots_per_historical_root = E::slots_per_historical_root() as u64;
+        let state_slot = Slot::new(era.saturating_mul(slots_per_historical_root));
+
+        let split_slot = self.get_split_slot();
+        if state_slot >= split_slot {
+            return Err(Error::NotFinalized {
+                slot: state_slot,
+                split_slot,
+            }
+            .into());
+        }
+
+        let (lower_limit, upper_limit) = self.get_historic_state_limits();
+        if state_slot > lower_limit && state_slot < upper_limit {
+            return Err(Error::StateUnavailable { slot: state_slot }.into());
+        }
+
+        let mut blocks = vec![];
+        if era > 0 {
+            let start_slot = state_slot - slots_per_historical_root;
+            let oldest_block_slot = self.get_oldest_block_slot();
+            if oldest_block_slot > start_slot {
+                return Err(Error::BlocksUnavailable { oldest_block_slot }.into());
+            }
+
+            let mut previous_block_root = None;
+            for slot in start_slot.as_u64()..state_slot.as_u64() {
+                let slot = Slot::new(slot);
+                let block_root = self
+                    .get_cold_block_root(slot)?
+                    .ok_or(Error::BlocksUnavailable { oldest_block_slot })?;
+                if previous_block_root == Some(block_root) {
+                    continue;
+                }
+                previous_block_root = Some(block_root);
+
+                // The genesis block is implied by the genesis state and is not included.
+                let block = self
+                    .get_full_block(&block_root)?
+                    .ok_or(StoreError::MissingBlock(block_root))?;
+                if block.slot() == slot && slot != self.spec.genesis_slot {
+                    blocks.push(block);
+                }
+            }
+        }
+
+        let state = self.load_cold_state_by_slot(state_slot)?;
+
+        Ok(EraFile { blocks, state })
+    }
+
+    /// Import the blocks of the era file at `path` which extend the block history backwards from
+    /// the anchor.
+    ///
+    /// Blocks are read newest first and imported in batches of `ERA_IMPORT_BATCH_SIZE`, updating
+    /// the anchor after each batch.
+    pub fn import_era_file(
+        &self,
+        era: u64,
+        path: &Path,
+        genesis_block_root: Hash256,
+        data_availability_boundary: Option<Epoch>,
+    ) -> Result<EraImport, StoreError> {
+        let mut reader = EraReader::<E>::open(path, &self.spec)?;
+        if reader.era() != era {
+            return Err(Error::EraNumberMismatch {
+                file_era: era,
+                state_era: reader.era(),
+            }
+            .into());
+        }
+
+        let oldest_block_slot = self.get_oldest_block_slot();
+        let slots = reader
+            .block_slots()
+            .into_iter()
+            .rev()
+            .filter(|slot| *slot < oldest_block_slot)
+            .collect::<Vec<_>>();
+
+        let mut import = EraImport::default();
+        for batch_slots in slots.chunks(ERA_IMPORT_BATCH_SIZE) {
+            let mut blocks = Vec::with_capacity(batch_slots.len());
+            for slot in batch_slots {
+                blocks.extend(reader.read_block(*slot, &self.spec)?);
+            }
+
+            let batch = self.import_era_blocks(
+                reader.state(),
+                &blocks,
+                genesis_block_root,
+                data_availability_boundary,
+            )?;
+            import.blocks_imported += batch.blocks_imported;
+            metrics::inc_counter_by(
+                &metrics::STORE_ERA_IMPORT_BLOCKS_TOTAL,
+                batch.blocks_imported as u64,
+            );
+            debug!(
+                era,
+                blocks = batch.blocks_imported,
+                oldest_block_slot = %self.get_oldest_block_slot(),
+                "Imported batch of era blocks"
+            );
+
+            if batch.sidecars_required_at.is_some() {
+                import.sidecars_required_at = batch.sidecars_required_at;
+                break;
+            }
+            if self.get_anchor_info().oldest_block_parent == Hash256::zero() {
+                break;
+            }
+        }
+
+        Ok(import)
+    }
+
+    /// Import `blocks`, given newest first, which extend the block history backwards from the
+    /// anchor.
+    ///
+    /// Blocks are checked against the block roots of the era's boundary state `state`, must chain
+    /// to the anchor's `oldest_block_parent` and must carry valid proposer signatures. They are
+    /// stored in the same way as blocks downloaded by backfill sync.
+    fn import_era_blocks(
+        &self,
+        state: &BeaconState<E>,
+        blocks: &[SignedBeaconBlock<E>],
+        genesis_block_root: Hash256,
+        data_availability_boundary: Option<Epoch>,
+    ) -> Result<EraImport, StoreError> {
+        let anchor = self.get_anchor_info();
+        let mut import = EraImport::default();
+
+        // Block history is already complete.
+        if anchor.oldest_block_parent == Hash256::zero() {
+            return Ok(import);
+        }
+
+        let mut expected_block_root = anchor.oldest_block_parent;
+        let mut prev_block_slot = anchor.oldest_block_slot;
+        let mut hot_batch = vec![];
+        let mut cold_batch = vec![];
+        let mut imported = vec![];
+
+        for block in blocks
+            .iter()
+            .filter(|block| block.slot() < anchor.oldest_block_slot)
+        {
+            let block_root = block.canonical_root();
+            let state_block_root = *state.get_block_root(block.slot())?;
+            if block_root != state_block_root {
+                return Err(Error::StateBlockRootMismatch {
+                    slot: block.slot(),
+                    block_root,
+                    state_block_root,
+                }
+                .into());
+            }
+            if block_root != expected_block_root {
+                return Err(Error::MismatchedBlockRoot {
+                    slot: block.slot(),
+                    block_root,
+                    expected_block_root,
+                }
+                .into());
+            }
+
+            // Gloas blocks are useless without their execution payload envelope, which is
+            // required irrespective of the data availability window.
+            let envelope_required = block.fork_name_unchecked().gloas_enabled()
+                && !self.payload_envelope_exists(&block_root)?;
+            if envelope_required
+                || (has_sidecars(block)
+                    && data_availability_boundary.is_some_and(|boundary| block.epoch() >= boundary))
+            {
+                import.sidecars_required_at = Some(block.slot());
+                break;
+            }
+
+            if self.config.prune_payloads {
+                self.blinded_block_as_kv_store_ops(
+                    &block_root,
+                    &block.clone_as_blinded(),
+                    &mut hot_batch,
+                );
+            } else {
+                self.block_as_kv_store_ops(&block_root, block.clone(), &mut hot_batch)?;
+            }
+
+            // Store block roots, including at all skip slots in the freezer DB.
+            for slot in block.slot().as_u64()..prev_block_slot.as_u64() {
+                cold_batch.push(KeyValueStoreOp::PutKeyValue(
+                    DBColumn::BeaconBlockRoots,
+                    slot.to_be_bytes().to_vec(),
+                    block_root.as_slice().to_vec(),
+                ));
+            }
+
+            prev_block_slot = block.slot();
+            expected_block_root = block.message().parent_root();
+            imported.push((block, block_root));
+
+            // If we've reached genesis, map the slots before the first block to the genesis block.
+            if expected_block_root == genesis_block_root {
+                let genesis_slot = self.spec.genesis_slot;
+                for slot in genesis_slot.as_u64()..prev_block_slot.as_u64() {
+                    cold_batch.push(KeyValueStoreOp::PutKeyValue(
+                        DBColumn::BeaconBlockRoots,
+                        slot.to_be_bytes().to_vec(),
+                        genesis_block_root.as_slice().to_vec(),
+                    ));
+                }
+                prev_block_slot = genesis_slot;
+                expected_block_root = Hash256::zero();
+                break;
+            }
+        }
+
+        if imported.is_empty() {
+            return Ok(import);
+        }
+
+        self.verify_era_signatures(state, &imported)?;
+
+        // Write the blocks before the block roots which point to them, and update the anchor
+        // last.
+        self.hot_db.do_atomically(hot_batch)?;
+        self.cold_db.do_atomically(cold_batch)?;
+
+        let new_anchor = AnchorInfo {
+            oldest_block_slot: prev_block_slot,
+            oldest_block_parent: expected_block_root,
+            ..anchor.clone()
+        };
+        let anchor_op = self.compare_and_set_anchor_info(anchor, new_anchor)?;
+        self.hot_db.do_atomically(vec![anchor_op])?;
+
+        import.blocks_imported = imported.len();
+        Ok(import)
+    }
+
+    /// Verify the proposer signatures of `blocks` using the validator set of the era's boundary
+    /// state.
+    fn verify_era_signatures(
+        &self,
+        state: &BeaconState<E>,
+        blocks: &[(&SignedBeaconBlock<E>, Hash256)],
+    ) -> Result<(), Error> {
+        // Only decompress the keys of the proposers, rather than the whole validator set.
+        let mut pubkeys = HashMap::new();
+        for (block, _) in blocks {
+            let proposer_index = block.message().proposer_index();
+            if let HashMapEntry::Vacant(entry) = pubkeys.entry(proposer_index) {
+                let pubkey = state
+                    .validators()
+                    .get(proposer_index as usize)
+                    .ok_or(Error::UnknownProposer { proposer_index })?
+                    .pubkey
+                    .decompress()
+                    .map_err(|_| Error::InvalidProposerPubkey { proposer_index })?;
+                entry.insert(pubkey);
+            }
+        }
+
+        let signature_set = blocks
+            .iter()
+            .map(|&(block, block_root)| {
+                block_proposal_signature_set_from_parts(
+                    block,
+                    Some(block_root),
+                    block.message().proposer_index(),
+                    &self.spec.fork_at_epoch(block.message().epoch()),
+                    state.genesis_validators_root(),
+                    |validator_index| pubkeys.get(&(validator_index as u64)).map(Cow::Borrowed),
+                    &self.spec,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::SignatureSet)
+            .map(ParallelSignatureSets::from)?;
+
+        if !signature_set.verify() {
+            return Err(Error::InvalidSignature);
+        }
+        Ok(())
+    }
+
+    /// Import the era files in `dir` which extend the block history backwards from the anchor,
+    /// newest first.
+    ///
+    /// Stops once block history is complete, or at the first block whose sidecars or execution
+    /// payload envelope must be fetched over the network. Returns the number of blocks imported.
+    pub fn import_era_dir(
+        &self,
+        dir: &Path,
+        genesis_block_root: Hash256,
+        data_availability_boundary: Option<Epoch>,
+    ) -> Result<usize, StoreError> {
+        let slots_per_historical_root = E::slots_per_historical_root() as u64;
+        let mut blocks_imported = 0;
+
+        let era_files = era_files_in_dir(dir)?;
+        info!(
+            dir = %dir.display(),
+            era_files = era_files.len(),
+            oldest_block_slot = %self.get_oldest_block_slot(),
+            "Importing era files"
+        );
+
+        for (era, path) in era_files.into_iter().rev() {
+            let anchor = self.get_anchor_info();
+            if anchor.oldest_block_parent == Hash256::zero() {
+                break;
+            }
+
+            // Era 0 has no blocks, and eras at or above the anchor have nothing to add.
+            let start_slot = Slot::new(era.saturating_sub(1) * slots_per_historical_root);
+            if era == 0 || start_slot >= anchor.oldest_block_slot {
+                debug!(era, path = %path.display(), "Skipping era file");
+                continue;
+            }
+
+            metrics::set_gauge(&metrics::STORE_ERA_IMPORT_ERA, era as i64);
+            let import =
+                self.import_era_file(era, &path, genesis_block_root, data_availability_boundary)?;
+            blocks_imported += import.blocks_imported;
+            info!(
+                era,
+                blocks = import.blocks_imported,
+                oldest_block_slot = %self.get_oldest_block_slot(),
+                "Imported era file"
+            );
+
+            if let Some(slot) = import.sidecars_required_at {
+                info!(
+                    %slot,
+                    "Stopping era import at block with sidecars or envelope to fetch from the network"
+                );
+                break;
+            }
+        }
+
+        Ok(blocks_imported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use types::{BeaconBlock, Eth1Data, ForkName, MinimalEthSpec, Signature};
+
+    type E = MinimalEthSpec;
+
+    fn spec() -> ChainSpec {
+        ForkName::Base.make_genesis_spec(E::default_spec())
+    }
+
+    fn block_at(slot: u64, spec: &ChainSpec) -> SignedBeaconBlock<E> {
+        let mut block = BeaconBlock::empty(spec);
+        *block.slot_mut() = Slot::new(slot);
+        SignedBeaconBlock::from_block(block, Signature::empty())
+    }
+
+    fn era_file(era: u64, block_slots: &[u64], spec: &ChainSpec) -> EraFile<E> {
+        let mut state = BeaconState::new(0, Eth1Data::default(), spec);
+        *state.slot_mut() = Slot::new(era * E::slots_per_historical_root() as u64);
+        EraFile {
+            blocks: block_slots
+                .iter()
+                .map(|&slot| block_at(slot, spec))
+                .collect(),
+            state,
+        }
+    }
+
+    #[test]
+    fn entries_roundtrip() {
+        let entries = vec![
+            Entry::new(VERSION, vec![]),
+            Entry::new(COMPRESSED_SIGNED_BEACON_BLOCK, vec![1, 2, 3]),
+            Entry::new([0xff, 0xff], vec![4; 300]),
+        ];
+        let mut buf = vec![];
+        for entry in &entries {
+            entry.write_to(&mut buf).unwrap();
+        }
+        assert_eq!(&buf[..HEADER_LEN], &[0x65, 0x32, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(read_entries(&buf).unwrap(), entries);
+
+        buf.pop();
+        assert!(matches!(
+            read_entries(&buf),
+            Err(Error::Truncated { offset: 19 })
+        ));
+    }
+
+    #[test]
+    fn era_file_roundtrip() {
+        let spec = spec();
+        let original = era_file(1, &[1, 2, 5, 63], &spec);
+        let bytes = original.to_bytes().unwrap();
+        let decoded = EraFile::<E>::from_bytes(&bytes, &spec).unwrap();
+        assert_eq!(decoded.era(), 1);
+        assert_eq!(decoded.blocks, original.blocks);
+        assert_eq!(decoded.state.slot(), original.state.slot());
+
+        // Two slot indices follow the state: one for the blocks and one for the state.
+        let entries = read_entries(&bytes).unwrap();
+        let types = entries
+            .iter()
+            .map(|entry| entry.entry_type)
+            .collect::<Vec<_>>();
+        assert_eq!(types[0], VERSION);
+        assert_eq!(types[5], COMPRESSED_BEACON_STATE);
+        assert_eq!(types[6..], [SLOT_INDEX, SLOT_INDEX]);
+
+        let block_index = &entries[6].data;
+        let slots_per_historical_root = E::slots_per_historical_root();
+        assert_eq!(block_index.len(), (slots_per_historical_root + 2) * 8);
+        // Slot 0 is empty, slot 1 points back at the first block.
+        assert_eq!(block_index[8..16], 0i64.to_le_bytes());
+        assert!(i64::from_le_bytes(block_index[16..24].try_into().unwrap()) < 0);
+    }
+
+    #[test]
+    fn era_reader_uses_slot_indices() {
+        let spec = spec();
+        let original = era_file(1, &[1, 2, 5, 63], &spec);
+        let bytes = original.to_bytes().unwrap();
+
+        let mut reader = EraReader::<E, _>::new(Cursor::new(bytes.clone()), &spec).unwrap();
+        assert_eq!(reader.era(), 1);
+        assert_eq!(reader.state().slot(), original.state.slot());
+        assert_eq!(reader.block_slots(), [1, 2, 5, 63].map(Slot::new).to_vec());
+        assert_eq!(
+            reader.read_block(Slot::new(5), &spec).unwrap().as_ref(),
+            Some(&original.blocks[2])
+        );
+        assert_eq!(reader.read_block(Slot::new(3), &spec).unwrap(), None);
+
+        let genesis_bytes = era_file(0, &[], &spec).to_bytes().unwrap();
+        let reader = EraReader::<E, _>::new(Cursor::new(genesis_bytes), &spec).unwrap();
+        assert_eq!(reader.era(), 0);
+        assert!(reader.block_slots().is_empty());
+
+        // A file whose state index has been cut off is rejected.
+        let truncated = bytes[..bytes.len() - 8].to_vec();
+        assert!(matches!(
+            EraReader::<E, _>::new(Cursor::new(truncated), &spec),
+            Err(Error::InvalidSlotIndex { .. } | Error::Truncated { .. })
+        ));
+    }
+
+    #[test]
+    fn genesis_era_has_no_block_index() {
+        let spec = spec();
+        let bytes = era_file(0, &[], &spec).to_bytes().unwrap();
+        let entries = read_entries(&bytes).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].entry_type, SLOT_INDEX);
+    }
+
+    #[test]
+    fn rejects_blocks_outside_era() {
+        let spec = spec();
+        let slots_per_historical_root = E::slots_per_historical_root() as u64;
+        assert!(matches!(
+            era_file(2, &[slots_per_historical_root - 1], &spec).to_bytes(),
+            Err(Error::BlockOutsideEra { era: 2, .. })
+        ));
+        assert!(matches!(
+            era_file(1, &[3, 2], &spec).to_bytes(),
+            Err(Error::UnorderedBlocks { .. })
+        ));
+    }
+
+    #[test]
+    fn file_name() {
+        let spec = spec();
+        let era = era_file(0, &[], &spec);
+        assert_eq!(
+            era.file_name("mainnet").unwrap(),
+            "mainnet-00000-00000000.era"
+        );
+        assert!(matches!(
+            era_file(3, &[], &spec).file_name("mainnet"),
+            Err(Error::MissingHistoricalRoot { era: 3 })
+        ));
+    }
+
+    #[test]
+    fn parse_file_names() {
+        assert_eq!(parse_era_number("mainnet-01234-abcdef01.era"), Some(1234));
+        assert_eq!(parse_era_number("my-devnet-00002-abcdef01.era"), Some(2));
+        assert_eq!(parse_era_number("mainnet-01234-abcdef01.era1"), None);
+        assert_eq!(parse_era_number("01234-abcdef01.era"), None);
+    }
+}
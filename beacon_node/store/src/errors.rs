@@ -1,8 +1,9 @@
-use crate::config::StoreConfigError;
+use crate::config::{DatabaseBackend, StoreConfigError};
 use crate::hot_cold_store::{HotColdDBError, StateSummaryIteratorError};
-use crate::{DBColumn, hdiff};
+use crate::{DBColumn, blob_archive, era, hdiff};
 use ssz::DecodeError;
 use state_processing::BlockReplayError;
+use std::path::PathBuf;
 use types::{BeaconStateError, EpochCacheError, Hash256, InconsistentFork, Slot, milhouse};
 
 pub(crate) type Result<T> = std::result::Result<T, Error>;
@@ -57,6 +58,18 @@ pub enum Error {
     InvalidBytes,
     InconsistentFork(InconsistentFork),
     RedbError(Box<redb::Error>),
+    #[cfg(feature = "lmdb")]
+    LmdbError(lmdb::Error),
+    #[cfg(feature = "mdbx")]
+    MdbxError(mdbx::Error),
+    /// The database at `path` was created by a different backend than the one configured.
+    DatabaseBackendMismatch {
+        path: PathBuf,
+        configured: DatabaseBackend,
+        on_disk: DatabaseBackend,
+    },
+    /// A database snapshot would overwrite the existing directory at `path`.
+    SnapshotTargetExists(PathBuf),
     CacheBuildError(EpochCacheError),
     MilhouseError(milhouse::Error),
     SszTypesError(ssz_types::Error),
@@ -68,6 +81,8 @@ pub enum Error {
         slot: Slot,
     },
     Hdiff(hdiff::Error),
+    Era(era::Error),
+    BlobArchive(blob_archive::Error),
     ForwardsIterInvalidColumn(DBColumn),
     ForwardsIterGap(DBColumn, Slot, Slot),
     StateShouldNotBeRequired(Slot),
@@ -156,6 +171,12 @@ impl From<hdiff::Error> for Error {
     }
 }
 
+impl From<era::Error> for Error {
+    fn from(e: era::Error) -> Self {
+        Self::Era(e)
+    }
+}
+
 impl From<BlockReplayError> for Error {
     fn from(e: BlockReplayError) -> Error {
         Error::BlockReplayError(e)
@@ -216,6 +237,20 @@ impl From<redb::SetDurabilityError> for Error {
     }
 }
 
+#[cfg(feature = "lmdb")]
+impl From<lmdb::Error> for Error {
+    fn from(e: lmdb::Error) -> Self {
+        Error::LmdbError(e)
+    }
+}
+
+#[cfg(feature = "mdbx")]
+impl From<mdbx::Error> for Error {
+    fn from(e: mdbx::Error) -> Self {
+        Error::MdbxError(e)
+    }
+}
+
 impl From<EpochCacheError> for Error {
     fn from(e: EpochCacheError) -> Error {
         Error::CacheBuildError(e)
@@ -1,7 +1,9 @@
 use crate::hdiff::{Error, HDiffBuffer};
+use crate::memory_budget::{MemoryBudget, StateMemoryTracker};
 use crate::metrics;
 use lru::LruCache;
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 use types::{BeaconState, ChainSpec, EthSpec, Slot};
 
 /// Holds a combination of finalized states in two formats:
@@ -15,6 +17,10 @@ use types::{BeaconState, ChainSpec, EthSpec, Slot};
 pub(crate) struct HistoricStateCache<E: EthSpec> {
     hdiff_buffers: LruCache<Slot, HDiffBuffer>,
     states: LruCache<Slot, BeaconState<E>>,
+    /// Memory used by `states`, only tracked if there is a memory budget.
+    state_memory: Option<StateMemoryTracker<Slot>>,
+    /// Byte budget limiting the cache instead of its capacities, if any.
+    memory_budget: Option<Arc<MemoryBudget>>,
 }
 
 #[derive(Debug, Default)]
@@ -22,6 +28,8 @@ pub(crate) struct Metrics {
     pub num_hdiff: usize,
     pub num_state: usize,
     pub hdiff_byte_size: usize,
+    /// Only measured if there is a memory budget.
+    pub state_byte_size: Option<usize>,
 }
 
 impl<E: EthSpec> HistoricStateCache<E> {
@@ -32,6 +40,19 @@ impl<E: EthSpec> HistoricStateCache<E> {
         Self {
             hdiff_buffers: LruCache::new(hdiff_buffer_cache_size),
             states: LruCache::new(state_cache_size),
+            state_memory: None,
+            memory_budget: None,
+        }
+    }
+
+    /// Create a cache limited by the estimated memory used by its states and `HDiffBuffer`s
+    /// rather than by their number.
+    pub(crate) fn with_memory_budget(memory_budget: Arc<MemoryBudget>) -> Self {
+        Self {
+            hdiff_buffers: LruCache::unbounded(),
+            states: LruCache::unbounded(),
+            state_memory: Some(StateMemoryTracker::default()),
+            memory_budget: Some(memory_budget),
         }
     }
 
@@ -50,7 +71,7 @@ impl<E: EthSpec> HistoricStateCache<E> {
             );
             let cloned = buffer.clone();
             drop(timer);
-            self.hdiff_buffers.put(slot, cloned);
+            self.put_hdiff_buffer(slot, cloned);
             Some(buffer)
         } else {
             None
@@ -66,7 +87,7 @@ impl<E: EthSpec> HistoricStateCache<E> {
             Ok(Some(state.clone()))
         } else if let Some(buffer) = self.hdiff_buffers.get(&slot) {
             let state = buffer.as_state(spec)?;
-            self.states.put(slot, state.clone());
+            self.put_state(slot, state.clone());
             Ok(Some(state))
         } else {
             Ok(None)
@@ -74,11 +95,22 @@ impl<E: EthSpec> HistoricStateCache<E> {
     }
 
     pub(crate) fn put_state(&mut self, slot: Slot, state: BeaconState<E>) {
-        self.states.put(slot, state);
+        // `push` returns the entry replaced at `slot` or evicted by a bounded cache.
+        let removed = self.states.push(slot, state);
+        if let Some(state_memory) = &mut self.state_memory {
+            if let Some((removed_slot, _)) = removed {
+                state_memory.untrack(&removed_slot);
+            }
+            if let Some(state) = self.states.peek(&slot) {
+                state_memory.track(slot, state);
+            }
+        }
+        self.evict_to_memory_budget();
     }
 
     pub(crate) fn put_hdiff_buffer(&mut self, slot: Slot, buffer: HDiffBuffer) {
         self.hdiff_buffers.put(slot, buffer);
+        self.evict_to_memory_budget();
     }
 
     pub(crate) fn put_both(&mut self, slot: Slot, state: BeaconState<E>, buffer: HDiffBuffer) {
@@ -86,16 +118,61 @@ impl<E: EthSpec> HistoricStateCache<E> {
         self.put_hdiff_buffer(slot, buffer);
     }
 
-    pub(crate) fn metrics(&self) -> Metrics {
-        let hdiff_byte_size = self
-            .hdiff_buffers
+    fn state_mem_usage(&self) -> Option<usize> {
+        self.state_memory.as_ref().map(StateMemoryTracker::bytes)
+    }
+
+    fn hdiff_buffer_mem_usage(&self) -> usize {
+        self.hdiff_buffers
             .iter()
             .map(|(_, buffer)| buffer.size())
-            .sum::<usize>();
+            .sum()
+    }
+
+    /// Evict states, and then `HDiffBuffer`s, until the cache fits in its memory budget.
+    ///
+    /// Entries are evicted LRU, keeping at least the most recently used state and buffer even if
+    /// they exceed the budget.
+    fn evict_to_memory_budget(&mut self) {
+        let Some(memory_budget) = self.memory_budget.clone() else {
+            return;
+        };
+        if let Some(state_memory) = &mut self.state_memory
+            && state_memory.rebuild_due()
+        {
+            state_memory.rebuild(self.states.iter().map(|(slot, state)| (*slot, state)));
+        }
+
+        let mut hdiff_buffer_mem_usage = self.hdiff_buffer_mem_usage();
+        loop {
+            let mem_usage = self.state_mem_usage().unwrap_or(0) + hdiff_buffer_mem_usage;
+            memory_budget.set_cold_usage(mem_usage);
+            if mem_usage <= memory_budget.cold_allowance() {
+                break;
+            }
+
+            if self.states.len() > 1 {
+                if let Some((slot, _)) = self.states.pop_lru()
+                    && let Some(state_memory) = &mut self.state_memory
+                {
+                    state_memory.untrack(&slot);
+                }
+            } else if self.hdiff_buffers.len() > 1 {
+                if let Some((_, buffer)) = self.hdiff_buffers.pop_lru() {
+                    hdiff_buffer_mem_usage = hdiff_buffer_mem_usage.saturating_sub(buffer.size());
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn metrics(&self) -> Metrics {
         Metrics {
             num_hdiff: self.hdiff_buffers.len(),
             num_state: self.states.len(),
-            hdiff_byte_size,
+            hdiff_byte_size: self.hdiff_buffer_mem_usage(),
+            state_byte_size: self.state_mem_usage(),
         }
     }
 }
@@ -103,7 +180,9 @@ impl<E: EthSpec> HistoricStateCache<E> {
 #[cfg(test)]
 mod tests {
     use super::{HDiffBuffer, HistoricStateCache};
+    use crate::memory_budget::MemoryBudget;
     use std::num::NonZeroUsize;
+    use std::sync::Arc;
     use types::{BeaconState, ChainSpec, Eth1Data, MinimalEthSpec, Slot};
 
     type E = MinimalEthSpec;
@@ -130,6 +209,7 @@ mod tests {
         assert_eq!(m.num_hdiff, 0);
         assert_eq!(m.num_state, 0);
         assert_eq!(m.hdiff_byte_size, 0);
+        assert_eq!(m.state_byte_size, None);
     }
 
     #[test]
@@ -279,4 +359,25 @@ mod tests {
         assert_eq!(m.num_state, 1);
         assert!(cache.get_state(Slot::new(5), &spec).unwrap().is_some());
     }
+
+    #[test]
+    fn memory_budget_evicts_lru() {
+        let mut unlimited =
+            HistoricStateCache::<E>::with_memory_budget(Arc::new(MemoryBudget::new(usize::MAX)));
+        unlimited.put_both(Slot::new(1), make_state(1), make_buffer(1));
+        let m = unlimited.metrics();
+        let entry_size = m.state_byte_size.unwrap() + m.hdiff_byte_size;
+
+        // The historic cache may use half of the budget.
+        let budget = Arc::new(MemoryBudget::new(4 * entry_size));
+        let mut cache = HistoricStateCache::<E>::with_memory_budget(budget.clone());
+        for slot in 1..6 {
+            cache.put_both(Slot::new(slot), make_state(slot), make_buffer(slot));
+        }
+
+        let m = cache.metrics();
+        assert!(m.state_byte_size.unwrap() + m.hdiff_byte_size <= budget.limit() / 2);
+        assert!(m.num_state < 5);
+        assert!(cache.get_hdiff_buffer(Slot::new(5)).is_some());
+    }
 }
@@ -1,9 +1,11 @@
+use crate::blob_archive::BlobArchive;
 use crate::config::{OnDiskStoreConfig, StoreConfig};
 use crate::database::interface::BeaconNodeBackend;
 use crate::forwards_iter::{HybridForwardsBlockRootsIterator, HybridForwardsStateRootsIterator};
 use crate::hdiff::{HDiff, HDiffBuffer, HierarchyConfig, HierarchyModuli, StorageStrategy};
 use crate::historic_state_cache::HistoricStateCache;
 use crate::iter::{BlockRootsIterator, ParentRootBlockIterator, RootsIterator};
+use crate::memory_budget::MemoryBudget;
 use crate::memory_store::MemoryStore;
 use crate::metadata::{
     ANCHOR_INFO_KEY, ANCHOR_UNINITIALIZED, AnchorInfo, BLOB_INFO_KEY, BlobInfo,
@@ -14,7 +16,7 @@ use crate::metadata::{
 use crate::state_cache::{PutStateOutcome, StateCache};
 use crate::{
     BlobSidecarListFromRoot, DBColumn, DatabaseBlock, Error, ItemStore, KeyValueStoreOp, StoreItem,
-    StoreOp, get_data_column_key,
+    StoreOp, get_data_column_key, get_execution_proof_key,
     metrics::{self, COLD_METRIC, HOT_METRIC},
     parse_data_column_key,
 };
@@ -42,9 +44,10 @@ use types::data_column_sidecar::{ColumnIndex, DataColumnSidecar, DataColumnSidec
 use types::{
     AbstractExecPayload, BeaconState, BeaconStateError, BlindedPayload, BlobSidecar,
     BlobSidecarList, ChainSpec, Epoch, EthSpec, ExecutionPayload, ExecutionPayloadGloas,
-    FixedBytesExtended, ForkName, ForkVersionDecode, Hash256, LightClientUpdate, MerkleProof,
-    SignedBeaconBlock, SignedBlindedBeaconBlock, SignedBlindedExecutionPayloadEnvelope,
-    SignedExecutionPayloadEnvelope, Slot, SyncCommittee, Unsigned, VariableList,
+    ExecutionProof, FixedBytesExtended, ForkName, ForkVersionDecode, Hash256, LightClientUpdate,
+    MerkleProof, SignedBeaconBlock, SignedBlindedBeaconBlock,
+    SignedBlindedExecutionPayloadEnvelope, SignedExecutionPayloadEnvelope, Slot, SyncCommittee,
+    Unsigned, VariableList, execution_proof_subnet_id::MAX_EXECUTION_PROOF_SUBNETS,
 };
 use zstd::{Decoder, Encoder};
 
@@ -71,6 +74,8 @@ pub struct HotColdDB<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> {
     pub cold_db: Cold,
     /// Database containing blobs. If None, store falls back to use `cold_db`.
     pub blobs_db: Cold,
+    /// Archive that pruned blobs and data columns are moved to, if configured.
+    pub(crate) blob_archive: Option<BlobArchive>,
     /// Hot database containing duplicated but quick-to-access recent data.
     ///
     /// The hot database also contains all blocks.
@@ -86,6 +91,9 @@ pub struct HotColdDB<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> {
     /// This cache is never pruned. It is only populated in response to historical queries from the
     /// HTTP API.
     historic_state_cache: Mutex<HistoricStateCache<E>>,
+    /// Serialises the read-modify-write of side objects, so that concurrent writes for the same
+    /// slot don't lose each other's objects.
+    pub(crate) side_objects_lock: Mutex<()>,
     /// Chain spec.
     pub spec: Arc<ChainSpec>,
     /// Mere vessel for E.
@@ -231,6 +239,33 @@ pub enum HotColdDBError {
     Rollback,
 }
 
+/// Create the hot and historic state caches.
+///
+/// If `config.state_cache_memory_budget` is set, both caches share it instead of being limited by
+/// the configured entry counts.
+fn new_state_caches<E: EthSpec>(config: &StoreConfig) -> (StateCache<E>, HistoricStateCache<E>) {
+    match config.state_cache_memory_budget {
+        Some(budget) => {
+            let memory_budget = Arc::new(MemoryBudget::new(budget.get()));
+            (
+                StateCache::with_memory_budget(config.state_cache_headroom, memory_budget.clone()),
+                HistoricStateCache::with_memory_budget(memory_budget),
+            )
+        }
+        None => (
+            StateCache::new(
+                config.state_cache_size,
+                config.state_cache_headroom,
+                config.hot_hdiff_buffer_cache_size,
+            ),
+            HistoricStateCache::new(
+                config.cold_hdiff_buffer_cache_size,
+                config.historic_state_cache_size,
+            ),
+        ),
+    }
+}
+
 impl<E: EthSpec> HotColdDB<E, MemoryStore<E>, MemoryStore<E>> {
     pub fn open_ephemeral(
         config: StoreConfig,
@@ -239,6 +274,13 @@ impl<E: EthSpec> HotColdDB<E, MemoryStore<E>, MemoryStore<E>> {
         config.verify::<E>()?;
 
         let hierarchy = config.hierarchy_config.to_moduli()?;
+        let blob_archive = config
+            .blob_archive_dir
+            .as_deref()
+            .map(BlobArchive::open)
+            .transpose()?;
+
+        let (state_cache, historic_state_cache) = new_state_caches(&config);
 
         // NOTE: Anchor slot is initialized to 0, which is only valid for new DBs. We shouldn't
         // be reusing memory stores, but if we want to do that we should redo this.
@@ -249,19 +291,14 @@ impl<E: EthSpec> HotColdDB<E, MemoryStore<E>, MemoryStore<E>> {
             data_column_info: RwLock::new(DataColumnInfo::default()),
             cold_db: MemoryStore::open(),
             blobs_db: MemoryStore::open(),
+            blob_archive,
             hot_db: MemoryStore::open(),
             block_cache: NonZeroUsize::new(config.block_cache_size)
                 .map(BlockCache::new)
                 .map(Mutex::new),
-            state_cache: Mutex::new(StateCache::new(
-                config.state_cache_size,
-                config.state_cache_headroom,
-                config.hot_hdiff_buffer_cache_size,
-            )),
-            historic_state_cache: Mutex::new(HistoricStateCache::new(
-                config.cold_hdiff_buffer_cache_size,
-                config.historic_state_cache_size,
-            )),
+            state_cache: Mutex::new(state_cache),
+            historic_state_cache: Mutex::new(historic_state_cache),
+            side_objects_lock: Mutex::new(()),
             config,
             hierarchy,
             spec,
@@ -291,31 +328,34 @@ impl<E: EthSpec> HotColdDB<E, BeaconNodeBackend<E>, BeaconNodeBackend<E>> {
         let hierarchy = config.hierarchy_config.to_moduli()?;
 
         debug!(?hot_path, "Opening database");
-        let hot_db = BeaconNodeBackend::open(hot_path)?;
+        let hot_db = BeaconNodeBackend::open(&config, hot_path)?;
 
         let anchor_info = RwLock::new(Self::load_anchor_info(&hot_db)?);
         debug!(?anchor_info, "Loaded anchor info");
 
+        let blob_archive = config
+            .blob_archive_dir
+            .as_deref()
+            .map(BlobArchive::open)
+            .transpose()?;
+
+        let (state_cache, historic_state_cache) = new_state_caches(&config);
+
         let db = HotColdDB {
             split: RwLock::new(Split::default()),
             anchor_info,
             blob_info: RwLock::new(BlobInfo::default()),
             data_column_info: RwLock::new(DataColumnInfo::default()),
-            blobs_db: BeaconNodeBackend::open(blobs_db_path)?,
-            cold_db: BeaconNodeBackend::open(cold_path)?,
+            blobs_db: BeaconNodeBackend::open(&config, blobs_db_path)?,
+            cold_db: BeaconNodeBackend::open(&config, cold_path)?,
+            blob_archive,
             hot_db,
             block_cache: NonZeroUsize::new(config.block_cache_size)
                 .map(BlockCache::new)
                 .map(Mutex::new),
-            state_cache: Mutex::new(StateCache::new(
-                config.state_cache_size,
-                config.state_cache_headroom,
-                config.hot_hdiff_buffer_cache_size,
-            )),
-            historic_state_cache: Mutex::new(HistoricStateCache::new(
-                config.cold_hdiff_buffer_cache_size,
-                config.historic_state_cache_size,
-            )),
+            state_cache: Mutex::new(state_cache),
+            historic_state_cache: Mutex::new(historic_state_cache),
+            side_objects_lock: Mutex::new(()),
             config,
             hierarchy,
             spec,
@@ -513,6 +553,14 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
             &metrics::STORE_BEACON_STATE_CACHE_SIZE,
             state_cache.len() as i64,
         );
+        // States are only measured when there is a memory budget.
+        if let Some(state_mem_usage) = state_cache.state_mem_usage() {
+            metrics::set_gauge_vec(
+                &metrics::STORE_BEACON_STATE_CACHE_BYTE_SIZE,
+                HOT_METRIC,
+                state_mem_usage as i64,
+            );
+        }
         metrics::set_gauge_vec(
             &metrics::STORE_BEACON_HDIFF_BUFFER_CACHE_SIZE,
             HOT_METRIC,
@@ -528,6 +576,19 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
             &metrics::STORE_BEACON_HISTORIC_STATE_CACHE_SIZE,
             hsc_metrics.num_state as i64,
         );
+        if let Some(state_byte_size) = hsc_metrics.state_byte_size {
+            metrics::set_gauge_vec(
+                &metrics::STORE_BEACON_STATE_CACHE_BYTE_SIZE,
+                COLD_METRIC,
+                state_byte_size as i64,
+            );
+        }
+        metrics::set_gauge(
+            &metrics::STORE_BEACON_STATE_CACHE_MEMORY_BUDGET,
+            self.config
+                .state_cache_memory_budget
+                .map_or(0, NonZeroUsize::get) as i64,
+        );
         metrics::set_gauge_vec(
             &metrics::STORE_BEACON_HDIFF_BUFFER_CACHE_SIZE,
             COLD_METRIC,
@@ -818,6 +879,43 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
             .key_exists(DBColumn::BeaconEnvelope, block_root.as_slice())
     }
 
+    /// Store a verified execution proof for stateless validation.
+    ///
+    /// Proofs are keyed by block root and subnet id, so a later proof for the same subnet
+    /// replaces the earlier one.
+    pub fn put_execution_proof(&self, proof: &ExecutionProof) -> Result<(), Error> {
+        self.hot_db.put_bytes(
+            DBColumn::BeaconExecutionProof,
+            &get_execution_proof_key(&proof.block_root, *proof.subnet_id),
+            &proof.as_ssz_bytes(),
+        )
+    }
+
+    /// Load the execution proof for `block_root` on `subnet_id`, if one has been stored.
+    pub fn get_execution_proof(
+        &self,
+        block_root: &Hash256,
+        subnet_id: u64,
+    ) -> Result<Option<Arc<ExecutionProof>>, Error> {
+        match self.hot_db.get_bytes(
+            DBColumn::BeaconExecutionProof,
+            &get_execution_proof_key(block_root, subnet_id),
+        )? {
+            Some(bytes) => Ok(Some(Arc::new(ExecutionProof::from_ssz_bytes(&bytes)?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Load all stored execution proofs for `block_root`, ordered by subnet id.
+    pub fn get_execution_proofs(
+        &self,
+        block_root: &Hash256,
+    ) -> Result<Vec<Arc<ExecutionProof>>, Error> {
+        (0..MAX_EXECUTION_PROOF_SUBNETS)
+            .filter_map(|subnet_id| self.get_execution_proof(block_root, subnet_id).transpose())
+            .collect()
+    }
+
     /// Get the sync committee branch for the given block root
     /// Note: we only persist sync committee branches for checkpoint slots
     pub fn get_sync_committee_branch(
@@ -1488,6 +1586,15 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
                     ));
                 }
 
+                StoreOp::DeleteExecutionProofs(block_root) => {
+                    for subnet_id in 0..MAX_EXECUTION_PROOF_SUBNETS {
+                        key_value_batch.push(KeyValueStoreOp::DeleteKey(
+                            DBColumn::BeaconExecutionProof,
+                            get_execution_proof_key(&block_root, subnet_id),
+                        ));
+                    }
+                }
+
                 StoreOp::DeleteSyncCommitteeBranch(block_root) => {
                     key_value_batch.push(KeyValueStoreOp::DeleteKey(
                         DBColumn::SyncCommitteeBranch,
@@ -1653,6 +1760,7 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
                     | StoreOp::DeleteExecutionPayload(_)
                     | StoreOp::PutPayloadEnvelope(_, _)
                     | StoreOp::DeletePayloadEnvelope(_)
+                    | StoreOp::DeleteExecutionProofs(_)
                     | StoreOp::DeleteSyncCommitteeBranch(_)
                     | StoreOp::KeyValueOp(_) => (),
                 }
@@ -2691,10 +2799,7 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
             return Ok(blobs.into());
         }
 
-        match self
-            .blobs_db
-            .get_bytes(DBColumn::BeaconBlob, block_root.as_slice())?
-        {
+        match self.get_blobs_db_bytes(DBColumn::BeaconBlob, block_root.as_slice())? {
             Some(ref blobs_bytes) => {
                 // We insert a VariableList of BlobSidecars into the db, but retrieve
                 // a plain vec since we don't know the length limit of the list without
@@ -2721,9 +2826,24 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
     }
 
     /// Fetch all keys in the data_column column with prefix `block_root`
+    ///
+    /// If the block's data columns have been moved to the blob archive, their keys are returned
+    /// instead.
     pub fn get_data_column_keys(&self, block_root: Hash256) -> Result<Vec<ColumnIndex>, Error> {
+        let column_indices = self.data_column_keys_in(DBColumn::BeaconDataColumn, block_root)?;
+        if column_indices.is_empty() && self.blob_archive.is_some() {
+            return self.data_column_keys_in(DBColumn::BeaconDataColumnArchive, block_root);
+        }
+        Ok(column_indices)
+    }
+
+    fn data_column_keys_in(
+        &self,
+        column: DBColumn,
+        block_root: Hash256,
+    ) -> Result<Vec<ColumnIndex>, Error> {
         self.blobs_db
-            .iter_column_from::<Vec<u8>>(DBColumn::BeaconDataColumn, block_root.as_slice())
+            .iter_column_from::<Vec<u8>>(column, block_root.as_slice())
             .take_while(|res| {
                 res.as_ref()
                     .is_ok_and(|(key, _)| key.starts_with(block_root.as_slice()))
@@ -2759,7 +2879,7 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
             return Ok(Some(data_column));
         }
 
-        match self.blobs_db.get_bytes(
+        match self.get_blobs_db_bytes(
             DBColumn::BeaconDataColumn,
             &get_data_column_key(block_root, column_index),
         )? {
@@ -3370,6 +3490,9 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
 
     /// Try to prune blobs and data columns older than the data availability boundary.
     ///
+    /// If a blob archive is configured, pruned blobs and data columns are moved to it rather than
+    /// being deleted.
+    ///
     /// Blobs from the epoch `data_availability_boundary - blob_prune_margin_epochs` are retained.
     /// This epoch is an _exclusive_ endpoint for the pruning process.
     ///
@@ -3468,6 +3591,8 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
         // vec won't go beyond 1GB. We can probably optimise this out eventually.
         let mut removed_block_roots = vec![];
         let mut blobs_db_ops = vec![];
+        // Sidecars to move into the archive instead of deleting, if one is configured.
+        let mut archive_keys = vec![];
 
         // Iterate blocks backwards until we reach a block for which we've already pruned
         // blobs/columns.
@@ -3500,7 +3625,11 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
             for db_key in db_keys {
                 if self.blobs_db.key_exists(db_column, &db_key)? {
                     data_stored_for_block = true;
-                    blobs_db_ops.push(KeyValueStoreOp::DeleteKey(db_column, db_key));
+                    if self.blob_archive.is_some() {
+                        archive_keys.push((slot, db_column, db_key));
+                    } else {
+                        blobs_db_ops.push(KeyValueStoreOp::DeleteKey(db_column, db_key));
+                    }
                 }
             }
 
@@ -3529,6 +3658,12 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
             }
         }
 
+        // Move to the archive, oldest first. If archiving is interrupted, the next run still
+        // reaches the newer sidecars which weren't archived before stopping at archived ones.
+        if let Some(archive) = &self.blob_archive {
+            self.archive_sidecars(archive, archive_keys.into_iter().rev())?;
+        }
+
         // Remove from disk.
         if !blobs_db_ops.is_empty() {
             debug!(
@@ -3686,6 +3821,19 @@ pub fn migrate_database<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>>(
             block_root.as_slice().to_vec(),
         ));
 
+        // Record the validator history from the first state of each epoch. The history doesn't
+        // depend on the rest of the freezer, so it can be written straight away.
+        let mut history_state = None;
+        if store.records_validator_history(slot) {
+            let state: BeaconState<E> = store
+                .get_hot_state(&state_root, false)?
+                .ok_or(HotColdDBError::MissingStateToFreeze(state_root))?;
+            let mut history_ops = vec![];
+            store.store_validator_history(&state, &mut history_ops)?;
+            store.cold_db.do_atomically(history_ops)?;
+            history_state = Some(state);
+        }
+
         // Do not try to store states if a restore point is yet to be stored, or will never be
         // stored (see `STATE_UPPER_LIMIT_NO_RETAIN`). Make an exception for the genesis state
         // which always needs to be copied from the hot DB to the freezer and should not be deleted.
@@ -3710,9 +3858,12 @@ pub fn migrate_database<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>>(
         } else {
             // This is some state that we want to migrate to the freezer db.
             // There is no reason to cache this state.
-            let state: BeaconState<E> = store
-                .get_hot_state(&state_root, false)?
-                .ok_or(HotColdDBError::MissingStateToFreeze(state_root))?;
+            let state: BeaconState<E> = match history_state {
+                Some(state) => state,
+                None => store
+                    .get_hot_state(&state_root, false)?
+                    .ok_or(HotColdDBError::MissingStateToFreeze(state_root))?,
+            };
 
             store.store_cold_state(&state_root, &state, &mut cold_db_state_ops)?;
         }
@@ -7,26 +7,33 @@
 //!
 //! Provides a simple API for storing/retrieving all types that sometimes needs type-hints. See
 //! tests for implementation examples.
+pub mod blob_archive;
 mod blob_sidecar_list_from_root;
 pub mod config;
 mod consensus_context;
+pub mod era;
 pub mod errors;
 mod forwards_iter;
 pub mod hdiff;
 pub(crate) mod historic_state_cache;
 pub mod hot_cold_store;
 mod impls;
+pub(crate) mod memory_budget;
 mod memory_store;
 pub mod metadata;
 pub mod metrics;
 mod reconstruct;
+pub mod side_objects;
+pub mod snapshot;
 pub(crate) mod state_cache;
+pub mod validator_history;
+pub mod verify;
 
 pub mod database;
 pub mod iter;
 
 pub use self::blob_sidecar_list_from_root::BlobSidecarListFromRoot;
-pub use self::config::StoreConfig;
+pub use self::config::{DatabaseBackend, StoreConfig};
 pub use self::consensus_context::OnDiskConsensusContext;
 pub use self::hot_cold_store::{HotColdDB, HotStateSummary, Split};
 pub use self::memory_store::MemoryStore;
@@ -34,7 +41,9 @@ pub use crate::metadata::BlobInfo;
 pub use errors::Error;
 pub use metadata::AnchorInfo;
 pub use metrics::scrape_for_metrics;
+use snapshot::SnapshotProgress;
 use std::collections::HashSet;
+use std::path::Path;
 use std::sync::Arc;
 use strum::{EnumIter, EnumString, IntoStaticStr};
 pub use types::{
@@ -45,6 +54,8 @@ pub use types::{
 };
 
 const DATA_COLUMN_DB_KEY_SIZE: usize = 32 + 8;
+const EXECUTION_PROOF_DB_KEY_SIZE: usize = 32 + 8;
+const VALIDATOR_HISTORY_KEY_SIZE: usize = 8 + 8;
 
 pub type ColumnIter<'a, K> = Box<dyn Iterator<Item = Result<(K, Vec<u8>), Error>> + 'a>;
 pub type ColumnKeyIter<'a, K> = Box<dyn Iterator<Item = Result<K, Error>> + 'a>;
@@ -104,6 +115,11 @@ pub trait KeyValueStore<E: EthSpec>: Sync + Send + Sized + 'static {
         column: DBColumn,
         f: impl FnMut(&[u8]) -> Result<bool, Error>,
     ) -> Result<(), Error>;
+
+    /// Copy every column from a single read transaction into a new database at `path`.
+    ///
+    /// Writes to this database may continue while the copy is made.
+    fn snapshot(&self, path: &Path, progress: &SnapshotProgress) -> Result<(), Error>;
 }
 
 pub trait Key: Sized + 'static {
@@ -149,6 +165,12 @@ pub(crate) fn get_data_column_key(block_root: &Hash256, column_index: ColumnInde
     result
 }
 
+pub(crate) fn get_execution_proof_key(block_root: &Hash256, subnet_id: u64) -> Vec<u8> {
+    let mut result = block_root.as_slice().to_vec();
+    result.extend_from_slice(&subnet_id.to_le_bytes());
+    result
+}
+
 pub(crate) fn parse_data_column_key(data: Vec<u8>) -> Result<(Hash256, ColumnIndex), Error> {
     if data.len() != DBColumn::BeaconDataColumn.key_size() {
         return Err(Error::InvalidKey(format!(
@@ -238,12 +260,13 @@ pub enum StoreOp<'a, E: EthSpec> {
     DeleteState(Hash256, Option<Slot>),
     DeleteExecutionPayload(Hash256),
     DeletePayloadEnvelope(Hash256),
+    DeleteExecutionProofs(Hash256),
     DeleteSyncCommitteeBranch(Hash256),
     KeyValueOp(KeyValueStoreOp),
 }
 
 /// A unique column identifier.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr, EnumString, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, IntoStaticStr, EnumString, EnumIter)]
 pub enum DBColumn {
     /// For data related to the database itself.
     #[strum(serialize = "bma")]
@@ -268,8 +291,44 @@ pub enum DBColumn {
     BeaconEnvelope,
     #[strum(serialize = "bdc")]
     BeaconDataColumn,
+    /// Locations of blobs moved from `BeaconBlob` into the blob archive.
+    ///
+    /// - Key: `Hash256` block root.
+    /// - Value: SSZ-encoded `ArchiveLocation`.
+    #[strum(serialize = "bla")]
+    BeaconBlobArchive,
+    /// Locations of data columns moved from `BeaconDataColumn` into the blob archive.
+    ///
+    /// - Key: `Hash256` block root ++ 8-byte little-endian column index, as in `BeaconDataColumn`.
+    /// - Value: SSZ-encoded `ArchiveLocation`.
+    #[strum(serialize = "dca")]
+    BeaconDataColumnArchive,
+    /// Verified execution proofs for stateless validation.
+    ///
+    /// - Key: `Hash256` block root ++ 8-byte little-endian subnet id.
+    /// - Value: SSZ-encoded `ExecutionProof`.
+    #[strum(serialize = "bep")]
+    BeaconExecutionProof,
+    /// Execution proofs of finalized blocks, retained for `side_object_retention_epochs`.
+    ///
+    /// - Key: 8-byte big-endian slot of the block.
+    /// - Value: SSZ-encoded list of `ExecutionProof`.
+    #[strum(serialize = "bph")]
+    BeaconExecutionProofHistory,
     #[strum(serialize = "bdi")]
     BeaconDataColumnCustodyInfo,
+    /// Signed inclusion lists, retained for `side_object_retention_epochs`.
+    ///
+    /// - Key: 8-byte big-endian slot.
+    /// - Value: SSZ-encoded list of `SignedInclusionList`.
+    #[strum(serialize = "bil")]
+    BeaconInclusionListHistory,
+    /// Aggregated payload attestations, retained for `side_object_retention_epochs`.
+    ///
+    /// - Key: 8-byte big-endian slot.
+    /// - Value: SSZ-encoded list of `PayloadAttestation`.
+    #[strum(serialize = "bpa")]
+    BeaconPayloadAttestationHistory,
     /// For full `BeaconState`s in the hot database (finalized or fork-boundary states).
     ///
     /// DEPRECATED.
@@ -381,6 +440,18 @@ pub enum DBColumn {
     /// For helping persist eagerly computed light client bootstrap data
     #[strum(serialize = "scm")]
     SyncCommittee,
+    /// Per-epoch builder reliability counters for the `/vibehouse/analysis/builders` API.
+    ///
+    /// - Key: 8-byte big-endian epoch, so that iteration is in epoch order.
+    /// - Value: SSZ-encoded list of `BuilderReliability`, one per builder.
+    #[strum(serialize = "brl")]
+    BuilderReliability,
+    /// Per-epoch summaries of every validator for the `/vibehouse/analysis/validator_history` API.
+    ///
+    /// - Key: 8-byte big-endian validator chunk index ++ 8-byte big-endian epoch.
+    /// - Value: compressed SSZ-encoded `ValidatorHistoryChunk`.
+    #[strum(serialize = "vhi")]
+    ValidatorHistory,
     /// The dummy table is used to force the db to sync
     #[strum(serialize = "dmy")]
     Dummy,
@@ -411,6 +482,7 @@ impl DBColumn {
             | Self::BeaconBlock
             | Self::BeaconState
             | Self::BeaconBlob
+            | Self::BeaconBlobArchive
             | Self::BeaconEnvelope
             | Self::BeaconStateSummary
             | Self::BeaconStateHotDiff
@@ -441,8 +513,14 @@ impl DBColumn {
             | Self::SyncCommittee
             | Self::SyncCommitteeBranch
             | Self::LightClientUpdate
+            | Self::BuilderReliability
+            | Self::BeaconExecutionProofHistory
+            | Self::BeaconInclusionListHistory
+            | Self::BeaconPayloadAttestationHistory
             | Self::Dummy => 8,
-            Self::BeaconDataColumn => DATA_COLUMN_DB_KEY_SIZE,
+            Self::ValidatorHistory => VALIDATOR_HISTORY_KEY_SIZE,
+            Self::BeaconDataColumn | Self::BeaconDataColumnArchive => DATA_COLUMN_DB_KEY_SIZE,
+            Self::BeaconExecutionProof => EXECUTION_PROOF_DB_KEY_SIZE,
         }
     }
 }
@@ -474,13 +552,15 @@ mod tests {
     use crate::database::interface::BeaconNodeBackend;
 
     use super::{
-        ColumnIndex, DATA_COLUMN_DB_KEY_SIZE, DBColumn, Error, Hash256, ItemStore, Key,
-        KeyValueStore, KeyValueStoreOp, MemoryStore, MinimalEthSpec, StoreItem, get_col_from_key,
-        get_data_column_key, get_key_for_col, parse_data_column_key,
+        ColumnIndex, DATA_COLUMN_DB_KEY_SIZE, DBColumn, DatabaseBackend,
+        EXECUTION_PROOF_DB_KEY_SIZE, Error, Hash256, ItemStore, Key, KeyValueStore,
+        KeyValueStoreOp, MemoryStore, MinimalEthSpec, StoreConfig, StoreItem, get_col_from_key,
+        get_data_column_key, get_execution_proof_key, get_key_for_col, parse_data_column_key,
     };
     use ssz::{Decode, Encode};
     use ssz_derive::{Decode, Encode};
     use std::collections::HashSet;
+    use strum::IntoEnumIterator;
     use tempfile::tempdir;
     use types::FixedBytesExtended;
 
@@ -528,7 +608,7 @@ mod tests {
     fn simplediskdb() {
         let dir = tempdir().unwrap();
         let path = dir.path();
-        let store = BeaconNodeBackend::open(path).unwrap();
+        let store = BeaconNodeBackend::open(&StoreConfig::default(), path).unwrap();
 
         test_impl(store);
     }
@@ -568,7 +648,7 @@ mod tests {
         let dir = tempdir().unwrap();
         // Leak the dir so it doesn't get cleaned up while the store is open.
         let path = Box::leak(Box::new(dir)).path().to_path_buf();
-        BeaconNodeBackend::open(&path).unwrap()
+        BeaconNodeBackend::open(&StoreConfig::default(), &path).unwrap()
     }
 
     #[test]
@@ -692,6 +772,239 @@ mod tests {
         }
     }
 
+    /// Iterate a column spanning several read chunks on every compiled-in backend.
+    #[test]
+    fn disk_iter_column_from_all_backends() {
+        let col = DBColumn::BeaconBlock;
+        let keys: Vec<Hash256> = (0u64..2500).map(Hash256::from_low_u64_be).collect();
+
+        for backend in DatabaseBackend::iter() {
+            let dir = tempdir().unwrap();
+            let config = StoreConfig {
+                backend,
+                ..StoreConfig::default()
+            };
+            let store = BeaconNodeBackend::<MinimalEthSpec>::open(&config, dir.path()).unwrap();
+            assert_eq!(store.backend(), backend);
+
+            let ops = keys
+                .iter()
+                .map(|k| KeyValueStoreOp::PutKeyValue(col, k.as_slice().to_vec(), k[31..].to_vec()))
+                .collect();
+            store.do_atomically(ops).unwrap();
+
+            let items = store
+                .iter_column_from::<Hash256>(col, keys[500].as_slice())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(items.len(), 2000, "{backend}");
+            for ((key, value), expected) in items.iter().zip(&keys[500..]) {
+                assert_eq!(key, expected, "{backend}");
+                assert_eq!(value.as_slice(), &expected[31..], "{backend}");
+            }
+
+            let key_count = store.iter_column_keys::<Hash256>(col).count();
+            assert_eq!(key_count, 2500, "{backend}");
+        }
+    }
+
+    #[test]
+    fn backend_is_detected_from_data_file() {
+        let dir = tempdir().unwrap();
+        assert_eq!(DatabaseBackend::detect(dir.path()), None);
+
+        let config = StoreConfig::default();
+        let _store = BeaconNodeBackend::<MinimalEthSpec>::open(&config, dir.path()).unwrap();
+        assert_eq!(
+            DatabaseBackend::detect(dir.path()),
+            Some(DatabaseBackend::Redb)
+        );
+    }
+
+    #[cfg(any(feature = "lmdb", feature = "mdbx"))]
+    #[test]
+    fn open_rejects_database_from_other_backend() {
+        let dir = tempdir().unwrap();
+        drop(
+            BeaconNodeBackend::<MinimalEthSpec>::open(&StoreConfig::default(), dir.path()).unwrap(),
+        );
+
+        let other = DatabaseBackend::iter()
+            .find(|backend| *backend != DatabaseBackend::Redb)
+            .unwrap();
+        let config = StoreConfig {
+            backend: other,
+            ..StoreConfig::default()
+        };
+        assert!(matches!(
+            BeaconNodeBackend::<MinimalEthSpec>::open(&config, dir.path()),
+            Err(Error::DatabaseBackendMismatch { .. })
+        ));
+    }
+
+    /// Open an empty database using `backend`, returning the directory holding it.
+    #[cfg(any(feature = "lmdb", feature = "mdbx"))]
+    fn open_backend(
+        backend: DatabaseBackend,
+    ) -> (tempfile::TempDir, BeaconNodeBackend<MinimalEthSpec>) {
+        let dir = tempdir().unwrap();
+        let config = StoreConfig {
+            backend,
+            ..StoreConfig::default()
+        };
+        let store = BeaconNodeBackend::open(&config, dir.path()).unwrap();
+        (dir, store)
+    }
+
+    /// Check iteration across read chunk boundaries, `delete_if` and `do_atomically` for a
+    /// backend which reads columns in chunks.
+    #[cfg(any(feature = "lmdb", feature = "mdbx"))]
+    fn check_chunked_backend(backend: DatabaseBackend) {
+        use crate::database::chunked_iter::ITER_CHUNK_SIZE;
+
+        let (_dir, store) = open_backend(backend);
+        let col = DBColumn::BeaconBlock;
+        let num_keys = 2 * ITER_CHUNK_SIZE as u64 + 1;
+        let keys: Vec<Hash256> = (0..num_keys).map(Hash256::from_low_u64_be).collect();
+
+        // Puts and deletes in a single batch, across columns.
+        let mut ops: Vec<_> = keys
+            .iter()
+            .map(|k| KeyValueStoreOp::PutKeyValue(col, k.as_slice().to_vec(), k[24..].to_vec()))
+            .collect();
+        ops.push(KeyValueStoreOp::PutKeyValue(
+            DBColumn::BeaconState,
+            keys[0].as_slice().to_vec(),
+            b"state".to_vec(),
+        ));
+        ops.push(KeyValueStoreOp::DeleteKey(
+            DBColumn::BeaconMeta,
+            keys[0].as_slice().to_vec(),
+        ));
+        store.do_atomically(ops).unwrap();
+        assert_eq!(
+            store
+                .get_bytes(DBColumn::BeaconState, keys[0].as_slice())
+                .unwrap()
+                .unwrap(),
+            b"state"
+        );
+
+        // A batch which updates one key, deletes another and deletes a key which is not present.
+        let extra_key = Hash256::from_low_u64_be(num_keys);
+        store
+            .do_atomically(vec![
+                KeyValueStoreOp::PutKeyValue(col, keys[1].as_slice().to_vec(), b"updated".to_vec()),
+                KeyValueStoreOp::DeleteKey(col, keys[2].as_slice().to_vec()),
+                KeyValueStoreOp::DeleteKey(col, extra_key.as_slice().to_vec()),
+            ])
+            .unwrap();
+        assert_eq!(
+            store.get_bytes(col, keys[1].as_slice()).unwrap().unwrap(),
+            b"updated"
+        );
+        assert!(!store.key_exists(col, keys[2].as_slice()).unwrap());
+        store
+            .put_bytes(col, keys[1].as_slice(), &keys[1][24..])
+            .unwrap();
+        store
+            .put_bytes(col, keys[2].as_slice(), &keys[2][24..])
+            .unwrap();
+
+        // Every key is returned exactly once and in order, whether iteration starts at, just
+        // before or just after a chunk boundary.
+        for start in [0, ITER_CHUNK_SIZE - 1, ITER_CHUNK_SIZE, ITER_CHUNK_SIZE + 1] {
+            let items = store
+                .iter_column_from::<Hash256>(col, keys[start].as_slice())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(items.len(), keys.len() - start, "{backend} from {start}");
+            for ((key, value), expected) in items.iter().zip(&keys[start..]) {
+                assert_eq!(key, expected, "{backend} from {start}");
+                assert_eq!(value.as_slice(), &expected[24..], "{backend} from {start}");
+            }
+
+            let item_keys = store
+                .iter_column_keys_from::<Hash256>(col, keys[start].as_slice())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(item_keys, keys[start..], "{backend} from {start}");
+        }
+
+        // Starting between two keys resumes at the next key.
+        let mut between = keys[ITER_CHUNK_SIZE].as_slice().to_vec();
+        between.push(0);
+        let first = store
+            .iter_column_keys_from::<Hash256>(col, &between)
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(first, keys[ITER_CHUNK_SIZE + 1], "{backend}");
+
+        // `delete_if` across more than one chunk.
+        store
+            .delete_if(col, |value| {
+                Ok(value.last().is_some_and(|byte| byte % 2 == 0))
+            })
+            .unwrap();
+        let remaining = store
+            .iter_column_keys::<Hash256>(col)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let expected: Vec<Hash256> = keys.iter().filter(|k| k[31] % 2 == 1).copied().collect();
+        assert_eq!(remaining, expected, "{backend}");
+        assert!(
+            store
+                .key_exists(DBColumn::BeaconState, keys[0].as_slice())
+                .unwrap(),
+            "{backend}"
+        );
+    }
+
+    /// Opening a database with a backend other than the one which created it fails, in both
+    /// directions.
+    #[cfg(any(feature = "lmdb", feature = "mdbx"))]
+    fn check_backend_mismatch(backend: DatabaseBackend) {
+        for other in DatabaseBackend::iter().filter(|other| *other != backend) {
+            for (created_by, opened_with) in [(backend, other), (other, backend)] {
+                let (dir, store) = open_backend(created_by);
+                drop(store);
+
+                let config = StoreConfig {
+                    backend: opened_with,
+                    ..StoreConfig::default()
+                };
+                match BeaconNodeBackend::<MinimalEthSpec>::open(&config, dir.path()) {
+                    Err(Error::DatabaseBackendMismatch {
+                        configured,
+                        on_disk,
+                        ..
+                    }) => {
+                        assert_eq!(configured, opened_with);
+                        assert_eq!(on_disk, created_by);
+                    }
+                    Err(e) => panic!("{created_by} opened with {opened_with}: {e:?}"),
+                    Ok(_) => panic!("{created_by} opened with {opened_with} should fail"),
+                }
+                assert_eq!(DatabaseBackend::detect(dir.path()), Some(created_by));
+            }
+        }
+    }
+
+    #[cfg(feature = "lmdb")]
+    #[test]
+    fn lmdb_backend() {
+        check_chunked_backend(DatabaseBackend::Lmdb);
+        check_backend_mismatch(DatabaseBackend::Lmdb);
+    }
+
+    #[cfg(feature = "mdbx")]
+    #[test]
+    fn mdbx_backend() {
+        check_chunked_backend(DatabaseBackend::Mdbx);
+        check_backend_mismatch(DatabaseBackend::Mdbx);
+    }
+
     #[test]
     fn disk_iter_column_from() {
         let store = open_disk_store();
@@ -892,6 +1205,16 @@ mod tests {
         assert_eq!(parsed_index, column_index);
     }
 
+    #[test]
+    fn get_execution_proof_key_layout() {
+        let block_root = Hash256::repeat_byte(0xcd);
+        let key = get_execution_proof_key(&block_root, 1);
+        assert_eq!(key.len(), EXECUTION_PROOF_DB_KEY_SIZE);
+        assert_eq!(key.len(), DBColumn::BeaconExecutionProof.key_size());
+        assert_eq!(&key[..32], block_root.as_slice());
+        assert_eq!(&key[32..], &1u64.to_le_bytes());
+    }
+
     #[test]
     fn parse_data_column_key_wrong_length() {
         let result = parse_data_column_key(vec![0u8; 10]);
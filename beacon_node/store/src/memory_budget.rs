@@ -0,0 +1,159 @@
+//! Memory accounting for the in-memory state caches.
+//!
+//! When `StoreConfig::state_cache_memory_budget` is set, the hot `StateCache` and the
+//! `HistoricStateCache` share a single byte budget. Each cache estimates its own usage, publishes it
+//! here, and evicts entries until its usage fits in the part of the budget left by the other. The
+//! historic cache is limited to half of the budget, so that queries for old states can't starve the
+//! hot cache which is required for block processing.
+//!
+//! Usage is only published by a cache while it is locked for an insertion, so the total can
+//! temporarily exceed the budget by the growth of one cache since the other last evicted. States
+//! are only measured while a budget is set.
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use types::milhouse::mem::MemoryTracker;
+use types::{BeaconState, EthSpec};
+
+/// Byte budget shared by the hot and historic state caches.
+#[derive(Debug)]
+pub struct MemoryBudget {
+    limit: usize,
+    hot_usage: AtomicUsize,
+    cold_usage: AtomicUsize,
+}
+
+impl MemoryBudget {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            hot_usage: AtomicUsize::new(0),
+            cold_usage: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Bytes the hot caches may use, given the last reported usage of the historic caches.
+    pub(crate) fn hot_allowance(&self) -> usize {
+        self.limit
+            .saturating_sub(self.cold_usage.load(Ordering::Relaxed))
+    }
+
+    /// Bytes the historic caches may use, given the last reported usage of the hot caches.
+    pub(crate) fn cold_allowance(&self) -> usize {
+        self.limit
+            .saturating_sub(self.hot_usage.load(Ordering::Relaxed))
+            .min(self.limit / 2)
+    }
+
+    pub(crate) fn set_hot_usage(&self, bytes: usize) {
+        self.hot_usage.store(bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_cold_usage(&self, bytes: usize) {
+        self.cold_usage.store(bytes, Ordering::Relaxed);
+    }
+}
+
+/// Estimate of the memory used by a set of cached states, counting subtrees shared between them
+/// only once.
+///
+/// Adding a state only measures the subtrees it doesn't share with the states already tracked, and
+/// removing a state subtracts the size measured when it was added. Subtrees which a removed state
+/// shared with a later state were only counted for the removed state, so the estimate drifts low as
+/// states are replaced. Once as many states have been removed as remain, the estimate is rebuilt
+/// from the remaining states by `rebuild`, which also stops the tracker from growing without bound.
+pub(crate) struct StateMemoryTracker<K> {
+    tracker: MemoryTracker,
+    sizes: HashMap<K, usize>,
+    bytes: usize,
+    /// Number of states removed since the estimate was last rebuilt.
+    removed: usize,
+}
+
+impl<K> Default for StateMemoryTracker<K> {
+    fn default() -> Self {
+        Self {
+            tracker: MemoryTracker::default(),
+            sizes: HashMap::new(),
+            bytes: 0,
+            removed: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq> StateMemoryTracker<K> {
+    /// Account for a state added to the cache under `key`.
+    pub(crate) fn track<E: EthSpec>(&mut self, key: K, state: &BeaconState<E>) {
+        if let Entry::Vacant(entry) = self.sizes.entry(key) {
+            let size = self.tracker.track_item(state).differential_size;
+            self.bytes += size;
+            entry.insert(size);
+        }
+    }
+
+    /// Account for the state under `key` being removed from the cache.
+    pub(crate) fn untrack(&mut self, key: &K) {
+        if let Some(size) = self.sizes.remove(key) {
+            self.bytes = self.bytes.saturating_sub(size);
+            self.removed += 1;
+        }
+    }
+
+    /// Estimated memory used by the tracked states.
+    pub(crate) fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns `true` if the estimate should be rebuilt from the remaining states.
+    pub(crate) fn rebuild_due(&self) -> bool {
+        self.removed > 0 && self.removed >= self.sizes.len()
+    }
+
+    /// Recompute the estimate from `states`, which must be all of the states currently cached.
+    pub(crate) fn rebuild<'a, E: EthSpec>(
+        &mut self,
+        states: impl Iterator<Item = (K, &'a BeaconState<E>)>,
+    ) {
+        *self = Self::default();
+        for (key, state) in states {
+            self.track(key, state);
+        }
+    }
+}
+
+impl<K> fmt::Debug for StateMemoryTracker<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateMemoryTracker")
+            .field("states", &self.sizes.len())
+            .field("bytes", &self.bytes)
+            .field("removed", &self.removed)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowances() {
+        let budget = MemoryBudget::new(1000);
+        assert_eq!(budget.hot_allowance(), 1000);
+        assert_eq!(budget.cold_allowance(), 500);
+
+        budget.set_hot_usage(800);
+        assert_eq!(budget.cold_allowance(), 200);
+
+        budget.set_cold_usage(300);
+        assert_eq!(budget.hot_allowance(), 700);
+
+        budget.set_hot_usage(2000);
+        assert_eq!(budget.cold_allowance(), 0);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::database::redb_impl::Redb;
+use crate::snapshot::{SnapshotProgress, SnapshotWriter};
 use crate::{
     ColumnIter, ColumnKeyIter, DBColumn, Error, ItemStore, Key, KeyValueStore, KeyValueStoreOp,
     errors::Error as DBError, get_key_for_col, hot_cold_store::BytesKey,
@@ -5,6 +7,8 @@ use crate::{
 use parking_lot::RwLock;
 use std::collections::{BTreeMap, HashSet};
 use std::marker::PhantomData;
+use std::path::Path;
+use strum::IntoEnumIterator;
 use types::EthSpec;
 
 type DBMap = BTreeMap<BytesKey, Vec<u8>>;
@@ -148,6 +152,22 @@ impl<E: EthSpec> KeyValueStore<E> for MemoryStore<E> {
         });
         Ok(())
     }
+
+    /// Memory stores are copied to a redb database, the default on-disk backend.
+    fn snapshot(&self, path: &Path, progress: &SnapshotProgress) -> Result<(), Error> {
+        let db = self.db.read().clone();
+        let target = Redb::<E>::open(path)?;
+        let mut writer = SnapshotWriter::new(progress, |ops| target.do_atomically(ops));
+        for column in DBColumn::iter() {
+            for (key, value) in &db {
+                if let Some(key) = key.remove_column_variable(column) {
+                    writer.put(column, key, value)?;
+                }
+            }
+        }
+        writer.finish()?;
+        target.sync()
+    }
 }
 
 impl<E: EthSpec> ItemStore<E> for MemoryStore<E> {}
@@ -133,6 +133,22 @@ pub(crate) static STORE_BEACON_STATE_LOWER_LIMIT: LazyLock<Result<IntGauge>> =
             "Current anchor info state_lower_limit value",
         )
     });
+/*
+ * Era Import
+ */
+pub(crate) static STORE_ERA_IMPORT_BLOCKS_TOTAL: LazyLock<Result<IntCounter>> =
+    LazyLock::new(|| {
+        try_create_int_counter(
+            "store_era_import_blocks_total",
+            "Number of historic blocks imported from era files",
+        )
+    });
+pub(crate) static STORE_ERA_IMPORT_ERA: LazyLock<Result<IntGauge>> = LazyLock::new(|| {
+    try_create_int_gauge(
+        "store_era_import_era",
+        "Era number of the era file most recently started to be imported",
+    )
+});
 /*
  * Beacon State
  */
@@ -309,6 +325,21 @@ pub(crate) static STORE_BEACON_HDIFF_BUFFER_CACHE_BYTE_SIZE: LazyLock<Result<Int
             &["db"],
         )
     });
+pub(crate) static STORE_BEACON_STATE_CACHE_BYTE_SIZE: LazyLock<Result<IntGaugeVec>> =
+    LazyLock::new(|| {
+        try_create_int_gauge_vec(
+            "store_beacon_state_cache_byte_size",
+            "Estimated memory consumed by states cached in memory, accounting for shared subtrees",
+            &["db"],
+        )
+    });
+pub(crate) static STORE_BEACON_STATE_CACHE_MEMORY_BUDGET: LazyLock<Result<IntGauge>> =
+    LazyLock::new(|| {
+        try_create_int_gauge(
+            "store_beacon_state_cache_memory_budget",
+            "Memory budget in bytes shared by the state and hdiff buffer caches, if set",
+        )
+    });
 pub(crate) static STORE_BEACON_STATE_FREEZER_COMPRESS_TIME: LazyLock<Result<Histogram>> =
     LazyLock::new(|| {
         try_create_histogram(
@@ -188,6 +188,7 @@ where
 
                 // Stage state for storage in freezer DB.
                 self.store_cold_state(&state_root, &state, &mut io_batch)?;
+                self.store_validator_history(&state, &mut io_batch)?;
 
                 let batch_complete =
                     num_blocks.is_some_and(|n_blocks| slot == lower_limit_slot + n_blocks as u64);
@@ -0,0 +1,165 @@
+//! Inclusion lists, payload attestations and execution proofs retained for later audits.
+//!
+//! These objects are normally discarded once fork choice, block production and stateless
+//! validation are done with them. When `StoreConfig::side_object_retention_epochs` is non-zero they
+//! are kept in the hot database instead, in columns keyed by the 8-byte big-endian slot, until they
+//! are more than that many epochs older than the finalized checkpoint:
+//!
+//! - Inclusion lists and payload attestations are written by the beacon chain as they leave its
+//!   in-memory pools, a few slots after their slot.
+//! - Execution proofs are moved out of `BeaconExecutionProof` when their block is finalized. The
+//!   proofs of blocks which are not canonical are deleted as usual.
+use crate::hot_cold_store::HotColdDB;
+use crate::{DBColumn, Error, ItemStore, KeyValueStore, KeyValueStoreOp};
+use ssz::{Decode, Encode};
+use types::{
+    Epoch, EthSpec, ExecutionProof, Hash256, PayloadAttestation, SignedInclusionList, Slot,
+};
+
+/// The columns holding side objects, all keyed by slot.
+const SIDE_OBJECT_COLUMNS: [DBColumn; 3] = [
+    DBColumn::BeaconInclusionListHistory,
+    DBColumn::BeaconPayloadAttestationHistory,
+    DBColumn::BeaconExecutionProofHistory,
+];
+
+fn parse_slot_key(key: &[u8]) -> Result<Slot, Error> {
+    let bytes = key
+        .try_into()
+        .map_err(|_| Error::InvalidKey(format!("side object key len {}", key.len())))?;
+    Ok(Slot::new(u64::from_be_bytes(bytes)))
+}
+
+impl<E, Hot, Cold> HotColdDB<E, Hot, Cold>
+where
+    E: EthSpec,
+    Hot: ItemStore<E>,
+    Cold: ItemStore<E>,
+{
+    /// Whether inclusion lists, payload attestations and execution proofs are retained.
+    pub fn retains_side_objects(&self) -> bool {
+        self.config.side_object_retention_epochs > 0
+    }
+
+    fn get_side_objects<T: Decode>(&self, column: DBColumn, slot: Slot) -> Result<Vec<T>, Error> {
+        match self
+            .hot_db
+            .get_bytes(column, &slot.as_u64().to_be_bytes())?
+        {
+            Some(bytes) => Ok(Vec::<T>::from_ssz_bytes(&bytes)?),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Add `objects` to the ones stored for `slot`, ignoring duplicates.
+    fn put_side_objects<T: Encode + Decode + PartialEq>(
+        &self,
+        column: DBColumn,
+        slot: Slot,
+        objects: Vec<T>,
+    ) -> Result<(), Error> {
+        if objects.is_empty() {
+            return Ok(());
+        }
+        let _lock = self.side_objects_lock.lock();
+        let mut stored = self.get_side_objects::<T>(column, slot)?;
+        for object in objects {
+            if !stored.contains(&object) {
+                stored.push(object);
+            }
+        }
+        self.hot_db
+            .put_bytes(column, &slot.as_u64().to_be_bytes(), &stored.as_ssz_bytes())
+    }
+
+    /// Store the signed inclusion lists received for `slot`.
+    pub fn put_inclusion_list_history(
+        &self,
+        slot: Slot,
+        inclusion_lists: Vec<SignedInclusionList<E>>,
+    ) -> Result<(), Error> {
+        self.put_side_objects(DBColumn::BeaconInclusionListHistory, slot, inclusion_lists)
+    }
+
+    /// Load the signed inclusion lists stored for `slot`.
+    pub fn get_inclusion_list_history(
+        &self,
+        slot: Slot,
+    ) -> Result<Vec<SignedInclusionList<E>>, Error> {
+        self.get_side_objects(DBColumn::BeaconInclusionListHistory, slot)
+    }
+
+    /// Store the aggregated payload attestations received for `slot`.
+    pub fn put_payload_attestation_history(
+        &self,
+        slot: Slot,
+        payload_attestations: Vec<PayloadAttestation<E>>,
+    ) -> Result<(), Error> {
+        self.put_side_objects(
+            DBColumn::BeaconPayloadAttestationHistory,
+            slot,
+            payload_attestations,
+        )
+    }
+
+    /// Load the aggregated payload attestations stored for `slot`.
+    pub fn get_payload_attestation_history(
+        &self,
+        slot: Slot,
+    ) -> Result<Vec<PayloadAttestation<E>>, Error> {
+        self.get_side_objects(DBColumn::BeaconPayloadAttestationHistory, slot)
+    }
+
+    /// Returns the op that copies the execution proofs of the finalized block `block_root` at
+    /// `slot` into the history, if it has any.
+    ///
+    /// The caller is responsible for deleting the proofs from `BeaconExecutionProof`.
+    pub fn execution_proof_history_op(
+        &self,
+        block_root: &Hash256,
+        slot: Slot,
+    ) -> Result<Option<KeyValueStoreOp>, Error> {
+        let proofs = self
+            .get_execution_proofs(block_root)?
+            .into_iter()
+            .map(|proof| (*proof).clone())
+            .collect::<Vec<_>>();
+        if proofs.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(KeyValueStoreOp::PutKeyValue(
+            DBColumn::BeaconExecutionProofHistory,
+            slot.as_u64().to_be_bytes().to_vec(),
+            proofs.as_ssz_bytes(),
+        )))
+    }
+
+    /// Load the execution proofs of the finalized block at `slot`, if they were retained.
+    pub fn get_execution_proof_history(&self, slot: Slot) -> Result<Vec<ExecutionProof>, Error> {
+        self.get_side_objects(DBColumn::BeaconExecutionProofHistory, slot)
+    }
+
+    /// Delete the side objects which are more than `side_object_retention_epochs` older than
+    /// `finalized_epoch`.
+    pub fn prune_side_objects(&self, finalized_epoch: Epoch) -> Result<(), Error> {
+        let cutoff_slot = finalized_epoch
+            .saturating_sub(self.config.side_object_retention_epochs)
+            .start_slot(E::slots_per_epoch());
+
+        let mut ops = vec![];
+        for column in SIDE_OBJECT_COLUMNS {
+            for res in self.hot_db.iter_column_keys::<Vec<u8>>(column) {
+                let key = res?;
+                if parse_slot_key(&key)? >= cutoff_slot {
+                    break;
+                }
+                ops.push(KeyValueStoreOp::DeleteKey(column, key));
+            }
+        }
+
+        if ops.is_empty() {
+            return Ok(());
+        }
+        self.hot_db.do_atomically(ops)
+    }
+}
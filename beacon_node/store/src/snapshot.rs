@@ -0,0 +1,133 @@
+//! Consistent point-in-time copies of the databases of a running node.
+//!
+//! Each database is copied from a single read transaction into a new database using the same
+//! backend, so the node can keep writing while the copy is made. The copies of the hot, cold and
+//! blobs databases are only consistent with each other if the freezer migration does not run in
+//! the meantime, which is the responsibility of the caller.
+use crate::hot_cold_store::HotColdDB;
+use crate::{DBColumn, Error, ItemStore, KeyValueStoreOp, Split};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::info;
+use types::EthSpec;
+
+/// Maximum total size of the keys and values written to the copy by a single batch.
+const SNAPSHOT_BATCH_BYTES: usize = 64 * 1024 * 1024;
+
+/// Directories to write the copies of the hot, cold and blobs databases to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotPaths {
+    pub hot: PathBuf,
+    pub cold: PathBuf,
+    pub blobs: PathBuf,
+}
+
+/// Number of entries and bytes copied by a snapshot so far, across all databases.
+#[derive(Debug, Default)]
+pub struct SnapshotProgress {
+    entries: AtomicU64,
+    bytes: AtomicU64,
+}
+
+impl SnapshotProgress {
+    pub fn entries(&self) -> u64 {
+        self.entries.load(Ordering::Relaxed)
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    fn record(&self, entries: usize, bytes: usize) {
+        self.entries.fetch_add(entries as u64, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+}
+
+/// Batches the entries of a snapshot into writes of at most `SNAPSHOT_BATCH_BYTES`.
+pub(crate) struct SnapshotWriter<'a, F> {
+    write: F,
+    progress: &'a SnapshotProgress,
+    batch: Vec<KeyValueStoreOp>,
+    batch_bytes: usize,
+}
+
+impl<'a, F> SnapshotWriter<'a, F>
+where
+    F: FnMut(Vec<KeyValueStoreOp>) -> Result<(), Error>,
+{
+    pub(crate) fn new(progress: &'a SnapshotProgress, write: F) -> Self {
+        Self {
+            write,
+            progress,
+            batch: vec![],
+            batch_bytes: 0,
+        }
+    }
+
+    pub(crate) fn put(&mut self, column: DBColumn, key: &[u8], value: &[u8]) -> Result<(), Error> {
+        self.batch_bytes += key.len() + value.len();
+        self.batch.push(KeyValueStoreOp::PutKeyValue(
+            column,
+            key.to_vec(),
+            value.to_vec(),
+        ));
+        if self.batch_bytes >= SNAPSHOT_BATCH_BYTES {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        if self.batch.is_empty() {
+            return Ok(());
+        }
+        let entries = self.batch.len();
+        (self.write)(std::mem::take(&mut self.batch))?;
+        self.progress.record(entries, self.batch_bytes);
+        self.batch_bytes = 0;
+        Ok(())
+    }
+
+    /// Write the remaining entries.
+    pub(crate) fn finish(mut self) -> Result<(), Error> {
+        self.flush()
+    }
+}
+
+impl<E, Hot, Cold> HotColdDB<E, Hot, Cold>
+where
+    E: EthSpec,
+    Hot: ItemStore<E>,
+    Cold: ItemStore<E>,
+{
+    /// Copy the hot, cold and blobs databases to new databases in `paths`, returning the split of
+    /// the copy.
+    ///
+    /// The freezer migration must not run until this function returns. Other writes may continue.
+    pub fn snapshot(
+        &self,
+        paths: &SnapshotPaths,
+        progress: &SnapshotProgress,
+    ) -> Result<Split, Error> {
+        for path in [&paths.hot, &paths.cold, &paths.blobs] {
+            if path.exists() {
+                return Err(Error::SnapshotTargetExists(path.clone()));
+            }
+        }
+
+        let split = self.get_split_info();
+
+        // The hot database is copied first. Without a migration, the cold and blobs databases can
+        // only gain entries relative to it (e.g. from backfill or block import), which leaves
+        // unreferenced entries in the copy at worst.
+        info!(path = %paths.hot.display(), "Copying hot database");
+        self.hot_db.snapshot(&paths.hot, progress)?;
+        info!(path = %paths.cold.display(), "Copying freezer database");
+        self.cold_db.snapshot(&paths.cold, progress)?;
+        info!(path = %paths.blobs.display(), "Copying blobs database");
+        self.blobs_db.snapshot(&paths.blobs, progress)?;
+
+        Ok(split)
+    }
+}
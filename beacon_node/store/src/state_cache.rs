@@ -1,4 +1,5 @@
 use crate::hdiff::HDiffBuffer;
+use crate::memory_budget::{MemoryBudget, StateMemoryTracker};
 use crate::{
     Error,
     metrics::{self, HOT_METRIC},
@@ -6,6 +7,7 @@ use crate::{
 use lru::LruCache;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 use tracing::instrument;
 use types::{BeaconState, ChainSpec, Epoch, EthSpec, Hash256, Slot};
 
@@ -17,6 +19,13 @@ const CULL_EXEMPT_DENOMINATOR: usize = 10;
 /// be culled from the cache.
 const EPOCH_FINALIZATION_LIMIT: u64 = 4;
 
+/// Key of a cached state in `StateCache::state_memory`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CachedState {
+    Finalized,
+    Hot(Hash256),
+}
+
 #[derive(Debug)]
 pub(crate) struct FinalizedState<E: EthSpec> {
     state_root: Hash256,
@@ -46,6 +55,10 @@ pub struct StateCache<E: EthSpec> {
     max_epoch: Epoch,
     head_block_root: Hash256,
     headroom: NonZeroUsize,
+    /// Memory used by `finalized_state` and `states`, only tracked if there is a memory budget.
+    state_memory: Option<StateMemoryTracker<CachedState>>,
+    /// Byte budget limiting the cache instead of its capacity, if any.
+    memory_budget: Option<Arc<MemoryBudget>>,
 }
 
 /// Cache of hdiff buffers for hot states.
@@ -91,6 +104,24 @@ impl<E: EthSpec> StateCache<E> {
             max_epoch: Epoch::new(0),
             head_block_root: Hash256::ZERO,
             headroom,
+            state_memory: None,
+            memory_budget: None,
+        }
+    }
+
+    /// Create a cache limited by the estimated memory used by its states and `HDiffBuffer`s
+    /// rather than by their number.
+    pub fn with_memory_budget(headroom: NonZeroUsize, memory_budget: Arc<MemoryBudget>) -> Self {
+        StateCache {
+            finalized_state: None,
+            states: LruCache::unbounded(),
+            block_map: BlockMap::default(),
+            hdiff_buffers: HotHDiffBufferCache::unbounded(),
+            max_epoch: Epoch::new(0),
+            head_block_root: Hash256::ZERO,
+            headroom,
+            state_memory: Some(StateMemoryTracker::default()),
+            memory_budget: Some(memory_budget),
         }
     }
 
@@ -114,6 +145,30 @@ impl<E: EthSpec> StateCache<E> {
         self.hdiff_buffers.mem_usage()
     }
 
+    /// Estimated memory used by the cached states, including the finalized state.
+    ///
+    /// Returns `None` if the cache has no memory budget, in which case states aren't measured.
+    pub fn state_mem_usage(&self) -> Option<usize> {
+        self.state_memory.as_ref().map(StateMemoryTracker::bytes)
+    }
+
+    /// Estimated memory used by the cached states and `HDiffBuffer`s.
+    pub fn mem_usage(&self) -> usize {
+        self.state_mem_usage().unwrap_or(0) + self.hdiff_buffer_mem_usage()
+    }
+
+    fn track_state(&mut self, key: CachedState, state: &BeaconState<E>) {
+        if let Some(state_memory) = &mut self.state_memory {
+            state_memory.track(key, state);
+        }
+    }
+
+    fn untrack_state(&mut self, key: CachedState) {
+        if let Some(state_memory) = &mut self.state_memory {
+            state_memory.untrack(&key);
+        }
+    }
+
     pub fn update_finalized_state(
         &mut self,
         state_root: Hash256,
@@ -144,7 +199,7 @@ impl<E: EthSpec> StateCache<E> {
         // preferences older slots.
         // NOTE: This isn't perfect as it prunes by slot: there could be multiple buffers
         // at some slots in the case of long forks without finality.
-        let new_hdiff_cache = HotHDiffBufferCache::new(self.hdiff_buffers.cap());
+        let new_hdiff_cache = self.hdiff_buffers.new_empty();
         let old_hdiff_cache = std::mem::replace(&mut self.hdiff_buffers, new_hdiff_cache);
         for (state_root, (slot, buffer)) in old_hdiff_cache.hdiff_buffers {
             if pre_finalized_slots_to_retain.contains(&slot) {
@@ -155,6 +210,7 @@ impl<E: EthSpec> StateCache<E> {
         // Delete states.
         for state_root in state_roots_to_prune {
             if let Some((_, state)) = self.states.pop(&state_root) {
+                self.untrack_state(CachedState::Hot(state_root));
                 // Add the hdiff buffer for this state to the hdiff cache if it is now part of
                 // the pre-finalized grid. The `put` method will take care of keeping the most
                 // useful buffers.
@@ -167,7 +223,10 @@ impl<E: EthSpec> StateCache<E> {
         }
 
         // Update finalized state.
+        self.untrack_state(CachedState::Finalized);
+        self.track_state(CachedState::Finalized, &state);
         self.finalized_state = Some(FinalizedState { state_root, state });
+        self.cull_to_memory_budget();
         Ok(())
     }
 
@@ -219,6 +278,7 @@ impl<E: EthSpec> StateCache<E> {
                 let hdiff_buffer = HDiffBuffer::from_state(state.clone());
                 self.hdiff_buffers
                     .put(state_root, state.slot(), hdiff_buffer);
+                self.cull_to_memory_budget();
                 return Ok(PutStateOutcome::PreFinalizedHDiffBuffer);
             }
         }
@@ -252,13 +312,18 @@ impl<E: EthSpec> StateCache<E> {
         if let Some((deleted_state_root, _)) =
             self.states.put(state_root, (state_root, state.clone()))
         {
+            self.untrack_state(CachedState::Hot(deleted_state_root));
             deleted_states.push(deleted_state_root);
         }
+        self.track_state(CachedState::Hot(state_root), state);
 
         // Record the connection from block root and slot to this state.
         let slot = state.slot();
         self.block_map.insert(block_root, slot, state_root);
 
+        // If the cache is over its memory budget, cull until it fits.
+        deleted_states.extend(self.cull_to_memory_budget());
+
         Ok(PutStateOutcome::New(deleted_states))
     }
 
@@ -280,6 +345,7 @@ impl<E: EthSpec> StateCache<E> {
             return;
         }
         self.hdiff_buffers.put(state_root, slot, buffer.clone());
+        self.cull_to_memory_budget();
     }
 
     pub fn get_hdiff_buffer_by_state_root(&mut self, state_root: Hash256) -> Option<HDiffBuffer> {
@@ -324,16 +390,61 @@ impl<E: EthSpec> StateCache<E> {
     }
 
     pub fn delete_state(&mut self, state_root: &Hash256) {
-        self.states.pop(state_root);
+        if self.states.pop(state_root).is_some() {
+            self.untrack_state(CachedState::Hot(*state_root));
+        }
         self.block_map.delete(state_root);
     }
 
     pub fn delete_block_states(&mut self, block_root: &Hash256) {
         if let Some(slot_map) = self.block_map.delete_block_states(block_root) {
             for state_root in slot_map.slots.values() {
-                self.states.pop(state_root);
+                if self.states.pop(state_root).is_some() {
+                    self.untrack_state(CachedState::Hot(*state_root));
+                }
+            }
+        }
+    }
+
+    /// Cull states, and then `HDiffBuffer`s, until the cache fits in its memory budget.
+    ///
+    /// States are culled `headroom` at a time, in the order used by `cull`. The states exempt from
+    /// culling and the oldest `HDiffBuffer` are always kept, even if they exceed the budget.
+    fn cull_to_memory_budget(&mut self) -> Vec<Hash256> {
+        let Some(memory_budget) = self.memory_budget.clone() else {
+            return vec![];
+        };
+        if let Some(state_memory) = &mut self.state_memory
+            && state_memory.rebuild_due()
+        {
+            let states = self
+                .finalized_state
+                .iter()
+                .map(|finalized_state| (CachedState::Finalized, &finalized_state.state))
+                .chain(
+                    self.states
+                        .iter()
+                        .map(|(state_root, (_, state))| (CachedState::Hot(*state_root), state)),
+                );
+            state_memory.rebuild(states);
+        }
+
+        let mut deleted_states = vec![];
+        loop {
+            let mem_usage = self.mem_usage();
+            memory_budget.set_hot_usage(mem_usage);
+            if mem_usage <= memory_budget.hot_allowance() {
+                break;
+            }
+
+            let culled = self.cull(self.headroom.get());
+            if !culled.is_empty() {
+                deleted_states.extend(culled);
+            } else if !self.hdiff_buffers.evict_lru() {
+                break;
             }
         }
+        deleted_states
     }
 
     /// Cull approximately `count` states from the cache.
@@ -446,6 +557,22 @@ impl HotHDiffBufferCache {
         }
     }
 
+    /// Create a cache limited only by `StateCache::cull_to_memory_budget`.
+    pub(crate) fn unbounded() -> Self {
+        Self {
+            hdiff_buffers: LruCache::unbounded(),
+        }
+    }
+
+    /// Create an empty cache with the same capacity as this one.
+    pub(crate) fn new_empty(&self) -> Self {
+        if self.cap() == NonZeroUsize::MAX {
+            Self::unbounded()
+        } else {
+            Self::new(self.cap())
+        }
+    }
+
     pub(crate) fn get(&mut self, state_root: &Hash256) -> Option<HDiffBuffer> {
         self.hdiff_buffers
             .get(state_root)
@@ -499,6 +626,25 @@ impl HotHDiffBufferCache {
         }
     }
 
+    /// Remove the least-recently used buffer, other than the buffer for the oldest slot.
+    ///
+    /// Returns `false` if there is no such buffer.
+    pub(crate) fn evict_lru(&mut self) -> bool {
+        let Some(min_slot) = self.hdiff_buffers.iter().map(|(_, (slot, _))| *slot).min() else {
+            return false;
+        };
+        let Some(state_root) = self
+            .hdiff_buffers
+            .iter()
+            .rev()
+            .find(|(_, (slot, _))| *slot != min_slot)
+            .map(|(state_root, _)| *state_root)
+        else {
+            return false;
+        };
+        self.hdiff_buffers.pop(&state_root).is_some()
+    }
+
     pub(crate) fn cap(&self) -> NonZeroUsize {
         self.hdiff_buffers.cap()
     }
@@ -518,7 +664,9 @@ impl HotHDiffBufferCache {
 #[cfg(test)]
 mod tests {
     use super::{BlockMap, HDiffBuffer, HotHDiffBufferCache, PutStateOutcome, StateCache};
+    use crate::memory_budget::MemoryBudget;
     use std::num::NonZeroUsize;
+    use std::sync::Arc;
     use types::{
         BeaconState, ChainSpec, Eth1Data, FixedBytesExtended, Hash256, MinimalEthSpec, Slot,
     };
@@ -1050,4 +1198,63 @@ mod tests {
         let cache = make_cache(10, 1);
         assert_eq!(cache.hdiff_buffer_mem_usage(), 0);
     }
+
+    #[test]
+    fn hdiff_cache_evict_lru_keeps_oldest() {
+        let mut cache = HotHDiffBufferCache::unbounded();
+        cache.put(hash(1), Slot::new(10), make_hdiff_buffer(10));
+        cache.put(hash(2), Slot::new(20), make_hdiff_buffer(20));
+        cache.put(hash(3), Slot::new(30), make_hdiff_buffer(30));
+        cache.get(&hash(2));
+
+        assert!(cache.evict_lru());
+        assert!(cache.get(&hash(3)).is_none());
+        assert!(cache.evict_lru());
+        assert!(cache.get(&hash(2)).is_none());
+        assert!(!cache.evict_lru());
+        assert!(cache.get(&hash(1)).is_some());
+        assert_eq!(cache.new_empty().cap(), NonZeroUsize::MAX);
+    }
+
+    #[test]
+    fn state_cache_memory_budget_culls() {
+        let mut unlimited =
+            StateCache::<E>::with_memory_budget(nz(1), Arc::new(MemoryBudget::new(usize::MAX)));
+        unlimited
+            .put_state(hash(1), hash(10), &make_state(1))
+            .unwrap();
+        let state_size = unlimited.mem_usage();
+        assert!(state_size > 0);
+
+        let budget = Arc::new(MemoryBudget::new(3 * state_size));
+        let mut cache = StateCache::<E>::with_memory_budget(nz(1), budget.clone());
+        for i in 1u64..8 {
+            cache
+                .put_state(hash(i), hash(i + 100), &make_state(i))
+                .unwrap();
+            assert!(cache.mem_usage() <= budget.limit());
+        }
+        assert!(cache.len() <= 3);
+        assert!(cache.get_by_state_root(hash(7)).is_some());
+    }
+
+    #[test]
+    fn state_cache_memory_tracked_incrementally() {
+        let mut cache = make_cache(10, 1);
+        cache.put_state(hash(1), hash(10), &make_state(1)).unwrap();
+        assert_eq!(cache.state_mem_usage(), None);
+
+        let mut cache =
+            StateCache::<E>::with_memory_budget(nz(1), Arc::new(MemoryBudget::new(usize::MAX)));
+        cache.put_state(hash(1), hash(10), &make_state(1)).unwrap();
+        let one_state = cache.state_mem_usage().unwrap();
+        cache.put_state(hash(2), hash(20), &make_state(2)).unwrap();
+        let two_states = cache.state_mem_usage().unwrap();
+        assert!(two_states > one_state);
+
+        cache.delete_state(&hash(2));
+        assert_eq!(cache.state_mem_usage(), Some(one_state));
+        cache.delete_state(&hash(1));
+        assert_eq!(cache.state_mem_usage(), Some(0));
+    }
 }
@@ -0,0 +1,262 @@
+//! Per-epoch summaries of every validator, indexed in the freezer database.
+//!
+//! When `StoreConfig::validator_history` is enabled, a summary of each validator is recorded for
+//! every epoch as states are migrated to the freezer or reconstructed. The entry for epoch `N` is
+//! taken from the state at the first slot of epoch `N + 1` (after any block in that slot). Its
+//! balances and statuses are those following the epoch transition out of `N`, and its
+//! participation flags are the ones earned by attestations for epoch `N`.
+//!
+//! Validators are grouped into chunks of `VALIDATORS_PER_CHUNK`, stored in the `ValidatorHistory`
+//! column under the key `chunk ++ epoch` (both 8-byte big-endian), so that the history of a single
+//! validator is a contiguous range of keys. Each value is a compressed `ValidatorHistoryChunk`
+//! holding one column per field.
+use crate::hot_cold_store::HotColdDB;
+use crate::{DBColumn, Error, ItemStore, KeyValueStore, KeyValueStoreOp};
+use ssz::{Decode, Encode};
+use ssz_derive::{Decode, Encode};
+use types::{BeaconState, Epoch, EthSpec, Slot, Validator};
+
+/// Number of validators summarised by each database entry.
+pub const VALIDATORS_PER_CHUNK: u64 = 256;
+
+const STATUS_ACTIVE: u8 = 1;
+const STATUS_SLASHED: u8 = 1 << 1;
+const STATUS_EXIT_INITIATED: u8 = 1 << 2;
+const STATUS_EXITED: u8 = 1 << 3;
+
+/// Summaries of up to `VALIDATORS_PER_CHUNK` consecutive validators for a single epoch.
+#[derive(Debug, Default, PartialEq, Eq, Encode, Decode)]
+pub struct ValidatorHistoryChunk {
+    balances: Vec<u64>,
+    effective_balances: Vec<u64>,
+    /// Previous epoch participation flags, or 0 prior to Altair.
+    participation: Vec<u8>,
+    /// `STATUS_*` bits.
+    statuses: Vec<u8>,
+}
+
+impl ValidatorHistoryChunk {
+    fn push(&mut self, validator: &Validator, balance: u64, participation: u8, epoch: Epoch) {
+        let mut status = 0;
+        if validator.is_active_at(epoch) {
+            status |= STATUS_ACTIVE;
+        }
+        if validator.slashed {
+            status |= STATUS_SLASHED;
+        }
+        if validator.exit_epoch != Epoch::max_value() {
+            status |= STATUS_EXIT_INITIATED;
+        }
+        if validator.is_exited_at(epoch) {
+            status |= STATUS_EXITED;
+        }
+
+        self.balances.push(balance);
+        self.effective_balances.push(validator.effective_balance);
+        self.participation.push(participation);
+        self.statuses.push(status);
+    }
+
+    fn len(&self) -> usize {
+        self.balances.len()
+    }
+
+    fn summary(&self, epoch: Epoch, offset: usize) -> Option<ValidatorEpochSummary> {
+        let status = *self.statuses.get(offset)?;
+        Some(ValidatorEpochSummary {
+            epoch,
+            balance: *self.balances.get(offset)?,
+            effective_balance: *self.effective_balances.get(offset)?,
+            participation_flags: *self.participation.get(offset)?,
+            active: status & STATUS_ACTIVE != 0,
+            slashed: status & STATUS_SLASHED != 0,
+            exit_initiated: status & STATUS_EXIT_INITIATED != 0,
+            exited: status & STATUS_EXITED != 0,
+        })
+    }
+}
+
+/// The recorded summary of a single validator for one epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidatorEpochSummary {
+    pub epoch: Epoch,
+    pub balance: u64,
+    pub effective_balance: u64,
+    /// Raw participation flags earned for `epoch`, see `ParticipationFlags`.
+    pub participation_flags: u8,
+    pub active: bool,
+    pub slashed: bool,
+    pub exit_initiated: bool,
+    pub exited: bool,
+}
+
+fn validator_history_key(chunk: u64, epoch: Epoch) -> Vec<u8> {
+    let mut key = Vec::with_capacity(16);
+    key.extend_from_slice(&chunk.to_be_bytes());
+    key.extend_from_slice(&epoch.as_u64().to_be_bytes());
+    key
+}
+
+fn parse_validator_history_key(key: &[u8]) -> Result<(u64, Epoch), Error> {
+    let invalid = || Error::InvalidKey(format!("validator history key len {}", key.len()));
+    let (chunk, epoch) = key.split_at_checked(8).ok_or_else(invalid)?;
+    let chunk = u64::from_be_bytes(chunk.try_into().map_err(|_| invalid())?);
+    let epoch = u64::from_be_bytes(epoch.try_into().map_err(|_| invalid())?);
+    Ok((chunk, Epoch::new(epoch)))
+}
+
+impl<E, Hot, Cold> HotColdDB<E, Hot, Cold>
+where
+    E: EthSpec,
+    Hot: ItemStore<E>,
+    Cold: ItemStore<E>,
+{
+    /// Whether the validator history is recorded from the state at `slot`.
+    pub(crate) fn records_validator_history(&self, slot: Slot) -> bool {
+        self.config.validator_history && slot != 0 && slot % E::slots_per_epoch() == 0
+    }
+
+    /// Append the ops to store the validator history recorded by `state` to `ops`, if any.
+    pub(crate) fn store_validator_history(
+        &self,
+        state: &BeaconState<E>,
+        ops: &mut Vec<KeyValueStoreOp>,
+    ) -> Result<(), Error> {
+        if !self.records_validator_history(state.slot()) {
+            return Ok(());
+        }
+        let epoch = state.previous_epoch();
+
+        let mut participation = state
+            .previous_epoch_participation()
+            .ok()
+            .map(|participation| participation.iter())
+            .into_iter()
+            .flatten();
+        let mut chunk_index = 0;
+        let mut chunk = ValidatorHistoryChunk::default();
+        let mut put_chunk = |chunk_index: u64, chunk: &ValidatorHistoryChunk| {
+            let bytes = self
+                .config
+                .compress_bytes(&chunk.as_ssz_bytes())
+                .map_err(Error::Compression)?;
+            ops.push(KeyValueStoreOp::PutKeyValue(
+                DBColumn::ValidatorHistory,
+                validator_history_key(chunk_index, epoch),
+                bytes,
+            ));
+            Ok::<_, Error>(())
+        };
+
+        for (validator, balance) in state.validators().iter().zip(state.balances().iter()) {
+            let flags = participation.next().map_or(0, |flags| flags.into_u8());
+            chunk.push(validator, *balance, flags, epoch);
+            if chunk.len() as u64 == VALIDATORS_PER_CHUNK {
+                put_chunk(chunk_index, &chunk)?;
+                chunk = ValidatorHistoryChunk::default();
+                chunk_index += 1;
+            }
+        }
+        if !chunk.balances.is_empty() {
+            put_chunk(chunk_index, &chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the recorded summaries of `validator_index` for the epochs in
+    /// `start_epoch..=end_epoch`, in epoch order.
+    ///
+    /// Epochs without a recorded summary, e.g. because the validator didn't exist yet or the
+    /// history was disabled at the time, are omitted.
+    pub fn get_validator_history(
+        &self,
+        validator_index: u64,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<Vec<ValidatorEpochSummary>, Error> {
+        let chunk_index = validator_index / VALIDATORS_PER_CHUNK;
+        let offset = (validator_index % VALIDATORS_PER_CHUNK) as usize;
+        let mut history = vec![];
+
+        for res in self.cold_db.iter_column_from::<Vec<u8>>(
+            DBColumn::ValidatorHistory,
+            &validator_history_key(chunk_index, start_epoch),
+        ) {
+            let (key, bytes) = res?;
+            let (key_chunk_index, epoch) = parse_validator_history_key(&key)?;
+            if key_chunk_index != chunk_index || epoch > end_epoch {
+                break;
+            }
+            let chunk = ValidatorHistoryChunk::from_ssz_bytes(
+                &self
+                    .config
+                    .decompress_bytes(&bytes)
+                    .map_err(Error::Compression)?,
+            )?;
+            if let Some(summary) = chunk.summary(epoch, offset) {
+                history.push(summary);
+            }
+        }
+
+        Ok(history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_roundtrip_and_order() {
+        let key = validator_history_key(3, Epoch::new(258));
+        assert_eq!(key.len(), DBColumn::ValidatorHistory.key_size());
+        assert_eq!(
+            parse_validator_history_key(&key).unwrap(),
+            (3, Epoch::new(258))
+        );
+        assert!(
+            validator_history_key(3, Epoch::new(u64::MAX))
+                < validator_history_key(4, Epoch::new(0))
+        );
+        assert!(parse_validator_history_key(&key[..15]).is_err());
+    }
+
+    #[test]
+    fn chunk_summary() {
+        let epoch = Epoch::new(10);
+        let active = Validator {
+            effective_balance: 32,
+            activation_epoch: Epoch::new(0),
+            exit_epoch: Epoch::max_value(),
+            ..Validator::default()
+        };
+        let exited = Validator {
+            slashed: true,
+            activation_epoch: Epoch::new(0),
+            exit_epoch: Epoch::new(9),
+            ..Validator::default()
+        };
+        let mut chunk = ValidatorHistoryChunk::default();
+        chunk.push(&active, 33, 0b111, epoch);
+        chunk.push(&exited, 30, 0, epoch);
+
+        let decoded = ValidatorHistoryChunk::from_ssz_bytes(&chunk.as_ssz_bytes()).unwrap();
+        assert_eq!(decoded, chunk);
+        assert_eq!(
+            decoded.summary(epoch, 0),
+            Some(ValidatorEpochSummary {
+                epoch,
+                balance: 33,
+                effective_balance: 32,
+                participation_flags: 0b111,
+                active: true,
+                slashed: false,
+                exit_initiated: false,
+                exited: false,
+            })
+        );
+        let summary = decoded.summary(epoch, 1).unwrap();
+        assert!(summary.slashed && summary.exit_initiated && summary.exited && !summary.active);
+        assert_eq!(decoded.summary(epoch, 2), None);
+    }
+}
@@ -0,0 +1,835 @@
+//! Offline integrity checks for the hot, cold and blobs databases.
+//!
+//! [`HotColdDB::verify`] checks that:
+//!
+//! - Every block links to its parent, from the split block and from the latest block of every
+//!   non-finalized state down to the oldest block, and the finalized chain agrees with the block
+//!   roots in the freezer.
+//! - Every hot state summary at or after the split resolves its previous state, its diff base and
+//!   its latest block, and has the snapshot or diff that its storage strategy requires.
+//! - The hot and cold hierarchical diff chains reconstruct states with the expected state roots.
+//! - Payload envelopes, blobs and data columns are present for every canonical block within their
+//!   retention windows.
+//!
+//! Entries which are no longer referenced by anything, such as blocks from abandoned forks or the
+//! sidecars of deleted blocks, are collected separately. They do not make the database unusable,
+//! and can be removed with [`HotColdDB::delete_dangling`]. Blocks and their data are only
+//! considered safe to delete if the block chains and hot state summaries are intact, see
+//! [`VerifyReport::deletable_dangling`].
+use crate::hdiff::StorageStrategy;
+use crate::hot_cold_store::{HotColdDB, HotStateSummary};
+use crate::{DBColumn, Error, ItemStore, KeyValueStore, KeyValueStoreOp, parse_data_column_key};
+use serde::{Serialize, Serializer};
+use ssz::Decode;
+use std::collections::{HashMap, HashSet};
+use tracing::info;
+use types::{BlindedPayload, EthSpec, ExecutionBlockHash, Hash256, SignedBeaconBlock, Slot};
+
+/// Options for [`HotColdDB::verify`].
+#[derive(Debug, Clone)]
+pub struct VerifyConfig {
+    /// Reconstruct every state stored as a snapshot or diff and check its root.
+    ///
+    /// This is by far the slowest check, particularly on archive nodes.
+    pub reconstruct_states: bool,
+}
+
+impl Default for VerifyConfig {
+    fn default() -> Self {
+        Self {
+            reconstruct_states: true,
+        }
+    }
+}
+
+/// The database an entry is stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Database {
+    Hot,
+    Cold,
+    Blobs,
+}
+
+/// An inconsistency which may prevent the node from serving or importing data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Problem {
+    /// A block could not be decoded.
+    CorruptBlock { block_root: Hash256, error: String },
+    /// A block above the split has no summary for its post-state, so it is unreachable but can't
+    /// be told apart from a block of an abandoned fork.
+    MissingBlockStateSummary {
+        block_root: Hash256,
+        slot: Slot,
+        state_root: Hash256,
+    },
+    /// The parent of a block is missing, breaking the chain above the oldest block.
+    MissingParentBlock {
+        block_root: Hash256,
+        slot: Slot,
+        parent_root: Hash256,
+    },
+    /// The freezer block root at the slot of a finalized block is a different block.
+    FinalizedBlockRootMismatch {
+        block_root: Hash256,
+        slot: Slot,
+        cold_block_root: Option<Hash256>,
+    },
+    /// A hot state summary references a previous state or diff base without a summary.
+    MissingHotStateSummary {
+        state_root: Hash256,
+        referenced_by: Hash256,
+    },
+    /// A hot state summary has a diff base which does not match its storage strategy.
+    InvalidHotStateSummary { state_root: Hash256, error: String },
+    /// The latest block of a hot state is missing.
+    MissingHotStateBlock {
+        state_root: Hash256,
+        block_root: Hash256,
+    },
+    /// The snapshot or diff required by a hot state's storage strategy is missing.
+    MissingHotStateData { state_root: Hash256, slot: Slot },
+    /// A state stored as a snapshot or diff could not be loaded.
+    StateReconstructionFailed {
+        database: Database,
+        slot: Slot,
+        error: String,
+    },
+    /// A state stored as a snapshot or diff was loaded, but its root is not the expected one.
+    StateRootMismatch {
+        database: Database,
+        slot: Slot,
+        expected: Hash256,
+        computed: Hash256,
+    },
+    /// A canonical block's payload was revealed, but its payload envelope is missing.
+    MissingPayloadEnvelope { block_root: Hash256, slot: Slot },
+    /// A canonical block within the blob retention window is missing its blobs.
+    MissingBlobs { block_root: Hash256, slot: Slot },
+    /// A canonical block within the data column retention window has no data columns.
+    MissingDataColumns { block_root: Hash256, slot: Slot },
+}
+
+/// Why a [`DanglingEntry`] is no longer needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DanglingReason {
+    /// A block which is neither on the finalized chain nor an ancestor of a hot state.
+    AbandonedBlock,
+    /// A hot state summary prior to the split which is not needed as a diff base.
+    UnreferencedHotStateSummary,
+    /// A hot state snapshot or diff without a retained summary.
+    OrphanedHotStateData,
+    /// A cold state summary whose state root does not match the freezer's root at its slot.
+    MismatchedColdStateSummary,
+    /// A blob, data column, payload envelope, execution payload or execution proof whose block is
+    /// missing or abandoned.
+    OrphanedBlockData,
+}
+
+/// A database entry which is no longer referenced and may be deleted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DanglingEntry {
+    pub database: Database,
+    #[serde(serialize_with = "serialize_column")]
+    pub column: DBColumn,
+    #[serde(with = "serde_utils::hex_vec")]
+    pub key: Vec<u8>,
+    pub reason: DanglingReason,
+}
+
+fn serialize_column<S: Serializer>(column: &DBColumn, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(column.as_str())
+}
+
+/// The result of [`HotColdDB::verify`].
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct VerifyReport {
+    pub blocks_checked: usize,
+    pub hot_state_summaries_checked: usize,
+    pub cold_state_summaries_checked: usize,
+    pub states_reconstructed: usize,
+    pub problems: Vec<Problem>,
+    pub dangling: Vec<DanglingEntry>,
+}
+
+impl Problem {
+    /// Whether the problem means that canonical blocks or hot states may have been classed as
+    /// dangling.
+    ///
+    /// A broken block chain, a corrupt freezer block root or a missing hot state summary can each
+    /// make canonical blocks unreachable from the split and the hot states, and a missing summary
+    /// orphans the snapshot or diff of its state.
+    pub fn affects_reachability(&self) -> bool {
+        match self {
+            Problem::CorruptBlock { .. }
+            | Problem::MissingBlockStateSummary { .. }
+            | Problem::MissingParentBlock { .. }
+            | Problem::FinalizedBlockRootMismatch { .. }
+            | Problem::MissingHotStateSummary { .. }
+            | Problem::InvalidHotStateSummary { .. }
+            | Problem::MissingHotStateBlock { .. } => true,
+            Problem::MissingHotStateData { .. }
+            | Problem::StateReconstructionFailed { .. }
+            | Problem::StateRootMismatch { .. }
+            | Problem::MissingPayloadEnvelope { .. }
+            | Problem::MissingBlobs { .. }
+            | Problem::MissingDataColumns { .. } => false,
+        }
+    }
+}
+
+impl VerifyReport {
+    /// Returns `true` if no problems were found. Dangling entries are not considered problems.
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// The dangling entries which are safe to delete.
+    ///
+    /// If any problem affects which blocks and states are reachable, abandoned blocks, their data
+    /// and orphaned hot state data are left alone, as canonical entries may be among them.
+    pub fn deletable_dangling(&self) -> Vec<DanglingEntry> {
+        let reachability_trusted = !self.problems.iter().any(Problem::affects_reachability);
+        self.dangling
+            .iter()
+            .filter(|entry| {
+                reachability_trusted
+                    || !matches!(
+                        entry.reason,
+                        DanglingReason::AbandonedBlock
+                            | DanglingReason::OrphanedBlockData
+                            | DanglingReason::OrphanedHotStateData
+                    )
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn dangling(
+        &mut self,
+        database: Database,
+        column: DBColumn,
+        key: &[u8],
+        reason: DanglingReason,
+    ) {
+        self.dangling.push(DanglingEntry {
+            database,
+            column,
+            key: key.to_vec(),
+            reason,
+        });
+    }
+}
+
+impl<E, Hot, Cold> HotColdDB<E, Hot, Cold>
+where
+    E: EthSpec,
+    Hot: ItemStore<E>,
+    Cold: ItemStore<E>,
+{
+    /// Check the consistency of the database, returning every problem and dangling entry found.
+    ///
+    /// Errors are only returned if the database can't be read. The database should not be written
+    /// to while it is being verified.
+    pub fn verify(&self, config: &VerifyConfig) -> Result<VerifyReport, Error> {
+        let mut report = VerifyReport::default();
+
+        info!("Verifying hot state summaries");
+        let summaries = self.verify_hot_state_summaries(&mut report)?;
+
+        info!("Verifying block chains");
+        let hot_blocks = self.verify_block_chains(&summaries, &mut report)?;
+
+        info!("Verifying stored blocks");
+        let abandoned_blocks = self.verify_stored_blocks(&hot_blocks, &mut report)?;
+
+        info!("Verifying block sidecars");
+        self.verify_block_data(&abandoned_blocks, &mut report)?;
+
+        info!("Verifying cold state summaries");
+        self.verify_cold_state_summaries(&mut report)?;
+
+        if config.reconstruct_states {
+            info!("Reconstructing hot states");
+            self.verify_hot_states(&summaries, &mut report)?;
+
+            info!("Reconstructing cold states");
+            self.verify_cold_states(&mut report)?;
+        }
+
+        Ok(report)
+    }
+
+    /// Delete `entries` from the database, as returned by [`VerifyReport::deletable_dangling`].
+    pub fn delete_dangling(&self, entries: &[DanglingEntry]) -> Result<(), Error> {
+        for database in [Database::Hot, Database::Cold, Database::Blobs] {
+            let ops = entries
+                .iter()
+                .filter(|entry| entry.database == database)
+                .map(|entry| KeyValueStoreOp::DeleteKey(entry.column, entry.key.clone()))
+                .collect::<Vec<_>>();
+            if ops.is_empty() {
+                continue;
+            }
+            match database {
+                Database::Hot => self.hot_db.do_atomically(ops)?,
+                Database::Cold => self.cold_db.do_atomically(ops)?,
+                Database::Blobs => self.blobs_db.do_atomically(ops)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the links of every hot state summary, returning the summaries which are retained.
+    ///
+    /// Summaries prior to the split are only retained if they are on a diff layer, as they may
+    /// be needed as the base of later states.
+    fn verify_hot_state_summaries(
+        &self,
+        report: &mut VerifyReport,
+    ) -> Result<HashMap<Hash256, HotStateSummary>, Error> {
+        let split = self.get_split_info();
+        let mut summaries = HashMap::new();
+        for (state_root, summary) in self.load_hot_state_summaries()? {
+            report.hot_state_summaries_checked += 1;
+            if summary.slot < split.slot
+                && self.hot_storage_strategy(summary.slot)?.is_replay_from()
+            {
+                report.dangling(
+                    Database::Hot,
+                    DBColumn::BeaconStateHotSummary,
+                    state_root.as_slice(),
+                    DanglingReason::UnreferencedHotStateSummary,
+                );
+            } else {
+                summaries.insert(state_root, summary);
+            }
+        }
+
+        for (state_root, summary) in &summaries {
+            if summary.slot > split.slot && !summaries.contains_key(&summary.previous_state_root) {
+                report.problems.push(Problem::MissingHotStateSummary {
+                    state_root: summary.previous_state_root,
+                    referenced_by: *state_root,
+                });
+            }
+
+            let strategy = self.hot_storage_strategy(summary.slot)?;
+            if let Some(base_slot) = strategy.diff_base_slot() {
+                match summary.diff_base_state.get_root(base_slot) {
+                    Ok(base_root) if !summaries.contains_key(&base_root) => {
+                        report.problems.push(Problem::MissingHotStateSummary {
+                            state_root: base_root,
+                            referenced_by: *state_root,
+                        });
+                    }
+                    Ok(_) => {}
+                    Err(e) => report.problems.push(Problem::InvalidHotStateSummary {
+                        state_root: *state_root,
+                        error: format!("{e:?}"),
+                    }),
+                }
+            }
+
+            if !self.block_exists(&summary.latest_block_root)? {
+                report.problems.push(Problem::MissingHotStateBlock {
+                    state_root: *state_root,
+                    block_root: summary.latest_block_root,
+                });
+            }
+
+            let data_column = match strategy {
+                StorageStrategy::Snapshot => Some(DBColumn::BeaconStateHotSnapshot),
+                StorageStrategy::DiffFrom(_) => Some(DBColumn::BeaconStateHotDiff),
+                StorageStrategy::ReplayFrom(_) => None,
+            };
+            if let Some(column) = data_column
+                && !self.hot_db.key_exists(column, state_root.as_slice())?
+            {
+                report.problems.push(Problem::MissingHotStateData {
+                    state_root: *state_root,
+                    slot: summary.slot,
+                });
+            }
+        }
+
+        for column in [
+            DBColumn::BeaconStateHotSnapshot,
+            DBColumn::BeaconStateHotDiff,
+        ] {
+            for state_root in self.hot_db.iter_column_keys::<Hash256>(column) {
+                let state_root = state_root?;
+                if !summaries.contains_key(&state_root) {
+                    report.dangling(
+                        Database::Hot,
+                        column,
+                        state_root.as_slice(),
+                        DanglingReason::OrphanedHotStateData,
+                    );
+                }
+            }
+        }
+
+        Ok(summaries)
+    }
+
+    /// Walk the parent links of the finalized chain, and of every non-finalized chain down to the
+    /// split, returning the roots of the non-finalized blocks.
+    fn verify_block_chains(
+        &self,
+        summaries: &HashMap<Hash256, HotStateSummary>,
+        report: &mut VerifyReport,
+    ) -> Result<HashSet<Hash256>, Error> {
+        let split = self.get_split_info();
+        let mut hot_blocks = HashSet::new();
+
+        // Walk the non-finalized chains first, highest slot first, so that each block is only
+        // visited once.
+        let mut heads = summaries
+            .values()
+            .filter(|summary| summary.latest_block_slot > split.slot)
+            .map(|summary| (summary.latest_block_slot, summary.latest_block_root))
+            .collect::<Vec<_>>();
+        heads.sort_unstable();
+        heads.dedup();
+        for (_, head_root) in heads.into_iter().rev() {
+            self.walk_block_chain(head_root, Some((split.slot, &mut hot_blocks)), report)?;
+        }
+
+        // The finalized chain is not recorded, as the freezer block roots already identify it.
+        self.walk_block_chain(split.block_root, None, report)?;
+
+        Ok(hot_blocks)
+    }
+
+    /// Walk from `block_root` to its ancestors, checking the block data of each block.
+    ///
+    /// If `hot` is set, the walk stops at or below the split slot or at a block which has already
+    /// been visited, and records the visited blocks. Otherwise, the walk continues to the oldest
+    /// block and checks each block against the freezer block roots.
+    fn walk_block_chain(
+        &self,
+        mut block_root: Hash256,
+        mut hot: Option<(Slot, &mut HashSet<Hash256>)>,
+        report: &mut VerifyReport,
+    ) -> Result<(), Error> {
+        let split_slot = self.get_split_info().slot;
+        let oldest_block_parent = self.get_anchor_info().oldest_block_parent;
+        // The parent block hash committed to by the bid of the previously visited child, if any.
+        let mut child_parent_block_hash = None;
+
+        loop {
+            if hot
+                .as_ref()
+                .is_some_and(|(_, visited)| visited.contains(&block_root))
+            {
+                return Ok(());
+            }
+            let Some(block) = self.load_block_for_verify(&block_root, report)? else {
+                return Ok(());
+            };
+            let slot = block.slot();
+            if let Some((stop_slot, visited)) = &mut hot {
+                if slot <= *stop_slot {
+                    return Ok(());
+                }
+                visited.insert(block_root);
+            }
+            report.blocks_checked += 1;
+
+            if hot.is_none() && slot < split_slot {
+                let cold_block_root = self.get_cold_block_root(slot)?;
+                if cold_block_root.is_some_and(|root| root != block_root) {
+                    report.problems.push(Problem::FinalizedBlockRootMismatch {
+                        block_root,
+                        slot,
+                        cold_block_root,
+                    });
+                }
+            }
+
+            self.verify_block_sidecars(block_root, &block, child_parent_block_hash, report)?;
+            child_parent_block_hash = block
+                .message()
+                .body()
+                .signed_execution_payload_bid()
+                .ok()
+                .map(|bid| *bid.message().parent_block_hash());
+
+            let parent_root = block.parent_root();
+            if parent_root.is_zero() || parent_root == oldest_block_parent {
+                return Ok(());
+            }
+            if !self.block_exists(&parent_root)? {
+                report.problems.push(Problem::MissingParentBlock {
+                    block_root,
+                    slot,
+                    parent_root,
+                });
+                return Ok(());
+            }
+            block_root = parent_root;
+        }
+    }
+
+    /// Load a block, recording it as corrupt if it can't be decoded.
+    fn load_block_for_verify(
+        &self,
+        block_root: &Hash256,
+        report: &mut VerifyReport,
+    ) -> Result<Option<SignedBeaconBlock<E, BlindedPayload<E>>>, Error> {
+        match self.get_blinded_block(block_root) {
+            Ok(block) => Ok(block),
+            Err(Error::SszDecodeError(e)) => {
+                let problem = Problem::CorruptBlock {
+                    block_root: *block_root,
+                    error: format!("{e:?}"),
+                };
+                if !report.problems.contains(&problem) {
+                    report.problems.push(problem);
+                }
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Check that the envelope, blobs and data columns of a canonical block are present.
+    ///
+    /// `child_parent_block_hash` is the parent block hash of the child's bid, which reveals
+    /// whether the payload of a Gloas block was included in the canonical chain.
+    fn verify_block_sidecars(
+        &self,
+        block_root: Hash256,
+        block: &SignedBeaconBlock<E, BlindedPayload<E>>,
+        child_parent_block_hash: Option<ExecutionBlockHash>,
+        report: &mut VerifyReport,
+    ) -> Result<(), Error> {
+        let slot = block.slot();
+        let oldest_blob_slot = self.get_blob_info().oldest_blob_slot;
+        let oldest_data_column_slot = self.get_data_column_info().oldest_data_column_slot;
+        let within = |oldest: Option<Slot>| oldest.is_some_and(|oldest| slot >= oldest);
+
+        if let Ok(bid) = block.message().body().signed_execution_payload_bid() {
+            let bid = bid.message();
+            let has_envelope = self.payload_envelope_exists(&block_root)?;
+            if child_parent_block_hash == Some(*bid.block_hash()) && !has_envelope {
+                report
+                    .problems
+                    .push(Problem::MissingPayloadEnvelope { block_root, slot });
+            }
+            if has_envelope
+                && !bid.blob_kzg_commitments().is_empty()
+                && within(oldest_data_column_slot)
+                && self.get_data_column_keys(block_root)?.is_empty()
+            {
+                report
+                    .problems
+                    .push(Problem::MissingDataColumns { block_root, slot });
+            }
+        } else if block.num_expected_blobs() > 0 {
+            if block.fork_name_unchecked().fulu_enabled() {
+                if within(oldest_data_column_slot)
+                    && self.get_data_column_keys(block_root)?.is_empty()
+                {
+                    report
+                        .problems
+                        .push(Problem::MissingDataColumns { block_root, slot });
+                }
+            } else if within(oldest_blob_slot) && !self.blobs_exist(&block_root)? {
+                report
+                    .problems
+                    .push(Problem::MissingBlobs { block_root, slot });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check every stored block, returning the roots of blocks which are not canonical.
+    fn verify_stored_blocks(
+        &self,
+        hot_blocks: &HashSet<Hash256>,
+        report: &mut VerifyReport,
+    ) -> Result<HashSet<Hash256>, Error> {
+        let split = self.get_split_info();
+        let mut abandoned = HashSet::new();
+        // Finalized blocks which disagree with the freezer block roots are canonical, the freezer
+        // is wrong.
+        let mismatched_finalized_blocks = report
+            .problems
+            .iter()
+            .filter_map(|problem| match problem {
+                Problem::FinalizedBlockRootMismatch { block_root, .. } => Some(*block_root),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        for res in self.hot_db.iter_column::<Hash256>(DBColumn::BeaconBlock) {
+            let (block_root, bytes) = res?;
+            let block =
+                match SignedBeaconBlock::<E, BlindedPayload<E>>::from_ssz_bytes(&bytes, &self.spec)
+                {
+                    Ok(block) => block,
+                    Err(e) => {
+                        let problem = Problem::CorruptBlock {
+                            block_root,
+                            error: format!("{e:?}"),
+                        };
+                        if !report.problems.contains(&problem) {
+                            report.problems.push(problem);
+                        }
+                        continue;
+                    }
+                };
+            let slot = block.slot();
+
+            let is_abandoned = if slot > split.slot {
+                if hot_blocks.contains(&block_root) {
+                    false
+                } else if self.hot_db.key_exists(
+                    DBColumn::BeaconStateHotSummary,
+                    block.state_root().as_slice(),
+                )? {
+                    true
+                } else {
+                    // Blocks and their states are written and pruned together, so the block may
+                    // be canonical.
+                    report.problems.push(Problem::MissingBlockStateSummary {
+                        block_root,
+                        slot,
+                        state_root: block.state_root(),
+                    });
+                    false
+                }
+            } else if block_root == split.block_root
+                || mismatched_finalized_blocks.contains(&block_root)
+            {
+                false
+            } else {
+                // Blocks without a freezer block root (e.g. prior to the anchor) can't be checked.
+                self.get_cold_block_root(slot)?
+                    .is_some_and(|cold_block_root| cold_block_root != block_root)
+                    || slot == split.slot
+            };
+            if is_abandoned {
+                report.dangling(
+                    Database::Hot,
+                    DBColumn::BeaconBlock,
+                    block_root.as_slice(),
+                    DanglingReason::AbandonedBlock,
+                );
+                abandoned.insert(block_root);
+            }
+        }
+
+        Ok(abandoned)
+    }
+
+    /// Collect the sidecars and payloads of missing or abandoned blocks.
+    fn verify_block_data(
+        &self,
+        abandoned_blocks: &HashSet<Hash256>,
+        report: &mut VerifyReport,
+    ) -> Result<(), Error> {
+        // Data columns and execution proofs are keyed by block root followed by an index, so
+        // consecutive keys usually share a block.
+        let mut last_checked = None;
+        let mut is_orphaned = |block_root: Hash256| -> Result<bool, Error> {
+            if let Some((root, orphaned)) = last_checked
+                && root == block_root
+            {
+                return Ok(orphaned);
+            }
+            let orphaned =
+                abandoned_blocks.contains(&block_root) || !self.block_exists(&block_root)?;
+            last_checked = Some((block_root, orphaned));
+            Ok(orphaned)
+        };
+
+        for block_root in self
+            .blobs_db
+            .iter_column_keys::<Hash256>(DBColumn::BeaconBlob)
+        {
+            let block_root = block_root?;
+            if is_orphaned(block_root)? {
+                report.dangling(
+                    Database::Blobs,
+                    DBColumn::BeaconBlob,
+                    block_root.as_slice(),
+                    DanglingReason::OrphanedBlockData,
+                );
+            }
+        }
+
+        for key in self
+            .blobs_db
+            .iter_column_keys::<Vec<u8>>(DBColumn::BeaconDataColumn)
+        {
+            let key = key?;
+            let (block_root, _) = parse_data_column_key(key.clone())?;
+            if is_orphaned(block_root)? {
+                report.dangling(
+                    Database::Blobs,
+                    DBColumn::BeaconDataColumn,
+                    &key,
+                    DanglingReason::OrphanedBlockData,
+                );
+            }
+        }
+
+        for column in [DBColumn::BeaconEnvelope, DBColumn::ExecPayload] {
+            for block_root in self.hot_db.iter_column_keys::<Hash256>(column) {
+                let block_root = block_root?;
+                if is_orphaned(block_root)? {
+                    report.dangling(
+                        Database::Hot,
+                        column,
+                        block_root.as_slice(),
+                        DanglingReason::OrphanedBlockData,
+                    );
+                }
+            }
+        }
+
+        for key in self
+            .hot_db
+            .iter_column_keys::<Vec<u8>>(DBColumn::BeaconExecutionProof)
+        {
+            let key = key?;
+            let Some(block_root) = key.get(..32).map(Hash256::from_slice) else {
+                return Err(Error::InvalidKey(format!(
+                    "Unexpected BeaconExecutionProof key len {}",
+                    key.len()
+                )));
+            };
+            if is_orphaned(block_root)? {
+                report.dangling(
+                    Database::Hot,
+                    DBColumn::BeaconExecutionProof,
+                    &key,
+                    DanglingReason::OrphanedBlockData,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that every cold state summary matches the freezer state root at its slot.
+    fn verify_cold_state_summaries(&self, report: &mut VerifyReport) -> Result<(), Error> {
+        for res in self
+            .cold_db
+            .iter_column::<Hash256>(DBColumn::BeaconColdStateSummary)
+        {
+            let (state_root, bytes) = res?;
+            report.cold_state_summaries_checked += 1;
+            let slot = Slot::from_ssz_bytes(&bytes)?;
+            if self.get_cold_state_root(slot)? != Some(state_root) {
+                report.dangling(
+                    Database::Cold,
+                    DBColumn::BeaconColdStateSummary,
+                    state_root.as_slice(),
+                    DanglingReason::MismatchedColdStateSummary,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Load every hot state stored as a snapshot or diff and check its root.
+    fn verify_hot_states(
+        &self,
+        summaries: &HashMap<Hash256, HotStateSummary>,
+        report: &mut VerifyReport,
+    ) -> Result<(), Error> {
+        let mut states = summaries
+            .iter()
+            .map(|(state_root, summary)| (summary.slot, *state_root))
+            .collect::<Vec<_>>();
+        states.sort_unstable();
+
+        for (slot, state_root) in states {
+            if self.hot_storage_strategy(slot)?.is_replay_from() {
+                continue;
+            }
+            report.states_reconstructed += 1;
+            match self.load_hot_state(&state_root, false) {
+                Ok(Some((mut state, _))) => {
+                    let computed = state.update_tree_hash_cache()?;
+                    if computed != state_root {
+                        report.problems.push(Problem::StateRootMismatch {
+                            database: Database::Hot,
+                            slot,
+                            expected: state_root,
+                            computed,
+                        });
+                    }
+                }
+                Ok(None) => report.problems.push(Problem::StateReconstructionFailed {
+                    database: Database::Hot,
+                    slot,
+                    error: "state summary not found".into(),
+                }),
+                Err(e) => report.problems.push(Problem::StateReconstructionFailed {
+                    database: Database::Hot,
+                    slot,
+                    error: format!("{e:?}"),
+                }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Load every cold state stored as a snapshot or diff and check its root.
+    fn verify_cold_states(&self, report: &mut VerifyReport) -> Result<(), Error> {
+        let mut slots = vec![];
+        for column in [DBColumn::BeaconStateSnapshot, DBColumn::BeaconStateDiff] {
+            for key in self.cold_db.iter_column_keys::<Vec<u8>>(column) {
+                let key = key?;
+                let slot = key
+                    .as_slice()
+                    .try_into()
+                    .map(|bytes| Slot::new(u64::from_be_bytes(bytes)))
+                    .map_err(|_| {
+                        Error::InvalidKey(format!(
+                            "Unexpected {} key len {}",
+                            column.as_str(),
+                            key.len()
+                        ))
+                    })?;
+                slots.push(slot);
+            }
+        }
+        slots.sort_unstable();
+        slots.dedup();
+
+        for slot in slots {
+            // States prior to the state lower limit or without a root can't be checked.
+            let Some(expected) = self.get_cold_state_root(slot)? else {
+                continue;
+            };
+            report.states_reconstructed += 1;
+            match self.load_cold_state_by_slot(slot) {
+                Ok(mut state) => {
+                    let computed = state.update_tree_hash_cache()?;
+                    if computed != expected {
+                        report.problems.push(Problem::StateRootMismatch {
+                            database: Database::Cold,
+                            slot,
+                            expected,
+                            computed,
+                        });
+                    }
+                }
+                Err(e) => report.problems.push(Problem::StateReconstructionFailed {
+                    database: Database::Cold,
+                    slot,
+                    error: format!("{e:?}"),
+                }),
+            }
+        }
+        Ok(())
+    }
+}
@@ -599,7 +599,9 @@ impl<E: EthSpec> PeerManager<E> {
                     | Protocol::DataColumnsByRoot
                     | Protocol::DataColumnsByRange
                     | Protocol::ExecutionPayloadEnvelopesByRoot
-                    | Protocol::InclusionListByCommitteeIndices => PeerAction::MidToleranceError,
+                    | Protocol::ExecutionPayloadEnvelopesByRange
+                    | Protocol::InclusionListByCommitteeIndices
+                    | Protocol::ExecutionProofsByRoot => PeerAction::MidToleranceError,
                     // Vibehouse does not currently make light client requests; therefore, this
                     // is an unexpected scenario. We do not ban the peer for rate limiting.
                     Protocol::LightClientBootstrap
@@ -625,7 +627,9 @@ impl<E: EthSpec> PeerManager<E> {
                     | Protocol::DataColumnsByRoot
                     | Protocol::DataColumnsByRange
                     | Protocol::ExecutionPayloadEnvelopesByRoot
+                    | Protocol::ExecutionPayloadEnvelopesByRange
                     | Protocol::InclusionListByCommitteeIndices
+                    | Protocol::ExecutionProofsByRoot
                     | Protocol::Goodbye
                     | Protocol::LightClientBootstrap
                     | Protocol::LightClientOptimisticUpdate
@@ -648,7 +652,9 @@ impl<E: EthSpec> PeerManager<E> {
                     | Protocol::DataColumnsByRoot
                     | Protocol::DataColumnsByRange
                     | Protocol::ExecutionPayloadEnvelopesByRoot
-                    | Protocol::InclusionListByCommitteeIndices => PeerAction::MidToleranceError,
+                    | Protocol::ExecutionPayloadEnvelopesByRange
+                    | Protocol::InclusionListByCommitteeIndices
+                    | Protocol::ExecutionProofsByRoot => PeerAction::MidToleranceError,
                     Protocol::LightClientBootstrap
                     | Protocol::LightClientOptimisticUpdate
                     | Protocol::LightClientFinalityUpdate
@@ -2,7 +2,8 @@ use crate::rpc::RequestType;
 use crate::rpc::methods::{
     BlobsByRangeRequest, BlobsByRootRequest, BlocksByRootRequest, BlocksByRootRequestV1,
     BlocksByRootRequestV2, DataColumnsByRangeRequest, DataColumnsByRootRequest, ErrorType,
-    ExecutionPayloadEnvelopesByRootRequest, GoodbyeReason, InclusionListByCommitteeIndicesRequest,
+    ExecutionPayloadEnvelopesByRangeRequest, ExecutionPayloadEnvelopesByRootRequest,
+    ExecutionProofsByRootRequest, GoodbyeReason, InclusionListByCommitteeIndicesRequest,
     LightClientBootstrapRequest, LightClientUpdatesByRangeRequest, MetaData, MetaDataV1,
     MetaDataV2, MetaDataV3, MetadataRequest, OldBlocksByRangeRequest, OldBlocksByRangeRequestV1,
     OldBlocksByRangeRequestV2, Ping, RpcErrorResponse, RpcResponse, RpcSuccessResponse,
@@ -24,10 +25,10 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 use tokio_util::codec::{Decoder, Encoder};
 use types::{
-    BlobSidecar, ChainSpec, DataColumnSidecar, DataColumnsByRootIdentifier, EthSpec, ForkContext,
-    ForkName, Hash256, LightClientBootstrap, LightClientFinalityUpdate,
-    LightClientOptimisticUpdate, LightClientUpdate, RuntimeVariableList, SignedBeaconBlock,
-    SignedBeaconBlockAltair, SignedBeaconBlockBase, SignedBeaconBlockBellatrix,
+    BlobSidecar, ChainSpec, DataColumnSidecar, DataColumnsByRootIdentifier, EthSpec,
+    ExecutionProof, ForkContext, ForkName, Hash256, LightClientBootstrap,
+    LightClientFinalityUpdate, LightClientOptimisticUpdate, LightClientUpdate, RuntimeVariableList,
+    SignedBeaconBlock, SignedBeaconBlockAltair, SignedBeaconBlockBase, SignedBeaconBlockBellatrix,
     SignedBeaconBlockCapella, SignedBeaconBlockDeneb, SignedBeaconBlockElectra,
     SignedBeaconBlockFulu, SignedBeaconBlockGloas, SignedBeaconBlockHeze,
     SignedExecutionPayloadEnvelope, SignedInclusionList,
@@ -91,7 +92,9 @@ impl<E: EthSpec> SSZSnappyInboundCodec<E> {
                 RpcSuccessResponse::DataColumnsByRoot(res) => res.as_ssz_bytes(),
                 RpcSuccessResponse::DataColumnsByRange(res) => res.as_ssz_bytes(),
                 RpcSuccessResponse::ExecutionPayloadEnvelopesByRoot(res) => res.as_ssz_bytes(),
+                RpcSuccessResponse::ExecutionPayloadEnvelopesByRange(res) => res.as_ssz_bytes(),
                 RpcSuccessResponse::InclusionListByCommitteeIndices(res) => res.as_ssz_bytes(),
+                RpcSuccessResponse::ExecutionProofsByRoot(res) => res.as_ssz_bytes(),
                 RpcSuccessResponse::LightClientBootstrap(res) => res.as_ssz_bytes(),
                 RpcSuccessResponse::LightClientOptimisticUpdate(res) => res.as_ssz_bytes(),
                 RpcSuccessResponse::LightClientFinalityUpdate(res) => res.as_ssz_bytes(),
@@ -372,7 +375,9 @@ impl<E: EthSpec> Encoder<RequestType<E>> for SSZSnappyOutboundCodec<E> {
             RequestType::DataColumnsByRange(req) => req.as_ssz_bytes(),
             RequestType::DataColumnsByRoot(req) => req.data_column_ids.as_ssz_bytes(),
             RequestType::ExecutionPayloadEnvelopesByRoot(req) => req.block_roots.as_ssz_bytes(),
+            RequestType::ExecutionPayloadEnvelopesByRange(req) => req.as_ssz_bytes(),
             RequestType::InclusionListByCommitteeIndices(req) => req.as_ssz_bytes(),
+            RequestType::ExecutionProofsByRoot(req) => req.block_roots.as_ssz_bytes(),
             RequestType::Ping(req) => req.as_ssz_bytes(),
             RequestType::LightClientBootstrap(req) => req.as_ssz_bytes(),
             RequestType::LightClientUpdatesByRange(req) => req.as_ssz_bytes(),
@@ -591,11 +596,24 @@ fn handle_rpc_request<E: EthSpec>(
                 )?,
             }),
         )),
+        SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1 => {
+            Ok(Some(RequestType::ExecutionPayloadEnvelopesByRange(
+                ExecutionPayloadEnvelopesByRangeRequest::from_ssz_bytes(decoded_buffer)?,
+            )))
+        }
         SupportedProtocol::InclusionListByCommitteeIndicesV1 => {
             Ok(Some(RequestType::InclusionListByCommitteeIndices(
                 InclusionListByCommitteeIndicesRequest::from_ssz_bytes(decoded_buffer)?,
             )))
         }
+        SupportedProtocol::ExecutionProofsByRootV1 => Ok(Some(RequestType::ExecutionProofsByRoot(
+            ExecutionProofsByRootRequest {
+                block_roots: RuntimeVariableList::from_ssz_bytes(
+                    decoded_buffer,
+                    spec.max_request_payloads,
+                )?,
+            },
+        ))),
         SupportedProtocol::PingV1 => Ok(Some(RequestType::Ping(Ping {
             data: u64::from_ssz_bytes(decoded_buffer)?,
         }))),
@@ -818,6 +836,26 @@ fn handle_rpc_response<E: EthSpec>(
                 format!("No context bytes provided for {versioned_protocol:?} response"),
             )),
         },
+        SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1 => match fork_name {
+            Some(fork_name) => {
+                if fork_name.gloas_enabled() {
+                    Ok(Some(RpcSuccessResponse::ExecutionPayloadEnvelopesByRange(
+                        Arc::new(SignedExecutionPayloadEnvelope::from_ssz_bytes(
+                            decoded_buffer,
+                        )?),
+                    )))
+                } else {
+                    Err(RPCError::ErrorResponse(
+                        RpcErrorResponse::InvalidRequest,
+                        "Invalid fork name for execution payload envelopes by range".to_string(),
+                    ))
+                }
+            }
+            None => Err(RPCError::ErrorResponse(
+                RpcErrorResponse::InvalidRequest,
+                format!("No context bytes provided for {versioned_protocol:?} response"),
+            )),
+        },
         SupportedProtocol::InclusionListByCommitteeIndicesV1 => match fork_name {
             Some(fork_name) => {
                 if fork_name.heze_enabled() {
@@ -836,6 +874,12 @@ fn handle_rpc_response<E: EthSpec>(
                 format!("No context bytes provided for {versioned_protocol:?} response"),
             )),
         },
+        // Execution proofs are not fork-versioned and have no context bytes.
+        SupportedProtocol::ExecutionProofsByRootV1 => {
+            Ok(Some(RpcSuccessResponse::ExecutionProofsByRoot(Arc::new(
+                ExecutionProof::from_ssz_bytes(decoded_buffer)?,
+            ))))
+        }
         // MetaData V2/V3 responses have no context bytes, so behave similarly to V1 responses
         SupportedProtocol::MetaDataV3 => Ok(Some(RpcSuccessResponse::MetaData(Arc::new(
             MetaData::V3(MetaDataV3::from_ssz_bytes(decoded_buffer)?),
@@ -1122,6 +1166,28 @@ mod tests {
         }
     }
 
+    fn eproots_request(spec: &ChainSpec) -> ExecutionProofsByRootRequest {
+        ExecutionProofsByRootRequest::new(vec![Hash256::zero(), Hash256::repeat_byte(1)], spec)
+            .unwrap()
+    }
+
+    fn execution_proof() -> ExecutionProof {
+        ExecutionProof::new(
+            Hash256::repeat_byte(1),
+            types::ExecutionBlockHash::repeat_byte(2),
+            types::ExecutionProofSubnetId::new(0).unwrap(),
+            1,
+            vec![0xab; 64],
+        )
+    }
+
+    fn epbrange_request() -> ExecutionPayloadEnvelopesByRangeRequest {
+        ExecutionPayloadEnvelopesByRangeRequest {
+            start_slot: 0,
+            count: 10,
+        }
+    }
+
     fn dcbroot_request(fork_name: ForkName, spec: &ChainSpec) -> DataColumnsByRootRequest<Spec> {
         DataColumnsByRootRequest {
             data_column_ids: RuntimeVariableList::new(
@@ -1332,9 +1398,39 @@ mod tests {
                     RequestType::ExecutionPayloadEnvelopesByRoot(epbroots)
                 );
             }
+            RequestType::ExecutionPayloadEnvelopesByRange(epbrange) => {
+                assert_eq!(
+                    decoded,
+                    RequestType::ExecutionPayloadEnvelopesByRange(epbrange)
+                );
+            }
             RequestType::InclusionListByCommitteeIndices(ilbci) => {
                 assert_eq!(decoded, RequestType::InclusionListByCommitteeIndices(ilbci));
             }
+            RequestType::ExecutionProofsByRoot(eproots) => {
+                assert_eq!(decoded, RequestType::ExecutionProofsByRoot(eproots));
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_then_decode_execution_proofs_by_root() {
+        let chain_spec = spec_with_all_forks_enabled();
+        let proof = Arc::new(execution_proof());
+
+        // Proofs carry no context bytes, so they round trip regardless of the fork.
+        for fork_name in [ForkName::Gloas, ForkName::Heze] {
+            assert_eq!(
+                encode_then_decode_response(
+                    SupportedProtocol::ExecutionProofsByRootV1,
+                    RpcResponse::Success(RpcSuccessResponse::ExecutionProofsByRoot(proof.clone())),
+                    fork_name,
+                    &chain_spec,
+                ),
+                Ok(Some(RpcSuccessResponse::ExecutionProofsByRoot(
+                    proof.clone()
+                )))
+            );
         }
     }
 
@@ -2041,6 +2137,8 @@ mod tests {
             RequestType::MetaData(MetadataRequest::new_v1()),
             RequestType::BlobsByRange(blbrange_request()),
             RequestType::DataColumnsByRange(dcbrange_request()),
+            RequestType::ExecutionPayloadEnvelopesByRange(epbrange_request()),
+            RequestType::ExecutionProofsByRoot(eproots_request(&chain_spec)),
             RequestType::MetaData(MetadataRequest::new_v2()),
         ];
         for req in requests {
@@ -98,7 +98,9 @@ pub struct RateLimiterConfig {
     pub(super) light_client_finality_update_quota: Quota,
     pub(super) light_client_updates_by_range_quota: Quota,
     pub(super) execution_payload_envelopes_by_root_quota: Quota,
+    pub(super) execution_payload_envelopes_by_range_quota: Quota,
     pub(super) inclusion_list_by_committee_indices_quota: Quota,
+    pub(super) execution_proofs_by_root_quota: Quota,
 }
 
 impl RateLimiterConfig {
@@ -131,9 +133,15 @@ impl RateLimiterConfig {
     // Allow up to `MAX_REQUEST_PAYLOADS` (128) payloads per request from the Gloas p2p spec.
     pub const DEFAULT_EXECUTION_PAYLOAD_ENVELOPES_BY_ROOT_QUOTA: Quota =
         Quota::n_every(NonZeroU64::new(128).unwrap(), 10);
+    // Matches `DEFAULT_BLOCKS_BY_RANGE_QUOTA`, since range sync requests one envelope per block.
+    pub const DEFAULT_EXECUTION_PAYLOAD_ENVELOPES_BY_RANGE_QUOTA: Quota =
+        Quota::n_every(NonZeroU64::new(128).unwrap(), 10);
     // Allow up to `INCLUSION_LIST_COMMITTEE_SIZE` (16) inclusion lists per request from the Heze p2p spec.
     pub const DEFAULT_INCLUSION_LIST_BY_COMMITTEE_INDICES_QUOTA: Quota =
         Quota::n_every(NonZeroU64::new(16).unwrap(), 10);
+    // Allow up to `MAX_REQUEST_PAYLOADS` (128) roots per request, one proof per subnet each.
+    pub const DEFAULT_EXECUTION_PROOFS_BY_ROOT_QUOTA: Quota =
+        Quota::n_every(NonZeroU64::new(128).unwrap(), 10);
 }
 
 impl Default for RateLimiterConfig {
@@ -156,8 +164,11 @@ impl Default for RateLimiterConfig {
             light_client_updates_by_range_quota: Self::DEFAULT_LIGHT_CLIENT_UPDATES_BY_RANGE_QUOTA,
             execution_payload_envelopes_by_root_quota:
                 Self::DEFAULT_EXECUTION_PAYLOAD_ENVELOPES_BY_ROOT_QUOTA,
+            execution_payload_envelopes_by_range_quota:
+                Self::DEFAULT_EXECUTION_PAYLOAD_ENVELOPES_BY_RANGE_QUOTA,
             inclusion_list_by_committee_indices_quota:
                 Self::DEFAULT_INCLUSION_LIST_BY_COMMITTEE_INDICES_QUOTA,
+            execution_proofs_by_root_quota: Self::DEFAULT_EXECUTION_PROOFS_BY_ROOT_QUOTA,
         }
     }
 }
@@ -211,10 +222,18 @@ impl Debug for RateLimiterConfig {
                 "execution_payload_envelopes_by_root",
                 fmt_q!(&self.execution_payload_envelopes_by_root_quota),
             )
+            .field(
+                "execution_payload_envelopes_by_range",
+                fmt_q!(&self.execution_payload_envelopes_by_range_quota),
+            )
             .field(
                 "inclusion_list_by_committee_indices",
                 fmt_q!(&self.inclusion_list_by_committee_indices_quota),
             )
+            .field(
+                "execution_proofs_by_root",
+                fmt_q!(&self.execution_proofs_by_root_quota),
+            )
             .finish()
     }
 }
@@ -242,7 +261,9 @@ impl FromStr for RateLimiterConfig {
         let mut light_client_finality_update_quota = None;
         let mut light_client_updates_by_range_quota = None;
         let mut execution_payload_envelopes_by_root_quota = None;
+        let mut execution_payload_envelopes_by_range_quota = None;
         let mut inclusion_list_by_committee_indices_quota = None;
+        let mut execution_proofs_by_root_quota = None;
 
         for proto_def in s.split(';') {
             let ProtocolQuota { protocol, quota } = proto_def.parse()?;
@@ -281,10 +302,17 @@ impl FromStr for RateLimiterConfig {
                     execution_payload_envelopes_by_root_quota =
                         execution_payload_envelopes_by_root_quota.or(quota);
                 }
+                Protocol::ExecutionPayloadEnvelopesByRange => {
+                    execution_payload_envelopes_by_range_quota =
+                        execution_payload_envelopes_by_range_quota.or(quota);
+                }
                 Protocol::InclusionListByCommitteeIndices => {
                     inclusion_list_by_committee_indices_quota =
                         inclusion_list_by_committee_indices_quota.or(quota);
                 }
+                Protocol::ExecutionProofsByRoot => {
+                    execution_proofs_by_root_quota = execution_proofs_by_root_quota.or(quota);
+                }
             }
         }
         Ok(RateLimiterConfig {
@@ -313,8 +341,12 @@ impl FromStr for RateLimiterConfig {
                 .unwrap_or(Self::DEFAULT_LIGHT_CLIENT_UPDATES_BY_RANGE_QUOTA),
             execution_payload_envelopes_by_root_quota: execution_payload_envelopes_by_root_quota
                 .unwrap_or(Self::DEFAULT_EXECUTION_PAYLOAD_ENVELOPES_BY_ROOT_QUOTA),
+            execution_payload_envelopes_by_range_quota: execution_payload_envelopes_by_range_quota
+                .unwrap_or(Self::DEFAULT_EXECUTION_PAYLOAD_ENVELOPES_BY_RANGE_QUOTA),
             inclusion_list_by_committee_indices_quota: inclusion_list_by_committee_indices_quota
                 .unwrap_or(Self::DEFAULT_INCLUSION_LIST_BY_COMMITTEE_INDICES_QUOTA),
+            execution_proofs_by_root_quota: execution_proofs_by_root_quota
+                .unwrap_or(Self::DEFAULT_EXECUTION_PROOFS_BY_ROOT_QUOTA),
         })
     }
 }
@@ -948,6 +948,20 @@ where
                     return;
                 }
             }
+            RequestType::ExecutionPayloadEnvelopesByRange(request) => {
+                let max_allowed = spec.max_request_payloads as u64;
+                if request.count > max_allowed {
+                    self.events_out.push(HandlerEvent::Err(HandlerErr::Inbound {
+                        id: self.current_inbound_substream_id,
+                        proto: Protocol::ExecutionPayloadEnvelopesByRange,
+                        error: RPCError::InvalidData(format!(
+                            "requested exceeded limit. allowed: {}, requested: {}",
+                            max_allowed, request.count
+                        )),
+                    }));
+                    return;
+                }
+            }
             _ => {}
         }
 
@@ -16,7 +16,7 @@ use types::blob_sidecar::BlobIdentifier;
 use types::light_client_update::MAX_REQUEST_LIGHT_CLIENT_UPDATES;
 use types::{
     ChainSpec, ColumnIndex, DataColumnSidecar, DataColumnsByRootIdentifier, Epoch, EthSpec,
-    ForkContext, Hash256, LightClientBootstrap, LightClientFinalityUpdate,
+    ExecutionProof, ForkContext, Hash256, LightClientBootstrap, LightClientFinalityUpdate,
     LightClientOptimisticUpdate, LightClientUpdate, RuntimeVariableList, SignedBeaconBlock,
     SignedExecutionPayloadEnvelope, SignedInclusionList, Slot, blob_sidecar::BlobSidecar,
 };
@@ -568,6 +568,38 @@ impl ExecutionPayloadEnvelopesByRootRequest {
     }
 }
 
+/// Request all known execution proofs for a number of beacon block roots.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionProofsByRootRequest {
+    /// The list of beacon block roots for which proofs are requested.
+    pub block_roots: RuntimeVariableList<Hash256>,
+}
+
+impl ExecutionProofsByRootRequest {
+    pub fn new(block_roots: Vec<Hash256>, spec: &ChainSpec) -> Result<Self, String> {
+        let block_roots = RuntimeVariableList::new(block_roots, spec.max_request_payloads)
+            .map_err(|e| format!("ExecutionProofsByRootRequest too many roots: {e:?}"))?;
+        Ok(Self { block_roots })
+    }
+
+    /// A peer may return at most one proof per subnet for each requested root.
+    pub fn max_requested(&self) -> u64 {
+        (self.block_roots.len() as u64)
+            .saturating_mul(types::execution_proof_subnet_id::MAX_EXECUTION_PROOF_SUBNETS)
+    }
+}
+
+/// Request a number of execution payload envelopes from a peer by slot range.
+///
+/// Spec: `(start_slot: Slot, count: uint64)`
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionPayloadEnvelopesByRangeRequest {
+    /// The starting slot to request envelopes.
+    pub start_slot: u64,
+    /// The number of slots from the start slot.
+    pub count: u64,
+}
+
 /// Request inclusion lists from a peer by committee indices.
 ///
 /// Spec: `(slot: Slot, committee_indices: Bitvector\[INCLUSION_LIST_COMMITTEE_SIZE\])`
@@ -720,9 +752,15 @@ pub enum RpcSuccessResponse<E: EthSpec> {
     /// A response to a get EXECUTION_PAYLOAD_ENVELOPES_BY_ROOT request.
     ExecutionPayloadEnvelopesByRoot(Arc<SignedExecutionPayloadEnvelope<E>>),
 
+    /// A response to a get EXECUTION_PAYLOAD_ENVELOPES_BY_RANGE request.
+    ExecutionPayloadEnvelopesByRange(Arc<SignedExecutionPayloadEnvelope<E>>),
+
     /// A response to a get INCLUSION_LIST_BY_COMMITTEE_INDICES request.
     InclusionListByCommitteeIndices(Arc<SignedInclusionList<E>>),
 
+    /// A response to a get EXECUTION_PROOFS_BY_ROOT request.
+    ExecutionProofsByRoot(Arc<ExecutionProof>),
+
     /// A PONG response to a PING request.
     Pong(Ping),
 
@@ -757,8 +795,14 @@ pub enum ResponseTermination {
     /// Execution payload envelopes by root stream termination.
     ExecutionPayloadEnvelopesByRoot,
 
+    /// Execution payload envelopes by range stream termination.
+    ExecutionPayloadEnvelopesByRange,
+
     /// Inclusion list by committee indices stream termination.
     InclusionListByCommitteeIndices,
+
+    /// Execution proofs by root stream termination.
+    ExecutionProofsByRoot,
 }
 
 impl ResponseTermination {
@@ -774,9 +818,13 @@ impl ResponseTermination {
             ResponseTermination::ExecutionPayloadEnvelopesByRoot => {
                 Protocol::ExecutionPayloadEnvelopesByRoot
             }
+            ResponseTermination::ExecutionPayloadEnvelopesByRange => {
+                Protocol::ExecutionPayloadEnvelopesByRange
+            }
             ResponseTermination::InclusionListByCommitteeIndices => {
                 Protocol::InclusionListByCommitteeIndices
             }
+            ResponseTermination::ExecutionProofsByRoot => Protocol::ExecutionProofsByRoot,
         }
     }
 }
@@ -882,9 +930,13 @@ impl<E: EthSpec> RpcSuccessResponse<E> {
             RpcSuccessResponse::ExecutionPayloadEnvelopesByRoot(_) => {
                 Protocol::ExecutionPayloadEnvelopesByRoot
             }
+            RpcSuccessResponse::ExecutionPayloadEnvelopesByRange(_) => {
+                Protocol::ExecutionPayloadEnvelopesByRange
+            }
             RpcSuccessResponse::InclusionListByCommitteeIndices(_) => {
                 Protocol::InclusionListByCommitteeIndices
             }
+            RpcSuccessResponse::ExecutionProofsByRoot(_) => Protocol::ExecutionProofsByRoot,
         }
     }
 
@@ -899,9 +951,13 @@ impl<E: EthSpec> RpcSuccessResponse<E> {
             Self::LightClientFinalityUpdate(r) => Some(r.get_attested_header_slot()),
             Self::LightClientOptimisticUpdate(r) => Some(r.get_slot()),
             Self::LightClientUpdatesByRange(r) => Some(r.attested_header_slot()),
-            Self::ExecutionPayloadEnvelopesByRoot(r) => Some(r.message.slot),
+            Self::ExecutionPayloadEnvelopesByRoot(r)
+            | Self::ExecutionPayloadEnvelopesByRange(r) => Some(r.message.slot),
             Self::InclusionListByCommitteeIndices(r) => Some(r.message.slot),
-            Self::MetaData(_) | Self::Status(_) | Self::Pong(_) => None,
+            Self::MetaData(_)
+            | Self::Status(_)
+            | Self::Pong(_)
+            | Self::ExecutionProofsByRoot(_) => None,
         }
     }
 }
@@ -996,6 +1052,13 @@ impl<E: EthSpec> std::fmt::Display for RpcSuccessResponse<E> {
                     envelope.message.slot,
                 )
             }
+            RpcSuccessResponse::ExecutionPayloadEnvelopesByRange(envelope) => {
+                write!(
+                    f,
+                    "ExecutionPayloadEnvelopesByRange Slot: {}",
+                    envelope.message.slot,
+                )
+            }
             RpcSuccessResponse::InclusionListByCommitteeIndices(il) => {
                 write!(
                     f,
@@ -1003,6 +1066,13 @@ impl<E: EthSpec> std::fmt::Display for RpcSuccessResponse<E> {
                     il.message.slot,
                 )
             }
+            RpcSuccessResponse::ExecutionProofsByRoot(proof) => {
+                write!(
+                    f,
+                    "ExecutionProofsByRoot Block Root: {}, Subnet: {}",
+                    proof.block_root, proof.subnet_id,
+                )
+            }
         }
     }
 }
@@ -1076,6 +1146,16 @@ impl std::fmt::Display for BlobsByRangeRequest {
     }
 }
 
+impl std::fmt::Display for ExecutionPayloadEnvelopesByRangeRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Request: ExecutionPayloadEnvelopesByRange: Start Slot: {}, Count: {}",
+            self.start_slot, self.count
+        )
+    }
+}
+
 impl<E: EthSpec> std::fmt::Display for DataColumnsByRootRequest<E> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -1322,6 +1402,31 @@ mod tests {
             ResponseTermination::ExecutionPayloadEnvelopesByRoot.as_protocol(),
             Protocol::ExecutionPayloadEnvelopesByRoot
         );
+        assert_eq!(
+            ResponseTermination::ExecutionPayloadEnvelopesByRange.as_protocol(),
+            Protocol::ExecutionPayloadEnvelopesByRange
+        );
+        assert_eq!(
+            ResponseTermination::ExecutionProofsByRoot.as_protocol(),
+            Protocol::ExecutionProofsByRoot
+        );
+    }
+
+    #[test]
+    fn execution_payload_envelopes_by_range_request_ssz_round_trip() {
+        let req = ExecutionPayloadEnvelopesByRangeRequest {
+            start_slot: 64,
+            count: 32,
+        };
+        let bytes = req.as_ssz_bytes();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(
+            ExecutionPayloadEnvelopesByRangeRequest::from_ssz_bytes(&bytes).unwrap(),
+            req
+        );
+        let s = format!("{req}");
+        assert!(s.contains("64"));
+        assert!(s.contains("32"));
     }
 
     // ── BlocksByRangeRequest ──────────────────────────────────
@@ -1,6 +1,7 @@
 use super::methods::{
     BlobsByRangeRequest, BlobsByRootRequest, BlocksByRootRequest, DataColumnsByRangeRequest,
-    DataColumnsByRootRequest, ExecutionPayloadEnvelopesByRootRequest, GoodbyeReason,
+    DataColumnsByRootRequest, ExecutionPayloadEnvelopesByRangeRequest,
+    ExecutionPayloadEnvelopesByRootRequest, ExecutionProofsByRootRequest, GoodbyeReason,
     InclusionListByCommitteeIndicesRequest, LightClientBootstrapRequest,
     LightClientUpdatesByRangeRequest, MAX_ERROR_LEN, MaxErrorLen, MetaDataV1, MetaDataV3,
     MetadataRequest, OldBlocksByRangeRequest, OldBlocksByRangeRequestV2, Ping, ResponseTermination,
@@ -28,6 +29,7 @@ use types::{
     LightClientBootstrapAltair, LightClientFinalityUpdate, LightClientFinalityUpdateAltair,
     LightClientOptimisticUpdate, LightClientOptimisticUpdateAltair, LightClientUpdate,
     MainnetEthSpec, MinimalEthSpec, Signature, SignedBeaconBlock,
+    execution_proof::MAX_EXECUTION_PROOF_SIZE,
 };
 
 // Note: Hardcoding the `EthSpec` type for `SignedBeaconBlock` as min/max values is
@@ -78,6 +80,12 @@ pub(super) static SIGNED_EXECUTION_PAYLOAD_ENVELOPE_MAX: LazyLock<usize> = LazyL
     *SIGNED_BEACON_BLOCK_BELLATRIX_MAX
 });
 
+/// Maximum SSZ-encoded size of an `ExecutionProof` response chunk.
+///
+/// SSZ structure: block_root (32) + block_hash (32) + subnet_id (8) + version (8) + proof_data
+/// offset (4) + at most `MAX_EXECUTION_PROOF_SIZE` bytes of proof data.
+pub(super) const EXECUTION_PROOF_MAX: usize = 32 + 32 + 8 + 8 + 4 + MAX_EXECUTION_PROOF_SIZE;
+
 /// Maximum SSZ-encoded size of a SignedInclusionList response chunk.
 ///
 /// SSZ structure: SignedInclusionList container (100 fixed) + InclusionList container (52 fixed)
@@ -297,9 +305,15 @@ pub enum Protocol {
     /// The `ExecutionPayloadEnvelopesByRoot` protocol name.
     #[strum(serialize = "execution_payload_envelopes_by_root")]
     ExecutionPayloadEnvelopesByRoot,
+    /// The `ExecutionPayloadEnvelopesByRange` protocol name.
+    #[strum(serialize = "execution_payload_envelopes_by_range")]
+    ExecutionPayloadEnvelopesByRange,
     /// The `InclusionListByCommitteeIndices` protocol name.
     #[strum(serialize = "inclusion_list_by_committee_indices")]
     InclusionListByCommitteeIndices,
+    /// The `ExecutionProofsByRoot` protocol name.
+    #[strum(serialize = "execution_proofs_by_root")]
+    ExecutionProofsByRoot,
 }
 
 impl Protocol {
@@ -322,9 +336,13 @@ impl Protocol {
             Protocol::ExecutionPayloadEnvelopesByRoot => {
                 Some(ResponseTermination::ExecutionPayloadEnvelopesByRoot)
             }
+            Protocol::ExecutionPayloadEnvelopesByRange => {
+                Some(ResponseTermination::ExecutionPayloadEnvelopesByRange)
+            }
             Protocol::InclusionListByCommitteeIndices => {
                 Some(ResponseTermination::InclusionListByCommitteeIndices)
             }
+            Protocol::ExecutionProofsByRoot => Some(ResponseTermination::ExecutionProofsByRoot),
         }
     }
 }
@@ -358,7 +376,9 @@ pub enum SupportedProtocol {
     LightClientFinalityUpdateV1,
     LightClientUpdatesByRangeV1,
     ExecutionPayloadEnvelopesByRootV1,
+    ExecutionPayloadEnvelopesByRangeV1,
     InclusionListByCommitteeIndicesV1,
+    ExecutionProofsByRootV1,
 }
 
 impl SupportedProtocol {
@@ -379,7 +399,9 @@ impl SupportedProtocol {
             | SupportedProtocol::LightClientFinalityUpdateV1
             | SupportedProtocol::LightClientUpdatesByRangeV1
             | SupportedProtocol::ExecutionPayloadEnvelopesByRootV1
-            | SupportedProtocol::InclusionListByCommitteeIndicesV1 => "1",
+            | SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1
+            | SupportedProtocol::InclusionListByCommitteeIndicesV1
+            | SupportedProtocol::ExecutionProofsByRootV1 => "1",
             SupportedProtocol::StatusV2
             | SupportedProtocol::BlocksByRangeV2
             | SupportedProtocol::BlocksByRootV2
@@ -415,9 +437,13 @@ impl SupportedProtocol {
             SupportedProtocol::ExecutionPayloadEnvelopesByRootV1 => {
                 Protocol::ExecutionPayloadEnvelopesByRoot
             }
+            SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1 => {
+                Protocol::ExecutionPayloadEnvelopesByRange
+            }
             SupportedProtocol::InclusionListByCommitteeIndicesV1 => {
                 Protocol::InclusionListByCommitteeIndices
             }
+            SupportedProtocol::ExecutionProofsByRootV1 => Protocol::ExecutionProofsByRoot,
         }
     }
 
@@ -459,10 +485,20 @@ impl SupportedProtocol {
             ]);
         }
         if fork_context.fork_exists(ForkName::Gloas) {
-            supported.push(ProtocolId::new(
-                SupportedProtocol::ExecutionPayloadEnvelopesByRootV1,
-                Encoding::SSZSnappy,
-            ));
+            supported.extend_from_slice(&[
+                ProtocolId::new(
+                    SupportedProtocol::ExecutionPayloadEnvelopesByRootV1,
+                    Encoding::SSZSnappy,
+                ),
+                ProtocolId::new(
+                    SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1,
+                    Encoding::SSZSnappy,
+                ),
+                ProtocolId::new(
+                    SupportedProtocol::ExecutionProofsByRootV1,
+                    Encoding::SSZSnappy,
+                ),
+            ]);
         }
         if fork_context.fork_exists(ForkName::Heze) {
             supported.push(ProtocolId::new(
@@ -600,10 +636,18 @@ impl ProtocolId {
             Protocol::ExecutionPayloadEnvelopesByRoot => {
                 RpcLimits::new(0, spec.max_execution_payload_envelopes_by_root_request)
             }
+            Protocol::ExecutionPayloadEnvelopesByRange => RpcLimits::new(
+                <ExecutionPayloadEnvelopesByRangeRequest as Encode>::ssz_fixed_len(),
+                <ExecutionPayloadEnvelopesByRangeRequest as Encode>::ssz_fixed_len(),
+            ),
             Protocol::InclusionListByCommitteeIndices => {
                 // Request is fixed: slot (8 bytes) + Bitvector[16] (2 bytes) = 10 bytes.
                 RpcLimits::new(10, 10)
             }
+            // Same shape as envelopes by root: a list of at most `MAX_REQUEST_PAYLOADS` roots.
+            Protocol::ExecutionProofsByRoot => {
+                RpcLimits::new(0, spec.max_execution_payload_envelopes_by_root_request)
+            }
         }
     }
 
@@ -642,12 +686,14 @@ impl ProtocolId {
             Protocol::LightClientUpdatesByRange => {
                 rpc_light_client_updates_by_range_limits_by_fork(fork_context.current_fork_name())
             }
-            Protocol::ExecutionPayloadEnvelopesByRoot => {
+            Protocol::ExecutionPayloadEnvelopesByRoot
+            | Protocol::ExecutionPayloadEnvelopesByRange => {
                 RpcLimits::new(0, *SIGNED_EXECUTION_PAYLOAD_ENVELOPE_MAX)
             }
             Protocol::InclusionListByCommitteeIndices => {
                 RpcLimits::new(0, SIGNED_INCLUSION_LIST_MAX)
             }
+            Protocol::ExecutionProofsByRoot => RpcLimits::new(0, EXECUTION_PROOF_MAX),
         }
     }
 
@@ -666,8 +712,12 @@ impl ProtocolId {
             | SupportedProtocol::LightClientFinalityUpdateV1
             | SupportedProtocol::LightClientUpdatesByRangeV1
             | SupportedProtocol::ExecutionPayloadEnvelopesByRootV1
+            | SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1
             | SupportedProtocol::InclusionListByCommitteeIndicesV1 => true,
-            SupportedProtocol::StatusV1
+            // Execution proofs are not fork-versioned and carry no slot to derive a fork
+            // digest from.
+            SupportedProtocol::ExecutionProofsByRootV1
+            | SupportedProtocol::StatusV1
             | SupportedProtocol::StatusV2
             | SupportedProtocol::BlocksByRootV1
             | SupportedProtocol::BlocksByRangeV1
@@ -806,7 +856,9 @@ pub enum RequestType<E: EthSpec> {
     LightClientFinalityUpdate,
     LightClientUpdatesByRange(LightClientUpdatesByRangeRequest),
     ExecutionPayloadEnvelopesByRoot(ExecutionPayloadEnvelopesByRootRequest),
+    ExecutionPayloadEnvelopesByRange(ExecutionPayloadEnvelopesByRangeRequest),
     InclusionListByCommitteeIndices(InclusionListByCommitteeIndicesRequest),
+    ExecutionProofsByRoot(ExecutionProofsByRootRequest),
     Ping(Ping),
     MetaData(MetadataRequest<E>),
 }
@@ -833,9 +885,11 @@ impl<E: EthSpec> RequestType<E> {
             RequestType::DataColumnsByRange(req) => req.max_requested::<E>(),
             RequestType::LightClientUpdatesByRange(req) => req.count,
             RequestType::ExecutionPayloadEnvelopesByRoot(req) => req.block_roots.len() as u64,
+            RequestType::ExecutionPayloadEnvelopesByRange(req) => req.count,
             RequestType::InclusionListByCommitteeIndices(req) => {
                 req.requested_positions().len() as u64
             }
+            RequestType::ExecutionProofsByRoot(req) => req.max_requested(),
         }
     }
 
@@ -878,9 +932,13 @@ impl<E: EthSpec> RequestType<E> {
             RequestType::ExecutionPayloadEnvelopesByRoot(_) => {
                 SupportedProtocol::ExecutionPayloadEnvelopesByRootV1
             }
+            RequestType::ExecutionPayloadEnvelopesByRange(_) => {
+                SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1
+            }
             RequestType::InclusionListByCommitteeIndices(_) => {
                 SupportedProtocol::InclusionListByCommitteeIndicesV1
             }
+            RequestType::ExecutionProofsByRoot(_) => SupportedProtocol::ExecutionProofsByRootV1,
         }
     }
 
@@ -899,9 +957,13 @@ impl<E: EthSpec> RequestType<E> {
             RequestType::ExecutionPayloadEnvelopesByRoot(_) => {
                 ResponseTermination::ExecutionPayloadEnvelopesByRoot
             }
+            RequestType::ExecutionPayloadEnvelopesByRange(_) => {
+                ResponseTermination::ExecutionPayloadEnvelopesByRange
+            }
             RequestType::InclusionListByCommitteeIndices(_) => {
                 ResponseTermination::InclusionListByCommitteeIndices
             }
+            RequestType::ExecutionProofsByRoot(_) => ResponseTermination::ExecutionProofsByRoot,
             RequestType::Status(_)
             | RequestType::Goodbye(_)
             | RequestType::Ping(_)
@@ -977,10 +1039,18 @@ impl<E: EthSpec> RequestType<E> {
                 SupportedProtocol::ExecutionPayloadEnvelopesByRootV1,
                 Encoding::SSZSnappy,
             )],
+            RequestType::ExecutionPayloadEnvelopesByRange(_) => vec![ProtocolId::new(
+                SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1,
+                Encoding::SSZSnappy,
+            )],
             RequestType::InclusionListByCommitteeIndices(_) => vec![ProtocolId::new(
                 SupportedProtocol::InclusionListByCommitteeIndicesV1,
                 Encoding::SSZSnappy,
             )],
+            RequestType::ExecutionProofsByRoot(_) => vec![ProtocolId::new(
+                SupportedProtocol::ExecutionProofsByRootV1,
+                Encoding::SSZSnappy,
+            )],
         }
     }
 
@@ -1001,7 +1071,9 @@ impl<E: EthSpec> RequestType<E> {
             | RequestType::DataColumnsByRoot(_)
             | RequestType::DataColumnsByRange(_)
             | RequestType::ExecutionPayloadEnvelopesByRoot(_)
-            | RequestType::InclusionListByCommitteeIndices(_) => false,
+            | RequestType::ExecutionPayloadEnvelopesByRange(_)
+            | RequestType::InclusionListByCommitteeIndices(_)
+            | RequestType::ExecutionProofsByRoot(_) => false,
         }
     }
 }
@@ -1114,12 +1186,20 @@ impl<E: EthSpec> std::fmt::Display for RequestType<E> {
                 "Execution payload envelopes by root: {} roots",
                 req.block_roots.len()
             ),
+            RequestType::ExecutionPayloadEnvelopesByRange(req) => {
+                write!(f, "Execution payload envelopes by range: {req}")
+            }
             RequestType::InclusionListByCommitteeIndices(req) => write!(
                 f,
                 "Inclusion list by committee indices: slot {}, {} positions",
                 req.slot,
                 req.requested_positions().len()
             ),
+            RequestType::ExecutionProofsByRoot(req) => write!(
+                f,
+                "Execution proofs by root: {} roots",
+                req.block_roots.len()
+            ),
         }
     }
 }
@@ -1387,6 +1467,13 @@ mod tests {
             has_envelope,
             "Gloas fork context should include ExecutionPayloadEnvelopesByRootV1"
         );
+        let has_envelope_range = protocols
+            .iter()
+            .any(|p| p.versioned_protocol == SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1);
+        assert!(
+            has_envelope_range,
+            "Gloas fork context should include ExecutionPayloadEnvelopesByRangeV1"
+        );
     }
 
     #[test]
@@ -1474,6 +1561,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn protocol_id_format_envelope_by_range() {
+        let pid = ProtocolId::new(
+            SupportedProtocol::ExecutionPayloadEnvelopesByRangeV1,
+            Encoding::SSZSnappy,
+        );
+        assert_eq!(
+            pid.as_ref(),
+            "/eth2/beacon_chain/req/execution_payload_envelopes_by_range/1/ssz_snappy"
+        );
+        assert!(pid.has_context_bytes());
+    }
+
     #[test]
     fn protocol_id_has_context_bytes_true() {
         let context_protocols = [
@@ -115,8 +115,12 @@ pub(super) struct RPCRateLimiter {
     lc_updates_by_range_rl: Limiter<PeerId>,
     /// ExecutionPayloadEnvelopesByRoot rate limiter.
     epbroots_rl: Limiter<PeerId>,
+    /// ExecutionPayloadEnvelopesByRange rate limiter.
+    epbrange_rl: Limiter<PeerId>,
     /// InclusionListByCommitteeIndices rate limiter.
     ilbci_rl: Limiter<PeerId>,
+    /// ExecutionProofsByRoot rate limiter.
+    eproofs_rl: Limiter<PeerId>,
     fork_context: Arc<ForkContext>,
 }
 
@@ -162,8 +166,12 @@ pub(super) struct RPCRateLimiterBuilder {
     lc_updates_by_range_quota: Option<Quota>,
     /// Quota for the ExecutionPayloadEnvelopesByRoot protocol.
     epbroots_quota: Option<Quota>,
+    /// Quota for the ExecutionPayloadEnvelopesByRange protocol.
+    epbrange_quota: Option<Quota>,
     /// Quota for the InclusionListByCommitteeIndices protocol.
     ilbci_quota: Option<Quota>,
+    /// Quota for the ExecutionProofsByRoot protocol.
+    eproofs_quota: Option<Quota>,
 }
 
 impl RPCRateLimiterBuilder {
@@ -186,7 +194,9 @@ impl RPCRateLimiterBuilder {
             Protocol::LightClientFinalityUpdate => self.lc_finality_update_quota = q,
             Protocol::LightClientUpdatesByRange => self.lc_updates_by_range_quota = q,
             Protocol::ExecutionPayloadEnvelopesByRoot => self.epbroots_quota = q,
+            Protocol::ExecutionPayloadEnvelopesByRange => self.epbrange_quota = q,
             Protocol::InclusionListByCommitteeIndices => self.ilbci_quota = q,
+            Protocol::ExecutionProofsByRoot => self.eproofs_quota = q,
         }
         self
     }
@@ -238,10 +248,18 @@ impl RPCRateLimiterBuilder {
             .epbroots_quota
             .ok_or("ExecutionPayloadEnvelopesByRoot quota not specified")?;
 
+        let epbrange_quota = self
+            .epbrange_quota
+            .ok_or("ExecutionPayloadEnvelopesByRange quota not specified")?;
+
         let ilbci_quota = self
             .ilbci_quota
             .ok_or("InclusionListByCommitteeIndices quota not specified")?;
 
+        let eproofs_quota = self
+            .eproofs_quota
+            .ok_or("ExecutionProofsByRoot quota not specified")?;
+
         // create the rate limiters
         let ping_rl = Limiter::from_quota(ping_quota)?;
         let metadata_rl = Limiter::from_quota(metadata_quota)?;
@@ -258,7 +276,9 @@ impl RPCRateLimiterBuilder {
         let lc_finality_update_rl = Limiter::from_quota(lc_finality_update_quota)?;
         let lc_updates_by_range_rl = Limiter::from_quota(lc_updates_by_range_quota)?;
         let epbroots_rl = Limiter::from_quota(epbroots_quota)?;
+        let epbrange_rl = Limiter::from_quota(epbrange_quota)?;
         let ilbci_rl = Limiter::from_quota(ilbci_quota)?;
+        let eproofs_rl = Limiter::from_quota(eproofs_quota)?;
 
         // check for peers to prune every 30 seconds, starting in 30 seconds
         let prune_every = tokio::time::Duration::from_secs(30);
@@ -283,7 +303,9 @@ impl RPCRateLimiterBuilder {
             lc_finality_update_rl,
             lc_updates_by_range_rl,
             epbroots_rl,
+            epbrange_rl,
             ilbci_rl,
+            eproofs_rl,
             init_time: Instant::now(),
             fork_context,
         })
@@ -338,7 +360,9 @@ impl RPCRateLimiter {
             light_client_finality_update_quota,
             light_client_updates_by_range_quota,
             execution_payload_envelopes_by_root_quota,
+            execution_payload_envelopes_by_range_quota,
             inclusion_list_by_committee_indices_quota,
+            execution_proofs_by_root_quota,
         } = config;
 
         Self::builder()
@@ -369,10 +393,18 @@ impl RPCRateLimiter {
                 Protocol::ExecutionPayloadEnvelopesByRoot,
                 execution_payload_envelopes_by_root_quota,
             )
+            .set_quota(
+                Protocol::ExecutionPayloadEnvelopesByRange,
+                execution_payload_envelopes_by_range_quota,
+            )
             .set_quota(
                 Protocol::InclusionListByCommitteeIndices,
                 inclusion_list_by_committee_indices_quota,
             )
+            .set_quota(
+                Protocol::ExecutionProofsByRoot,
+                execution_proofs_by_root_quota,
+            )
             .build(fork_context)
     }
 
@@ -412,7 +444,9 @@ impl RPCRateLimiter {
             Protocol::LightClientFinalityUpdate => &mut self.lc_finality_update_rl,
             Protocol::LightClientUpdatesByRange => &mut self.lc_updates_by_range_rl,
             Protocol::ExecutionPayloadEnvelopesByRoot => &mut self.epbroots_rl,
+            Protocol::ExecutionPayloadEnvelopesByRange => &mut self.epbrange_rl,
             Protocol::InclusionListByCommitteeIndices => &mut self.ilbci_rl,
+            Protocol::ExecutionProofsByRoot => &mut self.eproofs_rl,
         };
         check(limiter)
     }
@@ -438,7 +472,9 @@ impl RPCRateLimiter {
             lc_finality_update_rl,
             lc_updates_by_range_rl,
             epbroots_rl,
+            epbrange_rl,
             ilbci_rl,
+            eproofs_rl,
             fork_context: _,
         } = self;
 
@@ -457,7 +493,9 @@ impl RPCRateLimiter {
         lc_finality_update_rl.prune(time_since_start);
         lc_updates_by_range_rl.prune(time_since_start);
         epbroots_rl.prune(time_since_start);
+        epbrange_rl.prune(time_since_start);
         ilbci_rl.prune(time_since_start);
+        eproofs_rl.prune(time_since_start);
     }
 }
 
@@ -3,7 +3,7 @@ use libp2p::PeerId;
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 use types::{
-    BlobSidecar, DataColumnSidecar, Epoch, EthSpec, Hash256, LightClientBootstrap,
+    BlobSidecar, DataColumnSidecar, Epoch, EthSpec, ExecutionProof, Hash256, LightClientBootstrap,
     LightClientFinalityUpdate, LightClientOptimisticUpdate, LightClientUpdate, SignedBeaconBlock,
     SignedExecutionPayloadEnvelope, SignedInclusionList,
 };
@@ -31,19 +31,21 @@ pub enum SyncRequestId {
     BlobsByRange(BlobsByRangeRequestId),
     /// Data columns by range request
     DataColumnsByRange(DataColumnsByRangeRequestId),
-    /// Execution payload envelopes by root request (range sync)
-    EnvelopesByRoot(EnvelopesByRootRequestId),
+    /// Execution payload envelopes by range request
+    EnvelopesByRange(EnvelopesByRangeRequestId),
     /// Single envelope request triggered by an index-1 attestation (gloas ePBS).
     /// The block is known but the envelope hasn't been seen yet.
     SingleEnvelope { id: Id, block_root: Hash256 },
+    /// Execution proofs request for an imported block that has not yet gathered enough proofs
+    /// over gossip (stateless validation).
+    SingleExecutionProofs { id: Id, block_root: Hash256 },
 }
 
-/// Request ID for envelopes_by_root requests used during range sync.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-pub struct EnvelopesByRootRequestId {
-    /// Id to identify this request.
+pub struct EnvelopesByRangeRequestId {
+    /// Id to identify this attempt at an execution_payload_envelopes_by_range request for `parent_request_id`
     pub id: Id,
-    /// The parent components-by-range request that triggered this envelope download.
+    /// The Id of the overall By Range request for block components.
     pub parent_request_id: ComponentsByRangeRequestId,
 }
 
@@ -182,8 +184,12 @@ pub enum Response<E: EthSpec> {
     LightClientUpdatesByRange(Option<Arc<LightClientUpdate<E>>>),
     /// A response to a get EXECUTION_PAYLOAD_ENVELOPES_BY_ROOT request.
     ExecutionPayloadEnvelopesByRoot(Option<Arc<SignedExecutionPayloadEnvelope<E>>>),
+    /// A response to a get EXECUTION_PAYLOAD_ENVELOPES_BY_RANGE request. A None response signals the end of the batch.
+    ExecutionPayloadEnvelopesByRange(Option<Arc<SignedExecutionPayloadEnvelope<E>>>),
     /// A response to a get INCLUSION_LIST_BY_COMMITTEE_INDICES request.
     InclusionListByCommitteeIndices(Option<Arc<SignedInclusionList<E>>>),
+    /// A response to a get EXECUTION_PROOFS_BY_ROOT request. A None response signals the end of the batch.
+    ExecutionProofsByRoot(Option<Arc<ExecutionProof>>),
 }
 
 impl<E: EthSpec> std::convert::From<Response<E>> for RpcResponse<E> {
@@ -237,6 +243,14 @@ impl<E: EthSpec> std::convert::From<Response<E>> for RpcResponse<E> {
                     ResponseTermination::ExecutionPayloadEnvelopesByRoot,
                 ),
             },
+            Response::ExecutionPayloadEnvelopesByRange(r) => match r {
+                Some(e) => {
+                    RpcResponse::Success(RpcSuccessResponse::ExecutionPayloadEnvelopesByRange(e))
+                }
+                None => RpcResponse::StreamTermination(
+                    ResponseTermination::ExecutionPayloadEnvelopesByRange,
+                ),
+            },
             Response::InclusionListByCommitteeIndices(r) => match r {
                 Some(il) => {
                     RpcResponse::Success(RpcSuccessResponse::InclusionListByCommitteeIndices(il))
@@ -245,6 +259,10 @@ impl<E: EthSpec> std::convert::From<Response<E>> for RpcResponse<E> {
                     ResponseTermination::InclusionListByCommitteeIndices,
                 ),
             },
+            Response::ExecutionProofsByRoot(r) => match r {
+                Some(p) => RpcResponse::Success(RpcSuccessResponse::ExecutionProofsByRoot(p)),
+                None => RpcResponse::StreamTermination(ResponseTermination::ExecutionProofsByRoot),
+            },
         }
     }
 }
@@ -265,6 +283,7 @@ macro_rules! impl_display {
 impl_display!(BlocksByRangeRequestId, "{}/{}", id, parent_request_id);
 impl_display!(BlobsByRangeRequestId, "{}/{}", id, parent_request_id);
 impl_display!(DataColumnsByRangeRequestId, "{}/{}", id, parent_request_id);
+impl_display!(EnvelopesByRangeRequestId, "{}/{}", id, parent_request_id);
 impl_display!(ComponentsByRangeRequestId, "{}/{}", id, requester);
 impl_display!(DataColumnsByRootRequestId, "{}/{}", id, requester);
 impl_display!(SingleLookupReqId, "{}/Lookup/{}", req_id, lookup_id);
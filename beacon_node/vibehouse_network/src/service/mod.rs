@@ -1624,6 +1624,28 @@ impl<E: EthSpec> Network<E> {
                             request_type,
                         })
                     }
+                    RequestType::ExecutionPayloadEnvelopesByRange(_) => {
+                        metrics::inc_counter_vec(
+                            &metrics::TOTAL_RPC_REQUESTS,
+                            &["execution_payload_envelopes_by_range"],
+                        );
+                        Some(NetworkEvent::RequestReceived {
+                            peer_id,
+                            inbound_request_id,
+                            request_type,
+                        })
+                    }
+                    RequestType::ExecutionProofsByRoot(_) => {
+                        metrics::inc_counter_vec(
+                            &metrics::TOTAL_RPC_REQUESTS,
+                            &["execution_proofs_by_root"],
+                        );
+                        Some(NetworkEvent::RequestReceived {
+                            peer_id,
+                            inbound_request_id,
+                            request_type,
+                        })
+                    }
                 }
             }
             Ok(RPCReceived::Response(id, resp)) => {
@@ -1690,12 +1712,23 @@ impl<E: EthSpec> Network<E> {
                             peer_id,
                             Response::ExecutionPayloadEnvelopesByRoot(Some(resp)),
                         ),
+                    RpcSuccessResponse::ExecutionPayloadEnvelopesByRange(resp) => self
+                        .build_response(
+                            id,
+                            peer_id,
+                            Response::ExecutionPayloadEnvelopesByRange(Some(resp)),
+                        ),
                     RpcSuccessResponse::InclusionListByCommitteeIndices(resp) => self
                         .build_response(
                             id,
                             peer_id,
                             Response::InclusionListByCommitteeIndices(Some(resp)),
                         ),
+                    RpcSuccessResponse::ExecutionProofsByRoot(resp) => self.build_response(
+                        id,
+                        peer_id,
+                        Response::ExecutionProofsByRoot(Some(resp)),
+                    ),
                 }
             }
             Ok(RPCReceived::EndOfStream(id, termination)) => {
@@ -1712,9 +1745,15 @@ impl<E: EthSpec> Network<E> {
                     ResponseTermination::ExecutionPayloadEnvelopesByRoot => {
                         Response::ExecutionPayloadEnvelopesByRoot(None)
                     }
+                    ResponseTermination::ExecutionPayloadEnvelopesByRange => {
+                        Response::ExecutionPayloadEnvelopesByRange(None)
+                    }
                     ResponseTermination::InclusionListByCommitteeIndices => {
                         Response::InclusionListByCommitteeIndices(None)
                     }
+                    ResponseTermination::ExecutionProofsByRoot => {
+                        Response::ExecutionProofsByRoot(None)
+                    }
                 };
                 self.build_response(id, peer_id, response)
             }
@@ -42,6 +42,10 @@ pub const SPAN_HANDLE_BLOBS_BY_ROOT_REQUEST: &str = "handle_blobs_by_root_reques
 pub const SPAN_HANDLE_DATA_COLUMNS_BY_ROOT_REQUEST: &str = "handle_data_columns_by_root_request";
 pub const SPAN_HANDLE_EXECUTION_PAYLOAD_ENVELOPES_BY_ROOT_REQUEST: &str =
     "handle_execution_payload_envelopes_by_root_request";
+pub const SPAN_HANDLE_EXECUTION_PAYLOAD_ENVELOPES_BY_RANGE_REQUEST: &str =
+    "handle_execution_payload_envelopes_by_range_request";
+pub const SPAN_HANDLE_EXECUTION_PROOFS_BY_ROOT_REQUEST: &str =
+    "handle_execution_proofs_by_root_request";
 pub const SPAN_HANDLE_LIGHT_CLIENT_UPDATES_BY_RANGE: &str = "handle_light_client_updates_by_range";
 pub const SPAN_HANDLE_LIGHT_CLIENT_BOOTSTRAP: &str = "handle_light_client_bootstrap";
 pub const SPAN_HANDLE_LIGHT_CLIENT_OPTIMISTIC_UPDATE: &str =
@@ -79,6 +79,24 @@ pub struct Web3SignerDefinition {
     pub client_identity_password: Option<String>,
 }
 
+/// A share of a threshold validator key, held by a Web3Signer-compatible server.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Eq)]
+pub struct ThresholdShareDefinition {
+    /// The index of the share, which must be non-zero and unique among the shares of the key.
+    pub index: u64,
+    /// The public key of the share, which the remote signer signs with.
+    pub public_key: PublicKey,
+    #[serde(flatten)]
+    pub signer: Web3SignerDefinition,
+}
+
+/// A validator key split into shares, any `threshold` of which can sign for it.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Eq)]
+pub struct ThresholdDefinition {
+    pub threshold: usize,
+    pub shares: Vec<ThresholdShareDefinition>,
+}
+
 /// Defines how the validator client should attempt to sign messages for this validator.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -97,6 +115,10 @@ pub enum SigningDefinition {
     /// <https://github.com/ConsenSys/web3signer>
     #[serde(rename = "web3signer")]
     Web3Signer(Web3SignerDefinition),
+    /// A validator whose key is split between several Web3Signer HTTP servers, the signatures of
+    /// which are combined by this validator client.
+    #[serde(rename = "threshold")]
+    Threshold(ThresholdDefinition),
 }
 
 impl SigningDefinition {
@@ -104,6 +126,10 @@ impl SigningDefinition {
         matches!(self, SigningDefinition::LocalKeystore { .. })
     }
 
+    pub fn is_threshold(&self) -> bool {
+        matches!(self, SigningDefinition::Threshold(_))
+    }
+
     pub fn voting_keystore_password(&self) -> Result<Option<Zeroizing<String>>, Error> {
         match self {
             SigningDefinition::LocalKeystore {
@@ -117,7 +143,7 @@ impl SigningDefinition {
                 .map(Option::Some)
                 .map_err(Error::UnableToReadKeystorePassword),
             SigningDefinition::LocalKeystore { .. } => Err(Error::KeystoreWithoutPassword),
-            SigningDefinition::Web3Signer(_) => Ok(None),
+            SigningDefinition::Web3Signer(_) | SigningDefinition::Threshold(_) => Ok(None),
         }
     }
 
@@ -278,8 +304,8 @@ impl ValidatorDefinitions {
                     voting_keystore_path,
                     ..
                 } => Some(voting_keystore_path),
-                // A Web3Signer validator does not use a local keystore file.
-                SigningDefinition::Web3Signer { .. } => None,
+                // Remote signer validators do not use a local keystore file.
+                SigningDefinition::Web3Signer { .. } | SigningDefinition::Threshold(_) => None,
             })
             .collect();
 
@@ -676,4 +702,37 @@ mod tests {
         let def: ValidatorDefinition = serde_yaml::from_str(valid_builder_proposals).unwrap();
         assert_eq!(def.builder_proposals, Some(true));
     }
+
+    #[test]
+    fn threshold_checks() {
+        let threshold = r#"---
+        description: ""
+        enabled: true
+        type: threshold
+        threshold: 1
+        shares:
+          - index: 1
+            public_key: "0xaf3c7ddab7e293834710fca2d39d068f884455ede270e0d0293dc818e4f2f0f975355067e8437955cb29aec674e5c9e7"
+            url: "http://share-1:9000"
+          - index: 2
+            public_key: "0xaf3c7ddab7e293834710fca2d39d068f884455ede270e0d0293dc818e4f2f0f975355067e8437955cb29aec674e5c9e7"
+            url: "http://share-2:9000"
+            request_timeout_ms: 500
+        voting_public_key: "0xaf3c7ddab7e293834710fca2d39d068f884455ede270e0d0293dc818e4f2f0f975355067e8437955cb29aec674e5c9e7"
+        "#;
+
+        let def: ValidatorDefinition = serde_yaml::from_str(threshold).unwrap();
+        let SigningDefinition::Threshold(threshold) = &def.signing_definition else {
+            panic!("expected a threshold definition");
+        };
+        assert_eq!(threshold.threshold, 1);
+        assert_eq!(threshold.shares.len(), 2);
+        assert_eq!(threshold.shares[1].index, 2);
+        assert_eq!(threshold.shares[1].signer.url, "http://share-2:9000");
+        assert_eq!(threshold.shares[1].signer.request_timeout_ms, Some(500));
+
+        let roundtrip: ValidatorDefinition =
+            serde_yaml::from_str(&serde_yaml::to_string(&def).unwrap()).unwrap();
+        assert!(roundtrip == def);
+    }
 }
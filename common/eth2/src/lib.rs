@@ -18,33 +18,33 @@ use self::mixin::{RequestAccept, ResponseOptional};
 use self::types::{
     Accept, Attestation, AttestationData, AttesterData, AttesterSlashing, BeaconCommitteeSelection,
     BeaconCommitteeSubscription, BeaconResponse, BeaconState, BlindedBeaconBlock, BlobSidecarList,
-    BlobWrapper, BlockHeaderData, BlockId, BroadcastValidation, ChainHeadData, ChainSpec,
-    CommitteeData, CommitteeIndex, ContextDeserialize, DepositContractData, DepositData,
-    DutiesResponse, EndpointVersion, Epoch, Error as ResponseError, ErrorMessage, EthSpec,
-    EventKind, EventTopic, ExecutionOptimisticFinalizedResponse, ExecutionProofStatus,
-    FinalityCheckpointsData, Fork, ForkChoice, ForkName, ForkVersionedResponse, FullBlockContents,
-    GenericResponse, GenesisData, Graffiti, Hash256, IdentityData, InclusionListDutyData,
-    IndexedErrorMessage, JsonProduceBlockV3Response, LightClientBootstrap,
-    LightClientFinalityUpdate, LightClientOptimisticUpdate, LightClientUpdate, LivenessRequestData,
-    LivenessResponseData, PeerCount, PeerData, PeerDirection, PeerState, PeersData,
-    PendingConsolidation, PendingDeposit, PendingPartialWithdrawal, ProduceBlockV3Metadata,
-    ProduceBlockV3Response, ProposerData, ProposerPreparationData, ProposerSlashing, PtcDutyData,
-    PublishBlockRequest, RandaoMix, RootData, SignatureBytes, SignedAggregateAndProof,
-    SignedBeaconBlock, SignedBlindedBeaconBlock, SignedBlsToExecutionChange,
-    SignedContributionAndProof, SignedValidatorRegistrationData, SignedVoluntaryExit,
-    SingleAttestation, SkipRandaoVerification, Slot, StandardBlockReward,
-    StandardLivenessResponseData, StateId, SyncCommitteeByValidatorIndices,
-    SyncCommitteeContribution, SyncCommitteeMessage, SyncCommitteeSelection,
-    SyncCommitteeSubscription, SyncContributionData, SyncDuty, SyncingData, ValidatorBalanceData,
-    ValidatorBalancesRequestBody, ValidatorData, ValidatorId, ValidatorIdentitiesRequestBody,
-    ValidatorIdentityData, ValidatorIndexDataRef, ValidatorStatus, ValidatorsRequestBody,
-    VersionData, Withdrawal,
+    BlobWrapper, BlockHeaderData, BlockId, BroadcastValidation, BuilderData, BuilderStatus,
+    ChainHeadData, ChainSpec, CommitteeData, CommitteeIndex, ContextDeserialize,
+    DepositContractData, DepositData, DutiesResponse, EndpointVersion, Epoch,
+    Error as ResponseError, ErrorMessage, EthSpec, EventKind, EventTopic,
+    ExecutionOptimisticFinalizedResponse, ExecutionProofStatus, FinalityCheckpointsData, Fork,
+    ForkChoice, ForkName, ForkVersionedResponse, FullBlockContents, GenericResponse, GenesisData,
+    Graffiti, Hash256, IdentityData, InclusionListDutyData, IndexedErrorMessage,
+    JsonProduceBlockV3Response, LightClientBootstrap, LightClientFinalityUpdate,
+    LightClientOptimisticUpdate, LightClientUpdate, LivenessRequestData, LivenessResponseData,
+    PeerCount, PeerData, PeerDirection, PeerState, PeersData, PendingConsolidation, PendingDeposit,
+    PendingPartialWithdrawal, ProduceBlockV3Metadata, ProduceBlockV3Response, ProposerData,
+    ProposerPreparationData, ProposerSlashing, PtcDutyData, PublishBlockRequest, RandaoMix,
+    RejectionErrorMessage, RootData, SignatureBytes, SignedAggregateAndProof, SignedBeaconBlock,
+    SignedBlindedBeaconBlock, SignedBlsToExecutionChange, SignedContributionAndProof,
+    SignedValidatorRegistrationData, SignedVoluntaryExit, SingleAttestation,
+    SkipRandaoVerification, Slot, StandardBlockReward, StandardLivenessResponseData, StateId,
+    SyncCommitteeByValidatorIndices, SyncCommitteeContribution, SyncCommitteeMessage,
+    SyncCommitteeReward, SyncCommitteeSelection, SyncCommitteeSubscription, SyncContributionData,
+    SyncDuty, SyncingData, ValidatorBalanceData, ValidatorBalancesRequestBody, ValidatorData,
+    ValidatorId, ValidatorIdentitiesRequestBody, ValidatorIdentityData, ValidatorIndexDataRef,
+    ValidatorStatus, ValidatorsRequestBody, VersionData, Withdrawal,
 };
 use ::types::beacon_response::ExecutionOptimisticFinalizedBeaconResponse;
 use ::types::{
-    ExecutionProof, PayloadAttestation, PayloadAttestationData, PayloadAttestationMessage,
-    SignedExecutionPayloadBid, SignedExecutionPayloadEnvelope, SignedInclusionList,
-    SignedProposerPreferences,
+    BuilderPendingPayment, BuilderPendingWithdrawal, ExecutionProof, PayloadAttestation,
+    PayloadAttestationData, PayloadAttestationMessage, SignedExecutionPayloadBid,
+    SignedExecutionPayloadEnvelope, SignedInclusionList, SignedProposerPreferences,
 };
 use educe::Educe;
 use futures::Stream;
@@ -60,7 +60,7 @@ pub use reqwest::{StatusCode, Url};
 use reqwest_eventsource::{Event, EventSource};
 pub use sensitive_url::{SensitiveError, SensitiveUrl};
 use serde::{Serialize, de::DeserializeOwned};
-use ssz::Encode;
+use ssz::{Decode, Encode};
 use std::fmt;
 use std::future::Future;
 use std::path::PathBuf;
@@ -107,6 +107,8 @@ pub enum Error {
     ServerMessage(ErrorMessage),
     /// The server returned an error message with an array of errors.
     ServerIndexedMessage(IndexedErrorMessage),
+    /// The server rejected a message and returned the name of the failed check.
+    ServerRejectionMessage(RejectionErrorMessage),
     /// The server returned an error message where the body was unable to be parsed.
     StatusCode(StatusCode),
     /// The supplied URL is badly formatted. It should look something like `<http://127.0.0.1:5052`>.
@@ -153,6 +155,7 @@ impl Error {
             }
             Error::ServerMessage(msg) => StatusCode::try_from(msg.code).ok(),
             Error::ServerIndexedMessage(msg) => StatusCode::try_from(msg.code).ok(),
+            Error::ServerRejectionMessage(msg) => StatusCode::try_from(msg.code).ok(),
             Error::StatusCode(status) => Some(*status),
             Error::InvalidUrl(_)
             | Error::InvalidSecret(_)
@@ -989,6 +992,150 @@ impl BeaconNodeHttpClient {
             .map(|opt| opt.map(BeaconResponse::ForkVersioned))
     }
 
+    /// Build the path for `GET beacon/states/{state_id}/builders`.
+    fn get_beacon_states_builders_path(
+        &self,
+        state_id: StateId,
+        ids: Option<&[ValidatorId]>,
+        statuses: Option<&[BuilderStatus]>,
+    ) -> Result<Url, Error> {
+        let mut path = self.eth_path(V1)?;
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("beacon")
+            .push("states")
+            .push(&state_id.to_string())
+            .push("builders");
+
+        if let Some(ids) = ids {
+            let id_string = ids
+                .iter()
+                .map(std::string::ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            path.query_pairs_mut().append_pair("id", &id_string);
+        }
+
+        if let Some(statuses) = statuses {
+            let status_string = statuses
+                .iter()
+                .map(std::string::ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            path.query_pairs_mut().append_pair("status", &status_string);
+        }
+
+        Ok(path)
+    }
+
+    /// `GET beacon/states/{state_id}/builders?id,status`
+    ///
+    /// Returns `Ok(None)` on a 404 error.
+    pub async fn get_beacon_states_builders(
+        &self,
+        state_id: StateId,
+        ids: Option<&[ValidatorId]>,
+        statuses: Option<&[BuilderStatus]>,
+    ) -> Result<Option<ExecutionOptimisticFinalizedBeaconResponse<Vec<BuilderData>>>, Error> {
+        let path = self.get_beacon_states_builders_path(state_id, ids, statuses)?;
+
+        self.get_fork_contextual(path, |fork| fork)
+            .await
+            .map(|opt| opt.map(BeaconResponse::ForkVersioned))
+    }
+
+    /// `GET beacon/states/{state_id}/builders?id,status` as SSZ
+    ///
+    /// Returns `Ok(None)` on a 404 error.
+    pub async fn get_beacon_states_builders_ssz(
+        &self,
+        state_id: StateId,
+        ids: Option<&[ValidatorId]>,
+        statuses: Option<&[BuilderStatus]>,
+    ) -> Result<Option<Vec<BuilderData>>, Error> {
+        let path = self.get_beacon_states_builders_path(state_id, ids, statuses)?;
+
+        self.get_bytes_opt_accept_header(path, Accept::Ssz, self.timeouts.default)
+            .await?
+            .map(|bytes| Vec::<BuilderData>::from_ssz_bytes(&bytes).map_err(Error::InvalidSsz))
+            .transpose()
+    }
+
+    /// Build the path for a `GET beacon/states/{state_id}/{field}` request.
+    fn get_beacon_states_field_path(&self, state_id: StateId, field: &str) -> Result<Url, Error> {
+        let mut path = self.eth_path(V1)?;
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("beacon")
+            .push("states")
+            .push(&state_id.to_string())
+            .push(field);
+
+        Ok(path)
+    }
+
+    /// `GET beacon/states/{state_id}/builder_pending_payments`
+    ///
+    /// Returns `Ok(None)` on a 404 error.
+    pub async fn get_beacon_states_builder_pending_payments(
+        &self,
+        state_id: StateId,
+    ) -> Result<Option<ExecutionOptimisticFinalizedResponse<Vec<BuilderPendingPayment>>>, Error>
+    {
+        let path = self.get_beacon_states_field_path(state_id, "builder_pending_payments")?;
+
+        self.get_opt(path).await
+    }
+
+    /// `GET beacon/states/{state_id}/builder_pending_payments` as SSZ
+    ///
+    /// Returns `Ok(None)` on a 404 error.
+    pub async fn get_beacon_states_builder_pending_payments_ssz(
+        &self,
+        state_id: StateId,
+    ) -> Result<Option<Vec<BuilderPendingPayment>>, Error> {
+        let path = self.get_beacon_states_field_path(state_id, "builder_pending_payments")?;
+
+        self.get_bytes_opt_accept_header(path, Accept::Ssz, self.timeouts.default)
+            .await?
+            .map(|bytes| {
+                Vec::<BuilderPendingPayment>::from_ssz_bytes(&bytes).map_err(Error::InvalidSsz)
+            })
+            .transpose()
+    }
+
+    /// `GET beacon/states/{state_id}/builder_pending_withdrawals`
+    ///
+    /// Returns `Ok(None)` on a 404 error.
+    pub async fn get_beacon_states_builder_pending_withdrawals(
+        &self,
+        state_id: StateId,
+    ) -> Result<Option<ExecutionOptimisticFinalizedResponse<Vec<BuilderPendingWithdrawal>>>, Error>
+    {
+        let path = self.get_beacon_states_field_path(state_id, "builder_pending_withdrawals")?;
+
+        self.get_opt(path).await
+    }
+
+    /// `GET beacon/states/{state_id}/builder_pending_withdrawals` as SSZ
+    ///
+    /// Returns `Ok(None)` on a 404 error.
+    pub async fn get_beacon_states_builder_pending_withdrawals_ssz(
+        &self,
+        state_id: StateId,
+    ) -> Result<Option<Vec<BuilderPendingWithdrawal>>, Error> {
+        let path = self.get_beacon_states_field_path(state_id, "builder_pending_withdrawals")?;
+
+        self.get_bytes_opt_accept_header(path, Accept::Ssz, self.timeouts.default)
+            .await?
+            .map(|bytes| {
+                Vec::<BuilderPendingWithdrawal>::from_ssz_bytes(&bytes).map_err(Error::InvalidSsz)
+            })
+            .transpose()
+    }
+
     /// `GET beacon/states/{state_id}/proposer_lookahead`
     ///
     /// Returns the proposer lookahead vector from the beacon state. Only available from Fulu+.
@@ -1025,14 +1172,67 @@ impl BeaconNodeHttpClient {
         &self,
         bid: &SignedExecutionPayloadBid<E>,
     ) -> Result<(), Error> {
-        let mut path = self.eth_path(V1)?;
+        self.post(self.post_builder_bids_path(None)?, bid).await?;
 
+        Ok(())
+    }
+
+    pub(crate) fn post_builder_bids_path(
+        &self,
+        validation_level: Option<BroadcastValidation>,
+    ) -> Result<Url, Error> {
+        let mut path = self.eth_path(V1)?;
         path.path_segments_mut()
             .map_err(|()| Error::InvalidUrl(self.server.clone()))?
-            .push("builder")
-            .push("bids");
+            .extend(&["builder", "bids"]);
 
-        self.post(path, bid).await?;
+        path.set_query(
+            validation_level
+                .map(|v| format!("broadcast_validation={v}"))
+                .as_deref(),
+        );
+
+        Ok(path)
+    }
+
+    /// `POST builder/bids?broadcast_validation`
+    ///
+    /// Submits a bid as JSON, verified to `validation_level` before it is gossiped.
+    pub async fn post_builder_bids_with_validation<E: EthSpec>(
+        &self,
+        bid: &SignedExecutionPayloadBid<E>,
+        validation_level: Option<BroadcastValidation>,
+    ) -> Result<(), Error> {
+        self.post_generic_with_consensus_version(
+            self.post_builder_bids_path(validation_level)?,
+            bid,
+            None,
+            execution_bid_fork_name(bid),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// `POST builder/bids?broadcast_validation`
+    ///
+    /// Submits a bid as SSZ, verified to `validation_level` before it is gossiped.
+    pub async fn post_builder_bids_ssz<E: EthSpec>(
+        &self,
+        bid: &SignedExecutionPayloadBid<E>,
+        validation_level: Option<BroadcastValidation>,
+    ) -> Result<(), Error> {
+        let bytes = match bid {
+            SignedExecutionPayloadBid::Gloas(inner) => inner.as_ssz_bytes(),
+            SignedExecutionPayloadBid::Heze(inner) => inner.as_ssz_bytes(),
+        };
+        self.post_generic_with_consensus_version_and_ssz_body(
+            self.post_builder_bids_path(validation_level)?,
+            bytes,
+            None,
+            execution_bid_fork_name(bid),
+        )
+        .await?;
 
         Ok(())
     }
@@ -1129,14 +1329,66 @@ impl BeaconNodeHttpClient {
         &self,
         envelope: &SignedExecutionPayloadEnvelope<E>,
     ) -> Result<(), Error> {
-        let mut path = self.eth_path(V1)?;
+        self.post(
+            self.post_beacon_execution_payload_envelope_path(None)?,
+            envelope,
+        )
+        .await?;
+
+        Ok(())
+    }
 
+    pub(crate) fn post_beacon_execution_payload_envelope_path(
+        &self,
+        validation_level: Option<BroadcastValidation>,
+    ) -> Result<Url, Error> {
+        let mut path = self.eth_path(V1)?;
         path.path_segments_mut()
             .map_err(|()| Error::InvalidUrl(self.server.clone()))?
-            .push("beacon")
-            .push("execution_payload_envelope");
+            .extend(&["beacon", "execution_payload_envelope"]);
+
+        path.set_query(
+            validation_level
+                .map(|v| format!("broadcast_validation={v}"))
+                .as_deref(),
+        );
+
+        Ok(path)
+    }
+
+    /// `POST beacon/execution_payload_envelope?broadcast_validation`
+    ///
+    /// Submits an envelope as JSON, verified to `validation_level` before it is gossiped.
+    pub async fn post_beacon_execution_payload_envelope_with_validation<E: EthSpec>(
+        &self,
+        envelope: &SignedExecutionPayloadEnvelope<E>,
+        validation_level: Option<BroadcastValidation>,
+    ) -> Result<(), Error> {
+        self.post(
+            self.post_beacon_execution_payload_envelope_path(validation_level)?,
+            envelope,
+        )
+        .await?;
+
+        Ok(())
+    }
 
-        self.post(path, envelope).await?;
+    /// `POST beacon/execution_payload_envelope?broadcast_validation`
+    ///
+    /// Submits an envelope as SSZ, verified to `validation_level` before it is gossiped.
+    pub async fn post_beacon_execution_payload_envelope_ssz<E: EthSpec>(
+        &self,
+        envelope: &SignedExecutionPayloadEnvelope<E>,
+        fork_name: ForkName,
+        validation_level: Option<BroadcastValidation>,
+    ) -> Result<(), Error> {
+        self.post_generic_with_consensus_version_and_ssz_body(
+            self.post_beacon_execution_payload_envelope_path(validation_level)?,
+            envelope.as_ssz_bytes(),
+            None,
+            fork_name,
+        )
+        .await?;
 
         Ok(())
     }
@@ -1975,6 +2227,24 @@ impl BeaconNodeHttpClient {
         self.get(path).await
     }
 
+    /// `POST beacon/rewards/sync_committee/{block_id}`
+    pub async fn post_beacon_rewards_sync_committee(
+        &self,
+        block_id: BlockId,
+        validators: &[ValidatorId],
+    ) -> Result<ExecutionOptimisticFinalizedResponse<Vec<SyncCommitteeReward>>, Error> {
+        let mut path = self.eth_path(V1)?;
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("beacon")
+            .push("rewards")
+            .push("sync_committee")
+            .push(&block_id.to_string());
+
+        self.post_with_response(path, &validators).await
+    }
+
     // GET builder/states/{state_id}/expected_withdrawals
     pub async fn get_expected_withdrawals(
         &self,
@@ -3158,6 +3428,14 @@ impl BeaconNodeHttpClient {
     }
 }
 
+/// The fork of the `SignedExecutionPayloadBid` variant, for the `Eth-Consensus-Version` header.
+fn execution_bid_fork_name<E: EthSpec>(bid: &SignedExecutionPayloadBid<E>) -> ForkName {
+    match bid {
+        SignedExecutionPayloadBid::Gloas(_) => ForkName::Gloas,
+        SignedExecutionPayloadBid::Heze(_) => ForkName::Heze,
+    }
+}
+
 /// Returns `Ok(response)` if the response is a `200 OK` response. Otherwise, creates an
 /// appropriate error message.
 pub async fn ok_or_error(response: Response) -> Result<Response, Error> {
@@ -3169,6 +3447,7 @@ pub async fn ok_or_error(response: Response) -> Result<Response, Error> {
         match message {
             ResponseError::Message(message) => Err(Error::ServerMessage(message)),
             ResponseError::Indexed(indexed) => Err(Error::ServerIndexedMessage(indexed)),
+            ResponseError::Rejection(rejection) => Err(Error::ServerRejectionMessage(rejection)),
         }
     } else {
         Err(Error::StatusCode(status))
@@ -3186,6 +3465,7 @@ pub async fn success_or_error(response: Response) -> Result<Response, Error> {
         match message {
             ResponseError::Message(message) => Err(Error::ServerMessage(message)),
             ResponseError::Indexed(indexed) => Err(Error::ServerIndexedMessage(indexed)),
+            ResponseError::Rejection(rejection) => Err(Error::ServerRejectionMessage(rejection)),
         }
     } else {
         Err(Error::StatusCode(status))
@@ -31,6 +31,7 @@ use crate::vibehouse::BlockReward;
 #[serde(untagged)]
 pub enum Error {
     Indexed(IndexedErrorMessage),
+    Rejection(RejectionErrorMessage),
     Message(ErrorMessage),
 }
 
@@ -51,6 +52,17 @@ pub struct IndexedErrorMessage {
     pub failures: Vec<Failure>,
 }
 
+/// An API error for a message which failed verification, serializable to JSON.
+///
+/// `reason` is a stable, machine-readable name for the failed check (e.g. `BuilderEquivocation`),
+/// whilst `message` carries the full human-readable detail.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RejectionErrorMessage {
+    pub code: u16,
+    pub message: String,
+    pub reason: String,
+}
+
 /// A single failure in an index of API errors, serializable to JSON.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Failure {
@@ -483,6 +495,81 @@ impl fmt::Display for ValidatorStatus {
     }
 }
 
+/// A builder in the Gloas builder registry, as returned by `GET beacon/states/{state_id}/builders`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+pub struct BuilderData {
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub index: u64,
+    pub status: BuilderStatus,
+    pub builder: Builder,
+}
+
+/// Lifecycle status of a builder in the Gloas builder registry.
+///
+/// Builders become active once their deposit epoch has been finalized, and remain active until
+/// they initiate an exit. Once `withdrawable_epoch` is reached the remaining balance is swept,
+/// after which the registry slot may be reused by a new deposit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "snake_case")]
+#[ssz(enum_behaviour = "tag")]
+pub enum BuilderStatus {
+    Pending,
+    Active,
+    Exiting,
+    Withdrawable,
+    Withdrawn,
+}
+
+impl BuilderStatus {
+    pub fn from_builder(
+        builder: &Builder,
+        current_epoch: Epoch,
+        finalized_epoch: Epoch,
+        spec: &ChainSpec,
+    ) -> Self {
+        if builder.withdrawable_epoch == spec.far_future_epoch {
+            if builder.is_active_at_finalized_epoch(finalized_epoch, spec) {
+                BuilderStatus::Active
+            } else {
+                BuilderStatus::Pending
+            }
+        } else if current_epoch < builder.withdrawable_epoch {
+            BuilderStatus::Exiting
+        } else if builder.balance > 0 {
+            BuilderStatus::Withdrawable
+        } else {
+            BuilderStatus::Withdrawn
+        }
+    }
+}
+
+impl FromStr for BuilderStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(BuilderStatus::Pending),
+            "active" => Ok(BuilderStatus::Active),
+            "exiting" => Ok(BuilderStatus::Exiting),
+            "withdrawable" => Ok(BuilderStatus::Withdrawable),
+            "withdrawn" => Ok(BuilderStatus::Withdrawn),
+            _ => Err(format!("{s} cannot be parsed as a builder status.")),
+        }
+    }
+}
+
+impl fmt::Display for BuilderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuilderStatus::Pending => write!(f, "pending"),
+            BuilderStatus::Active => write!(f, "active"),
+            BuilderStatus::Exiting => write!(f, "exiting"),
+            BuilderStatus::Withdrawable => write!(f, "withdrawable"),
+            BuilderStatus::Withdrawn => write!(f, "withdrawn"),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct CommitteesQuery {
     pub slot: Option<Slot>,
@@ -525,6 +612,18 @@ pub struct ValidatorsQuery {
     pub status: Option<Vec<ValidatorStatus>>,
 }
 
+/// Query for `GET beacon/states/{state_id}/builders`.
+///
+/// Builders are identified by registry index or pubkey, using the same syntax as validators.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BuildersQuery {
+    #[serde(default, deserialize_with = "option_query_vec")]
+    pub id: Option<Vec<ValidatorId>>,
+    #[serde(default, deserialize_with = "option_query_vec")]
+    pub status: Option<Vec<BuilderStatus>>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ValidatorsRequestBody {
@@ -1133,6 +1232,23 @@ pub struct SsePayloadAttestation {
     pub blob_data_available: bool,
 }
 
+/// The PTC's final verdict on the payload of the block at `slot`.
+///
+/// Sent once `slot` has ended and no more payload attestations for it are accepted, rather than
+/// as each payload attestation is verified, since the verdict is only final then. The verdict on a
+/// block imported after its slot ended is sent once its child carrying the votes is imported.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct SsePayloadAttestationOutcome {
+    pub slot: Slot,
+    pub beacon_block_root: Hash256,
+    pub payload_present: bool,
+    pub blob_data_available: bool,
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub payload_present_votes: u64,
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub blob_data_available_votes: u64,
+}
+
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
 pub struct SseExecutionProof {
     pub block_root: Hash256,
@@ -1161,6 +1277,13 @@ pub struct SseFinalizedCheckpoint {
     pub execution_optimistic: bool,
 }
 
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct SseFastConfirmation {
+    pub block: Hash256,
+    pub slot: Slot,
+    pub execution_optimistic: bool,
+}
+
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
 pub struct SseHead {
     pub slot: Slot,
@@ -1323,6 +1446,10 @@ pub enum EventKind<E: EthSpec> {
     ExecutionPayload(SseExecutionPayload),
     PayloadAttestation(SsePayloadAttestation),
     ExecutionProofReceived(SseExecutionProof),
+    FastConfirmation(SseFastConfirmation),
+    InclusionList(Box<SignedInclusionList<E>>),
+    ProposerPreferences(Box<SignedProposerPreferences>),
+    PayloadAttestationOutcome(SsePayloadAttestationOutcome),
 }
 
 impl<E: EthSpec> EventKind<E> {
@@ -1352,6 +1479,10 @@ impl<E: EthSpec> EventKind<E> {
             EventKind::ExecutionPayload(_) => "execution_payload",
             EventKind::PayloadAttestation(_) => "payload_attestation",
             EventKind::ExecutionProofReceived(_) => "execution_proof_received",
+            EventKind::FastConfirmation(_) => "fast_confirmation",
+            EventKind::InclusionList(_) => "inclusion_list",
+            EventKind::ProposerPreferences(_) => "proposer_preferences",
+            EventKind::PayloadAttestationOutcome(_) => "payload_attestation_outcome",
         }
     }
 
@@ -1463,6 +1594,28 @@ impl<E: EthSpec> EventKind<E> {
                     ServerError::InvalidServerSentEvent(format!("Execution Proof Received: {e:?}"))
                 })?,
             )),
+            "fast_confirmation" => Ok(EventKind::FastConfirmation(
+                serde_json::from_str(data).map_err(|e| {
+                    ServerError::InvalidServerSentEvent(format!("Fast Confirmation: {e:?}"))
+                })?,
+            )),
+            "inclusion_list" => Ok(EventKind::InclusionList(
+                serde_json::from_str(data).map_err(|e| {
+                    ServerError::InvalidServerSentEvent(format!("Inclusion List: {e:?}"))
+                })?,
+            )),
+            "proposer_preferences" => Ok(EventKind::ProposerPreferences(
+                serde_json::from_str(data).map_err(|e| {
+                    ServerError::InvalidServerSentEvent(format!("Proposer Preferences: {e:?}"))
+                })?,
+            )),
+            "payload_attestation_outcome" => Ok(EventKind::PayloadAttestationOutcome(
+                serde_json::from_str(data).map_err(|e| {
+                    ServerError::InvalidServerSentEvent(format!(
+                        "Payload Attestation Outcome: {e:?}"
+                    ))
+                })?,
+            )),
             _ => Err(ServerError::InvalidServerSentEvent(
                 "Could not parse event tag".to_string(),
             )),
@@ -1504,6 +1657,10 @@ pub enum EventTopic {
     ExecutionPayload,
     PayloadAttestation,
     ExecutionProofReceived,
+    FastConfirmation,
+    InclusionList,
+    ProposerPreferences,
+    PayloadAttestationOutcome,
 }
 
 impl FromStr for EventTopic {
@@ -1535,6 +1692,10 @@ impl FromStr for EventTopic {
             "execution_payload" => Ok(EventTopic::ExecutionPayload),
             "payload_attestation" => Ok(EventTopic::PayloadAttestation),
             "execution_proof_received" => Ok(EventTopic::ExecutionProofReceived),
+            "fast_confirmation" => Ok(EventTopic::FastConfirmation),
+            "inclusion_list" => Ok(EventTopic::InclusionList),
+            "proposer_preferences" => Ok(EventTopic::ProposerPreferences),
+            "payload_attestation_outcome" => Ok(EventTopic::PayloadAttestationOutcome),
             _ => Err("event topic cannot be parsed.".to_string()),
         }
     }
@@ -1567,6 +1728,10 @@ impl fmt::Display for EventTopic {
             EventTopic::ExecutionPayload => write!(f, "execution_payload"),
             EventTopic::PayloadAttestation => write!(f, "payload_attestation"),
             EventTopic::ExecutionProofReceived => write!(f, "execution_proof_received"),
+            EventTopic::FastConfirmation => write!(f, "fast_confirmation"),
+            EventTopic::InclusionList => write!(f, "inclusion_list"),
+            EventTopic::ProposerPreferences => write!(f, "proposer_preferences"),
+            EventTopic::PayloadAttestationOutcome => write!(f, "payload_attestation_outcome"),
         }
     }
 }
@@ -1684,6 +1849,9 @@ pub struct LivenessResponseData {
 pub struct ForkChoice {
     pub justified_checkpoint: Checkpoint,
     pub finalized_checkpoint: Checkpoint,
+    /// The block confirmed by the Fast Confirmation Rule, if enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmed_root: Option<Hash256>,
     pub fork_choice_nodes: Vec<ForkChoiceNode>,
 }
 
@@ -2127,6 +2295,80 @@ mod tests {
         );
     }
 
+    // ── BuilderStatus ───────────────────────────────────────────
+
+    fn test_builder(deposit_epoch: u64, withdrawable_epoch: Epoch, balance: u64) -> Builder {
+        Builder {
+            pubkey: PublicKeyBytes::empty(),
+            version: 0,
+            execution_address: Address::ZERO,
+            balance,
+            deposit_epoch: Epoch::new(deposit_epoch),
+            withdrawable_epoch,
+        }
+    }
+
+    #[test]
+    fn builder_status_display_roundtrip() {
+        let statuses = [
+            BuilderStatus::Pending,
+            BuilderStatus::Active,
+            BuilderStatus::Exiting,
+            BuilderStatus::Withdrawable,
+            BuilderStatus::Withdrawn,
+        ];
+        for status in statuses {
+            assert_eq!(
+                BuilderStatus::from_str(&status.to_string()).unwrap(),
+                status
+            );
+        }
+        assert!(BuilderStatus::from_str("active_ongoing").is_err());
+    }
+
+    #[test]
+    fn builder_status_from_builder() {
+        let spec = ChainSpec::mainnet();
+        let far_future = spec.far_future_epoch;
+        let current = Epoch::new(10);
+        let finalized = Epoch::new(8);
+
+        let status =
+            |builder: &Builder| BuilderStatus::from_builder(builder, current, finalized, &spec);
+
+        assert_eq!(
+            status(&test_builder(8, far_future, 1)),
+            BuilderStatus::Pending
+        );
+        assert_eq!(
+            status(&test_builder(7, far_future, 1)),
+            BuilderStatus::Active
+        );
+        assert_eq!(
+            status(&test_builder(0, Epoch::new(11), 1)),
+            BuilderStatus::Exiting
+        );
+        assert_eq!(
+            status(&test_builder(0, Epoch::new(10), 1)),
+            BuilderStatus::Withdrawable
+        );
+        assert_eq!(
+            status(&test_builder(0, Epoch::new(10), 0)),
+            BuilderStatus::Withdrawn
+        );
+    }
+
+    #[test]
+    fn builder_data_ssz_roundtrip() {
+        let data = vec![BuilderData {
+            index: 3,
+            status: BuilderStatus::Exiting,
+            builder: test_builder(1, Epoch::new(20), 5),
+        }];
+        let bytes = data.as_ssz_bytes();
+        assert_eq!(Vec::<BuilderData>::from_ssz_bytes(&bytes).unwrap(), data);
+    }
+
     // ── PeerState ───────────────────────────────────────────────
 
     #[test]
@@ -2691,6 +2933,22 @@ mod tests {
         assert_eq!(json["builder_index"], serde_json::json!("3"));
     }
 
+    #[test]
+    fn sse_payload_attestation_outcome_json_roundtrip() {
+        let outcome = SsePayloadAttestationOutcome {
+            slot: Slot::new(99),
+            beacon_block_root: Hash256::repeat_byte(0xCC),
+            payload_present: false,
+            blob_data_available: true,
+            payload_present_votes: 3,
+            blob_data_available_votes: 400,
+        };
+        let json = serde_json::to_string(&outcome).unwrap();
+        assert!(json.contains("\"payload_present_votes\":\"3\""));
+        let decoded: SsePayloadAttestationOutcome = serde_json::from_str(&json).unwrap();
+        assert_eq!(outcome, decoded);
+    }
+
     #[test]
     fn sse_payload_attestation_json_roundtrip() {
         let att = SsePayloadAttestation {
@@ -2719,6 +2977,19 @@ mod tests {
         assert!(!decoded.blob_data_available);
     }
 
+    #[test]
+    fn sse_fast_confirmation_json_roundtrip() {
+        let confirmation = SseFastConfirmation {
+            block: Hash256::repeat_byte(0xAB),
+            slot: Slot::new(42),
+            execution_optimistic: false,
+        };
+        let json = serde_json::to_string(&confirmation).unwrap();
+        assert!(json.contains("\"slot\":\"42\""));
+        let decoded: SseFastConfirmation = serde_json::from_str(&json).unwrap();
+        assert_eq!(confirmation, decoded);
+    }
+
     #[test]
     fn sse_execution_proof_json_roundtrip() {
         let proof = SseExecutionProof {
@@ -2987,6 +3258,10 @@ mod tests {
             EventTopic::ExecutionPayload,
             EventTopic::PayloadAttestation,
             EventTopic::ExecutionProofReceived,
+            EventTopic::FastConfirmation,
+            EventTopic::InclusionList,
+            EventTopic::ProposerPreferences,
+            EventTopic::PayloadAttestationOutcome,
         ];
         for topic in topics {
             let s = topic.to_string();
@@ -3066,6 +3341,7 @@ mod tests {
                 epoch: Epoch::new(9),
                 root: Hash256::from_low_u64_be(2),
             },
+            confirmed_root: Some(Hash256::from_low_u64_be(3)),
             fork_choice_nodes: vec![ForkChoiceNode {
                 slot: Slot::new(80),
                 block_root: Hash256::from_low_u64_be(3),
@@ -3093,9 +3369,11 @@ mod tests {
                 epoch: Epoch::new(0),
                 root: Hash256::zero(),
             },
+            confirmed_root: None,
             fork_choice_nodes: vec![],
         };
         let json = serde_json::to_string(&fc).unwrap();
+        assert!(!json.contains("confirmed_root"));
         let decoded: ForkChoice = serde_json::from_str(&json).unwrap();
         assert_eq!(fc, decoded);
         assert!(decoded.fork_choice_nodes.is_empty());
@@ -3,12 +3,19 @@
 mod attestation_performance;
 mod block_packing_efficiency;
 mod block_rewards;
+mod builder_reliability;
 mod custody;
+mod database_snapshot;
+mod side_objects;
 pub mod sync_state;
+mod validator_history;
 
 use crate::{
     BeaconNodeHttpClient, DepositData, Error, Hash256, Slot,
-    types::{AdminPeer, Epoch, GenericResponse, ValidatorId},
+    types::{
+        AdminPeer, BlockId, Epoch, EthSpec, ExecutionOptimisticFinalizedResponse, ExecutionProof,
+        GenericResponse, PayloadAttestation, SignedInclusionList, ValidatorId,
+    },
     vibehouse::sync_state::SyncState,
 };
 use proto_array::core::ProtoArray;
@@ -23,7 +30,13 @@ pub use block_packing_efficiency::{
     BlockPackingEfficiency, BlockPackingEfficiencyQuery, ProposerInfo, UniqueAttestation,
 };
 pub use block_rewards::{AttestationRewards, BlockReward, BlockRewardMeta, BlockRewardsQuery};
+pub use builder_reliability::{BuilderReliability, BuilderReliabilityQuery};
 pub use custody::CustodyInfo;
+pub use database_snapshot::{
+    DatabaseSnapshotRequest, DatabaseSnapshotState, DatabaseSnapshotStatus,
+};
+pub use side_objects::PayloadAttestationHistoryQuery;
+pub use validator_history::{ValidatorHistory, ValidatorHistoryQuery};
 
 // Define "legacy" implementations of `Option<T>` which use four bytes for encoding the union
 // selector.
@@ -289,6 +302,39 @@ impl BeaconNodeHttpClient {
         self.post_with_response(path, &()).await
     }
 
+    /// `POST vibehouse/database/snapshot`
+    pub async fn post_vibehouse_database_snapshot(
+        &self,
+        req: &DatabaseSnapshotRequest,
+    ) -> Result<DatabaseSnapshotStatus, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("vibehouse")
+            .push("database")
+            .push("snapshot");
+
+        self.post_with_response(path, req).await
+    }
+
+    /// `GET vibehouse/database/snapshot`
+    ///
+    /// Returns `None` if no snapshot has been requested since the node started.
+    pub async fn get_vibehouse_database_snapshot(
+        &self,
+    ) -> Result<Option<DatabaseSnapshotStatus>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("vibehouse")
+            .push("database")
+            .push("snapshot");
+
+        self.get_opt(path).await
+    }
+
     /// `POST vibehouse/add_peer`
     pub async fn post_vibehouse_add_peer(&self, req: AdminPeer) -> Result<(), Error> {
         let mut path = self.server.full.clone();
@@ -338,6 +384,30 @@ impl BeaconNodeHttpClient {
         self.get(path).await
     }
 
+    /// `GET` vibehouse/analysis/block_rewards?start_slot,end_slot,include_attestations=true
+    ///
+    /// As `get_vibehouse_analysis_block_rewards`, with the attestations rewarded by each block.
+    pub async fn get_vibehouse_analysis_block_rewards_with_attestations(
+        &self,
+        start_slot: Slot,
+        end_slot: Slot,
+    ) -> Result<Vec<BlockReward>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("vibehouse")
+            .push("analysis")
+            .push("block_rewards");
+
+        path.query_pairs_mut()
+            .append_pair("start_slot", &start_slot.to_string())
+            .append_pair("end_slot", &end_slot.to_string())
+            .append_pair("include_attestations", "true");
+
+        self.get(path).await
+    }
+
     /// `GET` vibehouse/analysis/block_packing?start_epoch,end_epoch
     pub async fn get_vibehouse_analysis_block_packing(
         &self,
@@ -359,6 +429,107 @@ impl BeaconNodeHttpClient {
         self.get(path).await
     }
 
+    /// `GET` vibehouse/analysis/builders?start_epoch,end_epoch
+    pub async fn get_vibehouse_analysis_builders(
+        &self,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<Vec<BuilderReliability>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("vibehouse")
+            .push("analysis")
+            .push("builders");
+
+        path.query_pairs_mut()
+            .append_pair("start_epoch", &start_epoch.to_string())
+            .append_pair("end_epoch", &end_epoch.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET` vibehouse/analysis/validator_history/{validator_id}?start_epoch,end_epoch
+    pub async fn get_vibehouse_analysis_validator_history(
+        &self,
+        validator_id: ValidatorId,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<Vec<ValidatorHistory>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("vibehouse")
+            .push("analysis")
+            .push("validator_history")
+            .push(&validator_id.to_string());
+
+        path.query_pairs_mut()
+            .append_pair("start_epoch", &start_epoch.to_string())
+            .append_pair("end_epoch", &end_epoch.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET vibehouse/history/inclusion_lists/{block_id}`
+    pub async fn get_vibehouse_history_inclusion_lists<E: EthSpec>(
+        &self,
+        block_id: BlockId,
+    ) -> Result<GenericResponse<Vec<SignedInclusionList<E>>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("vibehouse")
+            .push("history")
+            .push("inclusion_lists")
+            .push(&block_id.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET vibehouse/history/payload_attestations/{block_id}?beacon_block_root`
+    pub async fn get_vibehouse_history_payload_attestations<E: EthSpec>(
+        &self,
+        block_id: BlockId,
+        beacon_block_root: Option<Hash256>,
+    ) -> Result<GenericResponse<Vec<PayloadAttestation<E>>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("vibehouse")
+            .push("history")
+            .push("payload_attestations")
+            .push(&block_id.to_string());
+
+        if let Some(beacon_block_root) = beacon_block_root {
+            path.query_pairs_mut()
+                .append_pair("beacon_block_root", &format!("{beacon_block_root:?}"));
+        }
+
+        self.get(path).await
+    }
+
+    /// `GET vibehouse/history/execution_proofs/{block_id}`
+    pub async fn get_vibehouse_history_execution_proofs(
+        &self,
+        block_id: BlockId,
+    ) -> Result<ExecutionOptimisticFinalizedResponse<Vec<ExecutionProof>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("vibehouse")
+            .push("history")
+            .push("execution_proofs")
+            .push(&block_id.to_string());
+
+        self.get(path).await
+    }
+
     /// `GET` vibehouse/analysis/attestation_performance/{index}?start_epoch,end_epoch
     pub async fn get_vibehouse_analysis_attestation_performance(
         &self,
@@ -45,6 +45,10 @@ pub struct AttestationRewards {
     /// The attestations themselves (optional).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub attestations: Vec<AttestationData>,
+    /// The indices of the validators attesting in each attestation, including those which earned
+    /// no reward for it (present with `attestations`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attesting_indices: Vec<Vec<u64>>,
 }
 
 /// Query parameters for the `/vibehouse/block_rewards` endpoint.
@@ -80,6 +84,7 @@ mod tests {
                 curr_epoch_total: 300,
                 per_attestation_rewards: vec![],
                 attestations: vec![],
+                attesting_indices: vec![],
             },
             sync_committee_rewards: 200,
         };
@@ -100,6 +105,7 @@ mod tests {
             curr_epoch_total: 0,
             per_attestation_rewards: vec![map],
             attestations: vec![],
+            attesting_indices: vec![vec![1, 2, 3]],
         };
         let json = serde_json::to_string(&rewards).unwrap();
         let decoded: AttestationRewards = serde_json::from_str(&json).unwrap();
@@ -114,9 +120,11 @@ mod tests {
             curr_epoch_total: 0,
             per_attestation_rewards: vec![],
             attestations: vec![],
+            attesting_indices: vec![],
         };
         let json = serde_json::to_string(&rewards).unwrap();
         assert!(!json.contains("attestations"));
+        assert!(!json.contains("attesting_indices"));
     }
 
     #[test]
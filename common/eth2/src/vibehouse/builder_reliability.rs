@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use ssz_derive::{Decode, Encode};
+use types::Epoch;
+
+/// Counters describing how an in-protocol builder behaved over a range of epochs.
+///
+/// Slots are attributed to the epoch they belong to, so a builder payment is counted in the epoch
+/// of the bid it pays for, not the epoch in which it was settled.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize, Encode, Decode)]
+pub struct BuilderReliability {
+    pub builder_index: u64,
+    /// Bids from this builder which passed gossip verification.
+    pub bids_seen: u64,
+    /// Imported blocks which committed to a bid from this builder.
+    pub bids_won: u64,
+    /// Won bids for which the PTC reached the payload timeliness quorum.
+    pub payloads_revealed: u64,
+    /// Won bids for which the PTC did not reach the payload timeliness quorum.
+    pub payloads_withheld: u64,
+    /// Total `payload_present` votes cast by the PTC for this builder's payloads.
+    pub ptc_votes: u64,
+    /// Builder pending payments which reached the quorum and were queued for withdrawal.
+    pub payments_settled: u64,
+    /// Builder pending payments which were dropped for lack of attestation weight.
+    pub payments_missed: u64,
+    /// Builder pending payments which were cancelled by a proposer slashing.
+    pub payments_slashed: u64,
+}
+
+impl BuilderReliability {
+    pub fn new(builder_index: u64) -> Self {
+        Self {
+            builder_index,
+            ..Default::default()
+        }
+    }
+
+    /// Add the counters of `other` to `self`.
+    pub fn merge(&mut self, other: &Self) {
+        self.bids_seen = self.bids_seen.saturating_add(other.bids_seen);
+        self.bids_won = self.bids_won.saturating_add(other.bids_won);
+        self.payloads_revealed = self
+            .payloads_revealed
+            .saturating_add(other.payloads_revealed);
+        self.payloads_withheld = self
+            .payloads_withheld
+            .saturating_add(other.payloads_withheld);
+        self.ptc_votes = self.ptc_votes.saturating_add(other.ptc_votes);
+        self.payments_settled = self.payments_settled.saturating_add(other.payments_settled);
+        self.payments_missed = self.payments_missed.saturating_add(other.payments_missed);
+        self.payments_slashed = self.payments_slashed.saturating_add(other.payments_slashed);
+    }
+}
+
+/// Query parameters for the `/vibehouse/analysis/builders` endpoint.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct BuilderReliabilityQuery {
+    /// Lower epoch limit (inclusive).
+    pub start_epoch: Epoch,
+    /// Upper epoch limit (inclusive).
+    pub end_epoch: Epoch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ssz::{Decode, Encode};
+
+    #[test]
+    fn merge_adds_counters() {
+        let mut a = BuilderReliability {
+            bids_seen: 3,
+            bids_won: 1,
+            payloads_revealed: 1,
+            ptc_votes: 300,
+            payments_settled: 1,
+            ..BuilderReliability::new(7)
+        };
+        let b = BuilderReliability {
+            bids_seen: 2,
+            bids_won: 2,
+            payloads_revealed: 1,
+            payloads_withheld: 1,
+            ptc_votes: 200,
+            payments_missed: 1,
+            payments_slashed: 1,
+            ..BuilderReliability::new(7)
+        };
+        a.merge(&b);
+        assert_eq!(
+            a,
+            BuilderReliability {
+                builder_index: 7,
+                bids_seen: 5,
+                bids_won: 3,
+                payloads_revealed: 2,
+                payloads_withheld: 1,
+                ptc_votes: 500,
+                payments_settled: 1,
+                payments_missed: 1,
+                payments_slashed: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn ssz_roundtrip() {
+        let records = vec![
+            BuilderReliability {
+                bids_seen: 4,
+                ..BuilderReliability::new(0)
+            },
+            BuilderReliability {
+                bids_won: 1,
+                payments_settled: 1,
+                ..BuilderReliability::new(12)
+            },
+        ];
+        let bytes = records.as_ssz_bytes();
+        assert_eq!(
+            Vec::<BuilderReliability>::from_ssz_bytes(&bytes).unwrap(),
+            records
+        );
+    }
+
+    #[test]
+    fn query_serde_roundtrip() {
+        let q = BuilderReliabilityQuery {
+            start_epoch: Epoch::new(10),
+            end_epoch: Epoch::new(20),
+        };
+        let json = serde_json::to_string(&q).unwrap();
+        let decoded: BuilderReliabilityQuery = serde_json::from_str(&json).unwrap();
+        assert_eq!(q, decoded);
+    }
+}
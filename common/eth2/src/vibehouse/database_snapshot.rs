@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use types::Checkpoint;
+
+/// Body of `POST vibehouse/database/snapshot`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DatabaseSnapshotRequest {
+    /// Data directory to write the copy to. The databases are written to the same locations
+    /// within it as in the node's own data directory, so it can be used with `--datadir`.
+    pub target_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatabaseSnapshotState {
+    Running,
+    Complete,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DatabaseSnapshotStatus {
+    pub target_dir: PathBuf,
+    pub state: DatabaseSnapshotState,
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub entries_copied: u64,
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub bytes_copied: u64,
+    /// The finalized checkpoint of the copy, once it is complete.
+    pub finalized_checkpoint: Option<Checkpoint>,
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::{Epoch, Hash256};
+
+    #[test]
+    fn serde_roundtrip() {
+        let status = DatabaseSnapshotStatus {
+            target_dir: PathBuf::from("/backups/vibehouse"),
+            state: DatabaseSnapshotState::Complete,
+            entries_copied: 1024,
+            bytes_copied: 1 << 30,
+            finalized_checkpoint: Some(Checkpoint {
+                epoch: Epoch::new(10),
+                root: Hash256::repeat_byte(0xaa),
+            }),
+            error: None,
+        };
+        let json = serde_json::to_string(&status).unwrap();
+        assert!(json.contains("\"state\":\"complete\""));
+        assert!(json.contains("\"entries_copied\":\"1024\""));
+        let decoded: DatabaseSnapshotStatus = serde_json::from_str(&json).unwrap();
+        assert_eq!(status, decoded);
+    }
+
+    #[test]
+    fn request_roundtrip() {
+        let request: DatabaseSnapshotRequest =
+            serde_json::from_str(r#"{"target_dir":"/backups/vibehouse"}"#).unwrap();
+        assert_eq!(request.target_dir, PathBuf::from("/backups/vibehouse"));
+    }
+}
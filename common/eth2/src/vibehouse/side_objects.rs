@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use types::Hash256;
+
+/// Query parameters for the `/vibehouse/history/payload_attestations/{block_id}` endpoint.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct PayloadAttestationHistoryQuery {
+    /// Only return attestations for this block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beacon_block_root: Option<Hash256>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_serde_roundtrip() {
+        let q = PayloadAttestationHistoryQuery {
+            beacon_block_root: Some(Hash256::repeat_byte(0x11)),
+        };
+        let json = serde_json::to_string(&q).unwrap();
+        let decoded: PayloadAttestationHistoryQuery = serde_json::from_str(&json).unwrap();
+        assert_eq!(q, decoded);
+
+        let empty: PayloadAttestationHistoryQuery = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty, PayloadAttestationHistoryQuery::default());
+    }
+}
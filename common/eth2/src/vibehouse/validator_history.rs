@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use types::Epoch;
+
+/// The balance, participation and status of a validator in a single epoch.
+///
+/// Balances and statuses are those at the start of the following epoch, after the epoch
+/// transition. The `timely_*` flags are the participation flags earned for `epoch`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct ValidatorHistory {
+    pub epoch: Epoch,
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub balance: u64,
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub effective_balance: u64,
+    pub timely_source: bool,
+    pub timely_target: bool,
+    pub timely_head: bool,
+    pub active: bool,
+    pub slashed: bool,
+    pub exit_initiated: bool,
+    pub exited: bool,
+}
+
+/// Query parameters for the `/vibehouse/analysis/validator_history/{validator_id}` endpoint.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct ValidatorHistoryQuery {
+    /// Lower epoch limit (inclusive).
+    pub start_epoch: Epoch,
+    /// Upper epoch limit (inclusive).
+    pub end_epoch: Epoch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_roundtrip() {
+        let history = vec![ValidatorHistory {
+            epoch: Epoch::new(7),
+            balance: 32_000_012_345,
+            effective_balance: 32_000_000_000,
+            timely_source: true,
+            timely_target: true,
+            timely_head: false,
+            active: true,
+            slashed: false,
+            exit_initiated: false,
+            exited: false,
+        }];
+        let json = serde_json::to_string(&history).unwrap();
+        assert!(json.contains("\"balance\":\"32000012345\""));
+        let decoded: Vec<ValidatorHistory> = serde_json::from_str(&json).unwrap();
+        assert_eq!(history, decoded);
+    }
+
+    #[test]
+    fn query_serde_roundtrip() {
+        let q = ValidatorHistoryQuery {
+            start_epoch: Epoch::new(10),
+            end_epoch: Epoch::new(20),
+        };
+        let json = serde_json::to_string(&q).unwrap();
+        let decoded: ValidatorHistoryQuery = serde_json::from_str(&json).unwrap();
+        assert_eq!(q, decoded);
+    }
+}
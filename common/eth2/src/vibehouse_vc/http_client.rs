@@ -1,13 +1,13 @@
 use super::types::{
     AuthResponse, CreateValidatorsMnemonicRequest, CreatedValidator, DeleteKeystoresRequest,
-    DeleteKeystoresResponse, DeleteRemotekeysRequest, DeleteRemotekeysResponse, Epoch,
-    ExportKeystoresResponse, GenericResponse, GetFeeRecipientResponse, GetGasLimitResponse,
-    GetGraffitiResponse, Health, ImportKeystoresRequest, ImportKeystoresResponse,
-    ImportRemotekeysRequest, ImportRemotekeysResponse, KeystoreValidatorsPostRequest,
-    ListKeystoresResponse, ListRemotekeysResponse, PostValidatorsResponseData, PublicKeyBytes,
-    SetGraffitiRequest, SignedVoluntaryExit, UpdateFeeRecipientRequest, UpdateGasLimitRequest,
-    ValidatorData, ValidatorPatchRequest, ValidatorRequest, VersionData,
-    Web3SignerValidatorRequest,
+    DeleteKeystoresResponse, DeleteRemotekeysRequest, DeleteRemotekeysResponse, DutyHistoryQuery,
+    DutyKind, DutyRecord, Epoch, ExportKeystoresResponse, GenericResponse, GetFeeRecipientResponse,
+    GetGasLimitResponse, GetGraffitiResponse, Health, ImportKeystoresRequest,
+    ImportKeystoresResponse, ImportRemotekeysRequest, ImportRemotekeysResponse,
+    KeystoreValidatorsPostRequest, ListKeystoresResponse, ListRemotekeysResponse,
+    PostValidatorsResponseData, PublicKeyBytes, SetGraffitiRequest, SignedVoluntaryExit,
+    UpdateFeeRecipientRequest, UpdateGasLimitRequest, ValidatorData, ValidatorPatchRequest,
+    ValidatorRequest, VersionData, Web3SignerValidatorRequest,
 };
 use crate::Error;
 use reqwest::{
@@ -359,6 +359,45 @@ impl ValidatorClientHttpClient {
         self.get_opt(path).await
     }
 
+    /// `GET vibehouse/validators/{validator_pubkey}/history[/{duty}]`
+    pub async fn get_vibehouse_validators_history(
+        &self,
+        validator_pubkey: &PublicKeyBytes,
+        duty: Option<DutyKind>,
+        query: &DutyHistoryQuery,
+    ) -> Result<GenericResponse<Vec<DutyRecord>>, Error> {
+        let mut path = self.server.full.clone();
+
+        {
+            let mut segments = path
+                .path_segments_mut()
+                .map_err(|()| Error::InvalidUrl(self.server.clone()))?;
+            segments
+                .push("vibehouse")
+                .push("validators")
+                .push(&validator_pubkey.to_string())
+                .push("history");
+            if let Some(duty) = duty {
+                segments.push(duty.as_str());
+            }
+        }
+
+        if let Some(start_slot) = query.start_slot {
+            path.query_pairs_mut()
+                .append_pair("start_slot", &start_slot.to_string());
+        }
+        if let Some(end_slot) = query.end_slot {
+            path.query_pairs_mut()
+                .append_pair("end_slot", &end_slot.to_string());
+        }
+        if let Some(limit) = query.limit {
+            path.query_pairs_mut()
+                .append_pair("limit", &limit.to_string());
+        }
+
+        self.get(path).await
+    }
+
     /// `POST vibehouse/validators`
     pub async fn post_vibehouse_validators(
         &self,
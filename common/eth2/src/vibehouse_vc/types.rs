@@ -208,6 +208,114 @@ pub struct UpdateCandidatesResponse {
     pub new_beacon_nodes_list: Vec<String>,
 }
 
+/// A kind of duty recorded in the duty history of the validator client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DutyKind {
+    Attestation,
+    Aggregate,
+    Block,
+    SyncCommittee,
+    PayloadAttestation,
+    InclusionList,
+}
+
+impl DutyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DutyKind::Attestation => "attestation",
+            DutyKind::Aggregate => "aggregate",
+            DutyKind::Block => "block",
+            DutyKind::SyncCommittee => "sync_committee",
+            DutyKind::PayloadAttestation => "payload_attestation",
+            DutyKind::InclusionList => "inclusion_list",
+        }
+    }
+}
+
+impl std::str::FromStr for DutyKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "attestation" => Ok(DutyKind::Attestation),
+            "aggregate" => Ok(DutyKind::Aggregate),
+            "block" => Ok(DutyKind::Block),
+            "sync_committee" => Ok(DutyKind::SyncCommittee),
+            "payload_attestation" => Ok(DutyKind::PayloadAttestation),
+            "inclusion_list" => Ok(DutyKind::InclusionList),
+            other => Err(format!("unknown duty kind: {other}")),
+        }
+    }
+}
+
+impl std::fmt::Display for DutyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How a duty turned out, as confirmed on chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DutyOutcome {
+    Included,
+    Missed,
+}
+
+impl DutyOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DutyOutcome::Included => "included",
+            DutyOutcome::Missed => "missed",
+        }
+    }
+}
+
+impl std::str::FromStr for DutyOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "included" => Ok(DutyOutcome::Included),
+            "missed" => Ok(DutyOutcome::Missed),
+            other => Err(format!("unknown duty outcome: {other}")),
+        }
+    }
+}
+
+/// A duty performed by a validator, from the duty history of the validator client.
+///
+/// Times are in milliseconds since the UNIX epoch. The outcome and the fields describing it are
+/// `None` until they have been confirmed on chain, and remain so for duties whose outcome can't
+/// be attributed to the validator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DutyRecord {
+    pub duty: DutyKind,
+    pub slot: Slot,
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub validator_index: u64,
+    pub signed_at_ms: u64,
+    pub published_at_ms: Option<u64>,
+    /// The beacon nodes which accepted the published message.
+    pub beacon_node: Option<String>,
+    pub outcome: Option<DutyOutcome>,
+    pub inclusion_delay: Option<u64>,
+    pub head_correct: Option<bool>,
+    pub target_correct: Option<bool>,
+}
+
+/// Query parameters for the `/vibehouse/validators/{validator_pubkey}/history` endpoints.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DutyHistoryQuery {
+    /// Lower slot limit (inclusive).
+    pub start_slot: Option<Slot>,
+    /// Upper slot limit (inclusive).
+    pub end_slot: Option<Slot>,
+    /// The maximum number of records to return, most recent first.
+    pub limit: Option<u64>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -376,4 +484,21 @@ mod tests {
         let out = serde_json::to_string(&req).unwrap();
         assert!(out.contains("0x0000000000000000000000000000000000000001"));
     }
+
+    #[test]
+    fn duty_kind_str_roundtrip() {
+        for duty in [
+            DutyKind::Attestation,
+            DutyKind::Aggregate,
+            DutyKind::Block,
+            DutyKind::SyncCommittee,
+            DutyKind::PayloadAttestation,
+            DutyKind::InclusionList,
+        ] {
+            assert_eq!(duty.as_str().parse::<DutyKind>().unwrap(), duty);
+            let json = serde_json::to_string(&duty).unwrap();
+            assert_eq!(json, format!("\"{}\"", duty.as_str()));
+        }
+        assert!("proposal".parse::<DutyKind>().is_err());
+    }
 }
@@ -123,6 +123,12 @@ pub trait SlotClock: Send + Sync + Sized + Clone {
         self.slot_duration() * 2 / self.current_intervals_per_slot() as u32
     }
 
+    /// Returns the delay between the start of the slot and when payload attestations should be
+    /// produced. Only applicable from Gloas.
+    fn payload_attestation_production_delay(&self) -> Duration {
+        self.slot_duration() * 3 / INTERVALS_PER_SLOT_GLOAS as u32
+    }
+
     /// Returns the `Duration` since the start of the current `Slot` at seconds precision. Useful in determining whether to apply proposer boosts.
     fn seconds_from_current_slot_start(&self) -> Option<Duration> {
         self.now_duration()
@@ -0,0 +1,277 @@
+//! Fast Confirmation Rule (FCR).
+//!
+//! Identifies blocks which are safe from re-orgs under honest-majority and synchrony assumptions,
+//! typically one or two slots behind the head rather than one or two epochs.
+//!
+//! See `docs/workstreams/fast-confirmation-rule.md` and consensus-specs#4747.
+//!
+//! A block is confirmed when it is LMD-GHOST safe (`is_one_confirmed`) and FFG safe:
+//!
+//! - A block from the current epoch additionally requires the head's voting source to be from the
+//!   previous epoch and the current target to be justified by the end of the epoch
+//!   (`will_current_target_be_justified`).
+//! - A block from the previous epoch additionally requires the head's voting source to be at most
+//!   two epochs old and that no checkpoint conflicting with the current target can be justified
+//!   (`will_no_conflicting_checkpoint_be_justified`).
+//!
+//! Block support only counts the committees of the slots since the block's parent, see
+//! `ProtoArrayForkChoice::get_block_supports_between_slots`.
+//!
+//! ## Deviations from the specification
+//!
+//! The empty-slot support discount and the equivocation score are not subtracted from the safety
+//! threshold. Both only ever lower the threshold, so omitting them makes confirmation strictly
+//! more conservative. The FCR store is not persisted: after a restart the confirmed root falls
+//! back to the finalized root, which is always safe.
+
+use types::{Checkpoint, EthSpec, Hash256, Slot};
+
+/// Maximum percentage of Byzantine validator weight assumed when confirming a block.
+pub const CONFIRMATION_BYZANTINE_THRESHOLD: u64 = 25;
+
+/// Per-mille increase applied to committee weight estimates that cannot be computed exactly.
+pub const COMMITTEE_WEIGHT_ESTIMATION_ADJUSTMENT_FACTOR: u64 = 5;
+
+/// The additional fork choice store fields required by the Fast Confirmation Rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FastConfirmationStore {
+    /// The most recently confirmed block root.
+    pub confirmed_root: Hash256,
+    /// The justified checkpoint observed at the start of the previous epoch.
+    pub previous_epoch_observed_justified_checkpoint: Checkpoint,
+    /// The justified checkpoint observed at the start of the current epoch.
+    pub current_epoch_observed_justified_checkpoint: Checkpoint,
+    /// The head at the start of the previous slot.
+    pub previous_slot_head: Hash256,
+    /// The head at the start of the current slot.
+    pub current_slot_head: Hash256,
+    /// The slot for which the rule last ran. The rule runs at most once per slot.
+    pub slot: Option<Slot>,
+}
+
+impl FastConfirmationStore {
+    /// Initialize the store from an anchor, which must be safe to consider confirmed.
+    pub fn new(anchor_root: Hash256, justified_checkpoint: Checkpoint) -> Self {
+        Self {
+            confirmed_root: anchor_root,
+            previous_epoch_observed_justified_checkpoint: justified_checkpoint,
+            current_epoch_observed_justified_checkpoint: justified_checkpoint,
+            previous_slot_head: anchor_root,
+            current_slot_head: anchor_root,
+            slot: None,
+        }
+    }
+}
+
+/// Returns `true` if every validator is assigned to attest in some slot of
+/// `[start_slot, end_slot]`, i.e. the range covers a whole epoch.
+pub fn is_full_validator_set_covered<E: EthSpec>(start_slot: Slot, end_slot: Slot) -> bool {
+    let slots_per_epoch = E::slots_per_epoch();
+    let start_epoch = start_slot.epoch(slots_per_epoch);
+    let first_full_epoch = if start_slot == start_epoch.start_slot(slots_per_epoch) {
+        start_epoch
+    } else {
+        start_epoch + 1
+    };
+    first_full_epoch.end_slot(slots_per_epoch) <= end_slot
+}
+
+/// Increase `estimate` by `COMMITTEE_WEIGHT_ESTIMATION_ADJUSTMENT_FACTOR` per-mille.
+pub fn adjust_committee_weight_estimate_to_ensure_safety(estimate: u64) -> u64 {
+    estimate
+        .saturating_mul(1000 + COMMITTEE_WEIGHT_ESTIMATION_ADJUSTMENT_FACTOR)
+        .saturating_div(1000)
+}
+
+/// Estimate the total weight of the committees of all slots in `[start_slot, end_slot]`.
+///
+/// Ranges within a single epoch are exact. Ranges spanning an epoch boundary without covering a
+/// whole epoch are estimated pro-rata: a validator from the previous epoch's portion only counts
+/// if it has not yet re-attested in the current epoch.
+pub fn estimate_committee_weight_between_slots<E: EthSpec>(
+    total_active_balance: u64,
+    start_slot: Slot,
+    end_slot: Slot,
+) -> u64 {
+    if start_slot > end_slot {
+        return 0;
+    }
+    if is_full_validator_set_covered::<E>(start_slot, end_slot) {
+        return total_active_balance;
+    }
+
+    let slots_per_epoch = E::slots_per_epoch();
+    let committee_weight = total_active_balance / slots_per_epoch;
+    let start_epoch = start_slot.epoch(slots_per_epoch);
+    let end_epoch = end_slot.epoch(slots_per_epoch);
+
+    if start_epoch == end_epoch {
+        return (end_slot - start_slot + 1)
+            .as_u64()
+            .saturating_mul(committee_weight);
+    }
+
+    let slots_in_current_epoch = end_slot.as_u64() % slots_per_epoch + 1;
+    let slots_in_previous_epoch = slots_per_epoch - start_slot.as_u64() % slots_per_epoch;
+    let remaining_slots_in_current_epoch = slots_per_epoch - slots_in_current_epoch;
+
+    let current_epoch_weight = slots_in_current_epoch.saturating_mul(committee_weight);
+    let previous_epoch_weight = slots_in_previous_epoch
+        .saturating_mul(committee_weight)
+        .saturating_mul(remaining_slots_in_current_epoch)
+        / slots_per_epoch;
+
+    adjust_committee_weight_estimate_to_ensure_safety(
+        current_epoch_weight.saturating_add(previous_epoch_weight),
+    )
+}
+
+/// The support a block needs so that no competing block can outweigh it, even if
+/// `adversarial_weight` switches sides and the next proposer is boosted.
+pub fn compute_safety_threshold(
+    maximum_support: u64,
+    proposer_score: u64,
+    adversarial_weight: u64,
+) -> u64 {
+    maximum_support.saturating_add(proposer_score) / 2 + adversarial_weight
+}
+
+/// The maximum weight controlled by the adversary out of `maximum_support`.
+pub fn compute_adversarial_weight(maximum_support: u64) -> u64 {
+    maximum_support.saturating_mul(CONFIRMATION_BYZANTINE_THRESHOLD) / 100
+}
+
+/// The FFG support for the current target which is guaranteed to come from honest validators by
+/// the end of the current epoch.
+///
+/// `target_score` is the support already received from the committees of the elapsed slots of the
+/// epoch, which together weigh `elapsed_weight`. Up to `CONFIRMATION_BYZANTINE_THRESHOLD` percent
+/// of it may be adversarial. Of the `remaining_weight` of committees yet to attest, only the honest
+/// share is assumed to vote for the current target.
+pub fn compute_honest_ffg_support_for_current_target(
+    target_score: u64,
+    elapsed_weight: u64,
+    remaining_weight: u64,
+) -> u64 {
+    let honest_target_score =
+        target_score.saturating_sub(compute_adversarial_weight(elapsed_weight));
+    let honest_remaining_weight =
+        remaining_weight.saturating_sub(compute_adversarial_weight(remaining_weight));
+    honest_target_score.saturating_add(honest_remaining_weight)
+}
+
+/// Returns `true` if `honest_ffg_support` is enough to justify the current target.
+pub fn will_current_target_be_justified(
+    honest_ffg_support: u64,
+    total_active_balance: u64,
+) -> bool {
+    honest_ffg_support.saturating_mul(3) >= total_active_balance.saturating_mul(2)
+}
+
+/// Returns `true` if `honest_ffg_support` for the current target leaves too little weight for any
+/// conflicting checkpoint of the current epoch to be justified.
+///
+/// Honest validators never cast two FFG votes in one epoch, so a conflicting checkpoint can be
+/// supported by at most `total_active_balance - honest_ffg_support`.
+pub fn will_no_conflicting_checkpoint_be_justified(
+    honest_ffg_support: u64,
+    total_active_balance: u64,
+) -> bool {
+    honest_ffg_support.saturating_mul(3) > total_active_balance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::MinimalEthSpec;
+
+    type E = MinimalEthSpec;
+
+    const TOTAL: u64 = 8 * 32_000_000_000;
+
+    fn committee_weight() -> u64 {
+        TOTAL / E::slots_per_epoch()
+    }
+
+    #[test]
+    fn full_validator_set_covered() {
+        // Minimal: 8 slots per epoch.
+        assert!(is_full_validator_set_covered::<E>(
+            Slot::new(0),
+            Slot::new(7)
+        ));
+        assert!(!is_full_validator_set_covered::<E>(
+            Slot::new(1),
+            Slot::new(8)
+        ));
+        assert!(is_full_validator_set_covered::<E>(
+            Slot::new(1),
+            Slot::new(15)
+        ));
+    }
+
+    #[test]
+    fn committee_weight_within_epoch_is_exact() {
+        assert_eq!(
+            estimate_committee_weight_between_slots::<E>(TOTAL, Slot::new(2), Slot::new(4)),
+            3 * committee_weight()
+        );
+        assert_eq!(
+            estimate_committee_weight_between_slots::<E>(TOTAL, Slot::new(4), Slot::new(3)),
+            0
+        );
+        assert_eq!(
+            estimate_committee_weight_between_slots::<E>(TOTAL, Slot::new(8), Slot::new(20)),
+            TOTAL
+        );
+    }
+
+    #[test]
+    fn committee_weight_across_epoch_boundary_is_pro_rata() {
+        // Slots 6..=9: 2 slots in the previous epoch, 2 slots in the current epoch leaving 6
+        // remaining.
+        let current = 2 * committee_weight();
+        let previous = 2 * committee_weight() * 6 / 8;
+        assert_eq!(
+            estimate_committee_weight_between_slots::<E>(TOTAL, Slot::new(6), Slot::new(9)),
+            adjust_committee_weight_estimate_to_ensure_safety(current + previous)
+        );
+    }
+
+    #[test]
+    fn safety_threshold() {
+        let maximum_support = 1000;
+        let adversarial_weight = compute_adversarial_weight(maximum_support);
+        assert_eq!(adversarial_weight, 250);
+        assert_eq!(
+            compute_safety_threshold(maximum_support, 400, adversarial_weight),
+            950
+        );
+    }
+
+    #[test]
+    fn honest_ffg_support() {
+        // Full participation: a quarter of every committee may be adversarial.
+        let honest = compute_honest_ffg_support_for_current_target(400, 400, 600);
+        assert_eq!(honest, 750);
+        assert!(will_current_target_be_justified(honest, 1000));
+        assert!(will_no_conflicting_checkpoint_be_justified(honest, 1000));
+
+        // At the start of the epoch only the honest share of all committees is counted.
+        let honest = compute_honest_ffg_support_for_current_target(0, 0, 1000);
+        assert_eq!(honest, 750);
+        assert!(will_current_target_be_justified(honest, 1000));
+
+        // Low participation: half of the elapsed committees voted for the target.
+        let honest = compute_honest_ffg_support_for_current_target(400, 800, 200);
+        assert_eq!(honest, 350);
+        assert!(!will_current_target_be_justified(honest, 1000));
+        assert!(will_no_conflicting_checkpoint_be_justified(honest, 1000));
+
+        // Almost no votes for the target by the end of the epoch.
+        let honest = compute_honest_ffg_support_for_current_target(300, 1000, 0);
+        assert_eq!(honest, 50);
+        assert!(!will_current_target_be_justified(honest, 1000));
+        assert!(!will_no_conflicting_checkpoint_be_justified(honest, 1000));
+    }
+}
@@ -1,3 +1,9 @@
+use crate::fast_confirmation::{
+    FastConfirmationStore, compute_adversarial_weight,
+    compute_honest_ffg_support_for_current_target, compute_safety_threshold,
+    estimate_committee_weight_between_slots, will_current_target_be_justified,
+    will_no_conflicting_checkpoint_be_justified,
+};
 use crate::metrics::{self, scrape_for_metrics};
 use crate::{ForkChoiceStore, InvalidationOperation};
 use logging::crit;
@@ -335,6 +341,28 @@ pub struct ForkchoiceUpdateParameters {
     pub head_hash: Option<ExecutionBlockHash>,
     pub justified_hash: Option<ExecutionBlockHash>,
     pub finalized_hash: Option<ExecutionBlockHash>,
+    /// The execution block hash of the Fast Confirmation Rule's confirmed block. `None` unless
+    /// the rule is enabled.
+    pub confirmed_hash: Option<ExecutionBlockHash>,
+}
+
+impl ForkchoiceUpdateParameters {
+    /// The `safeBlockHash` for `forkchoiceUpdated`: the confirmed block when the Fast
+    /// Confirmation Rule is enabled, otherwise the justified block.
+    pub fn safe_hash(&self) -> Option<ExecutionBlockHash> {
+        self.confirmed_hash.or(self.justified_hash)
+    }
+}
+
+/// The PTC's votes on the payload of a block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayloadAttestationOutcome {
+    /// Whether the payload timeliness quorum was reached.
+    pub payload_present: bool,
+    /// Whether the blob data availability quorum was reached.
+    pub blob_data_available: bool,
+    pub payload_present_votes: u64,
+    pub blob_data_available_votes: u64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -342,6 +370,8 @@ pub struct ForkChoiceView {
     pub head_block_root: Hash256,
     pub justified_checkpoint: Checkpoint,
     pub finalized_checkpoint: Checkpoint,
+    /// The Fast Confirmation Rule's confirmed block root. `None` unless the rule is enabled.
+    pub confirmed_root: Option<Hash256>,
 }
 
 /// Provides an implementation of "Ethereum 2.0 Phase 0 -- Beacon Chain Fork Choice":
@@ -363,6 +393,9 @@ pub struct ForkChoice<T, E> {
     queued_attestations: Vec<QueuedAttestation>,
     /// Stores a cache of the values required to be sent to the execution layer.
     forkchoice_update_parameters: ForkchoiceUpdateParameters,
+    /// State of the Fast Confirmation Rule. `None` until `Self::on_fast_confirmation` is first
+    /// called, i.e. whilst the rule is disabled. Not persisted.
+    fast_confirmation: Option<FastConfirmationStore>,
     _phantom: PhantomData<E>,
 }
 
@@ -482,9 +515,11 @@ where
                 head_hash: None,
                 justified_hash: None,
                 finalized_hash: None,
+                confirmed_hash: None,
                 // This will be updated during the next call to `Self::get_head`.
                 head_root: Hash256::zero(),
             },
+            fast_confirmation: None,
             _phantom: PhantomData,
         };
 
@@ -585,11 +620,15 @@ where
         let finalized_hash = self
             .get_block(&finalized_root)
             .and_then(|b| b.execution_status.block_hash());
+        let confirmed_hash = self
+            .confirmed_root()
+            .and_then(|root| self.confirmed_execution_block_hash(&root));
         self.forkchoice_update_parameters = ForkchoiceUpdateParameters {
             head_root,
             head_hash,
             justified_hash,
             finalized_hash,
+            confirmed_hash,
         };
 
         Ok(head_root)
@@ -684,9 +723,240 @@ where
             head_block_root: self.forkchoice_update_parameters.head_root,
             justified_checkpoint: self.justified_checkpoint(),
             finalized_checkpoint: self.finalized_checkpoint(),
+            confirmed_root: self.confirmed_root(),
         }
     }
 
+    /// Returns the Fast Confirmation Rule's confirmed block root, or `None` if the rule is
+    /// disabled.
+    pub fn confirmed_root(&self) -> Option<Hash256> {
+        self.fast_confirmation.map(|fcs| fcs.confirmed_root)
+    }
+
+    /// Returns the state of the Fast Confirmation Rule, or `None` if the rule is disabled.
+    pub fn fast_confirmation_store(&self) -> Option<&FastConfirmationStore> {
+        self.fast_confirmation.as_ref()
+    }
+
+    /// Run the Fast Confirmation Rule for `current_slot` and return the confirmed block root.
+    ///
+    /// Must be called after `Self::get_head` has run for `current_slot` so that the latest
+    /// messages have been applied. The rule runs at most once per slot, further calls in the same
+    /// slot return the existing confirmed root. The first call enables the rule, starting from the
+    /// finalized block.
+    ///
+    /// See `crate::fast_confirmation` for details.
+    pub fn on_fast_confirmation(&mut self, current_slot: Slot, spec: &ChainSpec) -> Hash256 {
+        let justified_checkpoint = self.justified_checkpoint();
+        let mut fcs = self.fast_confirmation.unwrap_or_else(|| {
+            FastConfirmationStore::new(self.finalized_checkpoint().root, justified_checkpoint)
+        });
+        if fcs.slot == Some(current_slot) {
+            return fcs.confirmed_root;
+        }
+
+        let head_root = self.forkchoice_update_parameters.head_root;
+        let slots_per_epoch = E::slots_per_epoch();
+        let is_epoch_start = current_slot
+            == current_slot
+                .epoch(slots_per_epoch)
+                .start_slot(slots_per_epoch);
+
+        fcs.previous_slot_head = fcs.current_slot_head;
+        fcs.current_slot_head = head_root;
+        if is_epoch_start {
+            fcs.previous_epoch_observed_justified_checkpoint =
+                fcs.current_epoch_observed_justified_checkpoint;
+            fcs.current_epoch_observed_justified_checkpoint = justified_checkpoint;
+        }
+        fcs.slot = Some(current_slot);
+        fcs.confirmed_root =
+            self.get_latest_confirmed(&fcs, head_root, current_slot, is_epoch_start, spec);
+
+        self.fast_confirmation = Some(fcs);
+        self.forkchoice_update_parameters.confirmed_hash =
+            self.confirmed_execution_block_hash(&fcs.confirmed_root);
+
+        fcs.confirmed_root
+    }
+
+    /// Determine the latest confirmed block, given the already-updated `fcs` variables.
+    fn get_latest_confirmed(
+        &self,
+        fcs: &FastConfirmationStore,
+        head_root: Hash256,
+        current_slot: Slot,
+        is_epoch_start: bool,
+        spec: &ChainSpec,
+    ) -> Hash256 {
+        let slots_per_epoch = E::slots_per_epoch();
+        let current_epoch = current_slot.epoch(slots_per_epoch);
+        let current_epoch_start_slot = current_epoch.start_slot(slots_per_epoch);
+        let previous_slot = current_slot.saturating_sub(1_u64);
+        let finalized_root = self.finalized_checkpoint().root;
+        let observed_justified = fcs.current_epoch_observed_justified_checkpoint;
+        let equivocating_indices = self.fc_store.equivocating_indices();
+        let total_active_balance = self.fc_store.justified_balances().total_effective_balance;
+
+        let Some(head_block) = self.proto_array.get_block(&head_root) else {
+            return finalized_root;
+        };
+
+        // The source honest validators use for their FFG votes whilst the head is canonical.
+        let voting_source = if head_block.slot.epoch(slots_per_epoch) < current_epoch {
+            head_block
+                .unrealized_justified_checkpoint
+                .unwrap_or(head_block.justified_checkpoint)
+        } else {
+            head_block.justified_checkpoint
+        };
+
+        // FFG safety: the honest support for the current target decides whether it will be
+        // justified, and whether a conflicting checkpoint still could be.
+        let current_target_score = self
+            .get_ancestor(head_root, current_epoch_start_slot)
+            .ok()
+            .flatten()
+            .and_then(|target_root| {
+                self.proto_array
+                    .get_block_supports_between_slots(
+                        current_epoch_start_slot,
+                        previous_slot,
+                        equivocating_indices,
+                    )
+                    .get(&target_root)
+                    .copied()
+            })
+            .unwrap_or(0);
+        let honest_ffg_support = compute_honest_ffg_support_for_current_target(
+            current_target_score,
+            estimate_committee_weight_between_slots::<E>(
+                total_active_balance,
+                current_epoch_start_slot,
+                previous_slot,
+            ),
+            estimate_committee_weight_between_slots::<E>(
+                total_active_balance,
+                current_slot,
+                current_epoch.end_slot(slots_per_epoch),
+            ),
+        );
+        let current_target_will_be_justified =
+            will_current_target_be_justified(honest_ffg_support, total_active_balance);
+        let no_conflicting_checkpoint_will_be_justified =
+            will_no_conflicting_checkpoint_be_justified(honest_ffg_support, total_active_balance);
+        let is_ffg_safe = |block_slot: Slot| {
+            let block_epoch = block_slot.epoch(slots_per_epoch);
+            if block_epoch == current_epoch {
+                voting_source.epoch + 1 >= current_epoch && current_target_will_be_justified
+            } else if block_epoch + 1 == current_epoch {
+                voting_source.epoch + 2 >= current_epoch
+                    && no_conflicting_checkpoint_will_be_justified
+            } else {
+                false
+            }
+        };
+
+        // Revert to the finalized block if the confirmed block is stale, was re-orged out or is no
+        // longer FFG safe.
+        let mut confirmed_root = fcs.confirmed_root;
+        let is_reverted = self
+            .proto_array
+            .get_block(&confirmed_root)
+            .is_none_or(|block| {
+                block.slot.epoch(slots_per_epoch) + 1 < current_epoch || !is_ffg_safe(block.slot)
+            });
+        if is_reverted
+            || !self.is_descendant(confirmed_root, fcs.previous_slot_head)
+            || !self.is_descendant(confirmed_root, head_root)
+        {
+            confirmed_root = finalized_root;
+        }
+        if !self.is_descendant(confirmed_root, head_root) {
+            return confirmed_root;
+        }
+
+        // At the start of each epoch, restart from the observed justified checkpoint if it is
+        // ahead of the confirmed block.
+        if is_epoch_start
+            && self.is_descendant(confirmed_root, observed_justified.root)
+            && self.is_descendant(observed_justified.root, head_root)
+        {
+            confirmed_root = observed_justified.root;
+        }
+
+        // Advance along the canonical chain, confirming blocks from previous slots in order.
+        let nodes = &self.proto_array.core_proto_array().nodes;
+        let mut candidates = self
+            .proto_array
+            .iter_nodes(&head_root)
+            .take_while(|node| node.root != confirmed_root)
+            .filter(|node| node.slot < current_slot)
+            .map(|node| {
+                let parent_slot = node
+                    .parent
+                    .and_then(|index| nodes.get(index))
+                    .map_or(node.slot.saturating_sub(1_u64), |parent| parent.slot);
+                (node.root, node.slot, parent_slot)
+            })
+            .collect::<Vec<_>>();
+        candidates.reverse();
+
+        // Only blocks from the previous and current epochs can be confirmed. Votes for a block
+        // are never from before its slot, so the support from the committees of these epochs is
+        // the support from the committees of the slots following each block's parent.
+        let supports = self.proto_array.get_block_supports_between_slots(
+            current_epoch
+                .saturating_sub(1_u64)
+                .start_slot(slots_per_epoch),
+            previous_slot,
+            equivocating_indices,
+        );
+        let proposer_score = (total_active_balance / slots_per_epoch)
+            .saturating_mul(spec.proposer_score_boost.unwrap_or(0))
+            / 100;
+
+        for (block_root, block_slot, parent_slot) in candidates {
+            if !is_ffg_safe(block_slot) {
+                break;
+            }
+
+            let support = supports.get(&block_root).copied().unwrap_or(0);
+            let maximum_support = estimate_committee_weight_between_slots::<E>(
+                total_active_balance,
+                parent_slot + 1,
+                previous_slot,
+            );
+            let safety_threshold = compute_safety_threshold(
+                maximum_support,
+                proposer_score,
+                compute_adversarial_weight(maximum_support),
+            );
+            if support <= safety_threshold {
+                break;
+            }
+            confirmed_root = block_root;
+        }
+
+        confirmed_root
+    }
+
+    /// Returns the execution block hash committed to by `block_root`, for use as `safeBlockHash`.
+    ///
+    /// Gloas blocks have an irrelevant execution status, so use the bid's block hash if the
+    /// payload was revealed, or the parent block hash the bid built on otherwise.
+    fn confirmed_execution_block_hash(&self, block_root: &Hash256) -> Option<ExecutionBlockHash> {
+        let block = self.get_block(block_root)?;
+        block
+            .execution_status
+            .block_hash()
+            .or(if block.payload_revealed {
+                block.bid_block_hash
+            } else {
+                block.bid_parent_block_hash
+            })
+    }
+
     /// See `ProtoArrayForkChoice::process_execution_payload_validation` for documentation.
     pub fn on_valid_execution_payload(
         &mut self,
@@ -1515,6 +1785,32 @@ where
         Ok(())
     }
 
+    /// Gloas ePBS: Returns the PTC's verdict on the payload of `block_root`, or `None` if the
+    /// block is unknown.
+    ///
+    /// The verdict is only final once payload attestations for the block's slot are no longer
+    /// accepted, i.e. after the block's slot has ended.
+    pub fn get_payload_attestation_outcome(
+        &self,
+        block_root: &Hash256,
+        spec: &ChainSpec,
+    ) -> Option<PayloadAttestationOutcome> {
+        let proto_array = self.proto_array.core_proto_array();
+        let node = proto_array
+            .nodes
+            .get(*proto_array.indices.get(block_root)?)?;
+
+        // Spec: PAYLOAD_TIMELY_THRESHOLD = PTC_SIZE // 2, see `Self::on_payload_attestation`.
+        let quorum_threshold = spec.ptc_size / 2;
+
+        Some(PayloadAttestationOutcome {
+            payload_present: node.ptc_weight > quorum_threshold,
+            blob_data_available: node.ptc_blob_data_available_weight > quorum_threshold,
+            payload_present_votes: node.ptc_weight,
+            blob_data_available_votes: node.ptc_blob_data_available_weight,
+        })
+    }
+
     /// Gloas ePBS: Process an execution payload envelope reveal.
     ///
     /// When a builder reveals their execution payload, mark the corresponding block's payload
@@ -1923,9 +2219,11 @@ where
                 head_hash: None,
                 justified_hash: None,
                 finalized_hash: None,
+                confirmed_hash: None,
                 // Will be updated in the following call to `Self::get_head`.
                 head_root: Hash256::zero(),
             },
+            fast_confirmation: None,
             _phantom: PhantomData,
         };
 
@@ -2203,7 +2501,9 @@ mod tests {
                     head_hash: None,
                     justified_hash: None,
                     finalized_hash: None,
+                    confirmed_hash: None,
                 },
+                fast_confirmation: None,
                 _phantom: PhantomData,
             }
         }
@@ -2657,6 +2957,45 @@ mod tests {
             );
         }
 
+        #[test]
+        fn payload_attestation_outcome_reflects_quorums() {
+            let mut fc = new_fc();
+            let block_root = root(1);
+            insert_block(&mut fc, 1, block_root);
+
+            let spec = ChainSpec::minimal();
+            let quorum_threshold = spec.ptc_size / 2;
+
+            // A quorum votes the payload present, but too few vote the blob data available.
+            let indices: Vec<u64> = (0..=quorum_threshold).collect();
+            let att = make_payload_attestation(1, block_root, true, false);
+            let indexed = make_indexed_payload_attestation(1, block_root, true, false, indices);
+            fc.on_payload_attestation(&att, &indexed, Slot::new(1), &spec)
+                .unwrap();
+
+            let att = make_payload_attestation(1, block_root, false, true);
+            let indexed = make_indexed_payload_attestation(
+                1,
+                block_root,
+                false,
+                true,
+                vec![quorum_threshold + 1],
+            );
+            fc.on_payload_attestation(&att, &indexed, Slot::new(1), &spec)
+                .unwrap();
+
+            assert_eq!(
+                fc.get_payload_attestation_outcome(&block_root, &spec),
+                Some(PayloadAttestationOutcome {
+                    payload_present: true,
+                    blob_data_available: false,
+                    payload_present_votes: quorum_threshold + 1,
+                    blob_data_available_votes: 1,
+                })
+            );
+            assert_eq!(fc.get_payload_attestation_outcome(&root(99), &spec), None);
+        }
+
         #[test]
         fn payload_attestation_at_threshold_does_not_reveal() {
             let mut fc = new_fc();
@@ -3330,7 +3669,9 @@ mod tests {
                     head_hash: None,
                     justified_hash: None,
                     finalized_hash: None,
+                    confirmed_hash: None,
                 },
+                fast_confirmation: None,
                 _phantom: PhantomData,
             };
 
@@ -1,12 +1,14 @@
+pub mod fast_confirmation;
 mod fork_choice;
 mod fork_choice_store;
 mod metrics;
 
 pub use crate::fork_choice::{
     AttestationFromBlock, Error, ForkChoice, ForkChoiceView, ForkchoiceUpdateParameters,
-    InvalidAttestation, InvalidBlock, PayloadVerificationStatus, PersistedForkChoice,
-    QueuedAttestation, ResetPayloadStatuses,
+    InvalidAttestation, InvalidBlock, PayloadAttestationOutcome, PayloadVerificationStatus,
+    PersistedForkChoice, QueuedAttestation, ResetPayloadStatuses,
 };
+pub use fast_confirmation::FastConfirmationStore;
 pub use fork_choice_store::ForkChoiceStore;
 pub use proto_array::{
     Block as ProtoBlock, ExecutionStatus, InvalidationOperation, ProposerHeadError,
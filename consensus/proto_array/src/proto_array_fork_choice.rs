@@ -1135,6 +1135,70 @@ impl ProtoArrayForkChoice {
             .is_descendant(ancestor_root, descendant_root)
     }
 
+    /// Returns the attestation support of every block in the DAG from the committees of the slots
+    /// in `[start_slot, end_slot]`.
+    ///
+    /// The support of a block is the sum of the justified balances of the committee members of
+    /// those slots whose latest message is for that block or one of its descendants. A validator's
+    /// latest message is always from the slot of its committee, so committee membership is
+    /// determined by the slot of the latest message. Unlike `ProtoNode::weight`, proposer boost is
+    /// not included and the result is available both before and after Gloas. Votes from
+    /// `equivocating_indices` are ignored, as are slashed validators since they have no justified
+    /// balance.
+    ///
+    /// Latest messages are only applied by `Self::find_head`, so this should be called after it.
+    pub fn get_block_supports_between_slots(
+        &self,
+        start_slot: Slot,
+        end_slot: Slot,
+        equivocating_indices: &BTreeSet<u64>,
+    ) -> HashMap<Hash256, u64> {
+        let nodes = &self.proto_array.nodes;
+        let mut supports = vec![0_u64; nodes.len()];
+
+        for (val_index, vote) in self.votes.0.iter().enumerate() {
+            if vote.current_root.is_zero()
+                || vote.current_slot < start_slot
+                || vote.current_slot > end_slot
+                || equivocating_indices.contains(&(val_index as u64))
+            {
+                continue;
+            }
+            let balance = self
+                .balances
+                .effective_balances
+                .get(val_index)
+                .copied()
+                .unwrap_or(0);
+            if let Some(support) = self
+                .proto_array
+                .indices
+                .get(&vote.current_root)
+                .and_then(|&index| supports.get_mut(index))
+            {
+                *support = support.saturating_add(balance);
+            }
+        }
+
+        // Parents always have a lower index than their children, so a single reverse pass
+        // accumulates every block's support into all of its ancestors.
+        for index in (0..nodes.len()).rev() {
+            let support = supports[index];
+            if let Some(parent_support) = nodes[index]
+                .parent
+                .and_then(|parent| supports.get_mut(parent))
+            {
+                *parent_support = parent_support.saturating_add(support);
+            }
+        }
+
+        nodes
+            .iter()
+            .zip(supports)
+            .map(|(node, support)| (node.root, support))
+            .collect()
+    }
+
     /// See `ProtoArray` documentation.
     pub fn is_finalized_checkpoint_or_descendant<E: EthSpec>(
         &self,
@@ -3820,6 +3884,62 @@ mod test_gloas_fork_choice {
         assert_eq!(head, root(2));
     }
 
+    // ───────────────────── get_block_supports_between_slots ─────────────────────
+
+    #[test]
+    fn block_supports_accumulate_into_ancestors() {
+        let (mut fc, spec) = new_gloas_fc();
+        for (slot, block, parent) in [(1, 1, 0), (2, 2, 1), (2, 3, 1)] {
+            insert_gloas_block(
+                &mut fc,
+                slot,
+                root(block),
+                root(parent),
+                Some(exec_hash(block)),
+                Some(exec_hash(parent)),
+                false,
+            );
+        }
+
+        fc.process_attestation(0, root(2), Epoch::new(0), Slot::new(2), false)
+            .unwrap();
+        fc.process_attestation(1, root(3), Epoch::new(0), Slot::new(2), false)
+            .unwrap();
+        fc.process_attestation(2, root(1), Epoch::new(0), Slot::new(1), false)
+            .unwrap();
+        // Validator 3 is an equivocator and must not count.
+        fc.process_attestation(3, root(2), Epoch::new(0), Slot::new(2), false)
+            .unwrap();
+        let equivocating_indices = BTreeSet::from([3]);
+
+        fc.find_head::<MinimalEthSpec>(
+            genesis_checkpoint(),
+            genesis_checkpoint(),
+            &balances(4),
+            Hash256::zero(),
+            &equivocating_indices,
+            Slot::new(3),
+            &spec,
+        )
+        .unwrap();
+
+        let supports =
+            fc.get_block_supports_between_slots(Slot::new(0), Slot::new(2), &equivocating_indices);
+        assert_eq!(supports[&root(2)], BALANCE);
+        assert_eq!(supports[&root(3)], BALANCE);
+        assert_eq!(supports[&root(1)], 3 * BALANCE);
+        assert_eq!(supports[&root(0)], 3 * BALANCE);
+
+        // Only the committees of the given slots count.
+        let supports =
+            fc.get_block_supports_between_slots(Slot::new(2), Slot::new(2), &equivocating_indices);
+        assert_eq!(supports[&root(1)], 2 * BALANCE);
+        let supports =
+            fc.get_block_supports_between_slots(Slot::new(1), Slot::new(1), &equivocating_indices);
+        assert_eq!(supports[&root(1)], BALANCE);
+        assert_eq!(supports[&root(2)], 0);
+    }
+
     // ───────────────────── payload_present in votes ─────────────────────
 
     #[test]
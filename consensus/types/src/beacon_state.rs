@@ -23,6 +23,7 @@ use educe::Educe;
 use ethereum_hashing::hash_fixed;
 use int_to_bytes::{int_to_bytes4, int_to_bytes8};
 use metastruct::{NumFields, metastruct};
+use milhouse::mem::MemorySize;
 pub use pubkey_cache::PubkeyCache;
 use safe_arith::{ArithError, SafeArith};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -3206,6 +3207,81 @@ impl From<milhouse::Error> for Error {
     }
 }
 
+/// Memory usage of a state's tree-backed lists and vectors, for estimating the memory used by sets
+/// of states which share subtrees.
+///
+/// The caches and sync committees are not counted, as they are shared by most states.
+impl<E: EthSpec> MemorySize for BeaconState<E> {
+    fn self_pointer(&self) -> usize {
+        self as *const Self as usize
+    }
+
+    fn subtrees(&self) -> Vec<&dyn MemorySize> {
+        let mut subtrees: Vec<&dyn MemorySize> = vec![];
+        match self {
+            Self::Base(self_inner) => {
+                map_beacon_state_base_tree_list_fields_immutable!(self_inner, |_, self_field| {
+                    subtrees.push(self_field);
+                });
+            }
+            Self::Altair(self_inner) => {
+                map_beacon_state_altair_tree_list_fields_immutable!(self_inner, |_, self_field| {
+                    subtrees.push(self_field);
+                });
+            }
+            Self::Bellatrix(self_inner) => {
+                map_beacon_state_bellatrix_tree_list_fields_immutable!(
+                    self_inner,
+                    |_, self_field| {
+                        subtrees.push(self_field);
+                    }
+                );
+            }
+            Self::Capella(self_inner) => {
+                map_beacon_state_capella_tree_list_fields_immutable!(
+                    self_inner,
+                    |_, self_field| {
+                        subtrees.push(self_field);
+                    }
+                );
+            }
+            Self::Deneb(self_inner) => {
+                map_beacon_state_deneb_tree_list_fields_immutable!(self_inner, |_, self_field| {
+                    subtrees.push(self_field);
+                });
+            }
+            Self::Electra(self_inner) => {
+                map_beacon_state_electra_tree_list_fields_immutable!(
+                    self_inner,
+                    |_, self_field| {
+                        subtrees.push(self_field);
+                    }
+                );
+            }
+            Self::Fulu(self_inner) => {
+                map_beacon_state_fulu_tree_list_fields_immutable!(self_inner, |_, self_field| {
+                    subtrees.push(self_field);
+                });
+            }
+            Self::Gloas(self_inner) => {
+                map_beacon_state_gloas_tree_list_fields_immutable!(self_inner, |_, self_field| {
+                    subtrees.push(self_field);
+                });
+            }
+            Self::Heze(self_inner) => {
+                map_beacon_state_heze_tree_list_fields_immutable!(self_inner, |_, self_field| {
+                    subtrees.push(self_field);
+                });
+            }
+        }
+        subtrees
+    }
+
+    fn intrinsic_size(&self) -> usize {
+        mem::size_of::<Self>()
+    }
+}
+
 impl<E: EthSpec> CompareFields for BeaconState<E> {
     fn compare_fields(&self, other: &Self) -> Vec<compare_fields::Comparison> {
         match (self, other) {
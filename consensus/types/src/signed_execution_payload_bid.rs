@@ -1,10 +1,11 @@
 use crate::execution_payload_bid::{ExecutionPayloadBidGloas, ExecutionPayloadBidHeze};
 use crate::test_utils::TestRandom;
-use crate::{EthSpec, ForkName};
+use crate::{ContextDeserialize, EthSpec, ForkName, ForkVersionDecode};
 use bls::Signature;
 use context_deserialize::context_deserialize;
 use educe::Educe;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use ssz::Decode;
 use ssz_derive::{Decode, Encode};
 use superstruct::superstruct;
 use test_random_derive::TestRandom;
@@ -76,6 +77,51 @@ impl<E: EthSpec> SignedExecutionPayloadBidHeze<E> {
     }
 }
 
+impl<E: EthSpec> ForkVersionDecode for SignedExecutionPayloadBid<E> {
+    /// SSZ decode with explicit fork variant.
+    fn from_ssz_bytes_by_fork(bytes: &[u8], fork_name: ForkName) -> Result<Self, ssz::DecodeError> {
+        if fork_name.heze_enabled() {
+            Ok(Self::Heze(SignedExecutionPayloadBidHeze::from_ssz_bytes(
+                bytes,
+            )?))
+        } else if fork_name.gloas_enabled() {
+            Ok(Self::Gloas(SignedExecutionPayloadBidGloas::from_ssz_bytes(
+                bytes,
+            )?))
+        } else {
+            Err(ssz::DecodeError::BytesInvalid(format!(
+                "unsupported fork for SignedExecutionPayloadBid: {fork_name}",
+            )))
+        }
+    }
+}
+
+impl<'de, E: EthSpec> ContextDeserialize<'de, ForkName> for SignedExecutionPayloadBid<E> {
+    fn context_deserialize<D>(deserializer: D, context: ForkName) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let convert_err = |e| {
+            serde::de::Error::custom(format!(
+                "SignedExecutionPayloadBid failed to deserialize: {e:?}"
+            ))
+        };
+        if context.heze_enabled() {
+            Ok(Self::Heze(
+                Deserialize::deserialize(deserializer).map_err(convert_err)?,
+            ))
+        } else if context.gloas_enabled() {
+            Ok(Self::Gloas(
+                Deserialize::deserialize(deserializer).map_err(convert_err)?,
+            ))
+        } else {
+            Err(serde::de::Error::custom(format!(
+                "SignedExecutionPayloadBid failed to deserialize: unsupported fork '{context}'"
+            )))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +209,28 @@ mod tests {
         signed.message.value = 999;
         assert_eq!(signed, signed.clone());
     }
+
+    #[test]
+    fn decode_by_fork_selects_variant() {
+        let mut gloas = SignedExecutionPayloadBidGloas::<E>::empty();
+        gloas.message.value = 5;
+        let decoded = SignedExecutionPayloadBid::<E>::from_ssz_bytes_by_fork(
+            &gloas.as_ssz_bytes(),
+            ForkName::Gloas,
+        )
+        .unwrap();
+        assert_eq!(decoded, SignedExecutionPayloadBid::Gloas(gloas));
+
+        let heze = SignedExecutionPayloadBidHeze::<E>::empty();
+        let decoded = SignedExecutionPayloadBid::<E>::from_ssz_bytes_by_fork(
+            &heze.as_ssz_bytes(),
+            ForkName::Heze,
+        )
+        .unwrap();
+        assert_eq!(decoded, SignedExecutionPayloadBid::Heze(heze));
+
+        assert!(
+            SignedExecutionPayloadBid::<E>::from_ssz_bytes_by_fork(&[], ForkName::Fulu).is_err()
+        );
+    }
 }
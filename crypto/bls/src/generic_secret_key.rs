@@ -25,6 +25,12 @@ pub trait TSecretKey<SignaturePoint, PublicKeyPoint>: Sized {
 
     /// Deserialize `self` from compressed bytes.
     fn deserialize(bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Splits `self` into `num_shares` shares, with indices `1..=num_shares`, any `threshold` of
+    /// which can produce signatures that combine into a signature by `self`.
+    ///
+    /// `threshold` has already been checked to be between 1 and `num_shares`.
+    fn split_into_shares(&self, threshold: usize, num_shares: usize) -> Result<Vec<Self>, Error>;
 }
 
 #[derive(Clone)]
@@ -76,6 +82,34 @@ where
         self.point.serialize()
     }
 
+    /// Splits `self` into `num_shares` shares, any `threshold` of which can produce signatures
+    /// that combine into a signature by `self` with `GenericSignature::combine_shares`.
+    ///
+    /// The share at position `i` of the result has the index `i + 1`.
+    ///
+    /// ## Note
+    ///
+    /// The shares are sensitive cryptographic material, just like `self`.
+    pub fn split_into_shares(
+        &self,
+        threshold: usize,
+        num_shares: usize,
+    ) -> Result<Vec<Self>, Error> {
+        if threshold == 0 || threshold > num_shares {
+            return Err(Error::InvalidThresholdShares);
+        }
+        Ok(self
+            .point
+            .split_into_shares(threshold, num_shares)?
+            .into_iter()
+            .map(|point| Self {
+                point,
+                _phantom_signature: PhantomData,
+                _phantom_public_key: PhantomData,
+            })
+            .collect())
+    }
+
     /// Deserialize `self` from compressed bytes.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         if bytes.len() != SECRET_KEY_BYTES_LEN {
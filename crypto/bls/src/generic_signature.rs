@@ -6,6 +6,7 @@ use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
 use serde_utils::hex::encode as hex_encode;
 use ssz::{Decode, Encode};
+use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
@@ -55,6 +56,12 @@ pub trait TSignature<GenericPublicKey>: Sized + Clone {
 
     /// Returns `true` if `self` is a signature across `msg` by `pubkey`.
     fn verify(&self, pubkey: &GenericPublicKey, msg: Hash256) -> bool;
+
+    /// Interpolates the signature of a threshold key from the signatures of its shares, each
+    /// paired with the index of the share that produced it.
+    ///
+    /// The indices have already been checked to be non-zero and distinct.
+    fn combine_shares(shares: &[(u64, &Self)]) -> Result<Self, Error>;
 }
 
 /// A BLS signature that is generic across:
@@ -154,6 +161,32 @@ where
         })
     }
 
+    /// Combines the signatures of at least `threshold` shares of a key split with
+    /// `GenericSecretKey::split_into_shares` into a signature by that key.
+    ///
+    /// Each signature is paired with the index of the share that produced it. The result is only
+    /// meaningful if every share signed the same message, so the shares should be verified first.
+    pub fn combine_shares(shares: &[(u64, &Self)]) -> Result<Self, Error> {
+        let mut indices = HashSet::with_capacity(shares.len());
+        let points = shares
+            .iter()
+            .map(|(index, signature)| {
+                if *index == 0 || !indices.insert(*index) {
+                    return Err(Error::InvalidThresholdShares);
+                }
+                let point = signature.point().ok_or(Error::InvalidThresholdShares)?;
+                Ok((*index, point))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if points.is_empty() {
+            return Err(Error::InvalidThresholdShares);
+        }
+
+        let point = Sig::combine_shares(&points)?;
+        let is_infinity = point.serialize() == INFINITY_SIGNATURE;
+        Ok(Self::from_point(point, is_infinity))
+    }
+
     /// Deserialize `self` from uncompressed bytes.
     pub fn deserialize_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
         // The "none signature" is a beacon chain concept. As we never directly deal with
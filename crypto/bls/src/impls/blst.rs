@@ -9,7 +9,7 @@ use crate::{
     generic_signature::{SIGNATURE_BYTES_LEN, SIGNATURE_UNCOMPRESSED_BYTES_LEN, TSignature},
 };
 pub use blst::min_pk as blst_core;
-use blst::{BLST_ERROR, blst_scalar};
+use blst::{BLST_ERROR, blst_fr, blst_p2, blst_p2_affine, blst_scalar};
 use rand::Rng;
 const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
 const RAND_BITS: usize = 64;
@@ -205,6 +205,83 @@ impl TSignature<blst_core::PublicKey> for blst_core::Signature {
         // Check Signature inside function for subgroup
         self.verify(true, msg.as_slice(), DST, &[], pubkey, false) == BLST_ERROR::BLST_SUCCESS
     }
+
+    fn combine_shares(shares: &[(u64, &Self)]) -> Result<Self, Error> {
+        let indices = shares.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+        let mut combined = blst_p2::default();
+        for (i, (_, share)) in shares.iter().enumerate() {
+            let coefficient = scalar_from_fr(&lagrange_coefficient_at_zero(&indices, i));
+            let mut affine = blst_p2_affine::default();
+            let mut point = blst_p2::default();
+            let mut term = blst_p2::default();
+            let mut sum = blst_p2::default();
+            // SAFETY: all pointers refer to initialized values on the stack, and the compressed
+            // bytes are exactly `SIGNATURE_BYTES_LEN` long as `blst_p2_uncompress` expects.
+            unsafe {
+                let err = blst::blst_p2_uncompress(&mut affine, share.to_bytes().as_ptr());
+                if err != BLST_ERROR::BLST_SUCCESS {
+                    return Err(err.into());
+                }
+                blst::blst_p2_from_affine(&mut point, &affine);
+                blst::blst_p2_mult(&mut term, &point, coefficient.b.as_ptr(), 255);
+                blst::blst_p2_add_or_double(&mut sum, &combined, &term);
+            }
+            combined = sum;
+        }
+
+        let mut bytes = [0; SIGNATURE_BYTES_LEN];
+        // SAFETY: `bytes` is exactly the length written by `blst_p2_compress`.
+        unsafe { blst::blst_p2_compress(bytes.as_mut_ptr(), &combined) };
+        Self::from_bytes(&bytes).map_err(Into::into)
+    }
+}
+
+/// Returns the field element `x`.
+fn fr_from_u64(x: u64) -> blst_fr {
+    let mut ret = blst_fr::default();
+    let limbs = [x, 0, 0, 0];
+    // SAFETY: `limbs` holds the 4 limbs read by `blst_fr_from_uint64`.
+    unsafe { blst::blst_fr_from_uint64(&mut ret, limbs.as_ptr()) };
+    ret
+}
+
+fn scalar_from_fr(fr: &blst_fr) -> blst_scalar {
+    let mut ret = blst_scalar::default();
+    // SAFETY: both pointers refer to initialized values.
+    unsafe { blst::blst_scalar_from_fr(&mut ret, fr) };
+    ret
+}
+
+/// Returns the Lagrange basis polynomial for `indices[i]`, evaluated at zero.
+///
+/// The indices must be non-zero and distinct.
+fn lagrange_coefficient_at_zero(indices: &[u64], i: usize) -> blst_fr {
+    let x_i = fr_from_u64(indices[i]);
+    let mut numerator = fr_from_u64(1);
+    let mut denominator = fr_from_u64(1);
+    for (j, x_j) in indices.iter().enumerate() {
+        if j == i {
+            continue;
+        }
+        let x_j = fr_from_u64(*x_j);
+        numerator = fr_mul(&numerator, &x_j);
+        let mut difference = blst_fr::default();
+        // SAFETY: all pointers refer to initialized values.
+        unsafe { blst::blst_fr_sub(&mut difference, &x_j, &x_i) };
+        denominator = fr_mul(&denominator, &difference);
+    }
+    let mut inverse = blst_fr::default();
+    // SAFETY: all pointers refer to initialized values, and `denominator` is non-zero because
+    // the indices are distinct.
+    unsafe { blst::blst_fr_inverse(&mut inverse, &denominator) };
+    fr_mul(&numerator, &inverse)
+}
+
+fn fr_mul(a: &blst_fr, b: &blst_fr) -> blst_fr {
+    let mut ret = blst_fr::default();
+    // SAFETY: all pointers refer to initialized values.
+    unsafe { blst::blst_fr_mul(&mut ret, a, b) };
+    ret
 }
 
 /// A wrapper that allows for `PartialEq` and `Clone` impls.
@@ -304,4 +381,40 @@ impl TSecretKey<blst_core::Signature, blst_core::PublicKey> for blst_core::Secre
     fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         Self::from_bytes(bytes).map_err(Into::into)
     }
+
+    fn split_into_shares(&self, threshold: usize, num_shares: usize) -> Result<Vec<Self>, Error> {
+        // The secret is the constant term of a random polynomial of degree `threshold - 1`, and
+        // share `i` is the polynomial evaluated at `i`.
+        let coefficients = std::iter::once(self.to_bytes())
+            .chain((1..threshold).map(|_| Self::random().to_bytes()))
+            .map(|bytes| {
+                let mut scalar = blst_scalar::default();
+                let mut fr = blst_fr::default();
+                // SAFETY: `bytes` is the 32 byte big-endian scalar read by
+                // `blst_scalar_from_bendian`.
+                unsafe {
+                    blst::blst_scalar_from_bendian(&mut scalar, bytes.as_ptr());
+                    blst::blst_fr_from_scalar(&mut fr, &scalar);
+                }
+                fr
+            })
+            .collect::<Vec<_>>();
+
+        (1..=num_shares as u64)
+            .map(|index| {
+                let x = fr_from_u64(index);
+                // Horner's method, from the highest degree coefficient down.
+                let mut y = blst_fr::default();
+                for coefficient in coefficients.iter().rev() {
+                    let product = fr_mul(&y, &x);
+                    // SAFETY: all pointers refer to initialized values.
+                    unsafe { blst::blst_fr_add(&mut y, &product, coefficient) };
+                }
+                let mut bytes = [0; 32];
+                // SAFETY: `bytes` is exactly the length written by `blst_bendian_from_scalar`.
+                unsafe { blst::blst_bendian_from_scalar(bytes.as_mut_ptr(), &scalar_from_fr(&y)) };
+                Self::from_bytes(&bytes).map_err(Into::into)
+            })
+            .collect()
+    }
 }
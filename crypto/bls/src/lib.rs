@@ -63,6 +63,9 @@ pub enum Error {
     InvalidInfinityPublicKey,
     /// The secret key is all zero bytes, which is invalid.
     InvalidZeroSecretKey,
+    /// The threshold or shares of a threshold key or signature were invalid, e.g., a share index
+    /// was zero or repeated.
+    InvalidThresholdShares,
 }
 
 #[cfg(feature = "supranational")]
@@ -523,6 +523,61 @@ macro_rules! test_suite {
                 .push_valid_set(2)
                 .run_checks()
         }
+
+        fn sign_with_shares(shares: &[SecretKey], indices: &[u64], msg: Hash256) -> Signature {
+            let signatures = indices
+                .iter()
+                .map(|index| (*index, shares[*index as usize - 1].sign(msg)))
+                .collect::<Vec<_>>();
+            let signature_refs = signatures
+                .iter()
+                .map(|(index, signature)| (*index, signature))
+                .collect::<Vec<_>>();
+            Signature::combine_shares(&signature_refs).unwrap()
+        }
+
+        #[test]
+        fn threshold_shares_combine_into_signature() {
+            let sk = SecretKey::random();
+            let pk = sk.public_key();
+            let msg = Hash256::from_low_u64_be(42);
+            let shares = sk.split_into_shares(3, 5).unwrap();
+
+            for indices in [[1, 2, 3], [5, 3, 1], [2, 4, 5]] {
+                let signature = sign_with_shares(&shares, &indices, msg);
+                assert!(signature.verify(&pk, msg));
+                assert_eq!(signature, sk.sign(msg));
+            }
+
+            // Any extra shares don't change the result.
+            assert_eq!(
+                sign_with_shares(&shares, &[1, 2, 3, 4, 5], msg),
+                sk.sign(msg)
+            );
+
+            // Fewer than the threshold do not produce a valid signature.
+            assert!(!sign_with_shares(&shares, &[1, 4], msg).verify(&pk, msg));
+        }
+
+        #[test]
+        fn threshold_shares_invalid() {
+            let sk = SecretKey::random();
+            assert!(sk.split_into_shares(0, 3).is_err());
+            assert!(sk.split_into_shares(4, 3).is_err());
+
+            let msg = Hash256::from_low_u64_be(42);
+            let signature = sk.sign(msg);
+            for shares in [
+                vec![],
+                vec![(0, &signature)],
+                vec![(1, &signature), (1, &signature)],
+            ] {
+                assert_eq!(
+                    Signature::combine_shares(&shares),
+                    Err(bls::Error::InvalidThresholdShares)
+                );
+            }
+        }
     };
 }
 
@@ -3,6 +3,7 @@ use clap_utils::FLAG_HEADER;
 use clap_utils::get_color_style;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use store::DatabaseBackend;
 use store::hdiff::HierarchyConfig;
 
 use crate::InspectTarget;
@@ -57,6 +58,25 @@ pub struct DatabaseManager {
     )]
     pub blobs_dir: Option<PathBuf>,
 
+    #[clap(
+        long,
+        value_name = "DIR",
+        help = "Directory of the blob archive. Pruned blobs and data columns are moved to it \
+                instead of being deleted.",
+        display_order = 0
+    )]
+    pub blob_archive_dir: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        value_name = "DATABASE",
+        help = "Database backend of the beacon node database. Defaults to the backend which \
+                created the existing database.",
+        display_order = 0
+    )]
+    pub beacon_node_backend: Option<DatabaseBackend>,
+
     #[clap(subcommand)]
     pub(crate) subcommand: DatabaseManagerSubcommand,
 }
@@ -71,6 +91,10 @@ pub(crate) enum DatabaseManagerSubcommand {
     PruneBlobs(PruneBlobs),
     PruneStates(PruneStates),
     Compact(Compact),
+    ExportEra(ExportEra),
+    ImportEra(ImportEra),
+    ConvertBackend(ConvertBackend),
+    Verify(Verify),
 }
 
 #[derive(Parser, Clone, Deserialize, Serialize, Debug)]
@@ -219,3 +243,110 @@ pub(crate) struct Compact {
     )]
     pub output_dir: Option<PathBuf>,
 }
+
+#[derive(Parser, Clone, Deserialize, Serialize, Debug)]
+#[clap(
+    about = "Export finalized blocks and boundary states from the freezer database as era files.",
+    alias = "export_era"
+)]
+pub(crate) struct ExportEra {
+    #[clap(
+        long,
+        value_name = "DIR",
+        help = "Directory to write the era files to.",
+        display_order = 0
+    )]
+    pub output_dir: PathBuf,
+
+    #[clap(
+        long,
+        value_name = "ERA",
+        default_value_t = 0,
+        help = "The first era to export.",
+        display_order = 0
+    )]
+    pub start_era: u64,
+
+    #[clap(
+        long,
+        value_name = "ERA",
+        help = "The last era to export. Defaults to the last era whose boundary state is in the \
+                freezer database.",
+        display_order = 0
+    )]
+    pub end_era: Option<u64>,
+}
+
+#[derive(Parser, Clone, Deserialize, Serialize, Debug)]
+#[clap(
+    about = "Fill the block history below the oldest stored block from a directory of era files.",
+    alias = "import_era"
+)]
+pub(crate) struct ImportEra {
+    #[clap(
+        long,
+        value_name = "DIR",
+        help = "Directory containing the era files to import.",
+        display_order = 0
+    )]
+    pub era_dir: PathBuf,
+}
+
+#[derive(Parser, Clone, Deserialize, Serialize, Debug)]
+#[clap(
+    about = "Copy the hot, freezer and blobs databases into new databases using another backend.",
+    alias = "convert_backend"
+)]
+pub(crate) struct ConvertBackend {
+    #[clap(
+        long,
+        value_name = "DATABASE",
+        help = "Database backend to convert to.",
+        display_order = 0
+    )]
+    pub to: DatabaseBackend,
+
+    #[clap(
+        long,
+        value_name = "DIR",
+        help = "Directory to write the converted databases to. The hot, freezer and blobs \
+                databases are written to the `chain_db`, `freezer_db` and `blobs_db` \
+                subdirectories.",
+        display_order = 0
+    )]
+    pub output_dir: PathBuf,
+}
+
+#[derive(Parser, Clone, Deserialize, Serialize, Debug)]
+#[clap(
+    about = "Check the consistency of the hot, freezer and blobs databases, and print the \
+             problems found as JSON. Exits with an error if any problems are found."
+)]
+pub(crate) struct Verify {
+    #[clap(
+        long,
+        help = "Delete entries which are no longer referenced, such as blocks from abandoned \
+                forks and the sidecars of deleted blocks. Blocks and their sidecars are kept if \
+                problems are found which may make canonical blocks appear abandoned.",
+        display_order = 0,
+        help_heading = FLAG_HEADER
+    )]
+    pub repair: bool,
+
+    #[clap(
+        long,
+        help = "Skip reconstructing the states stored as snapshots and diffs. This is much \
+                faster, but does not detect corrupt state data.",
+        display_order = 0,
+        help_heading = FLAG_HEADER
+    )]
+    pub skip_state_reconstruction: bool,
+
+    #[clap(
+        long,
+        value_name = "FILE",
+        help = "File to write the report to. Defaults to stdout.",
+        display_order = 0
+    )]
+    pub output: Option<PathBuf>,
+}
@@ -2,8 +2,11 @@ pub mod cli;
 use crate::cli::DatabaseManager;
 use crate::cli::Migrate;
 use crate::cli::PruneStates;
+use crate::cli::{ConvertBackend, ExportEra, ImportEra, Verify};
 use beacon_chain::{
-    builder::Witness, schema_change::migrate_schema, slot_clock::SystemTimeSlotClock,
+    builder::Witness,
+    schema_change::migrate_schema,
+    slot_clock::{SlotClock, SystemTimeSlotClock},
 };
 use beacon_node::{ClientConfig, get_data_dir};
 use clap::ArgMatches;
@@ -14,14 +17,16 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
+use std::time::Duration;
 use store::KeyValueStore;
 use store::{
-    DBColumn, HotColdDB,
+    DBColumn, DatabaseBackend, HotColdDB, KeyValueStoreOp, StoreConfig,
     database::interface::BeaconNodeBackend,
     errors::Error,
     metadata::{CURRENT_SCHEMA_VERSION, SchemaVersion},
+    verify::VerifyConfig,
 };
-use strum::{EnumString, VariantNames};
+use strum::{EnumString, IntoEnumIterator, VariantNames};
 use tracing::{info, warn};
 use types::{BeaconState, EthSpec, Slot};
 
@@ -40,7 +45,15 @@ fn parse_client_config<E: EthSpec>(
         .blobs_db_path
         .clone_from(&database_manager_config.blobs_dir);
     client_config.store.blob_prune_margin_epochs = database_manager_config.blob_prune_margin_epochs;
+    client_config
+        .store
+        .blob_archive_dir
+        .clone_from(&database_manager_config.blob_archive_dir);
     client_config.store.hierarchy_config = database_manager_config.hierarchy_exponents.clone();
+    client_config.store.backend = database_manager_config
+        .beacon_node_backend
+        .or_else(|| DatabaseBackend::detect(&client_config.get_db_path()))
+        .unwrap_or_default();
     client_config
 }
 
@@ -142,13 +155,13 @@ pub(crate) fn inspect_db<E: EthSpec>(
     let mut num_keys = 0;
 
     let sub_db = if inspect_config.freezer {
-        BeaconNodeBackend::<E>::open(&cold_path)
+        BeaconNodeBackend::<E>::open(&client_config.store, &cold_path)
             .map_err(|e| format!("Unable to open freezer DB: {e:?}"))?
     } else if inspect_config.blobs_db {
-        BeaconNodeBackend::<E>::open(&blobs_path)
+        BeaconNodeBackend::<E>::open(&client_config.store, &blobs_path)
             .map_err(|e| format!("Unable to open blobs DB: {e:?}"))?
     } else {
-        BeaconNodeBackend::<E>::open(&hot_path)
+        BeaconNodeBackend::<E>::open(&client_config.store, &hot_path)
             .map_err(|e| format!("Unable to open hot DB: {e:?}"))?
     };
 
@@ -262,11 +275,20 @@ pub(crate) fn compact_db<E: EthSpec>(
     let column = compact_config.column;
 
     let (sub_db, db_name) = if compact_config.freezer {
-        (BeaconNodeBackend::<E>::open(&cold_path)?, "freezer_db")
+        (
+            BeaconNodeBackend::<E>::open(&client_config.store, &cold_path)?,
+            "freezer_db",
+        )
     } else if compact_config.blobs_db {
-        (BeaconNodeBackend::<E>::open(&blobs_path)?, "blobs_db")
+        (
+            BeaconNodeBackend::<E>::open(&client_config.store, &blobs_path)?,
+            "blobs_db",
+        )
     } else {
-        (BeaconNodeBackend::<E>::open(&hot_path)?, "hot_db")
+        (
+            BeaconNodeBackend::<E>::open(&client_config.store, &hot_path)?,
+            "hot_db",
+        )
     };
     info!(
         db = db_name,
@@ -433,6 +455,340 @@ pub(crate) fn prune_states<E: EthSpec>(
     Ok(())
 }
 
+pub(crate) struct ExportEraConfig {
+    output_dir: PathBuf,
+    start_era: u64,
+    end_era: Option<u64>,
+}
+
+fn parse_export_era_config(export_era_config: &ExportEra) -> ExportEraConfig {
+    ExportEraConfig {
+        output_dir: export_era_config.output_dir.clone(),
+        start_era: export_era_config.start_era,
+        end_era: export_era_config.end_era,
+    }
+}
+
+pub(crate) fn export_era<E: EthSpec>(
+    export_config: ExportEraConfig,
+    client_config: ClientConfig,
+    runtime_context: &RuntimeContext<E>,
+) -> Result<(), String> {
+    let spec = &runtime_context.eth2_config.spec;
+    let hot_path = client_config.get_db_path();
+    let cold_path = client_config.get_freezer_db_path();
+    let blobs_path = client_config.get_blobs_db_path();
+
+    let db = HotColdDB::<E, BeaconNodeBackend<E>, BeaconNodeBackend<E>>::open(
+        &hot_path,
+        &cold_path,
+        &blobs_path,
+        |_, _, _| Ok(()),
+        client_config.store,
+        spec.clone(),
+    )
+    .map_err(|e| format!("Unable to open database: {e:?}"))?;
+
+    // The last era whose boundary state has been migrated to the freezer.
+    let split_slot = db.get_split_slot();
+    let last_era = split_slot
+        .as_u64()
+        .checked_sub(1)
+        .map(|slot| slot / E::slots_per_historical_root() as u64)
+        .ok_or("No finalized eras to export")?;
+    let end_era = export_config.end_era.unwrap_or(last_era);
+    if end_era > last_era {
+        return Err(format!(
+            "Era {end_era} is not finalized, the last finalized era is {last_era}"
+        ));
+    }
+
+    fs::create_dir_all(&export_config.output_dir)
+        .map_err(|e| format!("Unable to create output directory: {e:?}"))?;
+    let config_name = spec.config_name.as_deref().unwrap_or("unknown");
+
+    for era in export_config.start_era..=end_era {
+        let era_file = db
+            .export_era(era)
+            .map_err(|e| format!("Unable to export era {era}: {e:?}"))?;
+        let path = export_config.output_dir.join(
+            era_file
+                .file_name(config_name)
+                .map_err(|e| format!("Unable to name era {era}: {e:?}"))?,
+        );
+        let bytes = era_file
+            .to_bytes()
+            .map_err(|e| format!("Unable to encode era {era}: {e:?}"))?;
+        fs::write(&path, bytes).map_err(|e| format!("Unable to write era {era}: {e:?}"))?;
+
+        info!(
+            era,
+            blocks = era_file.blocks.len(),
+            path = %path.display(),
+            "Exported era"
+        );
+    }
+
+    Ok(())
+}
+
+pub(crate) struct ImportEraConfig {
+    era_dir: PathBuf,
+}
+
+fn parse_import_era_config(import_era_config: &ImportEra) -> ImportEraConfig {
+    ImportEraConfig {
+        era_dir: import_era_config.era_dir.clone(),
+    }
+}
+
+pub(crate) fn import_era<E: EthSpec>(
+    import_config: ImportEraConfig,
+    client_config: ClientConfig,
+    mut genesis_state: BeaconState<E>,
+    runtime_context: &RuntimeContext<E>,
+) -> Result<(), String> {
+    let spec = &runtime_context.eth2_config.spec;
+    let hot_path = client_config.get_db_path();
+    let cold_path = client_config.get_freezer_db_path();
+    let blobs_path = client_config.get_blobs_db_path();
+
+    let db = HotColdDB::<E, BeaconNodeBackend<E>, BeaconNodeBackend<E>>::open(
+        &hot_path,
+        &cold_path,
+        &blobs_path,
+        |_, _, _| Ok(()),
+        client_config.store,
+        spec.clone(),
+    )
+    .map_err(|e| format!("Unable to open database: {e:?}"))?;
+
+    // The genesis block is never stored in era files, so derive its root from the genesis state.
+    let mut genesis_block_header = genesis_state.latest_block_header().clone();
+    genesis_block_header.state_root = genesis_state
+        .update_tree_hash_cache()
+        .map_err(|e| format!("Error computing genesis state root: {e:?}"))?;
+    let genesis_block_root = genesis_block_header.canonical_root();
+
+    // Blocks with blobs or data columns within the data availability window are left for backfill
+    // sync, which fetches their sidecars.
+    let slot_clock = SystemTimeSlotClock::new(
+        spec.genesis_slot,
+        Duration::from_secs(genesis_state.genesis_time()),
+        Duration::from_secs(spec.seconds_per_slot),
+    );
+    let data_availability_boundary = slot_clock.now().and_then(|slot| {
+        spec.min_epoch_data_availability_boundary(slot.epoch(E::slots_per_epoch()))
+    });
+
+    info!(
+        oldest_block_slot = %db.get_oldest_block_slot(),
+        era_dir = %import_config.era_dir.display(),
+        "Importing era files"
+    );
+
+    let blocks_imported = db
+        .import_era_dir(
+            &import_config.era_dir,
+            genesis_block_root,
+            data_availability_boundary,
+        )
+        .map_err(|e| format!("Unable to import era files: {e:?}"))?;
+
+    info!(
+        blocks_imported,
+        oldest_block_slot = %db.get_oldest_block_slot(),
+        "Era import complete"
+    );
+    Ok(())
+}
+
+/// Maximum total size of the values copied by a single write batch during backend conversion.
+const CONVERT_BATCH_BYTES: usize = 64 * 1024 * 1024;
+
+pub(crate) struct ConvertBackendConfig {
+    to: DatabaseBackend,
+    output_dir: PathBuf,
+}
+
+fn parse_convert_backend_config(convert_backend_config: &ConvertBackend) -> ConvertBackendConfig {
+    ConvertBackendConfig {
+        to: convert_backend_config.to,
+        output_dir: convert_backend_config.output_dir.clone(),
+    }
+}
+
+pub(crate) fn convert_backend<E: EthSpec>(
+    convert_config: ConvertBackendConfig,
+    client_config: ClientConfig,
+) -> Result<(), String> {
+    let from = client_config.store.backend;
+    if from == convert_config.to {
+        return Err(format!("Database already uses the {from} backend"));
+    }
+    let target_config = StoreConfig {
+        backend: convert_config.to,
+        ..client_config.store.clone()
+    };
+
+    let sub_dbs = [
+        ("hot_db", client_config.get_db_path(), "chain_db"),
+        (
+            "freezer_db",
+            client_config.get_freezer_db_path(),
+            "freezer_db",
+        ),
+        ("blobs_db", client_config.get_blobs_db_path(), "blobs_db"),
+    ];
+    for (db_name, source_path, dir_name) in sub_dbs {
+        let target_path = convert_config.output_dir.join(dir_name);
+        if DatabaseBackend::detect(&target_path).is_some() {
+            return Err(format!(
+                "A database already exists at {}",
+                target_path.display()
+            ));
+        }
+
+        let source = BeaconNodeBackend::<E>::open(&client_config.store, &source_path)
+            .map_err(|e| format!("Unable to open {db_name}: {e:?}"))?;
+        let target = BeaconNodeBackend::<E>::open(&target_config, &target_path)
+            .map_err(|e| format!("Unable to create converted {db_name}: {e:?}"))?;
+
+        info!(
+            db = db_name,
+            %from,
+            to = %convert_config.to,
+            path = %target_path.display(),
+            "Converting database"
+        );
+        copy_database(&source, &target)
+            .map_err(|e| format!("Unable to convert {db_name}: {e:?}"))?;
+    }
+
+    info!(
+        output_dir = %convert_config.output_dir.display(),
+        "Conversion complete. Replace the existing databases with the converted ones and start \
+         the beacon node with --beacon-node-backend {}",
+        convert_config.to
+    );
+    Ok(())
+}
+
+/// Copy every key in every column of `source` to `target`.
+fn copy_database<E: EthSpec>(
+    source: &BeaconNodeBackend<E>,
+    target: &BeaconNodeBackend<E>,
+) -> Result<(), Error> {
+    for column in DBColumn::iter() {
+        let mut num_keys = 0;
+        let mut total_bytes = 0;
+        let mut batch = vec![];
+        let mut batch_bytes = 0;
+
+        for res in source.iter_column_from::<Vec<u8>>(column, &[]) {
+            let (key, value) = res?;
+            num_keys += 1;
+            total_bytes += value.len();
+            batch_bytes += key.len() + value.len();
+            batch.push(KeyValueStoreOp::PutKeyValue(column, key, value));
+
+            if batch_bytes >= CONVERT_BATCH_BYTES {
+                target.do_atomically(std::mem::take(&mut batch))?;
+                batch_bytes = 0;
+            }
+        }
+        target.do_atomically(batch)?;
+
+        if num_keys > 0 {
+            info!(
+                column = column.as_str(),
+                num_keys, total_bytes, "Copied column"
+            );
+        }
+    }
+    target.sync()
+}
+
+pub(crate) struct VerifyDbConfig {
+    repair: bool,
+    reconstruct_states: bool,
+    output: Option<PathBuf>,
+}
+
+fn parse_verify_config(verify_config: &Verify) -> VerifyDbConfig {
+    VerifyDbConfig {
+        repair: verify_config.repair,
+        reconstruct_states: !verify_config.skip_state_reconstruction,
+        output: verify_config.output.clone(),
+    }
+}
+
+pub(crate) fn verify_db<E: EthSpec>(
+    verify_config: VerifyDbConfig,
+    client_config: ClientConfig,
+    runtime_context: &RuntimeContext<E>,
+) -> Result<(), String> {
+    let spec = &runtime_context.eth2_config.spec;
+    let hot_path = client_config.get_db_path();
+    let cold_path = client_config.get_freezer_db_path();
+    let blobs_path = client_config.get_blobs_db_path();
+
+    let db = HotColdDB::<E, BeaconNodeBackend<E>, BeaconNodeBackend<E>>::open(
+        &hot_path,
+        &cold_path,
+        &blobs_path,
+        |_, _, _| Ok(()),
+        client_config.store,
+        spec.clone(),
+    )
+    .map_err(|e| format!("Unable to open database: {e:?}"))?;
+
+    let report = db
+        .verify(&VerifyConfig {
+            reconstruct_states: verify_config.reconstruct_states,
+        })
+        .map_err(|e| format!("Unable to verify database: {e:?}"))?;
+
+    let json = serde_json::to_string_pretty(&report)
+        .map_err(|e| format!("Unable to serialize report: {e:?}"))?;
+    match &verify_config.output {
+        Some(path) => fs::write(path, json)
+            .map_err(|e| format!("Unable to write report to {}: {e:?}", path.display()))?,
+        None => println!("{json}"),
+    }
+
+    info!(
+        blocks_checked = report.blocks_checked,
+        states_reconstructed = report.states_reconstructed,
+        problems = report.problems.len(),
+        dangling = report.dangling.len(),
+        "Verification complete"
+    );
+
+    if verify_config.repair && !report.dangling.is_empty() {
+        let deletable = report.deletable_dangling();
+        if deletable.len() < report.dangling.len() {
+            warn!(
+                kept = report.dangling.len() - deletable.len(),
+                "Not deleting abandoned blocks, block data or hot state data, as problems were \
+                 found which may make canonical entries appear dangling"
+            );
+        }
+        db.delete_dangling(&deletable)
+            .map_err(|e| format!("Unable to delete dangling entries: {e:?}"))?;
+        info!(deleted = deletable.len(), "Deleted dangling entries");
+    }
+
+    if report.is_ok() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Database verification found {} problems",
+            report.problems.len()
+        ))
+    }
+}
+
 /// Run the database manager, returning an error string if the operation did not succeed.
 pub fn run<E: EthSpec>(
     cli_args: &ArgMatches,
@@ -490,5 +846,133 @@ pub fn run<E: EthSpec>(
             let compact_config = parse_compact_config(compact_config);
             compact_db::<E>(compact_config, client_config).map_err(format_err)
         }
+        cli::DatabaseManagerSubcommand::ExportEra(export_era_config) => {
+            let export_config = parse_export_era_config(export_era_config);
+            export_era(export_config, client_config, &context)
+        }
+        cli::DatabaseManagerSubcommand::ImportEra(import_era_config) => {
+            let import_config = parse_import_era_config(import_era_config);
+            let genesis_state = get_genesis_state()?;
+            import_era(import_config, client_config, genesis_state, &context)
+        }
+        cli::DatabaseManagerSubcommand::ConvertBackend(convert_backend_config) => {
+            let convert_config = parse_convert_backend_config(convert_backend_config);
+            convert_backend::<E>(convert_config, client_config)
+        }
+        cli::DatabaseManagerSubcommand::Verify(verify_config) => {
+            let verify_config = parse_verify_config(verify_config);
+            verify_db(verify_config, client_config, &context)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "lmdb", feature = "mdbx"))]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use types::{Hash256, MinimalEthSpec};
+
+    type E = MinimalEthSpec;
+
+    fn client_config(data_dir: &std::path::Path, backend: DatabaseBackend) -> ClientConfig {
+        let mut client_config = ClientConfig::default();
+        client_config.set_data_dir(data_dir.to_path_buf());
+        client_config.store.backend = backend;
+        client_config
+    }
+
+    fn db_paths(client_config: &ClientConfig) -> [PathBuf; 3] {
+        [
+            client_config.get_db_path(),
+            client_config.get_freezer_db_path(),
+            client_config.get_blobs_db_path(),
+        ]
+    }
+
+    /// Every key and value in every column of `db`, in key order.
+    fn contents(db: &BeaconNodeBackend<E>) -> Vec<(DBColumn, Vec<u8>, Vec<u8>)> {
+        DBColumn::iter()
+            .flat_map(|column| {
+                db.iter_column_from::<Vec<u8>>(column, &[])
+                    .map(move |res| res.map(|(key, value)| (column, key, value)))
+            })
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// Converting redb -> LMDB -> MDBX preserves every entry of every database.
+    #[test]
+    fn convert_backend_round_trip() {
+        let source_dir = tempdir().unwrap();
+        let source_config = client_config(source_dir.path(), DatabaseBackend::Redb);
+
+        // Enough blocks to span several LMDB/MDBX read chunks, plus entries in other columns.
+        for (i, path) in db_paths(&source_config).iter().enumerate() {
+            let db = BeaconNodeBackend::<E>::open(&source_config.store, path).unwrap();
+            let mut ops: Vec<_> = (0..2500u64)
+                .map(|n| {
+                    KeyValueStoreOp::PutKeyValue(
+                        DBColumn::BeaconBlock,
+                        Hash256::from_low_u64_be(n).as_slice().to_vec(),
+                        vec![i as u8; (n % 64) as usize],
+                    )
+                })
+                .collect();
+            ops.push(KeyValueStoreOp::PutKeyValue(
+                DBColumn::BeaconMeta,
+                Hash256::from_low_u64_be(i as u64).as_slice().to_vec(),
+                b"meta".to_vec(),
+            ));
+            db.do_atomically(ops).unwrap();
+        }
+
+        let lmdb_dir = tempdir().unwrap();
+        convert_backend::<E>(
+            ConvertBackendConfig {
+                to: DatabaseBackend::Lmdb,
+                output_dir: lmdb_dir.path().to_path_buf(),
+            },
+            source_config.clone(),
+        )
+        .unwrap();
+
+        let lmdb_config = client_config(lmdb_dir.path(), DatabaseBackend::Lmdb);
+        let mdbx_dir = tempdir().unwrap();
+        convert_backend::<E>(
+            ConvertBackendConfig {
+                to: DatabaseBackend::Mdbx,
+                output_dir: mdbx_dir.path().to_path_buf(),
+            },
+            lmdb_config.clone(),
+        )
+        .unwrap();
+
+        let mdbx_config = client_config(mdbx_dir.path(), DatabaseBackend::Mdbx);
+        for ((source_path, lmdb_path), mdbx_path) in db_paths(&source_config)
+            .iter()
+            .zip(db_paths(&lmdb_config).iter())
+            .zip(db_paths(&mdbx_config).iter())
+        {
+            let source = BeaconNodeBackend::<E>::open(&source_config.store, source_path).unwrap();
+            let lmdb = BeaconNodeBackend::<E>::open(&lmdb_config.store, lmdb_path).unwrap();
+            let mdbx = BeaconNodeBackend::<E>::open(&mdbx_config.store, mdbx_path).unwrap();
+
+            let expected = contents(&source);
+            assert_eq!(expected.len(), 2501);
+            assert_eq!(contents(&lmdb), expected, "{}", lmdb_path.display());
+            assert_eq!(contents(&mdbx), expected, "{}", mdbx_path.display());
+        }
+
+        // Converting into an existing database is refused.
+        assert!(
+            convert_backend::<E>(
+                ConvertBackendConfig {
+                    to: DatabaseBackend::Mdbx,
+                    output_dir: mdbx_dir.path().to_path_buf(),
+                },
+                lmdb_config,
+            )
+            .is_err()
+        );
     }
 }
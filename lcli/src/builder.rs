@@ -0,0 +1,660 @@
+//! # builder
+//!
+//! Run a long-lived in-protocol (ePBS) builder against a local beacon node.
+//!
+//! The builder follows the beacon node's event stream. When a new head is imported and its payload
+//! has been revealed, it builds a payload on top of it using the execution engine, signs a bid for
+//! the next slot and submits it via `POST /eth/v1/builder/bids`. When a block committing to one of
+//! its bids is imported, it computes the post-payload state root, signs the execution payload
+//! envelope and reveals it via `POST /eth/v1/beacon/execution_payload_envelope`.
+//!
+//! Bids use the fee recipient and gas limit from the proposer preferences of the target slot, as
+//! observed on the `proposer_preferences` event topic. With `--sign-preferences` the builder signs
+//! and submits missing preferences itself using the proposer's interop keypair, which is useful
+//! when the builder starts after the validator client has already broadcast them.
+//!
+//! The builder keypair is deterministic: keypair at index `validator_count + builder_index`.
+//! Run the beacon node with `--genesis-builders N` to pre-register builders at genesis.
+//!
+//! The execution engine must be the one used by the beacon node (e.g. `lcli mock-el`), so that
+//! the parent payloads are known to it. Payloads containing blobs are bid on, but their data
+//! columns are not published.
+//!
+//! The `builder-sim` subcommand of `testing/simulator` runs the builder against a local network
+//! and checks the outcome of each mode.
+//!
+//! ## Misbehaviour modes
+//!
+//! - `honest`: reveal the payload as soon as the committing block is imported.
+//! - `withhold`: never reveal the payload.
+//! - `late-reveal`: reveal the payload `--reveal-delay` after the committing block is imported
+//!   (defaults to three quarters of a slot, past the payload attestation deadline).
+//! - `equivocate`: submit two different bids for every slot.
+//! - `underfunded`: bid more than the builder's balance.
+//!
+//! ## Example
+//!
+//! ```text
+//! lcli builder \
+//!     --spec minimal \
+//!     --beacon-url <http://localhost:5052> \
+//!     --execution-endpoint <http://localhost:8551> \
+//!     --execution-jwt /tmp/mockel.jwt \
+//!     --builder-index 0 \
+//!     --validator-count 64 \
+//!     --mode late-reveal
+//! ```
+use beacon_chain::ForkchoiceUpdateParameters;
+use clap::ArgMatches;
+use clap_utils::{parse_optional, parse_required};
+use environment::Environment;
+use eth2::{
+    BeaconNodeHttpClient, SensitiveUrl, Timeouts,
+    types::{BlockId, EventKind, EventTopic, StateId},
+};
+use eth2_network_config::Eth2NetworkConfig;
+use execution_layer::{
+    BlockProposalContentsType, BuilderParams, ChainHealth, ExecutionLayer, PayloadAttributes,
+    PayloadParameters,
+};
+use futures::StreamExt;
+use state_processing::{
+    VerifySignatures,
+    envelope_processing::process_execution_payload_envelope,
+    per_block_processing::{
+        compute_timestamp_at_slot,
+        gloas::{get_expected_withdrawals_gloas, is_parent_block_full},
+    },
+    state_advance::complete_state_advance,
+};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+use types::{
+    Address, BeaconState, ChainSpec, Domain, EthSpec, ExecutionPayloadBidGloas,
+    ExecutionPayloadEnvelope, ExecutionPayloadGloas, ExecutionRequests, ForkName, Hash256, Keypair,
+    ProposerPreferences, Signature, SignedExecutionPayloadBid, SignedExecutionPayloadBidGloas,
+    SignedExecutionPayloadEnvelope, SignedProposerPreferences, SignedRoot, Slot,
+    payload::BlockProductionVersion, test_utils::generate_deterministic_keypairs,
+};
+
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+/// Bids and preferences older than this many slots are pruned.
+const RETAINED_SLOTS: u64 = 64;
+
+/// How the builder behaves once it has built a payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Honest,
+    Withhold,
+    LateReveal,
+    Equivocate,
+    Underfunded,
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "honest" => Ok(Mode::Honest),
+            "withhold" => Ok(Mode::Withhold),
+            "late-reveal" => Ok(Mode::LateReveal),
+            "equivocate" => Ok(Mode::Equivocate),
+            "underfunded" => Ok(Mode::Underfunded),
+            other => Err(format!("Unknown builder mode: {other}")),
+        }
+    }
+}
+
+/// A payload which was bid on and may need to be revealed.
+struct PendingReveal<E: EthSpec> {
+    payload: ExecutionPayloadGloas<E>,
+    execution_requests: ExecutionRequests<E>,
+}
+
+struct Builder<E: EthSpec> {
+    client: BeaconNodeHttpClient,
+    execution_layer: ExecutionLayer<E>,
+    keypair: Keypair,
+    builder_index: u64,
+    /// Interop keypairs of the validators, present when `--sign-preferences` is set.
+    validator_keypairs: Option<Vec<Keypair>>,
+    bid_value: u64,
+    mode: Mode,
+    reveal_delay: Duration,
+    spec: Arc<ChainSpec>,
+    genesis_validators_root: Hash256,
+    /// The current head as `(slot, block_root, state_root)`.
+    head: Option<(Slot, Hash256, Hash256)>,
+    preferences: HashMap<Slot, ProposerPreferences>,
+    bid_slots: HashSet<Slot>,
+    pending_reveals: HashMap<Slot, PendingReveal<E>>,
+}
+
+pub(crate) fn run<E: EthSpec>(
+    env: Environment<E>,
+    network_config: Eth2NetworkConfig,
+    matches: &ArgMatches,
+) -> Result<(), String> {
+    let spec = Arc::new(network_config.chain_spec::<E>()?);
+    let executor = env.core_context().executor;
+
+    let beacon_url: SensitiveUrl = parse_required(matches, "beacon-url")?;
+    let execution_endpoint: SensitiveUrl = parse_required(matches, "execution-endpoint")?;
+    let execution_jwt: PathBuf = parse_required(matches, "execution-jwt")?;
+    let builder_index: u64 = parse_required(matches, "builder-index")?;
+    let validator_count: usize = parse_required(matches, "validator-count")?;
+    let bid_value: u64 = parse_required(matches, "bid-value")?;
+    let mode: Mode = parse_required(matches, "mode")?;
+    let reveal_delay_ms: Option<u64> = parse_optional(matches, "reveal-delay")?;
+    let sign_preferences = matches.get_flag("sign-preferences");
+
+    // By default late reveals land after the payload attestation deadline.
+    let reveal_delay = match (mode, reveal_delay_ms) {
+        (_, Some(ms)) => Duration::from_millis(ms),
+        (Mode::LateReveal, None) => Duration::from_secs(spec.seconds_per_slot) * 3 / 4,
+        (_, None) => Duration::ZERO,
+    };
+
+    let keypair_index = validator_count + builder_index as usize;
+    let all_keypairs = generate_deterministic_keypairs(keypair_index + 1);
+    let keypair = all_keypairs[keypair_index].clone();
+    let validator_keypairs = sign_preferences.then(|| all_keypairs[..validator_count].to_vec());
+    info!(
+        builder_index,
+        keypair_index,
+        pubkey = %keypair.pk,
+        ?mode,
+        "Using builder keypair"
+    );
+
+    let execution_layer = ExecutionLayer::from_config(
+        execution_layer::Config {
+            execution_endpoint: Some(execution_endpoint),
+            secret_file: Some(execution_jwt),
+            ..Default::default()
+        },
+        executor.clone(),
+    )
+    .map_err(|e| format!("Failed to create execution layer: {e:?}"))?;
+
+    let client = BeaconNodeHttpClient::new(beacon_url, Timeouts::set_all(HTTP_TIMEOUT));
+
+    executor
+        .handle()
+        .ok_or("shutdown in progress")?
+        .block_on(async move {
+            let genesis_validators_root = client
+                .get_beacon_genesis()
+                .await
+                .map_err(|e| format!("Failed to get genesis: {e:?}"))?
+                .data
+                .genesis_validators_root;
+
+            let mut builder = Builder {
+                client,
+                execution_layer,
+                keypair,
+                builder_index,
+                validator_keypairs,
+                bid_value,
+                mode,
+                reveal_delay,
+                spec,
+                genesis_validators_root,
+                head: None,
+                preferences: HashMap::new(),
+                bid_slots: HashSet::new(),
+                pending_reveals: HashMap::new(),
+            };
+            builder.run().await
+        })
+}
+
+impl<E: EthSpec> Builder<E> {
+    async fn run(&mut self) -> Result<(), String> {
+        let mut events = self
+            .client
+            .get_events::<E>(&[
+                EventTopic::Head,
+                EventTopic::Block,
+                EventTopic::ExecutionPayload,
+                EventTopic::ProposerPreferences,
+            ])
+            .await
+            .map_err(|e| format!("Failed to subscribe to events: {e:?}"))?;
+
+        info!("Builder running");
+
+        while let Some(event) = events.next().await {
+            let event = event.map_err(|e| format!("Event stream failed: {e:?}"))?;
+            match event {
+                EventKind::ProposerPreferences(preferences) => {
+                    let slot = Slot::new(preferences.message.proposal_slot);
+                    debug!(%slot, "Received proposer preferences");
+                    self.preferences.insert(slot, preferences.message);
+                }
+                EventKind::Head(head) => {
+                    self.head = Some((head.slot, head.block, head.state));
+                    self.prune(head.slot);
+                    self.try_bid(head.slot + 1).await;
+                }
+                EventKind::ExecutionPayload(payload) => {
+                    // The head's payload was revealed, so its children can now be built.
+                    if let Some((head_slot, head_root, _)) = self.head
+                        && head_root == payload.beacon_block_root
+                    {
+                        self.try_bid(head_slot + 1).await;
+                    }
+                }
+                EventKind::Block(block) => {
+                    if let Err(e) = self.maybe_reveal(block.slot, block.block).await {
+                        warn!(slot = %block.slot, error = %e, "Failed to reveal payload");
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Err("Event stream ended".to_string())
+    }
+
+    fn prune(&mut self, current_slot: Slot) {
+        let cutoff = current_slot.saturating_sub(RETAINED_SLOTS);
+        self.preferences.retain(|slot, _| *slot >= cutoff);
+        self.bid_slots.retain(|slot| *slot >= cutoff);
+        self.pending_reveals.retain(|slot, _| *slot >= cutoff);
+    }
+
+    async fn try_bid(&mut self, slot: Slot) {
+        if self.bid_slots.contains(&slot) {
+            return;
+        }
+        match self.build_and_submit_bid(slot).await {
+            Ok(true) => {
+                self.bid_slots.insert(slot);
+            }
+            Ok(false) => {}
+            Err(e) => {
+                // Don't retry, the next opportunity is the next slot.
+                self.bid_slots.insert(slot);
+                warn!(%slot, error = %e, "Failed to bid");
+            }
+        }
+    }
+
+    /// Build a payload for `slot` on top of the current head and submit bid(s) for it.
+    ///
+    /// Returns `Ok(false)` if bidding should be retried later, i.e. the head's payload has not
+    /// been revealed yet.
+    async fn build_and_submit_bid(&mut self, slot: Slot) -> Result<bool, String> {
+        let Some((head_slot, head_root, head_state_root)) = self.head else {
+            return Ok(false);
+        };
+        if head_slot + 1 != slot {
+            return Ok(true);
+        }
+        let fork_name = self.spec.fork_name_at_slot::<E>(slot);
+        if fork_name != ForkName::Gloas {
+            debug!(%slot, %fork_name, "Not bidding outside of Gloas");
+            return Ok(true);
+        }
+
+        let Some(mut state) = self.parent_state(head_root, head_state_root).await? else {
+            debug!(%slot, "Waiting for the head payload to be revealed");
+            return Ok(false);
+        };
+
+        let preferences = match self.preferences.get(&slot) {
+            Some(preferences) => preferences.clone(),
+            None => match self.sign_preferences(slot).await? {
+                Some(preferences) => preferences,
+                None => {
+                    info!(%slot, "No proposer preferences, not bidding");
+                    return Ok(true);
+                }
+            },
+        };
+
+        complete_state_advance(&mut state, Some(head_state_root), slot, &self.spec)
+            .map_err(|e| format!("Failed to advance state: {e:?}"))?;
+
+        let parent_block_hash = *state
+            .latest_block_hash()
+            .map_err(|e| format!("Pre-Gloas state: {e:?}"))?;
+        let parent_gas_limit = *state
+            .latest_execution_payload_bid()
+            .map_err(|e| format!("Pre-Gloas state: {e:?}"))?
+            .gas_limit();
+        let prev_randao = *state
+            .get_randao_mix(state.current_epoch())
+            .map_err(|e| format!("Failed to get randao mix: {e:?}"))?;
+        let timestamp = compute_timestamp_at_slot(&state, slot, &self.spec)
+            .map_err(|e| format!("Failed to compute timestamp: {e:?}"))?;
+        // Mirror the beacon node: an empty parent leaves the expected withdrawals unchanged.
+        let withdrawals = if is_parent_block_full::<E>(&state)
+            .map_err(|e| format!("Failed to check parent block: {e:?}"))?
+        {
+            get_expected_withdrawals_gloas(&state, &self.spec)
+                .map_err(|e| format!("Failed to compute withdrawals: {e:?}"))?
+        } else {
+            state
+                .payload_expected_withdrawals()
+                .map_err(|e| format!("Pre-Gloas state: {e:?}"))?
+                .iter()
+                .copied()
+                .collect()
+        };
+
+        let payload_attributes = PayloadAttributes::new(
+            timestamp,
+            prev_randao,
+            preferences.fee_recipient,
+            Some(withdrawals),
+            Some(head_root),
+        );
+        let forkchoice_update_params = ForkchoiceUpdateParameters {
+            head_root,
+            head_hash: Some(parent_block_hash),
+            justified_hash: None,
+            finalized_hash: None,
+            confirmed_hash: None,
+        };
+        let block_contents = self
+            .execution_layer
+            .get_payload(
+                PayloadParameters {
+                    parent_hash: parent_block_hash,
+                    parent_gas_limit,
+                    proposer_gas_limit: Some(preferences.gas_limit),
+                    payload_attributes: &payload_attributes,
+                    forkchoice_update_params: &forkchoice_update_params,
+                    current_fork: fork_name,
+                },
+                BuilderParams {
+                    pubkey: self.keypair.pk.compress(),
+                    slot,
+                    chain_health: ChainHealth::Healthy,
+                },
+                &self.spec,
+                None,
+                BlockProductionVersion::FullV2,
+            )
+            .await
+            .map_err(|e| format!("Failed to get payload: {e:?}"))?;
+        let BlockProposalContentsType::Full(block_contents) = block_contents else {
+            return Err("Execution layer returned a blinded payload".to_string());
+        };
+        let (payload, kzg_commitments, _, execution_requests, _) = block_contents.deconstruct();
+        let payload = payload
+            .execution_payload_gloas()
+            .map_err(|_| "Execution layer returned a non-Gloas payload".to_string())?
+            .clone();
+        let blob_kzg_commitments = kzg_commitments.unwrap_or_default();
+        if !blob_kzg_commitments.is_empty() {
+            warn!(%slot, blobs = blob_kzg_commitments.len(), "Blob data columns are not published");
+        }
+
+        let value = if self.mode == Mode::Underfunded {
+            let balance = state
+                .builders()
+                .map_err(|e| format!("Pre-Gloas state: {e:?}"))?
+                .get(self.builder_index as usize)
+                .ok_or_else(|| format!("Unknown builder {}", self.builder_index))?
+                .balance;
+            balance.saturating_add(1)
+        } else {
+            self.bid_value
+        };
+
+        let bid = ExecutionPayloadBidGloas::<E> {
+            slot,
+            builder_index: self.builder_index,
+            value,
+            parent_block_hash,
+            parent_block_root: head_root,
+            prev_randao,
+            block_hash: payload.block_hash,
+            fee_recipient: preferences.fee_recipient,
+            gas_limit: payload.gas_limit,
+            execution_payment: value,
+            blob_kzg_commitments,
+        };
+        self.submit_bid(bid.clone()).await;
+        if self.mode == Mode::Equivocate {
+            // Same payload, different bid.
+            let equivocating_bid = ExecutionPayloadBidGloas {
+                value: value.saturating_add(1),
+                execution_payment: value.saturating_add(1),
+                ..bid
+            };
+            self.submit_bid(equivocating_bid).await;
+        }
+
+        self.pending_reveals.insert(
+            slot,
+            PendingReveal {
+                payload,
+                execution_requests: execution_requests.unwrap_or_default(),
+            },
+        );
+        Ok(true)
+    }
+
+    /// Returns the state which a child of the head block is built on, or `None` if the head's
+    /// payload has not been revealed yet.
+    async fn parent_state(
+        &self,
+        head_root: Hash256,
+        head_state_root: Hash256,
+    ) -> Result<Option<BeaconState<E>>, String> {
+        let Some(envelope) = self
+            .client
+            .get_beacon_execution_payload_envelope::<E>(BlockId::Root(head_root))
+            .await
+            .map_err(|e| format!("Failed to get head envelope: {e:?}"))?
+        else {
+            return Ok(None);
+        };
+        let envelope = envelope.data;
+
+        let mut state = self
+            .client
+            .get_debug_beacon_states::<E>(StateId::Root(head_state_root))
+            .await
+            .map_err(|e| format!("Failed to get head state: {e:?}"))?
+            .ok_or("Head state not available")?
+            .into_data();
+
+        // The beacon node may serve the pre-payload state for the head block root.
+        if *state
+            .latest_block_hash()
+            .map_err(|e| format!("Pre-Gloas state: {e:?}"))?
+            != envelope.message.payload.block_hash
+        {
+            process_execution_payload_envelope(
+                &mut state,
+                Some(head_state_root),
+                &envelope,
+                VerifySignatures::False,
+                &self.spec,
+            )
+            .map_err(|e| format!("Failed to apply head envelope: {e:?}"))?;
+        }
+
+        Ok(Some(state))
+    }
+
+    /// Sign and submit proposer preferences for `slot` with the proposer's interop keypair.
+    ///
+    /// Returns `None` unless `--sign-preferences` is set.
+    async fn sign_preferences(
+        &mut self,
+        slot: Slot,
+    ) -> Result<Option<ProposerPreferences>, String> {
+        let Some(validator_keypairs) = &self.validator_keypairs else {
+            return Ok(None);
+        };
+        let epoch = slot.epoch(E::slots_per_epoch());
+        let duties = self
+            .client
+            .get_validator_duties_proposer(epoch)
+            .await
+            .map_err(|e| format!("Failed to get proposer duties for epoch {epoch}: {e:?}"))?;
+        let proposer_index = duties
+            .data
+            .iter()
+            .find(|duty| duty.slot == slot)
+            .ok_or_else(|| format!("No proposer duty found for slot {slot}"))?
+            .validator_index;
+        let proposer_keypair = validator_keypairs
+            .get(proposer_index as usize)
+            .ok_or_else(|| format!("Proposer {proposer_index} is not an interop validator"))?;
+
+        let message = ProposerPreferences {
+            proposal_slot: slot.as_u64(),
+            validator_index: proposer_index,
+            fee_recipient: Address::ZERO,
+            gas_limit: execution_layer::test_utils::DEFAULT_GAS_LIMIT,
+        };
+        let domain = self.spec.get_domain(
+            epoch,
+            Domain::ProposerPreferences,
+            &self.spec.fork_at_epoch(epoch),
+            self.genesis_validators_root,
+        );
+        let signed_preferences = SignedProposerPreferences {
+            signature: proposer_keypair.sk.sign(message.signing_root(domain)),
+            message: message.clone(),
+        };
+        self.client
+            .post_beacon_pool_proposer_preferences(&signed_preferences)
+            .await
+            .map_err(|e| format!("Failed to submit proposer preferences: {e:?}"))?;
+
+        info!(%slot, proposer_index, "Submitted proposer preferences");
+        self.preferences.insert(slot, message.clone());
+        Ok(Some(message))
+    }
+
+    async fn submit_bid(&self, bid: ExecutionPayloadBidGloas<E>) {
+        let epoch = bid.slot.epoch(E::slots_per_epoch());
+        let domain = self.spec.get_domain(
+            epoch,
+            Domain::BeaconBuilder,
+            &self.spec.fork_at_epoch(epoch),
+            self.genesis_validators_root,
+        );
+        let signature = self.keypair.sk.sign(bid.signing_root(domain));
+        let (slot, value, block_hash) = (bid.slot, bid.value, bid.block_hash);
+        let signed_bid = SignedExecutionPayloadBid::Gloas(SignedExecutionPayloadBidGloas {
+            message: bid,
+            signature,
+        });
+
+        match self.client.post_builder_bids::<E>(&signed_bid).await {
+            Ok(()) => info!(%slot, value, %block_hash, "Submitted bid"),
+            Err(e) => warn!(%slot, value, error = ?e, "Bid rejected"),
+        }
+    }
+
+    /// Reveal the payload for the block `block_root` if it committed to one of our bids.
+    async fn maybe_reveal(&mut self, slot: Slot, block_root: Hash256) -> Result<(), String> {
+        let Some(pending) = self.pending_reveals.get(&slot) else {
+            return Ok(());
+        };
+
+        let block = self
+            .client
+            .get_beacon_blocks::<E>(BlockId::Root(block_root))
+            .await
+            .map_err(|e| format!("Failed to get block: {e:?}"))?
+            .ok_or("Block not available")?
+            .into_data();
+        let bid = block
+            .message()
+            .body()
+            .signed_execution_payload_bid()
+            .map_err(|_| "Block has no bid".to_string())?;
+        let bid = bid.message();
+        if *bid.builder_index() != self.builder_index
+            || *bid.block_hash() != pending.payload.block_hash
+        {
+            info!(%slot, builder_index = bid.builder_index(), "Bid not selected");
+            self.pending_reveals.remove(&slot);
+            return Ok(());
+        }
+
+        let Some(pending) = self.pending_reveals.remove(&slot) else {
+            return Ok(());
+        };
+        info!(%slot, %block_root, "Bid selected");
+        if self.mode == Mode::Withhold {
+            info!(%slot, "Withholding payload");
+            return Ok(());
+        }
+
+        let block_state_root = block.state_root();
+        let mut state = self
+            .client
+            .get_debug_beacon_states::<E>(StateId::Root(block_state_root))
+            .await
+            .map_err(|e| format!("Failed to get block state: {e:?}"))?
+            .ok_or("Block state not available")?
+            .into_data();
+
+        let mut envelope = SignedExecutionPayloadEnvelope {
+            message: ExecutionPayloadEnvelope {
+                payload: pending.payload,
+                execution_requests: pending.execution_requests,
+                builder_index: self.builder_index,
+                beacon_block_root: block_root,
+                slot,
+                state_root: Hash256::zero(),
+            },
+            signature: Signature::empty(),
+        };
+        process_execution_payload_envelope(
+            &mut state,
+            Some(block_state_root),
+            &envelope,
+            VerifySignatures::False,
+            &self.spec,
+        )
+        .map_err(|e| format!("Failed to process envelope: {e:?}"))?;
+        envelope.message.state_root = state
+            .canonical_root()
+            .map_err(|e| format!("Failed to compute state root: {e:?}"))?;
+
+        let epoch = slot.epoch(E::slots_per_epoch());
+        let domain = self.spec.get_domain(
+            epoch,
+            Domain::BeaconBuilder,
+            &self.spec.fork_at_epoch(epoch),
+            self.genesis_validators_root,
+        );
+        envelope.signature = self.keypair.sk.sign(envelope.message.signing_root(domain));
+
+        let client = self.client.clone();
+        let reveal_delay = self.reveal_delay;
+        tokio::spawn(async move {
+            if !reveal_delay.is_zero() {
+                info!(%slot, delay_ms = reveal_delay.as_millis(), "Delaying payload reveal");
+                tokio::time::sleep(reveal_delay).await;
+            }
+            match client
+                .post_beacon_execution_payload_envelope::<E>(&envelope)
+                .await
+            {
+                Ok(()) => info!(%slot, "Revealed payload"),
+                Err(e) => warn!(%slot, error = ?e, "Payload reveal rejected"),
+            }
+        });
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 mod block_root;
+mod builder;
 mod check_deposit_data;
 mod generate_bootnode_enr;
 mod http_sync;
@@ -707,6 +708,101 @@ fn main() {
                         .display_order(0)
                 )
         )
+        .subcommand(
+            Command::new("builder")
+                .about(
+                    "Run a long-lived ePBS builder against a local beacon node. Follows the \
+                    beacon node's events, bids on every slot with payloads built by the execution \
+                    engine and reveals the payloads of winning bids. For devnet and simulator \
+                    testing only. Uses deterministic builder keypairs compatible with \
+                    --genesis-builders."
+                )
+                .arg(
+                    Arg::new("beacon-url")
+                        .long("beacon-url")
+                        .value_name("URL")
+                        .action(ArgAction::Set)
+                        .required(true)
+                        .help("URL of the beacon node HTTP API.")
+                        .display_order(0)
+                )
+                .arg(
+                    Arg::new("execution-endpoint")
+                        .long("execution-endpoint")
+                        .value_name("URL")
+                        .action(ArgAction::Set)
+                        .required(true)
+                        .help("URL of the engine API of the execution engine used by the beacon node.")
+                        .display_order(0)
+                )
+                .arg(
+                    Arg::new("execution-jwt")
+                        .long("execution-jwt")
+                        .value_name("PATH")
+                        .action(ArgAction::Set)
+                        .required(true)
+                        .help("Path to the JWT secret of the execution engine.")
+                        .display_order(0)
+                )
+                .arg(
+                    Arg::new("builder-index")
+                        .long("builder-index")
+                        .value_name("N")
+                        .action(ArgAction::Set)
+                        .default_value("0")
+                        .help("Builder index (0-based) within the genesis builders list.")
+                        .display_order(0)
+                )
+                .arg(
+                    Arg::new("validator-count")
+                        .long("validator-count")
+                        .value_name("N")
+                        .action(ArgAction::Set)
+                        .required(true)
+                        .help("Number of genesis validators (determines builder keypair offset).")
+                        .display_order(0)
+                )
+                .arg(
+                    Arg::new("bid-value")
+                        .long("bid-value")
+                        .value_name("GWEI")
+                        .action(ArgAction::Set)
+                        .default_value("1000000000")
+                        .help("Bid value in Gwei.")
+                        .display_order(0)
+                )
+                .arg(
+                    Arg::new("mode")
+                        .long("mode")
+                        .value_name("MODE")
+                        .action(ArgAction::Set)
+                        .value_parser(["honest", "withhold", "late-reveal", "equivocate", "underfunded"])
+                        .default_value("honest")
+                        .help("Builder behaviour. 'withhold' never reveals payloads, 'late-reveal' \
+                            reveals them after --reveal-delay, 'equivocate' submits two bids per \
+                            slot and 'underfunded' bids more than the builder's balance.")
+                        .display_order(0)
+                )
+                .arg(
+                    Arg::new("reveal-delay")
+                        .long("reveal-delay")
+                        .value_name("MILLIS")
+                        .action(ArgAction::Set)
+                        .help("Delay between importing a block that selected our bid and revealing \
+                            the payload. Defaults to 3/4 of a slot in 'late-reveal' mode and to \
+                            zero otherwise.")
+                        .display_order(0)
+                )
+                .arg(
+                    Arg::new("sign-preferences")
+                        .long("sign-preferences")
+                        .action(ArgAction::SetTrue)
+                        .help_heading(FLAG_HEADER)
+                        .help("Sign and submit missing proposer preferences using the proposer's \
+                            interop keypair. Requires the beacon node to use interop validators.")
+                        .display_order(0)
+                )
+        )
         .subcommand(
             Command::new("inject-slashing")
                 .about(
@@ -878,6 +974,11 @@ fn run<E: EthSpec>(env_builder: EnvironmentBuilder<E>, matches: &ArgMatches) ->
             submit_builder_bid::run::<E>(env, network_config, matches)
                 .map_err(|e| format!("Failed to run submit-builder-bid command: {e}"))
         }
+        Some(("builder", matches)) => {
+            let network_config = get_network_config()?;
+            builder::run::<E>(env, network_config, matches)
+                .map_err(|e| format!("Failed to run builder command: {e}"))
+        }
         Some(("inject-slashing", matches)) => {
             let network_config = get_network_config()?;
             inject_slashing::run::<E>(env, network_config, matches)
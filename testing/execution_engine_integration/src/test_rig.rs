@@ -286,6 +286,7 @@ impl<Engine: GenericExecutionEngine> TestRig<Engine> {
             head_hash: Some(parent_hash),
             justified_hash: Some(justified_block_hash),
             finalized_hash: Some(finalized_block_hash),
+            confirmed_hash: None,
         };
         let proposer_index = 0;
 
@@ -0,0 +1,375 @@
+use crate::local_network::LocalNetworkParams;
+use crate::{LocalNetwork, checks};
+use clap::ArgMatches;
+
+use crate::retry::with_retry;
+use environment::tracing_common;
+use eth2_network_config::{Eth2NetworkConfig, GenesisStateSource};
+use futures::prelude::*;
+use logging::build_workspace_filter;
+use node_test_rig::{
+    ValidatorFiles,
+    environment::{EnvironmentBuilder, LoggerConfig},
+    testing_validator_config,
+};
+use rayon::prelude::*;
+use std::cmp::max;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::TempDir;
+use tokio::time::sleep;
+use tracing::Level;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+use types::{Config, Epoch, EthSpec, MinimalEthSpec};
+
+const END_EPOCH: u64 = 10;
+const GENESIS_DELAY: u64 = 38;
+const GLOAS_FORK_EPOCH: u64 = 0;
+/// Genesis builders only become active once the chain has finalized, so bids are only checked
+/// from this epoch onwards.
+const BUILDER_CHECK_START_EPOCH: u64 = 5;
+const BUILDER_INDEX: u64 = 0;
+
+const SUGGESTED_FEE_RECIPIENT: [u8; 20] =
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+/// An `lcli builder` process which is killed when dropped.
+struct BuilderProcess(Child);
+
+impl Drop for BuilderProcess {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+#[allow(clippy::large_stack_frames)]
+pub(crate) fn run_builder_sim(matches: &ArgMatches) -> Result<(), String> {
+    let (_name, subcommand_matches) = matches.subcommand().expect("subcommand");
+    let node_count = subcommand_matches
+        .get_one::<String>("nodes")
+        .expect("missing nodes default")
+        .parse::<usize>()
+        .expect("missing nodes default");
+    let validators_per_node = subcommand_matches
+        .get_one::<String>("validators-per-node")
+        .expect("missing validators-per-node default")
+        .parse::<usize>()
+        .expect("missing validators-per-node default");
+    let speed_up_factor = subcommand_matches
+        .get_one::<String>("speed-up-factor")
+        .expect("missing speed-up-factor default")
+        .parse::<u64>()
+        .expect("missing speed-up-factor default");
+    let log_level = subcommand_matches
+        .get_one::<String>("debug-level")
+        .expect("missing debug-level");
+    let lcli = subcommand_matches
+        .get_one::<String>("lcli")
+        .expect("missing lcli default")
+        .clone();
+    let mode = subcommand_matches
+        .get_one::<String>("mode")
+        .expect("missing mode default")
+        .clone();
+
+    let continue_after_checks = subcommand_matches.get_flag("continue-after-checks");
+    let log_dir = subcommand_matches
+        .get_one::<String>("log-dir")
+        .map(PathBuf::from);
+    let disable_stdout_logging = subcommand_matches.get_flag("disable-stdout-logging");
+
+    println!("Builder Simulator:");
+    println!(" nodes: {node_count}");
+    println!(" validators-per-node: {validators_per_node}");
+    println!(" speed-up-factor: {speed_up_factor}");
+    println!(" lcli: {lcli}");
+    println!(" mode: {mode}");
+    println!(" continue-after-checks: {continue_after_checks}");
+    println!(" log-dir: {log_dir:?}");
+    println!(" disable-stdout-logging: {disable_stdout_logging}");
+
+    // Generate the directories and keystores required for the validator clients.
+    let validator_files = (0..node_count)
+        .into_par_iter()
+        .map(|i| {
+            println!(
+                "Generating keystores for validator {} of {}",
+                i + 1,
+                node_count
+            );
+
+            let indices =
+                (i * validators_per_node..(i + 1) * validators_per_node).collect::<Vec<_>>();
+            ValidatorFiles::with_keystores(&indices).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let (
+        env_builder,
+        logger_config,
+        stdout_logging_layer,
+        file_logging_layer,
+        _sse_logging_layer_opt,
+        libp2p_discv5_layer,
+    ) = tracing_common::construct_logger(
+        LoggerConfig {
+            path: log_dir,
+            debug_level: tracing_common::parse_level(&log_level.clone()),
+            logfile_debug_level: tracing_common::parse_level(&log_level.clone()),
+            log_format: None,
+            logfile_format: None,
+            log_color: true,
+            logfile_color: false,
+            disable_log_timestamp: false,
+            max_log_size: 200,
+            max_log_number: 5,
+            compression: false,
+            is_restricted: true,
+            sse_logging: false,
+            extra_info: false,
+        },
+        matches,
+        EnvironmentBuilder::minimal(),
+    );
+
+    let workspace_filter = build_workspace_filter()?;
+    let mut logging_layers = vec![];
+    if !disable_stdout_logging {
+        logging_layers.push(
+            stdout_logging_layer
+                .with_filter(logger_config.debug_level)
+                .with_filter(workspace_filter.clone())
+                .boxed(),
+        );
+    }
+    if let Some(file_logging_layer) = file_logging_layer {
+        logging_layers.push(
+            file_logging_layer
+                .with_filter(logger_config.logfile_debug_level)
+                .with_filter(workspace_filter)
+                .boxed(),
+        );
+    }
+    if let Some(libp2p_discv5_layer) = libp2p_discv5_layer {
+        logging_layers.push(
+            libp2p_discv5_layer
+                .with_filter(
+                    EnvFilter::builder()
+                        .with_default_directive(Level::DEBUG.into())
+                        .from_env_lossy(),
+                )
+                .boxed(),
+        );
+    }
+
+    if let Err(e) = tracing_subscriber::registry()
+        .with(logging_layers)
+        .try_init()
+    {
+        eprintln!("Failed to initialize dependency logging: {e}");
+    }
+
+    let mut env = env_builder.multi_threaded_tokio_runtime()?.build()?;
+
+    let mut spec = (*env.eth2_config.spec).clone();
+
+    let total_validator_count = validators_per_node * node_count;
+    let genesis_delay = GENESIS_DELAY;
+
+    spec.seconds_per_slot /= speed_up_factor;
+    spec.seconds_per_slot = max(1, spec.seconds_per_slot);
+    spec.genesis_delay = genesis_delay;
+    spec.min_genesis_time = 0;
+    spec.min_genesis_active_validator_count = total_validator_count as u64;
+    // Genesis builders can only be registered in a Gloas genesis state.
+    spec.altair_fork_epoch = Some(Epoch::new(GLOAS_FORK_EPOCH));
+    spec.bellatrix_fork_epoch = Some(Epoch::new(GLOAS_FORK_EPOCH));
+    spec.capella_fork_epoch = Some(Epoch::new(GLOAS_FORK_EPOCH));
+    spec.deneb_fork_epoch = Some(Epoch::new(GLOAS_FORK_EPOCH));
+    spec.electra_fork_epoch = Some(Epoch::new(GLOAS_FORK_EPOCH));
+    spec.fulu_fork_epoch = Some(Epoch::new(GLOAS_FORK_EPOCH));
+    spec.gloas_fork_epoch = Some(Epoch::new(GLOAS_FORK_EPOCH));
+    // The builder does not publish data columns, so keep payloads free of blobs.
+    spec.max_blobs_per_block_electra = 0;
+    let spec = Arc::new(spec);
+    env.eth2_config.spec = spec.clone();
+
+    // The builder reads the modified spec from a testnet directory.
+    let testnet_dir = TempDir::new().map_err(|e| format!("Unable to create testnet dir: {e}"))?;
+    Eth2NetworkConfig {
+        deposit_contract_deploy_block: 0,
+        boot_enr: None,
+        genesis_state_source: GenesisStateSource::Unknown,
+        genesis_state_bytes: None,
+        config: Config::from_chain_spec::<MinimalEthSpec>(&spec),
+        kzg_trusted_setup: vec![],
+    }
+    .write_to_file(testnet_dir.path().to_path_buf(), true)?;
+
+    let slot_duration = Duration::from_secs(spec.seconds_per_slot);
+    let slots_per_epoch = MinimalEthSpec::slots_per_epoch();
+
+    let context = env.core_context();
+
+    let main_future = async {
+        /*
+         * Create a new `LocalNetwork` with one beacon node.
+         */
+        let max_retries = 3;
+        let (network, mut beacon_config, mock_execution_config) = with_retry(max_retries, || {
+            Box::pin(LocalNetwork::create_local_network(
+                None,
+                None,
+                LocalNetworkParams {
+                    validator_count: total_validator_count,
+                    node_count,
+                    extra_nodes: 0,
+                    proposer_nodes: 0,
+                    genesis_delay,
+                },
+                context.clone(),
+            ))
+        })
+        .await?;
+        beacon_config.genesis_builders = BUILDER_INDEX as usize + 1;
+
+        // Add nodes to the network.
+        for _ in 0..node_count {
+            network
+                .add_beacon_node(beacon_config.clone(), mock_execution_config.clone(), false)
+                .await?;
+        }
+
+        /*
+         * One by one, add validators to the network.
+         */
+        let executor = context.executor.clone();
+        for (i, files) in validator_files.into_iter().enumerate() {
+            let network_1 = network.clone();
+            executor.spawn(
+                async move {
+                    let mut validator_config = testing_validator_config();
+                    validator_config.validator_store.fee_recipient =
+                        Some(SUGGESTED_FEE_RECIPIENT.into());
+                    println!("Adding validator client {i}");
+                    network_1
+                        .add_validator_client(validator_config, i, files)
+                        .await
+                        .expect("should add validator");
+                },
+                "vc",
+            );
+        }
+
+        // Set all payloads as valid. This effectively assumes the EL is infalliable.
+        network.execution_nodes.write().iter().for_each(|node| {
+            node.server.all_payloads_valid();
+        });
+
+        /*
+         * Start the external builder against the boot node and its execution node, which the
+         * builder needs to share so that the parent payloads are known to it.
+         */
+        let beacon_url = {
+            let beacon_nodes = network.beacon_nodes.read();
+            let socket_addr = beacon_nodes[0]
+                .client
+                .http_api_listen_addr()
+                .expect("Must have http started");
+            format!("http://{}:{}", socket_addr.ip(), socket_addr.port())
+        };
+        let (execution_endpoint, execution_jwt) = {
+            let execution_nodes = network.execution_nodes.read();
+            (
+                execution_nodes[0].server.url(),
+                execution_nodes[0].datadir.path().join("jwt.hex"),
+            )
+        };
+        println!("Starting {mode} builder");
+        let _builder = Command::new(&lcli)
+            .arg("--spec")
+            .arg("minimal")
+            .arg("--testnet-dir")
+            .arg(testnet_dir.path())
+            .arg("builder")
+            .arg("--beacon-url")
+            .arg(&beacon_url)
+            .arg("--execution-endpoint")
+            .arg(&execution_endpoint)
+            .arg("--execution-jwt")
+            .arg(&execution_jwt)
+            .arg("--builder-index")
+            .arg(BUILDER_INDEX.to_string())
+            .arg("--validator-count")
+            .arg(total_validator_count.to_string())
+            .arg("--mode")
+            .arg(&mode)
+            .arg("--sign-preferences")
+            .spawn()
+            .map(BuilderProcess)
+            .map_err(|e| format!("Failed to start {lcli} builder: {e}"))?;
+
+        let duration_to_genesis = network.duration_to_genesis().await?;
+        println!("Duration to genesis: {}", duration_to_genesis.as_secs());
+        sleep(duration_to_genesis).await;
+
+        /*
+         * Start the checks that ensure the network performs as expected.
+         */
+        let (finalization, block_prod, builder_payloads) = futures::join!(
+            // Check that the chain finalizes at the first given opportunity.
+            checks::verify_first_finalization(network.clone(), slot_duration),
+            // Check that a block is produced at every slot, whatever the builder does.
+            checks::verify_full_block_production_up_to(
+                network.clone(),
+                Epoch::new(END_EPOCH).start_slot(slots_per_epoch),
+                slot_duration,
+            ),
+            // Check that the bids and payloads of the builder were handled as expected.
+            checks::verify_external_builder_payloads(
+                network.clone(),
+                BUILDER_INDEX,
+                &mode,
+                Epoch::new(BUILDER_CHECK_START_EPOCH).start_slot(slots_per_epoch),
+                Epoch::new(END_EPOCH).start_slot(slots_per_epoch),
+                slot_duration,
+            ),
+        );
+
+        finalization?;
+        block_prod?;
+        builder_payloads?;
+
+        // The `final_future` either completes immediately or never completes, depending on the value
+        // of `continue_after_checks`.
+
+        if continue_after_checks {
+            future::pending::<()>().await;
+        }
+        /*
+         * End the simulation by dropping the network. This will kill all running beacon nodes and
+         * validator clients.
+         */
+        println!(
+            "Simulation complete. Finished with {} beacon nodes and {} validator clients",
+            network.beacon_node_count(),
+            network.validator_client_count()
+        );
+
+        // Be explicit about dropping the network, as this kills all the nodes. This ensures
+        // all the checks have adequate time to pass.
+        drop(network);
+        Ok::<(), String>(())
+    };
+
+    env.runtime().block_on(main_future)?;
+
+    env.fire_signal();
+    env.shutdown_on_idle();
+
+    Ok(())
+}
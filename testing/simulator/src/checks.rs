@@ -427,6 +427,72 @@ pub(crate) async fn verify_full_blob_production_up_to<E: EthSpec>(
     Ok(())
 }
 
+/// Verifies the blocks from `start_slot` until `upto_slot` which committed to a bid from
+/// `builder_index`, according to the `mode` the external builder is running in:
+///
+/// - `honest`: at least one bid was selected and every selected payload was revealed.
+/// - `withhold`: at least one bid was selected and no selected payload was revealed.
+/// - `underfunded`: no bid was selected.
+///
+/// Other modes only require the chain to keep producing blocks, which is checked separately.
+pub(crate) async fn verify_external_builder_payloads<E: EthSpec>(
+    network: LocalNetwork<E>,
+    builder_index: u64,
+    mode: &str,
+    start_slot: Slot,
+    upto_slot: Slot,
+    slot_duration: Duration,
+) -> Result<(), String> {
+    slot_delay(upto_slot, slot_duration).await;
+    let remote_nodes = network.remote_nodes()?;
+    let remote_node = remote_nodes.first().unwrap();
+
+    let mut selected = 0;
+    let mut revealed = 0;
+    for slot in start_slot.as_u64()..=upto_slot.as_u64() {
+        let Some(block) = remote_node
+            .get_beacon_blocks::<E>(BlockId::Slot(Slot::new(slot)))
+            .await
+            .map_err(|e| format!("Error while getting beacon block: {e:?}"))?
+            .map(|block| block.into_data())
+        else {
+            continue;
+        };
+        let bid = block
+            .message()
+            .body()
+            .signed_execution_payload_bid()
+            .map_err(|_| format!("Block at slot {slot} has no bid"))?;
+        if *bid.message().builder_index() != builder_index {
+            continue;
+        }
+        selected += 1;
+
+        let envelope = remote_node
+            .get_beacon_execution_payload_envelope::<E>(BlockId::Root(block.canonical_root()))
+            .await
+            .map_err(|e| format!("Error while getting payload envelope: {e:?}"))?;
+        if envelope.is_some() {
+            revealed += 1;
+        }
+    }
+
+    match mode {
+        "honest" if selected == 0 => Err("No bid from the external builder was selected".into()),
+        "honest" if revealed != selected => Err(format!(
+            "Only {revealed} of {selected} selected external builder payloads were revealed"
+        )),
+        "withhold" if selected == 0 => Err("No bid from the external builder was selected".into()),
+        "withhold" if revealed > 0 => Err(format!(
+            "{revealed} withheld external builder payloads were revealed"
+        )),
+        "underfunded" if selected > 0 => Err(format!(
+            "{selected} underfunded external builder bids were selected"
+        )),
+        _ => Ok(()),
+    }
+}
+
 // Causes the beacon node at `node_index` to disconnect from the execution layer.
 pub(crate) fn disconnect_from_execution_layer<E: EthSpec>(
     network: LocalNetwork<E>,
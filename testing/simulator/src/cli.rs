@@ -145,4 +145,87 @@ pub(crate) fn cli_app() -> Command {
                         .help("Disables stdout logging."),
                 ),
         )
+        .subcommand(
+            Command::new("builder-sim")
+                .about(
+                    "Runs a Beacon Chain simulation starting at Gloas with `n` beacon nodes and \
+                    validator clients, each with `v` validators, and an external ePBS builder \
+                    run by `lcli builder` against the first beacon node. \
+                    At the end of the simulation, there are checks made to ensure that the \
+                    builder's bids and payloads were handled as expected for its `--mode`, \
+                    and that the chain kept producing blocks and finalizing.",
+                )
+                .arg(
+                    Arg::new("nodes")
+                        .short('n')
+                        .long("nodes")
+                        .action(ArgAction::Set)
+                        .default_value("2")
+                        .help("Number of beacon nodes"),
+                )
+                .arg(
+                    Arg::new("validators-per-node")
+                        .short('v')
+                        .long("validators-per-node")
+                        .action(ArgAction::Set)
+                        .default_value("20")
+                        .help("Number of validators"),
+                )
+                .arg(
+                    Arg::new("speed-up-factor")
+                        .short('s')
+                        .long("speed-up-factor")
+                        .action(ArgAction::Set)
+                        .default_value("3")
+                        .help("Speed up factor. Please use a divisor of 12."),
+                )
+                .arg(
+                    Arg::new("lcli")
+                        .long("lcli")
+                        .action(ArgAction::Set)
+                        .default_value("lcli")
+                        .help("Path to the `lcli` binary used to run the builder."),
+                )
+                .arg(
+                    Arg::new("mode")
+                        .long("mode")
+                        .action(ArgAction::Set)
+                        .value_parser([
+                            "honest",
+                            "withhold",
+                            "late-reveal",
+                            "equivocate",
+                            "underfunded",
+                        ])
+                        .default_value("honest")
+                        .help("Misbehaviour mode of the builder, see `lcli builder --help`."),
+                )
+                .arg(
+                    Arg::new("debug-level")
+                        .short('d')
+                        .long("debug-level")
+                        .action(ArgAction::Set)
+                        .default_value("debug")
+                        .help("Set the severity level of the logs."),
+                )
+                .arg(
+                    Arg::new("continue-after-checks")
+                        .short('c')
+                        .long("continue_after_checks")
+                        .action(ArgAction::SetTrue)
+                        .help("Continue after checks (default false)"),
+                )
+                .arg(
+                    Arg::new("log-dir")
+                        .long("log-dir")
+                        .action(ArgAction::Set)
+                        .help("Set a path for logs of beacon nodes that run in this simulation."),
+                )
+                .arg(
+                    Arg::new("disable-stdout-logging")
+                        .long("disable-stdout-logging")
+                        .action(ArgAction::SetTrue)
+                        .help("Disables stdout logging."),
+                ),
+        )
 }
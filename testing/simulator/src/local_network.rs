@@ -92,6 +92,11 @@ fn default_mock_execution_config<E: EthSpec>(
             genesis_time + spec.seconds_per_slot * E::slots_per_epoch() * fulu_fork_epoch.as_u64(),
         );
     }
+    if let Some(gloas_fork_epoch) = spec.gloas_fork_epoch {
+        mock_execution_config.amsterdam_time = Some(
+            genesis_time + spec.seconds_per_slot * E::slots_per_epoch() * gloas_fork_epoch.as_u64(),
+        );
+    }
 
     mock_execution_config
 }
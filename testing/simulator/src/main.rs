@@ -11,6 +11,7 @@
 //! easy-to-find files and stdout only contained info from the simulation.
 //!
 mod basic_sim;
+mod builder_sim;
 mod checks;
 mod cli;
 mod fallback_sim;
@@ -33,6 +34,13 @@ fn main() {
                 std::process::exit(1)
             }
         },
+        Some("builder-sim") => match builder_sim::run_builder_sim(&matches) {
+            Ok(()) => println!("Simulation exited successfully"),
+            Err(e) => {
+                eprintln!("Simulation exited with error: {e}");
+                std::process::exit(1)
+            }
+        },
         Some("fallback-sim") => match fallback_sim::run_fallback_sim(&matches) {
             Ok(()) => println!("Simulation exited successfully"),
             Err(e) => {
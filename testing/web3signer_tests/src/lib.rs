@@ -9,6 +9,9 @@
 //! - Vibehouse can issue valid requests to Web3Signer.
 //! - The signatures generated by Web3Signer are identical to those which Vibehouse generates.
 //!
+//! Web3Signer doesn't sign the Gloas ePBS and Heze FOCIL types yet, so those requests are tested
+//! against a local stand-in signer which verifies their signing roots.
+//!
 //! There is a `download_binary` function in the `get_web3signer` module which obtains the latest version of Web3Signer and makes
 //! it available via the `TEMP_DIR`.
 #![cfg(all(test, unix, not(debug_assertions)))]
@@ -18,8 +21,13 @@ mod get_web3signer;
 mod tests {
     use crate::get_web3signer::download_binary;
     use account_utils::validator_definitions::{
-        SigningDefinition, ValidatorDefinition, ValidatorDefinitions, Web3SignerDefinition,
+        SigningDefinition, ThresholdDefinition, ThresholdShareDefinition, ValidatorDefinition,
+        ValidatorDefinitions, Web3SignerDefinition,
     };
+    use axum::extract::{self, State};
+    use axum::http::StatusCode;
+    use axum::routing::post;
+    use axum::{Json, Router};
     use eth2::types::FullBlockContents;
     use eth2_keystore::KeystoreBuilder;
     use eth2_network_config::Eth2NetworkConfig;
@@ -28,20 +36,21 @@ mod tests {
     };
     use parking_lot::Mutex;
     use reqwest::Client;
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
     use slashing_protection::{SLASHING_PROTECTION_FILENAME, SlashingDatabase};
     use slot_clock::{SlotClock, TestingSlotClock};
     use std::env;
     use std::fmt::Debug;
     use std::fs::{self, File};
     use std::future::Future;
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
     use std::process::{Child, Command, Stdio};
     use std::sync::{Arc, LazyLock};
     use std::time::{Duration, Instant};
     use task_executor::TaskExecutor;
     use tempfile::{TempDir, tempdir};
     use tokio::sync::OnceCell;
+    use tokio::task::JoinHandle;
     use tokio::time::sleep;
     use types::{attestation::AttestationBase, *};
     use url::Url;
@@ -84,6 +93,10 @@ mod tests {
     impl SignedObject for SyncCommitteeMessage {}
     impl SignedObject for SignedContributionAndProof<E> {}
     impl SignedObject for SignedValidatorRegistrationData {}
+    impl SignedObject for SignedExecutionPayloadEnvelope<E> {}
+    impl SignedObject for PayloadAttestationMessage {}
+    impl SignedObject for SignedProposerPreferences {}
+    impl SignedObject for SignedInclusionList<E> {}
 
     /// A file format used by Web3Signer to discover and unlock keystores.
     #[derive(Serialize)]
@@ -113,6 +126,16 @@ mod tests {
         Keypair::from_components(pk, sk)
     }
 
+    /// Write an EIP-2335 keystore for `keypair` to `path`, encrypted with `KEYSTORE_PASSWORD`.
+    fn write_keystore(keypair: &Keypair, path: &Path) {
+        let keystore = KeystoreBuilder::new(keypair, KEYSTORE_PASSWORD.as_bytes(), String::new())
+            .unwrap()
+            .build()
+            .unwrap();
+        let keystore_file = File::create(path).unwrap();
+        keystore.to_json_writer(&keystore_file).unwrap();
+    }
+
     /// The location of the Web3Signer binary generated by the build script.
     fn web3signer_binary() -> PathBuf {
         TEMP_DIR
@@ -188,15 +211,9 @@ mod tests {
 
             let keystore_dir = TempDir::new().unwrap();
             let keypair = testing_keypair();
-            let keystore =
-                KeystoreBuilder::new(&keypair, KEYSTORE_PASSWORD.as_bytes(), String::new())
-                    .unwrap()
-                    .build()
-                    .unwrap();
             let keystore_filename = "keystore.json";
             let keystore_path = keystore_dir.path().join(keystore_filename);
-            let keystore_file = File::create(&keystore_path).unwrap();
-            keystore.to_json_writer(&keystore_file).unwrap();
+            write_keystore(&keypair, &keystore_path);
 
             let keystore_password_filename = "password.txt";
             let keystore_password_path = keystore_dir.path().join(keystore_password_filename);
@@ -304,6 +321,240 @@ mod tests {
         }
     }
 
+    /// The `fork_info` field of a Web3Signer signing request.
+    #[derive(Deserialize)]
+    struct StandInForkInfo {
+        fork: Fork,
+        genesis_validators_root: Hash256,
+    }
+
+    /// A testing rig which holds a local stand-in for Web3Signer.
+    ///
+    /// Web3Signer doesn't sign the ePBS and FOCIL types yet, so these requests are served by a
+    /// minimal signer which checks them the way Web3Signer checks the types it does support: the
+    /// signing root is recomputed from the message, its type and the fork info before signing.
+    ///
+    /// It also signs attestations and blocks, so that stand-in signers can hold the shares of a
+    /// threshold key.
+    struct StandInSignerRig {
+        keypair: Keypair,
+        _keystore_dir: TempDir,
+        keystore_path: PathBuf,
+        url: Url,
+        /// The message types of every request signed so far.
+        signed_types: Arc<Mutex<Vec<String>>>,
+        server: JoinHandle<()>,
+    }
+
+    impl Drop for StandInSignerRig {
+        fn drop(&mut self) {
+            self.server.abort();
+        }
+    }
+
+    impl StandInSignerRig {
+        pub(crate) async fn new(spec: Arc<ChainSpec>) -> Self {
+            Self::with_keypair(spec, testing_keypair()).await
+        }
+
+        /// Create a stand-in signer which signs with `keypair`.
+        async fn with_keypair(spec: Arc<ChainSpec>, keypair: Keypair) -> Self {
+            let keystore_dir = TempDir::new().unwrap();
+            let keystore_path = keystore_dir.path().join("keystore.json");
+            write_keystore(&keypair, &keystore_path);
+
+            let signed_types = Arc::new(Mutex::new(vec![]));
+            let state = (keypair.clone(), spec, signed_types.clone());
+            let app = Router::new()
+                .route("/api/v1/eth2/sign/{identifier}", post(stand_in_sign))
+                .with_state(state);
+
+            let listener = tokio::net::TcpListener::bind((WEB3SIGNER_LISTEN_ADDRESS, 0))
+                .await
+                .unwrap();
+            let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+            let server = tokio::spawn(async move {
+                axum::serve(listener, app).await.unwrap();
+            });
+
+            Self {
+                keypair,
+                _keystore_dir: keystore_dir,
+                keystore_path,
+                url,
+                signed_types,
+                server,
+            }
+        }
+    }
+
+    type StandInState = (Keypair, Arc<ChainSpec>, Arc<Mutex<Vec<String>>>);
+
+    async fn stand_in_sign(
+        State((keypair, spec, signed_types)): State<StandInState>,
+        extract::Path(identifier): extract::Path<String>,
+        Json(request): Json<serde_json::Value>,
+    ) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+        let bad_request = |e: String| (StatusCode::BAD_REQUEST, e);
+        if identifier != keypair.pk.to_string() {
+            return Err((StatusCode::NOT_FOUND, format!("unknown key {identifier}")));
+        }
+
+        let message_type = request["type"]
+            .as_str()
+            .ok_or_else(|| bad_request("missing type".to_string()))?;
+        let field = |name: &str| {
+            request
+                .get(name)
+                .cloned()
+                .ok_or_else(|| bad_request(format!("{message_type} request without {name}")))
+        };
+        let fork_info: StandInForkInfo =
+            serde_json::from_value(field("fork_info")?).map_err(|e| bad_request(e.to_string()))?;
+        let signing_root: Hash256 = serde_json::from_value(field("signingRoot")?)
+            .map_err(|e| bad_request(e.to_string()))?;
+        let domain = |domain: Domain, epoch: Epoch| {
+            spec.get_domain(
+                epoch,
+                domain,
+                &fork_info.fork,
+                fork_info.genesis_validators_root,
+            )
+        };
+
+        let expected_root = match message_type {
+            "ATTESTATION" => {
+                let data: AttestationData = serde_json::from_value(field("attestation")?)
+                    .map_err(|e| bad_request(e.to_string()))?;
+                data.signing_root(domain(Domain::BeaconAttester, data.target.epoch))
+            }
+            "BLOCK_V2" => {
+                let block_header: BeaconBlockHeader =
+                    serde_json::from_value(field("beacon_block")?["block_header"].clone())
+                        .map_err(|e| bad_request(e.to_string()))?;
+                let epoch = block_header.slot.epoch(E::slots_per_epoch());
+                block_header.signing_root(domain(Domain::BeaconProposer, epoch))
+            }
+            "EXECUTION_PAYLOAD_ENVELOPE" => {
+                let envelope: ExecutionPayloadEnvelope<E> =
+                    serde_json::from_value(field("execution_payload_envelope")?)
+                        .map_err(|e| bad_request(e.to_string()))?;
+                let epoch = envelope.slot.epoch(E::slots_per_epoch());
+                envelope.signing_root(domain(Domain::BeaconBuilder, epoch))
+            }
+            "PAYLOAD_ATTESTATION" => {
+                let data: PayloadAttestationData =
+                    serde_json::from_value(field("payload_attestation_data")?)
+                        .map_err(|e| bad_request(e.to_string()))?;
+                let epoch = data.slot.epoch(E::slots_per_epoch());
+                data.signing_root(domain(Domain::PtcAttester, epoch))
+            }
+            "PROPOSER_PREFERENCES" => {
+                let preferences: ProposerPreferences =
+                    serde_json::from_value(field("proposer_preferences")?)
+                        .map_err(|e| bad_request(e.to_string()))?;
+                let epoch = Slot::new(preferences.proposal_slot).epoch(E::slots_per_epoch());
+                preferences.signing_root(domain(Domain::ProposerPreferences, epoch))
+            }
+            "INCLUSION_LIST" => {
+                let inclusion_list: InclusionList<E> =
+                    serde_json::from_value(field("inclusion_list")?)
+                        .map_err(|e| bad_request(e.to_string()))?;
+                let epoch = inclusion_list.slot.epoch(E::slots_per_epoch());
+                inclusion_list.signing_root(domain(Domain::InclusionListCommittee, epoch))
+            }
+            other => return Err(bad_request(format!("unsupported type {other}"))),
+        };
+        if expected_root != signing_root {
+            return Err(bad_request(format!(
+                "{message_type} signing root {signing_root:?} does not match {expected_root:?}"
+            )));
+        }
+
+        signed_types.lock().push(message_type.to_string());
+        Ok(Json(
+            serde_json::json!({ "signature": keypair.sk.sign(signing_root) }),
+        ))
+    }
+
+    /// A testing rig for a threshold key whose shares are held by stand-in signers.
+    struct ThresholdSignerRig {
+        keypair: Keypair,
+        _keystore_dir: TempDir,
+        keystore_path: PathBuf,
+        threshold: usize,
+        shares: Vec<Keypair>,
+        /// The signers of the first shares. The signers of the remaining shares are down.
+        share_rigs: Vec<StandInSignerRig>,
+    }
+
+    impl ThresholdSignerRig {
+        /// Split the testing key into `share_count` shares, any `threshold` of which can sign, and
+        /// start a stand-in signer for each of the first `online` shares.
+        pub(crate) async fn new(
+            spec: Arc<ChainSpec>,
+            threshold: usize,
+            share_count: usize,
+            online: usize,
+        ) -> Self {
+            let keystore_dir = TempDir::new().unwrap();
+            let keypair = testing_keypair();
+            let keystore_path = keystore_dir.path().join("keystore.json");
+            write_keystore(&keypair, &keystore_path);
+
+            let shares = keypair
+                .sk
+                .split_into_shares(threshold, share_count)
+                .unwrap()
+                .into_iter()
+                .map(|sk| Keypair::from_components(sk.public_key(), sk))
+                .collect::<Vec<_>>();
+            let mut share_rigs = vec![];
+            for share in &shares[..online] {
+                share_rigs.push(StandInSignerRig::with_keypair(spec.clone(), share.clone()).await);
+            }
+
+            Self {
+                keypair,
+                _keystore_dir: keystore_dir,
+                keystore_path,
+                threshold,
+                shares,
+                share_rigs,
+            }
+        }
+
+        fn signing_definition(&self) -> SigningDefinition {
+            let shares = self
+                .shares
+                .iter()
+                .enumerate()
+                .map(|(i, share)| {
+                    let url = self.share_rigs.get(i).map_or_else(
+                        // Nothing listens on this port.
+                        || format!("http://{WEB3SIGNER_LISTEN_ADDRESS}:1"),
+                        |rig| rig.url.to_string(),
+                    );
+                    ThresholdShareDefinition {
+                        index: i as u64 + 1,
+                        public_key: share.pk.clone(),
+                        signer: Web3SignerDefinition {
+                            url,
+                            root_certificate_path: None,
+                            request_timeout_ms: None,
+                            client_identity_path: None,
+                            client_identity_password: None,
+                        },
+                    }
+                })
+                .collect();
+            SigningDefinition::Threshold(ThresholdDefinition {
+                threshold: self.threshold,
+                shares,
+            })
+        }
+    }
+
     /// A testing rig which holds a `ValidatorStore`.
     struct ValidatorStoreRig {
         validator_store: Arc<VibehouseValidatorStore<TestingSlotClock, E>>,
@@ -382,13 +633,38 @@ mod tests {
         }
     }
 
+    /// The remote signer used by a `TestingRig`.
+    enum SignerRig {
+        Web3Signer(Web3SignerRig),
+        StandIn(StandInSignerRig),
+        Threshold(ThresholdSignerRig),
+    }
+
+    impl SignerRig {
+        fn keypair(&self) -> &Keypair {
+            match self {
+                SignerRig::Web3Signer(rig) => &rig.keypair,
+                SignerRig::StandIn(rig) => &rig.keypair,
+                SignerRig::Threshold(rig) => &rig.keypair,
+            }
+        }
+
+        fn keystore_path(&self) -> PathBuf {
+            match self {
+                SignerRig::Web3Signer(rig) => rig.keystore_path.clone(),
+                SignerRig::StandIn(rig) => rig.keystore_path.clone(),
+                SignerRig::Threshold(rig) => rig.keystore_path.clone(),
+            }
+        }
+    }
+
     /// A testing rig which holds multiple `ValidatorStore` rigs and one `Web3Signer` rig.
     ///
     /// The intent of this rig is to allow testing a `ValidatorStore` using `Web3Signer` against
     /// another `ValidatorStore` using a local keystore and ensure that both `ValidatorStore`s
     /// behave identically.
     struct TestingRig {
-        _signer_rig: Web3SignerRig,
+        signer_rig: SignerRig,
         validator_rigs: Vec<ValidatorStoreRig>,
         validator_pubkey: PublicKeyBytes,
     }
@@ -410,8 +686,62 @@ mod tests {
         ) -> Self {
             let signer_rig =
                 Web3SignerRig::new(network, WEB3SIGNER_LISTEN_ADDRESS, listen_port).await;
-            let validator_pubkey = signer_rig.keypair.pk.clone();
+            let web3signer_definition = Web3SignerDefinition {
+                url: signer_rig.url.to_string(),
+                root_certificate_path: Some(root_certificate_path()),
+                request_timeout_ms: None,
+                client_identity_path: Some(client_identity_path()),
+                client_identity_password: Some(client_identity_password()),
+            };
+            Self::with_signer(
+                SignerRig::Web3Signer(signer_rig),
+                SigningDefinition::Web3Signer(web3signer_definition),
+                slashing_protection_config,
+                spec,
+            )
+            .await
+        }
+
+        /// Create a rig whose remote signer is a `StandInSignerRig` rather than Web3Signer.
+        pub(crate) async fn new_stand_in(spec: Arc<ChainSpec>) -> Self {
+            let signer_rig = StandInSignerRig::new(spec.clone()).await;
+            let web3signer_definition = Web3SignerDefinition {
+                url: signer_rig.url.to_string(),
+                root_certificate_path: None,
+                request_timeout_ms: None,
+                client_identity_path: None,
+                client_identity_password: None,
+            };
+            Self::with_signer(
+                SignerRig::StandIn(signer_rig),
+                SigningDefinition::Web3Signer(web3signer_definition),
+                SlashingProtectionConfig::default(),
+                spec,
+            )
+            .await
+        }
+
+        /// Create a rig whose remote signer is a 2 of 3 threshold key, with the signer of the
+        /// third share down.
+        pub(crate) async fn new_threshold(spec: Arc<ChainSpec>) -> Self {
+            let signer_rig = ThresholdSignerRig::new(spec.clone(), 2, 3, 2).await;
+            let signing_definition = signer_rig.signing_definition();
+            Self::with_signer(
+                SignerRig::Threshold(signer_rig),
+                signing_definition,
+                SlashingProtectionConfig::default(),
+                spec,
+            )
+            .await
+        }
 
+        async fn with_signer(
+            signer_rig: SignerRig,
+            remote_signing_definition: SigningDefinition,
+            slashing_protection_config: SlashingProtectionConfig,
+            spec: Arc<ChainSpec>,
+        ) -> Self {
+            let validator_pubkey = signer_rig.keypair().pk.clone();
             let local_signer_validator_store = {
                 let validator_definition = ValidatorDefinition {
                     enabled: true,
@@ -424,7 +754,7 @@ mod tests {
                     prefer_builder_proposals: None,
                     description: String::default(),
                     signing_definition: SigningDefinition::LocalKeystore {
-                        voting_keystore_path: signer_rig.keystore_path.clone(),
+                        voting_keystore_path: signer_rig.keystore_path(),
                         voting_keystore_password_path: None,
                         voting_keystore_password: Some(KEYSTORE_PASSWORD.to_string().into()),
                     },
@@ -449,13 +779,7 @@ mod tests {
                     builder_boost_factor: None,
                     prefer_builder_proposals: None,
                     description: String::default(),
-                    signing_definition: SigningDefinition::Web3Signer(Web3SignerDefinition {
-                        url: signer_rig.url.to_string(),
-                        root_certificate_path: Some(root_certificate_path()),
-                        request_timeout_ms: None,
-                        client_identity_path: Some(client_identity_path()),
-                        client_identity_password: Some(client_identity_password()),
-                    }),
+                    signing_definition: remote_signing_definition,
                 };
                 ValidatorStoreRig::new(
                     vec![validator_definition],
@@ -467,7 +791,7 @@ mod tests {
             };
 
             Self {
-                _signer_rig: signer_rig,
+                signer_rig,
                 validator_rigs: vec![local_signer_validator_store, remote_signer_validator_store],
                 validator_pubkey: PublicKeyBytes::from(&validator_pubkey),
             }
@@ -506,6 +830,27 @@ mod tests {
             self
         }
 
+        /// Assert that the stand-in signer has signed exactly the `expected` message types, in order.
+        pub(crate) fn assert_stand_in_signed(self, expected: &[&str]) -> Self {
+            let SignerRig::StandIn(signer_rig) = &self.signer_rig else {
+                panic!("rig does not use the stand-in signer");
+            };
+            assert_eq!(*signer_rig.signed_types.lock(), expected);
+            self
+        }
+
+        /// Assert that each online share signer has signed exactly the `expected` message types, in
+        /// order.
+        pub(crate) fn assert_shares_signed(self, expected: &[&str]) -> Self {
+            let SignerRig::Threshold(signer_rig) = &self.signer_rig else {
+                panic!("rig does not use a threshold signer");
+            };
+            for share_rig in &signer_rig.share_rigs {
+                assert_eq!(*share_rig.signed_types.lock(), expected);
+            }
+            self
+        }
+
         /// Assert that a slashable message fails to be signed locally and is either signed or not
         /// by the web3signer rig depending on the value of `web3signer_should_sign`.
         pub(crate) async fn assert_slashable_message_should_sign<F, R>(
@@ -759,6 +1104,131 @@ mod tests {
         .await;
     }
 
+    /// Test the Gloas ePBS and Heze FOCIL types against the stand-in signer.
+    async fn test_epbs_and_focil_types() {
+        let spec = Arc::new(ForkName::Heze.make_genesis_spec(E::default_spec()));
+        let slot = Slot::new(E::slots_per_epoch() + 1);
+
+        TestingRig::new_stand_in(spec)
+            .await
+            .assert_signatures_match(
+                "execution_payload_envelope",
+                |pubkey, validator_store| async move {
+                    let envelope = ExecutionPayloadEnvelope::<E> {
+                        slot,
+                        beacon_block_root: Hash256::repeat_byte(0xaa),
+                        builder_index: 3,
+                        ..Default::default()
+                    };
+                    validator_store
+                        .sign_execution_payload_envelope(pubkey, &envelope)
+                        .await
+                        .unwrap()
+                },
+            )
+            .await
+            .assert_signatures_match(
+                "payload_attestation",
+                |pubkey, validator_store| async move {
+                    let data = PayloadAttestationData {
+                        beacon_block_root: Hash256::repeat_byte(0xbb),
+                        slot,
+                        payload_present: true,
+                        blob_data_available: true,
+                    };
+                    validator_store
+                        .sign_payload_attestation(pubkey, &data, 0)
+                        .await
+                        .unwrap()
+                },
+            )
+            .await
+            .assert_signatures_match(
+                "proposer_preferences",
+                |pubkey, validator_store| async move {
+                    let preferences = ProposerPreferences {
+                        proposal_slot: slot.as_u64(),
+                        validator_index: 0,
+                        fee_recipient: Address::repeat_byte(42),
+                        gas_limit: 30_000_000,
+                    };
+                    validator_store
+                        .sign_proposer_preferences(pubkey, &preferences)
+                        .await
+                        .unwrap()
+                },
+            )
+            .await
+            .assert_signatures_match("inclusion_list", |pubkey, validator_store| async move {
+                let inclusion_list = InclusionList::<E> {
+                    slot,
+                    validator_index: 0,
+                    inclusion_list_committee_root: Hash256::repeat_byte(0xcc),
+                    transactions: <_>::default(),
+                };
+                validator_store
+                    .sign_inclusion_list(pubkey, &inclusion_list)
+                    .await
+                    .unwrap()
+            })
+            .await
+            .assert_stand_in_signed(&[
+                "EXECUTION_PAYLOAD_ENVELOPE",
+                "PAYLOAD_ATTESTATION",
+                "PROPOSER_PREFERENCES",
+                "INCLUSION_LIST",
+            ]);
+    }
+
+    /// Test signing through a threshold key, the signer of one of whose shares is down.
+    async fn test_threshold_types() {
+        let spec = Arc::new(ForkName::Heze.make_genesis_spec(E::default_spec()));
+        let slot = Slot::new(E::slots_per_epoch() + 1);
+
+        TestingRig::new_threshold(spec.clone())
+            .await
+            .assert_signatures_match("beacon_block_heze", |pubkey, validator_store| {
+                let spec = spec.clone();
+                async move {
+                    let mut heze_block = BeaconBlockHeze::empty(&spec);
+                    heze_block.slot = slot;
+                    let unsigned_block =
+                        UnsignedBlock::Full(FullBlockContents::Block(heze_block.into()));
+                    validator_store
+                        .sign_block(pubkey, unsigned_block, slot)
+                        .await
+                        .unwrap()
+                }
+            })
+            .await
+            .assert_signatures_match("attestation", |pubkey, validator_store| async move {
+                let mut attestation = get_attestation();
+                validator_store
+                    .sign_attestation(pubkey, 0, &mut attestation, Epoch::new(0))
+                    .await
+                    .unwrap();
+                attestation
+            })
+            .await
+            .assert_signatures_match(
+                "payload_attestation",
+                |pubkey, validator_store| async move {
+                    let data = PayloadAttestationData {
+                        beacon_block_root: Hash256::repeat_byte(0xbb),
+                        slot,
+                        payload_present: true,
+                        blob_data_available: true,
+                    };
+                    validator_store
+                        .sign_payload_attestation(pubkey, &data, 0)
+                        .await
+                        .unwrap()
+                },
+            )
+            .await
+            .assert_shares_signed(&["BLOCK_V2", "ATTESTATION", "PAYLOAD_ATTESTATION"]);
+    }
+
     async fn test_vibehouse_slashing_protection(
         slashing_protection_config: SlashingProtectionConfig,
         listen_port: u16,
@@ -929,6 +1399,16 @@ mod tests {
         test_bellatrix_types("sepolia", 4252).await;
     }
 
+    #[tokio::test]
+    async fn epbs_and_focil_types() {
+        test_epbs_and_focil_types().await;
+    }
+
+    #[tokio::test]
+    async fn threshold_types() {
+        test_threshold_types().await;
+    }
+
     #[tokio::test]
     async fn slashing_protection_disabled_locally() {
         test_vibehouse_slashing_protection(SlashingProtectionConfig { local: false }, 4253).await;
@@ -20,12 +20,14 @@ use std::future::Future;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use std::vec::Vec;
-use strum::VariantNames;
+use strum::{IntoStaticStr, VariantNames};
 use task_executor::TaskExecutor;
 use tokio::{sync::RwLock, time::sleep};
 use tracing::{debug, error, warn};
 use types::{ChainSpec, Config as ConfigSpec, EthSpec, Slot};
-use validator_metrics::{ENDPOINT_ERRORS, ENDPOINT_REQUESTS, inc_counter_vec};
+use validator_metrics::{
+    BROADCAST_RESULTS, ENDPOINT_ERRORS, ENDPOINT_REQUESTS, ERROR, SUCCESS, inc_counter_vec,
+};
 
 /// Message emitted when the VC detects the BN is using a different spec.
 const UPDATE_REQUIRED_LOG_HINT: &str = "this VC or the remote BN may need updating";
@@ -682,6 +684,20 @@ impl<T: SlotClock> BeaconNodeFallback<T> {
     /// Since this ignores the actual result of `func`, this function should only be used for beacon
     /// node calls whose results we do not care about, only that they completed successfully.
     pub async fn broadcast<F, O, Err, R>(&self, func: F) -> Result<(), Errors<Err>>
+    where
+        F: Fn(BeaconNodeHttpClient) -> R,
+        R: Future<Output = Result<O, Err>>,
+        Err: Debug,
+    {
+        self.broadcast_topic(None, func).await
+    }
+
+    /// As for `broadcast`, additionally counting the result on each candidate against `topic`.
+    async fn broadcast_topic<F, O, Err, R>(
+        &self,
+        topic: Option<ApiTopic>,
+        func: F,
+    ) -> Result<(), Errors<Err>>
     where
         F: Fn(BeaconNodeHttpClient) -> R,
         R: Future<Output = Result<O, Err>>,
@@ -689,16 +705,26 @@ impl<T: SlotClock> BeaconNodeFallback<T> {
     {
         // Run `func` on all candidates.
         let candidates = self.candidates.read().await;
+        let mut endpoints = vec![];
         let mut futures = vec![];
 
         // Run `func` using a `candidate`, returning the value or capturing errors.
         for candidate in candidates.iter() {
+            endpoints.push(candidate.beacon_node.as_ref().to_string());
             futures.push(Self::run_on_candidate(candidate.beacon_node.clone(), &func));
         }
         drop(candidates);
 
         let results = future::join_all(futures).await;
 
+        if let Some(topic) = topic {
+            let topic: &'static str = topic.into();
+            for (endpoint, result) in endpoints.iter().zip(&results) {
+                let outcome = if result.is_ok() { SUCCESS } else { ERROR };
+                inc_counter_vec(&BROADCAST_RESULTS, &[topic, endpoint.as_str(), outcome]);
+            }
+        }
+
         let errors: Vec<_> = results
             .into_iter()
             .filter_map(std::result::Result::err)
@@ -720,7 +746,7 @@ impl<T: SlotClock> BeaconNodeFallback<T> {
         Err: Debug,
     {
         if self.broadcast_topics.contains(&topic) {
-            self.broadcast(func).await
+            self.broadcast_topic(Some(topic), func).await
         } else {
             self.first_success(func).await?;
             Ok(())
@@ -755,7 +781,18 @@ async fn sort_nodes_by_health(nodes: &mut Vec<CandidateBeaconNode>) {
 }
 
 /// Serves as a cue for `BeaconNodeFallback` to tell which requests need to be broadcasted.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, VariantNames, ValueEnum)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+    VariantNames,
+    IntoStaticStr,
+    ValueEnum,
+)]
 #[strum(serialize_all = "kebab-case")]
 pub enum ApiTopic {
     None,
@@ -763,12 +800,26 @@ pub enum ApiTopic {
     Blocks,
     Subscriptions,
     SyncCommittee,
+    PayloadAttestations,
+    InclusionLists,
+    ProposerPreferences,
 }
 
 impl ApiTopic {
     pub fn all() -> Vec<ApiTopic> {
-        use ApiTopic::{Attestations, Blocks, Subscriptions, SyncCommittee};
-        vec![Attestations, Blocks, Subscriptions, SyncCommittee]
+        use ApiTopic::{
+            Attestations, Blocks, InclusionLists, PayloadAttestations, ProposerPreferences,
+            Subscriptions, SyncCommittee,
+        };
+        vec![
+            Attestations,
+            Blocks,
+            Subscriptions,
+            SyncCommittee,
+            PayloadAttestations,
+            InclusionLists,
+            ProposerPreferences,
+        ]
     }
 }
 
@@ -780,8 +831,11 @@ mod tests {
     use eth2::Timeouts;
     use slot_clock::TestingSlotClock;
     use strum::VariantNames;
+    use types::{
+        Address, EmptyBlock, ProposerPreferences, Signature, SignedBeaconBlockDeneb,
+        SignedBlindedBeaconBlock, SignedProposerPreferences,
+    };
     use types::{BeaconBlockDeneb, MainnetEthSpec, Slot};
-    use types::{EmptyBlock, Signature, SignedBeaconBlockDeneb, SignedBlindedBeaconBlock};
     use validator_test_rig::mock_beacon_node::MockBeaconNode;
 
     type E = MainnetEthSpec;
@@ -1034,6 +1088,60 @@ mod tests {
         assert_eq!(received_blocks_from_bn_2.len(), 1);
     }
 
+    #[tokio::test]
+    async fn request_should_broadcast_topic_and_count_results() {
+        let spec = Arc::new(MainnetEthSpec::default_spec());
+        let (mut mock_beacon_node_1, beacon_node_1) = new_mock_beacon_node(0, &spec).await;
+        let (mut mock_beacon_node_2, beacon_node_2) = new_mock_beacon_node(1, &spec).await;
+        let endpoint_1 = beacon_node_1.beacon_node.as_ref().to_string();
+        let endpoint_2 = beacon_node_2.beacon_node.as_ref().to_string();
+
+        let beacon_node_fallback = create_beacon_node_fallback(
+            vec![beacon_node_1, beacon_node_2],
+            vec![ApiTopic::ProposerPreferences],
+            spec.clone(),
+        );
+
+        let mock1 = mock_beacon_node_1.mock_post_beacon_pool_proposer_preferences();
+        let mock2 = mock_beacon_node_2.mock_post_beacon_pool_proposer_preferences_error();
+
+        let preferences = SignedProposerPreferences {
+            message: ProposerPreferences {
+                proposal_slot: 1,
+                validator_index: 0,
+                fee_recipient: Address::repeat_byte(1),
+                gas_limit: 30_000_000,
+            },
+            signature: Signature::empty(),
+        };
+        let result = beacon_node_fallback
+            .request(ApiTopic::ProposerPreferences, |client| {
+                let preferences = preferences.clone();
+                async move {
+                    client
+                        .post_beacon_pool_proposer_preferences(&preferences)
+                        .await
+                }
+            })
+            .await;
+
+        // Both nodes are sent the message exactly once, and only the failing one is reported.
+        mock1.expect(1).assert();
+        mock2.expect(1).assert();
+        assert_eq!(result.unwrap_err().0.len(), 1);
+
+        let count = |endpoint: &str, result: &str| {
+            validator_metrics::get_int_counter(
+                &BROADCAST_RESULTS,
+                &["proposer-preferences", endpoint, result],
+            )
+            .map(|counter| counter.get())
+        };
+        assert_eq!(count(&endpoint_1, SUCCESS), Some(1));
+        assert_eq!(count(&endpoint_2, ERROR), Some(1));
+        assert_eq!(count(&endpoint_2, SUCCESS), Some(0));
+    }
+
     #[tokio::test]
     async fn first_success_should_try_nodes_in_order() {
         let spec = Arc::new(MainnetEthSpec::default_spec());
@@ -0,0 +1,627 @@
+//! A record of the duties performed by each validator and how they turned out on chain.
+//!
+//! The services which perform duties record when each message was signed, and when and through
+//! which beacon nodes it was published. These records are queued and written by a background
+//! thread in batches, so that recording never delays a duty. The outcome of each duty (whether it
+//! was included, and how well) is filled in later from the canonical chain by the duty outcome
+//! service in `validator_services`.
+use eth2::vibehouse_vc::types::{DutyHistoryQuery, DutyKind, DutyOutcome, DutyRecord};
+use filesystem::restrict_file_permissions;
+use parking_lot::Mutex;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{Row, params};
+use std::fmt::Display;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::sync::mpsc::{Receiver, Sender, SyncSender, channel, sync_channel};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::warn;
+use types::{PublicKeyBytes, Slot};
+
+/// The filename within the validator directory that contains the duty history database.
+pub const DUTY_HISTORY_FILENAME: &str = "duty_history.sqlite";
+
+/// The number of records returned by `DutyHistory::history` if the query sets no limit.
+pub const DEFAULT_HISTORY_LIMIT: u64 = 1_000;
+
+type Pool = r2d2::Pool<SqliteConnectionManager>;
+
+/// The HTTP API reads the history while the services are writing to it, so allow a few
+/// connections, each of which waits for the others' writes to complete.
+const POOL_SIZE: u32 = 4;
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub enum Error {
+    SQLError(rusqlite::Error),
+    SQLPoolError(r2d2::Error),
+    PermissionsError,
+    IOError(std::io::Error),
+    /// A row in the database could not be decoded.
+    InvalidRecord(String),
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Error::SQLError(error)
+    }
+}
+
+impl From<r2d2::Error> for Error {
+    fn from(error: r2d2::Error) -> Self {
+        Error::SQLPoolError(error)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::IOError(error)
+    }
+}
+
+/// A duty whose outcome has not yet been recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingDuty {
+    pub slot: Slot,
+    pub validator_index: u64,
+    /// The position of the validator in the committee for the duty, where it determines which
+    /// aggregation bit represents the validator on chain (e.g. the PTC).
+    pub committee_position: Option<u64>,
+}
+
+/// The outcome of a duty, as determined from the canonical chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfirmedOutcome {
+    pub outcome: DutyOutcome,
+    pub inclusion_delay: Option<u64>,
+    pub head_correct: Option<bool>,
+    pub target_correct: Option<bool>,
+}
+
+impl ConfirmedOutcome {
+    /// The duty was included on chain, with no further detail.
+    pub fn included() -> Self {
+        Self {
+            outcome: DutyOutcome::Included,
+            inclusion_delay: None,
+            head_correct: None,
+            target_correct: None,
+        }
+    }
+
+    /// The duty was not included on chain.
+    pub fn missed() -> Self {
+        Self {
+            outcome: DutyOutcome::Missed,
+            inclusion_delay: None,
+            head_correct: None,
+            target_correct: None,
+        }
+    }
+}
+
+/// A record queued for the writer thread.
+#[derive(Debug)]
+enum Write {
+    Signed {
+        duty: DutyKind,
+        slot: Slot,
+        validator_pubkey: PublicKeyBytes,
+        validator_index: u64,
+        committee_position: Option<u64>,
+        signed_at_ms: u64,
+    },
+    Published {
+        duty: DutyKind,
+        slot: Slot,
+        validator_indices: Vec<u64>,
+        beacon_node: String,
+        published_at_ms: u64,
+    },
+    /// Acknowledge once all previously queued records are written.
+    Flush(SyncSender<()>),
+}
+
+/// The duty history database.
+#[derive(Debug, Clone)]
+pub struct DutyHistory {
+    conn_pool: Pool,
+    writer: Sender<Write>,
+}
+
+impl DutyHistory {
+    /// Open the database at `path`, or create one if none exists.
+    pub fn open_or_create(path: &Path) -> Result<Self, Error> {
+        match File::options()
+            .write(true)
+            .read(true)
+            .create_new(true)
+            .open(path)
+        {
+            Ok(_) => restrict_file_permissions(path).map_err(|_| Error::PermissionsError)?,
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        let manager = SqliteConnectionManager::file(path)
+            .with_flags(rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE)
+            .with_init(|conn| conn.busy_timeout(CONNECTION_TIMEOUT));
+        let conn_pool = Pool::builder()
+            .max_size(POOL_SIZE)
+            .connection_timeout(CONNECTION_TIMEOUT)
+            .build(manager)?;
+
+        conn_pool.get()?.execute_batch(
+            "CREATE TABLE IF NOT EXISTS duties (
+                id INTEGER PRIMARY KEY,
+                public_key TEXT NOT NULL,
+                validator_index INTEGER NOT NULL,
+                duty TEXT NOT NULL,
+                slot INTEGER NOT NULL,
+                committee_position INTEGER,
+                signed_at_ms INTEGER NOT NULL,
+                published_at_ms INTEGER,
+                beacon_node TEXT,
+                outcome TEXT,
+                inclusion_delay INTEGER,
+                head_correct BOOLEAN,
+                target_correct BOOLEAN,
+                UNIQUE (public_key, duty, slot)
+            );
+            CREATE INDEX IF NOT EXISTS duties_by_slot ON duties (duty, slot);",
+        )?;
+
+        let (writer, writes) = channel();
+        let writer_pool = conn_pool.clone();
+        thread::Builder::new()
+            .name("duty_history".into())
+            .spawn(move || run_writer(&writer_pool, &writes))?;
+
+        Ok(Self { conn_pool, writer })
+    }
+
+    /// Record that the validator signed the message for a duty.
+    ///
+    /// Only the first signature for each duty is recorded. The record is written in the
+    /// background and errors are logged rather than returned, so that they never interfere with
+    /// the duty itself.
+    pub fn record_signed(
+        &self,
+        duty: DutyKind,
+        slot: Slot,
+        validator_pubkey: &PublicKeyBytes,
+        validator_index: u64,
+        committee_position: Option<u64>,
+    ) {
+        self.queue(Write::Signed {
+            duty,
+            slot,
+            validator_pubkey: *validator_pubkey,
+            validator_index,
+            committee_position,
+            signed_at_ms: now_millis(),
+        });
+    }
+
+    /// Record that the messages for a duty of the given validators were published to
+    /// `beacon_node`.
+    ///
+    /// Only the first publication of each duty is recorded. The record is written in the
+    /// background and errors are logged rather than returned.
+    pub fn record_published(
+        &self,
+        duty: DutyKind,
+        slot: Slot,
+        validator_indices: &[u64],
+        beacon_node: &str,
+    ) {
+        self.queue(Write::Published {
+            duty,
+            slot,
+            validator_indices: validator_indices.to_vec(),
+            beacon_node: beacon_node.to_owned(),
+            published_at_ms: now_millis(),
+        });
+    }
+
+    /// Block until all records queued so far have been written.
+    pub fn flush(&self) {
+        let (ack_tx, ack_rx) = sync_channel(1);
+        self.queue(Write::Flush(ack_tx));
+        // An error means the writer has stopped, in which case there is nothing to wait for.
+        let _ = ack_rx.recv();
+    }
+
+    fn queue(&self, write: Write) {
+        if self.writer.send(write).is_err() {
+            warn!("Duty history writer has stopped, record dropped");
+        }
+    }
+
+    /// Return the duties of kind `duty` between `start_slot` and `end_slot` (inclusive) which
+    /// don't have an outcome yet.
+    pub fn pending(
+        &self,
+        duty: DutyKind,
+        start_slot: Slot,
+        end_slot: Slot,
+    ) -> Result<Vec<PendingDuty>, Error> {
+        let conn = self.conn_pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT slot, validator_index, committee_position FROM duties
+             WHERE duty = ?1 AND slot >= ?2 AND slot <= ?3 AND outcome IS NULL
+             ORDER BY slot ASC",
+        )?;
+        let pending = stmt
+            .query_map(params![duty.as_str(), start_slot, end_slot], |row| {
+                Ok(PendingDuty {
+                    slot: row.get(0)?,
+                    validator_index: row.get(1)?,
+                    committee_position: row.get(2)?,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+        Ok(pending)
+    }
+
+    /// Record the outcomes of duties of kind `duty`.
+    pub fn record_outcomes(
+        &self,
+        duty: DutyKind,
+        outcomes: &[(PendingDuty, ConfirmedOutcome)],
+    ) -> Result<(), Error> {
+        let mut conn = self.conn_pool.get()?;
+        let txn = conn.transaction()?;
+        {
+            let mut stmt = txn.prepare(
+                "UPDATE duties
+                 SET outcome = ?1, inclusion_delay = ?2, head_correct = ?3, target_correct = ?4
+                 WHERE duty = ?5 AND slot = ?6 AND validator_index = ?7",
+            )?;
+            for (pending, outcome) in outcomes {
+                stmt.execute(params![
+                    outcome.outcome.as_str(),
+                    outcome.inclusion_delay,
+                    outcome.head_correct,
+                    outcome.target_correct,
+                    duty.as_str(),
+                    pending.slot,
+                    pending.validator_index
+                ])?;
+            }
+        }
+        txn.commit()?;
+        Ok(())
+    }
+
+    /// Return the duties of a validator matching `query`, optionally only those of kind `duty`,
+    /// most recent first.
+    pub fn history(
+        &self,
+        validator_pubkey: &PublicKeyBytes,
+        duty: Option<DutyKind>,
+        query: &DutyHistoryQuery,
+    ) -> Result<Vec<DutyRecord>, Error> {
+        let conn = self.conn_pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT duty, slot, validator_index, signed_at_ms, published_at_ms, beacon_node,
+                outcome, inclusion_delay, head_correct, target_correct
+             FROM duties
+             WHERE public_key = ?1
+                AND (?2 IS NULL OR duty = ?2)
+                AND (?3 IS NULL OR slot >= ?3)
+                AND (?4 IS NULL OR slot <= ?4)
+             ORDER BY slot DESC, duty ASC
+             LIMIT ?5",
+        )?;
+        let rows = stmt
+            .query_map(
+                params![
+                    validator_pubkey.as_hex_string(),
+                    duty.map(|duty| duty.as_str()),
+                    query.start_slot,
+                    query.end_slot,
+                    query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT)
+                ],
+                |row| Ok(decode_record(row)),
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter().collect()
+    }
+
+    /// Delete all duties from before `slot`.
+    pub fn prune(&self, slot: Slot) -> Result<(), Error> {
+        self.conn_pool
+            .get()?
+            .execute("DELETE FROM duties WHERE slot < ?1", params![slot])?;
+        Ok(())
+    }
+}
+
+/// Write queued records until every `DutyHistory` has been dropped.
+///
+/// All records queued while a batch is being written are written together in the next
+/// transaction.
+fn run_writer(conn_pool: &Pool, writes: &Receiver<Write>) {
+    while let Ok(write) = writes.recv() {
+        let mut batch = vec![write];
+        batch.extend(writes.try_iter());
+        let (flushes, batch): (Vec<_>, Vec<_>) = batch
+            .into_iter()
+            .partition(|write| matches!(write, Write::Flush(_)));
+
+        if let Err(error) = write_batch(conn_pool, &batch) {
+            warn!(?error, count = batch.len(), "Failed to record duties");
+        }
+
+        for flush in flushes {
+            if let Write::Flush(ack) = flush {
+                let _ = ack.send(());
+            }
+        }
+    }
+}
+
+fn write_batch(conn_pool: &Pool, batch: &[Write]) -> Result<(), Error> {
+    if batch.is_empty() {
+        return Ok(());
+    }
+
+    let mut conn = conn_pool.get()?;
+    let txn = conn.transaction()?;
+    {
+        let mut insert_signed = txn.prepare(
+            "INSERT INTO duties
+                (public_key, validator_index, duty, slot, committee_position, signed_at_ms)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT (public_key, duty, slot) DO NOTHING",
+        )?;
+        let mut update_published = txn.prepare(
+            "UPDATE duties SET published_at_ms = ?1, beacon_node = ?2
+             WHERE duty = ?3 AND slot = ?4 AND validator_index = ?5
+                AND published_at_ms IS NULL",
+        )?;
+        for write in batch {
+            match write {
+                Write::Signed {
+                    duty,
+                    slot,
+                    validator_pubkey,
+                    validator_index,
+                    committee_position,
+                    signed_at_ms,
+                } => {
+                    insert_signed.execute(params![
+                        validator_pubkey.as_hex_string(),
+                        validator_index,
+                        duty.as_str(),
+                        slot,
+                        committee_position,
+                        signed_at_ms
+                    ])?;
+                }
+                Write::Published {
+                    duty,
+                    slot,
+                    validator_indices,
+                    beacon_node,
+                    published_at_ms,
+                } => {
+                    for validator_index in validator_indices {
+                        update_published.execute(params![
+                            published_at_ms,
+                            beacon_node,
+                            duty.as_str(),
+                            slot,
+                            validator_index
+                        ])?;
+                    }
+                }
+                Write::Flush(_) => {}
+            }
+        }
+    }
+    txn.commit()?;
+    Ok(())
+}
+
+fn decode_record(row: &Row) -> Result<DutyRecord, Error> {
+    let duty: String = row.get(0)?;
+    let outcome: Option<String> = row.get(6)?;
+    Ok(DutyRecord {
+        duty: duty.parse().map_err(Error::InvalidRecord)?,
+        slot: row.get(1)?,
+        validator_index: row.get(2)?,
+        signed_at_ms: row.get(3)?,
+        published_at_ms: row.get(4)?,
+        beacon_node: row.get(5)?,
+        outcome: outcome
+            .map(|outcome| outcome.parse())
+            .transpose()
+            .map_err(Error::InvalidRecord)?,
+        inclusion_delay: row.get(7)?,
+        head_correct: row.get(8)?,
+        target_correct: row.get(9)?,
+    })
+}
+
+fn now_millis() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    u64::try_from(now.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// The beacon nodes which accepted a published message.
+///
+/// Record each beacon node from within the closure passed to `BeaconNodeFallback`, once the
+/// request to it has succeeded. More than one beacon node is recorded when messages are broadcast.
+#[derive(Debug, Default)]
+pub struct PublishedVia {
+    beacon_nodes: Mutex<Vec<String>>,
+}
+
+impl PublishedVia {
+    pub fn record(&self, beacon_node: &impl Display) {
+        let beacon_node = beacon_node.to_string();
+        let mut beacon_nodes = self.beacon_nodes.lock();
+        if !beacon_nodes.contains(&beacon_node) {
+            beacon_nodes.push(beacon_node);
+        }
+    }
+
+    /// The beacon nodes, separated by commas.
+    pub fn beacon_nodes(&self) -> String {
+        self.beacon_nodes.lock().join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn pubkey(byte: u8) -> PublicKeyBytes {
+        let mut bytes = [0; 48];
+        bytes[0] = byte;
+        PublicKeyBytes::deserialize(&bytes).unwrap()
+    }
+
+    #[test]
+    fn record_and_query() {
+        let dir = tempdir().unwrap();
+        let history = DutyHistory::open_or_create(&dir.path().join(DUTY_HISTORY_FILENAME)).unwrap();
+        let (pk_a, pk_b) = (pubkey(1), pubkey(2));
+
+        for slot in 1..=4 {
+            history.record_signed(DutyKind::Attestation, Slot::new(slot), &pk_a, 0, None);
+            history.record_signed(DutyKind::Attestation, Slot::new(slot), &pk_b, 1, None);
+        }
+        history.record_signed(
+            DutyKind::PayloadAttestation,
+            Slot::new(2),
+            &pk_a,
+            0,
+            Some(7),
+        );
+        history.record_published(DutyKind::Attestation, Slot::new(2), &[0, 1], "http://bn_a/");
+        // Only the first publication is recorded.
+        history.record_published(DutyKind::Attestation, Slot::new(2), &[0], "http://bn_b/");
+        history.flush();
+
+        let pending = history
+            .pending(DutyKind::PayloadAttestation, Slot::new(0), Slot::new(10))
+            .unwrap();
+        assert_eq!(
+            pending,
+            vec![PendingDuty {
+                slot: Slot::new(2),
+                validator_index: 0,
+                committee_position: Some(7),
+            }]
+        );
+
+        let attestations = history
+            .pending(DutyKind::Attestation, Slot::new(2), Slot::new(2))
+            .unwrap();
+        assert_eq!(attestations.len(), 2);
+        let outcome = ConfirmedOutcome {
+            inclusion_delay: Some(1),
+            head_correct: Some(true),
+            target_correct: Some(false),
+            ..ConfirmedOutcome::included()
+        };
+        history
+            .record_outcomes(
+                DutyKind::Attestation,
+                &[
+                    (attestations[0], outcome),
+                    (attestations[1], ConfirmedOutcome::missed()),
+                ],
+            )
+            .unwrap();
+        assert!(
+            history
+                .pending(DutyKind::Attestation, Slot::new(2), Slot::new(2))
+                .unwrap()
+                .is_empty()
+        );
+
+        let records = history
+            .history(&pk_a, None, &DutyHistoryQuery::default())
+            .unwrap();
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[0].slot, Slot::new(4));
+
+        let records = history
+            .history(
+                &pk_a,
+                Some(DutyKind::Attestation),
+                &DutyHistoryQuery {
+                    start_slot: Some(Slot::new(2)),
+                    end_slot: Some(Slot::new(3)),
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].slot, Slot::new(3));
+        assert_eq!(records[0].outcome, None);
+
+        let record = &history
+            .history(
+                &pk_a,
+                Some(DutyKind::Attestation),
+                &DutyHistoryQuery {
+                    start_slot: Some(Slot::new(2)),
+                    end_slot: Some(Slot::new(2)),
+                    limit: None,
+                },
+            )
+            .unwrap()[0];
+        assert_eq!(record.beacon_node.as_deref(), Some("http://bn_a/"));
+        assert!(record.published_at_ms.unwrap() >= record.signed_at_ms);
+        assert_eq!(record.outcome, Some(DutyOutcome::Included));
+        assert_eq!(record.inclusion_delay, Some(1));
+        assert_eq!(record.head_correct, Some(true));
+        assert_eq!(record.target_correct, Some(false));
+
+        history.prune(Slot::new(3)).unwrap();
+        let records = history
+            .history(&pk_b, None, &DutyHistoryQuery::default())
+            .unwrap();
+        assert_eq!(
+            records.iter().map(|record| record.slot).collect::<Vec<_>>(),
+            vec![Slot::new(4), Slot::new(3)]
+        );
+    }
+
+    #[test]
+    fn reopen() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(DUTY_HISTORY_FILENAME);
+        let pk = pubkey(1);
+        let history = DutyHistory::open_or_create(&path).unwrap();
+        history.record_signed(DutyKind::Block, Slot::new(1), &pk, 0, None);
+        history.flush();
+        drop(history);
+
+        let history = DutyHistory::open_or_create(&path).unwrap();
+        let records = history
+            .history(&pk, Some(DutyKind::Block), &DutyHistoryQuery::default())
+            .unwrap();
+        assert_eq!(records.len(), 1);
+    }
+
+    #[test]
+    fn published_via() {
+        let published_via = PublishedVia::default();
+        assert_eq!(published_via.beacon_nodes(), "");
+        published_via.record(&"http://bn_a/");
+        published_via.record(&"http://bn_b/");
+        published_via.record(&"http://bn_a/");
+        assert_eq!(published_via.beacon_nodes(), "http://bn_a/,http://bn_b/");
+    }
+}
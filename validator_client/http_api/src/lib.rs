@@ -28,11 +28,13 @@ use create_validator::{
     create_validators_mnemonic, create_validators_web3signer, get_voting_password_storage,
 };
 use directory::{DEFAULT_HARDCODED_NETWORK, DEFAULT_ROOT_DIR, DEFAULT_VALIDATOR_DIR};
+use duty_history::DutyHistory;
 use eth2::vibehouse_vc::{
     std_types::{AuthResponse, GetFeeRecipientResponse, GetGasLimitResponse},
     types::{
-        self as api_types, GenericResponse, GetGraffitiResponse, Graffiti, PublicKey,
-        PublicKeyBytes, SetGraffitiRequest, UpdateCandidatesRequest, UpdateCandidatesResponse,
+        self as api_types, DutyHistoryQuery, DutyKind, GenericResponse, GetGraffitiResponse,
+        Graffiti, PublicKey, PublicKeyBytes, SetGraffitiRequest, UpdateCandidatesRequest,
+        UpdateCandidatesResponse,
     },
 };
 use health_metrics::observe::Observe;
@@ -97,6 +99,7 @@ pub struct Context<T: SlotClock, E> {
     pub api_secret: ApiSecret,
     pub block_service: Option<BlockService<VibehouseValidatorStore<T, E>, T>>,
     pub validator_store: Option<Arc<VibehouseValidatorStore<T, E>>>,
+    pub duty_history: Option<Arc<DutyHistory>>,
     pub validator_dir: Option<PathBuf>,
     pub secrets_dir: Option<PathBuf>,
     pub graffiti_file: Option<GraffitiFile>,
@@ -229,6 +232,14 @@ pub fn serve<T: 'static + SlotClock + Clone, E: EthSpec>(
             "/vibehouse/validators/{validator_pubkey}",
             get(get_vibehouse_validators_pubkey::<T, E>),
         )
+        .route(
+            "/vibehouse/validators/{validator_pubkey}/history",
+            get(get_vibehouse_validators_history::<T, E>),
+        )
+        .route(
+            "/vibehouse/validators/{validator_pubkey}/history/{duty}",
+            get(get_vibehouse_validators_history_duty::<T, E>),
+        )
         .route("/vibehouse/ui/health", get(get_vibehouse_ui_health::<T, E>))
         .route(
             "/vibehouse/ui/graffiti",
@@ -401,6 +412,16 @@ fn get_validator_store<T: SlotClock, E: EthSpec>(
         .ok_or_else(|| ApiError::NotFound("validator store is not initialized.".to_string()))
 }
 
+fn get_duty_history<T: SlotClock, E: EthSpec>(
+    state: &AppState<T, E>,
+) -> Result<Arc<DutyHistory>, ApiError> {
+    state
+        .ctx
+        .duty_history
+        .clone()
+        .ok_or_else(|| ApiError::NotFound("duty history is not enabled.".to_string()))
+}
+
 fn get_validator_dir<T: SlotClock, E: EthSpec>(
     state: &AppState<T, E>,
 ) -> Result<PathBuf, ApiError> {
@@ -508,6 +529,38 @@ async fn get_vibehouse_validators_pubkey<T: 'static + SlotClock + Clone, E: EthS
     .await
 }
 
+async fn get_vibehouse_validators_history<T: 'static + SlotClock + Clone, E: EthSpec>(
+    State(state): State<SharedState<T, E>>,
+    Path(validator_pubkey): Path<PublicKey>,
+    Query(query): Query<DutyHistoryQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    get_duty_history_records(&state, validator_pubkey, None, query).await
+}
+
+async fn get_vibehouse_validators_history_duty<T: 'static + SlotClock + Clone, E: EthSpec>(
+    State(state): State<SharedState<T, E>>,
+    Path((validator_pubkey, duty)): Path<(PublicKey, DutyKind)>,
+    Query(query): Query<DutyHistoryQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    get_duty_history_records(&state, validator_pubkey, Some(duty), query).await
+}
+
+async fn get_duty_history_records<T: 'static + SlotClock + Clone, E: EthSpec>(
+    state: &AppState<T, E>,
+    validator_pubkey: PublicKey,
+    duty: Option<DutyKind>,
+    query: DutyHistoryQuery,
+) -> Result<Json<GenericResponse<Vec<api_types::DutyRecord>>>, ApiError> {
+    let duty_history = get_duty_history(state)?;
+    blocking_json(move || {
+        duty_history
+            .history(&PublicKeyBytes::from(&validator_pubkey), duty, &query)
+            .map(GenericResponse::from)
+            .map_err(|e| ApiError::ServerError(format!("unable to read duty history: {e:?}")))
+    })
+    .await
+}
+
 async fn get_vibehouse_ui_health<T: 'static + SlotClock + Clone, E: EthSpec>(
     State(state): State<SharedState<T, E>>,
 ) -> Result<impl IntoResponse, ApiError> {
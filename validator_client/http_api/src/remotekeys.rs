@@ -40,6 +40,8 @@ pub(crate) fn list<T: SlotClock + 'static, E: EthSpec>(
                         readonly: false,
                     })
                 }
+                // A threshold validator has several remote signers, which this API can't list.
+                SigningDefinition::Threshold(_) => None,
             }
         })
         .collect::<Vec<_>>();
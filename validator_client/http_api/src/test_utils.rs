@@ -137,6 +137,7 @@ impl ApiTester {
             validator_dir: Some(validator_dir.path().into()),
             secrets_dir: Some(secrets_dir.path().into()),
             validator_store: Some(validator_store.clone()),
+            duty_history: None,
             graffiti_file: None,
             graffiti_flag: Some(Graffiti::default()),
             spec: spec.clone(),
@@ -12,6 +12,7 @@ use account_utils::{
     random_password_string, validator_definitions::ValidatorDefinitions,
 };
 use deposit_contract::decode_eth1_tx_data;
+use duty_history::{DUTY_HISTORY_FILENAME, DutyHistory};
 use eth2::{
     Error as ApiError,
     types::ErrorMessage as ApiErrorMessage,
@@ -43,6 +44,7 @@ struct ApiTester {
     client: ValidatorClientHttpClient,
     initialized_validators: Arc<RwLock<InitializedValidators>>,
     validator_store: Arc<VibehouseValidatorStore<TestingSlotClock, E>>,
+    duty_history: Arc<DutyHistory>,
     url: SensitiveUrl,
     slot_clock: TestingSlotClock,
     spec: Arc<ChainSpec>,
@@ -83,6 +85,9 @@ impl ApiTester {
         let slashing_db_path = validator_dir.path().join(SLASHING_PROTECTION_FILENAME);
         let slashing_protection = SlashingDatabase::open_or_create(&slashing_db_path).unwrap();
 
+        let duty_history_path = validator_dir.path().join(DUTY_HISTORY_FILENAME);
+        let duty_history = Arc::new(DutyHistory::open_or_create(&duty_history_path).unwrap());
+
         let genesis_time: u64 = 0;
         let slot_clock = TestingSlotClock::new(
             Slot::new(0),
@@ -116,6 +121,7 @@ impl ApiTester {
             validator_dir: Some(validator_dir.path().into()),
             secrets_dir: Some(secrets_dir.path().into()),
             validator_store: Some(validator_store.clone()),
+            duty_history: Some(duty_history.clone()),
             graffiti_file: None,
             graffiti_flag: Some(Graffiti::default()),
             spec: spec.clone(),
@@ -151,6 +157,7 @@ impl ApiTester {
             client,
             initialized_validators,
             validator_store,
+            duty_history,
             url,
             slot_clock,
             spec,
@@ -232,6 +239,80 @@ impl ApiTester {
         self
     }
 
+    pub(crate) async fn test_get_vibehouse_validators_history(self) -> Self {
+        let validator_pubkey = PublicKeyBytes::deserialize(&[1; 48]).unwrap();
+        let other_pubkey = PublicKeyBytes::deserialize(&[2; 48]).unwrap();
+        for slot in 1..=4 {
+            self.duty_history.record_signed(
+                DutyKind::Attestation,
+                Slot::new(slot),
+                &validator_pubkey,
+                0,
+                None,
+            );
+        }
+        self.duty_history
+            .record_signed(DutyKind::Block, Slot::new(3), &validator_pubkey, 0, None);
+        self.duty_history
+            .record_signed(DutyKind::Block, Slot::new(5), &other_pubkey, 1, None);
+        self.duty_history.record_published(
+            DutyKind::Block,
+            Slot::new(3),
+            &[0],
+            "http://localhost:5052/",
+        );
+        self.duty_history.flush();
+
+        let records = self
+            .client
+            .get_vibehouse_validators_history(&validator_pubkey, None, &DutyHistoryQuery::default())
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[0].slot, Slot::new(4));
+        assert!(records.iter().all(|record| record.validator_index == 0));
+
+        let blocks = self
+            .client
+            .get_vibehouse_validators_history(
+                &validator_pubkey,
+                Some(DutyKind::Block),
+                &DutyHistoryQuery::default(),
+            )
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].slot, Slot::new(3));
+        assert_eq!(
+            blocks[0].beacon_node.as_deref(),
+            Some("http://localhost:5052/")
+        );
+        assert!(blocks[0].published_at_ms.is_some());
+        assert_eq!(blocks[0].outcome, None);
+
+        let query = DutyHistoryQuery {
+            start_slot: Some(Slot::new(2)),
+            end_slot: Some(Slot::new(3)),
+            limit: Some(1),
+        };
+        let attestations = self
+            .client
+            .get_vibehouse_validators_history(
+                &validator_pubkey,
+                Some(DutyKind::Attestation),
+                &query,
+            )
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(attestations.len(), 1);
+        assert_eq!(attestations[0].slot, Slot::new(3));
+
+        self
+    }
+
     pub(crate) async fn test_get_vibehouse_version(self) -> Self {
         let result = self.client.get_vibehouse_version().await.unwrap().data;
 
@@ -886,6 +967,16 @@ async fn routes_with_invalid_auth() {
                 .await
         })
         .await
+        .test_with_invalid_auth(|client| async move {
+            client
+                .get_vibehouse_validators_history(
+                    &PublicKeyBytes::empty(),
+                    None,
+                    &DutyHistoryQuery::default(),
+                )
+                .await
+        })
+        .await
         .test_with_invalid_auth(|client| async move {
             client
                 .post_vibehouse_validators(vec![ValidatorRequest {
@@ -1004,6 +1095,14 @@ async fn simple_getters() {
         .await;
 }
 
+#[tokio::test]
+async fn validator_duty_history() {
+    ApiTester::new()
+        .await
+        .test_get_vibehouse_validators_history()
+        .await;
+}
+
 #[tokio::test]
 async fn hd_validator_creation() {
     ApiTester::new()
@@ -11,8 +11,8 @@ pub mod key_cache;
 use account_utils::{
     read_password, read_password_from_user, read_password_string,
     validator_definitions::{
-        self, CONFIG_FILENAME, SigningDefinition, ValidatorDefinition, ValidatorDefinitions,
-        Web3SignerDefinition,
+        self, CONFIG_FILENAME, SigningDefinition, ThresholdDefinition, ValidatorDefinition,
+        ValidatorDefinitions, Web3SignerDefinition,
     },
 };
 use eth2_keystore::Keystore;
@@ -21,7 +21,7 @@ use metrics::set_gauge;
 use parking_lot::{MappedMutexGuard, Mutex, MutexGuard};
 use reqwest::{Certificate, Client, Error as ReqwestError, Identity};
 use serde::{Deserialize, Serialize};
-use signing_method::SigningMethod;
+use signing_method::{SigningMethod, ThresholdShare};
 use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
 use std::io::{self, Read};
@@ -129,6 +129,8 @@ pub enum Error {
     InvalidWeb3SignerClientIdentityCertificateFile(io::Error),
     InvalidWeb3SignerClientIdentityCertificate(ReqwestError),
     UnableToBuildWeb3SignerClient(ReqwestError),
+    /// The threshold or share indices of a threshold validator are invalid.
+    InvalidThresholdDefinition(String),
     /// Unable to apply an action to a validator.
     InvalidActionOnValidator,
     UnableToReadValidatorPassword(String),
@@ -168,8 +170,8 @@ impl InitializedValidator {
                 option_lockfile.as_mut()
             })
             .ok(),
-            // Web3Signer validators do not have any lockfiles.
-            SigningMethod::Web3Signer { .. } => None,
+            // Remote signer validators do not have any lockfiles.
+            SigningMethod::Web3Signer { .. } | SigningMethod::Threshold { .. } => None,
         }
     }
 
@@ -316,43 +318,8 @@ impl InitializedValidator {
             SigningDefinition::Web3Signer(web3_signer) => {
                 let signing_url = build_web3_signer_url(&web3_signer.url, &def.voting_public_key)
                     .map_err(|e| Error::InvalidWeb3SignerUrl(e.to_string()))?;
-
-                let request_timeout = web3_signer
-                    .request_timeout_ms
-                    .map_or(DEFAULT_REMOTE_SIGNER_REQUEST_TIMEOUT, Duration::from_millis);
-
-                // Check if a client has already been initialized for this remote signer url.
-                let http_client = if let Some(client_map) = web3_signer_client_map {
-                    if let Some(client) = client_map.get(&web3_signer) {
-                        client.clone()
-                    } else {
-                        let client = build_web3_signer_client(
-                            web3_signer.root_certificate_path.clone(),
-                            web3_signer.client_identity_path.clone(),
-                            web3_signer.client_identity_password.clone(),
-                            request_timeout,
-                            config.web3_signer_keep_alive_timeout,
-                            config.web3_signer_max_idle_connections,
-                        )?;
-                        client_map.insert(web3_signer, client.clone());
-                        client
-                    }
-                } else {
-                    // There are no clients in the map.
-                    let mut new_web3_signer_client_map: HashMap<Web3SignerDefinition, Client> =
-                        HashMap::new();
-                    let client = build_web3_signer_client(
-                        web3_signer.root_certificate_path.clone(),
-                        web3_signer.client_identity_path.clone(),
-                        web3_signer.client_identity_password.clone(),
-                        request_timeout,
-                        config.web3_signer_keep_alive_timeout,
-                        config.web3_signer_max_idle_connections,
-                    )?;
-                    new_web3_signer_client_map.insert(web3_signer, client.clone());
-                    *web3_signer_client_map = Some(new_web3_signer_client_map);
-                    client
-                };
+                let http_client =
+                    get_or_build_web3_signer_client(web3_signer, web3_signer_client_map, config)?;
 
                 SigningMethod::Web3Signer {
                     signing_url,
@@ -360,6 +327,46 @@ impl InitializedValidator {
                     voting_public_key: def.voting_public_key,
                 }
             }
+            SigningDefinition::Threshold(ThresholdDefinition { threshold, shares }) => {
+                if threshold == 0 || threshold > shares.len() {
+                    return Err(Error::InvalidThresholdDefinition(format!(
+                        "threshold {threshold} is not between 1 and the number of shares ({})",
+                        shares.len()
+                    )));
+                }
+                let mut indices = HashSet::new();
+                let shares = shares
+                    .into_iter()
+                    .map(|share| {
+                        if share.index == 0 || !indices.insert(share.index) {
+                            return Err(Error::InvalidThresholdDefinition(format!(
+                                "share index {} is zero or repeated",
+                                share.index
+                            )));
+                        }
+                        let signing_url =
+                            build_web3_signer_url(&share.signer.url, &share.public_key)
+                                .map_err(|e| Error::InvalidWeb3SignerUrl(e.to_string()))?;
+                        let http_client = get_or_build_web3_signer_client(
+                            share.signer,
+                            web3_signer_client_map,
+                            config,
+                        )?;
+                        Ok(ThresholdShare {
+                            index: share.index,
+                            public_key: share.public_key,
+                            signing_url,
+                            http_client,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                SigningMethod::Threshold {
+                    threshold,
+                    shares,
+                    voting_public_key: def.voting_public_key,
+                }
+            }
         };
 
         Ok(Self {
@@ -380,6 +387,9 @@ impl InitializedValidator {
             SigningMethod::LocalKeystore { voting_keypair, .. } => &voting_keypair.pk,
             SigningMethod::Web3Signer {
                 voting_public_key, ..
+            }
+            | SigningMethod::Threshold {
+                voting_public_key, ..
             } => voting_public_key,
         }
     }
@@ -411,6 +421,32 @@ fn build_web3_signer_url(base_url: &str, voting_public_key: &PublicKey) -> Resul
     Url::parse(base_url)?.join(&format!("api/v1/eth2/sign/{voting_public_key}"))
 }
 
+/// Return the client for `web3_signer`, building it if no validator has used it yet.
+fn get_or_build_web3_signer_client(
+    web3_signer: Web3SignerDefinition,
+    web3_signer_client_map: &mut Option<HashMap<Web3SignerDefinition, Client>>,
+    config: &Config,
+) -> Result<Client, Error> {
+    let client_map = web3_signer_client_map.get_or_insert_with(HashMap::new);
+    if let Some(client) = client_map.get(&web3_signer) {
+        return Ok(client.clone());
+    }
+
+    let request_timeout = web3_signer
+        .request_timeout_ms
+        .map_or(DEFAULT_REMOTE_SIGNER_REQUEST_TIMEOUT, Duration::from_millis);
+    let client = build_web3_signer_client(
+        web3_signer.root_certificate_path.clone(),
+        web3_signer.client_identity_path.clone(),
+        web3_signer.client_identity_password.clone(),
+        request_timeout,
+        config.web3_signer_keep_alive_timeout,
+        config.web3_signer_max_idle_connections,
+    )?;
+    client_map.insert(web3_signer, client.clone());
+    Ok(client)
+}
+
 fn build_web3_signer_client(
     root_certificate_path: Option<PathBuf>,
     client_identity_path: Option<PathBuf>,
@@ -636,7 +672,9 @@ impl InitializedValidators {
 
                     Some(KeystoreAndPassword { keystore, password })
                 }
-                SigningDefinition::Web3Signer(_) if !is_local_keystore => {
+                SigningDefinition::Web3Signer(_) | SigningDefinition::Threshold(_)
+                    if !is_local_keystore =>
+                {
                     def.enabled = false;
                     None
                 }
@@ -1133,7 +1171,7 @@ impl InitializedValidators {
                     definitions_map.insert(*key_store.uuid(), def);
                 }
                 // Remote signer validators don't interact with the key cache.
-                SigningDefinition::Web3Signer { .. } => (),
+                SigningDefinition::Web3Signer { .. } | SigningDefinition::Threshold(_) => (),
             }
         }
 
@@ -1176,7 +1214,7 @@ impl InitializedValidators {
                     public_keys.push(def.voting_public_key.clone());
                 }
                 // Remote signer validators don't interact with the key cache.
-                SigningDefinition::Web3Signer { .. } => (),
+                SigningDefinition::Web3Signer { .. } | SigningDefinition::Threshold(_) => (),
             }
         }
 
@@ -1298,7 +1336,12 @@ impl InitializedValidators {
                             }
                         }
                     }
-                    SigningDefinition::Web3Signer(Web3SignerDefinition { .. }) => {
+                    SigningDefinition::Web3Signer(_) | SigningDefinition::Threshold(_) => {
+                        let signing_method = if def.signing_definition.is_threshold() {
+                            "threshold"
+                        } else {
+                            "remote_signer"
+                        };
                         match InitializedValidator::from_definition(
                             def.clone(),
                             &mut key_cache,
@@ -1313,7 +1356,7 @@ impl InitializedValidators {
                                     .insert(init.voting_public_key().compress(), init);
 
                                 info!(
-                                    signing_method = "remote_signer",
+                                    signing_method,
                                     voting_pubkey = format!("{:?}", def.voting_public_key),
                                     "Enabled validator"
                                 );
@@ -1321,7 +1364,7 @@ impl InitializedValidators {
                             Err(e) => {
                                 error!(
                                     error = format!("{:?}", e),
-                                    signing_method = "remote_signer",
+                                    signing_method,
                                     validator = format!("{:?}", def.voting_public_key),
                                     "Failed to initialize validator"
                                 );
@@ -1344,7 +1387,7 @@ impl InitializedValidators {
                         }
                     }
                     // Remote signers do not interact with the key cache.
-                    SigningDefinition::Web3Signer { .. } => (),
+                    SigningDefinition::Web3Signer { .. } | SigningDefinition::Threshold(_) => (),
                 }
 
                 info!(
@@ -1415,7 +1458,7 @@ impl InitializedValidators {
                     }
                 }
                 // Remote signers don't have passwords.
-                SigningDefinition::Web3Signer { .. } => (),
+                SigningDefinition::Web3Signer { .. } | SigningDefinition::Threshold(_) => (),
             }
         }
 
@@ -2,14 +2,18 @@
 //!
 //! - Via a local `Keypair`.
 //! - Via a remote signer (Web3Signer)
+//! - Via several remote signers holding shares of the key, whose signatures are combined.
 
 use eth2_keystore::Keystore;
+use futures::stream::{FuturesUnordered, StreamExt};
 use lockfile::Lockfile;
 use parking_lot::Mutex;
 use reqwest::{Client, header::ACCEPT};
 use std::path::PathBuf;
 use std::sync::Arc;
 use task_executor::TaskExecutor;
+use tracing::warn;
+use types::application_domain::ApplicationDomain;
 use types::{
     AbstractExecPayload, AggregateAndProofRef, AttestationData, BeaconBlock, ChainSpec,
     ContributionAndProof, Domain, Epoch, EthSpec, ExecutionPayloadEnvelope, Fork, FullPayload,
@@ -36,6 +40,15 @@ pub enum Error {
     TokioJoin(String),
     MergeForkNotSupported,
     GenesisForkVersionRequired,
+    /// Fewer than `threshold` share signers returned a valid partial signature.
+    ThresholdNotReached {
+        valid_shares: usize,
+        threshold: usize,
+    },
+    ThresholdCombineFailed(String),
+    /// The combined signature is not valid for the voting public key, which means the share
+    /// public keys are not shares of it.
+    InvalidThresholdSignature,
 }
 
 /// Enumerates all messages that can be signed by a validator.
@@ -88,6 +101,28 @@ impl<E: EthSpec, Payload: AbstractExecPayload<E>> SignableMessage<'_, E, Payload
             SignableMessage::InclusionList(il) => il.signing_root(domain),
         }
     }
+
+    /// Returns the domain that the contained message must be signed with.
+    pub fn domain(&self) -> Domain {
+        match self {
+            SignableMessage::RandaoReveal(_) => Domain::Randao,
+            SignableMessage::BeaconBlock(_) => Domain::BeaconProposer,
+            SignableMessage::AttestationData(_) => Domain::BeaconAttester,
+            SignableMessage::SignedAggregateAndProof(_) => Domain::AggregateAndProof,
+            SignableMessage::SelectionProof(_) => Domain::SelectionProof,
+            SignableMessage::SyncSelectionProof(_) => Domain::SyncCommitteeSelectionProof,
+            SignableMessage::SyncCommitteeSignature { .. } => Domain::SyncCommittee,
+            SignableMessage::SignedContributionAndProof(_) => Domain::ContributionAndProof,
+            SignableMessage::ValidatorRegistration(_) => {
+                Domain::ApplicationMask(ApplicationDomain::Builder)
+            }
+            SignableMessage::VoluntaryExit(_) => Domain::VoluntaryExit,
+            SignableMessage::ExecutionPayloadEnvelope(_) => Domain::BeaconBuilder,
+            SignableMessage::PayloadAttestationData(_) => Domain::PtcAttester,
+            SignableMessage::ProposerPreferences(_) => Domain::ProposerPreferences,
+            SignableMessage::InclusionList(_) => Domain::InclusionListCommittee,
+        }
+    }
 }
 
 /// A method used by a validator to sign messages.
@@ -110,6 +145,28 @@ pub enum SigningMethod {
         http_client: Client,
         voting_public_key: PublicKey,
     },
+    /// A validator whose key is split into shares held by Web3Signer servers, any `threshold` of
+    /// which can sign.
+    Threshold {
+        threshold: usize,
+        shares: Vec<ThresholdShare>,
+        voting_public_key: PublicKey,
+    },
+}
+
+/// A share of a threshold validator key, held by a Web3Signer server.
+pub struct ThresholdShare {
+    pub index: u64,
+    pub public_key: PublicKey,
+    pub signing_url: Url,
+    pub http_client: Client,
+}
+
+impl ThresholdShare {
+    /// Identifies the signer holding this share in logs.
+    fn signer(&self) -> String {
+        self.signing_url.origin().ascii_serialization()
+    }
 }
 
 /// The additional information used to construct a signature. Mostly used for protection from replay
@@ -144,7 +201,9 @@ impl SigningMethod {
             SigningMethod::LocalKeystore { .. } => true,
             // Slashing protection is only required for remote signer keys when the configuration
             // dictates that it is desired.
-            SigningMethod::Web3Signer { .. } => enable_web3signer_slashing_protection,
+            SigningMethod::Web3Signer { .. } | SigningMethod::Threshold { .. } => {
+                enable_web3signer_slashing_protection
+            }
         }
     }
 
@@ -156,6 +215,16 @@ impl SigningMethod {
         spec: &ChainSpec,
         executor: &TaskExecutor,
     ) -> Result<Signature, Error> {
+        // A remote signer recomputes the signing root from the message type, so a message signed
+        // with the wrong domain would produce a signature that doesn't match the local one.
+        let message_type_domain = signable_message.domain();
+        if message_type_domain != signing_context.domain {
+            return Err(Error::InconsistentDomains {
+                message_type_domain,
+                domain: signing_context.domain,
+            });
+        }
+
         let domain_hash = signing_context.domain_hash(spec);
         let SigningContext {
             fork,
@@ -216,91 +285,185 @@ impl SigningMethod {
                     &[validator_metrics::WEB3SIGNER],
                 );
 
-                // Map the message into a Web3Signer type.
-                let object = match signable_message {
-                    SignableMessage::RandaoReveal(epoch) => {
-                        Web3SignerObject::RandaoReveal { epoch }
-                    }
-                    SignableMessage::BeaconBlock(block) => Web3SignerObject::beacon_block(block),
-                    SignableMessage::AttestationData(a) => Web3SignerObject::Attestation(a),
-                    SignableMessage::SignedAggregateAndProof(a) => {
-                        Web3SignerObject::AggregateAndProof(a)
-                    }
-                    SignableMessage::SelectionProof(slot) => {
-                        Web3SignerObject::AggregationSlot { slot }
-                    }
-                    SignableMessage::SyncSelectionProof(s) => {
-                        Web3SignerObject::SyncAggregatorSelectionData(s)
-                    }
-                    SignableMessage::SyncCommitteeSignature {
-                        beacon_block_root,
-                        slot,
-                    } => Web3SignerObject::SyncCommitteeMessage {
-                        beacon_block_root,
-                        slot,
-                    },
-                    SignableMessage::SignedContributionAndProof(c) => {
-                        Web3SignerObject::ContributionAndProof(c)
-                    }
-                    SignableMessage::ValidatorRegistration(v) => {
-                        Web3SignerObject::ValidatorRegistration(v)
-                    }
-                    SignableMessage::VoluntaryExit(e) => Web3SignerObject::VoluntaryExit(e),
-                    // Web3Signer doesn't support ePBS types yet.
-                    SignableMessage::ExecutionPayloadEnvelope(_) => {
-                        return Err(Error::Web3SignerRequestFailed(
-                            "Web3Signer does not support ExecutionPayloadEnvelope signing"
-                                .to_string(),
-                        ));
-                    }
-                    SignableMessage::PayloadAttestationData(_) => {
-                        return Err(Error::Web3SignerRequestFailed(
-                            "Web3Signer does not support PayloadAttestationData signing"
-                                .to_string(),
-                        ));
-                    }
-                    SignableMessage::ProposerPreferences(_) => {
-                        return Err(Error::Web3SignerRequestFailed(
-                            "Web3Signer does not support ProposerPreferences signing".to_string(),
-                        ));
-                    }
-                    SignableMessage::InclusionList(_) => {
-                        return Err(Error::Web3SignerRequestFailed(
-                            "Web3Signer does not support InclusionList signing".to_string(),
-                        ));
-                    }
-                };
-
-                // Determine the Web3Signer message type.
-                let message_type = object.message_type();
-                if matches!(message_type, MessageType::ValidatorRegistration) && fork_info.is_some()
-                {
-                    return Err(Error::GenesisForkVersionRequired);
-                }
-
-                let request = SigningRequest {
-                    message_type,
-                    fork_info,
-                    signing_root,
-                    object,
-                };
+                let request = signing_request(signable_message, signing_root, fork_info)?;
 
                 // Request a signature from the Web3Signer instance via HTTP(S).
-                let response: SigningResponse = http_client
-                    .post(signing_url.clone())
-                    .header(ACCEPT, "application/json")
-                    .json(&request)
-                    .send()
+                request_signature(http_client, signing_url, &request)
                     .await
-                    .map_err(|e| Error::Web3SignerRequestFailed(e.to_string()))?
-                    .error_for_status()
-                    .map_err(|e| Error::Web3SignerRequestFailed(e.to_string()))?
-                    .json()
+                    .map_err(|e| {
+                        if e.is_decode() {
+                            Error::Web3SignerJsonParsingFailed(e.to_string())
+                        } else {
+                            Error::Web3SignerRequestFailed(e.to_string())
+                        }
+                    })
+            }
+            SigningMethod::Threshold {
+                threshold,
+                shares,
+                voting_public_key,
+            } => {
+                let _timer = validator_metrics::start_timer_vec(
+                    &validator_metrics::SIGNING_TIMES,
+                    &[validator_metrics::THRESHOLD],
+                );
+
+                let request = signing_request(signable_message, signing_root, fork_info)?;
+                get_threshold_signature(*threshold, shares, signing_root, &request)
                     .await
-                    .map_err(|e| Error::Web3SignerJsonParsingFailed(e.to_string()))?;
+                    .and_then(|signature| {
+                        if signature.verify(voting_public_key, signing_root) {
+                            Ok(signature)
+                        } else {
+                            Err(Error::InvalidThresholdSignature)
+                        }
+                    })
+            }
+        }
+    }
+}
+
+/// Map `signable_message` into a Web3Signer signing request.
+fn signing_request<E: EthSpec, Payload: AbstractExecPayload<E>>(
+    signable_message: SignableMessage<'_, E, Payload>,
+    signing_root: Hash256,
+    fork_info: Option<ForkInfo>,
+) -> Result<SigningRequest<'_, E, Payload>, Error> {
+    // Map the message into a Web3Signer type.
+    let object = match signable_message {
+        SignableMessage::RandaoReveal(epoch) => Web3SignerObject::RandaoReveal { epoch },
+        SignableMessage::BeaconBlock(block) => Web3SignerObject::beacon_block(block),
+        SignableMessage::AttestationData(a) => Web3SignerObject::Attestation(a),
+        SignableMessage::SignedAggregateAndProof(a) => Web3SignerObject::AggregateAndProof(a),
+        SignableMessage::SelectionProof(slot) => Web3SignerObject::AggregationSlot { slot },
+        SignableMessage::SyncSelectionProof(s) => Web3SignerObject::SyncAggregatorSelectionData(s),
+        SignableMessage::SyncCommitteeSignature {
+            beacon_block_root,
+            slot,
+        } => Web3SignerObject::SyncCommitteeMessage {
+            beacon_block_root,
+            slot,
+        },
+        SignableMessage::SignedContributionAndProof(c) => Web3SignerObject::ContributionAndProof(c),
+        SignableMessage::ValidatorRegistration(v) => Web3SignerObject::ValidatorRegistration(v),
+        SignableMessage::VoluntaryExit(e) => Web3SignerObject::VoluntaryExit(e),
+        SignableMessage::ExecutionPayloadEnvelope(e) => {
+            Web3SignerObject::ExecutionPayloadEnvelope(e)
+        }
+        SignableMessage::PayloadAttestationData(d) => Web3SignerObject::PayloadAttestationData(d),
+        SignableMessage::ProposerPreferences(p) => Web3SignerObject::ProposerPreferences(p),
+        SignableMessage::InclusionList(il) => Web3SignerObject::InclusionList(il),
+    };
+
+    // Determine the Web3Signer message type.
+    let message_type = object.message_type();
+    if matches!(message_type, MessageType::ValidatorRegistration) && fork_info.is_some() {
+        return Err(Error::GenesisForkVersionRequired);
+    }
 
-                Ok(response.signature)
+    Ok(SigningRequest {
+        message_type,
+        fork_info,
+        signing_root,
+        object,
+    })
+}
+
+/// Request a signature for `request` from the Web3Signer server at `signing_url`.
+async fn request_signature<E: EthSpec, Payload: AbstractExecPayload<E>>(
+    http_client: &Client,
+    signing_url: &Url,
+    request: &SigningRequest<'_, E, Payload>,
+) -> Result<Signature, reqwest::Error> {
+    let response: SigningResponse = http_client
+        .post(signing_url.clone())
+        .header(ACCEPT, "application/json")
+        .json(request)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(response.signature)
+}
+
+/// Request partial signatures for `request` from all `shares` at once, and combine the first
+/// `threshold` valid ones.
+///
+/// Requests which are still outstanding once enough partial signatures have been received are
+/// cancelled.
+async fn get_threshold_signature<E: EthSpec, Payload: AbstractExecPayload<E>>(
+    threshold: usize,
+    shares: &[ThresholdShare],
+    signing_root: Hash256,
+    request: &SigningRequest<'_, E, Payload>,
+) -> Result<Signature, Error> {
+    let mut responses = shares
+        .iter()
+        .map(|share| async move {
+            let signer = share.signer();
+            let share_index = share.index.to_string();
+            let timer = validator_metrics::start_timer_vec(
+                &validator_metrics::THRESHOLD_SHARE_REQUEST_TIMES,
+                &[share_index.as_str()],
+            );
+            let result = request_signature(&share.http_client, &share.signing_url, request).await;
+            drop(timer);
+
+            let (partial, outcome) = match result {
+                Ok(signature) if signature.verify(&share.public_key, signing_root) => {
+                    (Some((share.index, signature)), validator_metrics::SUCCESS)
+                }
+                Ok(_) => {
+                    warn!(
+                        %signer,
+                        share_index = share.index,
+                        share_pubkey = %share.public_key,
+                        "Invalid partial signature from threshold share signer"
+                    );
+                    (None, validator_metrics::INVALID)
+                }
+                Err(e) => {
+                    warn!(
+                        %signer,
+                        share_index = share.index,
+                        error = %e,
+                        "Failed to obtain partial signature from threshold share signer"
+                    );
+                    let outcome = if e.is_timeout() {
+                        validator_metrics::TIMEOUT
+                    } else {
+                        validator_metrics::ERROR
+                    };
+                    (None, outcome)
+                }
+            };
+            validator_metrics::inc_counter_vec(
+                &validator_metrics::THRESHOLD_SHARE_REQUESTS_TOTAL,
+                &[share_index.as_str(), outcome],
+            );
+            partial
+        })
+        .collect::<FuturesUnordered<_>>();
+
+    let mut partials = Vec::with_capacity(threshold);
+    while partials.len() < threshold {
+        match responses.next().await {
+            Some(Some(partial)) => partials.push(partial),
+            Some(None) => (),
+            None => {
+                return Err(Error::ThresholdNotReached {
+                    valid_shares: partials.len(),
+                    threshold,
+                });
             }
         }
     }
+
+    let partials = partials
+        .iter()
+        .map(|(index, signature)| (*index, signature))
+        .collect::<Vec<_>>();
+    Signature::combine_shares(&partials)
+        .map_err(|e| Error::ThresholdCombineFailed(format!("{e:?}")))
 }
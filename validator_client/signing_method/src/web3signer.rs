@@ -3,7 +3,8 @@
 use serde::{Deserialize, Serialize};
 use types::{
     AbstractExecPayload, AggregateAndProofRef, AttestationData, BeaconBlock, BeaconBlockHeader,
-    ContributionAndProof, Epoch, EthSpec, Fork, Hash256, PublicKeyBytes, Signature, Slot,
+    ContributionAndProof, Epoch, EthSpec, ExecutionPayloadEnvelope, Fork, Hash256, InclusionList,
+    PayloadAttestationData, ProposerPreferences, PublicKeyBytes, Signature, Slot,
     SyncAggregatorSelectionData, ValidatorRegistrationData, VoluntaryExit,
 };
 
@@ -21,6 +22,10 @@ pub(crate) enum MessageType {
     SyncCommitteeSelectionProof,
     SyncCommitteeContributionAndProof,
     ValidatorRegistration,
+    ExecutionPayloadEnvelope,
+    PayloadAttestation,
+    ProposerPreferences,
+    InclusionList,
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize)]
@@ -78,6 +83,10 @@ pub(crate) enum Web3SignerObject<'a, E: EthSpec, Payload: AbstractExecPayload<E>
     SyncAggregatorSelectionData(&'a SyncAggregatorSelectionData),
     ContributionAndProof(&'a ContributionAndProof<E>),
     ValidatorRegistration(&'a ValidatorRegistrationData),
+    ExecutionPayloadEnvelope(&'a ExecutionPayloadEnvelope<E>),
+    PayloadAttestationData(&'a PayloadAttestationData),
+    ProposerPreferences(&'a ProposerPreferences),
+    InclusionList(&'a InclusionList<E>),
 }
 
 impl<'a, E: EthSpec, Payload: AbstractExecPayload<E>> Web3SignerObject<'a, E, Payload> {
@@ -148,6 +157,10 @@ impl<'a, E: EthSpec, Payload: AbstractExecPayload<E>> Web3SignerObject<'a, E, Pa
                 MessageType::SyncCommitteeContributionAndProof
             }
             Web3SignerObject::ValidatorRegistration(_) => MessageType::ValidatorRegistration,
+            Web3SignerObject::ExecutionPayloadEnvelope(_) => MessageType::ExecutionPayloadEnvelope,
+            Web3SignerObject::PayloadAttestationData(_) => MessageType::PayloadAttestation,
+            Web3SignerObject::ProposerPreferences(_) => MessageType::ProposerPreferences,
+            Web3SignerObject::InclusionList(_) => MessageType::InclusionList,
         }
     }
 }
@@ -223,6 +236,22 @@ mod tests {
             serde_json::to_string(&MessageType::ValidatorRegistration).unwrap(),
             r#""VALIDATOR_REGISTRATION""#
         );
+        assert_eq!(
+            serde_json::to_string(&MessageType::ExecutionPayloadEnvelope).unwrap(),
+            r#""EXECUTION_PAYLOAD_ENVELOPE""#
+        );
+        assert_eq!(
+            serde_json::to_string(&MessageType::PayloadAttestation).unwrap(),
+            r#""PAYLOAD_ATTESTATION""#
+        );
+        assert_eq!(
+            serde_json::to_string(&MessageType::ProposerPreferences).unwrap(),
+            r#""PROPOSER_PREFERENCES""#
+        );
+        assert_eq!(
+            serde_json::to_string(&MessageType::InclusionList).unwrap(),
+            r#""INCLUSION_LIST""#
+        );
     }
 
     #[test]
@@ -339,4 +368,72 @@ mod tests {
         let json = serde_json::to_string(&obj).unwrap();
         assert!(json.contains("99"));
     }
+
+    #[test]
+    fn payload_attestation_request_serde() {
+        let data = PayloadAttestationData {
+            beacon_block_root: Hash256::repeat_byte(0xbb),
+            slot: Slot::new(5),
+            payload_present: true,
+            blob_data_available: false,
+        };
+        let object: Web3SignerObject<E, FullPayload<E>> =
+            Web3SignerObject::PayloadAttestationData(&data);
+        let request = SigningRequest {
+            message_type: object.message_type(),
+            fork_info: Some(ForkInfo {
+                fork: Fork::default(),
+                genesis_validators_root: Hash256::zero(),
+            }),
+            signing_root: Hash256::zero(),
+            object,
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["type"], "PAYLOAD_ATTESTATION");
+        assert!(json["fork_info"].is_object());
+        assert_eq!(json["payload_attestation_data"]["slot"], "5");
+        assert_eq!(json["payload_attestation_data"]["payload_present"], true);
+    }
+
+    #[test]
+    fn message_type_epbs_and_focil() {
+        let envelope = ExecutionPayloadEnvelope::<E>::default();
+        let obj: Web3SignerObject<E, FullPayload<E>> =
+            Web3SignerObject::ExecutionPayloadEnvelope(&envelope);
+        assert_eq!(obj.message_type(), MessageType::ExecutionPayloadEnvelope);
+        assert!(
+            serde_json::to_value(&obj)
+                .unwrap()
+                .get("execution_payload_envelope")
+                .is_some()
+        );
+
+        let preferences = ProposerPreferences {
+            proposal_slot: 8,
+            validator_index: 1,
+            fee_recipient: <_>::default(),
+            gas_limit: 30_000_000,
+        };
+        let obj: Web3SignerObject<E, FullPayload<E>> =
+            Web3SignerObject::ProposerPreferences(&preferences);
+        assert_eq!(obj.message_type(), MessageType::ProposerPreferences);
+        assert_eq!(
+            serde_json::to_value(&obj).unwrap()["proposer_preferences"]["gas_limit"],
+            "30000000"
+        );
+
+        let inclusion_list = InclusionList::<E> {
+            slot: Slot::new(3),
+            validator_index: 2,
+            inclusion_list_committee_root: Hash256::zero(),
+            transactions: <_>::default(),
+        };
+        let obj: Web3SignerObject<E, FullPayload<E>> =
+            Web3SignerObject::InclusionList(&inclusion_list);
+        assert_eq!(obj.message_type(), MessageType::InclusionList);
+        assert_eq!(
+            serde_json::to_value(&obj).unwrap()["inclusion_list"]["validator_index"],
+            "2"
+        );
+    }
 }
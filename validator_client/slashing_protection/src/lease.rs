@@ -0,0 +1,219 @@
+//! A signing lease for validator clients which share a slashing protection database.
+//!
+//! In an active/passive deployment several validator clients load the same keys, but only the
+//! instance holding the lease may sign. The lease is a single row in a slashing protection database
+//! opened in shared mode by every instance. Each lease has a `term` which increases whenever the
+//! lease changes hands, and an expiry time which the holder extends while it is alive.
+//!
+//! Slashable messages signed under the lease are recorded in the shared database by
+//! `check_and_insert_*_under_lease`, which check the lease and insert the record in the same
+//! exclusive transaction. An instance which has lost the lease, for example because it was paused
+//! for longer than the lease duration, can therefore never record (and sign) a message once another
+//! instance has taken over.
+use crate::slashing_database::LockingMode;
+use crate::{NotSafe, Safe, SlashingDatabase};
+use rusqlite::{OptionalExtension, Transaction, params};
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+use types::{AttestationData, BeaconBlockHeader, Hash256, PublicKeyBytes, SignedRoot};
+
+/// The filename within the shared directory that contains the shared slashing protection DB.
+pub const SHARED_SLASHING_PROTECTION_FILENAME: &str = "shared_slashing_protection.sqlite";
+
+/// The right of one validator client to sign, until `expires_at`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lease {
+    /// The identifier of the instance holding the lease.
+    pub holder: String,
+    /// Incremented every time the lease is acquired after being free or expired.
+    pub term: u64,
+    /// The expiry time of the lease, as a duration since the UNIX epoch.
+    pub expires_at: Duration,
+}
+
+/// The result of an attempt to acquire or renew the lease.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LeaseOutcome {
+    /// The lease was free or expired and is now held by the caller under a new term.
+    Acquired(Lease),
+    /// The lease was already held by the caller and has been extended.
+    Renewed(Lease),
+    /// The lease is held by another instance.
+    HeldByOther(Lease),
+}
+
+impl SlashingDatabase {
+    /// Open the database at `path` for sharing with other processes, or create one if none exists.
+    ///
+    /// Unlike `open_or_create`, this does not lock the file while it is open, so it must only be
+    /// used for databases in which records are inserted under a lease.
+    pub fn open_or_create_shared(path: &Path) -> Result<Self, NotSafe> {
+        let db = if path.exists() {
+            Self::open_with_locking_mode(path, LockingMode::Shared)?
+        } else {
+            match Self::create_with_locking_mode(path, LockingMode::Shared) {
+                // Another instance created the database first.
+                Err(NotSafe::IOError(ErrorKind::AlreadyExists)) => {
+                    Self::open_with_locking_mode(path, LockingMode::Shared)?
+                }
+                res => res?,
+            }
+        };
+        db.with_exclusive_transaction(|txn| {
+            txn.execute(
+                "CREATE TABLE IF NOT EXISTS lease (
+                    id INTEGER PRIMARY KEY CHECK (id = 0),
+                    holder TEXT NOT NULL,
+                    term INTEGER NOT NULL,
+                    expires_at_millis INTEGER NOT NULL
+                )",
+                params![],
+            )?;
+            Ok::<_, NotSafe>(())
+        })?;
+        Ok(db)
+    }
+
+    /// Return the current lease, which may have expired, or `None` if no lease was ever taken.
+    pub fn current_lease(&self) -> Result<Option<Lease>, NotSafe> {
+        self.with_transaction(get_lease_in_txn)
+    }
+
+    /// Acquire the lease for `holder` until `now + duration`, if it is free, expired or already
+    /// held by `holder`.
+    pub fn try_acquire_lease(
+        &self,
+        holder: &str,
+        now: Duration,
+        duration: Duration,
+    ) -> Result<LeaseOutcome, NotSafe> {
+        self.with_exclusive_transaction(|txn| {
+            let expires_at = now + duration;
+            let (lease, acquired) = match get_lease_in_txn(txn)? {
+                Some(current) if current.expires_at > now && current.holder != holder => {
+                    return Ok(LeaseOutcome::HeldByOther(current));
+                }
+                Some(current) if current.expires_at > now => (
+                    Lease {
+                        expires_at,
+                        ..current
+                    },
+                    false,
+                ),
+                current => (
+                    Lease {
+                        holder: holder.to_string(),
+                        term: current.map_or(1, |lease| lease.term + 1),
+                        expires_at,
+                    },
+                    true,
+                ),
+            };
+
+            txn.execute(
+                "INSERT INTO lease (id, holder, term, expires_at_millis) VALUES (0, ?1, ?2, ?3)
+                 ON CONFLICT (id) DO UPDATE SET
+                    holder = excluded.holder,
+                    term = excluded.term,
+                    expires_at_millis = excluded.expires_at_millis",
+                params![lease.holder, lease.term, millis(lease.expires_at)],
+            )?;
+            Ok(if acquired {
+                LeaseOutcome::Acquired(lease)
+            } else {
+                LeaseOutcome::Renewed(lease)
+            })
+        })
+    }
+
+    /// Give up `lease` so that another instance can acquire it without waiting for it to expire.
+    ///
+    /// Has no effect if `lease` is no longer the current lease.
+    pub fn release_lease(&self, lease: &Lease) -> Result<(), NotSafe> {
+        self.with_exclusive_transaction(|txn| {
+            txn.execute(
+                "UPDATE lease SET expires_at_millis = 0 WHERE holder = ?1 AND term = ?2",
+                params![lease.holder, lease.term],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// As for `check_and_insert_block_proposal`, but only if `lease` is still the current lease
+    /// and has not expired at `now`.
+    pub fn check_and_insert_block_proposal_under_lease(
+        &self,
+        lease: &Lease,
+        now: Duration,
+        validator_pubkey: &PublicKeyBytes,
+        block_header: &BeaconBlockHeader,
+        domain: Hash256,
+    ) -> Result<Safe, NotSafe> {
+        self.with_exclusive_transaction(|txn| {
+            check_lease_in_txn(txn, lease, now)?;
+            self.check_and_insert_block_signing_root_txn(
+                validator_pubkey,
+                block_header.slot,
+                block_header.signing_root(domain).into(),
+                txn,
+            )
+        })
+    }
+
+    /// As for `check_and_insert_attestation`, but only if `lease` is still the current lease and
+    /// has not expired at `now`.
+    pub fn check_and_insert_attestation_under_lease(
+        &self,
+        lease: &Lease,
+        now: Duration,
+        validator_pubkey: &PublicKeyBytes,
+        attestation: &AttestationData,
+        domain: Hash256,
+    ) -> Result<Safe, NotSafe> {
+        self.with_exclusive_transaction(|txn| {
+            check_lease_in_txn(txn, lease, now)?;
+            self.check_and_insert_attestation_signing_root_txn(
+                validator_pubkey,
+                attestation.source.epoch,
+                attestation.target.epoch,
+                attestation.signing_root(domain).into(),
+                txn,
+            )
+        })
+    }
+}
+
+fn millis(time: Duration) -> u64 {
+    u64::try_from(time.as_millis()).unwrap_or(u64::MAX)
+}
+
+fn get_lease_in_txn(txn: &Transaction) -> Result<Option<Lease>, NotSafe> {
+    Ok(txn
+        .query_row(
+            "SELECT holder, term, expires_at_millis FROM lease WHERE id = 0",
+            params![],
+            |row| {
+                Ok(Lease {
+                    holder: row.get(0)?,
+                    term: row.get(1)?,
+                    expires_at: Duration::from_millis(row.get(2)?),
+                })
+            },
+        )
+        .optional()?)
+}
+
+/// Error unless `lease` is the current lease and has not expired at `now`.
+fn check_lease_in_txn(txn: &Transaction, lease: &Lease, now: Duration) -> Result<(), NotSafe> {
+    match get_lease_in_txn(txn)? {
+        Some(current)
+            if current.holder == lease.holder
+                && current.term == lease.term
+                && current.expires_at > now =>
+        {
+            Ok(())
+        }
+        _ => Err(NotSafe::LeaseNotHeld),
+    }
+}
@@ -0,0 +1,181 @@
+#![cfg(test)]
+
+use crate::attestation_tests::attestation_data_builder;
+use crate::block_tests::block;
+use crate::test_utils::*;
+use crate::*;
+use std::time::Duration;
+use tempfile::tempdir;
+
+const LEASE_DURATION: Duration = Duration::from_secs(12);
+
+fn secs(n: u64) -> Duration {
+    Duration::from_secs(n)
+}
+
+fn acquired(outcome: LeaseOutcome) -> Lease {
+    match outcome {
+        LeaseOutcome::Acquired(lease) => lease,
+        other => panic!("expected lease to be acquired, got {other:?}"),
+    }
+}
+
+#[test]
+fn acquire_renew_and_expire() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join(SHARED_SLASHING_PROTECTION_FILENAME);
+    let db_a = SlashingDatabase::open_or_create_shared(&file).unwrap();
+    let db_b = SlashingDatabase::open_or_create_shared(&file).unwrap();
+
+    assert_eq!(db_a.current_lease().unwrap(), None);
+
+    let lease = acquired(
+        db_a.try_acquire_lease("a", secs(100), LEASE_DURATION)
+            .unwrap(),
+    );
+    assert_eq!(lease.term, 1);
+    assert_eq!(lease.expires_at, secs(112));
+
+    // The other instance can't take an unexpired lease.
+    assert_eq!(
+        db_b.try_acquire_lease("b", secs(105), LEASE_DURATION)
+            .unwrap(),
+        LeaseOutcome::HeldByOther(lease.clone())
+    );
+
+    // Renewing keeps the term.
+    let renewed = Lease {
+        expires_at: secs(118),
+        ..lease.clone()
+    };
+    assert_eq!(
+        db_a.try_acquire_lease("a", secs(106), LEASE_DURATION)
+            .unwrap(),
+        LeaseOutcome::Renewed(renewed.clone())
+    );
+    assert_eq!(db_b.current_lease().unwrap(), Some(renewed));
+
+    // Once expired the lease can be taken over, under a new term.
+    let lease_b = acquired(
+        db_b.try_acquire_lease("b", secs(118), LEASE_DURATION)
+            .unwrap(),
+    );
+    assert_eq!(lease_b.holder, "b");
+    assert_eq!(lease_b.term, 2);
+
+    // The previous holder must acquire it again, rather than renewing.
+    assert!(matches!(
+        db_a.try_acquire_lease("a", secs(119), LEASE_DURATION)
+            .unwrap(),
+        LeaseOutcome::HeldByOther(_)
+    ));
+}
+
+#[test]
+fn release_lease() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join(SHARED_SLASHING_PROTECTION_FILENAME);
+    let db = SlashingDatabase::open_or_create_shared(&file).unwrap();
+
+    let lease = acquired(
+        db.try_acquire_lease("a", secs(100), LEASE_DURATION)
+            .unwrap(),
+    );
+    db.release_lease(&lease).unwrap();
+
+    let lease_b = acquired(
+        db.try_acquire_lease("b", secs(101), LEASE_DURATION)
+            .unwrap(),
+    );
+    assert_eq!(lease_b.term, 2);
+
+    // Releasing a stale lease has no effect.
+    db.release_lease(&lease).unwrap();
+    assert_eq!(db.current_lease().unwrap(), Some(lease_b));
+}
+
+#[test]
+fn inserts_fenced_by_lease() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join(SHARED_SLASHING_PROTECTION_FILENAME);
+    let db_a = SlashingDatabase::open_or_create_shared(&file).unwrap();
+    let db_b = SlashingDatabase::open_or_create_shared(&file).unwrap();
+    let pk = pubkey(0);
+    db_a.register_validator(pk).unwrap();
+
+    let block_1 = block(1);
+    let lease_a = acquired(
+        db_a.try_acquire_lease("a", secs(100), LEASE_DURATION)
+            .unwrap(),
+    );
+    assert_eq!(
+        db_a.check_and_insert_block_proposal_under_lease(
+            &lease_a,
+            secs(101),
+            &pk,
+            &block_1,
+            DEFAULT_DOMAIN
+        ),
+        Ok(Safe::Valid)
+    );
+    assert_eq!(
+        db_a.check_and_insert_attestation_under_lease(
+            &lease_a,
+            secs(101),
+            &pk,
+            &attestation_data_builder(0, 1),
+            DEFAULT_DOMAIN
+        ),
+        Ok(Safe::Valid)
+    );
+
+    // The lease is not valid after it expires, even if nobody else has taken it.
+    assert_eq!(
+        db_a.check_and_insert_block_proposal_under_lease(
+            &lease_a,
+            secs(112),
+            &pk,
+            &block(2),
+            DEFAULT_DOMAIN
+        ),
+        Err(NotSafe::LeaseNotHeld)
+    );
+
+    // After a takeover the old holder can't insert, and the new holder sees its records.
+    let lease_b = acquired(
+        db_b.try_acquire_lease("b", secs(112), LEASE_DURATION)
+            .unwrap(),
+    );
+    assert_eq!(
+        db_a.check_and_insert_attestation_under_lease(
+            &lease_a,
+            secs(113),
+            &pk,
+            &attestation_data_builder(1, 2),
+            DEFAULT_DOMAIN
+        ),
+        Err(NotSafe::LeaseNotHeld)
+    );
+    assert_eq!(
+        db_b.check_and_insert_block_proposal_under_lease(
+            &lease_b,
+            secs(113),
+            &pk,
+            &block_1,
+            DEFAULT_DOMAIN
+        ),
+        Ok(Safe::SameData)
+    );
+    let mut double_vote = attestation_data_builder(0, 1);
+    double_vote.beacon_block_root = Hash256::repeat_byte(1);
+    assert!(matches!(
+        db_b.check_and_insert_attestation_under_lease(
+            &lease_b,
+            secs(113),
+            &pk,
+            &double_vote,
+            DEFAULT_DOMAIN
+        ),
+        Err(NotSafe::InvalidAttestation(_))
+    ));
+}
@@ -2,6 +2,8 @@ mod attestation_tests;
 mod block_tests;
 mod extra_interchange_tests;
 pub mod interchange_test;
+mod lease;
+mod lease_tests;
 mod parallel_tests;
 mod registration_tests;
 mod signed_attestation;
@@ -13,6 +15,7 @@ pub mod interchange {
     pub use eip_3076::{Interchange, InterchangeMetadata};
 }
 
+pub use crate::lease::{Lease, LeaseOutcome, SHARED_SLASHING_PROTECTION_FILENAME};
 pub use crate::signed_attestation::{InvalidAttestation, SignedAttestation};
 pub use crate::signed_block::{InvalidBlock, SignedBlock};
 pub use crate::slashing_database::{
@@ -41,6 +44,8 @@ pub enum NotSafe {
     SQLError(String),
     SQLPoolError(String),
     ConsistencyError,
+    /// The lease under which the record was to be inserted is no longer held.
+    LeaseNotHeld,
 }
 
 /// The attestation or block is safe to sign, and will not cause the signer to be slashed.
@@ -31,6 +31,16 @@ pub const SUPPORTED_INTERCHANGE_FORMAT_VERSION: u64 = 5;
 /// Column ID of the `validators.enabled` column.
 pub(crate) const VALIDATORS_ENABLED_CID: i64 = 2;
 
+/// How a database file is locked against connections from other processes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LockingMode {
+    /// Hold an exclusive lock on the file for as long as the database is open.
+    Exclusive,
+    /// Only lock the file for the duration of each transaction, waiting for other processes to
+    /// release their locks.
+    Shared,
+}
+
 #[derive(Debug, Clone)]
 pub struct SlashingDatabase {
     conn_pool: Pool,
@@ -50,6 +60,14 @@ impl SlashingDatabase {
     ///
     /// Error if a database (or any file) already exists at `path`.
     pub fn create(path: &Path) -> Result<Self, NotSafe> {
+        Self::create_with_locking_mode(path, LockingMode::Exclusive)
+    }
+
+    /// Create a slashing database at the given path, using the given `locking_mode`.
+    pub(crate) fn create_with_locking_mode(
+        path: &Path,
+        locking_mode: LockingMode,
+    ) -> Result<Self, NotSafe> {
         let _file = File::options()
             .write(true)
             .read(true)
@@ -57,7 +75,7 @@ impl SlashingDatabase {
             .open(path)?;
 
         restrict_file_permissions(path).map_err(|_| NotSafe::PermissionsError)?;
-        let conn_pool = Self::open_conn_pool(path)?;
+        let conn_pool = Self::open_conn_pool(path, locking_mode)?;
         let mut conn = conn_pool.get()?;
 
         conn.execute(
@@ -104,7 +122,15 @@ impl SlashingDatabase {
     ///
     /// This will automatically check for and apply the latest schema migrations.
     pub fn open(path: &Path) -> Result<Self, NotSafe> {
-        let conn_pool = Self::open_conn_pool(path)?;
+        Self::open_with_locking_mode(path, LockingMode::Exclusive)
+    }
+
+    /// Open an existing `SlashingDatabase` from disk, using the given `locking_mode`.
+    pub(crate) fn open_with_locking_mode(
+        path: &Path,
+        locking_mode: LockingMode,
+    ) -> Result<Self, NotSafe> {
+        let conn_pool = Self::open_conn_pool(path, locking_mode)?;
         let db = Self { conn_pool };
         db.with_transaction(Self::apply_schema_migrations)?;
         Ok(db)
@@ -143,10 +169,13 @@ impl SlashingDatabase {
     }
 
     /// Open a new connection pool with all of the necessary settings and tweaks.
-    fn open_conn_pool(path: &Path) -> Result<Pool, NotSafe> {
+    fn open_conn_pool(path: &Path, locking_mode: LockingMode) -> Result<Pool, NotSafe> {
         let manager = SqliteConnectionManager::file(path)
-            .with_flags(rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE)
-            .with_init(Self::apply_pragmas);
+            .with_flags(rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE);
+        let manager = match locking_mode {
+            LockingMode::Exclusive => manager.with_init(Self::apply_pragmas),
+            LockingMode::Shared => manager.with_init(Self::apply_shared_pragmas),
+        };
         let conn_pool = Pool::builder()
             .max_size(POOL_SIZE)
             .connection_timeout(CONNECTION_TIMEOUT)
@@ -167,6 +196,16 @@ impl SlashingDatabase {
         Ok(())
     }
 
+    /// Apply the settings for a database shared with other processes.
+    ///
+    /// The file is only locked for the duration of each transaction, and a connection waits for
+    /// up to `CONNECTION_TIMEOUT` for another process to finish its transaction.
+    fn apply_shared_pragmas(conn: &mut rusqlite::Connection) -> Result<(), rusqlite::Error> {
+        conn.pragma_update(None, "foreign_keys", true)?;
+        conn.busy_timeout(CONNECTION_TIMEOUT)?;
+        Ok(())
+    }
+
     /// Creates an empty transaction and drops it. Used to test whether the database is locked.
     pub fn test_transaction(&self) -> Result<(), NotSafe> {
         let mut conn = self.conn_pool.get()?;
@@ -174,6 +213,24 @@ impl SlashingDatabase {
         Ok(())
     }
 
+    /// As for `with_transaction`, but taking the exclusive lock on the database before `f` runs.
+    ///
+    /// This must be used by transactions which write to a database shared with other processes,
+    /// so that concurrent writers queue for the lock instead of failing to upgrade a read lock.
+    pub(crate) fn with_exclusive_transaction<T, U, F>(&self, f: F) -> Result<T, U>
+    where
+        F: FnOnce(&Transaction) -> Result<T, U>,
+        U: From<NotSafe>,
+    {
+        let mut conn = self.conn_pool.get().map_err(NotSafe::from)?;
+        let txn = conn
+            .transaction_with_behavior(TransactionBehavior::Exclusive)
+            .map_err(NotSafe::from)?;
+        let value = f(&txn)?;
+        txn.commit().map_err(NotSafe::from)?;
+        Ok(value)
+    }
+
     /// Execute a database transaction as a closure, committing if `f` returns `Ok`.
     pub fn with_transaction<T, U, F>(&self, f: F) -> Result<T, U>
     where
@@ -641,7 +698,7 @@ impl SlashingDatabase {
     }
 
     /// Transactional variant of `check_and_insert_attestation_signing_root`.
-    fn check_and_insert_attestation_signing_root_txn(
+    pub(crate) fn check_and_insert_attestation_signing_root_txn(
         &self,
         validator_pubkey: &PublicKeyBytes,
         att_source_epoch: Epoch,
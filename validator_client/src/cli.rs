@@ -365,6 +365,53 @@ pub struct ValidatorClient {
     )]
     pub enable_doppelganger_protection: bool,
 
+    #[clap(
+        long,
+        help = "Records the duties performed by each validator in the validators directory, \
+                including when each message was signed and published and through which beacon \
+                nodes, and checks how each duty turned out on chain. The history is served by \
+                the /vibehouse/validators/{pubkey}/history endpoints of the HTTP API.",
+        display_order = 0,
+        help_heading = FLAG_HEADER
+    )]
+    pub enable_duty_history: bool,
+
+    #[clap(
+        long,
+        value_name = "DIR",
+        help = "Enables active/passive high availability with the other validator clients \
+                using the same DIR, which must be on a volume shared between them. Only the \
+                instance holding a lease in DIR signs. It records its blocks and attestations \
+                in DIR, so that a standby can import them before taking over once the lease \
+                expires. Use with --enable-doppelganger-protection so that a new lease holder \
+                also waits for the previous one to go silent on the network.",
+        display_order = 0
+    )]
+    pub high_availability_dir: Option<PathBuf>,
+
+    #[clap(
+        long,
+        value_name = "ID",
+        requires = "high_availability_dir",
+        help = "A name for this instance in the high availability lease, which must be \
+                different for every instance. Defaults to a new name each time the validator \
+                client starts.",
+        display_order = 0
+    )]
+    pub high_availability_instance_id: Option<String>,
+
+    #[clap(
+        long,
+        value_name = "SECONDS",
+        requires = "high_availability_dir",
+        default_value_t = 24,
+        help = "The duration of the high availability lease. The lease holder renews it every \
+                quarter of this duration, and a standby takes over if it is not renewed in \
+                time.",
+        display_order = 0
+    )]
+    pub high_availability_lease_duration: u64,
+
     #[clap(
         long,
         alias = "private-tx-proposals",
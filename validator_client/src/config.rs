@@ -15,11 +15,12 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::net::IpAddr;
 use std::path::PathBuf;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing::{info, warn};
 use types::GRAFFITI_BYTES_LEN;
 use validator_http_api::{self, PK_FILENAME};
 use vibehouse_validator_store::Config as ValidatorStoreConfig;
+use vibehouse_validator_store::high_availability::Config as HighAvailabilityConfig;
 
 pub(crate) const DEFAULT_BEACON_NODE: &str = "http://localhost:5052/";
 
@@ -65,6 +66,10 @@ pub struct Config {
     /// If true, enable functionality that monitors the network for attestations or proposals from
     /// any of the validators managed by this client before starting up.
     pub enable_doppelganger_protection: bool,
+    /// Configuration for active/passive high availability, if enabled.
+    pub high_availability: Option<HighAvailabilityConfig>,
+    /// If true, record the duties performed by each validator and their outcome on chain.
+    pub enable_duty_history: bool,
     /// If true, then we publish validator specific metrics (e.g next attestation duty slot)
     /// for all our managed validators.
     /// Note: We publish validator specific metrics for low validator counts without this flag
@@ -123,6 +128,8 @@ impl Default for Config {
             beacon_node_fallback: <_>::default(),
             monitoring_api: None,
             enable_doppelganger_protection: false,
+            high_availability: None,
+            enable_duty_history: false,
             enable_high_validator_count_metrics: false,
             beacon_nodes_tls_certs: None,
             builder_registration_timestamp_override: None,
@@ -247,6 +254,25 @@ impl Config {
 
         if let Some(mut broadcast_topics) = validator_client_config.broadcast.clone() {
             broadcast_topics.retain(|topic| *topic != ApiTopic::None);
+
+            // Payload attestations and inclusion lists have their own topics, which operators
+            // broadcasting attestations will usually want as well.
+            if broadcast_topics.contains(&ApiTopic::Attestations) {
+                let missing_topics = [ApiTopic::PayloadAttestations, ApiTopic::InclusionLists]
+                    .into_iter()
+                    .filter(|topic| !broadcast_topics.contains(topic))
+                    .map(<&str>::from)
+                    .collect::<Vec<_>>();
+                if !missing_topics.is_empty() {
+                    warn!(
+                        missing_topics = missing_topics.join(","),
+                        info = "add them to --broadcast to send these messages to all beacon \
+                                nodes as well",
+                        "Broadcasting attestations but not payload attestations or inclusion lists"
+                    );
+                }
+            }
+
             config.broadcast_topics = broadcast_topics;
         }
 
@@ -359,6 +385,31 @@ impl Config {
 
         config.enable_doppelganger_protection =
             validator_client_config.enable_doppelganger_protection;
+        config.enable_duty_history = validator_client_config.enable_duty_history;
+
+        if let Some(shared_dir) = validator_client_config.high_availability_dir.clone() {
+            if validator_client_config.high_availability_lease_duration == 0 {
+                return Err("high-availability-lease-duration cannot be 0".to_string());
+            }
+            let instance_id = validator_client_config
+                .high_availability_instance_id
+                .clone()
+                .unwrap_or_else(new_instance_id);
+            if !config.enable_doppelganger_protection {
+                warn!(
+                    info = "use --enable-doppelganger-protection to check that the previous \
+                            lease holder has stopped before signing",
+                    "High availability enabled without doppelganger protection"
+                );
+            }
+            config.high_availability = Some(HighAvailabilityConfig {
+                shared_dir,
+                instance_id,
+                lease_duration: Duration::from_secs(
+                    validator_client_config.high_availability_lease_duration,
+                ),
+            });
+        }
         config.validator_store.builder_proposals = validator_client_config.builder_proposals;
         config.validator_store.prefer_builder_proposals =
             validator_client_config.prefer_builder_proposals;
@@ -396,6 +447,16 @@ impl Config {
     }
 }
 
+/// A name for this instance in the high availability lease, which is unlikely to be used by any
+/// other instance.
+fn new_instance_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos();
+    format!("vc-{}-{:08x}", std::process::id(), nanos)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -14,6 +14,7 @@ use beacon_node_fallback::{
     BeaconNodeFallback, CandidateBeaconNode, start_fallback_updater_service,
 };
 use doppelganger_service::DoppelgangerService;
+use duty_history::{DUTY_HISTORY_FILENAME, DutyHistory};
 use environment::RuntimeContext;
 use eth2::{BeaconNodeHttpClient, StatusCode, Timeouts, reqwest::ClientBuilder};
 use initialized_validators::Error::UnableToOpenVotingKeystore;
@@ -39,6 +40,7 @@ use validator_services::{
     attestation_service::{AttestationService, AttestationServiceBuilder},
     block_service::{BlockService, BlockServiceBuilder},
     duties_service::{self, DutiesService, DutiesServiceBuilder},
+    duty_outcome_service,
     inclusion_list_service::{InclusionListService, InclusionListServiceBuilder},
     latency_service,
     payload_attestation_service::{PayloadAttestationService, PayloadAttestationServiceBuilder},
@@ -47,6 +49,7 @@ use validator_services::{
 };
 use validator_store::ValidatorStore as ValidatorStoreTrait;
 use vibehouse_validator_store::VibehouseValidatorStore;
+use vibehouse_validator_store::high_availability::HighAvailability;
 
 /// The interval between attempts to contact the beacon node during startup.
 const RETRY_DELAY: Duration = Duration::from_secs(2);
@@ -85,6 +88,7 @@ pub struct ProductionValidatorClient<E: EthSpec> {
     sync_committee_service: SyncCommitteeService<ValidatorStore<E>, SystemTimeSlotClock>,
     doppelganger_service: Option<Arc<DoppelgangerService>>,
     preparation_service: PreparationService<ValidatorStore<E>, SystemTimeSlotClock>,
+    duty_history: Option<Arc<DutyHistory>>,
     validator_store: Arc<ValidatorStore<E>>,
     slot_clock: SystemTimeSlotClock,
     http_api_listen_addr: Option<SocketAddr>,
@@ -245,6 +249,16 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
                 })?;
         }
 
+        let duty_history = if config.enable_duty_history {
+            let duty_history_path = config.validator_dir.join(DUTY_HISTORY_FILENAME);
+            let duty_history = DutyHistory::open_or_create(&duty_history_path)
+                .map_err(|e| format!("Failed to open or create duty history database: {e:?}"))?;
+            info!(path = %duty_history_path.display(), "Duty history enabled");
+            Some(Arc::new(duty_history))
+        } else {
+            None
+        };
+
         let last_beacon_node_index = config
             .beacon_nodes
             .len()
@@ -390,7 +404,7 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             None
         };
 
-        let validator_store = Arc::new(VibehouseValidatorStore::new(
+        let mut validator_store = VibehouseValidatorStore::new(
             validators,
             slashing_protection,
             genesis_validators_root,
@@ -399,7 +413,20 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             slot_clock.clone(),
             &config.validator_store,
             context.executor.clone(),
-        ));
+        );
+
+        if let Some(high_availability_config) = &config.high_availability {
+            info!(
+                shared_dir = ?high_availability_config.shared_dir,
+                instance_id = %high_availability_config.instance_id,
+                lease_duration_secs = high_availability_config.lease_duration.as_secs(),
+                "High availability enabled, waiting for lease before signing"
+            );
+            validator_store.enable_high_availability(HighAvailability::open(
+                high_availability_config.clone(),
+            )?)?;
+        }
+        let validator_store = Arc::new(validator_store);
 
         // Ensure all validators are registered in doppelganger protection.
         validator_store.register_all_in_doppelganger_protection_if_enabled()?;
@@ -478,7 +505,8 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             .executor(context.executor.clone())
             .chain_spec(context.eth2_config.spec.clone())
             .graffiti(config.graffiti)
-            .graffiti_file(config.graffiti_file.clone());
+            .graffiti_file(config.graffiti_file.clone())
+            .duty_history(duty_history.clone());
 
         // If we have proposer nodes, add them to the block service builder.
         if proposer_nodes_num > 0 {
@@ -494,6 +522,7 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             .beacon_nodes(beacon_nodes.clone())
             .executor(context.executor.clone())
             .chain_spec(context.eth2_config.spec.clone())
+            .duty_history(duty_history.clone())
             .disable(config.disable_attesting)
             .build()?;
 
@@ -504,6 +533,7 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             .beacon_nodes(beacon_nodes.clone())
             .executor(context.executor.clone())
             .spec(context.eth2_config.spec.clone())
+            .duty_history(duty_history.clone())
             .build()?;
 
         let inclusion_list_service = InclusionListServiceBuilder::new()
@@ -513,6 +543,7 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             .beacon_nodes(beacon_nodes.clone())
             .executor(context.executor.clone())
             .spec(context.eth2_config.spec.clone())
+            .duty_history(duty_history.clone())
             .build()?;
 
         let preparation_service = PreparationServiceBuilder::new()
@@ -530,6 +561,7 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             slot_clock.clone(),
             beacon_nodes,
             context.executor.clone(),
+            duty_history.clone(),
         );
 
         Ok(Self {
@@ -542,6 +574,7 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             sync_committee_service,
             doppelganger_service,
             preparation_service,
+            duty_history,
             validator_store,
             config,
             slot_clock,
@@ -573,6 +606,7 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
                 config: self.config.http_api.clone(),
                 sse_logging_components: self.context.sse_logging_components.clone(),
                 slot_clock: self.slot_clock.clone(),
+                duty_history: self.duty_history.clone(),
             });
 
             let exit = self.context.executor.exit();
@@ -626,6 +660,8 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             .start_update_service(&self.context.eth2_config.spec)
             .map_err(|e| format!("Unable to start preparation service: {e}"))?;
 
+        self.validator_store.start_high_availability_service();
+
         if let Some(doppelganger_service) = self.doppelganger_service.clone() {
             DoppelgangerService::start_update_service(
                 doppelganger_service,
@@ -656,6 +692,15 @@ impl<E: EthSpec> ProductionValidatorClient<E> {
             );
         }
 
+        if let Some(duty_history) = self.duty_history.clone() {
+            duty_outcome_service::start_duty_outcome_service::<E, _>(
+                self.context.executor.clone(),
+                self.duties_service.slot_clock.clone(),
+                self.duties_service.beacon_nodes.clone(),
+                duty_history,
+            );
+        }
+
         Ok(())
     }
 }
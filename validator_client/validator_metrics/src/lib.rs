@@ -32,6 +32,9 @@ pub const ATTESTATION_SELECTION_PROOFS: &str = "attestation_selection_proofs";
 pub const SUBSCRIPTIONS: &str = "subscriptions";
 pub const LOCAL_KEYSTORE: &str = "local_keystore";
 pub const WEB3SIGNER: &str = "web3signer";
+pub const THRESHOLD: &str = "threshold";
+pub const INVALID: &str = "invalid";
+pub const TIMEOUT: &str = "timeout";
 pub const PAYLOAD_ATTESTATIONS: &str = "payload_attestations";
 pub const PAYLOAD_ATTESTATIONS_HTTP_GET: &str = "payload_attestations_http_get";
 pub const PAYLOAD_ATTESTATIONS_HTTP_POST: &str = "payload_attestations_http_post";
@@ -191,6 +194,13 @@ pub static ENDPOINT_REQUESTS: LazyLock<Result<IntCounterVec>> = LazyLock::new(||
         &["endpoint"],
     )
 });
+pub static BROADCAST_RESULTS: LazyLock<Result<IntCounterVec>> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "bn_broadcast_results",
+        "The number of requests broadcast to each endpoint, by API topic and result",
+        &["topic", "endpoint", "result"],
+    )
+});
 
 /*
  * Beacon node availability metrics
@@ -227,6 +237,12 @@ pub static ETH2_FALLBACK_CONNECTED: LazyLock<Result<IntGauge>> = LazyLock::new(|
         "Set to 1 if connected to atleast one synced eth2 fallback node, otherwise set to 0",
     )
 });
+pub static HIGH_AVAILABILITY_LEASE_HELD: LazyLock<Result<IntGauge>> = LazyLock::new(|| {
+    try_create_int_gauge(
+        "vc_high_availability_lease_held",
+        "Set to 1 if this instance holds the high availability signing lease, otherwise set to 0",
+    )
+});
 /*
  * Signing Metrics
  */
@@ -237,6 +253,20 @@ pub static SIGNING_TIMES: LazyLock<Result<HistogramVec>> = LazyLock::new(|| {
         &["type"],
     )
 });
+pub static THRESHOLD_SHARE_REQUESTS_TOTAL: LazyLock<Result<IntCounterVec>> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "vc_threshold_share_requests_total",
+        "Total count of partial signature requests to threshold share signers, by result",
+        &["share_index", "result"],
+    )
+});
+pub static THRESHOLD_SHARE_REQUEST_TIMES: LazyLock<Result<HistogramVec>> = LazyLock::new(|| {
+    try_create_histogram_vec(
+        "vc_threshold_share_request_times_seconds",
+        "Duration to obtain a partial signature from a threshold share signer",
+        &["share_index"],
+    )
+});
 pub static BLOCK_SIGNING_TIMES: LazyLock<Result<Histogram>> = LazyLock::new(|| {
     try_create_histogram(
         "vc_block_signing_times_seconds",
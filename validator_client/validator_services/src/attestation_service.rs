@@ -1,5 +1,7 @@
 use crate::duties_service::{DutiesService, DutyAndProof};
 use beacon_node_fallback::{ApiTopic, BeaconNodeFallback};
+use duty_history::{DutyHistory, PublishedVia};
+use eth2::vibehouse_vc::types::DutyKind;
 use futures::future::join_all;
 use logging::crit;
 use slot_clock::SlotClock;
@@ -22,6 +24,7 @@ pub struct AttestationServiceBuilder<S: ValidatorStore, T: SlotClock + 'static>
     beacon_nodes: Option<Arc<BeaconNodeFallback<T>>>,
     executor: Option<TaskExecutor>,
     chain_spec: Option<Arc<ChainSpec>>,
+    duty_history: Option<Arc<DutyHistory>>,
     disable: bool,
 }
 
@@ -34,6 +37,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationServiceBuil
             beacon_nodes: None,
             executor: None,
             chain_spec: None,
+            duty_history: None,
             disable: false,
         }
     }
@@ -68,6 +72,11 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationServiceBuil
         self
     }
 
+    pub fn duty_history(mut self, duty_history: Option<Arc<DutyHistory>>) -> Self {
+        self.duty_history = duty_history;
+        self
+    }
+
     pub fn disable(mut self, disable: bool) -> Self {
         self.disable = disable;
         self
@@ -94,6 +103,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationServiceBuil
                 chain_spec: self
                     .chain_spec
                     .ok_or("Cannot build AttestationService without chain_spec")?,
+                duty_history: self.duty_history,
                 disable: self.disable,
             }),
         })
@@ -108,6 +118,7 @@ pub struct Inner<S, T> {
     beacon_nodes: Arc<BeaconNodeFallback<T>>,
     executor: TaskExecutor,
     chain_spec: Arc<ChainSpec>,
+    duty_history: Option<Arc<DutyHistory>>,
     disable: bool,
 }
 
@@ -436,7 +447,18 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationService<S,
                 )
                 .await
             {
-                Ok(()) => Some((attestation, duty.validator_index)),
+                Ok(()) => {
+                    if let Some(duty_history) = &self.duty_history {
+                        duty_history.record_signed(
+                            DutyKind::Attestation,
+                            attestation_data.slot,
+                            &duty.pubkey,
+                            duty.validator_index,
+                            None,
+                        );
+                    }
+                    Some((attestation, duty.validator_index))
+                }
                 Err(ValidatorStoreError::UnknownPubkey(pubkey)) => {
                     // A pubkey can be missing when a validator was recently
                     // removed via the API.
@@ -479,6 +501,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationService<S,
             .fork_name_at_slot::<S::E>(attestation_data.slot);
 
         // Post the attestations to the BN.
+        let published_via = &PublishedVia::default();
         match self
             .beacon_nodes
             .request(ApiTopic::Attestations, |beacon_node| async move {
@@ -509,21 +532,35 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationService<S,
                     })
                     .collect::<Vec<_>>();
 
-                beacon_node
+                let result = beacon_node
                     .post_beacon_pool_attestations_v2::<S::E>(single_attestations, fork_name)
-                    .await
+                    .await;
+                if result.is_ok() {
+                    published_via.record(&beacon_node);
+                }
+                result
             })
             .await
         {
-            Ok(()) => info!(
-                count = attestations.len(),
-                validator_indices = ?validator_indices,
-                head_block = ?attestation_data.beacon_block_root,
-                committee_index = attestation_data.index,
-                slot = attestation_data.slot.as_u64(),
-                "type" = "unaggregated",
-                "Successfully published attestations"
-            ),
+            Ok(()) => {
+                info!(
+                    count = attestations.len(),
+                    validator_indices = ?validator_indices,
+                    head_block = ?attestation_data.beacon_block_root,
+                    committee_index = attestation_data.index,
+                    slot = attestation_data.slot.as_u64(),
+                    "type" = "unaggregated",
+                    "Successfully published attestations"
+                );
+                if let Some(duty_history) = &self.duty_history {
+                    duty_history.record_published(
+                        DutyKind::Attestation,
+                        attestation_data.slot,
+                        validator_indices,
+                        &published_via.beacon_nodes(),
+                    );
+                }
+            }
             Err(e) => error!(
                 error = %e,
                 committee_index = attestation_data.index,
@@ -620,7 +657,18 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationService<S,
                 )
                 .await
             {
-                Ok(aggregate) => Some(aggregate),
+                Ok(aggregate) => {
+                    if let Some(duty_history) = &self.duty_history {
+                        duty_history.record_signed(
+                            DutyKind::Aggregate,
+                            attestation_data.slot,
+                            &duty.pubkey,
+                            duty.validator_index,
+                            None,
+                        );
+                    }
+                    Some(aggregate)
+                }
                 Err(ValidatorStoreError::UnknownPubkey(pubkey)) => {
                     // A pubkey can be missing when a validator was recently
                     // removed via the API.
@@ -647,6 +695,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationService<S,
 
         if !signed_aggregate_and_proofs.is_empty() {
             let signed_aggregate_and_proofs_slice = signed_aggregate_and_proofs.as_slice();
+            let published_via = &PublishedVia::default();
             match self
                 .beacon_nodes
                 .first_success(|beacon_node| async move {
@@ -654,7 +703,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationService<S,
                         &validator_metrics::ATTESTATION_SERVICE_TIMES,
                         &[validator_metrics::AGGREGATES_HTTP_POST],
                     );
-                    if fork_name.electra_enabled() {
+                    let result = if fork_name.electra_enabled() {
                         beacon_node
                             .post_validator_aggregate_and_proof_v2(
                                 signed_aggregate_and_proofs_slice,
@@ -667,11 +716,29 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> AttestationService<S,
                                 signed_aggregate_and_proofs_slice,
                             )
                             .await
+                    };
+                    if result.is_ok() {
+                        published_via.record(&beacon_node);
                     }
+                    result
                 })
                 .await
             {
                 Ok(()) => {
+                    if let Some(duty_history) = &self.duty_history {
+                        let aggregator_indices = signed_aggregate_and_proofs
+                            .iter()
+                            .map(|signed_aggregate_and_proof| {
+                                signed_aggregate_and_proof.message().aggregator_index()
+                            })
+                            .collect::<Vec<_>>();
+                        duty_history.record_published(
+                            DutyKind::Aggregate,
+                            attestation_data.slot,
+                            &aggregator_indices,
+                            &published_via.beacon_nodes(),
+                        );
+                    }
                     for signed_aggregate_and_proof in signed_aggregate_and_proofs {
                         let attestation = signed_aggregate_and_proof.message().aggregate();
                         info!(
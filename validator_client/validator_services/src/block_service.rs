@@ -1,5 +1,7 @@
 use beacon_node_fallback::{ApiTopic, BeaconNodeFallback, Error as FallbackError, Errors};
 use bls::SignatureBytes;
+use duty_history::{DutyHistory, PublishedVia};
+use eth2::vibehouse_vc::types::DutyKind;
 use eth2::{BeaconNodeHttpClient, StatusCode};
 use graffiti_file::{GraffitiFile, determine_graffiti};
 use logging::crit;
@@ -50,6 +52,7 @@ pub struct BlockServiceBuilder<S, T> {
     chain_spec: Option<Arc<ChainSpec>>,
     graffiti: Option<Graffiti>,
     graffiti_file: Option<GraffitiFile>,
+    duty_history: Option<Arc<DutyHistory>>,
 }
 
 impl<S: ValidatorStore, T: SlotClock + 'static> BlockServiceBuilder<S, T> {
@@ -63,6 +66,7 @@ impl<S: ValidatorStore, T: SlotClock + 'static> BlockServiceBuilder<S, T> {
             chain_spec: None,
             graffiti: None,
             graffiti_file: None,
+            duty_history: None,
         }
     }
 
@@ -106,6 +110,11 @@ impl<S: ValidatorStore, T: SlotClock + 'static> BlockServiceBuilder<S, T> {
         self
     }
 
+    pub fn duty_history(mut self, duty_history: Option<Arc<DutyHistory>>) -> Self {
+        self.duty_history = duty_history;
+        self
+    }
+
     pub fn build(self) -> Result<BlockService<S, T>, String> {
         Ok(BlockService {
             inner: Arc::new(Inner {
@@ -127,6 +136,7 @@ impl<S: ValidatorStore, T: SlotClock + 'static> BlockServiceBuilder<S, T> {
                 proposer_nodes: self.proposer_nodes,
                 graffiti: self.graffiti,
                 graffiti_file: self.graffiti_file,
+                duty_history: self.duty_history,
             }),
         })
     }
@@ -198,6 +208,7 @@ pub struct Inner<S, T> {
     chain_spec: Arc<ChainSpec>,
     graffiti: Option<Graffiti>,
     graffiti_file: Option<GraffitiFile>,
+    duty_history: Option<Arc<DutyHistory>>,
 }
 
 /// Attempts to produce attestations for any block producer(s) at the start of the epoch.
@@ -366,6 +377,17 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> BlockService<S, T> {
         )
         .as_millis();
 
+        let proposer_index = self.validator_store.validator_index(validator_pubkey);
+        if let (Some(duty_history), Some(proposer_index)) = (&self.duty_history, proposer_index) {
+            duty_history.record_signed(
+                DutyKind::Block,
+                slot,
+                validator_pubkey,
+                proposer_index,
+                None,
+            );
+        }
+
         info!(
             slot = slot.as_u64(),
             signing_time_ms = signing_time_ms,
@@ -377,13 +399,26 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> BlockService<S, T> {
         // Try the proposer nodes first, since we've likely gone to efforts to
         // protect them from DoS attacks and they're most likely to successfully
         // publish a block.
+        let published_via = &PublishedVia::default();
         proposer_fallback
             .request_proposers_first(|beacon_node| async {
+                let published_to = beacon_node.to_string();
                 self.publish_signed_block_contents(&signed_block, beacon_node)
-                    .await
+                    .await?;
+                published_via.record(&published_to);
+                Ok::<_, BlockError>(())
             })
             .await?;
 
+        if let (Some(duty_history), Some(proposer_index)) = (&self.duty_history, proposer_index) {
+            duty_history.record_published(
+                DutyKind::Block,
+                slot,
+                &[proposer_index],
+                &published_via.beacon_nodes(),
+            );
+        }
+
         let metadata = BlockMetadata::from(&signed_block);
         if metadata.external_builder {
             info!(
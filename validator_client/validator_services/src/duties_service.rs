@@ -1834,7 +1834,7 @@ async fn broadcast_proposer_preferences<S: ValidatorStore + 'static, T: SlotCloc
         // Submit to BN (which will validate and gossip to the network).
         if let Err(e) = duties_service
             .beacon_nodes
-            .first_success(|beacon_node| {
+            .request(ApiTopic::ProposerPreferences, |beacon_node| {
                 let signed_preferences = signed_preferences.clone();
                 async move {
                     beacon_node
@@ -2247,7 +2247,7 @@ mod broadcast_preferences_tests {
         let mut fallback = BeaconNodeFallback::new(
             vec![candidate],
             Config::default(),
-            vec![ApiTopic::Attestations],
+            vec![ApiTopic::ProposerPreferences],
             spec_arc.clone(),
         );
 
@@ -0,0 +1,486 @@
+//! Determines the on-chain outcome of the duties recorded in the duty history.
+//!
+//! - Attestations are included if a canonical block includes them, according to the attesting
+//!   indices the beacon node derives from their aggregation bits and committees, whether or not
+//!   the block rewarded them. The first such block gives the inclusion delay and the canonical
+//!   chain gives head and target correctness.
+//! - Blocks are included if they are canonical.
+//! - Sync committee messages are included if the canonical block at the next slot rewarded them.
+//! - Payload attestations are included if an aggregate in the canonical block at the next slot
+//!   has the validator's bit set.
+//! - Inclusion lists are included if the beacon node observed them, since they never appear on
+//!   chain.
+//! - Aggregates are never attributed to their aggregator on chain, so never have an outcome.
+use beacon_node_fallback::BeaconNodeFallback;
+use duty_history::{ConfirmedOutcome, DutyHistory, PendingDuty};
+use eth2::types::{BlockId, ValidatorId};
+use eth2::vibehouse::BlockReward;
+use eth2::vibehouse_vc::types::DutyKind;
+use slot_clock::SlotClock;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use task_executor::TaskExecutor;
+use tokio::time::sleep;
+use tracing::{debug, error, warn};
+use types::{Epoch, EthSpec, Hash256, Slot};
+
+/// The outcomes of the duties of an epoch are determined once it is this many epochs old, after
+/// the last slot in which its attestations can be included.
+const OUTCOME_DELAY_EPOCHS: u64 = 2;
+/// Duties whose outcome could not be determined are retried for this many subsequent epochs.
+const OUTCOME_RETRY_EPOCHS: u64 = 2;
+/// Duties are deleted from the duty history once they are this many epochs old.
+pub const DUTY_HISTORY_RETENTION_EPOCHS: u64 = 4096;
+
+type Outcomes = Vec<(PendingDuty, ConfirmedOutcome)>;
+
+/// Starts a service that records the outcome of past duties in `duty_history` once per epoch,
+/// and prunes old duties from it.
+pub fn start_duty_outcome_service<E: EthSpec, T: SlotClock + 'static>(
+    executor: TaskExecutor,
+    slot_clock: T,
+    beacon_nodes: Arc<BeaconNodeFallback<T>>,
+    duty_history: Arc<DutyHistory>,
+) {
+    let future = async move {
+        loop {
+            // Run half way through the first slot of each epoch, clear of the duties at its start.
+            let sleep_time = slot_clock
+                .duration_to_next_epoch(E::slots_per_epoch())
+                .map_or_else(
+                    || slot_clock.slot_duration(),
+                    |next_epoch| next_epoch + slot_clock.slot_duration() / 2,
+                );
+            sleep(sleep_time).await;
+
+            let Some(current_epoch) = slot_clock
+                .now()
+                .map(|slot| slot.epoch(E::slots_per_epoch()))
+            else {
+                error!("Failed to read slot clock");
+                continue;
+            };
+            let Some(last_epoch) = current_epoch.as_u64().checked_sub(OUTCOME_DELAY_EPOCHS) else {
+                continue;
+            };
+            update_outcomes::<E, T>(
+                &beacon_nodes,
+                &duty_history,
+                Epoch::new(last_epoch.saturating_sub(OUTCOME_RETRY_EPOCHS)),
+                Epoch::new(last_epoch),
+            )
+            .await;
+
+            let prune_epoch = current_epoch.saturating_sub(DUTY_HISTORY_RETENTION_EPOCHS);
+            if let Err(error) = duty_history.prune(prune_epoch.start_slot(E::slots_per_epoch())) {
+                warn!(?error, "Failed to prune duty history");
+            }
+        }
+    };
+
+    executor.spawn(future, "duty_outcome");
+}
+
+/// Record the outcome of the pending duties in the epochs from `first_epoch` to `last_epoch`
+/// (inclusive).
+async fn update_outcomes<E: EthSpec, T: SlotClock>(
+    beacon_nodes: &BeaconNodeFallback<T>,
+    duty_history: &DutyHistory,
+    first_epoch: Epoch,
+    last_epoch: Epoch,
+) {
+    // The block rewards of the whole window are fetched once, when first needed.
+    let mut block_rewards = None;
+
+    for epoch in (first_epoch.as_u64()..=last_epoch.as_u64()).map(Epoch::new) {
+        let start_slot = epoch.start_slot(E::slots_per_epoch());
+        let end_slot = epoch.end_slot(E::slots_per_epoch());
+
+        for duty in [
+            DutyKind::Attestation,
+            DutyKind::Block,
+            DutyKind::SyncCommittee,
+            DutyKind::PayloadAttestation,
+            DutyKind::InclusionList,
+        ] {
+            let pending = match duty_history.pending(duty, start_slot, end_slot) {
+                Ok(pending) => pending,
+                Err(error) => {
+                    warn!(?error, %duty, %epoch, "Failed to read pending duties");
+                    continue;
+                }
+            };
+            if pending.is_empty() {
+                continue;
+            }
+
+            let outcomes = match duty {
+                DutyKind::Attestation => {
+                    let rewards = match block_rewards.take() {
+                        Some(rewards) => rewards,
+                        None => {
+                            attestation_block_rewards::<E, T>(beacon_nodes, first_epoch, last_epoch)
+                                .await
+                        }
+                    };
+                    let outcomes = rewards
+                        .as_ref()
+                        .map(|rewards| attestation_outcomes::<E>(rewards, &pending))
+                        .map_err(Clone::clone);
+                    block_rewards = Some(rewards);
+                    outcomes
+                }
+                DutyKind::Block => block_outcomes(beacon_nodes, &pending).await,
+                DutyKind::SyncCommittee => sync_committee_outcomes(beacon_nodes, &pending).await,
+                DutyKind::PayloadAttestation => {
+                    payload_attestation_outcomes::<E, T>(beacon_nodes, &pending).await
+                }
+                DutyKind::InclusionList => {
+                    inclusion_list_outcomes::<E, T>(beacon_nodes, &pending).await
+                }
+                DutyKind::Aggregate => continue,
+            };
+
+            match outcomes.map(|outcomes| duty_history.record_outcomes(duty, &outcomes)) {
+                Ok(Ok(())) => {
+                    debug!(%duty, %epoch, count = pending.len(), "Recorded duty outcomes");
+                }
+                Ok(Err(error)) => warn!(?error, %duty, %epoch, "Failed to record duty outcomes"),
+                Err(error) => warn!(%error, %duty, %epoch, "Unable to determine duty outcomes"),
+            }
+        }
+    }
+}
+
+fn included_if(included: bool) -> ConfirmedOutcome {
+    if included {
+        ConfirmedOutcome::included()
+    } else {
+        ConfirmedOutcome::missed()
+    }
+}
+
+fn group_by_slot(pending: &[PendingDuty]) -> BTreeMap<Slot, Vec<PendingDuty>> {
+    let mut by_slot = BTreeMap::<_, Vec<_>>::new();
+    for duty in pending {
+        by_slot.entry(duty.slot).or_default().push(*duty);
+    }
+    by_slot
+}
+
+/// The rewards, with attestations, of the blocks which may include the attestations of the
+/// epochs from `first_epoch` to `last_epoch` (inclusive).
+async fn attestation_block_rewards<E: EthSpec, T: SlotClock>(
+    beacon_nodes: &BeaconNodeFallback<T>,
+    first_epoch: Epoch,
+    last_epoch: Epoch,
+) -> Result<Vec<BlockReward>, String> {
+    let slots_per_epoch = E::slots_per_epoch();
+    // Attestations from an epoch may be included until the end of the next epoch. Blocks from the
+    // previous epoch give the canonical block at any skipped slots at the start of an epoch.
+    let start_slot = std::cmp::max(
+        first_epoch.saturating_sub(1u64).start_slot(slots_per_epoch),
+        Slot::new(1),
+    );
+    let end_slot = (last_epoch + 1).end_slot(slots_per_epoch);
+    let block_rewards = beacon_nodes
+        .first_success(|beacon_node| async move {
+            beacon_node
+                .get_vibehouse_analysis_block_rewards_with_attestations(start_slot, end_slot)
+                .await
+                .map_err(|e| format!("Failed to get block rewards: {e:?}"))
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if block_rewards.iter().any(|reward| {
+        let rewards = &reward.attestation_rewards;
+        rewards.attestations.len() != rewards.per_attestation_rewards.len()
+            || rewards.attestations.len() != rewards.attesting_indices.len()
+    }) {
+        return Err("Beacon node did not return the attestations in each block".to_string());
+    }
+    Ok(block_rewards)
+}
+
+fn attestation_outcomes<E: EthSpec>(
+    block_rewards: &[BlockReward],
+    pending: &[PendingDuty],
+) -> Outcomes {
+    let slots_per_epoch = E::slots_per_epoch();
+
+    // The root of the latest block at or before `slot`.
+    let canonical_root_at = |slot: Slot| -> Option<Hash256> {
+        block_rewards
+            .iter()
+            .take_while(|reward| reward.meta.slot <= slot)
+            .last()
+            .map(|reward| reward.block_root)
+    };
+
+    // The first block to include each validator's attestation at each slot. Attestations which
+    // earn no reward (e.g. because an earlier block already included them) still count.
+    let mut inclusions = HashMap::new();
+    for reward in block_rewards {
+        let rewards = &reward.attestation_rewards;
+        for (data, attesting_indices) in rewards.attestations.iter().zip(&rewards.attesting_indices)
+        {
+            for validator_index in attesting_indices {
+                inclusions
+                    .entry((data.slot, *validator_index))
+                    .or_insert((reward.meta.slot, data));
+            }
+        }
+    }
+
+    pending
+        .iter()
+        .map(|duty| {
+            let outcome = match inclusions.get(&(duty.slot, duty.validator_index)) {
+                Some((inclusion_slot, data)) => ConfirmedOutcome {
+                    inclusion_delay: Some(inclusion_slot.saturating_sub(data.slot).as_u64()),
+                    head_correct: canonical_root_at(data.slot)
+                        .map(|root| root == data.beacon_block_root),
+                    target_correct: canonical_root_at(
+                        data.target.epoch.start_slot(slots_per_epoch),
+                    )
+                    .map(|root| root == data.target.root),
+                    ..ConfirmedOutcome::included()
+                },
+                None => ConfirmedOutcome::missed(),
+            };
+            (*duty, outcome)
+        })
+        .collect()
+}
+
+async fn block_outcomes<T: SlotClock>(
+    beacon_nodes: &BeaconNodeFallback<T>,
+    pending: &[PendingDuty],
+) -> Result<Outcomes, String> {
+    let mut outcomes = Vec::with_capacity(pending.len());
+    for duty in pending {
+        let slot = duty.slot;
+        let header = beacon_nodes
+            .first_success(|beacon_node| async move {
+                beacon_node
+                    .get_beacon_headers_block_id(BlockId::Slot(slot))
+                    .await
+                    .map_err(|e| format!("Failed to get block header: {e:?}"))
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        let included = header.is_some_and(|header| {
+            header.data.canonical
+                && header.data.header.message.proposer_index == duty.validator_index
+        });
+        outcomes.push((*duty, included_if(included)));
+    }
+    Ok(outcomes)
+}
+
+async fn sync_committee_outcomes<T: SlotClock>(
+    beacon_nodes: &BeaconNodeFallback<T>,
+    pending: &[PendingDuty],
+) -> Result<Outcomes, String> {
+    let mut outcomes = Vec::with_capacity(pending.len());
+    for (slot, duties) in group_by_slot(pending) {
+        // Sync committee messages are only included by the block at the next slot.
+        let block_slot = slot + 1;
+        let header = beacon_nodes
+            .first_success(|beacon_node| async move {
+                beacon_node
+                    .get_beacon_headers_block_id(BlockId::Slot(block_slot))
+                    .await
+                    .map_err(|e| format!("Failed to get block header: {e:?}"))
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let rewards = if header.is_some_and(|header| header.data.canonical) {
+            let validators = &duties
+                .iter()
+                .map(|duty| ValidatorId::Index(duty.validator_index))
+                .collect::<Vec<_>>();
+            beacon_nodes
+                .first_success(|beacon_node| async move {
+                    beacon_node
+                        .post_beacon_rewards_sync_committee(BlockId::Slot(block_slot), validators)
+                        .await
+                        .map_err(|e| format!("Failed to get sync committee rewards: {e:?}"))
+                })
+                .await
+                .map_err(|e| e.to_string())?
+                .data
+                .into_iter()
+                .map(|reward| (reward.validator_index, reward.reward))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        for duty in duties {
+            let included = rewards
+                .get(&duty.validator_index)
+                .is_some_and(|reward| *reward > 0);
+            outcomes.push((duty, included_if(included)));
+        }
+    }
+    Ok(outcomes)
+}
+
+async fn payload_attestation_outcomes<E: EthSpec, T: SlotClock>(
+    beacon_nodes: &BeaconNodeFallback<T>,
+    pending: &[PendingDuty],
+) -> Result<Outcomes, String> {
+    let mut outcomes = Vec::with_capacity(pending.len());
+    for (slot, duties) in group_by_slot(pending) {
+        // Payload attestations are only included by the block at the next slot.
+        let block_slot = slot + 1;
+        let block = beacon_nodes
+            .first_success(|beacon_node| async move {
+                beacon_node
+                    .get_beacon_blinded_blocks::<E>(BlockId::Slot(block_slot))
+                    .await
+                    .map_err(|e| format!("Failed to get block: {e:?}"))
+            })
+            .await
+            .map_err(|e| e.to_string())?
+            .map(types::BeaconResponse::into_data);
+
+        for duty in duties {
+            let included =
+                block.as_ref().is_some_and(|block| {
+                    block.message().body().payload_attestations().is_ok_and(
+                        |payload_attestations| {
+                            payload_attestations.iter().any(|payload_attestation| {
+                                payload_attestation.data.slot == slot
+                                    && duty.committee_position.is_some_and(|position| {
+                                        payload_attestation
+                                            .aggregation_bits
+                                            .get(position as usize)
+                                            .unwrap_or(false)
+                                    })
+                            })
+                        },
+                    )
+                });
+            outcomes.push((duty, included_if(included)));
+        }
+    }
+    Ok(outcomes)
+}
+
+async fn inclusion_list_outcomes<E: EthSpec, T: SlotClock>(
+    beacon_nodes: &BeaconNodeFallback<T>,
+    pending: &[PendingDuty],
+) -> Result<Outcomes, String> {
+    let mut outcomes = Vec::with_capacity(pending.len());
+    for (slot, duties) in group_by_slot(pending) {
+        let signers = beacon_nodes
+            .first_success(|beacon_node| async move {
+                beacon_node
+                    .get_vibehouse_history_inclusion_lists::<E>(BlockId::Slot(slot))
+                    .await
+                    .map_err(|e| format!("Failed to get inclusion lists: {e:?}"))
+            })
+            .await
+            .map_err(|e| e.to_string())?
+            .data
+            .into_iter()
+            .map(|inclusion_list| inclusion_list.message.validator_index)
+            .collect::<HashSet<_>>();
+
+        for duty in duties {
+            outcomes.push((duty, included_if(signers.contains(&duty.validator_index))));
+        }
+    }
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eth2::vibehouse::{AttestationRewards, BlockRewardMeta};
+    use eth2::vibehouse_vc::types::DutyOutcome;
+    use types::{AttestationData, Checkpoint, MainnetEthSpec};
+
+    fn block_reward(
+        slot: u64,
+        block_root: Hash256,
+        attestations: Vec<(AttestationData, Vec<u64>, HashMap<u64, u64>)>,
+    ) -> BlockReward {
+        let (data, (attesting_indices, per_attestation_rewards)): (Vec<_>, (Vec<_>, Vec<_>)) =
+            attestations
+                .into_iter()
+                .map(|(data, indices, rewards)| (data, (indices, rewards)))
+                .unzip();
+        BlockReward {
+            total: 0,
+            block_root,
+            meta: BlockRewardMeta {
+                slot: Slot::new(slot),
+                parent_slot: Slot::new(slot.saturating_sub(1)),
+                proposer_index: 0,
+                graffiti: String::new(),
+            },
+            attestation_rewards: AttestationRewards {
+                total: 0,
+                prev_epoch_total: 0,
+                curr_epoch_total: 0,
+                per_attestation_rewards,
+                attestations: data,
+                attesting_indices,
+            },
+            sync_committee_rewards: 0,
+        }
+    }
+
+    fn pending(slot: u64, validator_index: u64) -> PendingDuty {
+        PendingDuty {
+            slot: Slot::new(slot),
+            validator_index,
+            committee_position: None,
+        }
+    }
+
+    #[test]
+    fn included_but_unrewarded_attestation_is_included() {
+        let root_1 = Hash256::repeat_byte(1);
+        let root_2 = Hash256::repeat_byte(2);
+        let data = AttestationData {
+            slot: Slot::new(1),
+            index: 0,
+            beacon_block_root: root_1,
+            source: Checkpoint::default(),
+            target: Checkpoint {
+                epoch: Epoch::new(0),
+                root: Hash256::ZERO,
+            },
+        };
+        let block_rewards = vec![
+            block_reward(0, Hash256::ZERO, vec![]),
+            block_reward(1, root_1, vec![]),
+            // Validator 7 attested, but earned nothing for it, e.g. because its vote had already
+            // been counted by an aggregate in another block.
+            block_reward(
+                3,
+                root_2,
+                vec![(data, vec![5, 7], HashMap::from([(5, 100)]))],
+            ),
+        ];
+
+        let outcomes = attestation_outcomes::<MainnetEthSpec>(
+            &block_rewards,
+            &[pending(1, 5), pending(1, 7), pending(1, 9)],
+        );
+
+        for (duty, outcome) in &outcomes[..2] {
+            assert_eq!(outcome.outcome, DutyOutcome::Included, "{duty:?}");
+            assert_eq!(outcome.inclusion_delay, Some(2));
+            assert_eq!(outcome.head_correct, Some(true));
+            assert_eq!(outcome.target_correct, Some(true));
+        }
+        assert_eq!(outcomes[2].1, ConfirmedOutcome::missed());
+    }
+}
@@ -1,5 +1,7 @@
 use crate::duties_service::DutiesService;
 use beacon_node_fallback::{ApiTopic, BeaconNodeFallback};
+use duty_history::{DutyHistory, PublishedVia};
+use eth2::vibehouse_vc::types::DutyKind;
 use slot_clock::SlotClock;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -18,6 +20,7 @@ pub struct InclusionListServiceBuilder<S: ValidatorStore, T: SlotClock + 'static
     beacon_nodes: Option<Arc<BeaconNodeFallback<T>>>,
     executor: Option<TaskExecutor>,
     chain_spec: Option<Arc<ChainSpec>>,
+    duty_history: Option<Arc<DutyHistory>>,
 }
 
 impl<S: ValidatorStore + 'static, T: SlotClock + 'static> Default
@@ -37,6 +40,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> InclusionListServiceBu
             beacon_nodes: None,
             executor: None,
             chain_spec: None,
+            duty_history: None,
         }
     }
 
@@ -70,6 +74,11 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> InclusionListServiceBu
         self
     }
 
+    pub fn duty_history(mut self, duty_history: Option<Arc<DutyHistory>>) -> Self {
+        self.duty_history = duty_history;
+        self
+    }
+
     pub fn build(self) -> Result<InclusionListService<S, T>, String> {
         let chain_spec = self
             .chain_spec
@@ -93,6 +102,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> InclusionListServiceBu
                     .ok_or("Cannot build InclusionListService without executor")?,
                 heze_fork_epoch: chain_spec.heze_fork_epoch,
                 chain_spec,
+                duty_history: self.duty_history,
             }),
         })
     }
@@ -106,6 +116,7 @@ pub struct Inner<S, T> {
     executor: TaskExecutor,
     heze_fork_epoch: Option<Epoch>,
     chain_spec: Arc<ChainSpec>,
+    duty_history: Option<Arc<DutyHistory>>,
 }
 
 /// Produces and broadcasts inclusion lists for FOCIL committee duties.
@@ -254,6 +265,15 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> InclusionListService<S
                         &metrics::SIGNED_INCLUSION_LISTS_TOTAL,
                         &[metrics::SUCCESS],
                     );
+                    if let Some(duty_history) = &self.duty_history {
+                        duty_history.record_signed(
+                            DutyKind::InclusionList,
+                            slot,
+                            &duty.pubkey,
+                            duty.validator_index,
+                            None,
+                        );
+                    }
                     signed
                 }
                 Err(e) => {
@@ -272,19 +292,22 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> InclusionListService<S
             };
 
             // Submit to BN for gossip propagation.
+            let published_via = &PublishedVia::default();
             let submit_result = {
                 let _http_timer = metrics::start_timer_vec(
                     &metrics::INCLUSION_LIST_SERVICE_TIMES,
                     &[metrics::INCLUSION_LISTS_HTTP_POST],
                 );
                 self.beacon_nodes
-                    .request(ApiTopic::Attestations, |beacon_node| {
+                    .request(ApiTopic::InclusionLists, |beacon_node| {
                         let signed_il = signed_il.clone();
                         async move {
                             beacon_node
                                 .post_beacon_pool_inclusion_lists(&signed_il)
                                 .await
-                                .map_err(|e| e.to_string())
+                                .map_err(|e| e.to_string())?;
+                            published_via.record(&beacon_node);
+                            Ok::<_, String>(())
                         }
                     })
                     .await
@@ -296,6 +319,14 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> InclusionListService<S
                         slot = slot.as_u64(),
                         "Published inclusion list"
                     );
+                    if let Some(duty_history) = &self.duty_history {
+                        duty_history.record_published(
+                            DutyKind::InclusionList,
+                            slot,
+                            &[duty.validator_index],
+                            &published_via.beacon_nodes(),
+                        );
+                    }
                     submitted += 1;
                 }
                 Err(e) => {
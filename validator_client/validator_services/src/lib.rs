@@ -1,6 +1,7 @@
 pub mod attestation_service;
 pub mod block_service;
 pub mod duties_service;
+pub mod duty_outcome_service;
 pub(crate) mod inclusion_list_duties;
 pub mod inclusion_list_service;
 pub mod latency_service;
@@ -1,5 +1,7 @@
 use crate::duties_service::DutiesService;
 use beacon_node_fallback::{ApiTopic, BeaconNodeFallback};
+use duty_history::{DutyHistory, PublishedVia};
+use eth2::vibehouse_vc::types::DutyKind;
 use slot_clock::SlotClock;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -18,6 +20,7 @@ pub struct PayloadAttestationServiceBuilder<S: ValidatorStore, T: SlotClock + 's
     beacon_nodes: Option<Arc<BeaconNodeFallback<T>>>,
     executor: Option<TaskExecutor>,
     chain_spec: Option<Arc<ChainSpec>>,
+    duty_history: Option<Arc<DutyHistory>>,
 }
 
 impl<S: ValidatorStore + 'static, T: SlotClock + 'static> Default
@@ -37,6 +40,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> PayloadAttestationServ
             beacon_nodes: None,
             executor: None,
             chain_spec: None,
+            duty_history: None,
         }
     }
 
@@ -70,6 +74,11 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> PayloadAttestationServ
         self
     }
 
+    pub fn duty_history(mut self, duty_history: Option<Arc<DutyHistory>>) -> Self {
+        self.duty_history = duty_history;
+        self
+    }
+
     pub fn build(self) -> Result<PayloadAttestationService<S, T>, String> {
         let chain_spec = self
             .chain_spec
@@ -93,6 +102,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> PayloadAttestationServ
                     .ok_or("Cannot build PayloadAttestationService without executor")?,
                 gloas_fork_epoch: chain_spec.gloas_fork_epoch,
                 chain_spec,
+                duty_history: self.duty_history,
             }),
         })
     }
@@ -106,6 +116,7 @@ pub struct Inner<S, T> {
     executor: TaskExecutor,
     gloas_fork_epoch: Option<Epoch>,
     chain_spec: Arc<ChainSpec>,
+    duty_history: Option<Arc<DutyHistory>>,
 }
 
 /// Produces payload timeliness attestations for PTC (Payload Timeliness Committee) duties.
@@ -280,6 +291,15 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> PayloadAttestationServ
                         payload_present = attestation_data.payload_present,
                         "Signed payload attestation"
                     );
+                    if let Some(duty_history) = &self.duty_history {
+                        duty_history.record_signed(
+                            DutyKind::PayloadAttestation,
+                            slot,
+                            &duty.pubkey,
+                            duty.validator_index,
+                            Some(duty.ptc_committee_index),
+                        );
+                    }
                     messages.push(message);
                 }
                 Err(e) => {
@@ -303,19 +323,22 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> PayloadAttestationServ
 
         // Submit to BN.
         let num_messages = messages.len();
+        let published_via = &PublishedVia::default();
         {
             let _http_timer = metrics::start_timer_vec(
                 &metrics::PAYLOAD_ATTESTATION_SERVICE_TIMES,
                 &[metrics::PAYLOAD_ATTESTATIONS_HTTP_POST],
             );
             self.beacon_nodes
-                .request(ApiTopic::Attestations, |beacon_node| {
+                .request(ApiTopic::PayloadAttestations, |beacon_node| {
                     let messages = messages.clone();
                     async move {
                         beacon_node
                             .post_beacon_pool_payload_attestations(&messages)
                             .await
-                            .map_err(|e| e.to_string())
+                            .map_err(|e| e.to_string())?;
+                        published_via.record(&beacon_node);
+                        Ok::<_, String>(())
                     }
                 })
                 .await
@@ -328,6 +351,19 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> PayloadAttestationServ
                 })?;
         }
 
+        if let Some(duty_history) = &self.duty_history {
+            let validator_indices = messages
+                .iter()
+                .map(|message| message.validator_index)
+                .collect::<Vec<_>>();
+            duty_history.record_published(
+                DutyKind::PayloadAttestation,
+                slot,
+                &validator_indices,
+                &published_via.beacon_nodes(),
+            );
+        }
+
         info!(
             slot = slot.as_u64(),
             count = num_messages,
@@ -612,7 +648,7 @@ mod produce_tests {
         let mut fallback = BeaconNodeFallback::new(
             vec![candidate],
             Config::default(),
-            vec![ApiTopic::Attestations],
+            vec![ApiTopic::PayloadAttestations],
             spec_arc.clone(),
         );
         let slot_clock = TestingSlotClock::new(
@@ -854,7 +890,7 @@ mod produce_tests {
         let mut fallback = BeaconNodeFallback::new(
             vec![candidate],
             Config::default(),
-            vec![ApiTopic::Attestations],
+            vec![ApiTopic::PayloadAttestations],
             spec_arc.clone(),
         );
         let slot_clock = TestingSlotClock::new(
@@ -988,7 +1024,7 @@ mod produce_tests {
         let mut fallback = BeaconNodeFallback::new(
             vec![candidate],
             Config::default(),
-            vec![ApiTopic::Attestations],
+            vec![ApiTopic::PayloadAttestations],
             spec_arc.clone(),
         );
         let slot_clock = TestingSlotClock::new(
@@ -1,6 +1,8 @@
 use crate::duties_service::DutiesService;
 use beacon_node_fallback::{ApiTopic, BeaconNodeFallback};
+use duty_history::{DutyHistory, PublishedVia};
 use eth2::types::BlockId;
+use eth2::vibehouse_vc::types::DutyKind;
 use futures::future::FutureExt;
 use futures::future::join_all;
 use logging::crit;
@@ -46,6 +48,7 @@ pub struct Inner<S: ValidatorStore, T: SlotClock + 'static> {
     slot_clock: T,
     beacon_nodes: Arc<BeaconNodeFallback<T>>,
     executor: TaskExecutor,
+    duty_history: Option<Arc<DutyHistory>>,
     /// Boolean to track whether the service has posted subscriptions to the BN at least once.
     ///
     /// This acts as a latch that fires once upon start-up, and then never again.
@@ -59,6 +62,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> SyncCommitteeService<S
         slot_clock: T,
         beacon_nodes: Arc<BeaconNodeFallback<T>>,
         executor: TaskExecutor,
+        duty_history: Option<Arc<DutyHistory>>,
     ) -> Self {
         Self {
             inner: Arc::new(Inner {
@@ -67,6 +71,7 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> SyncCommitteeService<S
                 slot_clock,
                 beacon_nodes,
                 executor,
+                duty_history,
                 first_subscription_done: AtomicBool::new(false),
             }),
         }
@@ -272,7 +277,18 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> SyncCommitteeService<S
                 )
                 .await
             {
-                Ok(signature) => Some(signature),
+                Ok(signature) => {
+                    if let Some(duty_history) = &self.duty_history {
+                        duty_history.record_signed(
+                            DutyKind::SyncCommittee,
+                            slot,
+                            &duty.pubkey,
+                            duty.validator_index,
+                            None,
+                        );
+                    }
+                    Some(signature)
+                }
                 Err(ValidatorStoreError::UnknownPubkey(pubkey)) => {
                     // A pubkey can be missing when a validator was recently
                     // removed via the API.
@@ -303,11 +319,16 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> SyncCommitteeService<S
             .flatten()
             .collect::<Vec<_>>();
 
+        let published_via = &PublishedVia::default();
         self.beacon_nodes
             .request(ApiTopic::SyncCommittee, |beacon_node| async move {
-                beacon_node
+                let result = beacon_node
                     .post_beacon_pool_sync_committee_signatures(committee_signatures)
-                    .await
+                    .await;
+                if result.is_ok() {
+                    published_via.record(&beacon_node);
+                }
+                result
             })
             .await
             .map_err(|e| {
@@ -318,6 +339,19 @@ impl<S: ValidatorStore + 'static, T: SlotClock + 'static> SyncCommitteeService<S
                 );
             })?;
 
+        if let Some(duty_history) = &self.duty_history {
+            let validator_indices = committee_signatures
+                .iter()
+                .map(|signature| signature.validator_index)
+                .collect::<Vec<_>>();
+            duty_history.record_published(
+                DutyKind::SyncCommittee,
+                slot,
+                &validator_indices,
+                &published_via.beacon_nodes(),
+            );
+        }
+
         info!(
             count = committee_signatures.len(),
             head_block = ?beacon_block_root,
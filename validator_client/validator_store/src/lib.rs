@@ -16,6 +16,7 @@ use types::{
 #[derive(Debug, PartialEq, Clone)]
 pub enum Error<T> {
     DoppelgangerProtected(PublicKeyBytes),
+    HighAvailabilityStandby(PublicKeyBytes),
     UnknownToDoppelgangerService(PublicKeyBytes),
     UnknownPubkey(PublicKeyBytes),
     Slashable(NotSafe),
@@ -0,0 +1,157 @@
+//! Active/passive high availability for validator clients which load the same keys.
+//!
+//! Every instance opens a slashing protection database in a directory shared between them, and
+//! competes for the signing lease stored in it. Only the instance holding the lease signs. It
+//! records each block and attestation in the shared database as well as its own, under the lease,
+//! so that an instance taking over after the lease expires can import everything signed before
+//! it.
+//!
+//! An instance stops signing a quarter of the lease duration before its lease expires, so that it
+//! is no longer signing by the time another instance can acquire the lease. Slashable messages are
+//! protected regardless of the timing: the shared database refuses to record them for anyone but
+//! the current lease holder.
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use slashing_protection::{
+    Lease, LeaseOutcome, NotSafe, SHARED_SLASHING_PROTECTION_FILENAME, Safe, SlashingDatabase,
+};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use types::{AttestationData, BeaconBlockHeader, Hash256, PublicKeyBytes};
+
+/// The default duration of the signing lease.
+pub const DEFAULT_LEASE_DURATION: Duration = Duration::from_secs(24);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Config {
+    /// The directory shared by all instances, holding the shared slashing protection database.
+    pub shared_dir: PathBuf,
+    /// The identifier of this instance, which must be unique among the instances.
+    pub instance_id: String,
+    /// The duration of the lease, and therefore the longest time before a standby takes over.
+    pub lease_duration: Duration,
+}
+
+impl Config {
+    /// How often the lease holder renews the lease and standbys try to acquire it.
+    pub fn renew_interval(&self) -> Duration {
+        self.lease_duration / 4
+    }
+}
+
+/// The state of this instance's claim on the signing lease.
+pub struct HighAvailability {
+    config: Config,
+    shared_db: SlashingDatabase,
+    lease: RwLock<Option<Lease>>,
+}
+
+impl HighAvailability {
+    /// Open the shared slashing protection database in `config.shared_dir`, without taking the
+    /// lease.
+    pub fn open(config: Config) -> Result<Self, String> {
+        if config.lease_duration.is_zero() {
+            return Err("The high availability lease duration must not be zero".to_string());
+        }
+        let path = config.shared_dir.join(SHARED_SLASHING_PROTECTION_FILENAME);
+        let shared_db = SlashingDatabase::open_or_create_shared(&path).map_err(|e| {
+            format!(
+                "Failed to open the shared slashing protection database at {}: {:?}",
+                path.display(),
+                e
+            )
+        })?;
+        Ok(Self {
+            config,
+            shared_db,
+            lease: RwLock::new(None),
+        })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The slashing protection database shared by all instances.
+    pub fn shared_db(&self) -> &SlashingDatabase {
+        &self.shared_db
+    }
+
+    /// Returns the lease if this instance may sign under it at `now`.
+    pub fn active_lease(&self, now: Duration) -> Option<Lease> {
+        let margin = self.config.renew_interval();
+        self.lease
+            .read()
+            .as_ref()
+            .filter(|lease| lease.expires_at > now + margin)
+            .cloned()
+    }
+
+    /// Returns `true` if this instance may sign at the current time.
+    pub fn allows_signing(&self) -> bool {
+        self.active_lease(unix_now()).is_some()
+    }
+
+    /// Try to acquire or renew the lease in the shared database.
+    ///
+    /// This does not change which lease this instance signs under, that is done by `set_lease`
+    /// once the caller is ready to sign.
+    pub fn try_acquire_lease(&self) -> Result<LeaseOutcome, NotSafe> {
+        self.shared_db.try_acquire_lease(
+            &self.config.instance_id,
+            unix_now(),
+            self.config.lease_duration,
+        )
+    }
+
+    /// The lease this instance last signed under, which may have expired.
+    pub fn lease(&self) -> Option<Lease> {
+        self.lease.read().clone()
+    }
+
+    pub fn set_lease(&self, lease: Option<Lease>) {
+        *self.lease.write() = lease;
+    }
+
+    /// Record a block proposal in the shared database under the active lease.
+    pub fn check_and_insert_block_proposal(
+        &self,
+        validator_pubkey: &PublicKeyBytes,
+        block_header: &BeaconBlockHeader,
+        domain: Hash256,
+    ) -> Result<Safe, NotSafe> {
+        let now = unix_now();
+        let lease = self.active_lease(now).ok_or(NotSafe::LeaseNotHeld)?;
+        self.shared_db.check_and_insert_block_proposal_under_lease(
+            &lease,
+            now,
+            validator_pubkey,
+            block_header,
+            domain,
+        )
+    }
+
+    /// Record an attestation in the shared database under the active lease.
+    pub fn check_and_insert_attestation(
+        &self,
+        validator_pubkey: &PublicKeyBytes,
+        attestation: &AttestationData,
+        domain: Hash256,
+    ) -> Result<Safe, NotSafe> {
+        let now = unix_now();
+        let lease = self.active_lease(now).ok_or(NotSafe::LeaseNotHeld)?;
+        self.shared_db.check_and_insert_attestation_under_lease(
+            &lease,
+            now,
+            validator_pubkey,
+            attestation,
+            domain,
+        )
+    }
+}
+
+fn unix_now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
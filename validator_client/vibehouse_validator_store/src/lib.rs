@@ -1,6 +1,7 @@
 use account_utils::validator_definitions::{PasswordStorage, ValidatorDefinition};
 use doppelganger_service::DoppelgangerService;
 use eth2::types::PublishBlockRequest;
+use high_availability::HighAvailability;
 use initialized_validators::InitializedValidators;
 use logging::crit;
 use parking_lot::{Mutex, RwLock};
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use signing_method::Error as SigningError;
 use signing_method::{SignableMessage, SigningContext, SigningMethod};
 use slashing_protection::{
-    InterchangeError, NotSafe, Safe, SlashingDatabase, interchange::Interchange,
+    InterchangeError, LeaseOutcome, NotSafe, Safe, SlashingDatabase, interchange::Interchange,
 };
 use slot_clock::SlotClock;
 use std::marker::PhantomData;
@@ -32,6 +33,8 @@ use validator_store::{
     ValidatorStore,
 };
 
+pub mod high_availability;
+
 pub type Error = ValidatorStoreError<SigningError>;
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -67,6 +70,7 @@ pub struct VibehouseValidatorStore<T, E> {
     genesis_validators_root: Hash256,
     spec: Arc<ChainSpec>,
     doppelganger_service: Option<Arc<DoppelgangerService>>,
+    high_availability: Option<HighAvailability>,
     slot_clock: T,
     fee_recipient_process: Option<Address>,
     gas_limit: Option<u64>,
@@ -99,6 +103,7 @@ impl<T: SlotClock + 'static, E: EthSpec> VibehouseValidatorStore<T, E> {
             genesis_validators_root,
             spec,
             doppelganger_service,
+            high_availability: None,
             slot_clock,
             fee_recipient_process: config.fee_recipient,
             gas_limit: config.gas_limit,
@@ -129,6 +134,126 @@ impl<T: SlotClock + 'static, E: EthSpec> VibehouseValidatorStore<T, E> {
         Ok(())
     }
 
+    /// Enable active/passive high availability, so that this instance only signs while it holds
+    /// the lease in the database shared with the other instances.
+    ///
+    /// All local validators are registered in the shared database. Signing remains disabled until
+    /// the lease is acquired by `update_high_availability_lease`.
+    pub fn enable_high_availability(
+        &mut self,
+        high_availability: HighAvailability,
+    ) -> Result<(), String> {
+        high_availability
+            .shared_db()
+            .register_validators(self.validators.read().iter_voting_pubkeys())
+            .map_err(|e| format!("Failed to register validators in shared database: {e:?}"))?;
+        self.high_availability = Some(high_availability);
+        Ok(())
+    }
+
+    /// Returns `true` if high availability is disabled, or this instance holds the lease.
+    fn high_availability_allows_signing(&self) -> bool {
+        self.high_availability
+            .as_ref()
+            .is_none_or(HighAvailability::allows_signing)
+    }
+
+    /// Acquire or renew the high availability lease, enabling or disabling signing accordingly.
+    ///
+    /// Before signing is enabled under a new lease, the records of the shared slashing protection
+    /// database are imported into the local one and all validators are re-registered with
+    /// doppelganger protection, if it is enabled. Doppelganger protection then ensures that the
+    /// previous lease holder is no longer signing before this instance starts.
+    ///
+    /// This function has no effect if high availability is disabled.
+    pub fn update_high_availability_lease(&self) {
+        let Some(high_availability) = &self.high_availability else {
+            return;
+        };
+        let held_term = high_availability.lease().map(|lease| lease.term);
+
+        match high_availability.try_acquire_lease() {
+            Ok(LeaseOutcome::Renewed(lease)) if held_term == Some(lease.term) => {
+                high_availability.set_lease(Some(lease));
+            }
+            Ok(LeaseOutcome::Acquired(lease) | LeaseOutcome::Renewed(lease)) => {
+                if let Err(e) = self.take_over_from_shared_slashing_protection(high_availability) {
+                    error!(
+                        error = %e,
+                        "Unable to take over as high availability lease holder"
+                    );
+                    if let Err(e) = high_availability.shared_db().release_lease(&lease) {
+                        error!(error = ?e, "Unable to release high availability lease");
+                    }
+                    return;
+                }
+                info!(
+                    instance_id = %lease.holder,
+                    term = lease.term,
+                    "Acquired high availability lease"
+                );
+                high_availability.set_lease(Some(lease));
+            }
+            Ok(LeaseOutcome::HeldByOther(lease)) => {
+                if held_term.is_some() {
+                    warn!(
+                        holder = %lease.holder,
+                        term = lease.term,
+                        "High availability lease held by another instance, signing disabled"
+                    );
+                }
+                high_availability.set_lease(None);
+            }
+            Err(e) => {
+                error!(
+                    error = ?e,
+                    "Unable to renew high availability lease"
+                );
+            }
+        }
+
+        validator_metrics::set_gauge(
+            &validator_metrics::HIGH_AVAILABILITY_LEASE_HELD,
+            i64::from(high_availability.allows_signing()),
+        );
+    }
+
+    /// Spawn a task which calls `update_high_availability_lease` once per renewal interval.
+    ///
+    /// This function has no effect if high availability is disabled.
+    pub fn start_high_availability_service(self: &Arc<Self>) {
+        let Some(high_availability) = &self.high_availability else {
+            return;
+        };
+        let renew_interval = high_availability.config().renew_interval();
+        let validator_store = self.clone();
+        self.task_executor.spawn(
+            async move {
+                loop {
+                    validator_store.update_high_availability_lease();
+                    tokio::time::sleep(renew_interval).await;
+                }
+            },
+            "high_availability",
+        );
+    }
+
+    /// Import the shared slashing protection database into the local one, and restart doppelganger
+    /// protection for all validators.
+    fn take_over_from_shared_slashing_protection(
+        &self,
+        high_availability: &HighAvailability,
+    ) -> Result<(), String> {
+        let interchange = high_availability
+            .shared_db()
+            .export_all_interchange_info(self.genesis_validators_root)
+            .map_err(|e| format!("Failed to export shared slashing protection: {e:?}"))?;
+        self.slashing_protection
+            .import_interchange_info(interchange, self.genesis_validators_root)
+            .map_err(|e| format!("Failed to import shared slashing protection: {e:?}"))?;
+        self.register_all_in_doppelganger_protection_if_enabled()
+    }
+
     /// Returns `true` if doppelganger protection is enabled, or else `false`.
     pub fn doppelganger_protection_enabled(&self) -> bool {
         self.doppelganger_service.is_some()
@@ -197,6 +322,13 @@ impl<T: SlotClock + 'static, E: EthSpec> VibehouseValidatorStore<T, E> {
             .register_validator(validator_pubkey)
             .map_err(|e| format!("failed to register validator: {e:?}"))?;
 
+        if let Some(high_availability) = &self.high_availability {
+            high_availability
+                .shared_db()
+                .register_validator(validator_pubkey)
+                .map_err(|e| format!("failed to register validator in shared database: {e:?}"))?;
+        }
+
         if let Some(doppelganger_service) = &self.doppelganger_service {
             doppelganger_service.register_new_validator(
                 validator_pubkey,
@@ -247,6 +379,9 @@ impl<T: SlotClock + 'static, E: EthSpec> VibehouseValidatorStore<T, E> {
         &self,
         validator_pubkey: PublicKeyBytes,
     ) -> Result<Arc<SigningMethod>, Error> {
+        if !self.high_availability_allows_signing() {
+            return Err(Error::HighAvailabilityStandby(validator_pubkey));
+        }
         if self.doppelganger_protection_allows_signing(validator_pubkey) {
             self.validators
                 .read()
@@ -267,6 +402,9 @@ impl<T: SlotClock + 'static, E: EthSpec> VibehouseValidatorStore<T, E> {
         &self,
         validator_pubkey: PublicKeyBytes,
     ) -> Result<Arc<SigningMethod>, Error> {
+        if !self.high_availability_allows_signing() {
+            return Err(Error::HighAvailabilityStandby(validator_pubkey));
+        }
         self.validators
             .read()
             .signing_method(&validator_pubkey)
@@ -463,18 +601,26 @@ impl<T: SlotClock + 'static, E: EthSpec> VibehouseValidatorStore<T, E> {
         let signing_method = self.doppelganger_checked_signing_method(validator_pubkey)?;
 
         // Check for slashing conditions.
+        let block_header = block.block_header();
         let slashing_status = if signing_method
             .requires_local_slashing_protection(self.enable_web3signer_slashing_protection)
         {
             self.slashing_protection.check_and_insert_block_proposal(
                 &validator_pubkey,
-                &block.block_header(),
+                &block_header,
                 domain_hash,
             )
         } else {
             Ok(Safe::Valid)
         };
 
+        // Record the block for the other high availability instances.
+        let slashing_status = match (&self.high_availability, slashing_status) {
+            (Some(high_availability), Ok(Safe::Valid)) => high_availability
+                .check_and_insert_block_proposal(&validator_pubkey, &block_header, domain_hash),
+            (_, slashing_status) => slashing_status,
+        };
+
         match slashing_status {
             // We can safely sign this block without slashing.
             Ok(Safe::Valid) => {
@@ -501,6 +647,10 @@ impl<T: SlotClock + 'static, E: EthSpec> VibehouseValidatorStore<T, E> {
                 );
                 Err(Error::SameData)
             }
+            Err(NotSafe::LeaseNotHeld) => {
+                warn!("Not signing block without high availability lease");
+                Err(Error::HighAvailabilityStandby(validator_pubkey))
+            }
             Err(NotSafe::UnregisteredValidator(pk)) => {
                 warn!(
                     msg = "Carefully consider running with --init-slashing-protection (see --help)",
@@ -580,12 +730,15 @@ impl<T: SlotClock + 'static, E: EthSpec> ValidatorStore for VibehouseValidatorSt
     ///   protection and are safe-enough to sign messages.
     /// - `DoppelgangerStatus::ignored`: returns all the pubkeys from `only_safe` *plus* those still
     ///   undergoing protection. This is useful for collecting duties or other non-signing tasks.
+    ///
+    /// While this instance is a high availability standby, all pubkeys are `SigningDisabled`.
     #[allow(clippy::needless_collect)] // Collect is required to avoid holding a lock.
     fn voting_pubkeys<I, F>(&self, filter_func: F) -> I
     where
         I: FromIterator<PublicKeyBytes>,
         F: Fn(DoppelgangerStatus) -> Option<PublicKeyBytes>,
     {
+        let high_availability_allows_signing = self.high_availability_allows_signing();
         // Collect all the pubkeys first to avoid interleaving locks on `self.validators` and
         // `self.doppelganger_service()`.
         let pubkeys = self
@@ -603,23 +756,31 @@ impl<T: SlotClock + 'static, E: EthSpec> ValidatorStore for VibehouseValidatorSt
                     |doppelganger_service| doppelganger_service.validator_status(pubkey),
                 )
             })
+            .map(|status| match status {
+                DoppelgangerStatus::SigningEnabled(pubkey) if !high_availability_allows_signing => {
+                    DoppelgangerStatus::SigningDisabled(pubkey)
+                }
+                status => status,
+            })
             .filter_map(filter_func)
             .collect()
     }
 
     /// Check if the `validator_pubkey` is permitted by the doppleganger protection to sign
-    /// messages.
+    /// messages, and this instance is not a high availability standby.
     fn doppelganger_protection_allows_signing(&self, validator_pubkey: PublicKeyBytes) -> bool {
-        self.doppelganger_service
-            .as_ref()
-            // If there's no doppelganger service then we assume it is purposefully disabled and
-            // declare that all keys are safe with regard to it.
-            .is_none_or(|doppelganger_service| {
-                doppelganger_service
-                    .validator_status(validator_pubkey)
-                    .only_safe()
-                    .is_some()
-            })
+        self.high_availability_allows_signing()
+            && self
+                .doppelganger_service
+                .as_ref()
+                // If there's no doppelganger service then we assume it is purposefully disabled and
+                // declare that all keys are safe with regard to it.
+                .is_none_or(|doppelganger_service| {
+                    doppelganger_service
+                        .validator_status(validator_pubkey)
+                        .only_safe()
+                        .is_some()
+                })
     }
 
     fn num_voting_validators(&self) -> usize {
@@ -892,6 +1053,13 @@ impl<T: SlotClock + 'static, E: EthSpec> ValidatorStore for VibehouseValidatorSt
             Ok(Safe::Valid)
         };
 
+        // Record the attestation for the other high availability instances.
+        let slashing_status = match (&self.high_availability, slashing_status) {
+            (Some(high_availability), Ok(Safe::Valid)) => high_availability
+                .check_and_insert_attestation(&validator_pubkey, attestation.data(), domain_hash),
+            (_, slashing_status) => slashing_status,
+        };
+
         match slashing_status {
             // We can safely sign this attestation.
             Ok(Safe::Valid) => {
@@ -922,6 +1090,10 @@ impl<T: SlotClock + 'static, E: EthSpec> ValidatorStore for VibehouseValidatorSt
                 );
                 Err(Error::SameData)
             }
+            Err(NotSafe::LeaseNotHeld) => {
+                warn!("Not signing attestation without high availability lease");
+                Err(Error::HighAvailabilityStandby(validator_pubkey))
+            }
             Err(NotSafe::UnregisteredValidator(pk)) => {
                 warn!(
                     msg = "Carefully consider running with --init-slashing-protection (see --help)",
@@ -1220,6 +1392,24 @@ impl<T: SlotClock + 'static, E: EthSpec> ValidatorStore for VibehouseValidatorSt
             return;
         }
 
+        // Only the lease holder prunes the shared database, so that standbys don't contend for its
+        // lock.
+        if let Some(high_availability) = &self.high_availability
+            && high_availability.allows_signing()
+        {
+            let shared_db = high_availability.shared_db();
+            if let Err(e) = shared_db
+                .prune_all_signed_attestations(all_pubkeys.iter(), new_min_target_epoch)
+                .and_then(|()| shared_db.prune_all_signed_blocks(all_pubkeys.iter(), new_min_slot))
+            {
+                error!(
+                    error = ?e,
+                    "Error during pruning of shared slashing protection DB"
+                );
+                return;
+            }
+        }
+
         *last_prune = current_epoch;
 
         info!("Completed pruning of slashing protection DB");
@@ -1254,7 +1444,7 @@ mod tests {
     use task_executor::test_utils::TestRuntime;
     use tempfile::tempdir;
     use types::{
-        ExecutionPayloadEnvelope, ForkName, Hash256, InclusionList, MinimalEthSpec,
+        Checkpoint, ExecutionPayloadEnvelope, ForkName, Hash256, InclusionList, MinimalEthSpec,
         PayloadAttestationData, Slot,
     };
     use zeroize::Zeroizing;
@@ -1682,4 +1872,79 @@ mod tests {
 
         assert!(result.is_err(), "unknown pubkey should return error");
     }
+
+    #[tokio::test]
+    async fn high_availability_signs_only_under_lease() {
+        let (mut store, _keypair, pubkey_bytes) = store_with_validator().await;
+        let shared_dir = tempdir().unwrap();
+        let config = high_availability::Config {
+            shared_dir: shared_dir.path().into(),
+            instance_id: "primary".into(),
+            lease_duration: std::time::Duration::from_secs(60),
+        };
+        store
+            .enable_high_availability(HighAvailability::open(config.clone()).unwrap())
+            .unwrap();
+
+        let inclusion_list = InclusionList {
+            slot: Slot::new(1),
+            validator_index: 0,
+            inclusion_list_committee_root: Hash256::repeat_byte(0xbb),
+            transactions: <_>::default(),
+        };
+        let only_safe: Vec<PublicKeyBytes> = store.voting_pubkeys(DoppelgangerStatus::only_safe);
+        assert!(only_safe.is_empty());
+        assert_eq!(
+            store
+                .sign_inclusion_list(pubkey_bytes, &inclusion_list)
+                .await,
+            Err(Error::HighAvailabilityStandby(pubkey_bytes))
+        );
+
+        store.update_high_availability_lease();
+        let only_safe: Vec<PublicKeyBytes> = store.voting_pubkeys(DoppelgangerStatus::only_safe);
+        assert_eq!(only_safe, vec![pubkey_bytes]);
+        store
+            .sign_inclusion_list(pubkey_bytes, &inclusion_list)
+            .await
+            .expect("lease holder should sign");
+
+        // Attestations are recorded in the shared database.
+        let checkpoint = |epoch| Checkpoint {
+            epoch: Epoch::new(epoch),
+            root: Hash256::ZERO,
+        };
+        let mut attestation = Attestation::<E>::empty_for_signing(
+            0,
+            1,
+            Slot::new(8),
+            Hash256::ZERO,
+            checkpoint(0),
+            checkpoint(1),
+            &store.spec,
+            false,
+        )
+        .unwrap();
+        store
+            .sign_attestation(pubkey_bytes, 0, &mut attestation, Epoch::new(1))
+            .await
+            .unwrap();
+        let standby = HighAvailability::open(high_availability::Config {
+            instance_id: "standby".into(),
+            ..config
+        })
+        .unwrap();
+        let exported = standby
+            .shared_db()
+            .export_all_interchange_info(Hash256::repeat_byte(42))
+            .unwrap();
+        assert_eq!(exported.data.len(), 1);
+        assert_eq!(exported.data[0].signed_attestations.len(), 1);
+
+        // The standby can't take the lease while it is held.
+        assert!(matches!(
+            standby.try_acquire_lease(),
+            Ok(LeaseOutcome::HeldByOther(_))
+        ));
+    }
 }
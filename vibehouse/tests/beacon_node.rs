@@ -1,4 +1,5 @@
 use crate::exec::{CommandLineTestExec, CompletedTest};
+use beacon_node::beacon_chain::bid_selection::{BidSelectionPolicy, BuilderId};
 use beacon_node::beacon_chain::chain_config::{
     DEFAULT_RE_ORG_CUTOFF_DENOMINATOR, DEFAULT_RE_ORG_HEAD_THRESHOLD,
     DEFAULT_RE_ORG_MAX_EPOCHS_SINCE_FINALIZATION, DEFAULT_SYNC_TOLERANCE_EPOCHS,
@@ -227,6 +228,56 @@ fn checkpoint_sync_url_timeout_default() {
         });
 }
 
+#[test]
+fn checkpoint_sync_url_multiple() {
+    CommandLineTest::new()
+        .flag(
+            "checkpoint-sync-url",
+            Some("http://one.example:5052, http://two.example:5052,http://three.example:5052"),
+        )
+        .flag("checkpoint-sync-quorum", Some("2"))
+        .run_with_zero_port()
+        .with_config(|config| {
+            assert_eq!(
+                config.genesis_state_url.as_deref(),
+                Some("http://one.example:5052")
+            );
+        });
+}
+
+#[test]
+#[should_panic(expected = "")]
+fn checkpoint_sync_quorum_exceeds_urls() {
+    CommandLineTest::new()
+        .flag(
+            "checkpoint-sync-url",
+            Some("http://one.example:5052,http://two.example:5052"),
+        )
+        .flag("checkpoint-sync-quorum", Some("3"))
+        .run_with_zero_port();
+}
+
+#[test]
+#[should_panic(expected = "")]
+fn checkpoint_sync_url_duplicate_provider() {
+    CommandLineTest::new()
+        .flag(
+            "checkpoint-sync-url",
+            Some("http://one.example:5052,http://ONE.example:5052/eth"),
+        )
+        .flag("checkpoint-sync-quorum", Some("2"))
+        .run_with_zero_port();
+}
+
+#[test]
+#[should_panic(expected = "")]
+fn checkpoint_sync_quorum_zero() {
+    CommandLineTest::new()
+        .flag("checkpoint-sync-url", Some("http://one.example:5052"))
+        .flag("checkpoint-sync-quorum", Some("0"))
+        .run_with_zero_port();
+}
+
 #[test]
 fn prepare_payload_lookahead_default() {
     CommandLineTest::new()
@@ -292,6 +343,21 @@ fn paranoid_block_proposal_on() {
         .with_config(|config| assert!(config.chain.paranoid_block_proposal));
 }
 
+#[test]
+fn fast_confirmation_default() {
+    CommandLineTest::new()
+        .run_with_zero_port()
+        .with_config(|config| assert!(!config.chain.fast_confirmation));
+}
+
+#[test]
+fn fast_confirmation_on() {
+    CommandLineTest::new()
+        .flag("fast-confirmation", None)
+        .run_with_zero_port()
+        .with_config(|config| assert!(config.chain.fast_confirmation));
+}
+
 #[test]
 fn reset_payload_statuses_default() {
     CommandLineTest::new()
@@ -390,6 +456,21 @@ fn genesis_backfill_with_historic_flag() {
         .with_config(|config| assert!(config.chain.genesis_backfill));
 }
 
+#[test]
+fn era_dir_default() {
+    CommandLineTest::new()
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.chain.era_dir, None));
+}
+
+#[test]
+fn era_dir_flag() {
+    CommandLineTest::new()
+        .flag("era-dir", Some("/tmp/era"))
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.chain.era_dir, Some(PathBuf::from("/tmp/era"))));
+}
+
 #[test]
 fn complete_blob_backfill_default() {
     CommandLineTest::new()
@@ -506,6 +587,82 @@ fn execution_timeout_multiplier_flag() {
         });
 }
 #[test]
+fn execution_fallback_endpoints_default() {
+    let dir = TempDir::new().expect("Unable to create temporary directory");
+    CommandLineTest::new_with_no_execution_endpoint()
+        .flag("execution-endpoint", Some("http://meow.cats"))
+        .flag(
+            "execution-jwt",
+            dir.path().join("jwt-file").as_os_str().to_str(),
+        )
+        .run_with_zero_port()
+        .with_config(|config| {
+            let config = config.execution_layer.as_ref().unwrap();
+            assert!(config.fallback_execution_endpoints.is_empty());
+            assert!(config.fallback_secret_files.is_empty());
+            assert!(!config.cross_check_new_payload);
+        });
+}
+#[test]
+fn execution_fallback_endpoints_flag() {
+    let dir = TempDir::new().expect("Unable to create temporary directory");
+    let jwts = format!(
+        "{},{}",
+        dir.path().join("jwt-1").display(),
+        dir.path().join("jwt-2").display()
+    );
+    CommandLineTest::new_with_no_execution_endpoint()
+        .flag("execution-endpoint", Some("http://meow.cats"))
+        .flag(
+            "execution-jwt",
+            dir.path().join("jwt-file").as_os_str().to_str(),
+        )
+        .flag(
+            "execution-fallback-endpoints",
+            Some("http://woof.dogs:8551,http://quack.ducks:8551"),
+        )
+        .flag("execution-fallback-jwts", Some(&jwts))
+        .flag("execution-cross-check", None)
+        .run_with_zero_port()
+        .with_config(|config| {
+            let config = config.execution_layer.as_ref().unwrap();
+            let urls = config
+                .fallback_execution_endpoints
+                .iter()
+                .map(|url| url.full.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                urls,
+                vec!["http://woof.dogs:8551/", "http://quack.ducks:8551/"]
+            );
+            assert_eq!(
+                config.fallback_secret_files,
+                vec![dir.path().join("jwt-1"), dir.path().join("jwt-2")]
+            );
+            assert!(config.cross_check_new_payload);
+        });
+}
+#[test]
+#[should_panic(expected = "")]
+fn execution_fallback_jwts_count_mismatch() {
+    let dir = TempDir::new().expect("Unable to create temporary directory");
+    CommandLineTest::new_with_no_execution_endpoint()
+        .flag("execution-endpoint", Some("http://meow.cats"))
+        .flag(
+            "execution-jwt",
+            dir.path().join("jwt-file").as_os_str().to_str(),
+        )
+        .flag(
+            "execution-fallback-endpoints",
+            Some("http://woof.dogs:8551,http://quack.ducks:8551"),
+        )
+        .flag(
+            "execution-fallback-jwts",
+            dir.path().join("jwt-1").as_os_str().to_str(),
+        )
+        .run_with_zero_port();
+}
+#[test]
 fn bellatrix_execution_endpoints_flag() {
     run_bellatrix_execution_endpoints_flag_test("execution-endpoints");
 }
@@ -1860,6 +2017,31 @@ fn state_cache_headroom_flag() {
         });
 }
 #[test]
+fn state_cache_memory_mb_default() {
+    CommandLineTest::new()
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.store.state_cache_memory_budget, None));
+}
+#[test]
+fn state_cache_memory_mb_flag() {
+    CommandLineTest::new()
+        .flag("state-cache-memory-mb", Some("4096"))
+        .run_with_zero_port()
+        .with_config(|config| {
+            assert_eq!(
+                config.store.state_cache_memory_budget,
+                Some(new_non_zero_usize(4096 * 1024 * 1024))
+            );
+        });
+}
+#[test]
+#[should_panic(expected = "")]
+fn state_cache_memory_mb_zero() {
+    CommandLineTest::new()
+        .flag("state-cache-memory-mb", Some("0"))
+        .run_with_zero_port();
+}
+#[test]
 fn historic_state_cache_size_flag() {
     CommandLineTest::new()
         .flag("historic-state-cache-size", Some("4"))
@@ -1993,6 +2175,50 @@ fn blob_prune_margin_epochs_on_startup_ten() {
         .with_config(|config| assert!(config.store.blob_prune_margin_epochs == 10));
 }
 #[test]
+fn blob_archive_dir_default() {
+    CommandLineTest::new()
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.store.blob_archive_dir, None));
+}
+#[test]
+fn blob_archive_dir_flag() {
+    CommandLineTest::new()
+        .flag("blob-archive-dir", Some("/tmp/blob-archive"))
+        .run_with_zero_port()
+        .with_config(|config| {
+            assert_eq!(
+                config.store.blob_archive_dir,
+                Some(PathBuf::from("/tmp/blob-archive"))
+            )
+        });
+}
+#[test]
+fn validator_history_default() {
+    CommandLineTest::new()
+        .run_with_zero_port()
+        .with_config(|config| assert!(!config.store.validator_history));
+}
+#[test]
+fn validator_history_flag() {
+    CommandLineTest::new()
+        .flag("validator-history", None)
+        .run_with_zero_port()
+        .with_config(|config| assert!(config.store.validator_history));
+}
+#[test]
+fn side_object_retention_epochs_default() {
+    CommandLineTest::new()
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.store.side_object_retention_epochs, 0));
+}
+#[test]
+fn side_object_retention_epochs_flag() {
+    CommandLineTest::new()
+        .flag("side-object-retention-epochs", Some("2048"))
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.store.side_object_retention_epochs, 2048));
+}
+#[test]
 fn reconstruct_historic_states_flag() {
     CommandLineTest::new()
         .flag("reconstruct-historic-states", None)
@@ -2269,6 +2495,30 @@ fn slasher_backend_override_to_default() {
         });
 }
 
+#[test]
+fn beacon_node_backend_default() {
+    CommandLineTest::new()
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.store.backend.to_string(), "redb"));
+}
+
+#[test]
+fn beacon_node_backend_flag() {
+    CommandLineTest::new()
+        .flag("beacon-node-backend", Some("redb"))
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.store.backend.to_string(), "redb"));
+}
+
+#[cfg(feature = "beacon-node-lmdb")]
+#[test]
+fn beacon_node_backend_lmdb_flag() {
+    CommandLineTest::new()
+        .flag("beacon-node-backend", Some("lmdb"))
+        .run_with_zero_port()
+        .with_config(|config| assert_eq!(config.store.backend.to_string(), "lmdb"));
+}
+
 #[test]
 fn malloc_tuning_flag() {
     CommandLineTest::new()
@@ -2545,6 +2795,38 @@ fn light_client_http_server_disabled() {
         });
 }
 
+#[test]
+fn epbs_bid_selection_default() {
+    CommandLineTest::new()
+        .run_with_zero_port()
+        .with_config(|config| {
+            assert_eq!(config.chain.bid_selection, BidSelectionPolicy::default());
+        });
+}
+
+#[test]
+fn epbs_bid_selection_flags() {
+    let pubkey = "0xa99a76ed7796f7be22d5b7e85deeb7c5677e88e511e0b337618f8c4eb61349b4bf2d153f649f7b53359fe8b94a38e44c";
+    CommandLineTest::new()
+        .flag("epbs-min-bid-value", Some("1000"))
+        .flag("epbs-builder-allowlist", Some(&format!("3,{pubkey}")))
+        .flag("epbs-builder-denylist", Some("7"))
+        .flag("epbs-require-builder-balance", None)
+        .flag("epbs-self-build-boost", Some("10"))
+        .run_with_zero_port()
+        .with_config(|config| {
+            let policy = &config.chain.bid_selection;
+            assert_eq!(policy.min_bid_value, 1000);
+            assert_eq!(
+                policy.builder_allowlist,
+                vec![BuilderId::Index(3), BuilderId::from_str(pubkey).unwrap()]
+            );
+            assert_eq!(policy.builder_denylist, vec![BuilderId::Index(7)]);
+            assert!(policy.require_builder_balance);
+            assert_eq!(policy.self_build_boost_percent, Some(10));
+        });
+}
+
 #[test]
 fn sync_tolerance_epochs() {
     CommandLineTest::new()
@@ -501,6 +501,63 @@ fn no_doppelganger_protection_flag() {
         .with_config(|config| assert!(!config.enable_doppelganger_protection));
 }
 
+#[test]
+fn duty_history_flag() {
+    CommandLineTest::new()
+        .flag("enable-duty-history", None)
+        .run()
+        .with_config(|config| assert!(config.enable_duty_history));
+}
+#[test]
+fn no_duty_history_flag() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert!(!config.enable_duty_history));
+}
+
+#[test]
+fn high_availability_flags() {
+    CommandLineTest::new()
+        .flag("high-availability-dir", Some("/mnt/shared/vc"))
+        .flag("high-availability-instance-id", Some("vc-1"))
+        .flag("high-availability-lease-duration", Some("36"))
+        .run()
+        .with_config(|config| {
+            let high_availability = config.high_availability.as_ref().unwrap();
+            assert_eq!(
+                high_availability.shared_dir,
+                PathBuf::from("/mnt/shared/vc")
+            );
+            assert_eq!(high_availability.instance_id, "vc-1");
+            assert_eq!(high_availability.lease_duration, Duration::from_secs(36));
+        });
+}
+#[test]
+fn high_availability_defaults() {
+    CommandLineTest::new()
+        .flag("high-availability-dir", Some("/mnt/shared/vc"))
+        .run()
+        .with_config(|config| {
+            let high_availability = config.high_availability.as_ref().unwrap();
+            assert!(!high_availability.instance_id.is_empty());
+            assert_eq!(high_availability.lease_duration, Duration::from_secs(24));
+        });
+}
+#[test]
+fn no_high_availability_flag() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert!(config.high_availability.is_none()));
+}
+#[test]
+#[should_panic(expected = "")]
+fn high_availability_instance_id_requires_dir() {
+    CommandLineTest::new()
+        .flag("high-availability-instance-id", Some("vc-1"))
+        .run()
+        .with_config(|config| assert!(config.high_availability.is_none()));
+}
+
 #[test]
 fn no_gas_limit_flag() {
     CommandLineTest::new()
@@ -622,6 +679,23 @@ fn broadcast_flag() {
         .with_config(|config| {
             assert_eq!(config.broadcast_topics, vec![ApiTopic::Attestations]);
         });
+    // Gloas and Heze topics
+    CommandLineTest::new()
+        .flag(
+            "broadcast",
+            Some("payload-attestations,inclusion-lists,proposer-preferences"),
+        )
+        .run()
+        .with_config(|config| {
+            assert_eq!(
+                config.broadcast_topics,
+                vec![
+                    ApiTopic::PayloadAttestations,
+                    ApiTopic::InclusionLists,
+                    ApiTopic::ProposerPreferences
+                ],
+            );
+        });
 }
 
 /// Tests for validator fallback flags.